@@ -0,0 +1,757 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A HashMap is a data structure to store key-value pairs with an average lookup time of O(1).
+//! Multiple variations of that container are available.
+//!
+//!  * [`FixedSizeHashMap`](crate::hashmap::FixedSizeHashMap), compile-time fixed-size hashmap
+//!    that is self-contained and shared-memory compatible.
+//!  * [`RelocatableHashMap`](crate::hashmap::RelocatableHashMap), run-time fixed-size hashmap
+//!    that is shared-memory compatible.
+//!  * [`HashMap`](crate::hashmap::HashMap), run-time fixed-size hashmap that is not
+//!    shared-memory compatible since the memory resides in the heap.
+//!
+//! The keys are hashed with the [`DeterministicHasher`] so that every process that has access to
+//! the map computes the same bucket for the same key. The collisions are resolved with
+//! index-based chaining, therefore the map does not contain any pointers and can be relocated.
+//!
+//! # User Examples
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_container::hashmap::FixedSizeHashMap;
+//!
+//! const CAPACITY: usize = 100;
+//! let mut map = FixedSizeHashMap::<u64, u8, CAPACITY>::new();
+//! assert_eq!(map.insert(23, 4).is_ok(), true);
+//! assert_eq!(map.get(&23).unwrap(), 4);
+//! ```
+
+use core::fmt::Debug;
+use core::hash::{Hash, Hasher};
+use core::mem::MaybeUninit;
+use iceoryx2_bb_concurrency::atomic::AtomicBool;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary::relocatable_ptr::GenericRelocatablePointer;
+use iceoryx2_bb_elementary_traits::generic_pointer::GenericPointer;
+use iceoryx2_bb_elementary_traits::owning_pointer::GenericOwningPointer;
+pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::{
+    placement_default::PlacementDefault, zero_copy_send::ZeroCopySend,
+};
+use iceoryx2_log::{fail, fatal_panic};
+
+use crate::vec::{MetaVec, RelocatableVec};
+
+const INVALID: usize = usize::MAX;
+
+/// Failures caused by insert()
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum HashMapError {
+    /// The HashMap already contains the key that shall be inserted.
+    KeyAlreadyExists,
+    /// The HashMap is full and cannot hold an additional key-value pair.
+    IsFull,
+}
+
+/// A [`Hasher`] that implements the 64-bit FNV-1a algorithm. In contrast to the
+/// randomly seeded hasher of the standard library, it produces for the same input always the
+/// same output, in every process. This is required for hash-based containers that are shared
+/// between processes.
+#[derive(Debug, Clone, Copy)]
+pub struct DeterministicHasher {
+    state: u64,
+}
+
+impl DeterministicHasher {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    /// Creates a new [`DeterministicHasher`].
+    pub const fn new() -> Self {
+        Self {
+            state: Self::OFFSET_BASIS,
+        }
+    }
+}
+
+impl Default for DeterministicHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hasher for DeterministicHasher {
+    fn finish(&self) -> u64 {
+        self.state
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= *byte as u64;
+            self.state = self.state.wrapping_mul(Self::PRIME);
+        }
+    }
+}
+
+#[repr(C)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+}
+
+/// A runtime fixed-size, non-shared memory compatible [`HashMap`]. The [`HashMap`]s memory
+/// resides in the heap.
+pub type HashMap<K, V> = MetaHashMap<K, V, GenericOwningPointer>;
+
+/// A runtime fixed-size, shared-memory compatible [`RelocatableHashMap`].
+pub type RelocatableHashMap<K, V> = MetaHashMap<K, V, GenericRelocatablePointer>;
+
+#[doc(hidden)]
+/// The iterator of a [`HashMap`], [`RelocatableHashMap`] or [`FixedSizeHashMap`].
+pub struct Iter<'map, K: Eq + Hash, V, Ptr: GenericPointer> {
+    map: &'map MetaHashMap<K, V, Ptr>,
+    index: usize,
+}
+
+impl<'map, K: Eq + Hash, V, Ptr: GenericPointer> Iterator for Iter<'map, K, V, Ptr> {
+    type Item = (&'map K, &'map V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.map.entries.len() {
+            let index = self.index;
+            self.index += 1;
+            if let Some(entry) = &self.map.entries[index] {
+                return Some((&entry.key, &entry.value));
+            }
+        }
+
+        None
+    }
+}
+
+#[doc(hidden)]
+#[repr(C)]
+pub struct MetaHashMap<K: Eq + Hash, V, Ptr: GenericPointer> {
+    buckets: MetaVec<usize, Ptr>,
+    next: MetaVec<usize, Ptr>,
+    entries: MetaVec<Option<Entry<K, V>>, Ptr>,
+    free_list_head: usize,
+    len: usize,
+    is_initialized: AtomicBool,
+}
+
+unsafe impl<K: Eq + Hash + Send, V: Send, Ptr: GenericPointer> Send for MetaHashMap<K, V, Ptr> {}
+
+impl<K: Eq + Hash + Debug, V: Debug, Ptr: GenericPointer> Debug for MetaHashMap<K, V, Ptr> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "MetaHashMap<{}, {}, {}> {{ len: {}, capacity: {}, is_initialized: {} }}",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            core::any::type_name::<Ptr>(),
+            self.len,
+            self.capacity_impl(),
+            self.is_initialized
+                .load(core::sync::atomic::Ordering::Relaxed),
+        )
+    }
+}
+
+impl<K: Eq + Hash, V, Ptr: GenericPointer> MetaHashMap<K, V, Ptr> {
+    #[inline(always)]
+    fn verify_init(&self, source: &str) {
+        debug_assert!(
+            self.is_initialized
+                .load(core::sync::atomic::Ordering::Relaxed),
+            "From: MetaHashMap<{}, {}>::{}, Undefined behavior - the object was not initialized with 'init' before.",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            source
+        );
+    }
+
+    pub(crate) unsafe fn initialize_data_structures(&mut self) {
+        let capacity = self.buckets.capacity();
+        for n in 0..capacity {
+            unsafe { self.buckets.push_impl(INVALID) };
+            unsafe { self.entries.push_impl(None) };
+            unsafe { self.next.push_impl(n + 1) };
+        }
+
+        if capacity > 0 {
+            self.next[capacity - 1] = INVALID;
+            self.free_list_head = 0;
+        } else {
+            self.free_list_head = INVALID;
+        }
+    }
+
+    fn bucket_of(&self, key: &K) -> usize {
+        let mut hasher = DeterministicHasher::new();
+        key.hash(&mut hasher);
+        (hasher.finish() % self.buckets.len() as u64) as usize
+    }
+
+    fn find(&self, key: &K) -> Option<usize> {
+        if self.buckets.is_empty() {
+            return None;
+        }
+
+        let mut index = self.buckets[self.bucket_of(key)];
+        while index != INVALID {
+            match &self.entries[index] {
+                Some(entry) if entry.key == *key => return Some(index),
+                _ => index = self.next[index],
+            }
+        }
+
+        None
+    }
+
+    pub(crate) unsafe fn insert_impl(&mut self, key: K, value: V) -> Result<(), HashMapError> {
+        self.verify_init("insert()");
+
+        let msg = "Unable to insert key-value pair into HashMap";
+        let origin = "MetaHashMap::insert_impl()";
+
+        if self.find(&key).is_some() {
+            fail!(from origin, with HashMapError::KeyAlreadyExists, "{msg} since the passed key already exists.");
+        }
+
+        if self.free_list_head == INVALID {
+            fail!(from origin, with HashMapError::IsFull, "{msg} since the HashMap is full.");
+        }
+
+        let index = self.free_list_head;
+        self.free_list_head = self.next[index];
+
+        let bucket = self.bucket_of(&key);
+        self.next[index] = self.buckets[bucket];
+        self.buckets[bucket] = index;
+        self.entries[index] = Some(Entry { key, value });
+        self.len += 1;
+
+        Ok(())
+    }
+
+    pub(crate) unsafe fn get_ref_impl(&self, key: &K) -> Option<&V> {
+        self.verify_init("get_ref()");
+
+        self.find(key)
+            .and_then(|index| self.entries[index].as_ref())
+            .map(|entry| &entry.value)
+    }
+
+    pub(crate) unsafe fn get_mut_ref_impl(&mut self, key: &K) -> Option<&mut V> {
+        self.verify_init("get_mut_ref()");
+
+        let index = self.find(key)?;
+        self.entries[index].as_mut().map(|entry| &mut entry.value)
+    }
+
+    pub(crate) unsafe fn remove_impl(&mut self, key: &K) -> Option<V> {
+        self.verify_init("remove()");
+
+        if self.buckets.is_empty() {
+            return None;
+        }
+
+        let bucket = self.bucket_of(key);
+        let mut previous = INVALID;
+        let mut index = self.buckets[bucket];
+        while index != INVALID {
+            if matches!(&self.entries[index], Some(entry) if entry.key == *key) {
+                break;
+            }
+            previous = index;
+            index = self.next[index];
+        }
+
+        if index == INVALID {
+            return None;
+        }
+
+        if previous == INVALID {
+            self.buckets[bucket] = self.next[index];
+        } else {
+            self.next[previous] = self.next[index];
+        }
+
+        self.next[index] = self.free_list_head;
+        self.free_list_head = index;
+        self.len -= 1;
+
+        self.entries[index].take().map(|entry| entry.value)
+    }
+
+    pub(crate) unsafe fn clear_impl(&mut self) {
+        self.verify_init("clear()");
+
+        let capacity = self.buckets.len();
+        for n in 0..capacity {
+            self.buckets[n] = INVALID;
+            self.entries[n] = None;
+            self.next[n] = n + 1;
+        }
+
+        if capacity > 0 {
+            self.next[capacity - 1] = INVALID;
+            self.free_list_head = 0;
+        }
+        self.len = 0;
+    }
+
+    pub(crate) unsafe fn iter_impl(&self) -> Iter<'_, K, V, Ptr> {
+        self.verify_init("iter()");
+
+        Iter {
+            map: self,
+            index: 0,
+        }
+    }
+
+    pub(crate) unsafe fn list_keys_impl<F: FnMut(&K) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        for (key, _) in unsafe { self.iter_impl() } {
+            if callback(key) == CallbackProgression::Stop {
+                break;
+            }
+        }
+    }
+
+    pub(crate) fn len_impl(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn capacity_impl(&self) -> usize {
+        self.buckets.capacity()
+    }
+
+    pub(crate) fn is_empty_impl(&self) -> bool {
+        self.len == 0
+    }
+
+    pub(crate) fn is_full_impl(&self) -> bool {
+        self.len == self.capacity_impl()
+    }
+}
+
+impl<K: Eq + Hash, V> HashMap<K, V> {
+    /// Creates a new runtime-fixed size [`HashMap`] on the heap with the given capacity.
+    pub fn new(capacity: usize) -> Self {
+        let mut new_self = Self {
+            buckets: MetaVec::new(capacity),
+            next: MetaVec::new(capacity),
+            entries: MetaVec::new(capacity),
+            free_list_head: INVALID,
+            len: 0,
+            is_initialized: AtomicBool::new(true),
+        };
+        unsafe { new_self.initialize_data_structures() };
+        new_self
+    }
+
+    /// Inserts a new key-value pair into the [`HashMap`]. On success, the method returns [`Ok`],
+    /// otherwise a [`HashMapError`] describing the failure.
+    pub fn insert(&mut self, key: K, value: V) -> Result<(), HashMapError> {
+        unsafe { self.insert_impl(key, value) }
+    }
+
+    /// Returns a reference to the value corresponding to the given key. If there is no such
+    /// key, [`None`] is returned.
+    pub fn get_ref(&self, key: &K) -> Option<&V> {
+        unsafe { self.get_ref_impl(key) }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the given key. If there is
+    /// no such key, [`None`] is returned.
+    pub fn get_mut_ref(&mut self, key: &K) -> Option<&mut V> {
+        unsafe { self.get_mut_ref_impl(key) }
+    }
+
+    /// Removes a key from the [`HashMap`], returning the Some(value) at the key if the key
+    /// was previously in the map or [`None`] otherwise.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        unsafe { self.remove_impl(key) }
+    }
+
+    /// Removes all key-value pairs from the [`HashMap`].
+    pub fn clear(&mut self) {
+        unsafe { self.clear_impl() }
+    }
+
+    /// Returns true if the [`HashMap`] contains the given key, otherwise false.
+    pub fn contains(&self, key: &K) -> bool {
+        unsafe { self.get_ref_impl(key).is_some() }
+    }
+
+    /// Returns an [`Iterator`] over all key-value pairs. The order is unspecified.
+    pub fn iter(&self) -> Iter<'_, K, V, GenericOwningPointer> {
+        unsafe { self.iter_impl() }
+    }
+
+    /// Iterates over all keys of the map and calls the provided callback.
+    pub fn list_keys<F: FnMut(&K) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.list_keys_impl(callback) };
+    }
+
+    /// Returns true if the [`HashMap`] is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.is_empty_impl()
+    }
+
+    /// Returns true if the [`HashMap`] is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.is_full_impl()
+    }
+
+    /// Returns the number of stored key-value pairs.
+    pub fn len(&self) -> usize {
+        self.len_impl()
+    }
+
+    /// Returns the capacity of the [`HashMap`].
+    pub fn capacity(&self) -> usize {
+        self.capacity_impl()
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> HashMap<K, V> {
+    /// Returns a copy of the value corresponding to the given key. If there is no such key,
+    /// [`None`] is returned.
+    pub fn get(&self, key: &K) -> Option<V> {
+        unsafe { self.get_ref_impl(key).cloned() }
+    }
+}
+
+impl<K: Eq + Hash, V> RelocatableContainer for RelocatableHashMap<K, V> {
+    unsafe fn new_uninit(capacity: usize) -> Self {
+        Self {
+            buckets: unsafe { RelocatableVec::new_uninit(capacity) },
+            next: unsafe { RelocatableVec::new_uninit(capacity) },
+            entries: unsafe { RelocatableVec::new_uninit(capacity) },
+            free_list_head: INVALID,
+            len: 0,
+            is_initialized: AtomicBool::new(false),
+        }
+    }
+
+    unsafe fn init<Allocator: iceoryx2_bb_elementary_traits::allocator::BaseAllocator>(
+        &mut self,
+        allocator: &Allocator,
+    ) -> Result<(), iceoryx2_bb_elementary_traits::allocator::AllocationError> {
+        if self
+            .is_initialized
+            .load(core::sync::atomic::Ordering::Relaxed)
+        {
+            fatal_panic!(from "RelocatableHashMap::init()", "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+        }
+        let msg = "Unable to initialize RelocatableHashMap";
+        fail!(from "RelocatableHashMap::init()",
+                  when unsafe { self.buckets.init(allocator) },
+                  "{msg} since the underlying buckets vector could not be initialized.");
+        fail!(from "RelocatableHashMap::init()",
+                  when unsafe { self.next.init(allocator) },
+                  "{msg} since the underlying next vector could not be initialized.");
+        fail!(from "RelocatableHashMap::init()",
+                  when unsafe { self.entries.init(allocator) },
+                  "{msg} since the underlying entries vector could not be initialized.");
+
+        unsafe { self.initialize_data_structures() };
+        self.is_initialized
+            .store(true, core::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn memory_size(capacity: usize) -> usize {
+        Self::const_memory_size(capacity)
+    }
+}
+
+unsafe impl<K: Eq + Hash + ZeroCopySend, V: ZeroCopySend> ZeroCopySend
+    for RelocatableHashMap<K, V>
+{
+}
+
+impl<K: Eq + Hash, V> RelocatableHashMap<K, V> {
+    /// Returns how much memory the [`RelocatableHashMap`] will allocate from the allocator
+    /// in [`RelocatableHashMap::init()`].
+    pub const fn const_memory_size(capacity: usize) -> usize {
+        RelocatableVec::<usize>::const_memory_size(capacity)
+            + RelocatableVec::<usize>::const_memory_size(capacity)
+            + RelocatableVec::<Option<Entry<K, V>>>::const_memory_size(capacity)
+    }
+
+    /// Inserts a new key-value pair into the map. On success, the method returns [`Ok`],
+    /// otherwise a [`HashMapError`] describing the failure.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn insert(&mut self, key: K, value: V) -> Result<(), HashMapError> {
+        unsafe { self.insert_impl(key, value) }
+    }
+
+    /// Returns a reference to the value corresponding to the given key. If there is no such
+    /// key, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn get_ref(&self, key: &K) -> Option<&V> {
+        unsafe { self.get_ref_impl(key) }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the given key. If there is
+    /// no such key, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn get_mut_ref(&mut self, key: &K) -> Option<&mut V> {
+        unsafe { self.get_mut_ref_impl(key) }
+    }
+
+    /// Removes a key from the map, returning the Some(value) at the key if the key
+    /// was previously in the map or [`None`] otherwise.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn remove(&mut self, key: &K) -> Option<V> {
+        unsafe { self.remove_impl(key) }
+    }
+
+    /// Removes all key-value pairs from the map.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn clear(&mut self) {
+        unsafe { self.clear_impl() }
+    }
+
+    /// Returns true if the map contains the given key, otherwise false.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn contains(&self, key: &K) -> bool {
+        unsafe { self.get_ref_impl(key).is_some() }
+    }
+
+    /// Returns an [`Iterator`] over all key-value pairs. The order is unspecified.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn iter(&self) -> Iter<'_, K, V, GenericRelocatablePointer> {
+        unsafe { self.iter_impl() }
+    }
+
+    /// Iterates over all keys of the map and calls the provided callback.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn list_keys<F: FnMut(&K) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.list_keys_impl(callback) };
+    }
+
+    /// Returns true if the map is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.is_empty_impl()
+    }
+
+    /// Returns true if the map is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.is_full_impl()
+    }
+
+    /// Returns the number of stored key-value pairs.
+    pub fn len(&self) -> usize {
+        self.len_impl()
+    }
+
+    /// Returns the capacity of the map.
+    pub fn capacity(&self) -> usize {
+        self.capacity_impl()
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> RelocatableHashMap<K, V> {
+    /// Returns a copy of the value corresponding to the given key. If there is no such key,
+    /// [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn get(&self, key: &K) -> Option<V> {
+        unsafe { self.get_ref_impl(key).cloned() }
+    }
+}
+
+/// A compile-time fixed-size, shared-memory compatible [`FixedSizeHashMap`].
+#[repr(C)]
+pub struct FixedSizeHashMap<K: Eq + Hash, V, const CAPACITY: usize> {
+    map: RelocatableHashMap<K, V>,
+    _buckets: MaybeUninit<[usize; CAPACITY]>,
+    _next: MaybeUninit<[usize; CAPACITY]>,
+    _entries: MaybeUninit<[Option<Entry<K, V>>; CAPACITY]>,
+}
+
+unsafe impl<K: Eq + Hash + ZeroCopySend, V: ZeroCopySend, const CAPACITY: usize> ZeroCopySend
+    for FixedSizeHashMap<K, V, CAPACITY>
+{
+}
+
+impl<K: Eq + Hash, V, const CAPACITY: usize> PlacementDefault for FixedSizeHashMap<K, V, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        unsafe {
+            let map_ptr = core::ptr::addr_of_mut!((*ptr).map);
+            map_ptr.write(RelocatableHashMap::new_uninit(CAPACITY));
+            let allocator = BumpAllocator::new((*ptr)._buckets.as_mut_ptr().cast());
+            (*ptr)
+                .map
+                .init(&allocator)
+                .expect("All required memory is preallocated.");
+        }
+    }
+}
+
+impl<K: Eq + Hash, V, const CAPACITY: usize> Default for FixedSizeHashMap<K, V, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash + Debug, V: Debug, const CAPACITY: usize> Debug
+    for FixedSizeHashMap<K, V, CAPACITY>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "FixedSizeHashMap<{}, {}, {}> {{ {:?} }}",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            CAPACITY,
+            self.map
+        )
+    }
+}
+
+impl<K: Eq + Hash, V, const CAPACITY: usize> FixedSizeHashMap<K, V, CAPACITY> {
+    /// Creates a new [`FixedSizeHashMap`]
+    pub fn new() -> Self {
+        let mut new_self = Self {
+            map: unsafe { RelocatableHashMap::new_uninit(CAPACITY) },
+            _buckets: MaybeUninit::uninit(),
+            _next: MaybeUninit::uninit(),
+            _entries: MaybeUninit::uninit(),
+        };
+        let allocator = BumpAllocator::new(new_self._buckets.as_mut_ptr().cast());
+        unsafe {
+            new_self
+                .map
+                .init(&allocator)
+                .expect("All required memory is preallocated.")
+        };
+        new_self
+    }
+
+    /// Inserts a new key-value pair into the [`FixedSizeHashMap`]. On success, the method
+    /// returns [`Ok`], otherwise a [`HashMapError`] describing the failure.
+    pub fn insert(&mut self, key: K, value: V) -> Result<(), HashMapError> {
+        unsafe { self.map.insert(key, value) }
+    }
+
+    /// Returns a reference to the value corresponding to the given key. If there is no such
+    /// key, [`None`] is returned.
+    pub fn get_ref(&self, key: &K) -> Option<&V> {
+        unsafe { self.map.get_ref(key) }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the given key. If there is
+    /// no such key, [`None`] is returned.
+    pub fn get_mut_ref(&mut self, key: &K) -> Option<&mut V> {
+        unsafe { self.map.get_mut_ref(key) }
+    }
+
+    /// Removes a key from the [`FixedSizeHashMap`], returning the Some(value) at the key
+    /// if the key was previously in the map or [`None`] otherwise.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        unsafe { self.map.remove(key) }
+    }
+
+    /// Removes all key-value pairs from the [`FixedSizeHashMap`].
+    pub fn clear(&mut self) {
+        unsafe { self.map.clear() }
+    }
+
+    /// Returns true if the [`FixedSizeHashMap`] contains the given key, otherwise false.
+    pub fn contains(&self, key: &K) -> bool {
+        unsafe { self.map.contains(key) }
+    }
+
+    /// Returns an [`Iterator`] over all key-value pairs. The order is unspecified.
+    pub fn iter(&self) -> Iter<'_, K, V, GenericRelocatablePointer> {
+        unsafe { self.map.iter() }
+    }
+
+    /// Iterates over all keys of the map and calls the provided callback.
+    pub fn list_keys<F: FnMut(&K) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.map.list_keys(callback) };
+    }
+
+    /// Returns true if the [`FixedSizeHashMap`] is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns true if the [`FixedSizeHashMap`] is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    /// Returns the number of stored key-value pairs.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns the capacity of the [`FixedSizeHashMap`].
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+}
+
+impl<K: Eq + Hash, V: Clone, const CAPACITY: usize> FixedSizeHashMap<K, V, CAPACITY> {
+    /// Returns a copy of the value corresponding to the given key. If there is no such key,
+    /// [`None`] is returned.
+    pub fn get(&self, key: &K) -> Option<V> {
+        unsafe { self.map.get(key) }
+    }
+}
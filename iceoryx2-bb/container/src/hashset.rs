@@ -0,0 +1,354 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A HashSet is a data structure to store unique values with an average lookup time of O(1).
+//! It is build on top of the [`HashMap`](crate::hashmap) and comes in the same variations.
+//!
+//!  * [`FixedSizeHashSet`](crate::hashset::FixedSizeHashSet), compile-time fixed-size hashset
+//!    that is self-contained and shared-memory compatible.
+//!  * [`RelocatableHashSet`](crate::hashset::RelocatableHashSet), run-time fixed-size hashset
+//!    that is shared-memory compatible.
+//!  * [`HashSet`](crate::hashset::HashSet), run-time fixed-size hashset that is not
+//!    shared-memory compatible since the memory resides in the heap.
+//!
+//! # User Examples
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_container::hashset::FixedSizeHashSet;
+//!
+//! const CAPACITY: usize = 100;
+//! let mut set = FixedSizeHashSet::<u64, CAPACITY>::new();
+//! assert_eq!(set.insert(23).is_ok(), true);
+//! assert_eq!(set.contains(&23), true);
+//! ```
+
+use core::fmt::Debug;
+use core::hash::Hash;
+use iceoryx2_bb_elementary::relocatable_ptr::GenericRelocatablePointer;
+use iceoryx2_bb_elementary_traits::generic_pointer::GenericPointer;
+use iceoryx2_bb_elementary_traits::owning_pointer::GenericOwningPointer;
+pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::{
+    placement_default::PlacementDefault, zero_copy_send::ZeroCopySend,
+};
+use iceoryx2_log::fail;
+
+use crate::hashmap::{FixedSizeHashMap, HashMapError, MetaHashMap, RelocatableHashMap};
+
+/// Failures caused by insert()
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum HashSetError {
+    /// The HashSet already contains the value that shall be inserted.
+    ValueAlreadyExists,
+    /// The HashSet is full and cannot hold an additional value.
+    IsFull,
+}
+
+impl From<HashMapError> for HashSetError {
+    fn from(value: HashMapError) -> Self {
+        match value {
+            HashMapError::KeyAlreadyExists => HashSetError::ValueAlreadyExists,
+            HashMapError::IsFull => HashSetError::IsFull,
+        }
+    }
+}
+
+/// A runtime fixed-size, non-shared memory compatible [`HashSet`]. The [`HashSet`]s memory
+/// resides in the heap.
+pub type HashSet<T> = MetaHashSet<T, GenericOwningPointer>;
+
+/// A runtime fixed-size, shared-memory compatible [`RelocatableHashSet`].
+pub type RelocatableHashSet<T> = MetaHashSet<T, GenericRelocatablePointer>;
+
+#[doc(hidden)]
+#[repr(C)]
+pub struct MetaHashSet<T: Eq + Hash, Ptr: GenericPointer> {
+    map: MetaHashMap<T, (), Ptr>,
+}
+
+impl<T: Eq + Hash + Debug, Ptr: GenericPointer> Debug for MetaHashSet<T, Ptr> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "MetaHashSet<{}, {}> {{ {:?} }}",
+            core::any::type_name::<T>(),
+            core::any::type_name::<Ptr>(),
+            self.map
+        )
+    }
+}
+
+impl<T: Eq + Hash, Ptr: GenericPointer> MetaHashSet<T, Ptr> {
+    pub(crate) unsafe fn insert_impl(&mut self, value: T) -> Result<(), HashSetError> {
+        unsafe { self.map.insert_impl(value, ()) }.map_err(HashSetError::from)
+    }
+
+    pub(crate) unsafe fn iter_impl(&self) -> impl Iterator<Item = &T> {
+        unsafe { self.map.iter_impl() }.map(|(value, _)| value)
+    }
+}
+
+impl<T: Eq + Hash> HashSet<T> {
+    /// Creates a new runtime-fixed size [`HashSet`] on the heap with the given capacity.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            map: MetaHashMap::new(capacity),
+        }
+    }
+
+    /// Inserts a new value into the [`HashSet`]. On success, the method returns [`Ok`],
+    /// otherwise a [`HashSetError`] describing the failure.
+    pub fn insert(&mut self, value: T) -> Result<(), HashSetError> {
+        unsafe { self.insert_impl(value) }
+    }
+
+    /// Removes a value from the [`HashSet`]. Returns true if the value was contained,
+    /// otherwise false.
+    pub fn remove(&mut self, value: &T) -> bool {
+        self.map.remove(value).is_some()
+    }
+
+    /// Removes all values from the [`HashSet`].
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// Returns true if the [`HashSet`] contains the given value, otherwise false.
+    pub fn contains(&self, value: &T) -> bool {
+        self.map.contains(value)
+    }
+
+    /// Returns an [`Iterator`] over all values. The order is unspecified.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        unsafe { self.iter_impl() }
+    }
+
+    /// Returns true if the [`HashSet`] is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns true if the [`HashSet`] is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    /// Returns the number of stored values.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns the capacity of the [`HashSet`].
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+}
+
+impl<T: Eq + Hash> RelocatableContainer for RelocatableHashSet<T> {
+    unsafe fn new_uninit(capacity: usize) -> Self {
+        Self {
+            map: unsafe { RelocatableHashMap::new_uninit(capacity) },
+        }
+    }
+
+    unsafe fn init<Allocator: iceoryx2_bb_elementary_traits::allocator::BaseAllocator>(
+        &mut self,
+        allocator: &Allocator,
+    ) -> Result<(), iceoryx2_bb_elementary_traits::allocator::AllocationError> {
+        fail!(from "RelocatableHashSet::init()",
+              when unsafe { self.map.init(allocator) },
+              "Unable to initialize RelocatableHashSet since the underlying RelocatableHashMap could not be initialized.");
+        Ok(())
+    }
+
+    fn memory_size(capacity: usize) -> usize {
+        Self::const_memory_size(capacity)
+    }
+}
+
+unsafe impl<T: Eq + Hash + ZeroCopySend> ZeroCopySend for RelocatableHashSet<T> {}
+
+impl<T: Eq + Hash> RelocatableHashSet<T> {
+    /// Returns how much memory the [`RelocatableHashSet`] will allocate from the allocator
+    /// in [`RelocatableHashSet::init()`].
+    pub const fn const_memory_size(capacity: usize) -> usize {
+        RelocatableHashMap::<T, ()>::const_memory_size(capacity)
+    }
+
+    /// Inserts a new value into the set. On success, the method returns [`Ok`],
+    /// otherwise a [`HashSetError`] describing the failure.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashSet::init()`] must be called once before
+    ///
+    pub unsafe fn insert(&mut self, value: T) -> Result<(), HashSetError> {
+        unsafe { self.insert_impl(value) }
+    }
+
+    /// Removes a value from the set. Returns true if the value was contained,
+    /// otherwise false.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashSet::init()`] must be called once before
+    ///
+    pub unsafe fn remove(&mut self, value: &T) -> bool {
+        unsafe { self.map.remove(value) }.is_some()
+    }
+
+    /// Removes all values from the set.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashSet::init()`] must be called once before
+    ///
+    pub unsafe fn clear(&mut self) {
+        unsafe { self.map.clear() }
+    }
+
+    /// Returns true if the set contains the given value, otherwise false.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashSet::init()`] must be called once before
+    ///
+    pub unsafe fn contains(&self, value: &T) -> bool {
+        unsafe { self.map.contains(value) }
+    }
+
+    /// Returns an [`Iterator`] over all values. The order is unspecified.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashSet::init()`] must be called once before
+    ///
+    pub unsafe fn iter(&self) -> impl Iterator<Item = &T> {
+        unsafe { self.iter_impl() }
+    }
+
+    /// Returns true if the set is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns true if the set is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    /// Returns the number of stored values.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns the capacity of the set.
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+}
+
+/// A compile-time fixed-size, shared-memory compatible [`FixedSizeHashSet`].
+#[repr(C)]
+pub struct FixedSizeHashSet<T: Eq + Hash, const CAPACITY: usize> {
+    map: FixedSizeHashMap<T, (), CAPACITY>,
+}
+
+unsafe impl<T: Eq + Hash + ZeroCopySend, const CAPACITY: usize> ZeroCopySend
+    for FixedSizeHashSet<T, CAPACITY>
+{
+}
+
+impl<T: Eq + Hash, const CAPACITY: usize> PlacementDefault for FixedSizeHashSet<T, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        unsafe {
+            let map_ptr = core::ptr::addr_of_mut!((*ptr).map);
+            FixedSizeHashMap::placement_default(map_ptr);
+        }
+    }
+}
+
+impl<T: Eq + Hash, const CAPACITY: usize> Default for FixedSizeHashSet<T, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Eq + Hash + Debug, const CAPACITY: usize> Debug for FixedSizeHashSet<T, CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "FixedSizeHashSet<{}, {}> {{ {:?} }}",
+            core::any::type_name::<T>(),
+            CAPACITY,
+            self.map
+        )
+    }
+}
+
+impl<T: Eq + Hash, const CAPACITY: usize> FixedSizeHashSet<T, CAPACITY> {
+    /// Creates a new [`FixedSizeHashSet`]
+    pub fn new() -> Self {
+        Self {
+            map: FixedSizeHashMap::new(),
+        }
+    }
+
+    /// Inserts a new value into the [`FixedSizeHashSet`]. On success, the method returns
+    /// [`Ok`], otherwise a [`HashSetError`] describing the failure.
+    pub fn insert(&mut self, value: T) -> Result<(), HashSetError> {
+        self.map.insert(value, ()).map_err(HashSetError::from)
+    }
+
+    /// Removes a value from the [`FixedSizeHashSet`]. Returns true if the value was
+    /// contained, otherwise false.
+    pub fn remove(&mut self, value: &T) -> bool {
+        self.map.remove(value).is_some()
+    }
+
+    /// Removes all values from the [`FixedSizeHashSet`].
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// Returns true if the [`FixedSizeHashSet`] contains the given value, otherwise false.
+    pub fn contains(&self, value: &T) -> bool {
+        self.map.contains(value)
+    }
+
+    /// Returns an [`Iterator`] over all values. The order is unspecified.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.map.iter().map(|(value, _)| value)
+    }
+
+    /// Returns true if the [`FixedSizeHashSet`] is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns true if the [`FixedSizeHashSet`] is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    /// Returns the number of stored values.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns the capacity of the [`FixedSizeHashSet`].
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+}
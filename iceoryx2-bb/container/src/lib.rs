@@ -106,6 +106,14 @@ pub mod semantic_string;
 
 /// A container to store key-value pairs.
 pub mod flatmap;
+/// A container to store key-value pairs with hash-based lookup.
+pub mod hashmap;
+/// A container to store unique values with hash-based lookup.
+pub mod hashset;
+/// A doubly-linked list with stable handles to its elements.
+pub mod list;
+/// A container to store key-value pairs sorted by their keys.
+pub mod ordered_map;
 
 /// A trait that defines the interface of a string and several string variants.
 pub mod string;
@@ -0,0 +1,902 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A doubly-linked list similar to [`alloc::collections::LinkedList`]. The elements are stored
+//! in a preallocated pool and linked via indices, therefore the list does not contain any pointers
+//! and can be relocated. Every inserted element is identified by a [`ListHandle`] that stays valid
+//! until the element is removed, so that elements can be removed or inserted in the middle of the
+//! list in O(1). Every slot carries a generation counter, so that the [`ListHandle`] of a removed
+//! element does not identify a new element that reuses its slot.
+//!
+//!  * [`FixedSizeList`](crate::list::FixedSizeList), compile-time fixed-size list that is
+//!    self-contained and shared-memory compatible.
+//!  * [`RelocatableList`](crate::list::RelocatableList), run-time fixed-size list that is
+//!    shared-memory compatible.
+//!  * [`List`](crate::list::List), run-time fixed-size list that is not shared-memory
+//!    compatible since the memory resides in the heap.
+//!
+//! # User Examples
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_container::list::FixedSizeList;
+//!
+//! const CAPACITY: usize = 100;
+//! let mut list = FixedSizeList::<u64, CAPACITY>::new();
+//! let handle = list.push_back(23).unwrap();
+//! list.push_front(12);
+//! list.insert_after(handle, 42);
+//!
+//! assert_eq!(list.iter().copied().collect::<Vec<_>>(), [12, 23, 42]);
+//! assert_eq!(list.remove(handle), Some(23));
+//! ```
+
+use core::fmt::Debug;
+use core::mem::MaybeUninit;
+use iceoryx2_bb_concurrency::atomic::AtomicBool;
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary::relocatable_ptr::GenericRelocatablePointer;
+use iceoryx2_bb_elementary_traits::generic_pointer::GenericPointer;
+use iceoryx2_bb_elementary_traits::owning_pointer::GenericOwningPointer;
+pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::{
+    placement_default::PlacementDefault, zero_copy_send::ZeroCopySend,
+};
+use iceoryx2_log::{fail, fatal_panic};
+
+use crate::vec::{MetaVec, RelocatableVec};
+
+const INVALID: usize = usize::MAX;
+
+/// Identifies an element stored in a [`List`], [`RelocatableList`] or [`FixedSizeList`]. It
+/// stays valid until the element is removed.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct ListHandle {
+    index: usize,
+    generation: usize,
+}
+
+impl ListHandle {
+    /// Returns the underlying index of the handle.
+    pub fn value(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the generation of the slot at the time the element was inserted.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, ZeroCopySend)]
+struct Link {
+    previous: usize,
+    next: usize,
+    generation: usize,
+}
+
+/// A runtime fixed-size, non-shared memory compatible [`List`]. The [`List`]s memory resides
+/// in the heap.
+pub type List<T> = MetaList<T, GenericOwningPointer>;
+
+/// A runtime fixed-size, shared-memory compatible [`RelocatableList`].
+pub type RelocatableList<T> = MetaList<T, GenericRelocatablePointer>;
+
+#[doc(hidden)]
+/// The iterator of a [`List`], [`RelocatableList`] or [`FixedSizeList`]. It iterates from the
+/// front to the back of the list.
+pub struct Iter<'list, T, Ptr: GenericPointer> {
+    list: &'list MetaList<T, Ptr>,
+    index: usize,
+}
+
+impl<'list, T, Ptr: GenericPointer> Iterator for Iter<'list, T, Ptr> {
+    type Item = &'list T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == INVALID {
+            return None;
+        }
+
+        let index = self.index;
+        self.index = self.list.links[index].next;
+        self.list.data[index].as_ref()
+    }
+}
+
+#[doc(hidden)]
+#[repr(C)]
+pub struct MetaList<T, Ptr: GenericPointer> {
+    links: MetaVec<Link, Ptr>,
+    data: MetaVec<Option<T>, Ptr>,
+    head: usize,
+    tail: usize,
+    free_list_head: usize,
+    len: usize,
+    is_initialized: AtomicBool,
+}
+
+unsafe impl<T: Send, Ptr: GenericPointer> Send for MetaList<T, Ptr> {}
+
+impl<T: Debug, Ptr: GenericPointer> Debug for MetaList<T, Ptr> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "MetaList<{}, {}> {{ len: {}, capacity: {}, is_initialized: {} }}",
+            core::any::type_name::<T>(),
+            core::any::type_name::<Ptr>(),
+            self.len,
+            self.capacity_impl(),
+            self.is_initialized
+                .load(core::sync::atomic::Ordering::Relaxed),
+        )
+    }
+}
+
+impl<T, Ptr: GenericPointer> MetaList<T, Ptr> {
+    #[inline(always)]
+    fn verify_init(&self, source: &str) {
+        debug_assert!(
+            self.is_initialized
+                .load(core::sync::atomic::Ordering::Relaxed),
+            "From: MetaList<{}>::{}, Undefined behavior - the object was not initialized with 'init' before.",
+            core::any::type_name::<T>(),
+            source
+        );
+    }
+
+    pub(crate) unsafe fn initialize_data_structures(&mut self) {
+        let capacity = self.links.capacity();
+        for n in 0..capacity {
+            unsafe {
+                self.links.push_impl(Link {
+                    previous: INVALID,
+                    next: n + 1,
+                    generation: 0,
+                })
+            };
+            unsafe { self.data.push_impl(None) };
+        }
+
+        self.reset_links();
+    }
+
+    fn reset_links(&mut self) {
+        let capacity = self.links.len();
+        for n in 0..capacity {
+            self.links[n].previous = INVALID;
+            self.links[n].next = n + 1;
+        }
+
+        if capacity > 0 {
+            self.links[capacity - 1].next = INVALID;
+            self.free_list_head = 0;
+        } else {
+            self.free_list_head = INVALID;
+        }
+
+        self.head = INVALID;
+        self.tail = INVALID;
+        self.len = 0;
+    }
+
+    fn is_valid_handle(&self, handle: ListHandle) -> bool {
+        handle.index < self.data.len()
+            && self.links[handle.index].generation == handle.generation
+            && self.data[handle.index].is_some()
+    }
+
+    fn handle_of(&self, index: usize) -> ListHandle {
+        ListHandle {
+            index,
+            generation: self.links[index].generation,
+        }
+    }
+
+    fn acquire_free_index(&mut self, value: T) -> Option<usize> {
+        if self.free_list_head == INVALID {
+            return None;
+        }
+
+        let index = self.free_list_head;
+        self.free_list_head = self.links[index].next;
+        self.data[index] = Some(value);
+        self.len += 1;
+        Some(index)
+    }
+
+    fn link_between(&mut self, index: usize, previous: usize, next: usize) {
+        self.links[index].previous = previous;
+        self.links[index].next = next;
+
+        if previous == INVALID {
+            self.head = index;
+        } else {
+            self.links[previous].next = index;
+        }
+
+        if next == INVALID {
+            self.tail = index;
+        } else {
+            self.links[next].previous = index;
+        }
+    }
+
+    pub(crate) unsafe fn push_front_impl(&mut self, value: T) -> Option<ListHandle> {
+        self.verify_init("push_front()");
+
+        let index = self.acquire_free_index(value)?;
+        self.link_between(index, INVALID, self.head);
+        Some(self.handle_of(index))
+    }
+
+    pub(crate) unsafe fn push_back_impl(&mut self, value: T) -> Option<ListHandle> {
+        self.verify_init("push_back()");
+
+        let index = self.acquire_free_index(value)?;
+        self.link_between(index, self.tail, INVALID);
+        Some(self.handle_of(index))
+    }
+
+    pub(crate) unsafe fn insert_before_impl(
+        &mut self,
+        handle: ListHandle,
+        value: T,
+    ) -> Option<ListHandle> {
+        self.verify_init("insert_before()");
+
+        if !self.is_valid_handle(handle) {
+            return None;
+        }
+
+        let previous = self.links[handle.index].previous;
+        let index = self.acquire_free_index(value)?;
+        self.link_between(index, previous, handle.index);
+        Some(self.handle_of(index))
+    }
+
+    pub(crate) unsafe fn insert_after_impl(
+        &mut self,
+        handle: ListHandle,
+        value: T,
+    ) -> Option<ListHandle> {
+        self.verify_init("insert_after()");
+
+        if !self.is_valid_handle(handle) {
+            return None;
+        }
+
+        let next = self.links[handle.index].next;
+        let index = self.acquire_free_index(value)?;
+        self.link_between(index, handle.index, next);
+        Some(self.handle_of(index))
+    }
+
+    pub(crate) unsafe fn remove_impl(&mut self, handle: ListHandle) -> Option<T> {
+        self.verify_init("remove()");
+
+        if !self.is_valid_handle(handle) {
+            return None;
+        }
+
+        let index = handle.index;
+        let Link { previous, next, .. } = self.links[index];
+
+        if previous == INVALID {
+            self.head = next;
+        } else {
+            self.links[previous].next = next;
+        }
+
+        if next == INVALID {
+            self.tail = previous;
+        } else {
+            self.links[next].previous = previous;
+        }
+
+        self.links[index] = Link {
+            previous: INVALID,
+            next: self.free_list_head,
+            generation: self.links[index].generation.wrapping_add(1),
+        };
+        self.free_list_head = index;
+        self.len -= 1;
+
+        self.data[index].take()
+    }
+
+    pub(crate) unsafe fn pop_front_impl(&mut self) -> Option<T> {
+        self.verify_init("pop_front()");
+
+        match self.head {
+            INVALID => None,
+            index => unsafe { self.remove_impl(self.handle_of(index)) },
+        }
+    }
+
+    pub(crate) unsafe fn pop_back_impl(&mut self) -> Option<T> {
+        self.verify_init("pop_back()");
+
+        match self.tail {
+            INVALID => None,
+            index => unsafe { self.remove_impl(self.handle_of(index)) },
+        }
+    }
+
+    pub(crate) unsafe fn get_impl(&self, handle: ListHandle) -> Option<&T> {
+        self.verify_init("get()");
+
+        if self.is_valid_handle(handle) {
+            self.data[handle.index].as_ref()
+        } else {
+            None
+        }
+    }
+
+    pub(crate) unsafe fn get_mut_impl(&mut self, handle: ListHandle) -> Option<&mut T> {
+        self.verify_init("get_mut()");
+
+        if self.is_valid_handle(handle) {
+            self.data[handle.index].as_mut()
+        } else {
+            None
+        }
+    }
+
+    pub(crate) unsafe fn front_handle_impl(&self) -> Option<ListHandle> {
+        self.verify_init("front_handle()");
+
+        match self.head {
+            INVALID => None,
+            index => Some(self.handle_of(index)),
+        }
+    }
+
+    pub(crate) unsafe fn back_handle_impl(&self) -> Option<ListHandle> {
+        self.verify_init("back_handle()");
+
+        match self.tail {
+            INVALID => None,
+            index => Some(self.handle_of(index)),
+        }
+    }
+
+    pub(crate) unsafe fn clear_impl(&mut self) {
+        self.verify_init("clear()");
+
+        for n in 0..self.data.len() {
+            if self.data[n].take().is_some() {
+                self.links[n].generation = self.links[n].generation.wrapping_add(1);
+            }
+        }
+        self.reset_links();
+    }
+
+    pub(crate) unsafe fn iter_impl(&self) -> Iter<'_, T, Ptr> {
+        self.verify_init("iter()");
+
+        Iter {
+            list: self,
+            index: self.head,
+        }
+    }
+
+    pub(crate) fn len_impl(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn capacity_impl(&self) -> usize {
+        self.links.capacity()
+    }
+
+    pub(crate) fn is_empty_impl(&self) -> bool {
+        self.len == 0
+    }
+
+    pub(crate) fn is_full_impl(&self) -> bool {
+        self.len == self.capacity_impl()
+    }
+}
+
+impl<T> List<T> {
+    /// Creates a new runtime-fixed size [`List`] on the heap with the given capacity.
+    pub fn new(capacity: usize) -> Self {
+        let mut new_self = Self {
+            links: MetaVec::new(capacity),
+            data: MetaVec::new(capacity),
+            head: INVALID,
+            tail: INVALID,
+            free_list_head: INVALID,
+            len: 0,
+            is_initialized: AtomicBool::new(true),
+        };
+        unsafe { new_self.initialize_data_structures() };
+        new_self
+    }
+
+    /// Adds an element at the front of the [`List`] and returns its [`ListHandle`]. If the
+    /// [`List`] is full, [`None`] is returned.
+    pub fn push_front(&mut self, value: T) -> Option<ListHandle> {
+        unsafe { self.push_front_impl(value) }
+    }
+
+    /// Adds an element at the back of the [`List`] and returns its [`ListHandle`]. If the
+    /// [`List`] is full, [`None`] is returned.
+    pub fn push_back(&mut self, value: T) -> Option<ListHandle> {
+        unsafe { self.push_back_impl(value) }
+    }
+
+    /// Inserts an element in front of the element identified by `handle` and returns the
+    /// [`ListHandle`] of the new element. If the [`List`] is full or the `handle` does not
+    /// identify an element, [`None`] is returned.
+    pub fn insert_before(&mut self, handle: ListHandle, value: T) -> Option<ListHandle> {
+        unsafe { self.insert_before_impl(handle, value) }
+    }
+
+    /// Inserts an element behind the element identified by `handle` and returns the
+    /// [`ListHandle`] of the new element. If the [`List`] is full or the `handle` does not
+    /// identify an element, [`None`] is returned.
+    pub fn insert_after(&mut self, handle: ListHandle, value: T) -> Option<ListHandle> {
+        unsafe { self.insert_after_impl(handle, value) }
+    }
+
+    /// Removes the element identified by `handle` and returns it. If there is no such
+    /// element, [`None`] is returned.
+    pub fn remove(&mut self, handle: ListHandle) -> Option<T> {
+        unsafe { self.remove_impl(handle) }
+    }
+
+    /// Removes the first element of the [`List`] and returns it. If the [`List`] is empty,
+    /// [`None`] is returned.
+    pub fn pop_front(&mut self) -> Option<T> {
+        unsafe { self.pop_front_impl() }
+    }
+
+    /// Removes the last element of the [`List`] and returns it. If the [`List`] is empty,
+    /// [`None`] is returned.
+    pub fn pop_back(&mut self) -> Option<T> {
+        unsafe { self.pop_back_impl() }
+    }
+
+    /// Returns a reference to the element identified by `handle`. If there is no such
+    /// element, [`None`] is returned.
+    pub fn get(&self, handle: ListHandle) -> Option<&T> {
+        unsafe { self.get_impl(handle) }
+    }
+
+    /// Returns a mutable reference to the element identified by `handle`. If there is no such
+    /// element, [`None`] is returned.
+    pub fn get_mut(&mut self, handle: ListHandle) -> Option<&mut T> {
+        unsafe { self.get_mut_impl(handle) }
+    }
+
+    /// Returns the [`ListHandle`] of the first element. If the [`List`] is empty, [`None`] is
+    /// returned.
+    pub fn front_handle(&self) -> Option<ListHandle> {
+        unsafe { self.front_handle_impl() }
+    }
+
+    /// Returns the [`ListHandle`] of the last element. If the [`List`] is empty, [`None`] is
+    /// returned.
+    pub fn back_handle(&self) -> Option<ListHandle> {
+        unsafe { self.back_handle_impl() }
+    }
+
+    /// Removes all elements from the [`List`].
+    pub fn clear(&mut self) {
+        unsafe { self.clear_impl() }
+    }
+
+    /// Returns an [`Iterator`] that iterates from the front to the back of the [`List`].
+    pub fn iter(&self) -> Iter<'_, T, GenericOwningPointer> {
+        unsafe { self.iter_impl() }
+    }
+
+    /// Returns true if the [`List`] is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.is_empty_impl()
+    }
+
+    /// Returns true if the [`List`] is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.is_full_impl()
+    }
+
+    /// Returns the number of stored elements.
+    pub fn len(&self) -> usize {
+        self.len_impl()
+    }
+
+    /// Returns the capacity of the [`List`].
+    pub fn capacity(&self) -> usize {
+        self.capacity_impl()
+    }
+}
+
+impl<T> RelocatableContainer for RelocatableList<T> {
+    unsafe fn new_uninit(capacity: usize) -> Self {
+        Self {
+            links: unsafe { RelocatableVec::new_uninit(capacity) },
+            data: unsafe { RelocatableVec::new_uninit(capacity) },
+            head: INVALID,
+            tail: INVALID,
+            free_list_head: INVALID,
+            len: 0,
+            is_initialized: AtomicBool::new(false),
+        }
+    }
+
+    unsafe fn init<Allocator: iceoryx2_bb_elementary_traits::allocator::BaseAllocator>(
+        &mut self,
+        allocator: &Allocator,
+    ) -> Result<(), iceoryx2_bb_elementary_traits::allocator::AllocationError> {
+        if self
+            .is_initialized
+            .load(core::sync::atomic::Ordering::Relaxed)
+        {
+            fatal_panic!(from "RelocatableList::init()", "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+        }
+        let msg = "Unable to initialize RelocatableList";
+        fail!(from "RelocatableList::init()",
+                  when unsafe { self.links.init(allocator) },
+                  "{msg} since the underlying links vector could not be initialized.");
+        fail!(from "RelocatableList::init()",
+                  when unsafe { self.data.init(allocator) },
+                  "{msg} since the underlying data vector could not be initialized.");
+
+        unsafe { self.initialize_data_structures() };
+        self.is_initialized
+            .store(true, core::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn memory_size(capacity: usize) -> usize {
+        Self::const_memory_size(capacity)
+    }
+}
+
+unsafe impl<T: ZeroCopySend> ZeroCopySend for RelocatableList<T> {}
+
+impl<T> RelocatableList<T> {
+    /// Returns how much memory the [`RelocatableList`] will allocate from the allocator
+    /// in [`RelocatableList::init()`].
+    pub const fn const_memory_size(capacity: usize) -> usize {
+        RelocatableVec::<Link>::const_memory_size(capacity)
+            + RelocatableVec::<Option<T>>::const_memory_size(capacity)
+    }
+
+    /// Adds an element at the front of the list and returns its [`ListHandle`]. If the
+    /// list is full, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn push_front(&mut self, value: T) -> Option<ListHandle> {
+        unsafe { self.push_front_impl(value) }
+    }
+
+    /// Adds an element at the back of the list and returns its [`ListHandle`]. If the
+    /// list is full, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn push_back(&mut self, value: T) -> Option<ListHandle> {
+        unsafe { self.push_back_impl(value) }
+    }
+
+    /// Inserts an element in front of the element identified by `handle` and returns the
+    /// [`ListHandle`] of the new element. If the list is full or the `handle` does not
+    /// identify an element, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn insert_before(&mut self, handle: ListHandle, value: T) -> Option<ListHandle> {
+        unsafe { self.insert_before_impl(handle, value) }
+    }
+
+    /// Inserts an element behind the element identified by `handle` and returns the
+    /// [`ListHandle`] of the new element. If the list is full or the `handle` does not
+    /// identify an element, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn insert_after(&mut self, handle: ListHandle, value: T) -> Option<ListHandle> {
+        unsafe { self.insert_after_impl(handle, value) }
+    }
+
+    /// Removes the element identified by `handle` and returns it. If there is no such
+    /// element, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn remove(&mut self, handle: ListHandle) -> Option<T> {
+        unsafe { self.remove_impl(handle) }
+    }
+
+    /// Removes the first element of the list and returns it. If the list is empty,
+    /// [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn pop_front(&mut self) -> Option<T> {
+        unsafe { self.pop_front_impl() }
+    }
+
+    /// Removes the last element of the list and returns it. If the list is empty,
+    /// [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn pop_back(&mut self) -> Option<T> {
+        unsafe { self.pop_back_impl() }
+    }
+
+    /// Returns a reference to the element identified by `handle`. If there is no such
+    /// element, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn get(&self, handle: ListHandle) -> Option<&T> {
+        unsafe { self.get_impl(handle) }
+    }
+
+    /// Returns a mutable reference to the element identified by `handle`. If there is no such
+    /// element, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn get_mut(&mut self, handle: ListHandle) -> Option<&mut T> {
+        unsafe { self.get_mut_impl(handle) }
+    }
+
+    /// Returns the [`ListHandle`] of the first element. If the list is empty, [`None`] is
+    /// returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn front_handle(&self) -> Option<ListHandle> {
+        unsafe { self.front_handle_impl() }
+    }
+
+    /// Returns the [`ListHandle`] of the last element. If the list is empty, [`None`] is
+    /// returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn back_handle(&self) -> Option<ListHandle> {
+        unsafe { self.back_handle_impl() }
+    }
+
+    /// Removes all elements from the list.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn clear(&mut self) {
+        unsafe { self.clear_impl() }
+    }
+
+    /// Returns an [`Iterator`] that iterates from the front to the back of the list.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn iter(&self) -> Iter<'_, T, GenericRelocatablePointer> {
+        unsafe { self.iter_impl() }
+    }
+
+    /// Returns true if the list is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.is_empty_impl()
+    }
+
+    /// Returns true if the list is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.is_full_impl()
+    }
+
+    /// Returns the number of stored elements.
+    pub fn len(&self) -> usize {
+        self.len_impl()
+    }
+
+    /// Returns the capacity of the list.
+    pub fn capacity(&self) -> usize {
+        self.capacity_impl()
+    }
+}
+
+/// A compile-time fixed-size, shared-memory compatible [`FixedSizeList`].
+#[repr(C)]
+pub struct FixedSizeList<T, const CAPACITY: usize> {
+    list: RelocatableList<T>,
+    _links: MaybeUninit<[Link; CAPACITY]>,
+    _data: MaybeUninit<[Option<T>; CAPACITY]>,
+}
+
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeList<T, CAPACITY> {}
+
+impl<T, const CAPACITY: usize> PlacementDefault for FixedSizeList<T, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        unsafe {
+            let list_ptr = core::ptr::addr_of_mut!((*ptr).list);
+            list_ptr.write(RelocatableList::new_uninit(CAPACITY));
+            let allocator = BumpAllocator::new((*ptr)._links.as_mut_ptr().cast());
+            (*ptr)
+                .list
+                .init(&allocator)
+                .expect("All required memory is preallocated.");
+        }
+    }
+}
+
+impl<T, const CAPACITY: usize> Default for FixedSizeList<T, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug, const CAPACITY: usize> Debug for FixedSizeList<T, CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "FixedSizeList<{}, {}> {{ {:?} }}",
+            core::any::type_name::<T>(),
+            CAPACITY,
+            self.list
+        )
+    }
+}
+
+impl<T, const CAPACITY: usize> FixedSizeList<T, CAPACITY> {
+    /// Creates a new [`FixedSizeList`]
+    pub fn new() -> Self {
+        let mut new_self = Self {
+            list: unsafe { RelocatableList::new_uninit(CAPACITY) },
+            _links: MaybeUninit::uninit(),
+            _data: MaybeUninit::uninit(),
+        };
+        let allocator = BumpAllocator::new(new_self._links.as_mut_ptr().cast());
+        unsafe {
+            new_self
+                .list
+                .init(&allocator)
+                .expect("All required memory is preallocated.")
+        };
+        new_self
+    }
+
+    /// Adds an element at the front of the [`FixedSizeList`] and returns its [`ListHandle`].
+    /// If the [`FixedSizeList`] is full, [`None`] is returned.
+    pub fn push_front(&mut self, value: T) -> Option<ListHandle> {
+        unsafe { self.list.push_front(value) }
+    }
+
+    /// Adds an element at the back of the [`FixedSizeList`] and returns its [`ListHandle`].
+    /// If the [`FixedSizeList`] is full, [`None`] is returned.
+    pub fn push_back(&mut self, value: T) -> Option<ListHandle> {
+        unsafe { self.list.push_back(value) }
+    }
+
+    /// Inserts an element in front of the element identified by `handle` and returns the
+    /// [`ListHandle`] of the new element. If the [`FixedSizeList`] is full or the `handle`
+    /// does not identify an element, [`None`] is returned.
+    pub fn insert_before(&mut self, handle: ListHandle, value: T) -> Option<ListHandle> {
+        unsafe { self.list.insert_before(handle, value) }
+    }
+
+    /// Inserts an element behind the element identified by `handle` and returns the
+    /// [`ListHandle`] of the new element. If the [`FixedSizeList`] is full or the `handle`
+    /// does not identify an element, [`None`] is returned.
+    pub fn insert_after(&mut self, handle: ListHandle, value: T) -> Option<ListHandle> {
+        unsafe { self.list.insert_after(handle, value) }
+    }
+
+    /// Removes the element identified by `handle` and returns it. If there is no such
+    /// element, [`None`] is returned.
+    pub fn remove(&mut self, handle: ListHandle) -> Option<T> {
+        unsafe { self.list.remove(handle) }
+    }
+
+    /// Removes the first element of the [`FixedSizeList`] and returns it. If the
+    /// [`FixedSizeList`] is empty, [`None`] is returned.
+    pub fn pop_front(&mut self) -> Option<T> {
+        unsafe { self.list.pop_front() }
+    }
+
+    /// Removes the last element of the [`FixedSizeList`] and returns it. If the
+    /// [`FixedSizeList`] is empty, [`None`] is returned.
+    pub fn pop_back(&mut self) -> Option<T> {
+        unsafe { self.list.pop_back() }
+    }
+
+    /// Returns a reference to the element identified by `handle`. If there is no such
+    /// element, [`None`] is returned.
+    pub fn get(&self, handle: ListHandle) -> Option<&T> {
+        unsafe { self.list.get(handle) }
+    }
+
+    /// Returns a mutable reference to the element identified by `handle`. If there is no such
+    /// element, [`None`] is returned.
+    pub fn get_mut(&mut self, handle: ListHandle) -> Option<&mut T> {
+        unsafe { self.list.get_mut(handle) }
+    }
+
+    /// Returns the [`ListHandle`] of the first element. If the [`FixedSizeList`] is empty,
+    /// [`None`] is returned.
+    pub fn front_handle(&self) -> Option<ListHandle> {
+        unsafe { self.list.front_handle() }
+    }
+
+    /// Returns the [`ListHandle`] of the last element. If the [`FixedSizeList`] is empty,
+    /// [`None`] is returned.
+    pub fn back_handle(&self) -> Option<ListHandle> {
+        unsafe { self.list.back_handle() }
+    }
+
+    /// Removes all elements from the [`FixedSizeList`].
+    pub fn clear(&mut self) {
+        unsafe { self.list.clear() }
+    }
+
+    /// Returns an [`Iterator`] that iterates from the front to the back of the
+    /// [`FixedSizeList`].
+    pub fn iter(&self) -> Iter<'_, T, GenericRelocatablePointer> {
+        unsafe { self.list.iter() }
+    }
+
+    /// Returns true if the [`FixedSizeList`] is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns true if the [`FixedSizeList`] is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.list.is_full()
+    }
+
+    /// Returns the number of stored elements.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns the capacity of the [`FixedSizeList`].
+    pub fn capacity(&self) -> usize {
+        self.list.capacity()
+    }
+}
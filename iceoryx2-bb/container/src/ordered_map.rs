@@ -0,0 +1,924 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An OrderedMap is a data structure to store key-value pairs sorted by their keys, similar to
+//! [`alloc::collections::BTreeMap`]. Lookup, insertion and removal take O(log n).
+//! Multiple variations of that container are available.
+//!
+//!  * [`FixedSizeOrderedMap`](crate::ordered_map::FixedSizeOrderedMap), compile-time fixed-size
+//!    ordered map that is self-contained and shared-memory compatible.
+//!  * [`RelocatableOrderedMap`](crate::ordered_map::RelocatableOrderedMap), run-time fixed-size
+//!    ordered map that is shared-memory compatible.
+//!  * [`OrderedMap`](crate::ordered_map::OrderedMap), run-time fixed-size ordered map that is not
+//!    shared-memory compatible since the memory resides in the heap.
+//!
+//! The key-value pairs are stored in a preallocated pool and organized as an AVL tree whose
+//! nodes are linked via indices, therefore the map does not contain any pointers and can be
+//! relocated. Since the nodes do not store a link to their parent, advancing the iterator
+//! takes O(log n).
+//!
+//! # User Examples
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_container::ordered_map::FixedSizeOrderedMap;
+//!
+//! const CAPACITY: usize = 100;
+//! let mut map = FixedSizeOrderedMap::<u64, u8, CAPACITY>::new();
+//! assert_eq!(map.insert(23, 4).is_ok(), true);
+//! assert_eq!(map.insert(5, 2).is_ok(), true);
+//! assert_eq!(map.get(&23).unwrap(), 4);
+//!
+//! assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [5, 23]);
+//! ```
+
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use core::mem::MaybeUninit;
+use iceoryx2_bb_concurrency::atomic::AtomicBool;
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary::relocatable_ptr::GenericRelocatablePointer;
+use iceoryx2_bb_elementary_traits::generic_pointer::GenericPointer;
+use iceoryx2_bb_elementary_traits::owning_pointer::GenericOwningPointer;
+pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::{
+    placement_default::PlacementDefault, zero_copy_send::ZeroCopySend,
+};
+use iceoryx2_log::{fail, fatal_panic};
+
+use crate::vec::{MetaVec, RelocatableVec};
+
+const INVALID: usize = usize::MAX;
+
+/// Failures caused by insert()
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum OrderedMapError {
+    /// The OrderedMap already contains the key that shall be inserted.
+    KeyAlreadyExists,
+    /// The OrderedMap is full and cannot hold an additional key-value pair.
+    IsFull,
+}
+
+#[repr(C)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+}
+
+/// The links of a tree node. Unused nodes are chained via `left` in the free list.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, ZeroCopySend)]
+struct Node {
+    left: usize,
+    right: usize,
+    height: usize,
+}
+
+/// A runtime fixed-size, non-shared memory compatible [`OrderedMap`]. The [`OrderedMap`]s memory
+/// resides in the heap.
+pub type OrderedMap<K, V> = MetaOrderedMap<K, V, GenericOwningPointer>;
+
+/// A runtime fixed-size, shared-memory compatible [`RelocatableOrderedMap`].
+pub type RelocatableOrderedMap<K, V> = MetaOrderedMap<K, V, GenericRelocatablePointer>;
+
+#[doc(hidden)]
+/// The iterator of an [`OrderedMap`], [`RelocatableOrderedMap`] or [`FixedSizeOrderedMap`]. It
+/// iterates in ascending key order.
+pub struct Iter<'map, K: Ord, V, Ptr: GenericPointer> {
+    map: &'map MetaOrderedMap<K, V, Ptr>,
+    index: usize,
+}
+
+impl<'map, K: Ord, V, Ptr: GenericPointer> Iterator for Iter<'map, K, V, Ptr> {
+    type Item = (&'map K, &'map V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == INVALID {
+            return None;
+        }
+
+        let entry = self.map.entry(self.index);
+        self.index = self.map.successor(&entry.key);
+        Some((&entry.key, &entry.value))
+    }
+}
+
+#[doc(hidden)]
+#[repr(C)]
+pub struct MetaOrderedMap<K: Ord, V, Ptr: GenericPointer> {
+    nodes: MetaVec<Node, Ptr>,
+    entries: MetaVec<Option<Entry<K, V>>, Ptr>,
+    root: usize,
+    free_list_head: usize,
+    len: usize,
+    is_initialized: AtomicBool,
+}
+
+unsafe impl<K: Ord + Send, V: Send, Ptr: GenericPointer> Send for MetaOrderedMap<K, V, Ptr> {}
+
+impl<K: Ord + Debug, V: Debug, Ptr: GenericPointer> Debug for MetaOrderedMap<K, V, Ptr> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "MetaOrderedMap<{}, {}, {}> {{ len: {}, capacity: {}, is_initialized: {} }}",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            core::any::type_name::<Ptr>(),
+            self.len,
+            self.capacity_impl(),
+            self.is_initialized
+                .load(core::sync::atomic::Ordering::Relaxed),
+        )
+    }
+}
+
+impl<K: Ord, V, Ptr: GenericPointer> MetaOrderedMap<K, V, Ptr> {
+    #[inline(always)]
+    fn verify_init(&self, source: &str) {
+        debug_assert!(
+            self.is_initialized
+                .load(core::sync::atomic::Ordering::Relaxed),
+            "From: MetaOrderedMap<{}, {}>::{}, Undefined behavior - the object was not initialized with 'init' before.",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            source
+        );
+    }
+
+    pub(crate) unsafe fn initialize_data_structures(&mut self) {
+        let capacity = self.nodes.capacity();
+        for n in 0..capacity {
+            unsafe {
+                self.nodes.push_impl(Node {
+                    left: n + 1,
+                    right: INVALID,
+                    height: 0,
+                })
+            };
+            unsafe { self.entries.push_impl(None) };
+        }
+
+        self.reset_nodes();
+    }
+
+    fn reset_nodes(&mut self) {
+        let capacity = self.nodes.len();
+        for n in 0..capacity {
+            self.nodes[n] = Node {
+                left: n + 1,
+                right: INVALID,
+                height: 0,
+            };
+        }
+
+        if capacity > 0 {
+            self.nodes[capacity - 1].left = INVALID;
+            self.free_list_head = 0;
+        } else {
+            self.free_list_head = INVALID;
+        }
+
+        self.root = INVALID;
+        self.len = 0;
+    }
+
+    fn entry(&self, index: usize) -> &Entry<K, V> {
+        self.entries[index]
+            .as_ref()
+            .expect("Every node that is part of the tree contains an entry.")
+    }
+
+    fn height(&self, index: usize) -> usize {
+        match index {
+            INVALID => 0,
+            index => self.nodes[index].height,
+        }
+    }
+
+    fn balance_factor(&self, index: usize) -> isize {
+        self.height(self.nodes[index].left) as isize - self.height(self.nodes[index].right) as isize
+    }
+
+    fn update_height(&mut self, index: usize) {
+        self.nodes[index].height = 1 + self
+            .height(self.nodes[index].left)
+            .max(self.height(self.nodes[index].right));
+    }
+
+    fn rotate_right(&mut self, index: usize) -> usize {
+        let new_root = self.nodes[index].left;
+        self.nodes[index].left = self.nodes[new_root].right;
+        self.nodes[new_root].right = index;
+        self.update_height(index);
+        self.update_height(new_root);
+        new_root
+    }
+
+    fn rotate_left(&mut self, index: usize) -> usize {
+        let new_root = self.nodes[index].right;
+        self.nodes[index].right = self.nodes[new_root].left;
+        self.nodes[new_root].left = index;
+        self.update_height(index);
+        self.update_height(new_root);
+        new_root
+    }
+
+    /// Restores the AVL property of the subtree with the root `index` and returns its new root.
+    fn rebalance(&mut self, index: usize) -> usize {
+        self.update_height(index);
+        let balance_factor = self.balance_factor(index);
+
+        if balance_factor > 1 {
+            if self.balance_factor(self.nodes[index].left) < 0 {
+                self.nodes[index].left = self.rotate_left(self.nodes[index].left);
+            }
+            return self.rotate_right(index);
+        }
+
+        if balance_factor < -1 {
+            if self.balance_factor(self.nodes[index].right) > 0 {
+                self.nodes[index].right = self.rotate_right(self.nodes[index].right);
+            }
+            return self.rotate_left(index);
+        }
+
+        index
+    }
+
+    fn find(&self, key: &K) -> Option<usize> {
+        let mut index = self.root;
+        while index != INVALID {
+            match key.cmp(&self.entry(index).key) {
+                Ordering::Less => index = self.nodes[index].left,
+                Ordering::Greater => index = self.nodes[index].right,
+                Ordering::Equal => return Some(index),
+            }
+        }
+
+        None
+    }
+
+    /// Returns the index of the entry with the smallest key that is greater than `key`.
+    fn successor(&self, key: &K) -> usize {
+        let mut candidate = INVALID;
+        let mut index = self.root;
+        while index != INVALID {
+            if *key < self.entry(index).key {
+                candidate = index;
+                index = self.nodes[index].left;
+            } else {
+                index = self.nodes[index].right;
+            }
+        }
+
+        candidate
+    }
+
+    fn insert_node(&mut self, subtree: usize, index: usize) -> usize {
+        if subtree == INVALID {
+            return index;
+        }
+
+        if self.entry(index).key < self.entry(subtree).key {
+            self.nodes[subtree].left = self.insert_node(self.nodes[subtree].left, index);
+        } else {
+            self.nodes[subtree].right = self.insert_node(self.nodes[subtree].right, index);
+        }
+
+        self.rebalance(subtree)
+    }
+
+    /// Unlinks the node with the smallest key from the subtree and returns the new root of the
+    /// subtree and the unlinked node.
+    fn unlink_min(&mut self, subtree: usize) -> (usize, usize) {
+        let left = self.nodes[subtree].left;
+        if left == INVALID {
+            return (self.nodes[subtree].right, subtree);
+        }
+
+        let (new_left, min) = self.unlink_min(left);
+        self.nodes[subtree].left = new_left;
+        (self.rebalance(subtree), min)
+    }
+
+    /// Unlinks the node `index` from the subtree and returns the new root of the subtree.
+    fn unlink_node(&mut self, subtree: usize, index: usize) -> usize {
+        match self.entry(index).key.cmp(&self.entry(subtree).key) {
+            Ordering::Less => {
+                self.nodes[subtree].left = self.unlink_node(self.nodes[subtree].left, index);
+            }
+            Ordering::Greater => {
+                self.nodes[subtree].right = self.unlink_node(self.nodes[subtree].right, index);
+            }
+            Ordering::Equal => {
+                let Node { left, right, .. } = self.nodes[subtree];
+                if left == INVALID {
+                    return right;
+                }
+                if right == INVALID {
+                    return left;
+                }
+
+                let (new_right, min) = self.unlink_min(right);
+                self.nodes[min].left = left;
+                self.nodes[min].right = new_right;
+                return self.rebalance(min);
+            }
+        }
+
+        self.rebalance(subtree)
+    }
+
+    pub(crate) unsafe fn insert_impl(&mut self, key: K, value: V) -> Result<(), OrderedMapError> {
+        self.verify_init("insert()");
+
+        let msg = "Unable to insert key-value pair into OrderedMap";
+        let origin = "MetaOrderedMap::insert_impl()";
+
+        if self.find(&key).is_some() {
+            fail!(from origin, with OrderedMapError::KeyAlreadyExists, "{msg} since the passed key already exists.");
+        }
+
+        if self.free_list_head == INVALID {
+            fail!(from origin, with OrderedMapError::IsFull, "{msg} since the OrderedMap is full.");
+        }
+
+        let index = self.free_list_head;
+        self.free_list_head = self.nodes[index].left;
+        self.nodes[index] = Node {
+            left: INVALID,
+            right: INVALID,
+            height: 1,
+        };
+        self.entries[index] = Some(Entry { key, value });
+        self.root = self.insert_node(self.root, index);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    pub(crate) unsafe fn get_ref_impl(&self, key: &K) -> Option<&V> {
+        self.verify_init("get_ref()");
+
+        self.find(key).map(|index| &self.entry(index).value)
+    }
+
+    pub(crate) unsafe fn get_mut_ref_impl(&mut self, key: &K) -> Option<&mut V> {
+        self.verify_init("get_mut_ref()");
+
+        let index = self.find(key)?;
+        self.entries[index].as_mut().map(|entry| &mut entry.value)
+    }
+
+    pub(crate) unsafe fn remove_impl(&mut self, key: &K) -> Option<V> {
+        self.verify_init("remove()");
+
+        let index = self.find(key)?;
+        self.root = self.unlink_node(self.root, index);
+
+        self.nodes[index] = Node {
+            left: self.free_list_head,
+            right: INVALID,
+            height: 0,
+        };
+        self.free_list_head = index;
+        self.len -= 1;
+
+        self.entries[index].take().map(|entry| entry.value)
+    }
+
+    pub(crate) unsafe fn first_impl(&self) -> Option<(&K, &V)> {
+        self.verify_init("first()");
+
+        let mut index = self.root;
+        if index == INVALID {
+            return None;
+        }
+
+        while self.nodes[index].left != INVALID {
+            index = self.nodes[index].left;
+        }
+
+        let entry = self.entry(index);
+        Some((&entry.key, &entry.value))
+    }
+
+    pub(crate) unsafe fn last_impl(&self) -> Option<(&K, &V)> {
+        self.verify_init("last()");
+
+        let mut index = self.root;
+        if index == INVALID {
+            return None;
+        }
+
+        while self.nodes[index].right != INVALID {
+            index = self.nodes[index].right;
+        }
+
+        let entry = self.entry(index);
+        Some((&entry.key, &entry.value))
+    }
+
+    pub(crate) unsafe fn clear_impl(&mut self) {
+        self.verify_init("clear()");
+
+        for n in 0..self.entries.len() {
+            self.entries[n] = None;
+        }
+        self.reset_nodes();
+    }
+
+    pub(crate) unsafe fn iter_impl(&self) -> Iter<'_, K, V, Ptr> {
+        self.verify_init("iter()");
+
+        let mut index = self.root;
+        if index != INVALID {
+            while self.nodes[index].left != INVALID {
+                index = self.nodes[index].left;
+            }
+        }
+
+        Iter { map: self, index }
+    }
+
+    pub(crate) unsafe fn list_keys_impl<F: FnMut(&K) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        for (key, _) in unsafe { self.iter_impl() } {
+            if callback(key) == CallbackProgression::Stop {
+                break;
+            }
+        }
+    }
+
+    pub(crate) fn len_impl(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn capacity_impl(&self) -> usize {
+        self.nodes.capacity()
+    }
+
+    pub(crate) fn is_empty_impl(&self) -> bool {
+        self.len == 0
+    }
+
+    pub(crate) fn is_full_impl(&self) -> bool {
+        self.len == self.capacity_impl()
+    }
+}
+
+impl<K: Ord, V> OrderedMap<K, V> {
+    /// Creates a new runtime-fixed size [`OrderedMap`] on the heap with the given capacity.
+    pub fn new(capacity: usize) -> Self {
+        let mut new_self = Self {
+            nodes: MetaVec::new(capacity),
+            entries: MetaVec::new(capacity),
+            root: INVALID,
+            free_list_head: INVALID,
+            len: 0,
+            is_initialized: AtomicBool::new(true),
+        };
+        unsafe { new_self.initialize_data_structures() };
+        new_self
+    }
+
+    /// Inserts a new key-value pair into the [`OrderedMap`]. On success, the method returns
+    /// [`Ok`], otherwise an [`OrderedMapError`] describing the failure.
+    pub fn insert(&mut self, key: K, value: V) -> Result<(), OrderedMapError> {
+        unsafe { self.insert_impl(key, value) }
+    }
+
+    /// Returns a reference to the value corresponding to the given key. If there is no such
+    /// key, [`None`] is returned.
+    pub fn get_ref(&self, key: &K) -> Option<&V> {
+        unsafe { self.get_ref_impl(key) }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the given key. If there is
+    /// no such key, [`None`] is returned.
+    pub fn get_mut_ref(&mut self, key: &K) -> Option<&mut V> {
+        unsafe { self.get_mut_ref_impl(key) }
+    }
+
+    /// Removes a key from the [`OrderedMap`], returning the Some(value) at the key if the key
+    /// was previously in the map or [`None`] otherwise.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        unsafe { self.remove_impl(key) }
+    }
+
+    /// Returns the key-value pair with the smallest key. If the [`OrderedMap`] is empty,
+    /// [`None`] is returned.
+    pub fn first(&self) -> Option<(&K, &V)> {
+        unsafe { self.first_impl() }
+    }
+
+    /// Returns the key-value pair with the largest key. If the [`OrderedMap`] is empty,
+    /// [`None`] is returned.
+    pub fn last(&self) -> Option<(&K, &V)> {
+        unsafe { self.last_impl() }
+    }
+
+    /// Removes all key-value pairs from the [`OrderedMap`].
+    pub fn clear(&mut self) {
+        unsafe { self.clear_impl() }
+    }
+
+    /// Returns true if the [`OrderedMap`] contains the given key, otherwise false.
+    pub fn contains(&self, key: &K) -> bool {
+        unsafe { self.get_ref_impl(key).is_some() }
+    }
+
+    /// Returns an [`Iterator`] over all key-value pairs in ascending key order.
+    pub fn iter(&self) -> Iter<'_, K, V, GenericOwningPointer> {
+        unsafe { self.iter_impl() }
+    }
+
+    /// Iterates over all keys of the map in ascending order and calls the provided callback.
+    pub fn list_keys<F: FnMut(&K) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.list_keys_impl(callback) };
+    }
+
+    /// Returns true if the [`OrderedMap`] is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.is_empty_impl()
+    }
+
+    /// Returns true if the [`OrderedMap`] is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.is_full_impl()
+    }
+
+    /// Returns the number of stored key-value pairs.
+    pub fn len(&self) -> usize {
+        self.len_impl()
+    }
+
+    /// Returns the capacity of the [`OrderedMap`].
+    pub fn capacity(&self) -> usize {
+        self.capacity_impl()
+    }
+}
+
+impl<K: Ord, V: Clone> OrderedMap<K, V> {
+    /// Returns a copy of the value corresponding to the given key. If there is no such key,
+    /// [`None`] is returned.
+    pub fn get(&self, key: &K) -> Option<V> {
+        unsafe { self.get_ref_impl(key).cloned() }
+    }
+}
+
+impl<K: Ord, V> RelocatableContainer for RelocatableOrderedMap<K, V> {
+    unsafe fn new_uninit(capacity: usize) -> Self {
+        Self {
+            nodes: unsafe { RelocatableVec::new_uninit(capacity) },
+            entries: unsafe { RelocatableVec::new_uninit(capacity) },
+            root: INVALID,
+            free_list_head: INVALID,
+            len: 0,
+            is_initialized: AtomicBool::new(false),
+        }
+    }
+
+    unsafe fn init<Allocator: iceoryx2_bb_elementary_traits::allocator::BaseAllocator>(
+        &mut self,
+        allocator: &Allocator,
+    ) -> Result<(), iceoryx2_bb_elementary_traits::allocator::AllocationError> {
+        if self
+            .is_initialized
+            .load(core::sync::atomic::Ordering::Relaxed)
+        {
+            fatal_panic!(from "RelocatableOrderedMap::init()", "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+        }
+        let msg = "Unable to initialize RelocatableOrderedMap";
+        fail!(from "RelocatableOrderedMap::init()",
+                  when unsafe { self.nodes.init(allocator) },
+                  "{msg} since the underlying nodes vector could not be initialized.");
+        fail!(from "RelocatableOrderedMap::init()",
+                  when unsafe { self.entries.init(allocator) },
+                  "{msg} since the underlying entries vector could not be initialized.");
+
+        unsafe { self.initialize_data_structures() };
+        self.is_initialized
+            .store(true, core::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn memory_size(capacity: usize) -> usize {
+        Self::const_memory_size(capacity)
+    }
+}
+
+unsafe impl<K: Ord + ZeroCopySend, V: ZeroCopySend> ZeroCopySend for RelocatableOrderedMap<K, V> {}
+
+impl<K: Ord, V> RelocatableOrderedMap<K, V> {
+    /// Returns how much memory the [`RelocatableOrderedMap`] will allocate from the allocator
+    /// in [`RelocatableOrderedMap::init()`].
+    pub const fn const_memory_size(capacity: usize) -> usize {
+        RelocatableVec::<Node>::const_memory_size(capacity)
+            + RelocatableVec::<Option<Entry<K, V>>>::const_memory_size(capacity)
+    }
+
+    /// Inserts a new key-value pair into the map. On success, the method returns [`Ok`],
+    /// otherwise an [`OrderedMapError`] describing the failure.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn insert(&mut self, key: K, value: V) -> Result<(), OrderedMapError> {
+        unsafe { self.insert_impl(key, value) }
+    }
+
+    /// Returns a reference to the value corresponding to the given key. If there is no such
+    /// key, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn get_ref(&self, key: &K) -> Option<&V> {
+        unsafe { self.get_ref_impl(key) }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the given key. If there is
+    /// no such key, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn get_mut_ref(&mut self, key: &K) -> Option<&mut V> {
+        unsafe { self.get_mut_ref_impl(key) }
+    }
+
+    /// Removes a key from the map, returning the Some(value) at the key if the key
+    /// was previously in the map or [`None`] otherwise.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn remove(&mut self, key: &K) -> Option<V> {
+        unsafe { self.remove_impl(key) }
+    }
+
+    /// Returns the key-value pair with the smallest key. If the map is empty, [`None`] is
+    /// returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn first(&self) -> Option<(&K, &V)> {
+        unsafe { self.first_impl() }
+    }
+
+    /// Returns the key-value pair with the largest key. If the map is empty, [`None`] is
+    /// returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn last(&self) -> Option<(&K, &V)> {
+        unsafe { self.last_impl() }
+    }
+
+    /// Removes all key-value pairs from the map.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn clear(&mut self) {
+        unsafe { self.clear_impl() }
+    }
+
+    /// Returns true if the map contains the given key, otherwise false.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn contains(&self, key: &K) -> bool {
+        unsafe { self.get_ref_impl(key).is_some() }
+    }
+
+    /// Returns an [`Iterator`] over all key-value pairs in ascending key order.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn iter(&self) -> Iter<'_, K, V, GenericRelocatablePointer> {
+        unsafe { self.iter_impl() }
+    }
+
+    /// Iterates over all keys of the map in ascending order and calls the provided callback.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn list_keys<F: FnMut(&K) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.list_keys_impl(callback) };
+    }
+
+    /// Returns true if the map is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.is_empty_impl()
+    }
+
+    /// Returns true if the map is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.is_full_impl()
+    }
+
+    /// Returns the number of stored key-value pairs.
+    pub fn len(&self) -> usize {
+        self.len_impl()
+    }
+
+    /// Returns the capacity of the map.
+    pub fn capacity(&self) -> usize {
+        self.capacity_impl()
+    }
+}
+
+impl<K: Ord, V: Clone> RelocatableOrderedMap<K, V> {
+    /// Returns a copy of the value corresponding to the given key. If there is no such key,
+    /// [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn get(&self, key: &K) -> Option<V> {
+        unsafe { self.get_ref_impl(key).cloned() }
+    }
+}
+
+/// A compile-time fixed-size, shared-memory compatible [`FixedSizeOrderedMap`].
+#[repr(C)]
+pub struct FixedSizeOrderedMap<K: Ord, V, const CAPACITY: usize> {
+    map: RelocatableOrderedMap<K, V>,
+    _nodes: MaybeUninit<[Node; CAPACITY]>,
+    _entries: MaybeUninit<[Option<Entry<K, V>>; CAPACITY]>,
+}
+
+unsafe impl<K: Ord + ZeroCopySend, V: ZeroCopySend, const CAPACITY: usize> ZeroCopySend
+    for FixedSizeOrderedMap<K, V, CAPACITY>
+{
+}
+
+impl<K: Ord, V, const CAPACITY: usize> PlacementDefault for FixedSizeOrderedMap<K, V, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        unsafe {
+            let map_ptr = core::ptr::addr_of_mut!((*ptr).map);
+            map_ptr.write(RelocatableOrderedMap::new_uninit(CAPACITY));
+            let allocator = BumpAllocator::new((*ptr)._nodes.as_mut_ptr().cast());
+            (*ptr)
+                .map
+                .init(&allocator)
+                .expect("All required memory is preallocated.");
+        }
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> Default for FixedSizeOrderedMap<K, V, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord + Debug, V: Debug, const CAPACITY: usize> Debug
+    for FixedSizeOrderedMap<K, V, CAPACITY>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "FixedSizeOrderedMap<{}, {}, {}> {{ {:?} }}",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            CAPACITY,
+            self.map
+        )
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> FixedSizeOrderedMap<K, V, CAPACITY> {
+    /// Creates a new [`FixedSizeOrderedMap`]
+    pub fn new() -> Self {
+        let mut new_self = Self {
+            map: unsafe { RelocatableOrderedMap::new_uninit(CAPACITY) },
+            _nodes: MaybeUninit::uninit(),
+            _entries: MaybeUninit::uninit(),
+        };
+        let allocator = BumpAllocator::new(new_self._nodes.as_mut_ptr().cast());
+        unsafe {
+            new_self
+                .map
+                .init(&allocator)
+                .expect("All required memory is preallocated.")
+        };
+        new_self
+    }
+
+    /// Inserts a new key-value pair into the [`FixedSizeOrderedMap`]. On success, the method
+    /// returns [`Ok`], otherwise an [`OrderedMapError`] describing the failure.
+    pub fn insert(&mut self, key: K, value: V) -> Result<(), OrderedMapError> {
+        unsafe { self.map.insert(key, value) }
+    }
+
+    /// Returns a reference to the value corresponding to the given key. If there is no such
+    /// key, [`None`] is returned.
+    pub fn get_ref(&self, key: &K) -> Option<&V> {
+        unsafe { self.map.get_ref(key) }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the given key. If there is
+    /// no such key, [`None`] is returned.
+    pub fn get_mut_ref(&mut self, key: &K) -> Option<&mut V> {
+        unsafe { self.map.get_mut_ref(key) }
+    }
+
+    /// Removes a key from the [`FixedSizeOrderedMap`], returning the Some(value) at the key
+    /// if the key was previously in the map or [`None`] otherwise.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        unsafe { self.map.remove(key) }
+    }
+
+    /// Returns the key-value pair with the smallest key. If the [`FixedSizeOrderedMap`] is
+    /// empty, [`None`] is returned.
+    pub fn first(&self) -> Option<(&K, &V)> {
+        unsafe { self.map.first() }
+    }
+
+    /// Returns the key-value pair with the largest key. If the [`FixedSizeOrderedMap`] is
+    /// empty, [`None`] is returned.
+    pub fn last(&self) -> Option<(&K, &V)> {
+        unsafe { self.map.last() }
+    }
+
+    /// Removes all key-value pairs from the [`FixedSizeOrderedMap`].
+    pub fn clear(&mut self) {
+        unsafe { self.map.clear() }
+    }
+
+    /// Returns true if the [`FixedSizeOrderedMap`] contains the given key, otherwise false.
+    pub fn contains(&self, key: &K) -> bool {
+        unsafe { self.map.contains(key) }
+    }
+
+    /// Returns an [`Iterator`] over all key-value pairs in ascending key order.
+    pub fn iter(&self) -> Iter<'_, K, V, GenericRelocatablePointer> {
+        unsafe { self.map.iter() }
+    }
+
+    /// Iterates over all keys of the map in ascending order and calls the provided callback.
+    pub fn list_keys<F: FnMut(&K) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.map.list_keys(callback) };
+    }
+
+    /// Returns true if the [`FixedSizeOrderedMap`] is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns true if the [`FixedSizeOrderedMap`] is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    /// Returns the number of stored key-value pairs.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns the capacity of the [`FixedSizeOrderedMap`].
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+}
+
+impl<K: Ord, V: Clone, const CAPACITY: usize> FixedSizeOrderedMap<K, V, CAPACITY> {
+    /// Returns a copy of the value corresponding to the given key. If there is no such key,
+    /// [`None`] is returned.
+    pub fn get(&self, key: &K) -> Option<V> {
+        unsafe { self.map.get(key) }
+    }
+}
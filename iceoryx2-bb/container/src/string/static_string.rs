@@ -44,10 +44,10 @@ use core::{
 };
 use iceoryx2_bb_elementary_traits::atomic_copy::AtomicCopy;
 
-use iceoryx2_bb_derive_macros::{PlacementDefault, ZeroCopySend};
+use iceoryx2_bb_derive_macros::PlacementDefault;
 use iceoryx2_bb_elementary::math::align_to;
 use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
-use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::zero_copy_send::{SchemaKind, SchemaVisitor, ZeroCopySend};
 use iceoryx2_log::fail;
 use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Visitor};
 
@@ -57,7 +57,7 @@ use crate::string::{
 
 /// Variant of the [`String`] that has a compile-time fixed capacity and is
 /// shared-memory compatible.
-#[derive(PlacementDefault, ZeroCopySend, Clone, Copy)]
+#[derive(PlacementDefault, Clone, Copy)]
 #[repr(C)]
 pub struct StaticString<const CAPACITY: usize> {
     data: [MaybeUninit<u8>; CAPACITY],
@@ -65,6 +65,22 @@ pub struct StaticString<const CAPACITY: usize> {
     len: u64,
 }
 
+unsafe impl<const CAPACITY: usize> ZeroCopySend for StaticString<CAPACITY> {
+    fn describe_schema(visitor: &mut dyn SchemaVisitor, name: &str, offset: usize) {
+        visitor.enter(
+            name,
+            offset,
+            core::mem::size_of::<Self>(),
+            SchemaKind::String {
+                capacity: CAPACITY,
+                len_offset: core::mem::offset_of!(Self, len),
+                data_offset: core::mem::offset_of!(Self, data),
+            },
+        );
+        visitor.leave();
+    }
+}
+
 unsafe impl<const CAPACITY: usize> AtomicCopy for StaticString<CAPACITY> {
     fn __for_each_field<F: FnMut(usize, usize)>(&self, base_offset: usize, callback: &mut F) {
         let aligned_base_offset = align_to::<Self>(base_offset);
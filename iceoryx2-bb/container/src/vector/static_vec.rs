@@ -36,7 +36,8 @@ use core::{
 };
 
 use iceoryx2_bb_elementary_traits::{
-    placement_default::PlacementDefault, zero_copy_send::ZeroCopySend,
+    placement_default::PlacementDefault,
+    zero_copy_send::{SchemaKind, SchemaVisitor, ZeroCopySend},
 };
 use iceoryx2_log::fail;
 use serde::{Deserialize, Serialize, de::Visitor};
@@ -78,7 +79,22 @@ impl<T: Debug, const CAPACITY: usize> Debug for StaticVec<T, CAPACITY> {
     }
 }
 
-unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for StaticVec<T, CAPACITY> {}
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for StaticVec<T, CAPACITY> {
+    fn describe_schema(visitor: &mut dyn SchemaVisitor, name: &str, offset: usize) {
+        visitor.enter(
+            name,
+            offset,
+            core::mem::size_of::<Self>(),
+            SchemaKind::Vec {
+                capacity: CAPACITY,
+                len_offset: core::mem::offset_of!(Self, len),
+                stride: core::mem::size_of::<T>(),
+            },
+        );
+        T::describe_schema(visitor, "", offset + core::mem::offset_of!(Self, data));
+        visitor.leave();
+    }
+}
 
 impl<T, const CAPACITY: usize> Drop for StaticVec<T, CAPACITY> {
     fn drop(&mut self) {
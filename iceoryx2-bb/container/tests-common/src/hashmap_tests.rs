@@ -0,0 +1,255 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec;
+
+use core::hash::Hasher;
+use iceoryx2_bb_container::hashmap::*;
+use iceoryx2_bb_container::string::StaticString;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
+use iceoryx2_bb_testing::memory::RawMemory;
+use iceoryx2_bb_testing_macros::test;
+
+const CAPACITY: usize = 100;
+
+#[test]
+pub fn new_creates_empty_hash_map() {
+    let sut = HashMap::<u8, i32>::new(CAPACITY);
+    assert_that!(sut, is_empty);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut, len 0);
+    assert_that!(sut.capacity(), eq CAPACITY);
+}
+
+#[test]
+pub fn new_creates_empty_fixed_size_hash_map() {
+    let sut = FixedSizeHashMap::<u8, i32, CAPACITY>::new();
+    assert_that!(sut, is_empty);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut, len 0);
+    assert_that!(sut.capacity(), eq CAPACITY);
+}
+
+#[test]
+pub fn placement_default_works() {
+    type Sut = FixedSizeHashMap<u8, u8, CAPACITY>;
+    let mut sut = RawMemory::<Sut>::new_zeroed();
+    unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+    let sut = unsafe { sut.assume_init_mut() };
+    assert_that!(sut.insert(4, 6), is_ok);
+    assert_that!(sut.get(&4), eq Some(6));
+}
+
+#[test]
+pub fn drop_called_for_keys_and_values() {
+    let state = LifetimeTracker::start_tracking();
+    let mut sut = FixedSizeHashMap::<usize, LifetimeTracker, CAPACITY>::new();
+    for n in 0..CAPACITY {
+        assert_that!(sut.insert(n, LifetimeTracker::new_with_value(n)), is_ok);
+    }
+    assert_that!(state.number_of_living_instances(), eq CAPACITY);
+
+    drop(sut);
+    assert_that!(state.number_of_living_instances(), eq 0);
+}
+
+#[test]
+pub fn insert_the_same_key_fails() {
+    let mut sut = HashMap::<i16, i16>::new(CAPACITY);
+    let key = -2023;
+
+    assert_that!(sut.insert(key, -9), is_ok);
+    let result = sut.insert(key, 19);
+    assert_that!(result, eq Err(HashMapError::KeyAlreadyExists));
+    assert_that!(sut, len 1);
+    assert_that!(sut.get(&key), eq Some(-9));
+}
+
+#[test]
+pub fn insert_until_full_works() {
+    let mut sut = FixedSizeHashMap::<u32, u32, CAPACITY>::new();
+    for i in 0..CAPACITY as u32 {
+        assert_that!(sut.insert(i, 2 * i), is_ok);
+        assert_that!(sut.contains(&i), eq true);
+    }
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut, len CAPACITY);
+
+    let result = sut.insert(CAPACITY as u32, 0);
+    assert_that!(result, eq Err(HashMapError::IsFull));
+    assert_that!(sut.contains(&(CAPACITY as u32)), eq false);
+
+    for i in 0..CAPACITY as u32 {
+        assert_that!(sut.get(&i), eq Some(2 * i));
+    }
+}
+
+#[test]
+pub fn get_mut_ref_modifies_value() {
+    let mut sut = FixedSizeHashMap::<u8, u8, CAPACITY>::new();
+    assert_that!(sut.insert(34, 40), is_ok);
+
+    *sut.get_mut_ref(&34).unwrap() = 41;
+    assert_that!(*sut.get_ref(&34).unwrap(), eq 41);
+    assert_that!(sut.get_mut_ref(&35), is_none);
+}
+
+#[test]
+pub fn remove_from_colliding_chains_works() {
+    const SMALL_CAPACITY: usize = 3;
+    let mut sut = FixedSizeHashMap::<u64, u64, SMALL_CAPACITY>::new();
+
+    for i in 0..SMALL_CAPACITY as u64 {
+        assert_that!(sut.insert(i, i + 10), is_ok);
+    }
+
+    assert_that!(sut.remove(&1), eq Some(11));
+    assert_that!(sut.remove(&1), is_none);
+    assert_that!(sut.get(&0), eq Some(10));
+    assert_that!(sut.get(&2), eq Some(12));
+
+    assert_that!(sut.remove(&0), eq Some(10));
+    assert_that!(sut.remove(&2), eq Some(12));
+    assert_that!(sut, is_empty);
+}
+
+#[test]
+pub fn remove_until_empty_and_reinsert_works() {
+    let mut sut = HashMap::<u32, u32>::new(CAPACITY);
+    for _ in 0..2 {
+        for i in 0..CAPACITY as u32 {
+            assert_that!(sut.insert(i, i), is_ok);
+        }
+        assert_that!(sut.is_full(), eq true);
+
+        for i in (0..CAPACITY as u32).rev() {
+            assert_that!(sut.remove(&i), eq Some(i));
+        }
+        assert_that!(sut, is_empty);
+    }
+}
+
+#[test]
+pub fn clear_removes_all_elements() {
+    let state = LifetimeTracker::start_tracking();
+    let mut sut = HashMap::<u32, LifetimeTracker>::new(CAPACITY);
+    for i in 0..CAPACITY as u32 {
+        assert_that!(sut.insert(i, LifetimeTracker::new()), is_ok);
+    }
+
+    sut.clear();
+    assert_that!(sut, is_empty);
+    assert_that!(state.number_of_living_instances(), eq 0);
+    for i in 0..CAPACITY as u32 {
+        assert_that!(sut.insert(i, LifetimeTracker::new()), is_ok);
+    }
+    assert_that!(sut.is_full(), eq true);
+}
+
+#[test]
+pub fn string_keys_work() {
+    let mut sut = FixedSizeHashMap::<StaticString<16>, u32, CAPACITY>::new();
+    let key_a = StaticString::<16>::from_bytes(b"chicken").unwrap();
+    let key_b = StaticString::<16>::from_bytes(b"egg").unwrap();
+
+    assert_that!(sut.insert(key_a, 1), is_ok);
+    assert_that!(sut.insert(key_b, 2), is_ok);
+
+    assert_that!(sut.get(&key_a), eq Some(1));
+    assert_that!(sut.get(&key_b), eq Some(2));
+}
+
+#[test]
+pub fn iter_and_list_keys_visit_all_elements() {
+    let mut sut = FixedSizeHashMap::<u32, u32, CAPACITY>::new();
+    for i in 0..CAPACITY as u32 {
+        assert_that!(sut.insert(i, i * 3), is_ok);
+    }
+
+    let mut keys = vec![];
+    for (key, value) in sut.iter() {
+        assert_that!(*value, eq * key * 3);
+        keys.push(*key);
+    }
+    keys.sort();
+    assert_that!(keys, eq(0..CAPACITY as u32).collect::<alloc::vec::Vec<_>>());
+
+    let mut counter = 0;
+    sut.list_keys(|_| {
+        counter += 1;
+        if counter == 10 {
+            CallbackProgression::Stop
+        } else {
+            CallbackProgression::Continue
+        }
+    });
+    assert_that!(counter, eq 10);
+}
+
+#[test]
+pub fn relocatable_hash_map_works_with_bump_allocator() {
+    use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+
+    const MEM_SIZE: usize = RelocatableHashMap::<u64, u8>::const_memory_size(CAPACITY);
+    let mut memory = [0u8; MEM_SIZE];
+    let bump_allocator = BumpAllocator::new(memory.as_mut_ptr());
+
+    let mut sut = unsafe { RelocatableHashMap::<u64, u8>::new_uninit(CAPACITY) };
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+
+    for i in 0..CAPACITY as u64 {
+        assert_that!(unsafe { sut.insert(i, i as u8) }, is_ok);
+    }
+    for i in 0..CAPACITY as u64 {
+        assert_that!(unsafe { sut.get(&i) }, eq Some(i as u8));
+    }
+}
+
+#[test]
+#[should_panic]
+pub fn double_init_call_causes_panic() {
+    use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+
+    const MEM_SIZE: usize = RelocatableHashMap::<u8, u8>::const_memory_size(CAPACITY);
+    let mut memory = [0u8; MEM_SIZE];
+    let bump_allocator = BumpAllocator::new(memory.as_mut_ptr());
+
+    let mut sut = unsafe { RelocatableHashMap::<u8, u8>::new_uninit(CAPACITY) };
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+}
+
+#[test]
+pub fn zero_capacity_hash_map_is_always_full() {
+    let mut sut = HashMap::<u8, u8>::new(0);
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut.insert(1, 1), eq Err(HashMapError::IsFull));
+    assert_that!(sut.get(&1), is_none);
+    assert_that!(sut.remove(&1), is_none);
+}
+
+#[test]
+pub fn deterministic_hasher_produces_same_hash_for_same_input() {
+    let mut sut_1 = DeterministicHasher::new();
+    let mut sut_2 = DeterministicHasher::new();
+    sut_1.write(b"hypnotoad");
+    sut_2.write(b"hypnotoad");
+    assert_that!(sut_1.finish(), eq sut_2.finish());
+
+    // FNV-1a reference value for the empty input
+    assert_that!(DeterministicHasher::new().finish(), eq 0xcbf29ce484222325);
+}
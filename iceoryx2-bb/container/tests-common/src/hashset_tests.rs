@@ -0,0 +1,101 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_container::hashset::*;
+use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::memory::RawMemory;
+use iceoryx2_bb_testing_macros::test;
+
+const CAPACITY: usize = 50;
+
+#[test]
+pub fn new_creates_empty_hash_set() {
+    let sut = HashSet::<u8>::new(CAPACITY);
+    assert_that!(sut, is_empty);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut.capacity(), eq CAPACITY);
+
+    let sut = FixedSizeHashSet::<u8, CAPACITY>::new();
+    assert_that!(sut, is_empty);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut.capacity(), eq CAPACITY);
+}
+
+#[test]
+pub fn placement_default_works() {
+    type Sut = FixedSizeHashSet<u32, CAPACITY>;
+    let mut sut = RawMemory::<Sut>::new_zeroed();
+    unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+    let sut = unsafe { sut.assume_init_mut() };
+    assert_that!(sut.insert(4), is_ok);
+    assert_that!(sut.contains(&4), eq true);
+}
+
+#[test]
+pub fn insert_the_same_value_twice_fails() {
+    let mut sut = FixedSizeHashSet::<u32, CAPACITY>::new();
+    assert_that!(sut.insert(12), is_ok);
+    assert_that!(sut.insert(12), eq Err(HashSetError::ValueAlreadyExists));
+    assert_that!(sut, len 1);
+}
+
+#[test]
+pub fn insert_until_full_and_remove_until_empty_works() {
+    let mut sut = HashSet::<u64>::new(CAPACITY);
+    for i in 0..CAPACITY as u64 {
+        assert_that!(sut.insert(i), is_ok);
+    }
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut.insert(CAPACITY as u64), eq Err(HashSetError::IsFull));
+
+    for i in 0..CAPACITY as u64 {
+        assert_that!(sut.contains(&i), eq true);
+        assert_that!(sut.remove(&i), eq true);
+        assert_that!(sut.remove(&i), eq false);
+    }
+    assert_that!(sut, is_empty);
+}
+
+#[test]
+pub fn iter_visits_all_values() {
+    let mut sut = FixedSizeHashSet::<u64, CAPACITY>::new();
+    for i in 0..CAPACITY as u64 {
+        assert_that!(sut.insert(i), is_ok);
+    }
+
+    let sum: u64 = sut.iter().sum();
+    assert_that!(sum, eq(0..CAPACITY as u64).sum::<u64>());
+
+    sut.clear();
+    assert_that!(sut.iter().count(), eq 0);
+}
+
+#[test]
+pub fn relocatable_hash_set_works_with_bump_allocator() {
+    use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+
+    const MEM_SIZE: usize = RelocatableHashSet::<u16>::const_memory_size(CAPACITY);
+    let mut memory = [0u8; MEM_SIZE];
+    let bump_allocator = BumpAllocator::new(memory.as_mut_ptr());
+
+    let mut sut = unsafe { RelocatableHashSet::<u16>::new_uninit(CAPACITY) };
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+
+    for i in 0..CAPACITY as u16 {
+        assert_that!(unsafe { sut.insert(i) }, is_ok);
+    }
+    for i in 0..CAPACITY as u16 {
+        assert_that!(unsafe { sut.contains(&i) }, eq true);
+    }
+}
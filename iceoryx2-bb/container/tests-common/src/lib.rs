@@ -17,6 +17,10 @@ extern crate iceoryx2_bb_loggers;
 
 pub mod byte_atomic_tests;
 pub mod flatmap_tests;
+pub mod hashmap_tests;
+pub mod hashset_tests;
+pub mod list_tests;
+pub mod ordered_map_tests;
 pub mod polymorphic_string_tests;
 pub mod polymorphic_vec_tests;
 pub mod queue_tests;
@@ -0,0 +1,201 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use iceoryx2_bb_container::list::*;
+use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
+use iceoryx2_bb_testing::memory::RawMemory;
+use iceoryx2_bb_testing_macros::test;
+
+const CAPACITY: usize = 32;
+
+fn content<'a, I: Iterator<Item = &'a u32>>(iter: I) -> Vec<u32> {
+    iter.copied().collect()
+}
+
+#[test]
+pub fn new_creates_empty_list() {
+    let sut = List::<u32>::new(CAPACITY);
+    assert_that!(sut, is_empty);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut.capacity(), eq CAPACITY);
+    assert_that!(sut.front_handle(), is_none);
+    assert_that!(sut.back_handle(), is_none);
+
+    let sut = FixedSizeList::<u32, CAPACITY>::new();
+    assert_that!(sut, is_empty);
+    assert_that!(sut.capacity(), eq CAPACITY);
+}
+
+#[test]
+pub fn placement_default_works() {
+    type Sut = FixedSizeList<u32, CAPACITY>;
+    let mut sut = RawMemory::<Sut>::new_zeroed();
+    unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+    let sut = unsafe { sut.assume_init_mut() };
+    assert_that!(sut.push_back(4), is_some);
+    assert_that!(sut.pop_front(), eq Some(4));
+}
+
+#[test]
+pub fn push_front_and_back_orders_elements() {
+    let mut sut = FixedSizeList::<u32, CAPACITY>::new();
+    sut.push_back(2);
+    sut.push_back(3);
+    sut.push_front(1);
+    sut.push_front(0);
+
+    assert_that!(content(sut.iter()), eq vec![0, 1, 2, 3]);
+    assert_that!(sut.get(sut.front_handle().unwrap()), eq Some(&0));
+    assert_that!(sut.get(sut.back_handle().unwrap()), eq Some(&3));
+}
+
+#[test]
+pub fn pop_front_and_back_removes_elements_in_order() {
+    let mut sut = List::<u32>::new(CAPACITY);
+    for i in 0..4 {
+        sut.push_back(i);
+    }
+
+    assert_that!(sut.pop_front(), eq Some(0));
+    assert_that!(sut.pop_back(), eq Some(3));
+    assert_that!(sut.pop_back(), eq Some(2));
+    assert_that!(sut.pop_front(), eq Some(1));
+    assert_that!(sut.pop_front(), is_none);
+    assert_that!(sut.pop_back(), is_none);
+    assert_that!(sut, is_empty);
+}
+
+#[test]
+pub fn insert_before_and_after_handle_works() {
+    let mut sut = FixedSizeList::<u32, CAPACITY>::new();
+    let first = sut.push_back(1).unwrap();
+    let last = sut.push_back(5).unwrap();
+
+    let middle = sut.insert_after(first, 3).unwrap();
+    sut.insert_before(middle, 2);
+    sut.insert_after(middle, 4);
+    sut.insert_before(first, 0);
+    sut.insert_after(last, 6);
+
+    assert_that!(content(sut.iter()), eq vec![0, 1, 2, 3, 4, 5, 6]);
+    assert_that!(sut.get(sut.back_handle().unwrap()), eq Some(&6));
+}
+
+#[test]
+pub fn remove_with_handle_works() {
+    let mut sut = FixedSizeList::<u32, CAPACITY>::new();
+    let handles: Vec<ListHandle> = (0..5).map(|i| sut.push_back(i).unwrap()).collect();
+
+    assert_that!(sut.remove(handles[2]), eq Some(2));
+    assert_that!(sut.remove(handles[2]), is_none);
+    assert_that!(sut.remove(handles[0]), eq Some(0));
+    assert_that!(sut.remove(handles[4]), eq Some(4));
+
+    assert_that!(content(sut.iter()), eq vec![1, 3]);
+    assert_that!(sut.insert_after(handles[2], 10), is_none);
+}
+
+#[test]
+pub fn handles_stay_valid_when_other_elements_are_removed() {
+    let mut sut = List::<u32>::new(CAPACITY);
+    let handle_a = sut.push_back(1).unwrap();
+    let handle_b = sut.push_back(2).unwrap();
+    let handle_c = sut.push_back(3).unwrap();
+
+    sut.remove(handle_b);
+    *sut.get_mut(handle_c).unwrap() = 33;
+
+    assert_that!(sut.get(handle_a), eq Some(&1));
+    assert_that!(sut.get(handle_b), is_none);
+    assert_that!(sut.get(handle_c), eq Some(&33));
+}
+
+#[test]
+pub fn handle_of_removed_element_does_not_identify_element_in_reused_slot() {
+    let mut sut = FixedSizeList::<u32, CAPACITY>::new();
+    let stale_handle = sut.push_back(1).unwrap();
+    sut.remove(stale_handle);
+    let handle = sut.push_back(2).unwrap();
+    assert_that!(handle.value(), eq stale_handle.value());
+
+    assert_that!(sut.get(stale_handle), is_none);
+    assert_that!(sut.get_mut(stale_handle), is_none);
+    assert_that!(sut.insert_after(stale_handle, 3), is_none);
+    assert_that!(sut.remove(stale_handle), is_none);
+    assert_that!(sut.get(handle), eq Some(&2));
+
+    sut.clear();
+    assert_that!(sut.push_back(4).unwrap().value(), eq handle.value());
+    assert_that!(sut.get(handle), is_none);
+}
+
+#[test]
+pub fn fill_until_full_works() {
+    let mut sut = FixedSizeList::<u32, CAPACITY>::new();
+    for i in 0..CAPACITY as u32 {
+        assert_that!(sut.push_back(i), is_some);
+    }
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut.push_back(0), is_none);
+    assert_that!(sut.push_front(0), is_none);
+    assert_that!(sut.insert_after(sut.front_handle().unwrap(), 0), is_none);
+
+    sut.pop_front();
+    assert_that!(sut.push_front(100), is_some);
+    assert_that!(sut.get(sut.front_handle().unwrap()), eq Some(&100));
+}
+
+#[test]
+pub fn clear_and_drop_release_all_elements() {
+    let state = LifetimeTracker::start_tracking();
+    let mut sut = FixedSizeList::<LifetimeTracker, CAPACITY>::new();
+    for _ in 0..CAPACITY {
+        sut.push_back(LifetimeTracker::new());
+    }
+    assert_that!(state.number_of_living_instances(), eq CAPACITY);
+
+    sut.clear();
+    assert_that!(sut, is_empty);
+    assert_that!(state.number_of_living_instances(), eq 0);
+
+    for _ in 0..CAPACITY {
+        sut.push_front(LifetimeTracker::new());
+    }
+    drop(sut);
+    assert_that!(state.number_of_living_instances(), eq 0);
+}
+
+#[test]
+pub fn relocatable_list_works_with_bump_allocator() {
+    use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+
+    const MEM_SIZE: usize = RelocatableList::<u32>::const_memory_size(CAPACITY);
+    let mut memory = [0u8; MEM_SIZE];
+    let bump_allocator = BumpAllocator::new(memory.as_mut_ptr());
+
+    let mut sut = unsafe { RelocatableList::<u32>::new_uninit(CAPACITY) };
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+
+    for i in 0..CAPACITY as u32 {
+        assert_that!(unsafe { sut.push_back(i) }, is_some);
+    }
+    assert_that!(
+        content(unsafe { sut.iter() }),
+        eq(0..CAPACITY as u32).collect::<Vec<_>>()
+    );
+}
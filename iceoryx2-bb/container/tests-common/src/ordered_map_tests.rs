@@ -0,0 +1,235 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use iceoryx2_bb_container::ordered_map::*;
+use iceoryx2_bb_container::string::StaticString;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
+use iceoryx2_bb_testing::memory::RawMemory;
+use iceoryx2_bb_testing_macros::test;
+
+const CAPACITY: usize = 100;
+
+fn keys<'a, I: Iterator<Item = (&'a u32, &'a u32)>>(iter: I) -> Vec<u32> {
+    iter.map(|(key, _)| *key).collect()
+}
+
+#[test]
+pub fn new_creates_empty_ordered_map() {
+    let sut = OrderedMap::<u8, i32>::new(CAPACITY);
+    assert_that!(sut, is_empty);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut, len 0);
+    assert_that!(sut.capacity(), eq CAPACITY);
+    assert_that!(sut.first(), is_none);
+    assert_that!(sut.last(), is_none);
+
+    let sut = FixedSizeOrderedMap::<u8, i32, CAPACITY>::new();
+    assert_that!(sut, is_empty);
+    assert_that!(sut.capacity(), eq CAPACITY);
+}
+
+#[test]
+pub fn placement_default_works() {
+    type Sut = FixedSizeOrderedMap<u8, u8, CAPACITY>;
+    let mut sut = RawMemory::<Sut>::new_zeroed();
+    unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+    let sut = unsafe { sut.assume_init_mut() };
+    assert_that!(sut.insert(4, 6), is_ok);
+    assert_that!(sut.get(&4), eq Some(6));
+}
+
+#[test]
+pub fn drop_called_for_keys_and_values() {
+    let state = LifetimeTracker::start_tracking();
+    let mut sut = FixedSizeOrderedMap::<usize, LifetimeTracker, CAPACITY>::new();
+    for n in 0..CAPACITY {
+        assert_that!(sut.insert(n, LifetimeTracker::new_with_value(n)), is_ok);
+    }
+    assert_that!(state.number_of_living_instances(), eq CAPACITY);
+
+    drop(sut);
+    assert_that!(state.number_of_living_instances(), eq 0);
+}
+
+#[test]
+pub fn insert_the_same_key_fails() {
+    let mut sut = OrderedMap::<i16, i16>::new(CAPACITY);
+    let key = -2023;
+
+    assert_that!(sut.insert(key, -9), is_ok);
+    let result = sut.insert(key, 19);
+    assert_that!(result, eq Err(OrderedMapError::KeyAlreadyExists));
+    assert_that!(sut, len 1);
+    assert_that!(sut.get(&key), eq Some(-9));
+}
+
+#[test]
+pub fn insert_until_full_works() {
+    let mut sut = FixedSizeOrderedMap::<u32, u32, CAPACITY>::new();
+    for n in 0..CAPACITY as u32 {
+        assert_that!(sut.insert(n, 2 * n), is_ok);
+    }
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut.insert(1234, 0), eq Err(OrderedMapError::IsFull));
+
+    for n in 0..CAPACITY as u32 {
+        assert_that!(sut.get(&n), eq Some(2 * n));
+    }
+}
+
+#[test]
+pub fn iter_visits_elements_in_ascending_key_order() {
+    let mut sut = OrderedMap::<u32, u32>::new(CAPACITY);
+    // insert in a scrambled order to exercise all rotations
+    for n in 0..CAPACITY as u32 {
+        let key = (n * 37) % CAPACITY as u32;
+        assert_that!(sut.insert(key, key + 1), is_ok);
+    }
+
+    let expected: Vec<u32> = (0..CAPACITY as u32).collect();
+    assert_that!(keys(sut.iter()), eq expected);
+    for (key, value) in sut.iter() {
+        assert_that!(*value, eq * key + 1);
+    }
+    assert_that!(sut.first(), eq Some((&0, &1)));
+    assert_that!(sut.last(), eq Some((&(CAPACITY as u32 - 1), &(CAPACITY as u32))));
+}
+
+#[test]
+pub fn remove_keeps_remaining_elements_ordered() {
+    let mut sut = OrderedMap::<u32, u32>::new(CAPACITY);
+    for n in (0..CAPACITY as u32).rev() {
+        assert_that!(sut.insert(n, n), is_ok);
+    }
+
+    for n in (0..CAPACITY as u32).filter(|n| n % 3 != 0) {
+        assert_that!(sut.remove(&n), eq Some(n));
+        assert_that!(sut.remove(&n), is_none);
+    }
+
+    let expected: Vec<u32> = (0..CAPACITY as u32).filter(|n| n % 3 == 0).collect();
+    assert_that!(sut, len expected.len());
+    assert_that!(keys(sut.iter()), eq expected);
+    for n in 0..CAPACITY as u32 {
+        assert_that!(sut.contains(&n), eq n % 3 == 0);
+    }
+}
+
+#[test]
+pub fn remove_until_empty_and_reinsert_works() {
+    let mut sut = FixedSizeOrderedMap::<u32, u32, CAPACITY>::new();
+    for _ in 0..3 {
+        for n in 0..CAPACITY as u32 {
+            assert_that!(sut.insert(n, n), is_ok);
+        }
+        for n in 0..CAPACITY as u32 {
+            assert_that!(sut.remove(&((n * 7) % CAPACITY as u32)), is_some);
+        }
+        assert_that!(sut, is_empty);
+        assert_that!(sut.iter().next(), is_none);
+    }
+}
+
+#[test]
+pub fn get_mut_ref_modifies_value() {
+    let mut sut = OrderedMap::<u32, u32>::new(CAPACITY);
+    assert_that!(sut.insert(3, 4), is_ok);
+    *sut.get_mut_ref(&3).unwrap() = 99;
+    assert_that!(sut.get_ref(&3), eq Some(&99));
+    assert_that!(sut.get_mut_ref(&5), is_none);
+}
+
+#[test]
+pub fn clear_removes_all_elements() {
+    let state = LifetimeTracker::start_tracking();
+    let mut sut = OrderedMap::<u32, LifetimeTracker>::new(CAPACITY);
+    for n in 0..10 {
+        assert_that!(sut.insert(n, LifetimeTracker::new()), is_ok);
+    }
+
+    sut.clear();
+    assert_that!(sut, is_empty);
+    assert_that!(state.number_of_living_instances(), eq 0);
+    assert_that!(sut.insert(1, LifetimeTracker::new()), is_ok);
+    assert_that!(sut, len 1);
+}
+
+#[test]
+pub fn string_keys_are_ordered_lexicographically() {
+    let mut sut = FixedSizeOrderedMap::<StaticString<16>, u8, CAPACITY>::new();
+    for (n, name) in ["delta", "alpha", "charlie", "bravo"].iter().enumerate() {
+        let key = StaticString::<16>::from_bytes(name.as_bytes()).unwrap();
+        assert_that!(sut.insert(key, n as u8), is_ok);
+    }
+
+    let mut names = vec![];
+    sut.list_keys(|key| {
+        names.push(*key);
+        CallbackProgression::Continue
+    });
+    let expected: Vec<StaticString<16>> = ["alpha", "bravo", "charlie", "delta"]
+        .iter()
+        .map(|name| StaticString::<16>::from_bytes(name.as_bytes()).unwrap())
+        .collect();
+    assert_that!(names, eq expected);
+}
+
+#[test]
+pub fn relocatable_ordered_map_works_with_bump_allocator() {
+    use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+
+    const MEM_SIZE: usize = RelocatableOrderedMap::<u64, u8>::const_memory_size(CAPACITY);
+    let mut memory = [0u8; MEM_SIZE];
+    let bump_allocator = BumpAllocator::new(memory.as_mut_ptr());
+
+    let mut sut = unsafe { RelocatableOrderedMap::<u64, u8>::new_uninit(CAPACITY) };
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+
+    for i in (0..CAPACITY as u64).rev() {
+        assert_that!(unsafe { sut.insert(i, i as u8) }, is_ok);
+    }
+    for i in 0..CAPACITY as u64 {
+        assert_that!(unsafe { sut.get(&i) }, eq Some(i as u8));
+    }
+    assert_that!(unsafe { sut.first() }, eq Some((&0, &0)));
+}
+
+#[test]
+#[should_panic]
+pub fn double_init_call_causes_panic() {
+    use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+
+    const MEM_SIZE: usize = RelocatableOrderedMap::<u8, u8>::const_memory_size(CAPACITY);
+    let mut memory = [0u8; MEM_SIZE];
+    let bump_allocator = BumpAllocator::new(memory.as_mut_ptr());
+
+    let mut sut = unsafe { RelocatableOrderedMap::<u8, u8>::new_uninit(CAPACITY) };
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+}
+
+#[test]
+pub fn zero_capacity_ordered_map_is_always_full() {
+    let mut sut = OrderedMap::<u8, u8>::new(0);
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut.insert(1, 1), eq Err(OrderedMapError::IsFull));
+    assert_that!(sut.get(&1), is_none);
+    assert_that!(sut.remove(&1), is_none);
+}
@@ -25,7 +25,7 @@ use alloc::vec::Vec;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, LitStr, parse_macro_input};
+use syn::{Data, DeriveInput, Fields, LitInt, LitStr, parse_macro_input};
 
 /// Implements the [`iceoryx2_bb_elementary_traits::placement_default::PlacementDefault`] trait when all
 /// fields of the struct implement it.
@@ -112,7 +112,10 @@ pub fn placement_default_derive(input: TokenStream) -> TokenStream {
 
 /// Implements the [`iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend`] trait when all fields of
 /// the struct implement it and the struct is annotated with `repr(C)`. A type name can be optionally
-/// set with the helper attribute `type_name`.
+/// set with the helper attribute `type_name` and a type version with the helper attribute
+/// `type_version`. For structs, it additionally describes every field
+/// with its name and offset in
+/// [`iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend::describe_schema()`].
 ///
 /// ```
 /// use iceoryx2_bb_derive_macros::ZeroCopySend;
@@ -123,6 +126,7 @@ pub fn placement_default_derive(input: TokenStream) -> TokenStream {
 /// #[repr(C)]
 /// #[derive(ZeroCopySend)]
 /// #[type_name("MyTypeName")]
+/// #[type_version(3)]
 /// struct MyZeroCopySendStruct {
 ///     val1: u64,
 ///     val2: u64,
@@ -134,6 +138,7 @@ pub fn placement_default_derive(input: TokenStream) -> TokenStream {
 /// };
 /// needs_zero_copy_send_type(&x);
 /// assert_eq!(unsafe { MyZeroCopySendStruct::type_name() }, "MyTypeName");
+/// assert_eq!(MyZeroCopySendStruct::type_version(), 3);
 ///
 /// #[repr(C)]
 /// #[derive(ZeroCopySend)]
@@ -153,7 +158,7 @@ pub fn placement_default_derive(input: TokenStream) -> TokenStream {
 /// needs_zero_copy_send_type(&shape3);
 /// assert_eq!(unsafe { Shape::type_name() }, "GeometricShape");
 /// ```
-#[proc_macro_derive(ZeroCopySend, attributes(type_name))]
+#[proc_macro_derive(ZeroCopySend, attributes(type_name, type_version))]
 pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     let struct_name = &ast.ident;
@@ -188,6 +193,35 @@ pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
         }
     };
 
+    // check for type_version attribute
+    let version_attributes: &Vec<_> = &ast
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("type_version"))
+        .collect();
+    if version_attributes.len() > 1 {
+        panic!("Too many type_version attributes provided for ZeroCopySend trait.");
+    }
+
+    let type_version_impl = match version_attributes.first() {
+        None => quote! {},
+        Some(attribute) => {
+            let type_version: LitInt = attribute
+                .parse_args()
+                .expect("Wrong format for ZeroCopySend attribute. Please provide exactly one unsigned integer as \"type_version\".");
+            quote! {
+                fn type_version() -> u32 {
+                    #type_version
+                }
+            }
+        }
+    };
+
+    let type_name_impl = quote! {
+        #type_name_impl
+        #type_version_impl
+    };
+
     // check for repr(C) attribute
     let has_repr_c = &ast.attrs.iter().any(|a| {
         a.path().is_ident("repr")
@@ -214,12 +248,30 @@ pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
                     }
                 });
 
+                let field_schemas = fields_named.named.iter().map(|f| {
+                    let field_name = &f.ident;
+                    let field_type = &f.ty;
+                    quote! {
+                        field(
+                            stringify!(#field_name),
+                            core::mem::offset_of!(Self, #field_name),
+                            <#field_type as ZeroCopySend>::__describe_erased_schema,
+                        );
+                    }
+                });
+
                 quote! {
                     fn __is_zero_copy_send(&self) {
                         #(#field_inits)*
                     }
 
                     #type_name_impl
+
+                    const __HAS_SCHEMA_FIELDS: bool = true;
+
+                    fn __describe_schema_fields(field: &mut dyn FnMut(&str, usize, fn(*mut (), &str, usize))) {
+                        #(#field_schemas)*
+                    }
                 }
             }
             Fields::Unnamed(ref fields_unnamed) => {
@@ -231,16 +283,36 @@ pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
                     }
                 });
 
+                let field_schemas = fields_unnamed.unnamed.iter().enumerate().map(|(i, f)| {
+                    let field_index = syn::Index::from(i);
+                    let field_type = &f.ty;
+                    quote! {
+                        field(
+                            stringify!(#field_index),
+                            core::mem::offset_of!(Self, #field_index),
+                            <#field_type as ZeroCopySend>::__describe_erased_schema,
+                        );
+                    }
+                });
+
                 quote! {
                     fn __is_zero_copy_send(&self) {
                         #(#field_inits)*
                     }
 
                     #type_name_impl
+
+                    const __HAS_SCHEMA_FIELDS: bool = true;
+
+                    fn __describe_schema_fields(field: &mut dyn FnMut(&str, usize, fn(*mut (), &str, usize))) {
+                        #(#field_schemas)*
+                    }
                 }
             }
             Fields::Unit => quote! {
                 #type_name_impl
+
+                const __HAS_SCHEMA_FIELDS: bool = true;
             },
         },
         Data::Enum(ref data_enum) => {
@@ -11,7 +11,9 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use iceoryx2_bb_derive_macros::ZeroCopySend;
-use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::zero_copy_send::{
+    SchemaKind, SchemaPrimitive, SchemaVisitor, ZeroCopySend,
+};
 use iceoryx2_bb_testing::assert_that;
 use iceoryx2_bb_testing_macros::test;
 
@@ -23,6 +25,44 @@ fn is_zero_copy_send<T: ZeroCopySend>(_: &T) -> bool {
 struct Foo(u16);
 unsafe impl ZeroCopySend for Foo {}
 
+type ExpectedSchemaEntry<'a> = (usize, &'a str, usize, usize, SchemaKind);
+
+struct ExpectingSchemaVisitor<'a> {
+    expected: &'a [ExpectedSchemaEntry<'a>],
+    position: usize,
+    depth: usize,
+}
+
+impl SchemaVisitor for ExpectingSchemaVisitor<'_> {
+    fn enter(&mut self, name: &str, offset: usize, size: usize, kind: SchemaKind) {
+        assert_that!(self.position, lt self.expected.len());
+        let (depth, expected_name, expected_offset, expected_size, expected_kind) =
+            self.expected[self.position];
+        assert_that!(self.depth, eq depth);
+        assert_that!(name, eq expected_name);
+        assert_that!(offset, eq expected_offset);
+        assert_that!(size, eq expected_size);
+        assert_that!(kind, eq expected_kind);
+        self.position += 1;
+        self.depth += 1;
+    }
+
+    fn leave(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+fn verify_schema<T: ZeroCopySend>(expected: &[ExpectedSchemaEntry]) {
+    let mut visitor = ExpectingSchemaVisitor {
+        expected,
+        position: 0,
+        depth: 0,
+    };
+    T::describe_schema(&mut visitor, "root", 0);
+    assert_that!(visitor.position, eq expected.len());
+    assert_that!(visitor.depth, eq 0);
+}
+
 #[repr(C)]
 #[derive(ZeroCopySend)]
 struct NamedTestStruct {
@@ -38,6 +78,14 @@ struct NamedTestStructWithAttr {
     _val2: Foo,
 }
 
+#[repr(C)]
+#[derive(ZeroCopySend)]
+#[type_name("Nibbler")]
+#[type_version(7)]
+struct VersionedTestStruct {
+    _val1: u64,
+}
+
 #[allow(dead_code)]
 #[repr(C)]
 #[derive(ZeroCopySend)]
@@ -86,6 +134,18 @@ where
     T1: ZeroCopySend,
     T2: ZeroCopySend;
 
+#[repr(C)]
+#[derive(ZeroCopySend)]
+struct NestedTestStruct {
+    _val1: u8,
+    _val2: [NamedTestStruct; 2],
+    _val3: UnnamedTestStruct,
+}
+
+#[repr(C)]
+#[derive(ZeroCopySend)]
+struct UnitTestStruct;
+
 #[repr(C)]
 #[derive(ZeroCopySend)]
 #[type_name("TryMadHoney")]
@@ -186,3 +246,96 @@ pub fn for_unions() {
     assert_that!(is_zero_copy_send(&sut), eq true);
     assert_that!(unsafe { BasicUnionTest::type_name() }, eq "TryMadHoney");
 }
+
+#[test]
+pub fn sets_type_version_to_zero_by_default() {
+    assert_that!(NamedTestStruct::type_version(), eq 0);
+    assert_that!(UnnamedTestStructWithAttr::type_version(), eq 0);
+    assert_that!(BasicUnionTest::type_version(), eq 0);
+}
+
+#[test]
+pub fn sets_type_version_correctly_with_attribute() {
+    let sut = VersionedTestStruct { _val1: 42 };
+    assert_that!(is_zero_copy_send(&sut), eq true);
+    assert_that!(VersionedTestStruct::type_version(), eq 7);
+    assert_that!(unsafe { VersionedTestStruct::type_name() }, eq "Nibbler");
+}
+
+#[test]
+pub fn describes_schema_of_named_struct() {
+    verify_schema::<NamedTestStruct>(&[
+        (0, "root", 0, 16, SchemaKind::Struct),
+        (
+            1,
+            "_val1",
+            0,
+            8,
+            SchemaKind::Primitive(SchemaPrimitive::U64),
+        ),
+        (1, "_val2", 8, 2, SchemaKind::Opaque),
+    ]);
+}
+
+#[test]
+pub fn describes_schema_of_unnamed_struct() {
+    verify_schema::<UnnamedTestStruct>(&[
+        (0, "root", 0, 12, SchemaKind::Struct),
+        (1, "0", 0, 4, SchemaKind::Primitive(SchemaPrimitive::I32)),
+        (1, "1", 4, 4, SchemaKind::Primitive(SchemaPrimitive::U32)),
+        (1, "2", 8, 2, SchemaKind::Opaque),
+    ]);
+}
+
+#[test]
+pub fn describes_schema_of_generic_struct() {
+    verify_schema::<GenericNamedTestStruct<u16, f32>>(&[
+        (0, "root", 0, 8, SchemaKind::Struct),
+        (
+            1,
+            "_val1",
+            0,
+            2,
+            SchemaKind::Primitive(SchemaPrimitive::U16),
+        ),
+        (
+            1,
+            "_val2",
+            4,
+            4,
+            SchemaKind::Primitive(SchemaPrimitive::F32),
+        ),
+    ]);
+}
+
+#[test]
+pub fn describes_schema_of_nested_struct() {
+    verify_schema::<NestedTestStruct>(&[
+        (0, "root", 0, 56, SchemaKind::Struct),
+        (1, "_val1", 0, 1, SchemaKind::Primitive(SchemaPrimitive::U8)),
+        (1, "_val2", 8, 32, SchemaKind::Array { len: 2, stride: 16 }),
+        (2, "", 8, 16, SchemaKind::Struct),
+        (
+            3,
+            "_val1",
+            8,
+            8,
+            SchemaKind::Primitive(SchemaPrimitive::U64),
+        ),
+        (3, "_val2", 16, 2, SchemaKind::Opaque),
+        (1, "_val3", 40, 12, SchemaKind::Struct),
+        (2, "0", 40, 4, SchemaKind::Primitive(SchemaPrimitive::I32)),
+        (2, "1", 44, 4, SchemaKind::Primitive(SchemaPrimitive::U32)),
+        (2, "2", 48, 2, SchemaKind::Opaque),
+    ]);
+}
+
+#[test]
+pub fn describes_schema_of_unit_struct_without_fields() {
+    verify_schema::<UnitTestStruct>(&[(0, "root", 0, 0, SchemaKind::Struct)]);
+}
+
+#[test]
+pub fn describes_schema_of_unions_as_opaque() {
+    verify_schema::<BasicUnionTest>(&[(0, "root", 0, 4, SchemaKind::Opaque)]);
+}
@@ -41,36 +41,210 @@ pub unsafe trait ZeroCopySend {
         core::any::type_name::<Self>()
     }
 
+    /// The version of the type. It shall be increased whenever the semantic or the layout of
+    /// the type changes so that incompatible participants can be detected.
+    fn type_version() -> u32 {
+        0
+    }
+
     #[doc(hidden)]
     /// used as dummy call in the derive macro to ensure at compile-time that all fields of
     /// a struct implement ZeroCopySend
     fn __is_zero_copy_send(&self) {}
+
+    /// Describes the memory layout of the type to the provided [`SchemaVisitor`]. The type is
+    /// located at `offset` bytes from the start of the outermost described type and is
+    /// identified by `name` inside its parent.
+    ///
+    /// Structs that derive [`ZeroCopySend`] are described as [`SchemaKind::Struct`] with one
+    /// child node per field, all other types default to [`SchemaKind::Opaque`].
+    fn describe_schema(visitor: &mut dyn SchemaVisitor, name: &str, offset: usize)
+    where
+        Self: Sized,
+    {
+        if !Self::__HAS_SCHEMA_FIELDS {
+            visitor.enter(
+                name,
+                offset,
+                core::mem::size_of::<Self>(),
+                SchemaKind::Opaque,
+            );
+            visitor.leave();
+            return;
+        }
+
+        visitor.enter(
+            name,
+            offset,
+            core::mem::size_of::<Self>(),
+            SchemaKind::Struct,
+        );
+        let mut visitor = visitor;
+        let erased_visitor = (&mut visitor as *mut &mut dyn SchemaVisitor).cast::<()>();
+        Self::__describe_schema_fields(&mut |field_name, field_offset, describe_field| {
+            describe_field(erased_visitor, field_name, offset + field_offset)
+        });
+        visitor.leave();
+    }
+
+    #[doc(hidden)]
+    /// set by the derive macro when [`ZeroCopySend::__describe_schema_fields()`] describes
+    /// the fields of a struct
+    const __HAS_SCHEMA_FIELDS: bool = false;
+
+    #[doc(hidden)]
+    /// implemented by the derive macro, calls `field` with the name, offset and
+    /// [`ZeroCopySend::__describe_erased_schema()`] of every field of a struct
+    #[allow(clippy::type_complexity)]
+    fn __describe_schema_fields(_field: &mut dyn FnMut(&str, usize, fn(*mut (), &str, usize))) {}
+
+    #[doc(hidden)]
+    /// type-erased [`ZeroCopySend::describe_schema()`] that is used by the derive macro, so
+    /// that it does not have to name the [`SchemaVisitor`]
+    fn __describe_erased_schema(visitor: *mut (), name: &str, offset: usize)
+    where
+        Self: Sized,
+    {
+        // SAFETY: the pointer is always created in describe_schema() from a
+        //         `&mut &mut dyn SchemaVisitor` that outlives this call
+        let visitor = unsafe { &mut *visitor.cast::<&mut dyn SchemaVisitor>() };
+        Self::describe_schema(&mut **visitor, name, offset)
+    }
+}
+
+/// The primitive types a [`SchemaKind::Primitive`] can represent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchemaPrimitive {
+    /// [`bool`]
+    Bool,
+    /// [`char`]
+    Char,
+    /// [`u8`]
+    U8,
+    /// [`u16`]
+    U16,
+    /// [`u32`]
+    U32,
+    /// [`u64`]
+    U64,
+    /// [`u128`]
+    U128,
+    /// [`usize`]
+    Usize,
+    /// [`i8`]
+    I8,
+    /// [`i16`]
+    I16,
+    /// [`i32`]
+    I32,
+    /// [`i64`]
+    I64,
+    /// [`i128`]
+    I128,
+    /// [`isize`]
+    Isize,
+    /// [`f32`]
+    F32,
+    /// [`f64`]
+    F64,
+}
+
+/// Defines how a node of a type schema, reported via [`SchemaVisitor::enter()`], is laid out
+/// in memory. All offsets inside a [`SchemaKind`] are relative to the start of the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchemaKind {
+    /// A primitive value.
+    Primitive(SchemaPrimitive),
+    /// A struct, its fields are the child nodes.
+    Struct,
+    /// A fixed-size array of `len` elements. The single child node describes the first element,
+    /// every further element is located `stride` bytes after its predecessor.
+    Array {
+        /// The number of elements
+        len: usize,
+        /// The distance in bytes between two elements
+        stride: usize,
+    },
+    /// A vector with a fixed capacity. The number of contained elements is stored as [`u64`]
+    /// at `len_offset`. The single child node describes the first element, every further
+    /// element is located `stride` bytes after its predecessor.
+    Vec {
+        /// The maximum number of elements
+        capacity: usize,
+        /// The offset of the [`u64`] length
+        len_offset: usize,
+        /// The distance in bytes between two elements
+        stride: usize,
+    },
+    /// A UTF-8 string with a fixed capacity. The number of contained bytes is stored as [`u64`]
+    /// at `len_offset` and the bytes start at `data_offset`.
+    String {
+        /// The maximum number of bytes
+        capacity: usize,
+        /// The offset of the [`u64`] length
+        len_offset: usize,
+        /// The offset of the first byte
+        data_offset: usize,
+    },
+    /// A type whose internal structure is unknown.
+    Opaque,
 }
 
-unsafe impl ZeroCopySend for usize {}
-unsafe impl ZeroCopySend for u8 {}
-unsafe impl ZeroCopySend for u16 {}
-unsafe impl ZeroCopySend for u32 {}
-unsafe impl ZeroCopySend for u64 {}
-unsafe impl ZeroCopySend for u128 {}
+/// Receives the description of a type via [`ZeroCopySend::describe_schema()`]. Every node is
+/// reported with [`SchemaVisitor::enter()`], followed by its child nodes and terminated with
+/// [`SchemaVisitor::leave()`].
+pub trait SchemaVisitor {
+    /// Enters a new node named `name` of the given `kind` that is `size` bytes large and
+    /// located `offset` bytes after the start of the outermost described type.
+    fn enter(&mut self, name: &str, offset: usize, size: usize, kind: SchemaKind);
 
-unsafe impl ZeroCopySend for isize {}
-unsafe impl ZeroCopySend for i8 {}
-unsafe impl ZeroCopySend for i16 {}
-unsafe impl ZeroCopySend for i32 {}
-unsafe impl ZeroCopySend for i64 {}
-unsafe impl ZeroCopySend for i128 {}
+    /// Leaves the most recently entered node.
+    fn leave(&mut self);
+}
 
-unsafe impl ZeroCopySend for f32 {}
-unsafe impl ZeroCopySend for f64 {}
+macro_rules! impl_zero_copy_send_for_primitive {
+    ($($t:ty => $primitive:ident),*) => {
+        $(
+            unsafe impl ZeroCopySend for $t {
+                fn describe_schema(visitor: &mut dyn SchemaVisitor, name: &str, offset: usize) {
+                    visitor.enter(
+                        name,
+                        offset,
+                        core::mem::size_of::<Self>(),
+                        SchemaKind::Primitive(SchemaPrimitive::$primitive),
+                    );
+                    visitor.leave();
+                }
+            }
+        )*
+    };
+}
 
-unsafe impl ZeroCopySend for char {}
-unsafe impl ZeroCopySend for bool {}
+impl_zero_copy_send_for_primitive!(
+    usize => Usize, u8 => U8, u16 => U16, u32 => U32, u64 => U64, u128 => U128,
+    isize => Isize, i8 => I8, i16 => I16, i32 => I32, i64 => I64, i128 => I128,
+    f32 => F32, f64 => F64,
+    char => Char, bool => Bool
+);
 
 unsafe impl ZeroCopySend for () {}
 
 unsafe impl<T: ZeroCopySend> ZeroCopySend for [T] {}
-unsafe impl<T: ZeroCopySend, const N: usize> ZeroCopySend for [T; N] {}
+unsafe impl<T: ZeroCopySend, const N: usize> ZeroCopySend for [T; N] {
+    fn describe_schema(visitor: &mut dyn SchemaVisitor, name: &str, offset: usize) {
+        visitor.enter(
+            name,
+            offset,
+            core::mem::size_of::<Self>(),
+            SchemaKind::Array {
+                len: N,
+                stride: core::mem::size_of::<T>(),
+            },
+        );
+        T::describe_schema(visitor, "", offset);
+        visitor.leave();
+    }
+}
 unsafe impl<T: ZeroCopySend> ZeroCopySend for core::mem::MaybeUninit<T> {}
 
 // Note: `ZeroCopySend` cannot be implemented for tuples because `#[repr(C)]` can only be applied
@@ -290,6 +290,18 @@ impl<T: Copy + Debug> Container<T> {
         self.index_set.is_locked()
     }
 
+    /// Locks the [`Container`] when it is empty and returns [`ReleaseState::Locked`].
+    /// If the [`Container`] still contains elements it stays unlocked and
+    /// [`ReleaseState::Unlocked`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * Ensure that [`Container::init()`] was called before calling this method
+    ///
+    pub unsafe fn lock_if_empty(&self) -> ReleaseState {
+        unsafe { self.index_set.lock_if_empty() }
+    }
+
     /// Returns the current len of the container
     pub fn len(&self) -> usize {
         self.index_set.borrowed_indices()
@@ -789,4 +801,9 @@ impl<T: Copy + Debug, const CAPACITY: usize> FixedSizeContainer<T, CAPACITY> {
     pub fn is_locked(&self) -> bool {
         self.container.is_locked()
     }
+
+    /// See [`Container::lock_if_empty()`]
+    pub fn lock_if_empty(&self) -> ReleaseState {
+        unsafe { self.container.lock_if_empty() }
+    }
 }
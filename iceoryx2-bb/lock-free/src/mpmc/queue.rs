@@ -0,0 +1,424 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A **threadsafe** **lock-free** bounded multi producer multi consumer FIFO queue.
+//! **IMPORTANT** Can only be used with trivially copyable types which are also trivially dropable.
+//!
+//! Every slot of the queue has a sequence number that tells the producers and consumers
+//! whether the slot is ready to be written or read. A producer or consumer that is interrupted
+//! between claiming a slot and releasing it again delays only the operations on this one slot,
+//! all other slots can still be used concurrently.
+//!
+//! # Example
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_lock_free::mpmc::queue::*;
+//!
+//! const QUEUE_CAPACITY: usize = 128;
+//! let queue = FixedSizeQueue::<u64, QUEUE_CAPACITY>::new();
+//!
+//! if !queue.push(&1234) {
+//!     println!("queue is full");
+//! }
+//!
+//! match queue.pop() {
+//!     None => println!("queue is empty"),
+//!     Some(v) => println!("got {}", v)
+//! }
+//! ```
+
+use core::{alloc::Layout, fmt::Debug, mem::MaybeUninit};
+
+use iceoryx2_bb_concurrency::atomic::AtomicBool;
+use iceoryx2_bb_concurrency::atomic::AtomicU64;
+use iceoryx2_bb_concurrency::atomic::Ordering;
+use iceoryx2_bb_concurrency::cell::UnsafeCell;
+use iceoryx2_bb_elementary::math::unaligned_mem_size;
+use iceoryx2_bb_elementary::{bump_allocator::BumpAllocator, relocatable_ptr::RelocatablePointer};
+use iceoryx2_bb_elementary_traits::{
+    owning_pointer::OwningPointer, pointer_trait::PointerTrait,
+    relocatable_container::RelocatableContainer,
+};
+use iceoryx2_log::{fail, fatal_panic};
+
+/// A slot of the [`Queue`]/[`RelocatableQueue`]/[`FixedSizeQueue`] that stores one value
+/// together with its sequence number.
+#[repr(C)]
+#[derive(Debug)]
+pub struct Slot<T: Copy + Debug> {
+    sequence: AtomicU64,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+impl<T: Copy + Debug> Slot<T> {
+    const fn new(sequence: u64) -> Self {
+        Self {
+            sequence: AtomicU64::new(sequence),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+}
+
+/// Non-relocatable version of the [`details::Queue`] that stores its slots on the heap.
+pub type Queue<T> = details::Queue<T, OwningPointer<Slot<T>>>;
+/// Relocatable version of the [`details::Queue`] that can be stored inside shared memory.
+pub type RelocatableQueue<T> = details::Queue<T, RelocatablePointer<Slot<T>>>;
+
+pub mod details {
+    use super::*;
+
+    /// A threadsafe lock-free multi producer multi consumer queue with a capacity which can be
+    /// set up at runtime, when the queue is created.
+    ///
+    /// The slot at position `p` is ready to be written when its sequence number is `2 * p`
+    /// and ready to be read when its sequence number is `2 * p + 1`. Reading the slot advances
+    /// the sequence number to `2 * (p + capacity)`, the next write position that is mapped
+    /// onto the same slot.
+    #[repr(C)]
+    #[derive(Debug)]
+    pub struct Queue<T: Copy + Debug, PointerType: PointerTrait<Slot<T>>> {
+        write_position: AtomicU64,
+        read_position: AtomicU64,
+        is_memory_initialized: AtomicBool,
+        capacity: usize,
+        data_ptr: PointerType,
+        _phantom: core::marker::PhantomData<T>,
+    }
+
+    unsafe impl<T: Copy + Debug + Send, PointerType: PointerTrait<Slot<T>>> Sync
+        for Queue<T, PointerType>
+    {
+    }
+    unsafe impl<T: Copy + Debug + Send, PointerType: PointerTrait<Slot<T>>> Send
+        for Queue<T, PointerType>
+    {
+    }
+
+    impl<T: Copy + Debug> Queue<T, OwningPointer<Slot<T>>> {
+        /// Creates a new empty [`Queue`] that can hold up to `capacity` values.
+        ///
+        /// # Panics
+        ///
+        ///  * when the `capacity` is zero
+        pub fn new(capacity: usize) -> Self {
+            if capacity == 0 {
+                fatal_panic!(from "Queue::new()", "The capacity of the queue must not be zero.");
+            }
+
+            let mut data_ptr = OwningPointer::<Slot<T>>::new_with_alloc(capacity);
+
+            for i in 0..capacity {
+                unsafe { data_ptr.as_mut_ptr().add(i).write(Slot::new(2 * i as u64)) };
+            }
+
+            Self {
+                data_ptr,
+                capacity,
+                write_position: AtomicU64::new(0),
+                read_position: AtomicU64::new(0),
+                is_memory_initialized: AtomicBool::new(true),
+                _phantom: core::marker::PhantomData,
+            }
+        }
+    }
+
+    impl<T: Copy + Debug> RelocatableContainer for Queue<T, RelocatablePointer<Slot<T>>> {
+        unsafe fn new_uninit(capacity: usize) -> Self {
+            if capacity == 0 {
+                fatal_panic!(from "RelocatableQueue::new_uninit()",
+                    "The capacity of the queue must not be zero.");
+            }
+
+            Self {
+                data_ptr: unsafe { RelocatablePointer::new_uninit() },
+                capacity,
+                write_position: AtomicU64::new(0),
+                read_position: AtomicU64::new(0),
+                is_memory_initialized: AtomicBool::new(false),
+                _phantom: core::marker::PhantomData,
+            }
+        }
+
+        unsafe fn init<Allocator: iceoryx2_bb_elementary_traits::allocator::BaseAllocator>(
+            &mut self,
+            allocator: &Allocator,
+        ) -> Result<(), iceoryx2_bb_elementary_traits::allocator::AllocationError> {
+            if self.is_memory_initialized.load(Ordering::Relaxed) {
+                fatal_panic!(from self, "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+            }
+            unsafe {
+                self.data_ptr.init(fail!(from self, when allocator
+            .allocate(Layout::from_size_align_unchecked(
+                    core::mem::size_of::<Slot<T>>() * self.capacity,
+                    core::mem::align_of::<Slot<T>>())),
+            "Failed to initialize since the allocation of the data memory failed."));
+
+                for i in 0..self.capacity {
+                    (self.data_ptr.as_ptr() as *mut Slot<T>)
+                        .add(i)
+                        .write(Slot::new(2 * i as u64));
+                }
+            }
+            self.is_memory_initialized.store(true, Ordering::Relaxed);
+            Ok(())
+        }
+
+        fn memory_size(capacity: usize) -> usize {
+            Self::const_memory_size(capacity)
+        }
+    }
+
+    impl<T: Copy + Debug, PointerType: PointerTrait<Slot<T>> + Debug> Queue<T, PointerType> {
+        #[inline(always)]
+        fn verify_init(&self, source: &str) {
+            debug_assert!(
+                self.is_memory_initialized.load(Ordering::Relaxed),
+                "Undefined behavior when calling Queue::{source} and the object is not initialized."
+            );
+        }
+
+        /// Returns the amount of memory required to create a [`Queue`] with the provided
+        /// capacity.
+        pub const fn const_memory_size(capacity: usize) -> usize {
+            unaligned_mem_size::<Slot<T>>(capacity)
+        }
+
+        fn slot(&self, position: u64) -> &Slot<T> {
+            unsafe {
+                &*self
+                    .data_ptr
+                    .as_ptr()
+                    .add((position % self.capacity as u64) as usize)
+            }
+        }
+
+        /// Adds a copy of the value to the [`Queue`]. If the queue is full it returns false,
+        /// otherwise true. Can be called concurrently by any number of threads.
+        /// ```
+        /// # extern crate iceoryx2_bb_loggers;
+        ///
+        /// use iceoryx2_bb_lock_free::mpmc::queue::*;
+        ///
+        /// let queue = Queue::<u64>::new(128);
+        ///
+        /// if !queue.push(&1234) {
+        ///     println!("queue is full");
+        /// }
+        /// ```
+        pub fn push(&self, value: &T) -> bool {
+            self.verify_init("push()");
+            let mut write_position = self.write_position.load(Ordering::Relaxed);
+
+            loop {
+                let slot = self.slot(write_position);
+                ////////////////
+                // SYNC POINT: reading value has finished
+                ////////////////
+                let sequence = slot.sequence.load(Ordering::Acquire);
+                let expected_sequence = 2 * write_position;
+
+                if sequence == expected_sequence {
+                    match self.write_position.compare_exchange_weak(
+                        write_position,
+                        write_position + 1,
+                        // the slot content is synchronized via its sequence number
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    ) {
+                        Ok(_) => {
+                            unsafe { slot.value.get().write(MaybeUninit::new(*value)) };
+                            ////////////////
+                            // SYNC POINT: value content visible in pop
+                            ////////////////
+                            slot.sequence
+                                .store(expected_sequence + 1, Ordering::Release);
+                            return true;
+                        }
+                        Err(v) => write_position = v,
+                    }
+                } else if sequence < expected_sequence {
+                    // the value of the previous round was not yet acquired
+                    return false;
+                } else {
+                    // another producer claimed the slot in the meantime
+                    write_position = self.write_position.load(Ordering::Relaxed);
+                }
+            }
+        }
+
+        /// Acquires the oldest value from the [`Queue`]. If the queue is empty it returns
+        /// [`None`], otherwise the value. Can be called concurrently by any number of threads.
+        /// ```
+        /// # extern crate iceoryx2_bb_loggers;
+        ///
+        /// use iceoryx2_bb_lock_free::mpmc::queue::*;
+        ///
+        /// let queue = Queue::<u64>::new(128);
+        ///
+        /// match queue.pop() {
+        ///     None => println!("queue is empty"),
+        ///     Some(v) => println!("got {}", v)
+        /// }
+        /// ```
+        pub fn pop(&self) -> Option<T> {
+            self.verify_init("pop()");
+            let mut read_position = self.read_position.load(Ordering::Relaxed);
+
+            loop {
+                let slot = self.slot(read_position);
+                ////////////////
+                // SYNC POINT: value content visible in pop
+                ////////////////
+                let sequence = slot.sequence.load(Ordering::Acquire);
+                let expected_sequence = 2 * read_position + 1;
+
+                if sequence == expected_sequence {
+                    match self.read_position.compare_exchange_weak(
+                        read_position,
+                        read_position + 1,
+                        // the slot content is synchronized via its sequence number
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    ) {
+                        Ok(_) => {
+                            let value = unsafe { (*slot.value.get()).assume_init() };
+                            ////////////////
+                            // SYNC POINT: reading value has finished
+                            ////////////////
+                            slot.sequence.store(
+                                2 * (read_position + self.capacity as u64),
+                                Ordering::Release,
+                            );
+                            return Some(value);
+                        }
+                        Err(v) => read_position = v,
+                    }
+                } else if sequence < expected_sequence {
+                    // the value was not yet written
+                    return None;
+                } else {
+                    // another consumer acquired the slot in the meantime
+                    read_position = self.read_position.load(Ordering::Relaxed);
+                }
+            }
+        }
+
+        fn acquire_read_and_write_position(&self) -> (u64, u64) {
+            loop {
+                let write_position = self.write_position.load(Ordering::Relaxed);
+                let read_position = self.read_position.load(Ordering::Relaxed);
+
+                if write_position == self.write_position.load(Ordering::Relaxed)
+                    && read_position == self.read_position.load(Ordering::Relaxed)
+                {
+                    return (write_position, read_position);
+                }
+            }
+        }
+
+        /// Returns true when the [`Queue`] is empty, otherwise false.
+        /// Note: This method may make only sense in a non-concurrent setup since the information
+        ///       could be out-of-date as soon as it is acquired.
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        /// Returns the length of the [`Queue`].
+        /// Note: This method may make only sense in a non-concurrent setup since the information
+        ///       could be out-of-date as soon as it is acquired.
+        pub fn len(&self) -> usize {
+            let (write_position, read_position) = self.acquire_read_and_write_position();
+            write_position.saturating_sub(read_position) as usize
+        }
+
+        /// Returns the capacity of the [`Queue`].
+        pub const fn capacity(&self) -> usize {
+            self.capacity
+        }
+
+        /// Returns true when the [`Queue`] is full, otherwise false.
+        /// Note: This method may make only sense in a non-concurrent setup since the information
+        ///       could be out-of-date as soon as it is acquired.
+        pub fn is_full(&self) -> bool {
+            self.len() >= self.capacity
+        }
+    }
+}
+
+/// The compile-time fixed size version of the [`Queue`].
+#[derive(Debug)]
+#[repr(C)]
+pub struct FixedSizeQueue<T: Copy + Debug, const CAPACITY: usize> {
+    state: RelocatableQueue<T>,
+    data: [Slot<T>; CAPACITY],
+}
+
+unsafe impl<T: Copy + Debug + Send, const CAPACITY: usize> Sync for FixedSizeQueue<T, CAPACITY> {}
+unsafe impl<T: Copy + Debug + Send, const CAPACITY: usize> Send for FixedSizeQueue<T, CAPACITY> {}
+
+impl<T: Copy + Debug, const CAPACITY: usize> Default for FixedSizeQueue<T, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Debug, const CAPACITY: usize> FixedSizeQueue<T, CAPACITY> {
+    /// Creates a new empty [`FixedSizeQueue`].
+    pub fn new() -> Self {
+        let mut new_self = Self {
+            state: unsafe { RelocatableQueue::new_uninit(CAPACITY) },
+            data: [const { Slot::new(0) }; CAPACITY],
+        };
+
+        let allocator = BumpAllocator::new(new_self.data.as_mut_ptr().cast());
+        unsafe {
+            new_self
+                .state
+                .init(&allocator)
+                .expect("All required memory is preallocated.")
+        };
+
+        new_self
+    }
+
+    /// See [`Queue::push()`]
+    pub fn push(&self, value: &T) -> bool {
+        self.state.push(value)
+    }
+
+    /// See [`Queue::pop()`]
+    pub fn pop(&self) -> Option<T> {
+        self.state.pop()
+    }
+
+    /// See [`Queue::is_empty()`]
+    pub fn is_empty(&self) -> bool {
+        self.state.is_empty()
+    }
+
+    /// See [`Queue::len()`]
+    pub fn len(&self) -> usize {
+        self.state.len()
+    }
+
+    /// See [`Queue::capacity()`]
+    pub const fn capacity(&self) -> usize {
+        self.state.capacity()
+    }
+
+    /// See [`Queue::is_full()`]
+    pub fn is_full(&self) -> bool {
+        self.state.is_full()
+    }
+}
@@ -227,6 +227,20 @@ impl RobustUniqueIndexSet {
         self.generation_counter.load(Ordering::Relaxed) == GENERATION_COUNTER_LOCK_INDICATOR
     }
 
+    /// Locks the [`RobustUniqueIndexSet`] when no index is borrowed and returns
+    /// [`ReleaseState::Locked`]. If at least one index is still borrowed, the set stays
+    /// unlocked and [`ReleaseState::Unlocked`] is returned. A locked set cannot be unlocked
+    /// anymore.
+    ///
+    /// # Safety
+    ///
+    ///  * Ensure that [`RobustUniqueIndexSet::init()`] was called before calling this method
+    ///
+    pub unsafe fn lock_if_empty(&self) -> ReleaseState {
+        self.verify_init("lock_if_empty()");
+        self.lock()
+    }
+
     fn lock(&self) -> ReleaseState {
         if self.is_locked() {
             return ReleaseState::Locked;
@@ -655,6 +669,11 @@ impl<const CAPACITY: usize> StaticRobustUniqueIndexSet<CAPACITY> {
         self.state.is_locked()
     }
 
+    /// See [`RobustUniqueIndexSet::lock_if_empty()`]
+    pub fn lock_if_empty(&self) -> ReleaseState {
+        unsafe { self.state.lock_if_empty() }
+    }
+
     /// Returns the current len.
     pub fn borrowed_indices(&self) -> usize {
         self.state.borrowed_indices()
@@ -638,6 +638,21 @@ pub mod generic {
         assert_that!(sut.is_locked(), eq true);
     }
 
+    #[test]
+    pub fn lock_if_empty_locks_only_empty_container<T: Debug + Copy + From<usize> + Into<usize>>() {
+        let sut = FixedSizeContainer::<T, CAPACITY>::new();
+        let owner_id = OwnerId::new(3).unwrap();
+
+        let handle = sut.add(0.into(), owner_id).unwrap();
+        assert_that!(sut.lock_if_empty(), eq ReleaseState::Unlocked);
+        assert_that!(sut.is_locked(), eq false);
+
+        assert_that!(unsafe { sut.remove(handle, ReleaseMode::Default) }, is_ok);
+        assert_that!(sut.lock_if_empty(), eq ReleaseState::Locked);
+        assert_that!(sut.is_locked(), eq true);
+        assert_that!(sut.add(1.into(), owner_id), is_err);
+    }
+
     #[test]
     pub fn recover_provides_content_in_predicate_argument<
         T: Debug + Copy + From<usize> + Into<usize>,
@@ -0,0 +1,285 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_testing_macros::tests;
+
+#[tests(usize, TestType)]
+pub mod generic {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::fmt::Debug;
+
+    use iceoryx2_bb_concurrency::atomic::{AtomicUsize, Ordering};
+    use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+    use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+    use iceoryx2_bb_lock_free::mpmc::queue::*;
+    use iceoryx2_bb_posix::barrier::{BarrierBuilder, BarrierHandle, Handle};
+    use iceoryx2_bb_posix::mutex::{MutexBuilder, MutexHandle};
+    use iceoryx2_bb_posix::scheduler::yield_now;
+    use iceoryx2_bb_posix::system_configuration::SystemInfo;
+    use iceoryx2_bb_posix::thread::thread_scope;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord)]
+    pub struct TestType {
+        some_numbers: [usize; 32],
+    }
+
+    impl From<usize> for TestType {
+        fn from(value: usize) -> Self {
+            TestType {
+                some_numbers: {
+                    let mut n = [0usize; 32];
+                    n.iter_mut().enumerate().for_each(|(i, elem)| {
+                        *elem = value + i;
+                    });
+                    n
+                },
+            }
+        }
+    }
+
+    impl From<TestType> for usize {
+        fn from(value: TestType) -> Self {
+            for (i, &num) in value.some_numbers.iter().enumerate() {
+                assert_that!(num, eq value.some_numbers[0] + i);
+            }
+            value.some_numbers[0]
+        }
+    }
+
+    const CAPACITY: usize = 129;
+
+    #[test]
+    pub fn push_works_until_full<T: Debug + Copy + From<usize> + Into<usize> + Send>() {
+        let sut = FixedSizeQueue::<T, CAPACITY>::new();
+
+        assert_that!(sut.capacity(), eq CAPACITY);
+        assert_that!(sut, len 0);
+        assert_that!(sut.is_full(), eq false);
+        assert_that!(sut, is_empty);
+
+        for i in 0..CAPACITY {
+            assert_that!(sut, len i);
+            assert_that!(sut.push(&i.into()), eq true);
+        }
+        assert_that!(sut.push(&1234.into()), eq false);
+
+        assert_that!(sut, len CAPACITY);
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(sut, is_not_empty);
+    }
+
+    #[test]
+    pub fn pop_returns_values_in_fifo_order_until_empty<
+        T: Debug + Copy + From<usize> + Into<usize> + Send,
+    >() {
+        let sut = FixedSizeQueue::<T, CAPACITY>::new();
+        for i in 0..CAPACITY {
+            assert_that!(sut.push(&(i * 3 + 1).into()), eq true);
+        }
+
+        for i in 0..CAPACITY {
+            assert_that!(sut, len CAPACITY - i);
+            let result = sut.pop();
+            assert_that!(result, is_some);
+            assert_that!(result.unwrap().into(), eq i * 3 + 1);
+        }
+        assert_that!(sut.pop(), is_none);
+
+        assert_that!(sut, len 0);
+        assert_that!(sut.is_full(), eq false);
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    pub fn push_pop_alternation_works<T: Debug + Copy + From<usize> + Into<usize> + Send>() {
+        let sut = FixedSizeQueue::<T, CAPACITY>::new();
+
+        for i in 0..CAPACITY - 1 {
+            assert_that!(sut.push(&i.into()), eq true);
+            assert_that!(sut.push(&i.into()), eq true);
+
+            assert_that!(sut.pop().unwrap().into(), eq i / 2);
+        }
+    }
+
+    #[test]
+    pub fn queue_with_capacity_one_works_over_multiple_rounds<
+        T: Debug + Copy + From<usize> + Into<usize> + Send,
+    >() {
+        let sut = FixedSizeQueue::<T, 1>::new();
+
+        for i in 0..CAPACITY {
+            assert_that!(sut.push(&i.into()), eq true);
+            assert_that!(sut.push(&(i + 1).into()), eq false);
+            assert_that!(sut.is_full(), eq true);
+
+            assert_that!(sut.pop().unwrap().into(), eq i);
+            assert_that!(sut.pop(), is_none);
+            assert_that!(sut, is_empty);
+        }
+    }
+
+    #[test]
+    pub fn queue_with_runtime_capacity_works<T: Debug + Copy + From<usize> + Into<usize> + Send>() {
+        let sut = Queue::<T>::new(CAPACITY);
+        assert_that!(sut.capacity(), eq CAPACITY);
+
+        for round in 0..3 {
+            for i in 0..CAPACITY {
+                assert_that!(sut.push(&(round * CAPACITY + i).into()), eq true);
+            }
+            assert_that!(sut.push(&0.into()), eq false);
+
+            for i in 0..CAPACITY {
+                assert_that!(sut.pop().unwrap().into(), eq round * CAPACITY + i);
+            }
+            assert_that!(sut.pop(), is_none);
+        }
+    }
+
+    #[should_panic]
+    #[test]
+    pub fn queue_with_capacity_zero_panics<T: Debug + Copy + From<usize> + Into<usize> + Send>() {
+        let _sut = Queue::<T>::new(0);
+    }
+
+    #[should_panic]
+    #[test]
+    pub fn relocatable_queue_with_capacity_zero_panics<
+        T: Debug + Copy + From<usize> + Into<usize> + Send,
+    >() {
+        let _sut = unsafe { RelocatableQueue::<T>::new_uninit(0) };
+    }
+
+    #[test]
+    pub fn relocatable_queue_works_with_uninitialized_memory<
+        T: Debug + Copy + From<usize> + Into<usize> + Send,
+    >() {
+        // TestType is the largest test type so it is safe to acquire this memory for every test
+        // case - hack required since `T` cannot be used in const operations
+        let mut memory = [0u8; RelocatableQueue::<TestType>::const_memory_size(129_usize)];
+        let allocator = BumpAllocator::new(memory.as_mut_ptr());
+        let mut sut = unsafe { RelocatableQueue::<T>::new_uninit(CAPACITY) };
+        unsafe { assert_that!(sut.init(&allocator), is_ok) };
+
+        for i in 0..CAPACITY {
+            assert_that!(sut.push(&(i * 7 + 5).into()), eq true);
+        }
+        assert_that!(sut.push(&0.into()), eq false);
+
+        for i in 0..CAPACITY {
+            assert_that!(sut.pop().unwrap().into(), eq i * 7 + 5);
+        }
+        assert_that!(sut.pop(), is_none);
+    }
+
+    fn concurrent_push_pop_delivers_every_value_exactly_once<
+        T: Debug + Copy + From<usize> + Into<usize> + Send,
+        const QUEUE_CAPACITY: usize,
+    >() {
+        let _watchdog = Watchdog::new();
+        const VALUES_PER_PRODUCER: usize = 50000;
+        let number_of_threads_per_op = (SystemInfo::NumberOfCpuCores.value()).clamp(2, 8);
+        let number_of_values = number_of_threads_per_op * VALUES_PER_PRODUCER;
+
+        let sut = FixedSizeQueue::<T, QUEUE_CAPACITY>::new();
+        let barrier_handle = BarrierHandle::new();
+        let barrier = BarrierBuilder::new((number_of_threads_per_op * 2) as u32)
+            .create(&barrier_handle)
+            .unwrap();
+
+        let received_handle = MutexHandle::<Vec<usize>>::new();
+        let received = MutexBuilder::new()
+            .create(vec![], &received_handle)
+            .expect("failed to create mutex");
+        let number_of_received_values = AtomicUsize::new(0);
+
+        thread_scope(|s| {
+            for producer_id in 0..number_of_threads_per_op {
+                let sut = &sut;
+                let barrier = &barrier;
+                s.thread_builder()
+                    .spawn(move || {
+                        barrier.wait();
+                        for counter in 0..VALUES_PER_PRODUCER {
+                            let value = counter * number_of_threads_per_op + producer_id;
+                            while !sut.push(&value.into()) {
+                                yield_now();
+                            }
+                        }
+                    })
+                    .expect("failed to spawn thread");
+            }
+
+            for _ in 0..number_of_threads_per_op {
+                s.thread_builder()
+                    .spawn(|| {
+                        // values of one producer must arrive in the order they were pushed
+                        let mut last_counter_of_producer = vec![None; number_of_threads_per_op];
+                        let mut values = vec![];
+
+                        barrier.wait();
+                        while number_of_received_values.load(Ordering::Relaxed) < number_of_values {
+                            if let Some(value) = sut.pop() {
+                                let value: usize = value.into();
+                                let producer_id = value % number_of_threads_per_op;
+                                let counter = value / number_of_threads_per_op;
+                                if let Some(last_counter) = last_counter_of_producer[producer_id] {
+                                    assert_that!(counter, gt last_counter);
+                                }
+                                last_counter_of_producer[producer_id] = Some(counter);
+
+                                values.push(value);
+                                number_of_received_values.fetch_add(1, Ordering::Relaxed);
+                            } else {
+                                yield_now();
+                            }
+                        }
+
+                        received
+                            .lock()
+                            .expect("failed to lock mutex")
+                            .append(&mut values);
+                    })
+                    .expect("failed to spawn thread");
+            }
+
+            Ok(())
+        })
+        .expect("failed to run thread scope");
+
+        let mut received = received.lock().expect("failed to lock mutex");
+        received.sort();
+        assert_that!(*received, len number_of_values);
+        for (i, value) in received.iter().enumerate() {
+            assert_that!(*value, eq i);
+        }
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    pub fn concurrent_push_pop_with_large_capacity_delivers_every_value_exactly_once<
+        T: Debug + Copy + From<usize> + Into<usize> + Send,
+    >() {
+        concurrent_push_pop_delivers_every_value_exactly_once::<T, CAPACITY>();
+    }
+
+    #[test]
+    pub fn concurrent_push_pop_with_small_capacity_delivers_every_value_exactly_once<
+        T: Debug + Copy + From<usize> + Into<usize> + Send,
+    >() {
+        concurrent_push_pop_delivers_every_value_exactly_once::<T, 2>();
+    }
+}
@@ -151,6 +151,30 @@ pub fn release_not_last_index_and_set_release_mode_to_locked_does_not_lock_the_s
     assert_that!(sut.is_locked(), eq false);
 }
 
+#[test]
+pub fn lock_if_empty_locks_empty_set() {
+    let sut = StaticRobustUniqueIndexSet::<CAPACITY>::new();
+
+    assert_that!(sut.lock_if_empty(), eq ReleaseState::Locked);
+    assert_that!(sut.is_locked(), eq true);
+    assert_that!(sut.acquire(OwnerId::new(123).unwrap()), is_err);
+}
+
+#[test]
+pub fn lock_if_empty_does_not_lock_set_with_borrowed_indices() {
+    let sut = StaticRobustUniqueIndexSet::<CAPACITY>::new();
+
+    let owner_id = OwnerId::new(123).unwrap();
+    let index = sut.acquire(owner_id).unwrap();
+
+    assert_that!(sut.lock_if_empty(), eq ReleaseState::Unlocked);
+    assert_that!(sut.is_locked(), eq false);
+
+    assert_that!(sut.release(index, owner_id, ReleaseMode::Default), eq Ok(ReleaseState::Unlocked));
+    assert_that!(sut.lock_if_empty(), eq ReleaseState::Locked);
+    assert_that!(sut.is_locked(), eq true);
+}
+
 #[test]
 pub fn releasing_non_owned_index_fails() {
     let sut = StaticRobustUniqueIndexSet::<CAPACITY>::new();
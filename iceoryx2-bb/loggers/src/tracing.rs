@@ -10,8 +10,18 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+//! Links the [`tracing`] spans of different processes via their OpenTelemetry context, it
+//! requires that the [`tracing_opentelemetry`] layer is installed. Sent messages carry the
+//! [`TraceContext`] of the current span. The [`TraceContext`] of a received message becomes the
+//! parent of the current span when the span was not started yet, e.g. when the layer is created
+//! with `with_context_activation(false)`, otherwise the current span is linked to it.
+
 use iceoryx2_log_types::Log;
 use iceoryx2_log_types::LogLevel;
+use iceoryx2_log_types::TraceContext;
+use opentelemetry::Context;
+use opentelemetry::trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 
 pub struct Logger {
     _priv: (),
@@ -39,4 +49,32 @@ impl Log for Logger {
             LogLevel::Fatal => tracing::error!(origin, "{}", formatted_message),
         }
     }
+
+    fn current_trace_context(&self) -> Option<TraceContext> {
+        let context = tracing::Span::current().context();
+        let span = context.span();
+        let span_context = span.span_context();
+
+        span_context.is_valid().then(|| TraceContext {
+            trace_id: span_context.trace_id().to_bytes(),
+            span_id: span_context.span_id().to_bytes(),
+            trace_flags: span_context.trace_flags().to_u8(),
+        })
+    }
+
+    fn receive_trace_context(&self, trace_context: &TraceContext) {
+        let remote_span_context = SpanContext::new(
+            TraceId::from_bytes(trace_context.trace_id),
+            SpanId::from_bytes(trace_context.span_id),
+            TraceFlags::new(trace_context.trace_flags),
+            true,
+            TraceState::default(),
+        );
+
+        let span = tracing::Span::current();
+        let parent = Context::new().with_remote_span_context(remote_span_context.clone());
+        if span.set_parent(parent).is_err() {
+            span.add_link(remote_span_context);
+        }
+    }
 }
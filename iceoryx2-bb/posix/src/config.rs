@@ -45,6 +45,11 @@ pub const TEST_DIRECTORY: Path =
     unsafe { Path::new_unchecked_const(iceoryx2_pal_configuration::TEST_DIRECTORY) };
 pub const SHARED_MEMORY_DIRECTORY: Path =
     unsafe { Path::new_unchecked_const(iceoryx2_pal_configuration::SHARED_MEMORY_DIRECTORY) };
+/// The mount point of the `hugetlbfs` in which shared memory backed by huge pages is created.
+pub const HUGE_PAGE_DIRECTORY: Path = unsafe { Path::new_unchecked_const(b"/dev/hugepages/") };
+/// The prefix of every shared memory file in the [`HUGE_PAGE_DIRECTORY`]. It distinguishes the
+/// shared memory of this crate from other files in the `hugetlbfs`.
+pub const HUGE_PAGE_FILE_PREFIX: &[u8] = b"iox2_shm_";
 
 // TODO unable to verify?
 pub const ACL_LIST_CAPACITY: u32 = 25;
@@ -98,9 +98,12 @@ pub use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::enum_gen;
 pub use iceoryx2_bb_system_types::file_path::FilePath;
 
-use crate::{file_descriptor::FileDescriptor, system_configuration::SystemInfo};
-use iceoryx2_log::{fail, fatal_panic, trace};
-use iceoryx2_pal_posix::posix::{self, Errno, MAP_FAILED};
+use crate::file_descriptor::{FileDescriptor, FileDescriptorManagement};
+use crate::memory_lock::{MemoryLock, MemoryLockCreationError};
+use crate::signal::SignalHandler;
+use crate::system_configuration::SystemInfo;
+use iceoryx2_log::{fail, fatal_panic, trace, warn};
+use iceoryx2_pal_posix::posix::{self, Errno, MAP_FAILED, POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING};
 
 enum_gen! {
     /// Error that can occur when a new [`MemoryMapping`] is created with [`MemoryMappingBuilder::create()`].
@@ -123,6 +126,8 @@ enum_gen! {
     FileTooBig,
     OpenReturnedBrokedFileDescriptor,
     UnknownFailure(i32)
+  mapping:
+    MemoryLockCreationError
 }
 
 enum_gen! {
@@ -198,6 +203,9 @@ struct MemoryMappingBuilderSettings {
     size: usize,
     offset: isize,
     enforce_address_hint: bool,
+    use_huge_pages: bool,
+    is_memory_locked: bool,
+    prefault: bool,
 }
 
 impl MemoryMappingBuilderSettings {
@@ -209,6 +217,9 @@ impl MemoryMappingBuilderSettings {
             enforce_address_hint: false,
             offset: 0,
             size: 0,
+            use_huge_pages: false,
+            is_memory_locked: false,
+            prefault: false,
         }
     }
 }
@@ -326,6 +337,30 @@ impl MemoryMappingBuilder {
         self
     }
 
+    /// Requests that the [`MemoryMapping`] is backed by huge pages (`MAP_HUGETLB`). If the
+    /// platform does not support huge pages, no huge pages are reserved or the underlying
+    /// file does not support them, the [`MemoryMapping`] falls back to regular pages. File based
+    /// mappings support huge pages only when the file resides on a `hugetlbfs`. Use
+    /// [`MemoryMapping::has_huge_pages()`] to verify the outcome.
+    pub fn use_huge_pages(mut self, value: bool) -> Self {
+        self.settings.use_huge_pages = value;
+        self
+    }
+
+    /// Locks the [`MemoryMapping`] into the RAM so that it cannot be swapped. The
+    /// lock is released when the [`MemoryMapping`] goes out of scope.
+    pub fn is_memory_locked(mut self, value: bool) -> Self {
+        self.settings.is_memory_locked = value;
+        self
+    }
+
+    /// Touches every page of the [`MemoryMapping`] after it was created so that no page
+    /// fault occurs on the first access later on.
+    pub fn prefault(mut self, value: bool) -> Self {
+        self.settings.prefault = value;
+        self
+    }
+
     /// Tries to create a new [`MemoryMapping`].
     pub fn create(self) -> Result<MemoryMapping, MemoryMappingCreationError> {
         match self.origin {
@@ -437,17 +472,42 @@ impl MemoryMappingBuilder {
                 "{msg} since the size must be greater than 0.");
         }
 
-        let ret_val = unsafe {
+        let mmap = |size: usize, flags: i32| unsafe {
             posix::mmap(
                 settings.address_hint as *mut posix::void,
-                settings.size,
+                size,
                 settings.mapping_permission as _,
-                mapping_behavior,
+                flags,
                 fd_value,
                 settings.offset as _,
             )
         };
 
+        let mut has_huge_pages = false;
+        let mut mapped_size = settings.size;
+        let mut ret_val = MAP_FAILED;
+        if settings.use_huge_pages {
+            if posix::POSIX_SUPPORT_HUGE_PAGES {
+                // munmap fails for huge page mappings whose length is not aligned to the huge
+                // page size
+                if let Some(huge_page_size) = Self::huge_page_size(file_descriptor.as_ref()) {
+                    mapped_size = settings.size.next_multiple_of(huge_page_size);
+                    ret_val = mmap(mapped_size, mapping_behavior | posix::MAP_HUGETLB);
+                    has_huge_pages = ret_val != MAP_FAILED;
+                }
+            }
+
+            if !has_huge_pages {
+                warn!(from settings,
+                    "Huge pages are not available on this system or for this mapping, falling back to regular pages.");
+            }
+        }
+
+        if !has_huge_pages {
+            mapped_size = settings.size;
+            ret_val = mmap(mapped_size, mapping_behavior);
+        }
+
         if ret_val == MAP_FAILED {
             match Errno::get() {
                 Errno::EACCES => {
@@ -477,12 +537,15 @@ impl MemoryMappingBuilder {
             }
         }
 
-        let mapping = MemoryMapping {
+        let mut mapping = MemoryMapping {
             file_descriptor,
             file_path,
             base_address: ret_val.cast(),
             size: settings.size,
+            mapped_size,
             offset: settings.offset,
+            has_huge_pages,
+            memory_lock: None,
         };
 
         if settings.enforce_address_hint && ret_val as usize != settings.address_hint {
@@ -491,10 +554,79 @@ impl MemoryMappingBuilder {
                 settings.address_hint);
         }
 
+        if settings.is_memory_locked {
+            mapping.memory_lock = Some(
+                fail!(from settings, when unsafe { MemoryLock::new(mapping.base_address.cast(), mapping.mapped_size) },
+                    "{msg} since the memory could not be locked."),
+            );
+        }
+
+        if settings.prefault {
+            let base_address = mapping.base_address;
+            let touch_pages = || {
+                for offset in (0..settings.size).step_by(SystemInfo::PageSize.value()) {
+                    unsafe { base_address.add(offset).read_volatile() };
+                }
+            };
+
+            if POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING {
+                if let Some(v) = SignalHandler::call_and_fetch(touch_pages) {
+                    fail!(from settings, with MemoryMappingCreationError::InsufficientResources,
+                        "{msg} since a signal {v} was raised while prefaulting the memory. Is enough memory available on the system?");
+                }
+            } else {
+                touch_pages();
+            }
+        }
+
         trace!(from mapping, "mapped");
 
         Ok(mapping)
     }
+
+    /// Returns the huge page size of the file system the file descriptor resides on or the
+    /// default huge page size of the system for anonymous mappings.
+    fn huge_page_size(file_descriptor: Option<&FileDescriptor>) -> Option<usize> {
+        match file_descriptor {
+            Some(fd) => match fd.metadata() {
+                Ok(metadata) if metadata.block_size() > 0 => Some(metadata.block_size() as usize),
+                _ => None,
+            },
+            None => Self::default_huge_page_size(),
+        }
+    }
+
+    fn default_huge_page_size() -> Option<usize> {
+        let fd = unsafe { posix::open(c"/proc/meminfo".as_ptr(), posix::O_RDONLY) };
+        if fd < 0 {
+            return None;
+        }
+
+        let mut buffer = [0u8; 4096];
+        let number_of_bytes =
+            unsafe { posix::read(fd, buffer.as_mut_ptr().cast(), buffer.len() - 1) };
+        unsafe { posix::close(fd) };
+
+        if number_of_bytes <= 0 {
+            return None;
+        }
+
+        const MEMINFO_ENTRY: &str = "Hugepagesize:";
+        let meminfo = core::str::from_utf8(&buffer[..number_of_bytes as usize]).ok()?;
+        let size_in_kb: usize = meminfo
+            .lines()
+            .find(|l| l.starts_with(MEMINFO_ENTRY))?
+            .trim_start_matches(MEMINFO_ENTRY)
+            .split_whitespace()
+            .next()?
+            .parse()
+            .ok()?;
+
+        match size_in_kb {
+            0 => None,
+            v => Some(v * 1024),
+        }
+    }
 }
 
 /// A memory mapping that was created with [`MemoryMappingBuilder`]. Abstraction
@@ -507,12 +639,17 @@ pub struct MemoryMapping {
     file_path: Option<FilePath>,
     base_address: *mut u8,
     size: usize,
+    mapped_size: usize,
     offset: isize,
+    has_huge_pages: bool,
+    memory_lock: Option<MemoryLock>,
 }
 
 impl Drop for MemoryMapping {
     fn drop(&mut self) {
-        if unsafe { posix::munmap(self.base_address.cast(), self.size) } == -1 {
+        // the memory must be unlocked before it is unmapped
+        self.memory_lock.take();
+        if unsafe { posix::munmap(self.base_address.cast(), self.mapped_size) } == -1 {
             fatal_panic!(from self,
                 "This should never happen! Unable to unmap a mapped memory region.");
         }
@@ -521,6 +658,12 @@ impl Drop for MemoryMapping {
 }
 
 impl MemoryMapping {
+    /// Returns true if the platform honors a [`MappingPermission`] without write access so
+    /// that every write access causes a fault.
+    pub fn does_support_read_only_mapping() -> bool {
+        posix::POSIX_SUPPORT_READ_ONLY_MAPPING
+    }
+
     /// Updates the permissions of a region of the [`MemoryMapping`]. The start
     /// offset must be a multiple of page size.
     pub fn set_permission(&mut self, region_offset: usize) -> ProtectBuilder {
@@ -563,6 +706,16 @@ impl MemoryMapping {
         &self.file_path
     }
 
+    /// Returns true if the [`MemoryMapping`] is backed by huge pages, otherwise false.
+    pub fn has_huge_pages(&self) -> bool {
+        self.has_huge_pages
+    }
+
+    /// Returns true if the [`MemoryMapping`] is locked into the RAM, otherwise false.
+    pub fn is_memory_locked(&self) -> bool {
+        self.memory_lock.is_some()
+    }
+
     /// Returns the size of the [`MemoryMapping`]
     pub fn size(&self) -> usize {
         self.size
@@ -59,7 +59,8 @@ impl Metadata {
         self.size
     }
 
-    /// returns the block size of the file. the size which the file occupies on the file system.
+    /// returns the preferred block size for I/O of the file system the file resides on. On a
+    /// `hugetlbfs` it is the size of a huge page.
     pub fn block_size(&self) -> u64 {
         self.block_size
     }
@@ -102,7 +103,7 @@ impl Metadata {
                 .seconds(attr.st_ctime as u64)
                 .create(),
             size: attr.st_size as u64,
-            block_size: attr.st_size as u64,
+            block_size: attr.st_blksize as u64,
             device_id: attr.st_rdev as _,
             modification_time: TimeBuilder::new()
                 .clock_type(ClockType::Realtime)
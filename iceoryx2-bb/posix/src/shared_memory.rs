@@ -83,15 +83,20 @@ use iceoryx2_bb_system_types::path::*;
 use iceoryx2_log::{error, fail, fatal_panic, trace, warn};
 use iceoryx2_pal_configuration::PATH_SEPARATOR;
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING;
+use iceoryx2_pal_posix::posix::POSIX_SUPPORT_HUGE_PAGES;
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY;
+use iceoryx2_pal_posix::posix::POSIX_SUPPORT_READ_ONLY_MAPPING;
 use iceoryx2_pal_posix::posix::errno::Errno;
 use iceoryx2_pal_posix::*;
 
 pub use crate::access_mode::AccessMode;
+use crate::config::{HUGE_PAGE_DIRECTORY, HUGE_PAGE_FILE_PREFIX};
 pub use crate::creation_mode::CreationMode;
+use crate::directory::Directory;
 use crate::file::{FileStatError, FileTruncateError};
 use crate::file_descriptor::*;
-use crate::memory_lock::{MemoryLock, MemoryLockCreationError};
+use crate::file_type::FileType;
+use crate::memory_lock::MemoryLockCreationError;
 use crate::memory_mapping::{
     MappingBehavior, MemoryMapping, MemoryMappingBuilder, MemoryMappingCreationError,
 };
@@ -141,6 +146,8 @@ pub struct SharedMemoryBuilder {
     access_mode: AccessMode,
     mapping_offset: isize,
     enforce_base_address: Option<u64>,
+    prefault: bool,
+    use_huge_pages: bool,
 }
 
 impl SharedMemoryBuilder {
@@ -156,6 +163,8 @@ impl SharedMemoryBuilder {
             zero_memory: true,
             mapping_offset: 0,
             enforce_base_address: None,
+            prefault: false,
+            use_huge_pages: false,
         }
     }
 
@@ -167,12 +176,30 @@ impl SharedMemoryBuilder {
     }
 
     /// Locks the shared memory into the heap. If this is enabled swapping of the
-    /// created shared memory segment is no longer possible.
+    /// created or opened shared memory segment is no longer possible.
     pub fn is_memory_locked(mut self, value: bool) -> Self {
         self.is_memory_locked = value;
         self
     }
 
+    /// Touches every page of the shared memory when it is created or opened so that no page
+    /// fault occurs on the first access later on.
+    pub fn prefault(mut self, value: bool) -> Self {
+        self.prefault = value;
+        self
+    }
+
+    /// Requests that a newly created shared memory is backed by huge pages. It is then created
+    /// in the `hugetlbfs` mounted at [`HUGE_PAGE_DIRECTORY`], prefixed with
+    /// [`HUGE_PAGE_FILE_PREFIX`], and its size is rounded up to a
+    /// multiple of the huge page size. If huge pages are not available, regular pages are used
+    /// instead, see [`SharedMemory::has_huge_pages()`]. When an existing shared memory is opened,
+    /// it is backed by huge pages whenever its creator used them.
+    pub fn use_huge_pages(mut self, value: bool) -> Self {
+        self.use_huge_pages = value;
+        self
+    }
+
     /// Sets a base address for the shared memory which is enforced. When the shared memory
     /// could not mapped at the provided address the creation fails.
     pub fn enforce_base_address(mut self, value: u64) -> Self {
@@ -192,6 +219,7 @@ impl SharedMemoryBuilder {
     fn create_memory_mapping(
         file_descriptor: FileDescriptor,
         config: &SharedMemoryBuilder,
+        has_huge_pages: bool,
     ) -> Result<MemoryMapping, SharedMemoryCreationError> {
         match MemoryMappingBuilder::from_file_descriptor(file_descriptor)
             .mapping_behavior(MappingBehavior::Shared)
@@ -200,6 +228,9 @@ impl SharedMemoryBuilder {
             .enforce_mapping_address_hint(config.enforce_base_address.is_some())
             .offset(config.mapping_offset)
             .size(config.size)
+            .is_memory_locked(config.is_memory_locked)
+            .prefault(config.prefault)
+            .use_huge_pages(has_huge_pages)
             .create()
         {
             Ok(mapping) => Ok(mapping),
@@ -212,19 +243,18 @@ impl SharedMemoryBuilder {
 
     fn open(mut self) -> Result<SharedMemory, SharedMemoryCreationError> {
         let msg = "Unable to open shared memory";
-        let fd = SharedMemory::shm_open(&self.name, &self)?;
+        let (fd, has_huge_pages) = SharedMemory::open_file_descriptor(&self.name, &self)?;
 
         let actual_shm_size = fail!(from self, when fd.metadata(),
                 "{} since a failure occurred while acquiring the file attributes.", msg)
         .size();
         self.size = actual_shm_size as usize;
 
-        let memory_mapping = Self::create_memory_mapping(fd, &self)?;
+        let memory_mapping = Self::create_memory_mapping(fd, &self, has_huge_pages)?;
 
         let shm = SharedMemory {
             name: self.name,
             has_ownership: AtomicBool::new(false),
-            memory_lock: None,
             memory_mapping,
             mapping_offset: self.mapping_offset,
         };
@@ -286,7 +316,14 @@ impl SharedMemoryCreationBuilder {
                 "{msg} since a size of 0 is not supported for a shared memory object.");
         }
 
+        if self.config.use_huge_pages && POSIX_SUPPORT_HUGE_PAGES {
+            if let Some(shm) = self.create_with_huge_pages()? {
+                return Ok(shm);
+            }
+        }
+
         let shm_created;
+        let mut has_huge_pages = false;
         let mut fd = match self
             .config
             .creation_mode
@@ -294,18 +331,25 @@ impl SharedMemoryCreationBuilder {
         {
             CreationMode::CreateExclusive => {
                 shm_created = true;
+                if POSIX_SUPPORT_HUGE_PAGES
+                    && SharedMemory::does_huge_page_file_exist(&self.config.name)
+                {
+                    fail!(from self.config, with SharedMemoryCreationError::AlreadyExist,
+                        "{} since it already exists.", msg);
+                }
                 SharedMemory::shm_create(&self.config.name, &self.config)?
             }
             CreationMode::PurgeAndCreate => {
                 shm_created = true;
-                fail!(from self.config, when SharedMemory::shm_unlink(&self.config.name),
+                fail!(from self.config, when SharedMemory::remove(&self.config.name),
                     "Failed to remove already existing shared memory.");
                 SharedMemory::shm_create(&self.config.name, &self.config)?
             }
             CreationMode::OpenOrCreate => {
-                match SharedMemory::shm_open(&self.config.name, &self.config) {
-                    Ok(fd) => {
+                match SharedMemory::open_file_descriptor(&self.config.name, &self.config) {
+                    Ok((fd, is_huge_page_file)) => {
                         shm_created = false;
+                        has_huge_pages = is_huge_page_file;
                         self.config.has_ownership = false;
                         fd
                     }
@@ -334,12 +378,12 @@ impl SharedMemoryCreationBuilder {
             }
 
             self.config.size = actual_shm_size as _;
-            let memory_mapping = SharedMemoryBuilder::create_memory_mapping(fd, &self.config)?;
+            let memory_mapping =
+                SharedMemoryBuilder::create_memory_mapping(fd, &self.config, has_huge_pages)?;
 
             let shm = SharedMemory {
                 name: self.config.name,
                 has_ownership: AtomicBool::new(self.config.has_ownership),
-                memory_lock: None,
                 memory_mapping,
                 mapping_offset: self.config.mapping_offset,
             };
@@ -359,23 +403,118 @@ impl SharedMemoryCreationBuilder {
         }
 
         self.config.size = actual_shm_size as _;
-        let memory_mapping = SharedMemoryBuilder::create_memory_mapping(fd, &self.config)?;
+        let memory_mapping = SharedMemoryBuilder::create_memory_mapping(fd, &self.config, false)?;
 
         let mut shm = SharedMemory {
             name: self.config.name,
             has_ownership: AtomicBool::new(self.config.has_ownership),
-            memory_lock: None,
             memory_mapping,
             mapping_offset: self.config.mapping_offset,
         };
 
-        if self.config.is_memory_locked {
-            shm.memory_lock = Some(
-                fail!(from self.config, when unsafe { MemoryLock::new(shm.memory_mapping.base_address().cast(), shm.memory_mapping.size()) },
-                        "{} since the memory lock failed.", msg),
-            )
+        self.zero_memory_of(&mut shm)?;
+
+        trace!(from shm, "created");
+        Ok(shm)
+    }
+
+    /// Creates the shared memory in the `hugetlbfs`. Returns [`None`] when an already existing
+    /// regular shared memory shall be opened or when huge pages are not available, so that the
+    /// caller falls back to regular pages.
+    fn create_with_huge_pages(
+        &mut self,
+    ) -> Result<Option<SharedMemory>, SharedMemoryCreationError> {
+        let msg = "Unable to create shared memory with huge pages";
+        let name = self.config.name;
+
+        match self
+            .config
+            .creation_mode
+            .expect("CreationMode must be set on creation")
+        {
+            CreationMode::CreateExclusive => {
+                if SharedMemory::does_exist(&name) {
+                    fail!(from self.config, with SharedMemoryCreationError::AlreadyExist,
+                        "{} since it already exists.", msg);
+                }
+            }
+            CreationMode::PurgeAndCreate => {
+                fail!(from self.config, when SharedMemory::remove(&name),
+                    "Failed to remove already existing shared memory.");
+            }
+            CreationMode::OpenOrCreate => {
+                if SharedMemory::does_exist(&name) {
+                    return Ok(None);
+                }
+            }
         }
 
+        let mut fd = match SharedMemory::huge_page_file_create(&name, &self.config) {
+            Ok(fd) => fd,
+            Err(SharedMemoryCreationError::AlreadyExist)
+                if self.config.creation_mode != Some(CreationMode::OpenOrCreate) =>
+            {
+                fail!(from self.config, with SharedMemoryCreationError::AlreadyExist,
+                    "{} since it already exists.", msg);
+            }
+            Err(SharedMemoryCreationError::AlreadyExist) => return Ok(None),
+            Err(e) => {
+                warn!(from self.config,
+                    "Huge pages are not available since the shared memory could not be created in {} ({:?}), falling back to regular pages.",
+                    HUGE_PAGE_DIRECTORY, e);
+                return Ok(None);
+            }
+        };
+
+        let huge_page_size = match fd.metadata() {
+            Ok(metadata) => (metadata.block_size() as usize).max(1),
+            Err(_) => {
+                self.discard_huge_page_file("the huge page size could not be acquired");
+                return Ok(None);
+            }
+        };
+
+        let size = self.config.size.next_multiple_of(huge_page_size);
+        if fd.truncate(size).is_err() {
+            self.discard_huge_page_file(
+                "the shared memory could not be resized to a multiple of the huge page size",
+            );
+            return Ok(None);
+        }
+
+        self.config.size = size;
+        let memory_mapping =
+            match SharedMemoryBuilder::create_memory_mapping(fd, &self.config, true) {
+                Ok(memory_mapping) => memory_mapping,
+                Err(_) => {
+                    self.discard_huge_page_file("no huge pages could be reserved for the mapping");
+                    return Ok(None);
+                }
+            };
+
+        let mut shm = SharedMemory {
+            name,
+            has_ownership: AtomicBool::new(self.config.has_ownership),
+            memory_mapping,
+            mapping_offset: self.config.mapping_offset,
+        };
+
+        self.zero_memory_of(&mut shm)?;
+
+        trace!(from shm, "created with huge pages of size {}", huge_page_size);
+        Ok(Some(shm))
+    }
+
+    fn discard_huge_page_file(&self, reason: &str) {
+        warn!(from self.config,
+            "Huge pages are not available since {}, falling back to regular pages.", reason);
+        if let Err(e) = SharedMemory::huge_page_file_unlink(&self.config.name) {
+            warn!(from self.config, "Unable to remove the huge page backed shared memory ({e:?}).");
+        }
+    }
+
+    fn zero_memory_of(&self, shm: &mut SharedMemory) -> Result<(), SharedMemoryCreationError> {
+        let msg = "Unable to create shared memory";
         if self.config.zero_memory {
             if POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING {
                 let memset_call = || unsafe {
@@ -403,8 +542,7 @@ impl SharedMemoryCreationBuilder {
             }
         }
 
-        trace!(from shm, "created");
-        Ok(shm)
+        Ok(())
     }
 }
 
@@ -414,7 +552,6 @@ pub struct SharedMemory {
     name: FileName,
     has_ownership: AtomicBool,
     memory_mapping: MemoryMapping,
-    memory_lock: Option<MemoryLock>,
     mapping_offset: isize,
 }
 
@@ -422,7 +559,6 @@ impl Abandonable for SharedMemory {
     unsafe fn abandon_in_place(mut this: NonNull<Self>) {
         let this = unsafe { this.as_mut() };
         unsafe { core::ptr::drop_in_place(&mut this.memory_mapping) };
-        unsafe { core::ptr::drop_in_place(&mut this.memory_lock) };
     }
 }
 
@@ -460,6 +596,7 @@ impl SharedMemory {
             )
         })
         .is_some()
+            || (POSIX_SUPPORT_HUGE_PAGES && Self::does_huge_page_file_exist(name))
     }
 
     /// Returns the mapping offset used when the shared memory object was mapped into process space
@@ -473,6 +610,12 @@ impl SharedMemory {
         POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY
     }
 
+    /// Returns true if the posix implementation maps shared memory that was opened with
+    /// [`AccessMode::Read`] read-only so that every write access causes a fault.
+    pub fn does_support_read_only_mapping() -> bool {
+        POSIX_SUPPORT_READ_ONLY_MAPPING
+    }
+
     /// Returns true if the shared memory object has the ownership of the underlying posix shared
     /// memory. Ownership implies hereby that the posix shared memory is removed as soon as this
     /// object goes out of scope.
@@ -494,7 +637,12 @@ impl SharedMemory {
 
     /// Removes a shared memory file.
     pub fn remove(name: &FileName) -> Result<bool, SharedMemoryRemoveError> {
-        match Self::shm_unlink(name) {
+        let result = match Self::shm_unlink(name) {
+            Ok(false) if POSIX_SUPPORT_HUGE_PAGES => Self::huge_page_file_unlink(name),
+            v => v,
+        };
+
+        match result {
             Ok(true) => {
                 trace!(from "SharedMemory::remove()", "\"{}\"", name);
                 Ok(true)
@@ -515,6 +663,21 @@ impl SharedMemory {
             }
         }
 
+        if POSIX_SUPPORT_HUGE_PAGES {
+            if let Ok(contents) = Directory::new(&HUGE_PAGE_DIRECTORY).map(|d| d.contents()) {
+                result.extend(
+                    contents
+                        .iter()
+                        .flatten()
+                        .filter(|entry| entry.metadata().file_type() == FileType::File)
+                        .filter_map(|entry| {
+                            entry.name().as_bytes().strip_prefix(HUGE_PAGE_FILE_PREFIX)
+                        })
+                        .filter_map(|name| FileName::new(name).ok()),
+                );
+            }
+        }
+
         result
     }
 
@@ -536,6 +699,17 @@ impl SharedMemory {
         }
     }
 
+    /// returns true if the shared memory is backed by huge pages of the `hugetlbfs`, otherwise
+    /// false
+    pub fn has_huge_pages(&self) -> bool {
+        self.memory_mapping.has_huge_pages()
+    }
+
+    /// returns true if the shared memory is locked into the RAM, otherwise false
+    pub fn is_memory_locked(&self) -> bool {
+        self.memory_mapping.is_memory_locked()
+    }
+
     /// returns the size of the shared memory
     pub fn size(&self) -> usize {
         self.memory_mapping.size()
@@ -611,6 +785,121 @@ impl SharedMemory {
         );
     }
 
+    fn open_file_descriptor(
+        name: &FileName,
+        config: &SharedMemoryBuilder,
+    ) -> Result<(FileDescriptor, bool), SharedMemoryCreationError> {
+        match Self::shm_open(name, config) {
+            Err(SharedMemoryCreationError::DoesNotExist) if POSIX_SUPPORT_HUGE_PAGES => {
+                Ok((Self::huge_page_file_open(name, config)?, true))
+            }
+            v => Ok((v?, false)),
+        }
+    }
+
+    fn huge_page_file_path(name: &FileName) -> Result<FilePath, SemanticStringError> {
+        let mut file_name = FileName::new(HUGE_PAGE_FILE_PREFIX)?;
+        file_name.push_bytes(name.as_bytes())?;
+        FilePath::from_path_and_file(&HUGE_PAGE_DIRECTORY, &file_name)
+    }
+
+    fn does_huge_page_file_exist(name: &FileName) -> bool {
+        match Self::huge_page_file_path(name) {
+            Ok(file_path) => FileDescriptor::new(unsafe {
+                posix::open(file_path.as_c_str(), AccessMode::Read.as_oflag())
+            })
+            .is_some(),
+            Err(_) => false,
+        }
+    }
+
+    fn huge_page_file_create(
+        name: &FileName,
+        config: &SharedMemoryBuilder,
+    ) -> Result<FileDescriptor, SharedMemoryCreationError> {
+        let msg = "Unable to create huge page backed shared memory";
+        let file_path = fail!(from config, when Self::huge_page_file_path(name),
+            with SharedMemoryCreationError::NameTooLong,
+            "{} since the name \"{}\" combined with the huge page directory {} exceeds the maximum supported path length.",
+            msg, name, HUGE_PAGE_DIRECTORY);
+
+        let fd = FileDescriptor::new(unsafe {
+            posix::open_with_mode(
+                file_path.as_c_str(),
+                CreationMode::CreateExclusive.as_oflag() | config.access_mode.as_oflag(),
+                config.permission.as_mode(),
+            )
+        });
+
+        if let Some(v) = fd {
+            return Ok(v);
+        }
+
+        handle_errno!(SharedMemoryCreationError, from config,
+            Errno::ENOENT => (DoesNotExist, "{} since the hugetlbfs is not mounted at {}.", msg, HUGE_PAGE_DIRECTORY),
+            Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+            Errno::EEXIST => (AlreadyExist, "{} since it already exists.", msg),
+            Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process file handle limit was reached.", msg),
+            Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system-wide file handle limit was reached.", msg),
+            Errno::ENAMETOOLONG => (NameTooLong, "{} since the name exceeds the maximum supported length of {}.", msg, Limit::MaxFileNameLength.value() ),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
+    fn huge_page_file_open(
+        name: &FileName,
+        config: &SharedMemoryBuilder,
+    ) -> Result<FileDescriptor, SharedMemoryCreationError> {
+        let msg = "Unable to open huge page backed shared memory";
+        let file_path = fail!(from config, when Self::huge_page_file_path(name),
+            with SharedMemoryCreationError::NameTooLong,
+            "{} since the name \"{}\" combined with the huge page directory {} exceeds the maximum supported path length.",
+            msg, name, HUGE_PAGE_DIRECTORY);
+
+        let fd = FileDescriptor::new(unsafe {
+            posix::open(file_path.as_c_str(), config.access_mode.as_oflag())
+        });
+
+        if let Some(v) = fd {
+            return Ok(v);
+        }
+
+        handle_errno!(SharedMemoryCreationError, from config,
+            Errno::ENOENT => (DoesNotExist, "{} since the shared memory does not exist.", msg),
+            Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+            Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process file handle limit was reached.", msg),
+            Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system-wide file handle limit was reached.", msg),
+            Errno::ENAMETOOLONG => (NameTooLong, "{} since the name exceeds the maximum supported length of {}.", msg, Limit::MaxFileNameLength.value() ),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
+    fn huge_page_file_unlink(name: &FileName) -> Result<bool, SharedMemoryRemoveError> {
+        let msg = "Unable to remove huge page backed shared memory file";
+        let origin = "SharedMemory::unlink()";
+        let file_path = match Self::huge_page_file_path(name) {
+            Ok(file_path) => file_path,
+            // a name that exceeds the maximum path length cannot exist in the hugetlbfs
+            Err(_) => return Ok(false),
+        };
+
+        if unsafe { posix::unlink(file_path.as_c_str()) } == 0 {
+            return Ok(true);
+        }
+
+        match posix::Errno::get() {
+            posix::Errno::EACCES | posix::Errno::EPERM => {
+                fail!(from origin, with SharedMemoryRemoveError::InsufficientPermissions,
+                    "{} \"{}\" due to insufficient permissions.", msg, name);
+            }
+            posix::Errno::ENOENT | posix::Errno::ENOTDIR => Ok(false),
+            v => {
+                fail!(from origin, with SharedMemoryRemoveError::UnknownError(v as i32),
+                    "{} \"{}\" since an unknown error occurred ({}).", msg, name, v);
+            }
+        }
+    }
+
     fn shm_unlink(name: &FileName) -> Result<bool, SharedMemoryRemoveError> {
         let file_path =
             FilePath::from_path_and_file(&Path::new(&[PATH_SEPARATOR; 1]).unwrap(), name).unwrap();
@@ -11,13 +11,16 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 //! Provides information about the POSIX [`SystemInfo`], [`Limit`]s, available [`SysOption`] and
-//! [`Feature`]s.
+//! [`Feature`]s as well as the [`host_name()`] of the system.
 
 use alloc::string::ToString;
 
 use enum_iterator::Sequence;
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_system_types::host_name::HostName;
 use iceoryx2_bb_system_types::path::Path;
-use iceoryx2_log::{fatal_panic, warn};
+use iceoryx2_log::{fail, fatal_panic, warn};
 use iceoryx2_pal_posix::{posix::MemZeroedStruct, *};
 
 /// The global config path of the system, where all config files shall be stored.
@@ -27,6 +30,46 @@ pub const GLOBAL_CONFIG_PATH: Path =
 pub const USER_CONFIG_PATH: Path =
     unsafe { Path::new_unchecked_const(iceoryx2_pal_configuration::USER_CONFIG_PATH) };
 
+enum_gen! { HostNameError
+  entry:
+    ContainsInvalidCharacters,
+    UnableToRead
+}
+
+/// Returns the [`HostName`] of the system.
+/// ```
+/// # extern crate iceoryx2_bb_loggers;
+///
+/// use iceoryx2_bb_posix::system_configuration::*;
+///
+/// match host_name() {
+///     Ok(name) => println!("{}", name),
+///     Err(e) => println!("unable to acquire host name: {:?}", e),
+/// }
+/// ```
+pub fn host_name() -> Result<HostName, HostNameError> {
+    let origin = "host_name()";
+    let msg = "Unable to acquire host name";
+    let mut buffer = [0u8; HostName::max_len() + 1];
+    if unsafe { posix::gethostname(buffer.as_mut_ptr().cast(), buffer.len()) } != 0 {
+        fail!(from origin, with HostNameError::UnableToRead,
+            "{} since the name could not be read ({:?}).", msg, posix::Errno::get());
+    }
+
+    // the name is not null-terminated when it was truncated
+    let len = buffer
+        .iter()
+        .position(|c| *c == 0)
+        .unwrap_or(HostName::max_len());
+
+    let name = fail!(from origin,
+                    when HostName::new(&buffer[..len]),
+                    with HostNameError::ContainsInvalidCharacters,
+                    "{} since the acquired name contains invalid characters.", msg);
+
+    Ok(name)
+}
+
 /// Generic information about the POSIX system.
 /// ```
 /// # extern crate iceoryx2_bb_loggers;
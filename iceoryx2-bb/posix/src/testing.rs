@@ -16,9 +16,12 @@ use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_log::fatal_panic;
+use iceoryx2_pal_posix::posix;
 
 use crate::config::TEST_DIRECTORY;
 use crate::directory::{Directory, DirectoryCreateError};
+use crate::file::{CreationMode, FileBuilder};
+use crate::file_descriptor::FileDescriptorBased;
 use crate::permission::Permission;
 use crate::unique_system_id::UniqueSystemId;
 
@@ -47,3 +50,37 @@ pub fn generate_file_path() -> FilePath {
 
     FilePath::from_path_and_file(&TEST_DIRECTORY, &file).unwrap()
 }
+
+/// Returns true when the memory in `[address, address + size)` can be written by the process.
+/// The check lets the kernel write the current content back into the memory with
+/// [`posix::read()`], therefore a read-only mapping is reported with an error instead of a
+/// segmentation fault and the content of writable memory is not modified.
+///
+/// # Safety
+///
+///  * the memory range must be mapped and readable
+pub unsafe fn is_memory_writable(address: *const u8, size: usize) -> bool {
+    let origin = "is_memory_writable()";
+    let mut file = fatal_panic!(from origin,
+                        when FileBuilder::new(&generate_file_path())
+                            .has_ownership(true)
+                            .creation_mode(CreationMode::PurgeAndCreate)
+                            .create(),
+                        "Failed to create the file to probe the memory.");
+
+    let content = unsafe { core::slice::from_raw_parts(address, size) };
+    fatal_panic!(from origin, when file.write(content),
+        "Failed to write the memory content into the probe file.");
+    fatal_panic!(from origin, when file.seek(0),
+        "Failed to rewind the probe file.");
+
+    let bytes_read = unsafe {
+        posix::read(
+            file.file_descriptor().native_handle(),
+            address as *mut posix::void,
+            size,
+        )
+    };
+
+    bytes_read == size as posix::ssize_t
+}
@@ -46,6 +46,7 @@ pub mod signal_set_tests;
 pub mod signal_tests;
 pub mod socket_ancillary_tests;
 pub mod socket_pair_tests;
+pub mod system_configuration_tests;
 pub mod thread_tests;
 pub mod udp_socket_tests;
 pub mod unique_system_id_tests;
@@ -17,8 +17,9 @@ use iceoryx2_bb_posix::{
     memory_mapping::*,
     system_configuration::SystemInfo,
 };
-use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::{assert_that, test_requires};
 use iceoryx2_bb_testing_macros::test;
+use iceoryx2_pal_posix::posix::{POSIX_SUPPORT_HUGE_PAGES, POSIX_SUPPORT_MEMORY_LOCK};
 
 #[test]
 pub fn mapping_anonymous_memory_works() {
@@ -224,3 +225,67 @@ pub fn fails_when_it_is_not_mapped_to_address_hint() {
 
     assert_that!(sut.err(), eq Some(MemoryMappingCreationError::FailedToEnforceAddressHint));
 }
+
+#[test]
+pub fn prefaulted_mapping_works() {
+    let memory_size: usize = SystemInfo::PageSize.value() * 4;
+    let mut sut = MemoryMappingBuilder::from_anonymous()
+        .initial_mapping_permission(MappingPermission::ReadWrite)
+        .prefault(true)
+        .size(memory_size)
+        .create()
+        .unwrap();
+
+    for i in 0..memory_size {
+        unsafe { sut.base_address_mut().add(i).write((i % 255) as u8) };
+        assert_that!(unsafe { *sut.base_address_mut().add(i) }, eq(i % 255) as u8);
+    }
+}
+
+#[test]
+pub fn memory_locked_mapping_works() {
+    test_requires!(POSIX_SUPPORT_MEMORY_LOCK);
+
+    let memory_size: usize = SystemInfo::PageSize.value() * 2;
+    let sut = MemoryMappingBuilder::from_anonymous()
+        .initial_mapping_permission(MappingPermission::ReadWrite)
+        .is_memory_locked(true)
+        .size(memory_size)
+        .create()
+        .unwrap();
+
+    assert_that!(sut.is_memory_locked(), eq true);
+}
+
+#[test]
+pub fn mapping_is_not_memory_locked_by_default() {
+    let memory_size: usize = SystemInfo::PageSize.value() * 2;
+    let sut = MemoryMappingBuilder::from_anonymous()
+        .initial_mapping_permission(MappingPermission::ReadWrite)
+        .size(memory_size)
+        .create()
+        .unwrap();
+
+    assert_that!(sut.is_memory_locked(), eq false);
+    assert_that!(sut.has_huge_pages(), eq false);
+}
+
+#[test]
+pub fn huge_page_mapping_falls_back_to_regular_pages_when_unavailable() {
+    let memory_size: usize = SystemInfo::PageSize.value() * 2;
+    let mut sut = MemoryMappingBuilder::from_anonymous()
+        .initial_mapping_permission(MappingPermission::ReadWrite)
+        .use_huge_pages(true)
+        .size(memory_size)
+        .create()
+        .unwrap();
+
+    if !POSIX_SUPPORT_HUGE_PAGES {
+        assert_that!(sut.has_huge_pages(), eq false);
+    }
+
+    for i in 0..memory_size {
+        unsafe { sut.base_address_mut().add(i).write((i % 255) as u8) };
+        assert_that!(unsafe { *sut.base_address_mut().add(i) }, eq(i % 255) as u8);
+    }
+}
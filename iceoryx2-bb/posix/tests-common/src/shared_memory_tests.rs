@@ -10,14 +10,22 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use alloc::string::String;
 use alloc::vec;
 
+use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
+use iceoryx2_bb_posix::config::HUGE_PAGE_DIRECTORY;
+use iceoryx2_bb_posix::file::{File, FileBuilder};
 use iceoryx2_bb_posix::shared_memory::*;
-use iceoryx2_bb_posix::testing::generate_file_path;
+use iceoryx2_bb_posix::testing::{generate_file_path, is_memory_writable};
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_bb_testing::{assert_that, test_requires};
 use iceoryx2_bb_testing_macros::test;
-use iceoryx2_pal_posix::posix::POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY;
+use iceoryx2_pal_posix::posix::{
+    POSIX_SUPPORT_HUGE_PAGES, POSIX_SUPPORT_MEMORY_LOCK, POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY,
+};
 
 #[test]
 pub fn create_and_open_works() {
@@ -51,6 +59,28 @@ pub fn create_and_open_works() {
     }
 }
 
+#[test]
+pub fn shared_memory_opened_read_only_is_not_writable() {
+    test_requires!(SharedMemory::does_support_read_only_mapping());
+
+    let shm_name = generate_file_path().file_name();
+    let sut_create = SharedMemoryBuilder::new(&shm_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .permission(Permission::OWNER_ALL)
+        .create()
+        .unwrap();
+
+    let sut_open = SharedMemoryBuilder::new(&shm_name)
+        .open_existing(AccessMode::Read)
+        .unwrap();
+
+    let created_memory = sut_create.base_address().as_ptr();
+    let opened_memory = sut_open.base_address().as_ptr();
+    assert_that!(unsafe { is_memory_writable(created_memory, sut_create.size()) }, eq true);
+    assert_that!(unsafe { is_memory_writable(opened_memory, sut_open.size()) }, eq false);
+}
+
 #[test]
 pub fn create_and_modify_open_works() {
     let shm_name = generate_file_path().file_name();
@@ -293,3 +323,200 @@ pub fn abandoning_shared_memory_keeps_resources() {
 
     sut_open.acquire_ownership();
 }
+
+#[test]
+pub fn memory_locked_and_prefaulted_shared_memory_works() {
+    test_requires!(POSIX_SUPPORT_MEMORY_LOCK);
+
+    let shm_name = generate_file_path().file_name();
+    let mut sut_create = SharedMemoryBuilder::new(&shm_name)
+        .is_memory_locked(true)
+        .prefault(true)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .permission(Permission::OWNER_ALL)
+        .zero_memory(true)
+        .create()
+        .unwrap();
+
+    let sut_open = SharedMemoryBuilder::new(&shm_name)
+        .is_memory_locked(true)
+        .prefault(true)
+        .open_existing(AccessMode::Read)
+        .unwrap();
+
+    assert_that!(sut_create.is_memory_locked(), eq true);
+    assert_that!(sut_open.is_memory_locked(), eq true);
+
+    for e in sut_create.as_mut_slice().iter_mut() {
+        *e = 129;
+    }
+
+    for e in sut_open.as_slice().iter() {
+        assert_that!(*e, eq 129);
+    }
+}
+
+#[test]
+pub fn shared_memory_with_huge_pages_falls_back_to_regular_pages() {
+    let shm_name = generate_file_path().file_name();
+    let mut sut = SharedMemoryBuilder::new(&shm_name)
+        .use_huge_pages(true)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .permission(Permission::OWNER_ALL)
+        .zero_memory(true)
+        .create()
+        .unwrap();
+
+    assert_that!(sut.size(), ge 1024);
+
+    for e in sut.as_mut_slice().iter_mut() {
+        *e = 77;
+    }
+
+    for e in sut.as_slice().iter() {
+        assert_that!(*e, eq 77);
+    }
+}
+
+// returns the page size in bytes the kernel uses for the mapping starting at `address`
+fn kernel_page_size_of_mapping(address: usize) -> Option<usize> {
+    let smaps = FileBuilder::new(&FilePath::new(b"/proc/self/smaps").unwrap())
+        .open_existing(AccessMode::Read)
+        .ok()?;
+
+    let mut content = vec![];
+    let mut buffer = [0u8; 4096];
+    loop {
+        match smaps.read(&mut buffer) {
+            Ok(0) | Err(_) => break,
+            Ok(n) => content.extend_from_slice(&buffer[..n as usize]),
+        }
+    }
+
+    let content = String::from_utf8(content).ok()?;
+    let mapping_start = alloc::format!("{address:x}-");
+    content
+        .lines()
+        .skip_while(|line| !line.starts_with(&mapping_start))
+        .find_map(|line| line.strip_prefix("KernelPageSize:"))
+        .and_then(|value| {
+            value
+                .trim()
+                .trim_end_matches("kB")
+                .trim()
+                .parse::<usize>()
+                .ok()
+        })
+        .map(|kilobytes| kilobytes * 1024)
+}
+
+#[test]
+pub fn shared_memory_with_huge_pages_is_mapped_with_huge_pages_when_available() {
+    test_requires!(POSIX_SUPPORT_HUGE_PAGES);
+
+    let shm_name = generate_file_path().file_name();
+    let sut_create = SharedMemoryBuilder::new(&shm_name)
+        .use_huge_pages(true)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .permission(Permission::OWNER_ALL)
+        .create()
+        .unwrap();
+
+    // requires a mounted hugetlbfs with reserved huge pages
+    test_requires!(sut_create.has_huge_pages());
+
+    let page_size =
+        kernel_page_size_of_mapping(sut_create.base_address().as_ptr() as usize).unwrap();
+    assert_that!(page_size, gt 4096);
+    assert_that!(sut_create.size(), eq page_size);
+
+    let sut_open = SharedMemoryBuilder::new(&shm_name)
+        .open_existing(AccessMode::Read)
+        .unwrap();
+
+    assert_that!(sut_open.has_huge_pages(), eq true);
+    assert_that!(sut_open.size(), eq sut_create.size());
+    assert_that!(
+        kernel_page_size_of_mapping(sut_open.base_address().as_ptr() as usize),
+        eq Some(page_size)
+    );
+
+    drop(sut_open);
+    drop(sut_create);
+    assert_that!(SharedMemory::does_exist(&shm_name), eq false);
+}
+
+#[test]
+pub fn shared_memory_with_huge_pages_cannot_be_created_twice() {
+    let shm_name = generate_file_path().file_name();
+    let _sut = SharedMemoryBuilder::new(&shm_name)
+        .use_huge_pages(true)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .create()
+        .unwrap();
+
+    let sut_regular = SharedMemoryBuilder::new(&shm_name)
+        .creation_mode(CreationMode::CreateExclusive)
+        .size(1024)
+        .create();
+    assert_that!(sut_regular.err(), eq Some(SharedMemoryCreationError::AlreadyExist));
+
+    let sut_huge_pages = SharedMemoryBuilder::new(&shm_name)
+        .use_huge_pages(true)
+        .creation_mode(CreationMode::CreateExclusive)
+        .size(1024)
+        .create();
+    assert_that!(sut_huge_pages.err(), eq Some(SharedMemoryCreationError::AlreadyExist));
+}
+
+#[test]
+pub fn shared_memory_with_huge_pages_and_maximum_name_length_works() {
+    // the longest name that fits into the path of a regular shared memory but not into the
+    // path of a huge page backed one
+    let max_name_len = FileName::max_len().min(FilePath::max_len() - 3);
+    let mut shm_name = generate_file_path().file_name();
+    while shm_name.len() < max_name_len {
+        shm_name.push(b'x').unwrap();
+    }
+
+    assert_that!(SharedMemory::does_exist(&shm_name), eq false);
+    assert_that!(SharedMemory::remove(&shm_name), eq Ok(false));
+    let sut_open = SharedMemoryBuilder::new(&shm_name).open_existing(AccessMode::Read);
+    assert_that!(sut_open, is_err);
+
+    let sut = SharedMemoryBuilder::new(&shm_name)
+        .use_huge_pages(true)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .create();
+    test_requires!(sut.is_ok());
+    let sut = sut.unwrap();
+
+    assert_that!(sut.has_huge_pages(), eq false);
+    assert_that!(SharedMemory::does_exist(&shm_name), eq true);
+    drop(sut);
+    assert_that!(SharedMemory::does_exist(&shm_name), eq false);
+}
+
+#[test]
+pub fn list_does_not_contain_foreign_huge_page_files() {
+    test_requires!(POSIX_SUPPORT_HUGE_PAGES);
+
+    let foreign_name = generate_file_path().file_name();
+    let foreign_file_path =
+        FilePath::from_path_and_file(&HUGE_PAGE_DIRECTORY, &foreign_name).unwrap();
+    let foreign_file = FileBuilder::new(&foreign_file_path)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create();
+    // requires a writable hugetlbfs mount point
+    test_requires!(foreign_file.is_ok());
+
+    let sut = SharedMemory::list();
+    let _ = File::remove(&foreign_file_path);
+
+    assert_that!(sut.contains(&foreign_name), eq false);
+}
@@ -0,0 +1,28 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_posix::system_configuration::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+#[test]
+pub fn host_name_can_be_acquired() {
+    let sut = host_name();
+
+    assert_that!(sut, is_ok);
+    assert_that!(sut.unwrap().len(), gt 0);
+}
+
+#[test]
+pub fn host_name_is_stable() {
+    assert_that!(host_name(), eq host_name());
+}
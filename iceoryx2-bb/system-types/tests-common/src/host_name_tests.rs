@@ -0,0 +1,49 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_container::semantic_string::*;
+use iceoryx2_bb_system_types::host_name::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+#[test]
+pub fn new_with_illegal_name_fails() {
+    let sut = HostName::new(b"");
+    assert_that!(sut, is_err);
+
+    let sut = HostName::new(b"-asdf");
+    assert_that!(sut, is_err);
+
+    let sut = HostName::new(b".asdf");
+    assert_that!(sut, is_err);
+
+    let sut = HostName::new(b"as df");
+    assert_that!(sut, is_err);
+
+    let sut = HostName::new(b"asd\0f");
+    assert_that!(sut, is_err);
+}
+
+#[test]
+pub fn new_with_legal_name_works() {
+    let sut = HostName::new(b"abcdefghijklmnopqrstuvwxyz-0123");
+    assert_that!(sut, is_ok);
+
+    let sut = HostName::new(b"0815-host");
+    assert_that!(sut, is_ok);
+
+    let sut = HostName::new(b"my-Host.example.com");
+    assert_that!(sut, is_ok);
+
+    let sut = HostName::new(b"DESKTOP_42");
+    assert_that!(sut, is_ok);
+}
@@ -24,7 +24,7 @@ pub mod resizable_shared_memory_trait {
     use iceoryx2_bb_posix::file::AccessMode;
     use iceoryx2_pal_posix::posix::POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY;
 
-    use iceoryx2_bb_posix::testing::generate_file_path;
+    use iceoryx2_bb_posix::testing::{generate_file_path, is_memory_writable};
     use iceoryx2_bb_testing::{assert_that, test_requires};
     use iceoryx2_bb_testing_macros::conformance_test;
     use iceoryx2_cal::named_concept::*;
@@ -71,6 +71,52 @@ pub mod resizable_shared_memory_trait {
         assert_that!(unsafe{ *ptr_view }, eq test_value_2);
     }
 
+    #[conformance_test]
+    pub fn read_only_opened_view_cannot_write_into_any_segment<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        test_requires!(Sut::does_support_read_only_mapping());
+
+        let storage_name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_creator = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_number_of_chunks_hint(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        let sut_viewer = Sut::ViewBuilder::new(&storage_name)
+            .config(&config)
+            .open(AccessMode::Read)
+            .unwrap();
+
+        // the second allocation exceeds the hint and enforces a new segment
+        let layouts = [Layout::new::<u64>(), Layout::new::<[u64; 128]>()];
+        let mut chunks = vec![];
+        for layout in layouts {
+            let chunk = sut_creator.allocate(layout).unwrap();
+            unsafe { chunk.data_ptr.write_bytes(0xcd, layout.size()) };
+            chunks.push((chunk, layout));
+        }
+        assert_that!(sut_creator.number_of_active_segments(), eq 2);
+
+        for (chunk, layout) in chunks {
+            let ptr_view = unsafe {
+                sut_viewer
+                    .register_and_translate_offset(chunk.offset)
+                    .unwrap()
+            };
+
+            assert_that!(unsafe { is_memory_writable(chunk.data_ptr, layout.size()) }, eq true);
+            assert_that!(unsafe { is_memory_writable(ptr_view, layout.size()) }, eq false);
+            for i in 0..layout.size() {
+                assert_that!(unsafe { *ptr_view.add(i) }, eq 0xcd);
+            }
+        }
+    }
+
     #[conformance_test]
     pub fn allocate_more_layout_than_hinted_when_no_other_chunks_are_in_use_releases_smaller_segment<
         Shm: SharedMemory<DefaultAllocator>,
@@ -166,6 +212,31 @@ pub mod resizable_shared_memory_trait {
         assert_that!(unsafe{ *ptr_view_2 }, eq test_value_2);
     }
 
+    #[conformance_test]
+    pub fn payload_start_address_translates_offsets_of_all_segments<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let storage_name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_number_of_chunks_hint(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+
+        let ptr_1 = sut.allocate(Layout::new::<u64>()).unwrap();
+        let ptr_2 = sut.allocate(Layout::new::<u64>()).unwrap();
+        assert_that!(sut.number_of_active_segments(), eq 2);
+
+        for ptr in [ptr_1, ptr_2] {
+            let address = sut.payload_start_address(ptr.offset.segment_id()) + ptr.offset.offset();
+            assert_that!(address, eq ptr.data_ptr as usize);
+        }
+    }
+
     #[conformance_test]
     pub fn deallocate_removes_unused_segments_on_creator_side<
         Shm: SharedMemory<DefaultAllocator>,
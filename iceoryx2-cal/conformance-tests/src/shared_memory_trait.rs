@@ -21,9 +21,11 @@ pub mod shared_memory_trait {
     use alloc::vec;
     use core::alloc::Layout;
     use iceoryx2_bb_posix::file::AccessMode;
-    use iceoryx2_pal_posix::posix::POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY;
+    use iceoryx2_pal_posix::posix::{
+        POSIX_SUPPORT_MEMORY_LOCK, POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY,
+    };
 
-    use iceoryx2_bb_posix::testing::generate_file_path;
+    use iceoryx2_bb_posix::testing::{generate_file_path, is_memory_writable};
     use iceoryx2_bb_testing::{assert_that, test_requires};
     use iceoryx2_bb_testing_macros::conformance_test;
     use iceoryx2_cal::named_concept::*;
@@ -219,6 +221,75 @@ pub mod shared_memory_trait {
         assert_that!(chunk, is_ok);
     }
 
+    #[conformance_test]
+    pub fn memory_locked_and_prefaulted_shm_works<Sut: SharedMemory<DefaultAllocator>>() {
+        test_requires!(POSIX_SUPPORT_MEMORY_LOCK);
+
+        let name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_create = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .lock_memory(true)
+            .prefault(true)
+            .use_huge_pages(true)
+            .config(&config)
+            .create(&SHM_CONFIG)
+            .unwrap();
+
+        let sut_open = Sut::Builder::new(&name)
+            .lock_memory(true)
+            .prefault(true)
+            .config(&config)
+            .open(AccessMode::ReadWrite)
+            .unwrap();
+
+        let chunk = sut_create.allocate(DEFAULT_LAYOUT).unwrap();
+        unsafe { chunk.data_ptr.write_bytes(0xab, CHUNK_SIZE) };
+
+        let data_ptr = (sut_open.payload_start_address() + chunk.offset.offset()) as *const u8;
+        for i in 0..CHUNK_SIZE {
+            assert_that!(unsafe { *data_ptr.add(i) }, eq 0xab);
+        }
+    }
+
+    #[conformance_test]
+    pub fn read_only_opened_shm_cannot_be_written<Sut: SharedMemory<DefaultAllocator>>() {
+        test_requires!(Sut::does_support_read_only_mapping());
+
+        let name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_create = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .config(&config)
+            .create(&SHM_CONFIG)
+            .unwrap();
+
+        let sut_open_read = Sut::Builder::new(&name)
+            .config(&config)
+            .open(AccessMode::Read)
+            .unwrap();
+        let sut_open_read_write = Sut::Builder::new(&name)
+            .config(&config)
+            .open(AccessMode::ReadWrite)
+            .unwrap();
+
+        let chunk = sut_create.allocate(DEFAULT_LAYOUT).unwrap();
+        unsafe { chunk.data_ptr.write_bytes(0xab, CHUNK_SIZE) };
+
+        let read_ptr = (sut_open_read.payload_start_address() + chunk.offset.offset()) as *const u8;
+        let read_write_ptr =
+            (sut_open_read_write.payload_start_address() + chunk.offset.offset()) as *const u8;
+
+        assert_that!(unsafe { is_memory_writable(chunk.data_ptr, CHUNK_SIZE) }, eq true);
+        assert_that!(unsafe { is_memory_writable(read_write_ptr, CHUNK_SIZE) }, eq true);
+        assert_that!(unsafe { is_memory_writable(read_ptr, CHUNK_SIZE) }, eq false);
+        for i in 0..CHUNK_SIZE {
+            assert_that!(unsafe { *read_ptr.add(i) }, eq 0xab);
+        }
+    }
+
     #[conformance_test]
     pub fn allocated_chunks_have_correct_alignment<Sut: SharedMemory<DefaultAllocator>>() {
         let name = generate_file_path().file_name();
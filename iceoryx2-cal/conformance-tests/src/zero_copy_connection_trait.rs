@@ -322,6 +322,37 @@ pub mod zero_copy_connection_trait {
         );
     }
 
+    #[conformance_test]
+    pub fn connecting_with_incompatible_channel_overflow_setting_fails<Sut: ZeroCopyConnection>() {
+        let name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let _sut_sender = Sut::Builder::new(&name)
+            .number_of_channels(2)
+            .enable_safe_overflow_for_channel(ChannelId::new(1), true)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_channels(2)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver();
+
+        assert_that!(sut_receiver.err(), eq Some(ZeroCopyCreationError::IncompatibleOverflowSetting));
+
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_channels(2)
+            .enable_safe_overflow_for_channel(ChannelId::new(1), true)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver();
+
+        assert_that!(sut_receiver, is_ok);
+    }
+
     #[conformance_test]
     pub fn connecting_with_incompatible_number_of_samples_fails<Sut: ZeroCopyConnection>() {
         let name = generate_file_path().file_name();
@@ -681,6 +712,54 @@ pub mod zero_copy_connection_trait {
         }
     }
 
+    #[conformance_test]
+    pub fn safe_overflow_can_be_configured_per_channel<Sut: ZeroCopyConnection>() {
+        let overflowing_channel = ChannelId::new(0);
+        let non_overflowing_channel = ChannelId::new(1);
+        let name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+        const BUFFER_SIZE: usize = 12;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .number_of_channels(2)
+            .enable_safe_overflow(true)
+            .enable_safe_overflow_for_channel(non_overflowing_channel, false)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+
+        assert_that!(sut_sender.has_enabled_safe_overflow(), eq true);
+        assert_that!(sut_sender.has_enabled_safe_overflow_for_channel(overflowing_channel), eq true);
+        assert_that!(sut_sender.has_enabled_safe_overflow_for_channel(non_overflowing_channel), eq false);
+
+        for channel_id in [overflowing_channel, non_overflowing_channel] {
+            for i in 0..BUFFER_SIZE {
+                let sample_offset = SAMPLE_SIZE * i;
+                assert_that!(
+                    sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE, channel_id),
+                    is_ok
+                );
+            }
+        }
+
+        let sample_offset = SAMPLE_SIZE * BUFFER_SIZE;
+        let result = sut_sender.try_send(
+            PointerOffset::new(sample_offset),
+            SAMPLE_SIZE,
+            overflowing_channel,
+        );
+        assert_that!(result.ok().unwrap().unwrap().offset(), eq 0);
+
+        let result = sut_sender.try_send(
+            PointerOffset::new(sample_offset),
+            SAMPLE_SIZE,
+            non_overflowing_channel,
+        );
+        assert_that!(result.err(), eq Some(ZeroCopySendError::ReceiveBufferFull));
+    }
+
     #[conformance_test]
     pub fn receive_can_acquire_data_with_late_connection<Sut: ZeroCopyConnection>() {
         let id = ChannelId::new(0);
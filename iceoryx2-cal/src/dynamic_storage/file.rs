@@ -43,7 +43,7 @@ use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
 use iceoryx2_bb_posix::memory_mapping::MappingBehavior;
 use iceoryx2_bb_posix::memory_mapping::MappingPermission;
 use iceoryx2_bb_posix::memory_mapping::MemoryMapping;
-use iceoryx2_bb_posix::memory_mapping::MemoryMappingBuilder;
+use iceoryx2_bb_posix::memory_mapping::{MemoryMappingBuilder, MemoryMappingCreationError};
 use iceoryx2_bb_posix::shared_memory::*;
 use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_log::fail;
@@ -69,6 +69,9 @@ pub struct Builder<'builder, T: Send + Sync + Debug> {
     call_drop_on_destruction: bool,
     supplementary_size: usize,
     has_ownership: bool,
+    is_memory_locked: bool,
+    prefault: bool,
+    use_huge_pages: bool,
     config: Configuration<T>,
     timeout: Duration,
     initializer: Initializer<'builder, T>,
@@ -163,6 +166,9 @@ impl<T: Send + Sync + Debug> NamedConceptBuilder<Storage<T>> for Builder<'_, T>
         Self {
             call_drop_on_destruction: true,
             has_ownership: true,
+            is_memory_locked: false,
+            prefault: false,
+            use_huge_pages: false,
             storage_name: *storage_name,
             supplementary_size: 0,
             config: Configuration::default(),
@@ -246,9 +252,19 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
             .mapping_behavior(MappingBehavior::Shared)
             .initial_mapping_permission(access_mode.into())
             .size(file_size as usize)
+            .is_memory_locked(self.is_memory_locked)
+            .prefault(self.prefault)
+            .use_huge_pages(self.use_huge_pages)
             .create()
         {
             Ok(v) => v,
+            Err(
+                e @ (MemoryMappingCreationError::MemoryLockCreationError(_)
+                | MemoryMappingCreationError::InsufficientResources),
+            ) => {
+                fail!(from self, with DynamicStorageOpenError::InsufficientResources,
+                        "{msg} since the memory could not be locked or prefaulted due to insufficient resources ({e:?}).");
+            }
             Err(e) => {
                 fail!(from self, with DynamicStorageOpenError::InternalError,
                         "{msg} since the memory could not be mapped into the process ({e:?}).");
@@ -336,9 +352,19 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
             .mapping_behavior(MappingBehavior::Shared)
             .initial_mapping_permission(MappingPermission::ReadWrite)
             .size(file_size)
+            .is_memory_locked(self.is_memory_locked)
+            .prefault(self.prefault)
+            .use_huge_pages(self.use_huge_pages)
             .create()
         {
             Ok(m) => m,
+            Err(
+                e @ (MemoryMappingCreationError::MemoryLockCreationError(_)
+                | MemoryMappingCreationError::InsufficientResources),
+            ) => {
+                fail!(from self, with DynamicStorageCreateError::InsufficientResources,
+                        "{msg} since the memory could not be locked or prefaulted due to insufficient resources ({e:?}).");
+            }
             Err(e) => {
                 fail!(from self, with DynamicStorageCreateError::InternalError,
                         "{msg} since the file could not be mapped into the process space ({e:?}).");
@@ -429,6 +455,21 @@ impl<'builder, T: Send + Sync + Debug> DynamicStorageBuilder<'builder, T, Storag
         self
     }
 
+    fn lock_memory(mut self, value: bool) -> Self {
+        self.is_memory_locked = value;
+        self
+    }
+
+    fn prefault(mut self, value: bool) -> Self {
+        self.prefault = value;
+        self
+    }
+
+    fn use_huge_pages(mut self, value: bool) -> Self {
+        self.use_huge_pages = value;
+        self
+    }
+
     fn timeout(mut self, value: Duration) -> Self {
         self.timeout = value;
         self
@@ -619,6 +660,10 @@ impl<T: Send + Sync + Debug> DynamicStorage<T> for Storage<T> {
         SharedMemory::does_support_persistency()
     }
 
+    fn does_support_read_only_mapping() -> bool {
+        MemoryMapping::does_support_read_only_mapping()
+    }
+
     fn acquire_ownership(&self) {
         self.file.acquire_ownership()
     }
@@ -90,6 +90,7 @@ pub enum DynamicStorageCreateError {
     AlreadyExists,
     InsufficientPermissions,
     InitializationFailed,
+    InsufficientResources,
     InternalError,
 }
 
@@ -107,6 +108,7 @@ pub enum DynamicStorageOpenError {
     DoesNotExist,
     InitializationNotYetFinalized,
     VersionMismatch,
+    InsufficientResources,
     InternalError,
 }
 
@@ -141,6 +143,18 @@ pub trait DynamicStorageBuilder<'builder, T: Send + Sync, D: DynamicStorage<T>>:
     /// the already initialized [`DynamicStorage`] with the full size is used.
     fn supplementary_size(self, value: usize) -> Self;
 
+    /// Locks the memory of the [`DynamicStorage`] into the RAM when it is created or opened so
+    /// that it cannot be swapped. The default is [`false`].
+    fn lock_memory(self, value: bool) -> Self;
+
+    /// Touches every page of the [`DynamicStorage`] when it is created or opened so that
+    /// no page fault occurs on the first access. The default is [`false`].
+    fn prefault(self, value: bool) -> Self;
+
+    /// Requests huge pages for the memory of the [`DynamicStorage`]. When they are not
+    /// available, regular pages are used. The default is [`false`].
+    fn use_huge_pages(self, value: bool) -> Self;
+
     /// The timeout defines how long the [`DynamicStorageBuilder`] should wait for
     /// [`DynamicStorageBuilder::create()`]
     /// to finialize the initialization. This is required when the [`DynamicStorage`] is
@@ -180,6 +194,10 @@ pub trait DynamicStorage<T: Send + Sync>:
     /// resource remain even when every [`DynamicStorage`] instance in every process was removed.
     fn does_support_persistency() -> bool;
 
+    /// Returns true if a [`DynamicStorage`] that was opened with [`AccessMode::Read`] is mapped
+    /// read-only so that every write access from the opening process causes a fault.
+    fn does_support_read_only_mapping() -> bool;
+
     /// Returns true if the storage holds the ownership, otherwise false.
     fn has_ownership(&self) -> bool;
 
@@ -62,6 +62,7 @@ use iceoryx2_bb_elementary_traits::non_null::NonNullCompat;
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_bb_posix::directory::*;
 use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
+use iceoryx2_bb_posix::memory_mapping::MemoryMappingCreationError;
 use iceoryx2_bb_posix::shared_memory::*;
 use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_log::fail;
@@ -87,6 +88,9 @@ pub struct Builder<'builder, T: Send + Sync + Debug> {
     call_drop_on_destruction: bool,
     supplementary_size: usize,
     has_ownership: bool,
+    is_memory_locked: bool,
+    prefault: bool,
+    use_huge_pages: bool,
     config: Configuration<T>,
     timeout: Duration,
     initializer: Initializer<'builder, T>,
@@ -181,6 +185,9 @@ impl<T: Send + Sync + Debug> NamedConceptBuilder<Storage<T>> for Builder<'_, T>
         Self {
             call_drop_on_destruction: true,
             has_ownership: true,
+            is_memory_locked: false,
+            prefault: false,
+            use_huge_pages: false,
             storage_name: *storage_name,
             supplementary_size: 0,
             config: Configuration::default(),
@@ -207,12 +214,24 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
 
         let mut elapsed_time = Duration::ZERO;
         let shm = loop {
-            match SharedMemoryBuilder::new(&full_name).open_existing(access_mode) {
+            match SharedMemoryBuilder::new(&full_name)
+                .is_memory_locked(self.is_memory_locked)
+                .prefault(self.prefault)
+                .use_huge_pages(self.use_huge_pages)
+                .open_existing(access_mode)
+            {
                 Ok(v) => break v,
                 Err(SharedMemoryCreationError::DoesNotExist) => {
                     fail!(from self, with DynamicStorageOpenError::DoesNotExist,
                     "{} since a shared memory with that name does not exists.", msg);
                 }
+                Err(SharedMemoryCreationError::MemoryMappingCreationError(
+                    MemoryMappingCreationError::MemoryLockCreationError(_)
+                    | MemoryMappingCreationError::InsufficientResources,
+                )) => {
+                    fail!(from self, with DynamicStorageOpenError::InsufficientResources,
+                    "{} since the shared memory could not be locked or prefaulted due to insufficient resources.", msg);
+                }
                 Err(SharedMemoryCreationError::InsufficientPermissions) => {
                     if elapsed_time >= self.timeout {
                         fail!(from self, with DynamicStorageOpenError::InitializationNotYetFinalized,
@@ -276,6 +295,9 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
 
         let full_name = self.config.path_for(&self.storage_name).file_name();
         let shm = match SharedMemoryBuilder::new(&full_name)
+            .is_memory_locked(self.is_memory_locked)
+            .prefault(self.prefault)
+            .use_huge_pages(self.use_huge_pages)
             .creation_mode(CreationMode::CreateExclusive)
             // posix shared memory is always aligned to the greatest possible value (PAGE_SIZE)
             // therefore we do not have to add additional alignment space for T
@@ -294,6 +316,13 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
                 fail!(from self, with DynamicStorageCreateError::InsufficientPermissions,
                     "{} due to insufficient permissions.", msg);
             }
+            Err(SharedMemoryCreationError::MemoryMappingCreationError(
+                MemoryMappingCreationError::MemoryLockCreationError(_)
+                | MemoryMappingCreationError::InsufficientResources,
+            )) => {
+                fail!(from self, with DynamicStorageCreateError::InsufficientResources,
+                    "{} since the shared memory could not be locked or prefaulted due to insufficient resources.", msg);
+            }
             Err(_) => {
                 fail!(from self, with DynamicStorageCreateError::InternalError,
                     "{} since the underlying shared memory could not be created.", msg);
@@ -385,6 +414,21 @@ impl<'builder, T: Send + Sync + Debug> DynamicStorageBuilder<'builder, T, Storag
         self
     }
 
+    fn lock_memory(mut self, value: bool) -> Self {
+        self.is_memory_locked = value;
+        self
+    }
+
+    fn prefault(mut self, value: bool) -> Self {
+        self.prefault = value;
+        self
+    }
+
+    fn use_huge_pages(mut self, value: bool) -> Self {
+        self.use_huge_pages = value;
+        self
+    }
+
     fn timeout(mut self, value: Duration) -> Self {
         self.timeout = value;
         self
@@ -545,6 +589,10 @@ impl<T: Send + Sync + Debug> DynamicStorage<T> for Storage<T> {
         SharedMemory::does_support_persistency()
     }
 
+    fn does_support_read_only_mapping() -> bool {
+        SharedMemory::does_support_read_only_mapping()
+    }
+
     fn acquire_ownership(&self) {
         self.shm.acquire_ownership()
     }
@@ -76,6 +76,8 @@ use self::dynamic_storage_configuration::DynamicStorageConfiguration;
 #[derive(Debug)]
 struct StorageEntry {
     content: Arc<dyn Any + Send + Sync>,
+    // type erased destructor so that the storage can be removed without knowing its type
+    drop_content: unsafe fn(&Arc<dyn Any + Send + Sync>),
 }
 
 #[derive(Debug)]
@@ -189,6 +191,14 @@ impl<T> Drop for StorageDetails<T> {
     }
 }
 
+unsafe fn drop_content<T: 'static>(content: &Arc<dyn Any + Send + Sync>) {
+    if let Ok(details) = content.clone().downcast::<StorageDetails<T>>()
+        && details.call_drop_on_destruction
+    {
+        unsafe { core::ptr::drop_in_place(details.data_ptr) };
+    }
+}
+
 unsafe impl<T> Send for StorageDetails<T> {}
 unsafe impl<T> Sync for StorageDetails<T> {}
 
@@ -269,23 +279,13 @@ impl<T: Send + Sync + Debug + 'static> NamedConceptMgmt for Storage<T> {
                                 with NamedConceptRemoveError::InternalError,
                                 "{} since the lock could not be acquired.", msg);
 
-        let mut entry = guard.get_mut(&storage_name);
-        if entry.is_none() {
-            return Ok(false);
-        }
-
-        let details = entry
-            .as_mut()
-            .unwrap()
-            .content
-            .clone()
-            .downcast::<StorageDetails<T>>()
-            .unwrap();
-
-        if details.call_drop_on_destruction {
-            unsafe { core::ptr::drop_in_place(details.data_ptr) };
+        match guard.remove(&storage_name) {
+            Some(entry) => {
+                unsafe { (entry.drop_content)(&entry.content) };
+                Ok(true)
+            }
+            None => Ok(false),
         }
-        Ok(guard.remove(&storage_name).is_some())
     }
 
     fn remove_path_hint(
@@ -302,6 +302,10 @@ impl<T: Send + Sync + Debug + 'static> DynamicStorage<T> for Storage<T> {
         true
     }
 
+    fn does_support_read_only_mapping() -> bool {
+        false
+    }
+
     fn acquire_ownership(&self) {
         self.has_ownership.store(true, Ordering::Relaxed);
     }
@@ -450,6 +454,7 @@ impl<T: Send + Sync + Debug + 'static> Builder<'_, T> {
             full_path,
             StorageEntry {
                 content: storage_details,
+                drop_content: drop_content::<T>,
             },
         );
 
@@ -477,6 +482,19 @@ impl<'builder, T: Send + Sync + Debug + 'static> DynamicStorageBuilder<'builder,
         self
     }
 
+    fn lock_memory(self, _value: bool) -> Self {
+        // process local memory is heap memory and cannot be locked or prefaulted individually
+        self
+    }
+
+    fn prefault(self, _value: bool) -> Self {
+        self
+    }
+
+    fn use_huge_pages(self, _value: bool) -> Self {
+        self
+    }
+
     fn has_ownership(mut self, value: bool) -> Self {
         self.has_ownership = value;
         self
@@ -71,6 +71,9 @@ struct MemoryConfig<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>> {
     base_name: FileName,
     shm: Shm::Configuration,
     allocator_config_hint: Allocator::Configuration,
+    is_memory_locked: bool,
+    prefault: bool,
+    use_huge_pages: bool,
 }
 
 #[derive(Debug)]
@@ -231,6 +234,9 @@ where
                 base_name: *name,
                 allocator_config_hint: Allocator::Configuration::default(),
                 shm: Shm::Configuration::default(),
+                is_memory_locked: false,
+                prefault: false,
+                use_huge_pages: false,
             },
             shared_state: SharedState {
                 allocation_strategy: AllocationStrategy::default(),
@@ -276,6 +282,21 @@ where
         self
     }
 
+    fn lock_memory(mut self, value: bool) -> Self {
+        self.config.is_memory_locked = value;
+        self
+    }
+
+    fn prefault(mut self, value: bool) -> Self {
+        self.config.prefault = value;
+        self
+    }
+
+    fn use_huge_pages(mut self, value: bool) -> Self {
+        self.config.use_huge_pages = value;
+        self
+    }
+
     fn create(mut self) -> Result<DynamicMemory<Allocator, Shm>, SharedMemoryCreateError> {
         let msg = "Unable to create ResizableSharedMemory";
         let origin = format!("{self:?}");
@@ -623,6 +644,9 @@ where
     ) -> Result<Shm, SharedMemoryCreateError> {
         Self::segment_builder(&config.base_name, &config.shm, segment_id)
             .has_ownership(true)
+            .lock_memory(config.is_memory_locked)
+            .prefault(config.prefault)
+            .use_huge_pages(config.use_huge_pages)
             .size(payload_size)
             .create(&config.allocator_config_hint)
     }
@@ -779,6 +803,10 @@ where
         MAX_NUMBER_OF_REALLOCATIONS
     }
 
+    fn does_support_read_only_mapping() -> bool {
+        Shm::does_support_read_only_mapping()
+    }
+
     fn number_of_active_segments(&self) -> usize {
         self.state().shared_memory_map.len()
     }
@@ -809,4 +837,14 @@ where
             self.perform_deallocation(offset, |entry| entry.shm.deallocate(offset, layout));
         }
     }
+
+    fn payload_start_address(&self, segment_id: SegmentId) -> usize {
+        let segment_id_key = SlotMapKey::new(segment_id.value() as usize);
+        match self.state_mut().shared_memory_map.get(segment_id_key) {
+            Some(entry) => entry.shm.payload_start_address(),
+            None => fatal_panic!(from self,
+                        "This should never happen! Unable to acquire the payload start address since the segment {:?} does not exist.",
+                        segment_id),
+        }
+    }
 }
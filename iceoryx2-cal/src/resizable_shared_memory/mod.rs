@@ -166,6 +166,18 @@ pub trait ResizableSharedMemoryBuilder<
     /// acquired.
     fn allocation_strategy(self, value: AllocationStrategy) -> Self;
 
+    /// Locks every [`SharedMemory`] segment into the RAM when it is created.
+    /// See [`SharedMemoryBuilder::lock_memory()`].
+    fn lock_memory(self, value: bool) -> Self;
+
+    /// Touches every page of a [`SharedMemory`] segment when it is created.
+    /// See [`SharedMemoryBuilder::prefault()`].
+    fn prefault(self, value: bool) -> Self;
+
+    /// Requests huge pages for every [`SharedMemory`] segment.
+    /// See [`SharedMemoryBuilder::use_huge_pages()`].
+    fn use_huge_pages(self, value: bool) -> Self;
+
     /// Creates new [`SharedMemory`]. If it already exists the method will fail.
     fn create(self) -> Result<ResizableShm, SharedMemoryCreateError>;
 }
@@ -223,6 +235,10 @@ pub trait ResizableSharedMemory<Allocator: ShmAllocator, Shm: SharedMemory<Alloc
     /// underlying [`SharedMemory`] segments will fail.
     fn max_number_of_reallocations() -> usize;
 
+    /// Returns true if the [`ResizableSharedMemory::View`] maps every underlying
+    /// [`SharedMemory`] segment read-only when it is opened with [`AccessMode::Read`].
+    fn does_support_read_only_mapping() -> bool;
+
     /// Returns the number of active [`SharedMemory`] segments.
     fn number_of_active_segments(&self) -> usize;
 
@@ -243,6 +259,11 @@ pub trait ResizableSharedMemory<Allocator: ShmAllocator, Shm: SharedMemory<Alloc
     ///    [`ShmPointer`]
     ///  * the layout must be identical to the one used in [`SharedMemory::allocate()`]
     unsafe fn deallocate(&self, offset: PointerOffset, layout: core::alloc::Layout);
+
+    /// Returns the payload start address of the [`SharedMemory`] segment with the provided
+    /// [`SegmentId`]. The address of previously allocated memory is the payload start address
+    /// of its segment plus the offset of its [`PointerOffset`].
+    fn payload_start_address(&self, segment_id: SegmentId) -> usize;
 }
 
 pub trait ResizableSharedMemoryForPoolAllocator<Shm: SharedMemory<PoolAllocator>>:
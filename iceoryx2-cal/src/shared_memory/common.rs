@@ -145,6 +145,9 @@ pub mod details {
         config: Configuration<Allocator, Storage>,
         timeout: Duration,
         has_ownership: bool,
+        is_memory_locked: bool,
+        prefault: bool,
+        use_huge_pages: bool,
     }
 
     impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
@@ -157,6 +160,9 @@ pub mod details {
                 size: 0,
                 timeout: Duration::ZERO,
                 has_ownership: true,
+                is_memory_locked: false,
+                prefault: false,
+                use_huge_pages: false,
             }
         }
 
@@ -221,6 +227,21 @@ pub mod details {
             self
         }
 
+        fn lock_memory(mut self, value: bool) -> Self {
+            self.is_memory_locked = value;
+            self
+        }
+
+        fn prefault(mut self, value: bool) -> Self {
+            self.prefault = value;
+            self
+        }
+
+        fn use_huge_pages(mut self, value: bool) -> Self {
+            self.use_huge_pages = value;
+            self
+        }
+
         fn create(
             self,
             allocator_config: &Allocator::Configuration,
@@ -238,6 +259,9 @@ pub mod details {
                 .config(&self.config.dynamic_storage_config)
                 .supplementary_size(self.size + allocator_mgmt_size)
                 .has_ownership(self.has_ownership)
+                .lock_memory(self.is_memory_locked)
+                .prefault(self.prefault)
+                .use_huge_pages(self.use_huge_pages)
                 .initializer(|details, init_allocator| -> bool {
                     self.initialize(allocator_config, details, init_allocator)
                 })
@@ -257,6 +281,10 @@ pub mod details {
                     fail!(from self, with SharedMemoryCreateError::InsufficientPermissions,
                         "{} due to insufficient permissions.", msg);
                 }
+                Err(DynamicStorageCreateError::InsufficientResources) => {
+                    fail!(from self, with SharedMemoryCreateError::InsufficientResources,
+                        "{} since the memory could not be locked or prefaulted due to insufficient resources.", msg);
+                }
                 Err(DynamicStorageCreateError::InitializationFailed) => {
                     fail!(from self, with SharedMemoryCreateError::InternalError,
                         "{} since the initialization failed.", msg);
@@ -284,6 +312,9 @@ pub mod details {
             let storage = match Storage::Builder::new(&self.name)
                 .config(&self.config.dynamic_storage_config)
                 .has_ownership(false)
+                .lock_memory(self.is_memory_locked)
+                .prefault(self.prefault)
+                .use_huge_pages(self.use_huge_pages)
                 .timeout(self.timeout)
                 .open(access_mode)
             {
@@ -300,6 +331,10 @@ pub mod details {
                     fail!(from self, with SharedMemoryOpenError::VersionMismatch,
                         "{} since the version number of the construct does not match.", msg);
                 }
+                Err(DynamicStorageOpenError::InsufficientResources) => {
+                    fail!(from self, with SharedMemoryOpenError::InsufficientResources,
+                        "{} since the memory could not be locked or prefaulted due to insufficient resources.", msg);
+                }
                 Err(DynamicStorageOpenError::InternalError) => {
                     fail!(from self, with SharedMemoryOpenError::InternalError,
                         "{} since an unknown error has occurred.", msg);
@@ -419,6 +454,10 @@ pub mod details {
             Storage::does_support_persistency()
         }
 
+        fn does_support_read_only_mapping() -> bool {
+            Storage::does_support_read_only_mapping()
+        }
+
         fn has_ownership(&self) -> bool {
             self.storage.has_ownership()
         }
@@ -77,6 +77,7 @@ pub enum SharedMemoryCreateError {
     AlreadyExists,
     SizeIsZero,
     InsufficientPermissions,
+    InsufficientResources,
     InternalError,
 }
 
@@ -98,6 +99,7 @@ pub enum SharedMemoryOpenError {
     WrongAllocatorSelected,
     InitializationNotYetFinalized,
     VersionMismatch,
+    InsufficientResources,
     InternalError,
 }
 
@@ -139,6 +141,19 @@ pub trait SharedMemoryBuilder<Allocator: ShmAllocator, Shm: SharedMemory<Allocat
     /// space.
     fn size(self, value: usize) -> Self;
 
+    /// Locks the [`SharedMemory`] into the RAM when it is created or opened so that it cannot
+    /// be swapped. Fails with [`SharedMemoryCreateError::InsufficientResources`] or
+    /// [`SharedMemoryOpenError::InsufficientResources`] when the memory lock limit is exceeded.
+    fn lock_memory(self, value: bool) -> Self;
+
+    /// Touches every page of the [`SharedMemory`] when it is created or opened so that no
+    /// page fault occurs on the first access.
+    fn prefault(self, value: bool) -> Self;
+
+    /// Requests huge pages for the [`SharedMemory`]. When they are not available, regular
+    /// pages are used.
+    fn use_huge_pages(self, value: bool) -> Self;
+
     /// The timeout defines how long the [`SharedMemoryBuilder`] should wait for
     /// [`SharedMemoryBuilder::create()`] to finialize
     /// the initialization. This is required when the [`SharedMemory`] is created and initialized
@@ -197,6 +212,10 @@ pub trait SharedMemory<Allocator: ShmAllocator>:
     /// resource remain even when every [`SharedMemory`] instance in every process was removed.
     fn does_support_persistency() -> bool;
 
+    /// Returns true if a [`SharedMemory`] that was opened with [`AccessMode::Read`] is mapped
+    /// read-only so that every write access from the opening process causes a fault.
+    fn does_support_read_only_mapping() -> bool;
+
     /// Returns true if the [`SharedMemory`] holds the ownership, otherwise false
     fn has_ownership(&self) -> bool;
 
@@ -165,6 +165,7 @@ pub mod details {
     #[repr(C)]
     struct Channel {
         state: AtomicU64,
+        enable_safe_overflow: bool,
         completion_queue: RelocatableIndexQueue,
         submission_queue: RelocatableSafelyOverflowingIndexQueue,
     }
@@ -179,6 +180,7 @@ pub mod details {
                     RelocatableIndexQueue::new_uninit(completion_queue_capacity)
                 },
                 state: AtomicU64::new(CHANNEL_STATE_OPEN.0),
+                enable_safe_overflow: DEFAULT_ENABLE_SAFE_OVERFLOW,
             }
         }
 
@@ -370,6 +372,7 @@ pub mod details {
         name: FileName,
         buffer_size: usize,
         enable_safe_overflow: bool,
+        channel_safe_overflow: Vec<(ChannelId, bool)>,
         max_borrowed_samples_per_channel: usize,
         number_of_samples_per_segment: usize,
         number_of_segments: u8,
@@ -388,6 +391,14 @@ pub mod details {
             self.buffer_size + self.max_borrowed_samples_per_channel + 1
         }
 
+        fn has_safe_overflow_for_channel(&self, channel_id: usize) -> bool {
+            self.channel_safe_overflow
+                .iter()
+                .rev()
+                .find(|(id, _)| id.value() == channel_id)
+                .map_or(self.enable_safe_overflow, |(_, value)| *value)
+        }
+
         fn create_or_open_shm(
             &self,
             port_to_register: State,
@@ -413,6 +424,9 @@ pub mod details {
             for channel in data.channels.iter() {
                 channel.state.store(self.initial_channel_state.0, Ordering::Relaxed);
             }
+            for n in 0..data.channels.len() {
+                data.channels[n].enable_safe_overflow = self.has_safe_overflow_for_channel(n);
+            }
 
             true
         })
@@ -505,6 +519,15 @@ pub mod details {
                         "{} since the requested number of channels is set to {} but should be set to {}.",
                         msg, self.number_of_channels, storage.get().channels.capacity());
                 }
+
+                for (n, channel) in storage.get().channels.iter().enumerate() {
+                    if channel.enable_safe_overflow != self.has_safe_overflow_for_channel(n) {
+                        cleanup_shared_memory(&storage, port_to_register);
+                        fail!(from self, with ZeroCopyCreationError::IncompatibleOverflowSetting,
+                            "{} since the safe overflow of channel {} is set to {} but should be set to {}.",
+                            msg, n, channel.enable_safe_overflow, self.has_safe_overflow_for_channel(n));
+                    }
+                }
             }
 
             Ok(storage)
@@ -519,6 +542,7 @@ pub mod details {
                 name: *name,
                 buffer_size: DEFAULT_BUFFER_SIZE,
                 enable_safe_overflow: DEFAULT_ENABLE_SAFE_OVERFLOW,
+                channel_safe_overflow: Vec::new(),
                 max_borrowed_samples_per_channel: DEFAULT_MAX_BORROWED_SAMPLES_PER_CHANNEL,
                 number_of_samples_per_segment: DEFAULT_NUMBER_OF_SAMPLES_PER_SEGMENT,
                 number_of_segments: DEFAULT_MAX_SUPPORTED_SHARED_MEMORY_SEGMENTS,
@@ -563,6 +587,11 @@ pub mod details {
             self
         }
 
+        fn enable_safe_overflow_for_channel(mut self, channel_id: ChannelId, value: bool) -> Self {
+            self.channel_safe_overflow.push((channel_id, value));
+            self
+        }
+
         fn number_of_samples_per_segment(mut self, value: usize) -> Self {
             self.number_of_samples_per_segment = value.clamp(1, usize::MAX);
             self
@@ -656,6 +685,11 @@ pub mod details {
             self.storage.get().enable_safe_overflow
         }
 
+        fn has_enabled_safe_overflow_for_channel(&self, channel_id: ChannelId) -> bool {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+            self.storage.get().channels[channel_id.value()].enable_safe_overflow
+        }
+
         fn is_connected(&self) -> bool {
             self.storage.get().is_connected()
         }
@@ -682,7 +716,7 @@ pub mod details {
             let msg = "Unable to send sample";
             let storage = self.storage.get();
 
-            if !storage.enable_safe_overflow
+            if !storage.channels[channel_id.value()].enable_safe_overflow
                 && storage.channels[channel_id.value()]
                     .submission_queue
                     .is_full()
@@ -746,7 +780,7 @@ pub mod details {
             debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
 
             let mgmt = self.storage.get();
-            if !mgmt.enable_safe_overflow {
+            if !mgmt.channels[channel_id.value()].enable_safe_overflow {
                 let mut is_connected = false;
                 let mut has_valid_channel_state = false;
                 let mut do_fail = false;
@@ -961,6 +995,11 @@ pub mod details {
             self.storage.get().enable_safe_overflow
         }
 
+        fn has_enabled_safe_overflow_for_channel(&self, channel_id: ChannelId) -> bool {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+            self.storage.get().channels[channel_id.value()].enable_safe_overflow
+        }
+
         fn is_connected(&self) -> bool {
             self.storage.get().is_connected()
         }
@@ -1108,7 +1147,7 @@ pub mod details {
                                fail!(from origin, with ZeroCopyPortRemoveError::DoesNotExist,
                                    "{msg} since the underlying dynamic storage does not exist.");
                            }
-                           Err(DynamicStorageOpenError::InternalError) => {
+                           Err(DynamicStorageOpenError::InternalError | DynamicStorageOpenError::InsufficientResources) => {
                                fail!(from origin, with ZeroCopyPortRemoveError::InternalError,
                                    "{msg} due to an internal error.");
                            }
@@ -125,6 +125,18 @@ pub(crate) fn describe_schema(config: &Config) -> Vec<Section> {
                     default_value: format!("\"{}\"", config.global.node.service_tag_suffix),
                     description: "Suffix added to the service tag of the node.",
                 },
+                Field {
+                    key: "global.node.heartbeat-suffix",
+                    value_type: "string",
+                    default_value: format!("\"{}\"", config.global.node.heartbeat_suffix),
+                    description: "Suffix added to the heartbeat of the node.",
+                },
+                Field {
+                    key: "global.node.control-suffix",
+                    value_type: "string",
+                    default_value: format!("\"{}\"", config.global.node.control_suffix),
+                    description: "Suffix added to the control channel of the node that receives shutdown requests.",
+                },
                 Field {
                     key: "global.node.cleanup-dead-nodes-on-creation",
                     value_type: "`true`|`false`",
@@ -213,6 +225,31 @@ pub(crate) fn describe_schema(config: &Config) -> Vec<Section> {
                     ),
                     description: "Defines if there shall be a scan for dead nodes with a following stale resource cleanup whenever an existing service is opened.",
                 },
+                Field {
+                    key: "global.service.enforce-read-only-data-segments",
+                    value_type: "string",
+                    default_value: format!(
+                        "\"{}\"",
+                        config.global.service.enforce_read_only_data_segments
+                    ),
+                    description: "Defines if a node can only be created when the service implementation maps the data segments of other ports read-only.",
+                },
+                Field {
+                    key: "global.service.name-remappings",
+                    value_type: "[string]",
+                    default_value: format!(
+                        "[{}]",
+                        config
+                            .global
+                            .service
+                            .name_remappings
+                            .iter()
+                            .map(|remapping| format!("\"{remapping}\""))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    description: "Service name remappings of the form \"from:=to\" that every node applies when it creates a service builder.",
+                },
             ],
         },
         Section {
@@ -321,6 +358,36 @@ pub(crate) fn describe_schema(config: &Config) -> Vec<Section> {
                     ),
                     description: "Default allocation strategy used by the publisher when the initially preallocated memory is insufficient.",
                 },
+                Field {
+                    key: "defaults.publish-subscribe.publisher-is-memory-locked",
+                    value_type: "`true`|`false`",
+                    default_value: config
+                        .defaults
+                        .publish_subscribe
+                        .publisher_is_memory_locked
+                        .to_string(),
+                    description: "Locks the data segment of the publisher into the RAM.",
+                },
+                Field {
+                    key: "defaults.publish-subscribe.publisher-prefault",
+                    value_type: "`true`|`false`",
+                    default_value: config
+                        .defaults
+                        .publish_subscribe
+                        .publisher_prefault
+                        .to_string(),
+                    description: "Touches every page of the data segment of the publisher on creation to avoid page faults.",
+                },
+                Field {
+                    key: "defaults.publish-subscribe.publisher-use-huge-pages",
+                    value_type: "`true`|`false`",
+                    default_value: config
+                        .defaults
+                        .publish_subscribe
+                        .publisher_use_huge_pages
+                        .to_string(),
+                    description: "Backs the data segment of the publisher with huge pages when available.",
+                },
             ],
         },
         Section {
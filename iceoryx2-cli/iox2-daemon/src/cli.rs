@@ -0,0 +1,62 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use clap::Parser;
+
+use iceoryx2_cli::Format;
+use iceoryx2_cli::help_template;
+
+#[derive(Parser)]
+#[command(
+    name = "iox2 daemon",
+    bin_name = "iox2 daemon",
+    about = "Monitor all iceoryx2 nodes, clean up the resources of dead nodes and report unresponsive nodes",
+    long_about = None,
+    version = env!("CARGO_PKG_VERSION"),
+    disable_help_subcommand = true,
+    arg_required_else_help = false,
+    help_template = help_template().build(),
+)]
+pub struct Cli {
+    #[clap(
+        short,
+        long,
+        default_value = "100",
+        help = "Monitoring rate in milliseconds"
+    )]
+    pub rate: u64,
+
+    #[clap(
+        long,
+        default_value = "1000",
+        help = "Maximum time in milliseconds to wait for another instance that is cleaning up the same dead node"
+    )]
+    pub cleanup_timeout: u64,
+
+    #[clap(long, help = "Do not publish dead nodes and node health changes")]
+    pub disable_publish: bool,
+
+    #[clap(long, default_value = "10", help = "The maximum number of subscribers")]
+    pub max_subscribers: usize,
+
+    #[clap(long, help = "Do not notify of dead nodes and node health changes")]
+    pub disable_notify: bool,
+
+    #[clap(long, default_value = "10", help = "The maximum number of listeners")]
+    pub max_listeners: usize,
+
+    #[clap(long, help = "Do not print dead nodes and node health changes")]
+    pub quiet: bool,
+
+    #[clap(long, short = 'f', value_enum, default_value_t = Format::Ron)]
+    pub format: Format,
+}
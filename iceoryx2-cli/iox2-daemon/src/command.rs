@@ -0,0 +1,159 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+use anyhow::Result;
+use anyhow::anyhow;
+use iceoryx2::prelude::*;
+use iceoryx2_cli::Format;
+use iceoryx2_cli::output::NodeIdString;
+use iceoryx2_pal_posix::posix::pid_t;
+use iceoryx2_services_process_monitor::health_monitor::Config as HealthConfig;
+use iceoryx2_services_process_monitor::health_monitor::NodeHealth;
+use iceoryx2_services_process_monitor::health_monitor::Service as HealthService;
+use iceoryx2_services_process_monitor::process_monitor::Config as MonitorConfig;
+use iceoryx2_services_process_monitor::process_monitor::DeadNode;
+use iceoryx2_services_process_monitor::process_monitor::Service as MonitorService;
+
+#[derive(serde::Serialize)]
+struct DeadNodeDescriptor {
+    id: NodeIdString,
+    pid: pid_t,
+    name: String,
+    cleaned_up: bool,
+}
+
+impl From<&DeadNode> for DeadNodeDescriptor {
+    fn from(node: &DeadNode) -> Self {
+        DeadNodeDescriptor {
+            id: NodeIdString::from(&node.node_id),
+            pid: node.node_id.pid().value(),
+            name: String::from_utf8_lossy(&node.node_name).to_string(),
+            cleaned_up: node.is_cleaned_up,
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+struct NodeHealthDescriptor {
+    id: NodeIdString,
+    pid: pid_t,
+    name: String,
+    state: String,
+    heartbeat_interval_ms: u128,
+    time_since_last_heartbeat_ms: u128,
+}
+
+impl From<&NodeHealth> for NodeHealthDescriptor {
+    fn from(health: &NodeHealth) -> Self {
+        NodeHealthDescriptor {
+            id: NodeIdString::from(&health.node_id),
+            pid: health.node_id.pid().value(),
+            name: String::from_utf8_lossy(&health.node_name).to_string(),
+            state: format!("{:?}", health.state),
+            heartbeat_interval_ms: Duration::from(health.heartbeat_interval).as_millis(),
+            time_since_last_heartbeat_ms: Duration::from(health.time_since_last_heartbeat)
+                .as_millis(),
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn run(
+    rate: u64,
+    cleanup_timeout: u64,
+    publish_events: bool,
+    max_subscribers: usize,
+    send_notifications: bool,
+    max_listeners: usize,
+    quiet: bool,
+    format: Format,
+) -> Result<()> {
+    let monitor_config = MonitorConfig {
+        publish_events,
+        max_subscribers,
+        send_notifications,
+        max_listeners,
+        cleanup_timeout: Duration::from_millis(cleanup_timeout),
+        ..Default::default()
+    };
+
+    let mut service =
+        MonitorService::<ipc::Service>::create(&monitor_config, Config::global_config())
+            .map_err(|e| anyhow!("failed to create service: {:?}", e))?;
+
+    let health_config = HealthConfig {
+        publish_events,
+        max_subscribers,
+        send_notifications,
+        max_listeners,
+        ..Default::default()
+    };
+
+    let mut health_service =
+        HealthService::<ipc::Service>::create(&health_config, Config::global_config())
+            .map_err(|e| anyhow!("failed to create health service: {:?}", e))?;
+
+    if !quiet {
+        println!("Monitoring Nodes (rate: {rate}ms)");
+    }
+
+    // a SIGTERM, e.g. sent by systemd, stops the waitset and shuts the daemon down gracefully
+    let waitset = WaitSetBuilder::new()
+        .signal_handling_mode(SignalHandlingMode::HandleTerminationRequests)
+        .create::<ipc::Service>()?;
+    let guard = waitset
+        .attach_interval(Duration::from_millis(rate))
+        .map_err(|e| anyhow!("failed to attach interval to waitset: {:?}", e))?;
+    let tick = WaitSetAttachmentId::from_guard(&guard);
+
+    let on_event = |id: WaitSetAttachmentId<ipc::Service>| {
+        if id == tick {
+            let on_dead_node = |node: &DeadNode| {
+                if !quiet {
+                    println!(
+                        "{}",
+                        format
+                            .as_string(&DeadNodeDescriptor::from(node))
+                            .unwrap_or_default()
+                    )
+                }
+            };
+            if let Err(e) = service.spin(on_dead_node) {
+                eprintln!("error while spinning service: {e:?}");
+            }
+
+            let on_health_change = |health: &NodeHealth| {
+                if !quiet {
+                    println!(
+                        "{}",
+                        format
+                            .as_string(&NodeHealthDescriptor::from(health))
+                            .unwrap_or_default()
+                    )
+                }
+            };
+            if let Err(e) = health_service.spin(on_health_change) {
+                eprintln!("error while spinning health service: {e:?}");
+            }
+        }
+
+        CallbackProgression::Continue
+    };
+
+    waitset
+        .wait_and_process(on_event)
+        .map_err(|e| anyhow!("error waiting on waitset: {:?}", e))?;
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::path::PathBuf;
+
+use clap::Args;
+use clap::Parser;
+use clap::Subcommand;
+use clap::ValueEnum;
+
+use iceoryx2_cli::Format;
+use iceoryx2_cli::filter::NodeIdentifier;
+use iceoryx2_cli::help_template;
+use iceoryx2_services_logging::record::Level;
+
+#[derive(Parser)]
+#[command(
+    name = "iox2 log",
+    bin_name = "iox2 log",
+    about = "Tail and collect the logs of all iceoryx2 nodes",
+    long_about = None,
+    version = env!("CARGO_PKG_VERSION"),
+    disable_help_subcommand = true,
+    arg_required_else_help = false,
+    help_template = help_template().with_subcommands().build(),
+)]
+pub struct Cli {
+    #[clap(subcommand)]
+    pub action: Option<Action>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+#[value(rename_all = "UPPERCASE")]
+pub enum LevelFilter {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Fatal,
+}
+
+impl From<LevelFilter> for Level {
+    fn from(value: LevelFilter) -> Self {
+        match value {
+            LevelFilter::Trace => Level::Trace,
+            LevelFilter::Debug => Level::Debug,
+            LevelFilter::Info => Level::Info,
+            LevelFilter::Warn => Level::Warn,
+            LevelFilter::Error => Level::Error,
+            LevelFilter::Fatal => Level::Fatal,
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct TailOptions {
+    #[clap(
+        short,
+        long,
+        value_enum,
+        default_value_t = LevelFilter::Trace,
+        help = "Only show log records with at least this level"
+    )]
+    pub level: LevelFilter,
+
+    #[clap(
+        short,
+        long,
+        help = "Only show log records of the node with this name, ID or PID"
+    )]
+    pub node: Option<NodeIdentifier>,
+
+    #[clap(
+        short,
+        long,
+        help = "Only show log records whose origin contains this string"
+    )]
+    pub origin: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        default_value = "100",
+        help = "Polling rate in milliseconds"
+    )]
+    pub rate: u64,
+
+    #[clap(
+        long,
+        short = 'f',
+        value_enum,
+        help = "Print structured log records instead of log lines"
+    )]
+    pub format: Option<Format>,
+}
+
+#[derive(Args)]
+pub struct CollectOptions {
+    #[clap(short, long, help = "Directory in which the log files are stored")]
+    pub directory: PathBuf,
+
+    #[clap(
+        short,
+        long,
+        default_value = "iceoryx2",
+        help = "Name of the log files, the active one is <PREFIX>.log"
+    )]
+    pub prefix: String,
+
+    #[clap(
+        long,
+        default_value = "10485760",
+        help = "Size in bytes after which the log file is rotated"
+    )]
+    pub max_file_size: u64,
+
+    #[clap(
+        long,
+        default_value = "5",
+        help = "Maximum number of log files, including the active one"
+    )]
+    pub max_files: usize,
+
+    #[clap(
+        short,
+        long,
+        value_enum,
+        default_value_t = LevelFilter::Trace,
+        help = "Only store log records with at least this level"
+    )]
+    pub level: LevelFilter,
+
+    #[clap(
+        short,
+        long,
+        default_value = "100",
+        help = "Polling rate in milliseconds"
+    )]
+    pub rate: u64,
+}
+
+#[derive(Subcommand)]
+pub enum Action {
+    #[clap(about = "Print the log records of all nodes", help_template = help_template().build())]
+    Tail(TailOptions),
+    #[clap(about = "Write the log records of all nodes into rotated files", help_template = help_template().build())]
+    Collect(CollectOptions),
+}
@@ -0,0 +1,140 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+use anyhow::Result;
+use anyhow::anyhow;
+use iceoryx2::prelude::*;
+use iceoryx2_bb_container::string::String as _;
+use iceoryx2_cli::Format;
+use iceoryx2_cli::output::NodeIdString;
+use iceoryx2_pal_posix::posix::pid_t;
+use iceoryx2_services_logging::collector::{Collector, CollectorConfig};
+use iceoryx2_services_logging::record::LogRecord;
+use iceoryx2_services_logging::service::{Config as LogServiceConfig, open_or_create};
+
+use crate::cli::{CollectOptions, TailOptions};
+use crate::filter::RecordFilter;
+
+#[derive(serde::Serialize)]
+struct LogRecordDescriptor {
+    level: String,
+    node_id: NodeIdString,
+    pid: pid_t,
+    timestamp_us: u128,
+    origin: String,
+    message: String,
+}
+
+impl From<&LogRecord> for LogRecordDescriptor {
+    fn from(record: &LogRecord) -> Self {
+        LogRecordDescriptor {
+            level: record.level.to_string(),
+            node_id: NodeIdString::from(&record.node_id),
+            pid: record.node_id.pid().value(),
+            timestamp_us: Duration::from(record.timestamp).as_micros(),
+            origin: record.origin.as_str().to_string(),
+            message: record.message.as_str().to_string(),
+        }
+    }
+}
+
+fn print_record(record: &LogRecord, format: Option<Format>) {
+    match format {
+        None => println!("{record}"),
+        Some(format) => println!(
+            "{}",
+            format
+                .as_string(&LogRecordDescriptor::from(record))
+                .unwrap_or_default()
+        ),
+    }
+}
+
+// a SIGTERM or SIGINT stops the waitset and ends the command gracefully
+fn run_periodically<F: FnMut()>(rate: u64, mut on_tick: F) -> Result<()> {
+    let waitset = WaitSetBuilder::new()
+        .signal_handling_mode(SignalHandlingMode::HandleTerminationRequests)
+        .create::<ipc::Service>()?;
+    let guard = waitset
+        .attach_interval(Duration::from_millis(rate))
+        .map_err(|e| anyhow!("failed to attach interval to waitset: {:?}", e))?;
+    let tick = WaitSetAttachmentId::from_guard(&guard);
+
+    waitset
+        .wait_and_process(|id| {
+            if id == tick {
+                on_tick();
+            }
+            CallbackProgression::Continue
+        })
+        .map_err(|e| anyhow!("error waiting on waitset: {:?}", e))?;
+
+    Ok(())
+}
+
+pub(crate) fn tail(options: TailOptions) -> Result<()> {
+    let node = NodeBuilder::new().create::<ipc::Service>()?;
+    let service = open_or_create(&node, &LogServiceConfig::default())
+        .map_err(|e| anyhow!("failed to open log service: {:?}", e))?;
+    let subscriber = service
+        .subscriber_builder()
+        .create()
+        .map_err(|e| anyhow!("failed to create subscriber: {:?}", e))?;
+
+    let mut filter = RecordFilter::new(options.level.into(), options.node, options.origin);
+
+    run_periodically(options.rate, || {
+        loop {
+            match subscriber.receive() {
+                Ok(Some(sample)) => {
+                    if filter.matches(sample.payload()) {
+                        print_record(sample.payload(), options.format);
+                    }
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    eprintln!("error while receiving log records: {e:?}");
+                    break;
+                }
+            }
+        }
+    })
+}
+
+pub(crate) fn collect(options: CollectOptions) -> Result<()> {
+    let collector_config = CollectorConfig {
+        directory: options.directory,
+        file_prefix: options.prefix,
+        max_file_size: options.max_file_size,
+        max_files: options.max_files,
+        min_level: options.level.into(),
+        service: LogServiceConfig::default(),
+    };
+
+    let mut collector =
+        Collector::<ipc::Service>::create(&collector_config, Config::global_config())
+            .map_err(|e| anyhow!("failed to create collector: {:?}", e))?;
+
+    println!(
+        "Collecting logs into {} (rate: {}ms)",
+        collector.log_file().display(),
+        options.rate
+    );
+
+    run_periodically(options.rate, || {
+        if let Err(e) = collector.spin(|_| {}) {
+            eprintln!("error while collecting log records: {e:?}");
+        }
+    })
+}
@@ -0,0 +1,83 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::collections::BTreeMap;
+
+use iceoryx2::identifiers::UniqueNodeId;
+use iceoryx2::node::{Node, NodeState, NodeView};
+use iceoryx2::prelude::*;
+use iceoryx2_bb_container::string::String as _;
+use iceoryx2_cli::filter::NodeIdentifier;
+use iceoryx2_cli::output::NodeIdString;
+use iceoryx2_services_logging::record::{Level, LogRecord};
+
+pub struct RecordFilter {
+    min_level: Level,
+    node: Option<NodeIdentifier>,
+    origin: Option<String>,
+    // the log records do not contain the node name, it is looked up once per node
+    node_names: BTreeMap<UniqueNodeId, Option<String>>,
+}
+
+impl RecordFilter {
+    pub fn new(min_level: Level, node: Option<NodeIdentifier>, origin: Option<String>) -> Self {
+        Self {
+            min_level,
+            node,
+            origin,
+            node_names: BTreeMap::new(),
+        }
+    }
+
+    pub fn matches(&mut self, record: &LogRecord) -> bool {
+        if record.level < self.min_level {
+            return false;
+        }
+
+        if let Some(origin) = &self.origin {
+            if !record.origin.as_str().contains(origin.as_str()) {
+                return false;
+            }
+        }
+
+        match &self.node {
+            None => true,
+            Some(NodeIdentifier::Id(id)) => NodeIdString::from(&record.node_id) == **id,
+            Some(NodeIdentifier::Pid(pid)) => record.node_id.pid().value() == *pid,
+            Some(NodeIdentifier::Name(name)) => {
+                let node_name = self
+                    .node_names
+                    .entry(record.node_id)
+                    .or_insert_with(|| lookup_node_name(&record.node_id));
+                node_name.as_deref() == Some(name.as_str())
+            }
+        }
+    }
+}
+
+fn lookup_node_name(node_id: &UniqueNodeId) -> Option<String> {
+    let mut node_name = None;
+    let _ = Node::<ipc::Service>::list(Config::global_config(), |node_state| {
+        if let NodeState::Alive(view) = node_state {
+            if view.id() == node_id {
+                node_name = view
+                    .details()
+                    .as_ref()
+                    .map(|details| details.name().as_str().to_string());
+                return CallbackProgression::Stop;
+            }
+        }
+        CallbackProgression::Continue
+    });
+
+    node_name
+}
@@ -59,10 +59,26 @@ pub struct DetailsOptions {
     pub filter: OutputFilter,
 }
 
+#[derive(Args)]
+pub struct StopOptions {
+    #[clap(help = "Name, ID or PID of the node")]
+    pub node: NodeIdentifier,
+}
+
+#[derive(Args)]
+pub struct CleanupOptions {
+    #[clap(long, help = "Only show which dead nodes would be removed")]
+    pub dry_run: bool,
+}
+
 #[derive(Subcommand)]
 pub enum Action {
     #[clap(about = "List all nodes", help_template = help_template().build())]
     List(ListOptions),
     #[clap(about = "Show node details", help_template = help_template().with_positionals().build())]
     Details(DetailsOptions),
+    #[clap(about = "Request the graceful shutdown of a node", help_template = help_template().with_positionals().build())]
+    Stop(StopOptions),
+    #[clap(about = "Remove the stale resources of all dead nodes", help_template = help_template().build())]
+    Cleanup(CleanupOptions),
 }
@@ -0,0 +1,68 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use anyhow::{Context, Result};
+use iceoryx2::node::{DeadNodeView, NodeState, NodeView};
+use iceoryx2::prelude::*;
+use iceoryx2_cli::Format;
+use iceoryx2_cli::output::NodeIdString;
+
+#[derive(serde::Serialize)]
+enum CleanupOutcome {
+    Removed,
+    WouldBeRemoved,
+    Failed(String),
+}
+
+#[derive(serde::Serialize)]
+struct CleanupDescriptor {
+    id: NodeIdString,
+    name: String,
+    outcome: CleanupOutcome,
+}
+
+pub(crate) fn cleanup(dry_run: bool, format: Format) -> Result<()> {
+    let mut dead_nodes = Vec::<DeadNodeView<ipc::Service>>::new();
+
+    Node::<ipc::Service>::list(Config::global_config(), |node| {
+        if let NodeState::Dead(view) = node {
+            dead_nodes.push(view);
+        }
+        CallbackProgression::Continue
+    })
+    .context("failed to retrieve nodes")?;
+
+    for view in dead_nodes {
+        let id = NodeIdString::from(view.id());
+        let name = view
+            .details()
+            .as_ref()
+            .map(|details| details.name().to_string())
+            .unwrap_or_default();
+
+        let outcome = if dry_run {
+            CleanupOutcome::WouldBeRemoved
+        } else {
+            match view.try_remove_stale_resources() {
+                Ok(()) => CleanupOutcome::Removed,
+                Err(e) => CleanupOutcome::Failed(format!("{e:?}")),
+            }
+        };
+
+        println!(
+            "{}",
+            format.as_string(&CleanupDescriptor { id, name, outcome })?
+        );
+    }
+
+    Ok(())
+}
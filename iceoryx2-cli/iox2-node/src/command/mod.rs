@@ -10,8 +10,12 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+mod cleanup;
 mod details;
 mod list;
+mod stop;
 
+pub(crate) use cleanup::*;
 pub(crate) use details::*;
 pub(crate) use list::*;
+pub(crate) use stop::*;
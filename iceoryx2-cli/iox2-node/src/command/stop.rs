@@ -0,0 +1,61 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use anyhow::{Context, Result, anyhow};
+use iceoryx2::node::{NodeState, NodeView};
+use iceoryx2::prelude::*;
+use iceoryx2_cli::Format;
+use iceoryx2_cli::filter::Filter;
+use iceoryx2_cli::filter::NodeIdentifier;
+use iceoryx2_cli::output::NodeIdString;
+
+#[derive(serde::Serialize)]
+struct ShutdownRequestDescriptor {
+    id: NodeIdString,
+    name: String,
+    requested: bool,
+    error: Option<String>,
+}
+
+pub(crate) fn stop(identifier: NodeIdentifier, format: Format) -> Result<()> {
+    let mut requests = Vec::<ShutdownRequestDescriptor>::new();
+
+    Node::<ipc::Service>::list(Config::global_config(), |node| {
+        if identifier.matches(&node) {
+            if let NodeState::Alive(view) = node {
+                let result = view.request_shutdown();
+                requests.push(ShutdownRequestDescriptor {
+                    id: NodeIdString::from(view.id()),
+                    name: view
+                        .details()
+                        .as_ref()
+                        .map(|details| details.name().to_string())
+                        .unwrap_or_default(),
+                    requested: result.is_ok(),
+                    error: result.err().map(|e| format!("{e:?}")),
+                });
+            }
+        }
+        CallbackProgression::Continue
+    })
+    .context("failed to retrieve nodes")?;
+
+    if requests.is_empty() {
+        return Err(anyhow!("no alive node matches the provided identifier"));
+    }
+
+    for request in &requests {
+        println!("{}", format.as_string(request)?);
+    }
+
+    Ok(())
+}
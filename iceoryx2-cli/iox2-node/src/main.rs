@@ -40,6 +40,16 @@ fn main() -> Result<()> {
                     eprintln!("Failed to retrieve node details: {e}");
                 }
             }
+            Action::Stop(options) => {
+                if let Err(e) = command::stop(options.node, cli.format) {
+                    eprintln!("Failed to request node shutdown: {e}");
+                }
+            }
+            Action::Cleanup(options) => {
+                if let Err(e) = command::cleanup(options.dry_run, cli.format) {
+                    eprintln!("Failed to cleanup dead nodes: {e}");
+                }
+            }
         }
     } else {
         Cli::command().print_help().expect("Failed to print help");
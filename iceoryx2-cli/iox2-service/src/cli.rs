@@ -59,6 +59,21 @@ pub struct DetailsOptions {
     pub filter: OutputFilter,
 }
 
+#[derive(Parser)]
+pub struct RemoveOptions {
+    #[clap(help = "Name of the service e.g. \"My Service\"")]
+    pub service: String,
+
+    #[command(flatten)]
+    pub filter: OutputFilter,
+
+    #[clap(long, help = "Remove the service even when it is used by alive nodes")]
+    pub force: bool,
+
+    #[clap(long, help = "Only show what would be removed")]
+    pub dry_run: bool,
+}
+
 #[derive(Parser)]
 pub struct DiscoveryOptions {
     #[clap(
@@ -460,6 +475,11 @@ pub enum Action {
         help_template = help_template().with_positionals().build()
     )]
     Details(DetailsOptions),
+    #[clap(
+        about = "Remove a service together with the stale resources of its dead nodes",
+        help_template = help_template().with_positionals().build()
+    )]
+    Remove(RemoveOptions),
     #[clap(
         about = "Runs the service discovery service within a process",
         help_template = help_template().build()
@@ -18,6 +18,7 @@ mod listen;
 mod notify;
 mod publish;
 mod record;
+mod remove;
 mod replay;
 mod subscribe;
 
@@ -29,6 +30,7 @@ pub(crate) use listen::*;
 pub(crate) use notify::*;
 pub(crate) use publish::*;
 pub(crate) use record::*;
+pub(crate) use remove::*;
 pub(crate) use replay::*;
 pub(crate) use subscribe::*;
 
@@ -39,7 +41,11 @@ use iceoryx2::{
     sample::Sample,
     service::{
         header::publish_subscribe::Header,
-        static_config::message_type_details::{TypeDetail, TypeVariant},
+        static_config::{
+            message_type_details::{TypeDetail, TypeVariant},
+            publish_subscribe,
+            type_schema::MessageTypeSchema,
+        },
     },
 };
 use iceoryx2_userland_record_and_replay::prelude::ServiceTypes;
@@ -62,10 +68,10 @@ struct EventFeedback {
     event_id: Option<usize>,
 }
 
-pub(crate) fn get_pubsub_service_types(
+fn get_pubsub_static_config(
     service_name: &ServiceName,
     node: &Node<ipc::Service>,
-) -> Result<ServiceTypes> {
+) -> Result<publish_subscribe::StaticConfig> {
     let service_details = match ipc::Service::details(
         service_name,
         node.config(),
@@ -79,33 +85,35 @@ pub(crate) fn get_pubsub_service_types(
         }
     };
 
-    let user_header = unsafe {
-        service_details
-            .static_details
-            .messaging_pattern()
-            .publish_subscribe()
-            .message_type_details()
-            .user_header
-    };
-
-    let payload = unsafe {
-        service_details
+    Ok(unsafe {
+        *service_details
             .static_details
             .messaging_pattern()
             .publish_subscribe()
-            .message_type_details()
-            .payload
-    };
+    })
+}
 
+pub(crate) fn get_pubsub_service_types(
+    service_name: &ServiceName,
+    node: &Node<ipc::Service>,
+) -> Result<ServiceTypes> {
+    let static_config = get_pubsub_static_config(service_name, node)?;
     let system_header = TypeDetail::new::<Header>(TypeVariant::FixedSize);
 
     Ok(ServiceTypes {
-        payload,
-        user_header,
+        payload: static_config.message_type_details().payload,
+        user_header: static_config.message_type_details().user_header,
         system_header,
     })
 }
 
+pub(crate) fn get_pubsub_message_type_schema(
+    service_name: &ServiceName,
+    node: &Node<ipc::Service>,
+) -> Result<MessageTypeSchema> {
+    Ok(*get_pubsub_static_config(service_name, node)?.message_type_schema())
+}
+
 pub(crate) fn extract_pubsub_payload<'a>(
     sample: &'a Sample<ipc::Service, [CustomPayloadMarker], CustomHeaderMarker>,
     user_header_type: &TypeDetail,
@@ -11,7 +11,9 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::cli::RecordOptions;
-use crate::command::{extract_pubsub_payload, get_pubsub_service_types};
+use crate::command::{
+    extract_pubsub_payload, get_pubsub_message_type_schema, get_pubsub_service_types,
+};
 use anyhow::Result;
 use core::time::Duration;
 use iceoryx2::prelude::*;
@@ -28,6 +30,7 @@ pub(crate) fn record(options: RecordOptions, _format: Format) -> Result<()> {
 
     let service_name = ServiceName::new(&options.service)?;
     let service_types = get_pubsub_service_types(&service_name, &node)?;
+    let type_schema = get_pubsub_message_type_schema(&service_name, &node)?;
 
     let service = unsafe {
         node.service_builder(&service_name)
@@ -40,6 +43,7 @@ pub(crate) fn record(options: RecordOptions, _format: Format) -> Result<()> {
 
     let subscriber = service.subscriber_builder().create()?;
     let mut recorder = RecorderBuilder::new(&service_types)
+        .type_schema(&type_schema)
         .data_representation(options.data_representation.into())
         .messaging_pattern(options.messaging_pattern.into())
         .create(&FilePath::new(options.output.as_bytes())?, &service_name)?;
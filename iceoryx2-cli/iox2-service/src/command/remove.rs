@@ -0,0 +1,111 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use anyhow::{Context, Result, anyhow};
+use iceoryx2::node::NodeState;
+use iceoryx2::prelude::*;
+use iceoryx2::service::ServiceDetails;
+use iceoryx2::service::static_config::messaging_pattern::MessagingPattern as StaticMessagingPattern;
+use iceoryx2::service::{ServiceRemoveError, ServiceRemovePolicy};
+use iceoryx2_cli::Format;
+use iceoryx2_cli::filter::Filter;
+use iceoryx2_cli::output::{NodeList, ServiceDescriptor};
+
+use crate::cli::RemoveOptions;
+
+#[derive(serde::Serialize)]
+enum RemovalOutcome {
+    Removed,
+    WouldBeRemoved,
+    Failed(String),
+}
+
+#[derive(serde::Serialize)]
+struct ServiceRemovalDescriptor {
+    service: ServiceDescriptor,
+    nodes: Option<NodeList>,
+    outcome: RemovalOutcome,
+}
+
+fn messaging_pattern(pattern: &StaticMessagingPattern) -> Option<MessagingPattern> {
+    match pattern {
+        StaticMessagingPattern::PublishSubscribe(_) => Some(MessagingPattern::PublishSubscribe),
+        StaticMessagingPattern::Event(_) => Some(MessagingPattern::Event),
+        StaticMessagingPattern::RequestResponse(_) => Some(MessagingPattern::RequestResponse),
+        StaticMessagingPattern::Blackboard(_) => Some(MessagingPattern::Blackboard),
+        _ => None,
+    }
+}
+
+fn has_alive_nodes(service: &ServiceDetails<ipc::Service>) -> bool {
+    service.dynamic_details.as_ref().is_some_and(|details| {
+        details
+            .nodes
+            .iter()
+            .any(|node| !matches!(node, NodeState::Dead(_)))
+    })
+}
+
+pub(crate) fn remove(options: RemoveOptions, format: Format) -> Result<()> {
+    let policy = if options.force {
+        ServiceRemovePolicy::Force
+    } else {
+        ServiceRemovePolicy::OnlyWithoutAliveNodes
+    };
+
+    let mut services = Vec::<ServiceDetails<ipc::Service>>::new();
+    ipc::Service::list(Config::global_config(), |service| {
+        if options.service == service.static_details.name().to_string()
+            && options.filter.matches(&service)
+        {
+            services.push(service);
+        }
+        CallbackProgression::Continue
+    })
+    .context("failed to retrieve services")?;
+
+    if services.is_empty() {
+        return Err(anyhow!("no service matches the provided name and pattern"));
+    }
+
+    for service in &services {
+        let outcome = match messaging_pattern(service.static_details.messaging_pattern()) {
+            None => RemovalOutcome::Failed("unsupported messaging pattern".to_string()),
+            Some(_) if options.dry_run => {
+                if policy == ServiceRemovePolicy::OnlyWithoutAliveNodes && has_alive_nodes(service)
+                {
+                    RemovalOutcome::Failed(format!("{:?}", ServiceRemoveError::InUse))
+                } else {
+                    RemovalOutcome::WouldBeRemoved
+                }
+            }
+            Some(pattern) => match ipc::Service::remove(
+                service.static_details.name(),
+                Config::global_config(),
+                pattern,
+                policy,
+            ) {
+                Ok(()) => RemovalOutcome::Removed,
+                Err(e) => RemovalOutcome::Failed(format!("{e:?}")),
+            },
+        };
+
+        let descriptor = ServiceRemovalDescriptor {
+            service: ServiceDescriptor::from(service),
+            nodes: service.dynamic_details.as_ref().map(NodeList::from),
+            outcome,
+        };
+        println!("{}", format.as_string(&descriptor)?);
+    }
+
+    Ok(())
+}
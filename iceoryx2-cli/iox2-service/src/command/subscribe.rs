@@ -11,12 +11,17 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::cli::{DataRepresentation, SubscribeOptions};
-use crate::command::{extract_pubsub_payload, get_pubsub_service_types};
+use crate::command::{
+    extract_pubsub_payload, get_pubsub_message_type_schema, get_pubsub_service_types,
+};
 use anyhow::Result;
 use iceoryx2::prelude::*;
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
+use iceoryx2::service::static_config::message_type_details::TypeVariant;
+use iceoryx2::service::static_config::type_schema::{MessageTypeSchema, SchemaValue};
 use iceoryx2_cli::Format;
 use iceoryx2_userland_record_and_replay::hex_conversion::bytes_to_hex_string;
+use iceoryx2_userland_record_and_replay::prelude::ServiceTypes;
 use std::time::Duration;
 use std::time::Instant;
 
@@ -28,12 +33,39 @@ struct Message {
     user_header: String,
     payload_len: usize,
     payload: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    decoded_user_header: Option<SchemaValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    decoded_payload: Option<SchemaValue>,
+}
+
+struct Decoder<'a> {
+    service_types: &'a ServiceTypes,
+    type_schema: &'a MessageTypeSchema,
+}
+
+impl Decoder<'_> {
+    fn decode_user_header(&self, user_header: &[u8]) -> Option<SchemaValue> {
+        if user_header.is_empty() {
+            return None;
+        }
+
+        self.type_schema.user_header.decode(user_header).ok()
+    }
+
+    fn decode_payload(&self, payload: &[u8]) -> Option<SchemaValue> {
+        match self.service_types.payload.variant() {
+            TypeVariant::FixedSize => self.type_schema.payload.decode(payload).ok(),
+            TypeVariant::Dynamic => self.type_schema.payload.decode_slice(payload).ok(),
+        }
+    }
 }
 
 fn print_hex_dump(
     system_header: &[u8],
     user_header: &[u8],
     payload: &[u8],
+    decoder: &Decoder,
     format: Format,
 ) -> Result<()> {
     let msg = Message {
@@ -43,6 +75,8 @@ fn print_hex_dump(
         user_header: bytes_to_hex_string(user_header),
         payload_len: payload.len(),
         payload: bytes_to_hex_string(payload),
+        decoded_user_header: decoder.decode_user_header(user_header),
+        decoded_payload: decoder.decode_payload(payload),
     };
 
     println!(
@@ -68,6 +102,8 @@ fn print_iox2_dump(
         user_header: bytes_to_hex_string(user_header),
         payload_len: payload.len(),
         payload: String::from_utf8_lossy(payload).to_string(),
+        decoded_user_header: None,
+        decoded_payload: None,
     };
 
     println!(
@@ -87,6 +123,11 @@ pub(crate) fn subscribe(options: SubscribeOptions, format: Format) -> Result<()>
 
     let service_name = ServiceName::new(&options.service)?;
     let service_types = get_pubsub_service_types(&service_name, &node)?;
+    let type_schema = get_pubsub_message_type_schema(&service_name, &node)?;
+    let decoder = Decoder {
+        service_types: &service_types,
+        type_schema: &type_schema,
+    };
 
     let service = unsafe {
         node.service_builder(&service_name)
@@ -112,7 +153,7 @@ pub(crate) fn subscribe(options: SubscribeOptions, format: Format) -> Result<()>
                     print_iox2_dump(system_header, user_header, payload, format)?;
                 }
                 DataRepresentation::HumanReadable => {
-                    print_hex_dump(system_header, user_header, payload, format)?;
+                    print_hex_dump(system_header, user_header, payload, &decoder, format)?;
                 }
             }
 
@@ -51,6 +51,11 @@ fn main() -> Result<()> {
                     error!("failed to retrieve service details: {}", e);
                 }
             }
+            Action::Remove(options) => {
+                if let Err(e) = command::remove(options, cli.format) {
+                    error!("failed to remove service: {}", e);
+                }
+            }
             Action::Publish(options) => {
                 if let Err(e) = command::publish(options, cli.format) {
                     error!("failed to publish messages: {}", e);
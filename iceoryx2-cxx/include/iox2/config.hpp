@@ -105,6 +105,12 @@ class Service {
     /// Sets if dead nodes shall be cleaned up whenever the `ServiceBuilder` opens
     /// an existing service.
     void set_cleanup_dead_nodes_on_open(bool value) &&;
+    /// When true, a `Node` can only be created when the `ServiceType` maps the data
+    /// segments of other ports read-only.
+    auto enforce_read_only_data_segments() && -> bool;
+    /// Sets if a `Node` can only be created when the `ServiceType` maps the data
+    /// segments of other ports read-only.
+    void set_enforce_read_only_data_segments(bool value) &&;
 
   private:
     friend class Global;
@@ -22,6 +22,10 @@ enum class DegradationCause : uint8_t {
     FailedToEstablishConnection,
     /// Connection is corrupted
     ConnectionCorrupted,
+    /// No data was sent within the deadline of the [`Service`]
+    MissedDeadline,
+    /// The [`Node`] of a connected [`Publisher`] died
+    PublisherLivelinessLost,
 };
 } // namespace iox2
 
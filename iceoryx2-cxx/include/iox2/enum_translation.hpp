@@ -122,6 +122,10 @@ constexpr auto from<int, iox2::NodeCreationFailure>(const int value) noexcept ->
         return iox2::NodeCreationFailure::InternalError;
     case iox2_node_creation_failure_e_SYSTEM_CORRUPTED:
         return iox2::NodeCreationFailure::SystemCorrupted;
+    case iox2_node_creation_failure_e_READ_ONLY_DATA_SEGMENTS_NOT_SUPPORTED:
+        return iox2::NodeCreationFailure::ReadOnlyDataSegmentsNotSupported;
+    case iox2_node_creation_failure_e_INVALID_SERVICE_NAME_REMAPPING:
+        return iox2::NodeCreationFailure::InvalidServiceNameRemapping;
     }
 
     IOX2_UNREACHABLE();
@@ -138,6 +142,10 @@ from<iox2::NodeCreationFailure, iox2_node_creation_failure_e>(const iox2::NodeCr
         return iox2_node_creation_failure_e_INTERNAL_ERROR;
     case iox2::NodeCreationFailure::SystemCorrupted:
         return iox2_node_creation_failure_e_SYSTEM_CORRUPTED;
+    case iox2::NodeCreationFailure::ReadOnlyDataSegmentsNotSupported:
+        return iox2_node_creation_failure_e_READ_ONLY_DATA_SEGMENTS_NOT_SUPPORTED;
+    case iox2::NodeCreationFailure::InvalidServiceNameRemapping:
+        return iox2_node_creation_failure_e_INVALID_SERVICE_NAME_REMAPPING;
     }
 
     IOX2_UNREACHABLE();
@@ -219,6 +227,8 @@ constexpr auto from<int, iox2::NodeWaitFailure>(const int value) noexcept -> iox
         return iox2::NodeWaitFailure::TerminationRequest;
     case iox2_node_wait_failure_e_INTERRUPT:
         return iox2::NodeWaitFailure::Interrupt;
+    case iox2_node_wait_failure_e_SHUTDOWN_REQUEST:
+        return iox2::NodeWaitFailure::ShutdownRequest;
     }
 
     IOX2_UNREACHABLE();
@@ -232,6 +242,8 @@ constexpr auto from<iox2::NodeWaitFailure, iox2_node_wait_failure_e>(const iox2:
         return iox2_node_wait_failure_e_TERMINATION_REQUEST;
     case iox2::NodeWaitFailure::Interrupt:
         return iox2_node_wait_failure_e_INTERRUPT;
+    case iox2::NodeWaitFailure::ShutdownRequest:
+        return iox2_node_wait_failure_e_SHUTDOWN_REQUEST;
     }
 
     IOX2_UNREACHABLE();
@@ -613,6 +625,22 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR:
         return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleOverflowBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_NUMBER_OF_PRIORITIES:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenDoesNotSupportRequestedNumberOfPriorities;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SUBSCRIBER_WAKEUP:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleSubscriberWakeup;
+    case iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MIN_HISTORY_CACHE_SIZE:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenDoesNotSupportRequestedMinHistoryCacheSize;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_DEADLINE:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleDeadline;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPE_VERSION:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleTypeVersion;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPE_SCHEMA:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleTypeSchema;
+    case iox2_pub_sub_open_or_create_error_e_O_TYPE_SCHEMA_IS_NOT_APPEND_ONLY:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenTypeSchemaIsNotAppendOnly;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_COPY_ON_SEND:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleCopyOnSend;
     case iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::PublishSubscribeOpenOrCreateError::OpenInsufficientPermissions;
     case iox2_pub_sub_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE:
@@ -675,6 +703,22 @@ constexpr auto from<int, iox2::PublishSubscribeOpenError>(const int value) noexc
         return iox2::PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR:
         return iox2::PublishSubscribeOpenError::IncompatibleOverflowBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_NUMBER_OF_PRIORITIES:
+        return iox2::PublishSubscribeOpenError::DoesNotSupportRequestedNumberOfPriorities;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SUBSCRIBER_WAKEUP:
+        return iox2::PublishSubscribeOpenError::IncompatibleSubscriberWakeup;
+    case iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MIN_HISTORY_CACHE_SIZE:
+        return iox2::PublishSubscribeOpenError::DoesNotSupportRequestedMinHistoryCacheSize;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_DEADLINE:
+        return iox2::PublishSubscribeOpenError::IncompatibleDeadline;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPE_VERSION:
+        return iox2::PublishSubscribeOpenError::IncompatibleTypeVersion;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPE_SCHEMA:
+        return iox2::PublishSubscribeOpenError::IncompatibleTypeSchema;
+    case iox2_pub_sub_open_or_create_error_e_O_TYPE_SCHEMA_IS_NOT_APPEND_ONLY:
+        return iox2::PublishSubscribeOpenError::TypeSchemaIsNotAppendOnly;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_COPY_ON_SEND:
+        return iox2::PublishSubscribeOpenError::IncompatibleCopyOnSend;
     case iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::PublishSubscribeOpenError::InsufficientPermissions;
     case iox2_pub_sub_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE:
@@ -720,6 +764,22 @@ constexpr auto from<iox2::PublishSubscribeOpenError, iox2_pub_sub_open_or_create
         return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
     case iox2::PublishSubscribeOpenError::IncompatibleOverflowBehavior:
         return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR;
+    case iox2::PublishSubscribeOpenError::DoesNotSupportRequestedNumberOfPriorities:
+        return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_NUMBER_OF_PRIORITIES;
+    case iox2::PublishSubscribeOpenError::IncompatibleSubscriberWakeup:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SUBSCRIBER_WAKEUP;
+    case iox2::PublishSubscribeOpenError::DoesNotSupportRequestedMinHistoryCacheSize:
+        return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MIN_HISTORY_CACHE_SIZE;
+    case iox2::PublishSubscribeOpenError::IncompatibleDeadline:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_DEADLINE;
+    case iox2::PublishSubscribeOpenError::IncompatibleTypeVersion:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPE_VERSION;
+    case iox2::PublishSubscribeOpenError::IncompatibleTypeSchema:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPE_SCHEMA;
+    case iox2::PublishSubscribeOpenError::TypeSchemaIsNotAppendOnly:
+        return iox2_pub_sub_open_or_create_error_e_O_TYPE_SCHEMA_IS_NOT_APPEND_ONLY;
+    case iox2::PublishSubscribeOpenError::IncompatibleCopyOnSend:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_COPY_ON_SEND;
     case iox2::PublishSubscribeOpenError::InsufficientPermissions:
         return iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::PublishSubscribeOpenError::ServiceInCorruptedState:
@@ -824,6 +884,22 @@ constexpr auto from<iox2::PublishSubscribeOpenOrCreateError, iox2_pub_sub_open_o
         return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
     case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleOverflowBehavior:
         return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenDoesNotSupportRequestedNumberOfPriorities:
+        return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_NUMBER_OF_PRIORITIES;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleSubscriberWakeup:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SUBSCRIBER_WAKEUP;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenDoesNotSupportRequestedMinHistoryCacheSize:
+        return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MIN_HISTORY_CACHE_SIZE;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleDeadline:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_DEADLINE;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleTypeVersion:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPE_VERSION;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleTypeSchema:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPE_SCHEMA;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenTypeSchemaIsNotAppendOnly:
+        return iox2_pub_sub_open_or_create_error_e_O_TYPE_SCHEMA_IS_NOT_APPEND_ONLY;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleCopyOnSend:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_COPY_ON_SEND;
     case iox2::PublishSubscribeOpenOrCreateError::OpenInsufficientPermissions:
         return iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::PublishSubscribeOpenOrCreateError::OpenServiceInCorruptedState:
@@ -946,6 +1022,18 @@ constexpr auto from<int, iox2::RequestResponseOpenError>(const int value) noexce
         return iox2::RequestResponseOpenError::IncompatibleRequestType;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_RESPONSE_TYPE:
         return iox2::RequestResponseOpenError::IncompatibleResponseType;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_TYPE_VERSION:
+        return iox2::RequestResponseOpenError::IncompatibleRequestTypeVersion;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_RESPONSE_TYPE_VERSION:
+        return iox2::RequestResponseOpenError::IncompatibleResponseTypeVersion;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_TYPE_SCHEMA:
+        return iox2::RequestResponseOpenError::IncompatibleRequestTypeSchema;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_RESPONSE_TYPE_SCHEMA:
+        return iox2::RequestResponseOpenError::IncompatibleResponseTypeSchema;
+    case iox2_request_response_open_or_create_error_e_O_REQUEST_TYPE_SCHEMA_IS_NOT_APPEND_ONLY:
+        return iox2::RequestResponseOpenError::RequestTypeSchemaIsNotAppendOnly;
+    case iox2_request_response_open_or_create_error_e_O_RESPONSE_TYPE_SCHEMA_IS_NOT_APPEND_ONLY:
+        return iox2::RequestResponseOpenError::ResponseTypeSchemaIsNotAppendOnly;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_ATTRIBUTES:
         return iox2::RequestResponseOpenError::IncompatibleAttributes;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_MESSAGING_PATTERN:
@@ -999,6 +1087,18 @@ constexpr auto from<iox2::RequestResponseOpenError, iox2_request_response_open_o
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_TYPE;
     case iox2::RequestResponseOpenError::IncompatibleResponseType:
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_RESPONSE_TYPE;
+    case iox2::RequestResponseOpenError::IncompatibleRequestTypeVersion:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_TYPE_VERSION;
+    case iox2::RequestResponseOpenError::IncompatibleResponseTypeVersion:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_RESPONSE_TYPE_VERSION;
+    case iox2::RequestResponseOpenError::IncompatibleRequestTypeSchema:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_TYPE_SCHEMA;
+    case iox2::RequestResponseOpenError::IncompatibleResponseTypeSchema:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_RESPONSE_TYPE_SCHEMA;
+    case iox2::RequestResponseOpenError::RequestTypeSchemaIsNotAppendOnly:
+        return iox2_request_response_open_or_create_error_e_O_REQUEST_TYPE_SCHEMA_IS_NOT_APPEND_ONLY;
+    case iox2::RequestResponseOpenError::ResponseTypeSchemaIsNotAppendOnly:
+        return iox2_request_response_open_or_create_error_e_O_RESPONSE_TYPE_SCHEMA_IS_NOT_APPEND_ONLY;
     case iox2::RequestResponseOpenError::IncompatibleAttributes:
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_ATTRIBUTES;
     case iox2::RequestResponseOpenError::IncompatibleMessagingPattern:
@@ -1059,6 +1159,18 @@ constexpr auto from<int, iox2::RequestResponseOpenOrCreateError>(const int value
         return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleRequestType;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_RESPONSE_TYPE:
         return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleResponseType;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_TYPE_VERSION:
+        return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleRequestTypeVersion;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_RESPONSE_TYPE_VERSION:
+        return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleResponseTypeVersion;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_TYPE_SCHEMA:
+        return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleRequestTypeSchema;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_RESPONSE_TYPE_SCHEMA:
+        return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleResponseTypeSchema;
+    case iox2_request_response_open_or_create_error_e_O_REQUEST_TYPE_SCHEMA_IS_NOT_APPEND_ONLY:
+        return iox2::RequestResponseOpenOrCreateError::OpenRequestTypeSchemaIsNotAppendOnly;
+    case iox2_request_response_open_or_create_error_e_O_RESPONSE_TYPE_SCHEMA_IS_NOT_APPEND_ONLY:
+        return iox2::RequestResponseOpenOrCreateError::OpenResponseTypeSchemaIsNotAppendOnly;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_ATTRIBUTES:
         return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleAttributes;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_MESSAGING_PATTERN:
@@ -1125,6 +1237,18 @@ constexpr auto from<iox2::RequestResponseOpenOrCreateError, iox2_request_respons
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_TYPE;
     case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleResponseType:
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_RESPONSE_TYPE;
+    case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleRequestTypeVersion:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_TYPE_VERSION;
+    case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleResponseTypeVersion:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_RESPONSE_TYPE_VERSION;
+    case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleRequestTypeSchema:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_TYPE_SCHEMA;
+    case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleResponseTypeSchema:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_RESPONSE_TYPE_SCHEMA;
+    case iox2::RequestResponseOpenOrCreateError::OpenRequestTypeSchemaIsNotAppendOnly:
+        return iox2_request_response_open_or_create_error_e_O_REQUEST_TYPE_SCHEMA_IS_NOT_APPEND_ONLY;
+    case iox2::RequestResponseOpenOrCreateError::OpenResponseTypeSchemaIsNotAppendOnly:
+        return iox2_request_response_open_or_create_error_e_O_RESPONSE_TYPE_SCHEMA_IS_NOT_APPEND_ONLY;
     case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleAttributes:
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_ATTRIBUTES;
     case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleMessagingPattern:
@@ -1645,6 +1769,10 @@ constexpr auto from<int, iox2::PublisherCreateError>(const int value) noexcept -
         return iox2::PublisherCreateError::UnableToCreateDataSegment;
     case iox2_publisher_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY:
         return iox2::PublisherCreateError::FailedToDeployThreadsafetyPolicy;
+    case iox2_publisher_create_error_e_INSUFFICIENT_RESOURCES:
+        return iox2::PublisherCreateError::InsufficientResources;
+    case iox2_publisher_create_error_e_UNABLE_TO_CREATE_WAKEUP_EVENT:
+        return iox2::PublisherCreateError::UnableToCreateWakeupEvent;
     }
 
     IOX2_UNREACHABLE();
@@ -1661,6 +1789,10 @@ from<iox2::PublisherCreateError, iox2_publisher_create_error_e>(const iox2::Publ
         return iox2_publisher_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT;
     case iox2::PublisherCreateError::FailedToDeployThreadsafetyPolicy:
         return iox2_publisher_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
+    case iox2::PublisherCreateError::InsufficientResources:
+        return iox2_publisher_create_error_e_INSUFFICIENT_RESOURCES;
+    case iox2::PublisherCreateError::UnableToCreateWakeupEvent:
+        return iox2_publisher_create_error_e_UNABLE_TO_CREATE_WAKEUP_EVENT;
     }
 
     IOX2_UNREACHABLE();
@@ -1682,6 +1814,10 @@ constexpr auto from<int, iox2::SubscriberCreateError>(const int value) noexcept
         return iox2::SubscriberCreateError::ExceedsMaxSupportedSubscribers;
     case iox2_subscriber_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY:
         return iox2::SubscriberCreateError::FailedToDeployThreadsafetyPolicy;
+    case iox2_subscriber_create_error_e_UNABLE_TO_CREATE_WAKEUP_EVENT:
+        return iox2::SubscriberCreateError::UnableToCreateWakeupEvent;
+    case iox2_subscriber_create_error_e_SUBSCRIBER_WAKEUP_NOT_ENABLED:
+        return iox2::SubscriberCreateError::SubscriberWakeupNotEnabled;
     }
 
     IOX2_UNREACHABLE();
@@ -1698,6 +1834,10 @@ from<iox2::SubscriberCreateError, iox2_subscriber_create_error_e>(const iox2::Su
         return iox2_subscriber_create_error_e_EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS;
     case iox2::SubscriberCreateError::FailedToDeployThreadsafetyPolicy:
         return iox2_subscriber_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
+    case iox2::SubscriberCreateError::UnableToCreateWakeupEvent:
+        return iox2_subscriber_create_error_e_UNABLE_TO_CREATE_WAKEUP_EVENT;
+    case iox2::SubscriberCreateError::SubscriberWakeupNotEnabled:
+        return iox2_subscriber_create_error_e_SUBSCRIBER_WAKEUP_NOT_ENABLED;
     }
 
     IOX2_UNREACHABLE();
@@ -1777,6 +1917,10 @@ constexpr auto from<int, iox2::ReceiveError>(const int value) noexcept -> iox2::
         return iox2::ReceiveError::UnableToMapSendersDataSegment;
     case iox2_receive_error_e_EXCEEDS_MAX_BORROWS:
         return iox2::ReceiveError::ExceedsMaxBorrows;
+    case iox2_receive_error_e_MISSED_DEADLINE:
+        return iox2::ReceiveError::MissedDeadline;
+    case iox2_receive_error_e_PUBLISHER_LIVELINESS_LOST:
+        return iox2::ReceiveError::PublisherLivelinessLost;
     }
 
     IOX2_UNREACHABLE();
@@ -1792,6 +1936,10 @@ constexpr auto from<iox2::ReceiveError, iox2_receive_error_e>(const iox2::Receiv
         return iox2_receive_error_e_UNABLE_TO_MAP_SENDERS_DATA_SEGMENT;
     case iox2::ReceiveError::ExceedsMaxBorrows:
         return iox2_receive_error_e_EXCEEDS_MAX_BORROWS;
+    case iox2::ReceiveError::MissedDeadline:
+        return iox2_receive_error_e_MISSED_DEADLINE;
+    case iox2::ReceiveError::PublisherLivelinessLost:
+        return iox2_receive_error_e_PUBLISHER_LIVELINESS_LOST;
     }
 
     IOX2_UNREACHABLE();
@@ -2356,6 +2504,10 @@ constexpr auto from<iox2_degradation_cause_e, iox2::DegradationCause>(const iox2
         return iox2::DegradationCause::FailedToEstablishConnection;
     case iox2_degradation_cause_e_CONNECTION_CORRUPTED:
         return iox2::DegradationCause::ConnectionCorrupted;
+    case iox2_degradation_cause_e_MISSED_DEADLINE:
+        return iox2::DegradationCause::MissedDeadline;
+    case iox2_degradation_cause_e_PUBLISHER_LIVELINESS_LOST:
+        return iox2::DegradationCause::PublisherLivelinessLost;
     }
 
     IOX2_UNREACHABLE();
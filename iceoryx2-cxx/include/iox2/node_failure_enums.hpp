@@ -36,6 +36,12 @@ enum class NodeCreationFailure : uint8_t {
     InternalError,
     /// Indicates that another "instance" on the system removed the resource required by the [`Node`].
     SystemCorrupted,
+    /// The [`Config`] enforces read-only data segments but the [`ServiceType`] is unable to
+    /// map the data segments of other ports read-only.
+    ReadOnlyDataSegmentsNotSupported,
+    /// The environment variable `IOX2_SERVICE_NAME_REMAPPINGS` contains an invalid
+    /// service name remapping.
+    InvalidServiceNameRemapping,
 };
 
 /// Failures of [`DeadNodeView::remove_stale_resources()`] that occur when the stale resources of
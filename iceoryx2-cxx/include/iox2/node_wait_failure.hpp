@@ -23,6 +23,8 @@ enum class NodeWaitFailure : uint8_t {
     TerminationRequest,
     /// SIGINT signal was received
     Interrupt,
+    /// Another process requested the shutdown of the [`Node`]
+    ShutdownRequest,
 };
 
 } // namespace iox2
@@ -73,7 +73,15 @@ enum class ReceiveError : uint8_t {
     FailedToEstablishConnection,
 
     /// Failures when mapping the corresponding data segment
-    UnableToMapSendersDataSegment
+    UnableToMapSendersDataSegment,
+
+    /// No data was sent within the deadline of the [`Service`] and the
+    /// [`DegradationHandler`] requested to fail.
+    MissedDeadline,
+
+    /// The [`Node`] of a connected [`Publisher`] died and the [`DegradationHandler`]
+    /// requested to fail.
+    PublisherLivelinessLost
 };
 
 /// Failure that can be emitted when a [`RequestMut`] is sent.
@@ -32,6 +32,11 @@ enum class PublisherCreateError : uint8_t {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`] as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The data segment could not be locked into memory or prefaulted since a
+    /// system limit, like the maximum amount of lockable memory, was exceeded.
+    InsufficientResources,
+    /// The event to wake up the [`Subscriber`]s could not be created.
+    UnableToCreateWakeupEvent,
 };
 } // namespace iox2
 
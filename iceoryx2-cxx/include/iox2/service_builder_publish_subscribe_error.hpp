@@ -49,6 +49,25 @@ enum class PublishSubscribeOpenError : uint8_t {
     DoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     IncompatibleOverflowBehavior,
+    /// The [`Service`] supports less priorities than requested.
+    DoesNotSupportRequestedNumberOfPriorities,
+    /// The [`Service`] required subscriber wakeup setting is not compatible.
+    IncompatibleSubscriberWakeup,
+    /// The [`Service`] has a smaller history cache than requested.
+    DoesNotSupportRequestedMinHistoryCacheSize,
+    /// The [`Service`]s deadline settings are not equal the the user given requirements.
+    IncompatibleDeadline,
+    /// The [`Service`] has a user header or payload type with a different version or,
+    /// with append-only schema compatibility, an older one.
+    IncompatibleTypeVersion,
+    /// The [`Service`] has a user header or payload type with the same name and
+    /// size but a different field layout.
+    IncompatibleTypeSchema,
+    /// With append-only schema compatibility, the user header or payload type of
+    /// the [`Service`] does not extend the requested type by only appending fields.
+    TypeSchemaIsNotAppendOnly,
+    /// The [`Service`] required copy on send setting is not compatible.
+    IncompatibleCopyOnSend,
     /// The process has not enough permissions to open the [`Service`]
     InsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing,
@@ -127,6 +146,25 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     OpenDoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     OpenIncompatibleOverflowBehavior,
+    /// The [`Service`] supports less priorities than requested.
+    OpenDoesNotSupportRequestedNumberOfPriorities,
+    /// The [`Service`] required subscriber wakeup setting is not compatible.
+    OpenIncompatibleSubscriberWakeup,
+    /// The [`Service`] has a smaller history cache than requested.
+    OpenDoesNotSupportRequestedMinHistoryCacheSize,
+    /// The [`Service`]s deadline settings are not equal the the user given requirements.
+    OpenIncompatibleDeadline,
+    /// The [`Service`] has a user header or payload type with a different version or,
+    /// with append-only schema compatibility, an older one.
+    OpenIncompatibleTypeVersion,
+    /// The [`Service`] has a user header or payload type with the same name and
+    /// size but a different field layout.
+    OpenIncompatibleTypeSchema,
+    /// With append-only schema compatibility, the user header or payload type of
+    /// the [`Service`] does not extend the requested type by only appending fields.
+    OpenTypeSchemaIsNotAppendOnly,
+    /// The [`Service`] required copy on send setting is not compatible.
+    OpenIncompatibleCopyOnSend,
     /// The process has not enough permissions to open the [`Service`]
     OpenInsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing,
@@ -46,6 +46,18 @@ enum class RequestResponseOpenError : uint8_t {
     IncompatibleRequestType,
     /// The [`Service`] has the wrong response payload type, response header type or type alignment.
     IncompatibleResponseType,
+    /// The [`Service`] has a request type with a different or, with append-only schema compatibility, an older version.
+    IncompatibleRequestTypeVersion,
+    /// The [`Service`] has a response type with a different or, with append-only schema compatibility, an older version.
+    IncompatibleResponseTypeVersion,
+    /// The [`Service`] has a request type with the same name and size but a different field layout.
+    IncompatibleRequestTypeSchema,
+    /// The [`Service`] has a response type with the same name and size but a different field layout.
+    IncompatibleResponseTypeSchema,
+    /// The request type of the [`Service`] does not extend the requested type by only appending fields.
+    RequestTypeSchemaIsNotAppendOnly,
+    /// The response type of the [`Service`] does not extend the requested type by only appending fields.
+    ResponseTypeSchemaIsNotAppendOnly,
     /// The [`AttributeVerifier`] required attributes that the [`Service`] does not satisfy.
     IncompatibleAttributes,
     /// The [`Service`] has the wrong messaging pattern.
@@ -115,6 +127,18 @@ enum class RequestResponseOpenOrCreateError : uint8_t {
     OpenIncompatibleRequestType,
     /// The [`Service`] has the wrong response payload type, response header type or type alignment.
     OpenIncompatibleResponseType,
+    /// The [`Service`] has a request type with a different or, with append-only schema compatibility, an older version.
+    OpenIncompatibleRequestTypeVersion,
+    /// The [`Service`] has a response type with a different or, with append-only schema compatibility, an older version.
+    OpenIncompatibleResponseTypeVersion,
+    /// The [`Service`] has a request type with the same name and size but a different field layout.
+    OpenIncompatibleRequestTypeSchema,
+    /// The [`Service`] has a response type with the same name and size but a different field layout.
+    OpenIncompatibleResponseTypeSchema,
+    /// The request type of the [`Service`] does not extend the requested type by only appending fields.
+    OpenRequestTypeSchemaIsNotAppendOnly,
+    /// The response type of the [`Service`] does not extend the requested type by only appending fields.
+    OpenResponseTypeSchemaIsNotAppendOnly,
     /// The [`AttributeVerifier`] required attributes that the [`Service`] does not satisfy.
     OpenIncompatibleAttributes,
     /// The [`Service`] has the wrong messaging pattern.
@@ -30,6 +30,12 @@ enum class SubscriberCreateError : uint8_t {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`] as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The event that wakes up the [`Subscriber`] when a new [`Sample`]
+    /// arrives could not be created.
+    UnableToCreateWakeupEvent,
+    /// A [`Subscriber`] with wakeup was requested but the [`Service`] was
+    /// created without subscriber wakeup.
+    SubscriberWakeupNotEnabled,
 };
 
 } // namespace iox2
@@ -465,6 +465,14 @@ void Service::set_cleanup_dead_nodes_on_open(bool value) && {
     iox2_config_global_service_set_cleanup_dead_nodes_on_open(m_config, value);
 }
 
+auto Service::enforce_read_only_data_segments() && -> bool {
+    return iox2_config_global_service_enforce_read_only_data_segments(m_config);
+}
+
+void Service::set_enforce_read_only_data_segments(bool value) && {
+    iox2_config_global_service_set_enforce_read_only_data_segments(m_config, value);
+}
+
 /////////////////////////
 // END: Service
 /////////////////////////
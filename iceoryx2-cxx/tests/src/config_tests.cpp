@@ -252,6 +252,14 @@ TEST(Config, global_service_cleanup_dead_nodes_on_open) {
     ASSERT_THAT(config.global().service().cleanup_dead_nodes_on_open(), Eq(test_value));
 }
 
+TEST(Config, global_service_enforce_read_only_data_segments) {
+    const auto test_value = true;
+    auto config = Config();
+
+    config.global().service().set_enforce_read_only_data_segments(test_value);
+    ASSERT_THAT(config.global().service().enforce_read_only_data_segments(), Eq(test_value));
+}
+
 TEST(Config, global_node_directory) {
     const auto test_value = iox2::bb::Path::create("eat/the/carrototier").value();
     auto config = Config();
@@ -66,12 +66,15 @@ TEST(EnumConversionTest, node_creation_failure_into_c_str) {
     using Sut = iox2::NodeCreationFailure;
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::InsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::InternalError)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ReadOnlyDataSegmentsNotSupported)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::InvalidServiceNameRemapping)), 1U);
 }
 
 TEST(EnumConversionTest, node_wait_failure_into_c_str) {
     using Sut = iox2::NodeWaitFailure;
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::TerminationRequest)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::Interrupt)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ShutdownRequest)), 1U);
 }
 
 TEST(EnumConversionTest, notifier_create_into_c_str) {
@@ -88,6 +91,8 @@ TEST(EnumConversionTest, publisher_create_into_c_str) {
     using Sut = iox2::PublisherCreateError;
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ExceedsMaxSupportedPublishers)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::UnableToCreateDataSegment)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::InsufficientResources)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::UnableToCreateWakeupEvent)), 1U);
 }
 
 TEST(EnumConversionTest, publisher_loan_into_c_str) {
@@ -175,6 +180,14 @@ TEST(EnumConversionTest, publish_subscribe_open_into_c_str) {
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::DoesNotSupportRequestedAmountOfSubscribers)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::DoesNotSupportRequestedAmountOfNodes)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::IncompatibleOverflowBehavior)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::DoesNotSupportRequestedNumberOfPriorities)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::IncompatibleSubscriberWakeup)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::DoesNotSupportRequestedMinHistoryCacheSize)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::IncompatibleDeadline)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::IncompatibleTypeVersion)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::IncompatibleTypeSchema)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::TypeSchemaIsNotAppendOnly)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::IncompatibleCopyOnSend)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::InsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ServiceInCorruptedState)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::HangsInCreation)), 1U);
@@ -207,6 +220,14 @@ TEST(EnumConversionTest, publish_subscribe_open_or_create_into_c_str) {
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenDoesNotSupportRequestedAmountOfSubscribers)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenDoesNotSupportRequestedAmountOfNodes)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenIncompatibleOverflowBehavior)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenDoesNotSupportRequestedNumberOfPriorities)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenIncompatibleSubscriberWakeup)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenDoesNotSupportRequestedMinHistoryCacheSize)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenIncompatibleDeadline)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenIncompatibleTypeVersion)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenIncompatibleTypeSchema)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenTypeSchemaIsNotAppendOnly)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenIncompatibleCopyOnSend)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenInsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenServiceInCorruptedState)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenHangsInCreation)), 1U);
@@ -244,12 +265,16 @@ TEST(EnumConversionTest, subscriber_receive_into_c_str) {
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ExceedsMaxBorrows)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::FailedToEstablishConnection)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::UnableToMapSendersDataSegment)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::MissedDeadline)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::PublisherLivelinessLost)), 1U);
 }
 
 TEST(EnumConversionTest, subscriber_create_into_c_str) {
     using Sut = iox2::SubscriberCreateError;
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ExceedsMaxSupportedSubscribers)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::BufferSizeExceedsMaxSupportedBufferSizeOfService)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::UnableToCreateWakeupEvent)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::SubscriberWakeupNotEnabled)), 1U);
 }
 
 TEST(EnumConversionTest, waitset_create_into_c_str) {
@@ -86,7 +86,7 @@ pub(super) struct ConfigOwner {
 #[repr(C)]
 #[repr(align(8))] // align_of<ConfigOwner>()
 pub struct iox2_config_storage_t {
-    internal: [u8; 4264], // size_of<ConfigOwner>()
+    internal: [u8; 4816], // size_of<ConfigOwner>()
 }
 
 /// Contains the iceoryx2 config
@@ -1095,6 +1095,53 @@ pub unsafe extern "C" fn iox2_config_global_service_set_cleanup_dead_nodes_on_op
     }
 }
 
+/// Returns if nodes can only be created when the service implementation maps the
+/// data segments of other ports read-only.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_config_global_service_enforce_read_only_data_segments(
+    handle: iox2_config_h_ref,
+) -> bool {
+    handle.assert_non_null();
+    unsafe {
+        let config = &*handle.as_type();
+        config
+            .value
+            .as_ref()
+            .value
+            .global
+            .service
+            .enforce_read_only_data_segments
+    }
+}
+
+/// Sets if nodes can only be created when the service implementation maps the
+/// data segments of other ports read-only.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_config_global_service_set_enforce_read_only_data_segments(
+    handle: iox2_config_h_ref,
+    value: bool,
+) {
+    handle.assert_non_null();
+    unsafe {
+        let config = &mut *handle.as_type();
+        config
+            .value
+            .as_mut()
+            .value
+            .global
+            .service
+            .enforce_read_only_data_segments = value;
+    }
+}
+
 /// Returns the duration how long another process will wait until an entity like a Node
 /// or Service creation is finalized
 ///
@@ -52,6 +52,10 @@ pub enum iox2_degradation_cause_e {
     FAILED_TO_ESTABLISH_CONNECTION,
     /// Connection is corrupted
     CONNECTION_CORRUPTED,
+    /// No data was sent within the deadline of the service
+    MISSED_DEADLINE,
+    /// The node of a connected publisher died
+    PUBLISHER_LIVELINESS_LOST,
 }
 
 impl From<iox2_degradation_cause_e> for DegradationCause {
@@ -61,6 +65,10 @@ impl From<iox2_degradation_cause_e> for DegradationCause {
                 DegradationCause::FailedToEstablishConnection
             }
             iox2_degradation_cause_e::CONNECTION_CORRUPTED => DegradationCause::ConnectionCorrupted,
+            iox2_degradation_cause_e::MISSED_DEADLINE => DegradationCause::MissedDeadline,
+            iox2_degradation_cause_e::PUBLISHER_LIVELINESS_LOST => {
+                DegradationCause::PublisherLivelinessLost
+            }
         }
     }
 }
@@ -72,6 +80,10 @@ impl From<DegradationCause> for iox2_degradation_cause_e {
                 iox2_degradation_cause_e::FAILED_TO_ESTABLISH_CONNECTION
             }
             DegradationCause::ConnectionCorrupted => iox2_degradation_cause_e::CONNECTION_CORRUPTED,
+            DegradationCause::MissedDeadline => iox2_degradation_cause_e::MISSED_DEADLINE,
+            DegradationCause::PublisherLivelinessLost => {
+                iox2_degradation_cause_e::PUBLISHER_LIVELINESS_LOST
+            }
         }
     }
 }
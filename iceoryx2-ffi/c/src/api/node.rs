@@ -70,6 +70,7 @@ impl IntoCInt for NodeListFailure {
 pub enum iox2_node_wait_failure_e {
     INTERRUPT = IOX2_OK as isize + 1,
     TERMINATION_REQUEST,
+    SHUTDOWN_REQUEST,
 }
 
 impl IntoCInt for NodeWaitFailure {
@@ -77,6 +78,7 @@ impl IntoCInt for NodeWaitFailure {
         (match self {
             NodeWaitFailure::TerminationRequest => iox2_node_wait_failure_e::TERMINATION_REQUEST,
             NodeWaitFailure::Interrupt => iox2_node_wait_failure_e::INTERRUPT,
+            NodeWaitFailure::ShutdownRequest => iox2_node_wait_failure_e::SHUTDOWN_REQUEST,
         }) as c_int
     }
 }
@@ -37,6 +37,8 @@ pub enum iox2_node_creation_failure_e {
     INSUFFICIENT_PERMISSIONS = IOX2_OK as isize + 1,
     INTERNAL_ERROR,
     SYSTEM_CORRUPTED,
+    READ_ONLY_DATA_SEGMENTS_NOT_SUPPORTED,
+    INVALID_SERVICE_NAME_REMAPPING,
 }
 
 impl IntoCInt for NodeCreationFailure {
@@ -47,6 +49,12 @@ impl IntoCInt for NodeCreationFailure {
             }
             NodeCreationFailure::InternalError => iox2_node_creation_failure_e::INTERNAL_ERROR,
             NodeCreationFailure::SystemCorrupted => iox2_node_creation_failure_e::SYSTEM_CORRUPTED,
+            NodeCreationFailure::ReadOnlyDataSegmentsNotSupported => {
+                iox2_node_creation_failure_e::READ_ONLY_DATA_SEGMENTS_NOT_SUPPORTED
+            }
+            NodeCreationFailure::InvalidServiceNameRemapping => {
+                iox2_node_creation_failure_e::INVALID_SERVICE_NAME_REMAPPING
+            }
         }) as c_int
     }
 }
@@ -98,6 +98,8 @@ pub enum iox2_publisher_create_error_e {
     EXCEEDS_MAX_SUPPORTED_PUBLISHERS = IOX2_OK as isize + 1,
     UNABLE_TO_CREATE_DATA_SEGMENT,
     FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY,
+    INSUFFICIENT_RESOURCES,
+    UNABLE_TO_CREATE_WAKEUP_EVENT,
 }
 
 impl IntoCInt for PublisherCreateError {
@@ -112,6 +114,12 @@ impl IntoCInt for PublisherCreateError {
             PublisherCreateError::FailedToDeployThreadsafetyPolicy => {
                 iox2_publisher_create_error_e::FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY
             }
+            PublisherCreateError::InsufficientResources => {
+                iox2_publisher_create_error_e::INSUFFICIENT_RESOURCES
+            }
+            PublisherCreateError::UnableToCreateWakeupEvent => {
+                iox2_publisher_create_error_e::UNABLE_TO_CREATE_WAKEUP_EVENT
+            }
         }) as c_int
     }
 }
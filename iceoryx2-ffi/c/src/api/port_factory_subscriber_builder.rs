@@ -37,6 +37,8 @@ pub enum iox2_subscriber_create_error_e {
     EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS = IOX2_OK as isize + 1,
     BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE,
     FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY,
+    UNABLE_TO_CREATE_WAKEUP_EVENT,
+    SUBSCRIBER_WAKEUP_NOT_ENABLED,
 }
 
 impl IntoCInt for SubscriberCreateError {
@@ -51,6 +53,12 @@ impl IntoCInt for SubscriberCreateError {
             SubscriberCreateError::FailedToDeployThreadsafetyPolicy => {
                 iox2_subscriber_create_error_e::FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY
             }
+            SubscriberCreateError::UnableToCreateWakeupEvent => {
+                iox2_subscriber_create_error_e::UNABLE_TO_CREATE_WAKEUP_EVENT
+            }
+            SubscriberCreateError::SubscriberWakeupNotEnabled => {
+                iox2_subscriber_create_error_e::SUBSCRIBER_WAKEUP_NOT_ENABLED
+            }
         }) as c_int
     }
 }
@@ -27,7 +27,7 @@ use crate::{
 #[repr(C)]
 #[repr(align(8))] // core::mem::align_of::<Option<Header>>()
 pub struct iox2_publish_subscribe_header_storage_t {
-    internal: [u8; 48], // core::mem::size_of::<Option<Header>>()
+    internal: [u8; 88], // core::mem::size_of::<Option<Header>>()
 }
 
 #[repr(C)]
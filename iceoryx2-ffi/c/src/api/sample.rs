@@ -49,7 +49,7 @@ impl SampleUnion {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<SampleUnion>
 pub struct iox2_sample_storage_t {
-    internal: [u8; 96], // magic number obtained with size_of::<Option<SampleUnion>>()
+    internal: [u8; 112], // magic number obtained with size_of::<Option<SampleUnion>>()
 }
 
 #[repr(C)]
@@ -65,6 +65,22 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES,
     #[CStr = "incompatible overflow behavior"]
     O_INCOMPATIBLE_OVERFLOW_BEHAVIOR,
+    #[CStr = "does not support requested number of priorities"]
+    O_DOES_NOT_SUPPORT_REQUESTED_NUMBER_OF_PRIORITIES,
+    #[CStr = "incompatible subscriber wakeup"]
+    O_INCOMPATIBLE_SUBSCRIBER_WAKEUP,
+    #[CStr = "does not support requested min history cache size"]
+    O_DOES_NOT_SUPPORT_REQUESTED_MIN_HISTORY_CACHE_SIZE,
+    #[CStr = "incompatible deadline"]
+    O_INCOMPATIBLE_DEADLINE,
+    #[CStr = "incompatible type version"]
+    O_INCOMPATIBLE_TYPE_VERSION,
+    #[CStr = "incompatible type schema"]
+    O_INCOMPATIBLE_TYPE_SCHEMA,
+    #[CStr = "type schema is not append only"]
+    O_TYPE_SCHEMA_IS_NOT_APPEND_ONLY,
+    #[CStr = "incompatible copy on send"]
+    O_INCOMPATIBLE_COPY_ON_SEND,
     #[CStr = "insufficient permissions"]
     O_INSUFFICIENT_PERMISSIONS,
     #[CStr = "service in corrupted state"]
@@ -132,6 +148,30 @@ impl IntoCInt for PublishSubscribeOpenError {
          PublishSubscribeOpenError::IncompatibleOverflowBehavior => {
              iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR
          }
+         PublishSubscribeOpenError::DoesNotSupportRequestedNumberOfPriorities => {
+             iox2_pub_sub_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_NUMBER_OF_PRIORITIES
+         }
+         PublishSubscribeOpenError::IncompatibleSubscriberWakeup => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_SUBSCRIBER_WAKEUP
+         }
+         PublishSubscribeOpenError::DoesNotSupportRequestedMinHistoryCacheSize => {
+             iox2_pub_sub_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_MIN_HISTORY_CACHE_SIZE
+         }
+         PublishSubscribeOpenError::IncompatibleDeadline => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_DEADLINE
+         }
+         PublishSubscribeOpenError::IncompatibleTypeVersion => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_TYPE_VERSION
+         }
+         PublishSubscribeOpenError::IncompatibleTypeSchema => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_TYPE_SCHEMA
+         }
+         PublishSubscribeOpenError::TypeSchemaIsNotAppendOnly => {
+             iox2_pub_sub_open_or_create_error_e::O_TYPE_SCHEMA_IS_NOT_APPEND_ONLY
+         }
+         PublishSubscribeOpenError::IncompatibleCopyOnSend => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_COPY_ON_SEND
+         }
          PublishSubscribeOpenError::InsufficientPermissions => {
              iox2_pub_sub_open_or_create_error_e::O_INSUFFICIENT_PERMISSIONS
          }
@@ -78,6 +78,18 @@ pub enum iox2_request_response_open_or_create_error_e {
     O_INCOMPATIBLE_REQUEST_TYPE,
     #[CStr = "incompatible response type"]
     O_INCOMPATIBLE_RESPONSE_TYPE,
+    #[CStr = "incompatible request type version"]
+    O_INCOMPATIBLE_REQUEST_TYPE_VERSION,
+    #[CStr = "incompatible response type version"]
+    O_INCOMPATIBLE_RESPONSE_TYPE_VERSION,
+    #[CStr = "incompatible request type schema"]
+    O_INCOMPATIBLE_REQUEST_TYPE_SCHEMA,
+    #[CStr = "incompatible response type schema"]
+    O_INCOMPATIBLE_RESPONSE_TYPE_SCHEMA,
+    #[CStr = "request type schema is not append only"]
+    O_REQUEST_TYPE_SCHEMA_IS_NOT_APPEND_ONLY,
+    #[CStr = "response type schema is not append only"]
+    O_RESPONSE_TYPE_SCHEMA_IS_NOT_APPEND_ONLY,
     #[CStr = "incompatible attributes"]
     O_INCOMPATIBLE_ATTRIBUTES,
     #[CStr = "incompatible messaging pattern"]
@@ -127,6 +139,12 @@ impl IntoCInt for RequestResponseOpenError {
             RequestResponseOpenError::HangsInCreation => iox2_request_response_open_or_create_error_e::O_HANGS_IN_CREATION,
             RequestResponseOpenError::IncompatibleRequestType => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_REQUEST_TYPE,
             RequestResponseOpenError::IncompatibleResponseType => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_RESPONSE_TYPE,
+            RequestResponseOpenError::IncompatibleRequestTypeVersion => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_REQUEST_TYPE_VERSION,
+            RequestResponseOpenError::IncompatibleResponseTypeVersion => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_RESPONSE_TYPE_VERSION,
+            RequestResponseOpenError::IncompatibleRequestTypeSchema => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_REQUEST_TYPE_SCHEMA,
+            RequestResponseOpenError::IncompatibleResponseTypeSchema => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_RESPONSE_TYPE_SCHEMA,
+            RequestResponseOpenError::RequestTypeSchemaIsNotAppendOnly => iox2_request_response_open_or_create_error_e::O_REQUEST_TYPE_SCHEMA_IS_NOT_APPEND_ONLY,
+            RequestResponseOpenError::ResponseTypeSchemaIsNotAppendOnly => iox2_request_response_open_or_create_error_e::O_RESPONSE_TYPE_SCHEMA_IS_NOT_APPEND_ONLY,
             RequestResponseOpenError::IncompatibleAttributes => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_ATTRIBUTES,
             RequestResponseOpenError::IncompatibleMessagingPattern => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_MESSAGING_PATTERN,
             RequestResponseOpenError::IncompatibleOverflowBehaviorForRequests => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_REQUESTS,
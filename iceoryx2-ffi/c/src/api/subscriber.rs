@@ -37,6 +37,8 @@ pub enum iox2_receive_error_e {
     EXCEEDS_MAX_BORROWS = IOX2_OK as isize + 1,
     FAILED_TO_ESTABLISH_CONNECTION,
     UNABLE_TO_MAP_SENDERS_DATA_SEGMENT,
+    MISSED_DEADLINE,
+    PUBLISHER_LIVELINESS_LOST,
 }
 
 impl IntoCInt for ReceiveError {
@@ -49,6 +51,10 @@ impl IntoCInt for ReceiveError {
             ReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapSendersDataSegment(
                 _,
             )) => iox2_receive_error_e::UNABLE_TO_MAP_SENDERS_DATA_SEGMENT,
+            ReceiveError::MissedDeadline => iox2_receive_error_e::MISSED_DEADLINE,
+            ReceiveError::PublisherLivelinessLost => {
+                iox2_receive_error_e::PUBLISHER_LIVELINESS_LOST
+            }
         }) as c_int
     }
 }
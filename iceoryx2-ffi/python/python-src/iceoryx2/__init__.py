@@ -13,6 +13,7 @@
 """Python language bindings for iceoryx2."""
 
 from ._iceoryx2 import *
+from .asyncio_extensions import *
 from .blackboard_extensions import *
 from .publish_subscribe_extensions import *
 from .request_response_extensions import *
@@ -0,0 +1,161 @@
+# Copyright (c) 2026 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+"""Awaitable extensions to integrate the ports and the `WaitSet` into `asyncio`."""
+
+import asyncio
+from typing import AsyncIterator, List, Optional, Tuple
+
+from ._iceoryx2 import *
+
+
+async def _wait_until_readable(
+    native_handles: List[int], timeout: Optional[float] = None
+) -> None:
+    loop = asyncio.get_running_loop()
+    is_readable = loop.create_future()
+
+    def on_readable() -> None:
+        if not is_readable.done():
+            is_readable.set_result(None)
+
+    for native_handle in native_handles:
+        loop.add_reader(native_handle, on_readable)
+    try:
+        await asyncio.wait_for(is_readable, timeout)
+    except asyncio.TimeoutError:
+        pass
+    finally:
+        for native_handle in native_handles:
+            loop.remove_reader(native_handle)
+
+
+async def wait_one(self: Listener) -> EventId:
+    """
+    Awaits a new `EventId` without blocking the running event loop.
+
+    The file descriptor of the `Listener` is registered at the running loop,
+    therefore the loop can process other tasks until an `EventId` is notified.
+    On error it emits `ListenerWaitError`.
+    """
+    while True:
+        event_id = self.try_wait_one()
+        if event_id is not None:
+            return event_id
+
+        await _wait_until_readable([self.file_descriptor.native_handle])
+
+
+async def wait_all(self: Listener) -> List[EventId]:
+    """
+    Awaits new `EventId`s without blocking the running event loop and returns
+    all `EventId`s that were received.
+
+    On error it emits `ListenerWaitError`.
+    """
+    while True:
+        event_ids = self.try_wait_all()
+        if len(event_ids) != 0:
+            return event_ids
+
+        await _wait_until_readable([self.file_descriptor.native_handle])
+
+
+async def receive_async(
+    self: Subscriber, listener: Optional[Listener] = None
+) -> Sample:
+    """
+    Awaits the next `Sample` without blocking the running event loop.
+
+    The `Subscriber` awaits the notifications of the provided `Listener`, that
+    is notified whenever a new `Sample` was sent. Without a `Listener` the
+    `Subscriber` requires a wakeup, see
+    `ServiceBuilderPublishSubscribe.enable_subscriber_wakeup()`, otherwise it
+    emits `ValueError`.
+    On error it emits `ReceiveError`.
+    """
+    wakeup = None
+    if listener is None:
+        wakeup = self.wakeup_file_descriptor()
+        if wakeup is None:
+            raise ValueError(
+                "The Subscriber has no wakeup. Either provide a Listener or "
+                "create the service with enable_subscriber_wakeup(True)."
+            )
+
+    while True:
+        sample = self.receive()
+        if sample is not None:
+            return sample
+
+        if listener is not None:
+            await wait_all(listener)
+        else:
+            await _wait_until_readable([wakeup.native_handle])
+
+
+async def samples(
+    self: Subscriber, listener: Optional[Listener] = None
+) -> AsyncIterator[Sample]:
+    """
+    Returns an asynchronous iterator that yields every received `Sample`.
+
+    See `Subscriber.receive_async()` for the meaning of `listener`.
+    """
+    while True:
+        yield await receive_async(self, listener)
+
+
+async def wait_and_process_async(
+    self: WaitSet, timeout: Optional[Duration] = None
+) -> Tuple[List[WaitSetAttachmentId], WaitSetRunResult]:
+    """
+    Awaits `WaitSet.wait_and_process()` without blocking the running event loop.
+
+    All attached file descriptors are registered at the running loop, which
+    wakes up the `WaitSet` when one of them becomes readable, when the next
+    interval or deadline is reached or when the `timeout` has passed. The
+    events are then handled without blocking.
+    """
+    loop = asyncio.get_running_loop()
+    no_wait = Duration.from_secs(0)
+    end_of_wait = None if timeout is None else loop.time() + timeout.as_secs_f64()
+
+    while True:
+        triggers, result = self.wait_and_process_with_timeout(no_wait)
+        if len(triggers) != 0 or result != WaitSetRunResult.AllEventsHandled:
+            return (triggers, result)
+
+        wait_time = self.duration_until_next_deadline()
+        wait_time = None if wait_time is None else wait_time.as_secs_f64()
+        if end_of_wait is not None:
+            remaining = end_of_wait - loop.time()
+            if remaining <= 0:
+                return (triggers, result)
+            wait_time = remaining if wait_time is None else min(wait_time, remaining)
+
+        await _wait_until_readable(self.attached_file_descriptors(), wait_time)
+
+
+def subscriber_aiter(self: Subscriber) -> AsyncIterator[Sample]:
+    """Iterates asynchronously over all received `Sample`s."""
+    return samples(self)
+
+
+Listener.wait_one = wait_one
+Listener.wait_all = wait_all
+
+Subscriber.receive_async = receive_async
+Subscriber.samples = samples
+Subscriber.__aiter__ = subscriber_aiter
+
+WaitSet.wait_and_process_async = wait_and_process_async
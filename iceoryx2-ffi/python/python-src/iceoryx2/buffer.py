@@ -0,0 +1,48 @@
+# Copyright (c) 2026 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+"""Zero-copy views of payloads that are stored in shared memory."""
+
+from typing import Any, Type, get_args, get_origin
+
+from .slice import Slice
+
+
+def payload_memoryview(owner: Any) -> memoryview:
+    """
+    Returns a byte `memoryview` of the payload without copying it.
+
+    The `memoryview` is acquired via the buffer protocol of `owner` and is
+    read-only for received payloads. As long as the `memoryview` exists,
+    `owner` can neither be sent nor deleted, release it with
+    `memoryview.release()` or use it in a `with` statement.
+    """
+    return memoryview(owner)
+
+
+def payload_numpy(owner: Any, payload_type: Type[Any]) -> Any:
+    """
+    Returns a numpy array view of the payload without copying it.
+
+    The element type of the array is derived from the ctypes payload type. For
+    `Slice` payloads the array contains all elements of the slice, otherwise it
+    contains exactly one element. The array holds a view of the payload, so the
+    payload can only be sent or deleted after the array was released.
+    Requires numpy to be installed.
+    """
+    import numpy  # pylint: disable=import-outside-toplevel
+
+    element_type = payload_type
+    if get_origin(payload_type) is Slice:
+        (element_type,) = get_args(payload_type)
+
+    return numpy.frombuffer(memoryview(owner), dtype=numpy.dtype(element_type))
@@ -16,6 +16,7 @@ import ctypes
 from typing import Any, Type, TypeVar, get_args, get_origin
 
 from ._iceoryx2 import *
+from .buffer import payload_memoryview, payload_numpy
 from .slice import Slice
 from .type_name import get_type_name
 
@@ -32,6 +33,27 @@ def payload(self: Any) -> Any:
     return ctypes.cast(self.payload_ptr, ctypes.POINTER(self.__payload_type_details))
 
 
+def memoryview_of_payload(self: Any) -> memoryview:
+    """
+    Returns a byte `memoryview` of the payload without copying it.
+
+    The `memoryview` is read-only for received samples. A sample cannot be sent
+    or deleted while a `memoryview` of its payload exists.
+    """
+    return payload_memoryview(self)
+
+
+def numpy_of_payload(self: Any) -> Any:
+    """
+    Returns a numpy array view of the payload without copying it.
+
+    The array is read-only for received samples. A sample cannot be sent or
+    deleted while an array of its payload exists.
+    """
+    assert self.__payload_type_details is not None
+    return payload_numpy(self, self.__payload_type_details)
+
+
 def user_header(self: Any) -> Any:
     """Returns a `ctypes.POINTER` to the user header."""
     assert self.__user_header_type_details is not None
@@ -170,13 +192,19 @@ Publisher.loan_slice_uninit = loan_slice_uninit
 
 Sample.payload = payload
 Sample.user_header = user_header
+Sample.payload_memoryview = memoryview_of_payload
+Sample.payload_numpy = numpy_of_payload
 
 SampleMut.payload = payload
 SampleMut.user_header = user_header
+SampleMut.payload_memoryview = memoryview_of_payload
+SampleMut.payload_numpy = numpy_of_payload
 
 SampleMutUninit.write_payload = write_payload
 SampleMutUninit.payload = payload
 SampleMutUninit.user_header = user_header
+SampleMutUninit.payload_memoryview = memoryview_of_payload
+SampleMutUninit.payload_numpy = numpy_of_payload
 
 ServiceBuilder.publish_subscribe = publish_subscribe
 ServiceBuilderPublishSubscribe.user_header = set_user_header
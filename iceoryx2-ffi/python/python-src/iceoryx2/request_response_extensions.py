@@ -16,6 +16,7 @@ import ctypes
 from typing import Any, Type, TypeVar, get_args, get_origin
 
 from ._iceoryx2 import *
+from .buffer import payload_memoryview, payload_numpy
 from .slice import Slice
 from .type_name import get_type_name
 
@@ -156,6 +157,39 @@ def response_payload(self: Any) -> Any:
     )
 
 
+def memoryview_of_payload(self: Any) -> memoryview:
+    """
+    Returns a byte `memoryview` of the payload without copying it.
+
+    The `memoryview` is read-only for received requests and responses. A request
+    or response cannot be sent or deleted while a `memoryview` of its payload
+    exists.
+    """
+    return payload_memoryview(self)
+
+
+def request_numpy(self: Any) -> Any:
+    """
+    Returns a numpy array view of the requests payload without copying it.
+
+    The array is read-only for received requests. A request cannot be sent or
+    deleted while an array of its payload exists.
+    """
+    assert self.__request_payload_type_details is not None
+    return payload_numpy(self, self.__request_payload_type_details)
+
+
+def response_numpy(self: Any) -> Any:
+    """
+    Returns a numpy array view of the responses payload without copying it.
+
+    The array is read-only for received responses. A response cannot be sent or
+    deleted while an array of its payload exists.
+    """
+    assert self.__response_payload_type_details is not None
+    return payload_numpy(self, self.__response_payload_type_details)
+
+
 def request_header(self: Any) -> Any:
     """Returns a `ctypes.POINTER` to the request header."""
     assert self.__request_header_type_details is not None
@@ -324,6 +358,8 @@ ServiceBuilderRequestResponse.response_header = set_response_header
 ActiveRequest.send_copy = send_response_copy
 ActiveRequest.payload = request_payload
 ActiveRequest.user_header = request_header
+ActiveRequest.payload_memoryview = memoryview_of_payload
+ActiveRequest.payload_numpy = request_numpy
 ActiveRequest.loan_uninit = loan_uninit_response
 ActiveRequest.loan_slice_uninit = loan_slice_uninit_response
 
@@ -337,19 +373,29 @@ PendingResponse.user_header = request_header
 
 RequestMut.payload = request_payload
 RequestMut.user_header = request_header
+RequestMut.payload_memoryview = memoryview_of_payload
+RequestMut.payload_numpy = request_numpy
 
 RequestMutUninit.payload = request_payload
 RequestMutUninit.user_header = request_header
+RequestMutUninit.payload_memoryview = memoryview_of_payload
+RequestMutUninit.payload_numpy = request_numpy
 RequestMutUninit.write_payload = write_request_payload
 
 Response.payload = response_payload
 Response.user_header = response_header
+Response.payload_memoryview = memoryview_of_payload
+Response.payload_numpy = response_numpy
 
 ResponseMut.payload = response_payload
 ResponseMut.user_header = response_header
+ResponseMut.payload_memoryview = memoryview_of_payload
+ResponseMut.payload_numpy = response_numpy
 
 ResponseMutUninit.payload = response_payload
 ResponseMutUninit.user_header = response_header
+ResponseMutUninit.payload_memoryview = memoryview_of_payload
+ResponseMutUninit.payload_numpy = response_numpy
 ResponseMutUninit.write_payload = write_response_payload
 
 Client.loan_uninit = loan_uninit_request
@@ -10,13 +10,18 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::ffi::c_int;
+
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use iceoryx2_log::fatal_panic;
+use pyo3::exceptions::PyBufferError;
+use pyo3::ffi;
 use pyo3::prelude::*;
 
 use crate::{
     error::LoanError,
     parc::Parc,
+    payload_buffer::{PayloadExports, fill_read_only_payload_buffer},
     request_header::RequestHeader,
     response_mut_uninit::{ResponseMutUninit, ResponseMutUninitType},
     type_storage::TypeStorage,
@@ -48,6 +53,7 @@ pub(crate) enum ActiveRequestType {
 /// `Server`.
 pub struct ActiveRequest {
     pub(crate) value: Parc<ActiveRequestType>,
+    pub(crate) exports: PayloadExports,
     pub(crate) request_payload_type_details: TypeStorage,
     pub(crate) response_payload_type_details: TypeStorage,
     pub(crate) request_header_type_details: TypeStorage,
@@ -109,6 +115,36 @@ impl ActiveRequest {
         }
     }
 
+    /// Provides the payload as read-only byte buffer via the Python buffer protocol,
+    /// e.g. `memoryview(active_request)`.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        let this = slf.borrow();
+        let (payload, len) = match &*this.value.lock() {
+            ActiveRequestType::Ipc(Some(v)) => {
+                (v.payload().as_ptr() as *const u8, v.payload().len())
+            }
+            ActiveRequestType::Local(Some(v)) => {
+                (v.payload().as_ptr() as *const u8, v.payload().len())
+            }
+            _ => {
+                return Err(PyBufferError::new_err(
+                    "Accessing a released active request.",
+                ));
+            }
+        };
+        unsafe {
+            fill_read_only_payload_buffer(view, flags, slf.as_any(), &this.exports, payload, len)
+        }
+    }
+
     #[getter]
     /// Returns a pointer to the user_header of the received `RequestMut`
     pub fn user_header_ptr(&self) -> usize {
@@ -160,10 +196,20 @@ impl ActiveRequest {
         }
     }
 
+    /// Releases a view that was acquired with `ActiveRequest::__getbuffer__()`.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __releasebuffer__(&self, _view: *mut ffi::Py_buffer) {
+        self.exports.release();
+    }
+
     /// Releases the `ActiveRequest` and terminates the connection.
     ///
     /// After this call the `ActiveRequest` is no longer usable!
-    pub fn delete(&mut self) {
+    pub fn delete(&mut self) -> PyResult<()> {
+        self.exports.ensure_released()?;
         match &mut *self.value.lock() {
             ActiveRequestType::Ipc(v) => {
                 v.take();
@@ -172,6 +218,7 @@ impl ActiveRequest {
                 v.take();
             }
         }
+        Ok(())
     }
 
     /// Loans uninitialized memory for a `ResponseMut` where the user can write its payload to.
@@ -186,6 +233,7 @@ impl ActiveRequest {
     pub fn __loan_slice_uninit(&self, slice_len: usize) -> PyResult<ResponseMutUninit> {
         match &*self.value.lock() {
             ActiveRequestType::Ipc(Some(v)) => Ok(ResponseMutUninit {
+                exports: PayloadExports::default(),
                 value: Parc::new(ResponseMutUninitType::Ipc(Some(unsafe {
                     v.loan_custom_payload(slice_len)
                         .map_err(|e| LoanError::new_err(format!("{e:?}")))?
@@ -194,6 +242,7 @@ impl ActiveRequest {
                 response_payload_type_details: self.response_payload_type_details.clone(),
             }),
             ActiveRequestType::Local(Some(v)) => Ok(ResponseMutUninit {
+                exports: PayloadExports::default(),
                 value: Parc::new(ResponseMutUninitType::Local(Some(unsafe {
                     v.loan_custom_payload(slice_len)
                         .map_err(|e| LoanError::new_err(format!("{e:?}")))?
@@ -17,6 +17,7 @@ use pyo3::prelude::*;
 use crate::{
     error::LoanError,
     parc::Parc,
+    payload_buffer::PayloadExports,
     request_mut_uninit::{RequestMutUninit, RequestMutUninitType},
     type_storage::TypeStorage,
     unable_to_deliver_strategy::UnableToDeliverStrategy,
@@ -126,6 +127,7 @@ impl Client {
     pub fn __loan_slice_uninit(&self, slice_len: usize) -> PyResult<RequestMutUninit> {
         match &self.value {
             ClientType::Ipc(Some(v)) => Ok(RequestMutUninit {
+                exports: PayloadExports::default(),
                 value: Parc::new(RequestMutUninitType::Ipc(Some(unsafe {
                     v.loan_custom_payload(slice_len)
                         .map_err(|e| LoanError::new_err(format!("{e:?}")))?
@@ -136,6 +138,7 @@ impl Client {
                 response_header_type_details: self.response_header_type_details.clone(),
             }),
             ClientType::Local(Some(v)) => Ok(RequestMutUninit {
+                exports: PayloadExports::default(),
                 value: Parc::new(RequestMutUninitType::Local(Some(unsafe {
                     v.loan_custom_payload(slice_len)
                         .map_err(|e| LoanError::new_err(format!("{e:?}")))?
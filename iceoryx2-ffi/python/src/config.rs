@@ -212,6 +212,19 @@ impl Service {
     pub fn set_cleanup_dead_nodes_on_open(&self, value: bool) {
         self.0.lock().global.service.cleanup_dead_nodes_on_open = value
     }
+
+    /// When true, a `Node` can only be created when the service type maps the data
+    /// segments of other ports read-only.
+    #[getter]
+    pub fn enforce_read_only_data_segments(&self) -> bool {
+        self.0.lock().global.service.enforce_read_only_data_segments
+    }
+    /// Sets if a `Node` can only be created when the service type maps the data
+    /// segments of other ports read-only.
+    #[setter]
+    pub fn set_enforce_read_only_data_segments(&self, value: bool) {
+        self.0.lock().global.service.enforce_read_only_data_segments = value
+    }
 }
 
 #[pyclass]
@@ -44,6 +44,7 @@ pub mod node_state;
 pub mod notifier;
 pub mod parc;
 pub mod path;
+pub mod payload_buffer;
 pub mod pending_response;
 pub mod port_factory_blackboard;
 pub mod port_factory_client;
@@ -140,6 +141,7 @@ fn _iceoryx2(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::entry_handle_mut::EntryHandleMut>()?;
     m.add_class::<crate::entry_value_uninit::EntryValueUninit>()?;
     m.add_class::<crate::event_id::EventId>()?;
+    m.add_class::<crate::file_descriptor::FileDescriptor>()?;
     m.add_class::<crate::file_name::FileName>()?;
     m.add_class::<crate::file_path::FilePath>()?;
     m.add_class::<crate::header_publish_subscribe::HeaderPublishSubscribe>()?;
@@ -12,12 +12,13 @@
 
 use std::sync::Arc;
 
+use iceoryx2::prelude::FileDescriptorBased;
 use iceoryx2_log::fatal_panic;
 use pyo3::prelude::*;
 
 use crate::{
     duration::Duration, error::ListenerWaitError, event_id::EventId,
-    unique_listener_id::UniqueListenerId,
+    file_descriptor::FileDescriptor, unique_listener_id::UniqueListenerId,
 };
 
 #[allow(clippy::large_enum_variant)] // used purely for python and there it will reside always in
@@ -161,6 +162,25 @@ impl Listener {
         })
     }
 
+    #[getter]
+    /// Returns a non-owning `FileDescriptor` of the `Listener` that becomes readable as soon as
+    /// an `EventId` was notified. It can be used to integrate the `Listener` into external event
+    /// loops like `asyncio`.
+    pub fn file_descriptor(&self) -> FileDescriptor {
+        let native_handle = match &self.0 {
+            ListenerType::Ipc(Some(v)) => unsafe { v.file_descriptor().native_handle() },
+            ListenerType::Local(Some(v)) => unsafe { v.file_descriptor().native_handle() },
+            _ => fatal_panic!(from "Listener::file_descriptor()",
+                    "Accessing a released listener."),
+        };
+
+        match FileDescriptor::non_owning_new(native_handle) {
+            Some(fd) => fd,
+            None => fatal_panic!(from "Listener::file_descriptor()",
+                    "This should never happen! The listener contains an invalid file descriptor."),
+        }
+    }
+
     #[getter]
     /// Returns the `UniqueListenerId` of the `Listener`
     pub fn id(&self) -> UniqueListenerId {
@@ -0,0 +1,131 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::ffi::{c_int, c_void};
+
+use pyo3::exceptions::PyBufferError;
+use pyo3::ffi;
+use pyo3::prelude::*;
+
+use crate::parc::Parc;
+
+/// Counts the active buffer protocol exports of a payload. It is shared
+/// between the uninitialized and the initialized variant of a loan, so that the
+/// payload can neither be sent nor released while a view of it exists.
+#[derive(Clone)]
+pub struct PayloadExports(Parc<usize>);
+
+impl Default for PayloadExports {
+    fn default() -> Self {
+        Self(Parc::new(0))
+    }
+}
+
+impl PayloadExports {
+    /// Returns an error when the payload is still exported via the buffer protocol.
+    pub fn ensure_released(&self) -> PyResult<()> {
+        match *self.0.lock() {
+            0 => Ok(()),
+            n => Err(PyBufferError::new_err(format!(
+                "The payload is still exported by {n} memoryview(s) or array(s). Release them before sending or deleting the payload."
+            ))),
+        }
+    }
+
+    fn acquire(&self) {
+        *self.0.lock() += 1;
+    }
+
+    /// Must be called from `__releasebuffer__` for every successful
+    /// [`fill_read_only_payload_buffer()`] or [`fill_writable_payload_buffer()`].
+    pub fn release(&self) {
+        let mut exports = self.0.lock();
+        *exports = exports.saturating_sub(1);
+    }
+}
+
+unsafe fn fill_buffer(
+    view: *mut ffi::Py_buffer,
+    flags: c_int,
+    owner: &Bound<'_, PyAny>,
+    payload: *mut u8,
+    len: usize,
+    read_only: bool,
+) -> PyResult<()> {
+    if view.is_null() {
+        return Err(PyBufferError::new_err("The view must not be null."));
+    }
+
+    if read_only && (flags & ffi::PyBUF_WRITABLE) == ffi::PyBUF_WRITABLE {
+        return Err(PyBufferError::new_err("The payload is read-only."));
+    }
+
+    // stores a new reference to `owner` in the view, so that the port
+    // outlives every view of its payload
+    if unsafe {
+        ffi::PyBuffer_FillInfo(
+            view,
+            owner.as_ptr(),
+            payload as *mut c_void,
+            len as ffi::Py_ssize_t,
+            read_only as c_int,
+            flags,
+        )
+    } == -1
+    {
+        return Err(PyErr::fetch(owner.py()));
+    }
+
+    Ok(())
+}
+
+/// Fills `view` with a read-only byte buffer of the payload and registers the
+/// export in `exports`.
+///
+/// # Safety
+///
+///  * `view` must be the pointer provided to `__getbuffer__`
+///  * `payload` must point to `len` bytes that are valid as long as `exports`
+///    has an active export
+pub unsafe fn fill_read_only_payload_buffer(
+    view: *mut ffi::Py_buffer,
+    flags: c_int,
+    owner: &Bound<'_, PyAny>,
+    exports: &PayloadExports,
+    payload: *const u8,
+    len: usize,
+) -> PyResult<()> {
+    unsafe { fill_buffer(view, flags, owner, payload as *mut u8, len, true)? };
+    exports.acquire();
+    Ok(())
+}
+
+/// Fills `view` with a writable byte buffer of the payload and registers the
+/// export in `exports`.
+///
+/// # Safety
+///
+///  * `view` must be the pointer provided to `__getbuffer__`
+///  * `payload` must point to `len` bytes that are valid as long as `exports`
+///    has an active export
+pub unsafe fn fill_writable_payload_buffer(
+    view: *mut ffi::Py_buffer,
+    flags: c_int,
+    owner: &Bound<'_, PyAny>,
+    exports: &PayloadExports,
+    payload: *mut u8,
+    len: usize,
+) -> PyResult<()> {
+    unsafe { fill_buffer(view, flags, owner, payload, len, false)? };
+    exports.acquire();
+    Ok(())
+}
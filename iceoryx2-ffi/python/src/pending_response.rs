@@ -17,6 +17,7 @@ use pyo3::prelude::*;
 use crate::{
     error::ReceiveError,
     parc::Parc,
+    payload_buffer::PayloadExports,
     request_header::RequestHeader,
     response::{Response, ResponseType},
     type_storage::TypeStorage,
@@ -174,6 +175,7 @@ impl PendingResponse {
                 v.receive_custom_payload()
                     .map_err(|e| ReceiveError::new_err(format!("{e:?}")))?
                     .map(|response| Response {
+                        exports: PayloadExports::default(),
                         value: Parc::new(ResponseType::Ipc(Some(response))),
                         response_header_type_details: self.response_header_type_details.clone(),
                         response_payload_type_details: self.response_payload_type_details.clone(),
@@ -183,6 +185,7 @@ impl PendingResponse {
                 v.receive_custom_payload()
                     .map_err(|e| ReceiveError::new_err(format!("{e:?}")))?
                     .map(|response| Response {
+                        exports: PayloadExports::default(),
                         value: Parc::new(ResponseType::Local(Some(response))),
                         response_header_type_details: self.response_header_type_details.clone(),
                         response_payload_type_details: self.response_payload_type_details.clone(),
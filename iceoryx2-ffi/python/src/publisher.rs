@@ -20,6 +20,7 @@ use pyo3::prelude::*;
 use crate::{
     error::{ConnectionFailure, LoanError},
     parc::Parc,
+    payload_buffer::PayloadExports,
     sample_mut_uninit::{SampleMutUninit, SampleMutUninitType},
     type_storage::TypeStorage,
     unable_to_deliver_strategy::UnableToDeliverStrategy,
@@ -118,6 +119,7 @@ impl Publisher {
                 };
                 Ok(SampleMutUninit {
                     value: Parc::new(SampleMutUninitType::Ipc(Some(sample))),
+                    exports: PayloadExports::default(),
                     payload_type_details: self.payload_type_details.clone(),
                     user_header_type_details: self.user_header_type_details.clone(),
                 })
@@ -129,6 +131,7 @@ impl Publisher {
                 };
                 Ok(SampleMutUninit {
                     value: Parc::new(SampleMutUninitType::Local(Some(sample))),
+                    exports: PayloadExports::default(),
                     payload_type_details: self.payload_type_details.clone(),
                     user_header_type_details: self.user_header_type_details.clone(),
                 })
@@ -10,13 +10,18 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::ffi::c_int;
+
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use iceoryx2_log::fatal_panic;
+use pyo3::exceptions::PyBufferError;
+use pyo3::ffi;
 use pyo3::prelude::*;
 
 use crate::{
     error::RequestSendError,
     parc::Parc,
+    payload_buffer::{PayloadExports, fill_writable_payload_buffer},
     pending_response::{PendingResponse, PendingResponseType},
     request_header::RequestHeader,
     type_storage::TypeStorage,
@@ -47,6 +52,7 @@ pub(crate) enum RequestMutType {
 /// `Server`.
 pub struct RequestMut {
     pub(crate) value: Parc<RequestMutType>,
+    pub(crate) exports: PayloadExports,
     pub(crate) request_payload_type_details: TypeStorage,
     pub(crate) response_payload_type_details: TypeStorage,
     pub(crate) request_header_type_details: TypeStorage,
@@ -121,10 +127,49 @@ impl RequestMut {
         }
     }
 
+    /// Provides the payload as writable byte buffer via the Python buffer protocol,
+    /// e.g. `memoryview(request)`. The `RequestMut` cannot be sent or deleted as long as a
+    /// view of the payload exists.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        let this = slf.borrow();
+        let (payload, len) = match &mut *this.value.lock() {
+            RequestMutType::Ipc(Some(v)) => (
+                v.payload_mut().as_mut_ptr() as *mut u8,
+                v.payload_mut().len(),
+            ),
+            RequestMutType::Local(Some(v)) => (
+                v.payload_mut().as_mut_ptr() as *mut u8,
+                v.payload_mut().len(),
+            ),
+            _ => return Err(PyBufferError::new_err("Accessing a released request.")),
+        };
+        unsafe {
+            fill_writable_payload_buffer(view, flags, slf.as_any(), &this.exports, payload, len)
+        }
+    }
+
+    /// Releases a view that was acquired with `RequestMut::__getbuffer__()`.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __releasebuffer__(&self, _view: *mut ffi::Py_buffer) {
+        self.exports.release();
+    }
+
     /// Releases the `RequestMut`.
     ///
     /// After this call the `RequestMut` is no longer usable!
-    pub fn delete(&mut self) {
+    pub fn delete(&mut self) -> PyResult<()> {
+        self.exports.ensure_released()?;
         match &mut *self.value.lock() {
             RequestMutType::Ipc(v) => {
                 v.take();
@@ -133,10 +178,12 @@ impl RequestMut {
                 v.take();
             }
         }
+        Ok(())
     }
 
     /// Sends the `RequestMut` to all connected `Server`s of the `Service`.
     pub fn send(&self) -> PyResult<PendingResponse> {
+        self.exports.ensure_released()?;
         match &mut *self.value.lock() {
             RequestMutType::Ipc(v) => {
                 let request = v.take().unwrap();
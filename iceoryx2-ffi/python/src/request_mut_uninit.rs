@@ -10,14 +10,18 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::ffi::c_int;
 use core::mem::MaybeUninit;
 
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use iceoryx2_log::fatal_panic;
+use pyo3::exceptions::PyBufferError;
+use pyo3::ffi;
 use pyo3::prelude::*;
 
 use crate::{
     parc::Parc,
+    payload_buffer::{PayloadExports, fill_writable_payload_buffer},
     request_header::RequestHeader,
     request_mut::{RequestMut, RequestMutType},
     type_storage::TypeStorage,
@@ -49,6 +53,7 @@ pub(crate) enum RequestMutUninitType {
 /// and converted into `RequestMut` with `RequestMutUninit::assume_init()`.
 pub struct RequestMutUninit {
     pub(crate) value: Parc<RequestMutUninitType>,
+    pub(crate) exports: PayloadExports,
     pub(crate) request_payload_type_details: TypeStorage,
     pub(crate) response_payload_type_details: TypeStorage,
     pub(crate) request_header_type_details: TypeStorage,
@@ -124,10 +129,49 @@ impl RequestMutUninit {
         }
     }
 
+    /// Provides the payload as writable byte buffer via the Python buffer protocol,
+    /// e.g. `memoryview(request)`. The `RequestMutUninit` cannot be sent or deleted as long as a
+    /// view of the payload exists.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        let this = slf.borrow();
+        let (payload, len) = match &mut *this.value.lock() {
+            RequestMutUninitType::Ipc(Some(v)) => (
+                v.payload_mut().as_mut_ptr() as *mut u8,
+                v.payload_mut().len(),
+            ),
+            RequestMutUninitType::Local(Some(v)) => (
+                v.payload_mut().as_mut_ptr() as *mut u8,
+                v.payload_mut().len(),
+            ),
+            _ => return Err(PyBufferError::new_err("Accessing a released request.")),
+        };
+        unsafe {
+            fill_writable_payload_buffer(view, flags, slf.as_any(), &this.exports, payload, len)
+        }
+    }
+
+    /// Releases a view that was acquired with `RequestMutUninit::__getbuffer__()`.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __releasebuffer__(&self, _view: *mut ffi::Py_buffer) {
+        self.exports.release();
+    }
+
     /// Releases the `RequestMutUninit`.
     ///
     /// After this call the `RequestMutUninit` is no longer usable!
-    pub fn delete(&mut self) {
+    pub fn delete(&mut self) -> PyResult<()> {
+        self.exports.ensure_released()?;
         match &mut *self.value.lock() {
             RequestMutUninitType::Ipc(v) => {
                 v.take();
@@ -136,6 +180,7 @@ impl RequestMutUninit {
                 v.take();
             }
         }
+        Ok(())
     }
 
     /// When the payload is manually populated by using
@@ -147,6 +192,7 @@ impl RequestMutUninit {
                 let request = v.take().unwrap();
                 RequestMut {
                     value: Parc::new(RequestMutType::Ipc(Some(unsafe { request.assume_init() }))),
+                    exports: self.exports.clone(),
                     request_header_type_details: self.request_header_type_details.clone(),
                     request_payload_type_details: self.request_payload_type_details.clone(),
                     response_header_type_details: self.response_header_type_details.clone(),
@@ -156,6 +202,7 @@ impl RequestMutUninit {
             RequestMutUninitType::Local(v) => {
                 let request = v.take().unwrap();
                 RequestMut {
+                    exports: self.exports.clone(),
                     value: Parc::new(RequestMutType::Local(Some(unsafe {
                         request.assume_init()
                     }))),
@@ -10,12 +10,19 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::ffi::c_int;
+
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use iceoryx2_log::fatal_panic;
+use pyo3::exceptions::PyBufferError;
+use pyo3::ffi;
 use pyo3::prelude::*;
 
 use crate::{
-    parc::Parc, response_header::ResponseHeader, type_storage::TypeStorage,
+    parc::Parc,
+    payload_buffer::{PayloadExports, fill_read_only_payload_buffer},
+    response_header::ResponseHeader,
+    type_storage::TypeStorage,
     unique_server_id::UniqueServerId,
 };
 
@@ -34,6 +41,7 @@ pub(crate) enum ResponseType {
 /// sent to a `Server` via the `Client`.
 pub struct Response {
     pub(crate) value: Parc<ResponseType>,
+    pub(crate) exports: PayloadExports,
     pub(crate) response_payload_type_details: TypeStorage,
     pub(crate) response_header_type_details: TypeStorage,
 }
@@ -93,6 +101,28 @@ impl Response {
         }
     }
 
+    /// Provides the payload as read-only byte buffer via the Python buffer protocol,
+    /// e.g. `memoryview(response)`.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        let this = slf.borrow();
+        let (payload, len) = match &*this.value.lock() {
+            ResponseType::Ipc(Some(v)) => (v.payload().as_ptr() as *const u8, v.payload().len()),
+            ResponseType::Local(Some(v)) => (v.payload().as_ptr() as *const u8, v.payload().len()),
+            _ => return Err(PyBufferError::new_err("Accessing a released response.")),
+        };
+        unsafe {
+            fill_read_only_payload_buffer(view, flags, slf.as_any(), &this.exports, payload, len)
+        }
+    }
+
     #[getter]
     /// Returns the `UniqueServerId` of the `Server` which sent the `Response`.
     pub fn origin(&self) -> UniqueServerId {
@@ -104,10 +134,20 @@ impl Response {
         }
     }
 
+    /// Releases a view that was acquired with `Response::__getbuffer__()`.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __releasebuffer__(&self, _view: *mut ffi::Py_buffer) {
+        self.exports.release();
+    }
+
     /// Releases the `Response`.
     ///
     /// After this call the `Response` is no longer usable!
-    pub fn delete(&mut self) {
+    pub fn delete(&mut self) -> PyResult<()> {
+        self.exports.ensure_released()?;
         match &mut *self.value.lock() {
             ResponseType::Ipc(v) => {
                 v.take();
@@ -116,5 +156,6 @@ impl Response {
                 v.take();
             }
         }
+        Ok(())
     }
 }
@@ -10,12 +10,20 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::ffi::c_int;
+
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use iceoryx2_log::fatal_panic;
+use pyo3::exceptions::PyBufferError;
+use pyo3::ffi;
 use pyo3::prelude::*;
 
 use crate::{
-    error::SendError, parc::Parc, response_header::ResponseHeader, type_storage::TypeStorage,
+    error::SendError,
+    parc::Parc,
+    payload_buffer::{PayloadExports, fill_writable_payload_buffer},
+    response_header::ResponseHeader,
+    type_storage::TypeStorage,
 };
 
 type IpcResponseMut = iceoryx2::response_mut::ResponseMut<
@@ -37,6 +45,7 @@ pub(crate) enum ResponseMutType {
 #[pyclass]
 pub struct ResponseMut {
     pub(crate) value: Parc<ResponseMutType>,
+    pub(crate) exports: PayloadExports,
     pub(crate) response_payload_type_details: TypeStorage,
     pub(crate) response_header_type_details: TypeStorage,
 }
@@ -100,10 +109,49 @@ impl ResponseMut {
         }
     }
 
+    /// Provides the payload as writable byte buffer via the Python buffer protocol,
+    /// e.g. `memoryview(response)`. The `ResponseMut` cannot be sent or deleted as long as a
+    /// view of the payload exists.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        let this = slf.borrow();
+        let (payload, len) = match &mut *this.value.lock() {
+            ResponseMutType::Ipc(Some(v)) => (
+                v.payload_mut().as_mut_ptr() as *mut u8,
+                v.payload_mut().len(),
+            ),
+            ResponseMutType::Local(Some(v)) => (
+                v.payload_mut().as_mut_ptr() as *mut u8,
+                v.payload_mut().len(),
+            ),
+            _ => return Err(PyBufferError::new_err("Accessing a released response.")),
+        };
+        unsafe {
+            fill_writable_payload_buffer(view, flags, slf.as_any(), &this.exports, payload, len)
+        }
+    }
+
+    /// Releases a view that was acquired with `ResponseMut::__getbuffer__()`.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __releasebuffer__(&self, _view: *mut ffi::Py_buffer) {
+        self.exports.release();
+    }
+
     /// Releases the `ResponseMut`.
     ///
     /// After this call the `ResponseMut` is no longer usable!
-    pub fn delete(&mut self) {
+    pub fn delete(&mut self) -> PyResult<()> {
+        self.exports.ensure_released()?;
         match &mut *self.value.lock() {
             ResponseMutType::Ipc(v) => {
                 v.take();
@@ -112,11 +160,13 @@ impl ResponseMut {
                 v.take();
             }
         }
+        Ok(())
     }
 
     /// Sends a `ResponseMut` to the corresponding `PendingResponse` of the
     /// `Client`.
     pub fn send(&self) -> PyResult<()> {
+        self.exports.ensure_released()?;
         match &mut *self.value.lock() {
             ResponseMutType::Ipc(v) => {
                 let response = v.take().unwrap();
@@ -10,14 +10,18 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::ffi::c_int;
 use core::mem::MaybeUninit;
 
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use iceoryx2_log::fatal_panic;
+use pyo3::exceptions::PyBufferError;
+use pyo3::ffi;
 use pyo3::prelude::*;
 
 use crate::{
     parc::Parc,
+    payload_buffer::{PayloadExports, fill_writable_payload_buffer},
     response_header::ResponseHeader,
     response_mut::{ResponseMut, ResponseMutType},
     type_storage::TypeStorage,
@@ -50,6 +54,7 @@ pub(crate) enum ResponseMutUninitType {
 /// scope.
 pub struct ResponseMutUninit {
     pub(crate) value: Parc<ResponseMutUninitType>,
+    pub(crate) exports: PayloadExports,
     pub(crate) response_payload_type_details: TypeStorage,
     pub(crate) response_header_type_details: TypeStorage,
 }
@@ -113,10 +118,49 @@ impl ResponseMutUninit {
         }
     }
 
+    /// Provides the payload as writable byte buffer via the Python buffer protocol,
+    /// e.g. `memoryview(response)`. The `ResponseMutUninit` cannot be sent or deleted as long as a
+    /// view of the payload exists.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        let this = slf.borrow();
+        let (payload, len) = match &mut *this.value.lock() {
+            ResponseMutUninitType::Ipc(Some(v)) => (
+                v.payload_mut().as_mut_ptr() as *mut u8,
+                v.payload_mut().len(),
+            ),
+            ResponseMutUninitType::Local(Some(v)) => (
+                v.payload_mut().as_mut_ptr() as *mut u8,
+                v.payload_mut().len(),
+            ),
+            _ => return Err(PyBufferError::new_err("Accessing a released response.")),
+        };
+        unsafe {
+            fill_writable_payload_buffer(view, flags, slf.as_any(), &this.exports, payload, len)
+        }
+    }
+
+    /// Releases a view that was acquired with `ResponseMutUninit::__getbuffer__()`.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __releasebuffer__(&self, _view: *mut ffi::Py_buffer) {
+        self.exports.release();
+    }
+
     /// Releases the `ResponseMutUninit`.
     ///
     /// After this call the `ResponseMutUninit` is no longer usable!
-    pub fn delete(&mut self) {
+    pub fn delete(&mut self) -> PyResult<()> {
+        self.exports.ensure_released()?;
         match &mut *self.value.lock() {
             ResponseMutUninitType::Ipc(v) => {
                 v.take();
@@ -125,6 +169,7 @@ impl ResponseMutUninit {
                 v.take();
             }
         }
+        Ok(())
     }
 
     /// Converts the `ResponseMutUninit` into `ResponseMut`. This shall be done after the
@@ -134,6 +179,7 @@ impl ResponseMutUninit {
             ResponseMutUninitType::Ipc(v) => {
                 let response = v.take().unwrap();
                 ResponseMut {
+                    exports: self.exports.clone(),
                     value: Parc::new(ResponseMutType::Ipc(Some(unsafe {
                         response.assume_init()
                     }))),
@@ -144,6 +190,7 @@ impl ResponseMutUninit {
             ResponseMutUninitType::Local(v) => {
                 let response = v.take().unwrap();
                 ResponseMut {
+                    exports: self.exports.clone(),
                     value: Parc::new(ResponseMutType::Local(Some(unsafe {
                         response.assume_init()
                     }))),
@@ -10,12 +10,19 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::ffi::c_int;
+
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use iceoryx2_log::fatal_panic;
+use pyo3::exceptions::PyBufferError;
+use pyo3::ffi;
 use pyo3::prelude::*;
 
 use crate::{
-    header_publish_subscribe::HeaderPublishSubscribe, parc::Parc, type_storage::TypeStorage,
+    header_publish_subscribe::HeaderPublishSubscribe,
+    parc::Parc,
+    payload_buffer::{PayloadExports, fill_read_only_payload_buffer},
+    type_storage::TypeStorage,
 };
 
 pub(crate) enum SampleType {
@@ -40,6 +47,7 @@ pub(crate) enum SampleType {
 /// it receives new data from a `Publisher` via `Subscriber::receive()`.
 pub struct Sample {
     pub(crate) value: Parc<SampleType>,
+    pub(crate) exports: PayloadExports,
     pub payload_type_details: TypeStorage,
     pub user_header_type_details: TypeStorage,
 }
@@ -99,10 +107,42 @@ impl Sample {
         }
     }
 
+    /// Provides the payload as read-only byte buffer via the Python buffer protocol,
+    /// e.g. `memoryview(sample)`.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        let this = slf.borrow();
+        let (payload, len) = match &*this.value.lock() {
+            SampleType::Ipc(Some(v)) => (v.payload().as_ptr() as *const u8, v.payload().len()),
+            SampleType::Local(Some(v)) => (v.payload().as_ptr() as *const u8, v.payload().len()),
+            _ => return Err(PyBufferError::new_err("Accessing a released sample.")),
+        };
+        unsafe {
+            fill_read_only_payload_buffer(view, flags, slf.as_any(), &this.exports, payload, len)
+        }
+    }
+
+    /// Releases a view that was acquired with `Sample::__getbuffer__()`.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __releasebuffer__(&self, _view: *mut ffi::Py_buffer) {
+        self.exports.release();
+    }
+
     /// Releases the `Sample`.
     ///
     /// After this call the `Sample` is no longer usable!
-    pub fn delete(&mut self) {
+    pub fn delete(&mut self) -> PyResult<()> {
+        self.exports.ensure_released()?;
         match &mut *self.value.lock() {
             SampleType::Ipc(v) => {
                 v.take();
@@ -111,5 +151,6 @@ impl Sample {
                 v.take();
             }
         }
+        Ok(())
     }
 }
@@ -10,12 +10,19 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::ffi::c_int;
+
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use iceoryx2_log::fatal_panic;
+use pyo3::exceptions::PyBufferError;
+use pyo3::ffi;
 use pyo3::prelude::*;
 
 use crate::{
-    error::SendError, header_publish_subscribe::HeaderPublishSubscribe, parc::Parc,
+    error::SendError,
+    header_publish_subscribe::HeaderPublishSubscribe,
+    parc::Parc,
+    payload_buffer::{PayloadExports, fill_writable_payload_buffer},
     type_storage::TypeStorage,
 };
 
@@ -50,6 +57,7 @@ pub(crate) enum SampleMutType {
 /// it will release the loaned memory when going out of scope.
 pub struct SampleMut {
     pub(crate) value: Parc<SampleMutType>,
+    pub(crate) exports: PayloadExports,
     pub(crate) payload_type_details: TypeStorage,
     pub(crate) user_header_type_details: TypeStorage,
 }
@@ -113,10 +121,49 @@ impl SampleMut {
         }
     }
 
+    /// Provides the payload as writable byte buffer via the Python buffer protocol,
+    /// e.g. `memoryview(sample)`. The `SampleMut` cannot be sent or deleted as long as a
+    /// view of the payload exists.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        let this = slf.borrow();
+        let (payload, len) = match &mut *this.value.lock() {
+            SampleMutType::Ipc(Some(v)) => (
+                v.payload_mut().as_mut_ptr() as *mut u8,
+                v.payload_mut().len(),
+            ),
+            SampleMutType::Local(Some(v)) => (
+                v.payload_mut().as_mut_ptr() as *mut u8,
+                v.payload_mut().len(),
+            ),
+            _ => return Err(PyBufferError::new_err("Accessing a released sample.")),
+        };
+        unsafe {
+            fill_writable_payload_buffer(view, flags, slf.as_any(), &this.exports, payload, len)
+        }
+    }
+
+    /// Releases a view that was acquired with `SampleMut::__getbuffer__()`.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __releasebuffer__(&self, _view: *mut ffi::Py_buffer) {
+        self.exports.release();
+    }
+
     /// Releases the `SampleMut`.
     ///
     /// After this call the `SampleMut` is no longer usable!
-    pub fn delete(&mut self) {
+    pub fn delete(&mut self) -> PyResult<()> {
+        self.exports.ensure_released()?;
         match &mut *self.value.lock() {
             SampleMutType::Ipc(v) => {
                 v.take();
@@ -125,6 +172,7 @@ impl SampleMut {
                 v.take();
             }
         }
+        Ok(())
     }
 
     /// Send a previously loaned `Publisher::loan_uninit()` `SampleMut` to all connected
@@ -133,6 +181,7 @@ impl SampleMut {
     /// On success the number of `Subscriber`s that received
     /// the data is returned, otherwise a `SendError` is emitted describing the failure.
     pub fn send(&self) -> PyResult<usize> {
+        self.exports.ensure_released()?;
         match &mut *self.value.lock() {
             SampleMutType::Ipc(v) => {
                 let sample = v.take().unwrap();
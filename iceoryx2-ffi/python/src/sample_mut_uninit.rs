@@ -10,15 +10,19 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::ffi::c_int;
 use core::mem::MaybeUninit;
 
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use iceoryx2_log::fatal_panic;
+use pyo3::exceptions::PyBufferError;
+use pyo3::ffi;
 use pyo3::prelude::*;
 
 use crate::{
     header_publish_subscribe::HeaderPublishSubscribe,
     parc::Parc,
+    payload_buffer::{PayloadExports, fill_writable_payload_buffer},
     sample_mut::{SampleMut, SampleMutType},
     type_storage::TypeStorage,
 };
@@ -53,6 +57,7 @@ pub(crate) enum SampleMutUninitType {
 /// it will release the loaned memory when going out of scope.
 pub struct SampleMutUninit {
     pub(crate) value: Parc<SampleMutUninitType>,
+    pub(crate) exports: PayloadExports,
     pub(crate) payload_type_details: TypeStorage,
     pub(crate) user_header_type_details: TypeStorage,
 }
@@ -116,10 +121,49 @@ impl SampleMutUninit {
         }
     }
 
+    /// Provides the payload as writable byte buffer via the Python buffer protocol,
+    /// e.g. `memoryview(sample)`. The `SampleMutUninit` cannot be sent or deleted as long as a
+    /// view of the payload exists.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        let this = slf.borrow();
+        let (payload, len) = match &mut *this.value.lock() {
+            SampleMutUninitType::Ipc(Some(v)) => (
+                v.payload_mut().as_mut_ptr() as *mut u8,
+                v.payload_mut().len(),
+            ),
+            SampleMutUninitType::Local(Some(v)) => (
+                v.payload_mut().as_mut_ptr() as *mut u8,
+                v.payload_mut().len(),
+            ),
+            _ => return Err(PyBufferError::new_err("Accessing a released sample.")),
+        };
+        unsafe {
+            fill_writable_payload_buffer(view, flags, slf.as_any(), &this.exports, payload, len)
+        }
+    }
+
+    /// Releases a view that was acquired with `SampleMutUninit::__getbuffer__()`.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called by the Python interpreter
+    pub unsafe fn __releasebuffer__(&self, _view: *mut ffi::Py_buffer) {
+        self.exports.release();
+    }
+
     /// Releases the `SampleMutUninit`.
     ///
     /// After this call the `SampleMutUninit` is no longer usable!
-    pub fn delete(&mut self) {
+    pub fn delete(&mut self) -> PyResult<()> {
+        self.exports.ensure_released()?;
         match &mut *self.value.lock() {
             SampleMutUninitType::Ipc(v) => {
                 v.take();
@@ -128,6 +172,7 @@ impl SampleMutUninit {
                 v.take();
             }
         }
+        Ok(())
     }
 
     /// Extracts the value of the uninitialized payload and labels the `SampleMutUninit` as
@@ -140,6 +185,7 @@ impl SampleMutUninit {
                 let sample = v.take().unwrap();
                 SampleMut {
                     value: Parc::new(SampleMutType::Ipc(Some(unsafe { sample.assume_init() }))),
+                    exports: self.exports.clone(),
                     payload_type_details: self.payload_type_details.clone(),
                     user_header_type_details: self.user_header_type_details.clone(),
                 }
@@ -148,6 +194,7 @@ impl SampleMutUninit {
                 let sample = v.take().unwrap();
                 SampleMut {
                     value: Parc::new(SampleMutType::Local(Some(unsafe { sample.assume_init() }))),
+                    exports: self.exports.clone(),
                     payload_type_details: self.payload_type_details.clone(),
                     user_header_type_details: self.user_header_type_details.clone(),
                 }
@@ -18,6 +18,7 @@ use crate::{
     active_request::{ActiveRequest, ActiveRequestType},
     error::{ConnectionFailure, ReceiveError},
     parc::Parc,
+    payload_buffer::PayloadExports,
     type_storage::TypeStorage,
     unable_to_deliver_strategy::UnableToDeliverStrategy,
     unique_server_id::UniqueServerId,
@@ -120,6 +121,7 @@ impl Server {
                 v.receive_custom_payload()
                     .map_err(|e| ReceiveError::new_err(format!("{e:?}")))?
                     .map(|v| ActiveRequest {
+                        exports: PayloadExports::default(),
                         value: Parc::new(ActiveRequestType::Ipc(Some(v))),
                         request_header_type_details: self.request_header_type_details.clone(),
                         request_payload_type_details: self.request_payload_type_details.clone(),
@@ -131,6 +133,7 @@ impl Server {
                 v.receive_custom_payload()
                     .map_err(|e| ReceiveError::new_err(format!("{e:?}")))?
                     .map(|v| ActiveRequest {
+                        exports: PayloadExports::default(),
                         value: Parc::new(ActiveRequestType::Local(Some(v))),
                         request_header_type_details: self.request_header_type_details.clone(),
                         request_payload_type_details: self.request_payload_type_details.clone(),
@@ -155,6 +155,24 @@ impl ServiceBuilderPublishSubscribe {
         }
     }
 
+    /// If the `Service` is created, defines if every `Subscriber` is woken up via a `Listener`
+    /// whenever a new `Sample` arrives. If an existing `Service` is opened it requires the
+    /// service to have the defined subscriber wakeup setting.
+    pub fn enable_subscriber_wakeup(&self, value: bool) -> Self {
+        match &self.value {
+            ServiceBuilderPublishSubscribeType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.enable_subscriber_wakeup(value);
+                self.clone_ipc(this)
+            }
+            ServiceBuilderPublishSubscribeType::Local(v) => {
+                let this = v.clone();
+                let this = this.enable_subscriber_wakeup(value);
+                self.clone_local(this)
+            }
+        }
+    }
+
     /// If the `Service` is created it defines how many `Sample`s a
     /// `Subscriber` can borrow at most in parallel. If an existing
     /// `Service` is opened it defines the minimum required.
@@ -68,6 +68,13 @@ impl StaticConfigPublishSubscribe {
         self.0.has_safe_overflow()
     }
 
+    #[getter]
+    /// Returns true if every `Subscriber` of the `Service` is woken up whenever a new `Sample`
+    /// arrives, otherwise false.
+    pub fn has_subscriber_wakeup(&self) -> bool {
+        self.0.has_subscriber_wakeup()
+    }
+
     #[getter]
     /// Returns the type details of the `Service`.
     pub fn message_type_details(&self) -> MessageTypeDetails {
@@ -10,13 +10,16 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use iceoryx2::prelude::FileDescriptorBased;
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use iceoryx2_log::fatal_panic;
 use pyo3::prelude::*;
 
 use crate::{
     error::{ConnectionFailure, ReceiveError},
+    file_descriptor::FileDescriptor,
     parc::Parc,
+    payload_buffer::PayloadExports,
     sample::{Sample, SampleType},
     type_storage::TypeStorage,
     unique_subscriber_id::UniqueSubscriberId,
@@ -90,6 +93,41 @@ impl Subscriber {
         }
     }
 
+    #[getter]
+    /// Returns true if the `Subscriber` is woken up whenever a new `Sample` arrives. This
+    /// requires a `Service` created with `enable_subscriber_wakeup(True)`.
+    pub fn has_wakeup(&self) -> bool {
+        match &*self.value.lock() {
+            SubscriberType::Ipc(Some(v)) => v.has_wakeup(),
+            SubscriberType::Local(Some(v)) => v.has_wakeup(),
+            _ => fatal_panic!(from "Subscriber::has_wakeup()",
+                    "Accessing a released Subscriber."),
+        }
+    }
+
+    /// Returns a non-owning `FileDescriptor` that becomes readable as soon as a new `Sample`
+    /// arrives or `None` when the `Subscriber` has no wakeup. It can be attached to a `WaitSet`
+    /// with `WaitSet::attach_notification_fd()` or integrated into external event loops like
+    /// `asyncio`.
+    pub fn wakeup_file_descriptor(&self) -> Option<FileDescriptor> {
+        let native_handle = match &*self.value.lock() {
+            SubscriberType::Ipc(Some(v)) => v
+                .wakeup()
+                .map(|l| unsafe { l.file_descriptor().native_handle() }),
+            SubscriberType::Local(Some(v)) => v
+                .wakeup()
+                .map(|l| unsafe { l.file_descriptor().native_handle() }),
+            _ => fatal_panic!(from "Subscriber::wakeup_file_descriptor()",
+                    "Accessing a released Subscriber."),
+        };
+
+        native_handle.map(|native_handle| match FileDescriptor::non_owning_new(native_handle) {
+            Some(fd) => fd,
+            None => fatal_panic!(from "Subscriber::wakeup_file_descriptor()",
+                    "This should never happen! The subscriber wakeup contains an invalid file descriptor."),
+        })
+    }
+
     /// Receives a `Sample` from `Publisher`. If no sample could be received `None` is returned.
     /// If a failure occurs `ReceiveError` is returned.
     pub fn receive(&self) -> PyResult<Option<Sample>> {
@@ -98,6 +136,7 @@ impl Subscriber {
                 v.receive_custom_payload()
                     .map_err(|e| ReceiveError::new_err(format!("{e:?}")))?
                     .map(|s| Sample {
+                        exports: PayloadExports::default(),
                         value: Parc::new(SampleType::Ipc(Some(s))),
                         payload_type_details: self.payload_type_details.clone(),
                         user_header_type_details: self.user_header_type_details.clone(),
@@ -107,6 +146,7 @@ impl Subscriber {
                 v.receive_custom_payload()
                     .map_err(|e| ReceiveError::new_err(format!("{e:?}")))?
                     .map(|s| Sample {
+                        exports: PayloadExports::default(),
                         value: Parc::new(SampleType::Local(Some(s))),
                         payload_type_details: self.payload_type_details.clone(),
                         user_header_type_details: self.user_header_type_details.clone(),
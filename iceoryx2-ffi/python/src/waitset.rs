@@ -397,6 +397,33 @@ impl WaitSet {
         })
     }
 
+    /// Returns the native handles of all attached file descriptors. Together with
+    /// `WaitSet::duration_until_next_deadline()` it allows to integrate the `WaitSet` into
+    /// external event loops like `asyncio`, that wait until one of the file descriptors becomes
+    /// readable and then call `WaitSet::wait_and_process_with_timeout()` with a zero timeout.
+    pub fn attached_file_descriptors(&self) -> Vec<i32> {
+        match &*self.0.lock() {
+            WaitSetType::Ipc(Some(v)) => v.attached_file_descriptors(),
+            WaitSetType::Local(Some(v)) => v.attached_file_descriptors(),
+            _ => fatal_panic!(from "WaitSet::attached_file_descriptors()",
+                "Accessing a deleted WaitSet."),
+        }
+    }
+
+    /// Returns the `Duration` until the next interval or deadline attachment must be handled
+    /// or `None` when nothing with a timeout is attached. Emits `WaitSetRunError` on failure.
+    pub fn duration_until_next_deadline(&self) -> PyResult<Option<Duration>> {
+        let duration = match &*self.0.lock() {
+            WaitSetType::Ipc(Some(v)) => v.duration_until_next_deadline(),
+            WaitSetType::Local(Some(v)) => v.duration_until_next_deadline(),
+            _ => fatal_panic!(from "WaitSet::duration_until_next_deadline()",
+                "Accessing a deleted WaitSet."),
+        }
+        .map_err(|e| WaitSetRunError::new_err(format!("{e:?}")))?;
+
+        Ok((duration != core::time::Duration::MAX).then_some(Duration(duration)))
+    }
+
     #[getter]
     /// Returns the capacity of the `WaitSet`
     pub fn capacity(&self) -> usize {
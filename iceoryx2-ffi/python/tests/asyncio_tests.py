@@ -0,0 +1,313 @@
+# Copyright (c) 2026 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+import asyncio
+import ctypes
+
+import iceoryx2 as iox2
+import pytest
+
+service_types = [iox2.ServiceType.Ipc, iox2.ServiceType.Local]
+
+
+class Payload(ctypes.Structure):
+    _fields_ = [("data", ctypes.c_ulonglong)]
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_listener_wait_one_awaits_notification(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    event_id = iox2.EventId.new(12)
+
+    service_name = iox2.testing.generate_service_name()
+    service = node.service_builder(service_name).event().create()
+
+    notifier = service.notifier_builder().create()
+    listener = service.listener_builder().create()
+
+    async def notify_later() -> None:
+        await asyncio.sleep(0.01)
+        notifier.notify_with_custom_event_id(event_id)
+
+    async def run() -> iox2.EventId:
+        notification = asyncio.create_task(notify_later())
+        received_event_id = await asyncio.wait_for(listener.wait_one(), 5)
+        await notification
+        return received_event_id
+
+    assert asyncio.run(run()) == event_id
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_listener_wait_all_awaits_notifications(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    event_id_1 = iox2.EventId.new(3)
+    event_id_2 = iox2.EventId.new(7)
+
+    service_name = iox2.testing.generate_service_name()
+    service = node.service_builder(service_name).event().create()
+
+    notifier = service.notifier_builder().create()
+    listener = service.listener_builder().create()
+
+    notifier.notify_with_custom_event_id(event_id_1)
+    notifier.notify_with_custom_event_id(event_id_2)
+
+    event_ids = asyncio.run(asyncio.wait_for(listener.wait_all(), 5))
+    assert len(event_ids) == 2
+    assert event_id_1 in event_ids
+    assert event_id_2 in event_ids
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_listener_wait_one_does_not_block_event_loop(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = node.service_builder(service_name).event().create()
+    listener = service.listener_builder().create()
+
+    async def run() -> bool:
+        waiter = asyncio.create_task(listener.wait_one())
+        await asyncio.sleep(0.01)
+        is_still_waiting = not waiter.done()
+        waiter.cancel()
+        with pytest.raises(asyncio.CancelledError):
+            await waiter
+        return is_still_waiting
+
+    assert asyncio.run(run())
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_subscriber_receive_async_with_listener_works(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    pubsub_service = (
+        node.service_builder(service_name).publish_subscribe(Payload).create()
+    )
+    event_service = node.service_builder(service_name).event().create()
+
+    publisher = pubsub_service.publisher_builder().create()
+    subscriber = pubsub_service.subscriber_builder().create()
+    notifier = event_service.notifier_builder().create()
+    listener = event_service.listener_builder().create()
+
+    async def send_later() -> None:
+        await asyncio.sleep(0.01)
+        publisher.send_copy(Payload(data=981))
+        notifier.notify()
+
+    async def run() -> int:
+        sending = asyncio.create_task(send_later())
+        sample = await asyncio.wait_for(subscriber.receive_async(listener), 5)
+        await sending
+        return sample.payload().contents.data
+
+    assert asyncio.run(run()) == 981
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_subscriber_receive_async_with_wakeup_works(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .enable_subscriber_wakeup(True)
+        .create()
+    )
+
+    publisher = service.publisher_builder().create()
+    subscriber = service.subscriber_builder().create()
+
+    async def send_later() -> None:
+        await asyncio.sleep(0.01)
+        publisher.send_copy(Payload(data=1293))
+
+    async def run() -> int:
+        sending = asyncio.create_task(send_later())
+        sample = await asyncio.wait_for(subscriber.receive_async(), 5)
+        await sending
+        return sample.payload().contents.data
+
+    assert asyncio.run(run()) == 1293
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_subscriber_receive_async_without_listener_and_wakeup_fails(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = node.service_builder(service_name).publish_subscribe(Payload).create()
+    subscriber = service.subscriber_builder().create()
+
+    with pytest.raises(ValueError):
+        asyncio.run(subscriber.receive_async())
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_subscriber_async_iteration_yields_all_samples(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    number_of_samples = 4
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .subscriber_max_buffer_size(number_of_samples)
+        .enable_subscriber_wakeup(True)
+        .create()
+    )
+
+    publisher = service.publisher_builder().create()
+    subscriber = service.subscriber_builder().create()
+
+    async def send_all() -> None:
+        for i in range(0, number_of_samples):
+            publisher.send_copy(Payload(data=i))
+            await asyncio.sleep(0.001)
+
+    async def receive_all() -> list:
+        received = []
+        async for sample in subscriber:
+            received.append(sample.payload().contents.data)
+            if len(received) == number_of_samples:
+                break
+        return received
+
+    async def run() -> list:
+        sending = asyncio.create_task(send_all())
+        received = await asyncio.wait_for(receive_all(), 5)
+        await sending
+        return received
+
+    assert asyncio.run(run()) == list(range(0, number_of_samples))
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_waitset_wait_and_process_async_returns_triggered_listener(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = node.service_builder(service_name).event().create()
+    notifier = service.notifier_builder().create()
+    listener = service.listener_builder().create()
+
+    sut = iox2.WaitSetBuilder.new().create(service_type)
+    guard = sut.attach_notification(listener)
+
+    notifier.notify()
+
+    triggers, result = asyncio.run(
+        sut.wait_and_process_async(iox2.Duration.from_secs(5))
+    )
+    assert len(triggers) == 1
+    assert triggers[0].has_event_from(guard)
+    assert result == iox2.WaitSetRunResult.AllEventsHandled
+
+    iox2.WaitSetGuard.delete(guard)
+    iox2.WaitSet.delete(sut)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_waitset_wait_and_process_async_returns_when_timeout_has_passed(
+    service_type: iox2.ServiceType,
+) -> None:
+    sut = iox2.WaitSetBuilder.new().create(service_type)
+    guard = sut.attach_interval(iox2.Duration.from_secs(123))
+
+    triggers, result = asyncio.run(
+        sut.wait_and_process_async(iox2.Duration.from_millis(1))
+    )
+    assert len(triggers) == 0
+    assert result == iox2.WaitSetRunResult.AllEventsHandled
+
+    iox2.WaitSetGuard.delete(guard)
+    iox2.WaitSet.delete(sut)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_waitset_wait_and_process_async_returns_triggered_interval(
+    service_type: iox2.ServiceType,
+) -> None:
+    sut = iox2.WaitSetBuilder.new().create(service_type)
+    guard = sut.attach_interval(iox2.Duration.from_millis(10))
+
+    triggers, result = asyncio.run(
+        sut.wait_and_process_async(iox2.Duration.from_secs(5))
+    )
+    assert len(triggers) == 1
+    assert triggers[0].has_event_from(guard)
+    assert result == iox2.WaitSetRunResult.AllEventsHandled
+
+    iox2.WaitSetGuard.delete(guard)
+    iox2.WaitSet.delete(sut)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_waitset_wait_and_process_async_does_not_block_event_loop(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = node.service_builder(service_name).event().create()
+    notifier = service.notifier_builder().create()
+    listener = service.listener_builder().create()
+
+    sut = iox2.WaitSetBuilder.new().create(service_type)
+    guard = sut.attach_notification(listener)
+
+    async def notify_later() -> None:
+        await asyncio.sleep(0.01)
+        notifier.notify()
+
+    async def run() -> list:
+        notification = asyncio.create_task(notify_later())
+        triggers, _ = await asyncio.wait_for(sut.wait_and_process_async(), 5)
+        await notification
+        return triggers
+
+    triggers = asyncio.run(run())
+    assert len(triggers) == 1
+    assert triggers[0].has_event_from(guard)
+
+    iox2.WaitSetGuard.delete(guard)
+    iox2.WaitSet.delete(sut)
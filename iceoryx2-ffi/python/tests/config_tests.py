@@ -70,6 +70,13 @@ def test_global_service_cleanup_dead_nodes_on_open_can_be_set() -> None:
     assert sut.global_cfg.service.cleanup_dead_nodes_on_open == value
 
 
+def test_global_service_enforce_read_only_data_segments_can_be_set() -> None:
+    sut = iox2.config.default()
+    value = True
+    sut.global_cfg.service.enforce_read_only_data_segments = value
+    assert sut.global_cfg.service.enforce_read_only_data_segments == value
+
+
 def test_global_service_connection_suffix_can_be_set() -> None:
     sut = iox2.config.default()
     path = iox2.FileName.new(".fuldaba")
@@ -239,6 +239,7 @@ def test_service_builder_configuration_works(
 
     service_name = iox2.testing.generate_service_name()
     safe_overflow = False
+    subscriber_wakeup = True
     subscriber_max_borrowed_samples = 10
     history_size = 29
     subscriber_max_buffer_size = 38
@@ -249,6 +250,7 @@ def test_service_builder_configuration_works(
         node.service_builder(service_name)
         .publish_subscribe(Payload)
         .enable_safe_overflow(safe_overflow)
+        .enable_subscriber_wakeup(subscriber_wakeup)
         .subscriber_max_borrowed_samples(subscriber_max_borrowed_samples)
         .history_size(history_size)
         .subscriber_max_buffer_size(subscriber_max_buffer_size)
@@ -268,6 +270,7 @@ def test_service_builder_configuration_works(
         static_config.subscriber_max_borrowed_samples == subscriber_max_borrowed_samples
     )
     assert static_config.has_safe_overflow == safe_overflow
+    assert static_config.has_subscriber_wakeup == subscriber_wakeup
 
 
 @pytest.mark.parametrize("service_type", service_types)
@@ -391,3 +391,191 @@ def test_history_is_delivered_with_update_connections(
         assert received_sample.payload().contents.data == 85 + i
 
     assert not subscriber.has_samples()
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_slice_payload_can_be_written_and_read_via_memoryview(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    number_of_elements = 16
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(iox2.Slice[ctypes.c_uint32])
+        .create()
+    )
+
+    publisher = (
+        service.publisher_builder().initial_max_slice_len(number_of_elements).create()
+    )
+    subscriber = service.subscriber_builder().create()
+
+    sample_uninit = publisher.loan_slice_uninit(number_of_elements)
+    with sample_uninit.payload_memoryview() as view, view.cast("I") as send_view:
+        assert len(send_view) == number_of_elements
+        for i in range(0, number_of_elements):
+            send_view[i] = 3 * i
+    sample_uninit.assume_init().send()
+
+    received_sample = subscriber.receive()
+    received_view = received_sample.payload_memoryview()
+    assert received_view.readonly
+    assert received_view.nbytes == number_of_elements * ctypes.sizeof(
+        ctypes.c_uint32
+    )
+    assert received_view.cast("I").tolist() == [
+        3 * i for i in range(0, number_of_elements)
+    ]
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_fixed_size_payload_can_be_read_via_memoryview(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name).publish_subscribe(LargePayload).create()
+    )
+
+    publisher = service.publisher_builder().create()
+    subscriber = service.subscriber_builder().create()
+
+    publisher.send_copy(LargePayload(data=0xDEADBEEF))
+
+    received_sample = subscriber.receive()
+    view = received_sample.payload_memoryview()
+    assert view.nbytes == ctypes.sizeof(LargePayload)
+    assert view.cast("Q")[0] == 0xDEADBEEF
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_slice_payload_can_be_accessed_via_numpy(
+    service_type: iox2.ServiceType,
+) -> None:
+    numpy = pytest.importorskip("numpy")
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    number_of_elements = 32
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(iox2.Slice[ctypes.c_float])
+        .create()
+    )
+
+    publisher = (
+        service.publisher_builder().initial_max_slice_len(number_of_elements).create()
+    )
+    subscriber = service.subscriber_builder().create()
+
+    sample_uninit = publisher.loan_slice_uninit(number_of_elements)
+    send_array = sample_uninit.payload_numpy()
+    send_array[:] = numpy.arange(number_of_elements, dtype=numpy.float32)
+    del send_array
+    sample_uninit.assume_init().send()
+
+    received_sample = subscriber.receive()
+    received_array = received_sample.payload_numpy()
+    assert received_array.dtype == numpy.float32
+    assert not received_array.flags.writeable
+    assert numpy.array_equal(
+        received_array, numpy.arange(number_of_elements, dtype=numpy.float32)
+    )
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_samples_implement_the_buffer_protocol(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(iox2.Slice[ctypes.c_uint8])
+        .create()
+    )
+
+    publisher = service.publisher_builder().initial_max_slice_len(4).create()
+    subscriber = service.subscriber_builder().create()
+
+    sample_uninit = publisher.loan_slice_uninit(4)
+    memoryview(sample_uninit)[:] = b"iox2"
+    sample_uninit.assume_init().send()
+
+    received_sample = subscriber.receive()
+    assert memoryview(received_sample).readonly
+    assert bytes(received_sample) == b"iox2"
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_sample_cannot_be_sent_or_deleted_while_payload_is_exported(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(iox2.Slice[ctypes.c_uint8])
+        .create()
+    )
+
+    publisher = service.publisher_builder().initial_max_slice_len(4).create()
+    subscriber = service.subscriber_builder().create()
+
+    sample_uninit = publisher.loan_slice_uninit(4)
+    view = memoryview(sample_uninit)
+    view[:] = b"iox2"
+    sample = sample_uninit.assume_init()
+
+    with pytest.raises(BufferError):
+        sample.send()
+    with pytest.raises(BufferError):
+        sample.delete()
+
+    view.release()
+    assert sample.send() == 1
+
+    received_sample = subscriber.receive()
+    assert bytes(received_sample) == b"iox2"
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_received_sample_cannot_be_deleted_while_payload_is_exported(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(iox2.Slice[ctypes.c_uint8])
+        .create()
+    )
+
+    publisher = service.publisher_builder().initial_max_slice_len(4).create()
+    subscriber = service.subscriber_builder().create()
+
+    sample_uninit = publisher.loan_slice_uninit(4)
+    memoryview(sample_uninit)[:] = b"iox2"
+    sample_uninit.assume_init().send()
+
+    received_sample = subscriber.receive()
+    view = received_sample.payload_memoryview()
+    with pytest.raises(BufferError):
+        received_sample.delete()
+
+    assert bytes(view) == b"iox2"
+    view.release()
+    received_sample.delete()
@@ -548,3 +548,122 @@ def test_client_can_request_graceful_disconnect(
 
     assert active_request.is_connected is False
     assert active_request.has_disconnect_hint is False
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_request_and_response_payload_can_be_accessed_via_memoryview(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    number_of_elements = 8
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .request_response(iox2.Slice[ctypes.c_uint8], iox2.Slice[ctypes.c_uint8])
+        .create()
+    )
+
+    client = service.client_builder().initial_max_slice_len(number_of_elements).create()
+    server = (
+        service.server_builder().initial_max_slice_len(number_of_elements).create()
+    )
+
+    request_uninit = client.loan_slice_uninit(number_of_elements)
+    with request_uninit.payload_memoryview() as view:
+        view[:] = bytes(range(0, number_of_elements))
+    pending_response = request_uninit.assume_init().send()
+
+    active_request = server.receive()
+    request_view = active_request.payload_memoryview()
+    assert request_view.readonly
+    assert request_view.tobytes() == bytes(range(0, number_of_elements))
+
+    response_uninit = active_request.loan_slice_uninit(number_of_elements)
+    with response_uninit.payload_memoryview() as view:
+        view[:] = bytes(range(10, 10 + number_of_elements))
+    response_uninit.assume_init().send()
+
+    response = pending_response.receive()
+    response_view = response.payload_memoryview()
+    assert response_view.readonly
+    assert response_view.tobytes() == bytes(range(10, 10 + number_of_elements))
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_request_and_response_cannot_be_sent_while_payload_is_exported(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .request_response(iox2.Slice[ctypes.c_uint8], iox2.Slice[ctypes.c_uint8])
+        .create()
+    )
+
+    client = service.client_builder().initial_max_slice_len(4).create()
+    server = service.server_builder().initial_max_slice_len(4).create()
+
+    request = client.loan_slice_uninit(4).assume_init()
+    view = memoryview(request)
+    with pytest.raises(BufferError):
+        request.send()
+    view.release()
+    pending_response = request.send()
+
+    active_request = server.receive()
+    response = active_request.loan_slice_uninit(4).assume_init()
+    view = memoryview(response)
+    view[:] = b"iox2"
+    with pytest.raises(BufferError):
+        response.send()
+    view.release()
+    response.send()
+
+    assert bytes(pending_response.receive()) == b"iox2"
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_received_request_and_response_cannot_be_deleted_while_payload_is_exported(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .request_response(iox2.Slice[ctypes.c_uint8], iox2.Slice[ctypes.c_uint8])
+        .create()
+    )
+
+    client = service.client_builder().initial_max_slice_len(4).create()
+    server = service.server_builder().initial_max_slice_len(4).create()
+
+    request_uninit = client.loan_slice_uninit(4)
+    memoryview(request_uninit)[:] = b"iox2"
+    pending_response = request_uninit.assume_init().send()
+
+    active_request = server.receive()
+    response_uninit = active_request.loan_slice_uninit(4)
+    memoryview(response_uninit)[:] = b"2xoi"
+    response_uninit.assume_init().send()
+
+    view = active_request.payload_memoryview()
+    with pytest.raises(BufferError):
+        active_request.delete()
+    assert bytes(view) == b"iox2"
+    view.release()
+    active_request.delete()
+
+    response = pending_response.receive()
+    view = response.payload_memoryview()
+    with pytest.raises(BufferError):
+        response.delete()
+    assert bytes(view) == b"2xoi"
+    view.release()
+    response.delete()
@@ -100,3 +100,50 @@ def test_deleting_sample_releases_it(
         sample = sut.receive()
     except iox2.ReceiveError:
         assert False
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_subscriber_has_no_wakeup_by_default(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = node.service_builder(service_name).publish_subscribe(Payload).create()
+
+    sut = service.subscriber_builder().create()
+
+    assert not sut.has_wakeup
+    assert sut.wakeup_file_descriptor() is None
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_subscriber_wakeup_file_descriptor_becomes_readable_on_new_sample(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .enable_subscriber_wakeup(True)
+        .create()
+    )
+
+    publisher = service.publisher_builder().create()
+    sut = service.subscriber_builder().create()
+    assert sut.has_wakeup
+
+    waitset = iox2.WaitSetBuilder.new().create(service_type)
+    guard = waitset.attach_notification_fd(sut.wakeup_file_descriptor())
+
+    publisher.send_copy(Payload(data=5))
+
+    triggers, _ = waitset.wait_and_process_with_timeout(iox2.Duration.from_secs(5))
+    assert len(triggers) == 1
+    assert triggers[0].has_event_from(guard)
+    assert sut.receive().payload().contents.data == 5
+
+    iox2.WaitSetGuard.delete(guard)
+    iox2.WaitSet.delete(waitset)
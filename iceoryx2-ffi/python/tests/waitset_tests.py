@@ -291,3 +291,43 @@ def test_deleting_guard_explicitly_removes_attachment(
     for i in range(0, number_of_attachments):
         iox2.Listener.delete(listeners[i])
     iox2.WaitSet.delete(sut)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_attached_file_descriptors_contains_all_notifications(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    service_name = iox2.testing.generate_service_name()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = node.service_builder(service_name).event().create()
+    listener = service.listener_builder().create()
+
+    sut = iox2.WaitSetBuilder.new().create(service_type)
+    assert sut.attached_file_descriptors() == []
+
+    guard = sut.attach_notification(listener)
+    interval_guard = sut.attach_interval(iox2.Duration.from_secs(123))
+    assert sut.attached_file_descriptors() == [listener.file_descriptor.native_handle]
+
+    iox2.WaitSetGuard.delete(guard)
+    assert sut.attached_file_descriptors() == []
+
+    iox2.WaitSetGuard.delete(interval_guard)
+    iox2.WaitSet.delete(sut)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_duration_until_next_deadline_is_bounded_by_interval(
+    service_type: iox2.ServiceType,
+) -> None:
+    sut = iox2.WaitSetBuilder.new().create(service_type)
+    assert sut.duration_until_next_deadline() is None
+
+    interval = iox2.Duration.from_secs(123)
+    guard = sut.attach_interval(interval)
+    assert sut.duration_until_next_deadline().as_nanos() <= interval.as_nanos()
+
+    iox2.WaitSetGuard.delete(guard)
+    assert sut.duration_until_next_deadline() is None
+    iox2.WaitSet.delete(sut)
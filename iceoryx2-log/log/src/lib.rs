@@ -128,7 +128,7 @@
 pub use from_env::{set_log_level_from_env_or, set_log_level_from_env_or_default};
 
 // Re-export so library crates need only depend on this crate
-pub use iceoryx2_log_types::{Log, LogLevel};
+pub use iceoryx2_log_types::{Log, LogLevel, TraceContext};
 
 use iceoryx2_pal_concurrency_sync::atomic::AtomicU8;
 use iceoryx2_pal_concurrency_sync::atomic::Ordering;
@@ -204,6 +204,22 @@ pub fn set_logger(logger: &'static dyn Log) -> bool {
     set_logger_success
 }
 
+/// Returns the [`TraceContext`] of the current thread that shall be attached to outgoing
+/// messages. It is provided by the [`Log`]ger, [`None`] when it does not support tracing.
+pub fn current_trace_context() -> Option<TraceContext> {
+    get_logger()
+        .current_trace_context()
+        .filter(|trace_context| trace_context.is_valid())
+}
+
+/// Forwards the [`TraceContext`] of a received message to the [`Log`]ger. Invalid
+/// [`TraceContext`]s are ignored.
+pub fn receive_trace_context(trace_context: &TraceContext) {
+    if trace_context.is_valid() {
+        get_logger().receive_trace_context(trace_context)
+    }
+}
+
 #[cfg(feature = "std")]
 mod from_env {
     use super::{DEFAULT_LOG_LEVEL, LogLevel, set_log_level};
@@ -12,6 +12,8 @@
 
 #![no_std]
 
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+
 /// Log levels from least to most severe
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -24,6 +26,30 @@ pub enum LogLevel {
     Fatal = 5,
 }
 
+/// Identifies the trace and the span a message belongs to so that spans of different processes
+/// can be linked. The layout follows the W3C trace context, a [`TraceContext`] is valid when
+/// neither the trace id nor the span id consist only of zeros.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TraceContext {
+    /// identifies the whole trace across all processes
+    pub trace_id: [u8; 16],
+    /// identifies the span in which the message was created
+    pub span_id: [u8; 8],
+    /// trace flags, bit 0 signals that the trace is sampled
+    pub trace_flags: u8,
+}
+
+// SAFETY: `TraceContext` is `#[repr(C)]`, self-contained and consists only of plain bytes
+unsafe impl ZeroCopySend for TraceContext {}
+
+impl TraceContext {
+    /// Returns true when the [`TraceContext`] contains a trace id and a span id
+    pub fn is_valid(&self) -> bool {
+        self.trace_id != [0; 16] && self.span_id != [0; 8]
+    }
+}
+
 /// Core logging trait to be implemented by loggers
 pub trait Log: Send + Sync {
     /// logs a message
@@ -33,4 +59,14 @@ pub trait Log: Send + Sync {
         origin: core::fmt::Arguments,
         formatted_message: core::fmt::Arguments,
     );
+
+    /// Returns the [`TraceContext`] of the current thread that shall be attached to outgoing
+    /// messages. Loggers without tracing support return [`None`].
+    fn current_trace_context(&self) -> Option<TraceContext> {
+        None
+    }
+
+    /// Is called with the valid [`TraceContext`] of every received message so that the logger
+    /// can link its spans to the span of the sender.
+    fn receive_trace_context(&self, _trace_context: &TraceContext) {}
 }
@@ -24,6 +24,7 @@ pub const NAME_MAX: usize = 255;
 pub const USER_NAME_LENGTH: usize = 255;
 #[cfg(not(target_os = "linux"))]
 pub const USER_NAME_LENGTH: usize = 31;
+pub const HOST_NAME_LENGTH: usize = 255;
 
 pub const GROUP_NAME_LENGTH: usize = 31;
 
@@ -58,6 +59,7 @@ pub const MCL_FUTURE: int = libc::MCL_FUTURE as _;
 pub const MAP_SHARED: int = libc::MAP_SHARED as _;
 pub const MAP_PRIVATE: int = libc::MAP_PRIVATE as _;
 pub const MAP_ANONYMOUS: int = libc::MAP_ANONYMOUS as _;
+pub const MAP_HUGETLB: int = libc::MAP_HUGETLB as _;
 pub const MAP_FAILED: *mut void = u64::MAX as *mut void;
 
 pub const PTHREAD_BARRIER_SERIAL_THREAD: int = libc::PTHREAD_BARRIER_SERIAL_THREAD as _;
@@ -20,6 +20,7 @@ pub const FD_SETSIZE: usize = crate::internal::FD_SETSIZE as _;
 pub const NULL_TERMINATOR: c_char = 0;
 pub const USER_NAME_LENGTH: usize = 31;
 pub const GROUP_NAME_LENGTH: usize = 31;
+pub const HOST_NAME_LENGTH: usize = 255;
 pub const NAME_MAX: usize = crate::internal::NAME_MAX as _;
 
 pub const STDOUT_FILENO: u32 = crate::internal::STDOUT_FILENO as _;
@@ -56,6 +57,7 @@ pub const MCL_FUTURE: int = crate::internal::MCL_FUTURE as _;
 pub const MAP_SHARED: int = crate::internal::MAP_SHARED as _;
 pub const MAP_PRIVATE: int = crate::internal::MAP_PRIVATE as _;
 pub const MAP_ANONYMOUS: int = crate::internal::MAP_ANONYMOUS as _;
+pub const MAP_HUGETLB: int = 0;
 pub const MAP_FAILED: *mut void = u64::MAX as *mut void;
 
 pub const PTHREAD_BARRIER_SERIAL_THREAD: int = crate::internal::PTHREAD_BARRIER_SERIAL_THREAD as _;
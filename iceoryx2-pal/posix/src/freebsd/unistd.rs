@@ -49,6 +49,10 @@ pub unsafe fn getppid() -> pid_t {
     unsafe { crate::internal::getppid() }
 }
 
+pub unsafe fn gethostname(name: *mut c_char, len: size_t) -> int {
+    unsafe { crate::internal::gethostname(name, len as _) }
+}
+
 pub unsafe fn dup(fildes: int) -> int {
     unsafe { crate::internal::dup(fildes) }
 }
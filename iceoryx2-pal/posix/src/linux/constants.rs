@@ -22,6 +22,7 @@ pub const NAME_MAX: usize = libc::NAME_MAX as _;
 
 pub const USER_NAME_LENGTH: usize = 255;
 pub const GROUP_NAME_LENGTH: usize = 31;
+pub const HOST_NAME_LENGTH: usize = 255;
 
 pub const STDOUT_FILENO: u32 = libc::STDOUT_FILENO as _;
 pub const STDERR_FILENO: u32 = libc::STDERR_FILENO as _;
@@ -57,6 +58,7 @@ pub const MCL_FUTURE: int = libc::MCL_FUTURE as _;
 pub const MAP_SHARED: int = libc::MAP_SHARED as _;
 pub const MAP_PRIVATE: int = libc::MAP_PRIVATE as _;
 pub const MAP_ANONYMOUS: int = libc::MAP_ANONYMOUS as _;
+pub const MAP_HUGETLB: int = libc::MAP_HUGETLB as _;
 pub const MAP_FAILED: *mut void = libc::MAP_FAILED as *mut void;
 
 pub const PTHREAD_BARRIER_SERIAL_THREAD: int = libc::PTHREAD_BARRIER_SERIAL_THREAD as _;
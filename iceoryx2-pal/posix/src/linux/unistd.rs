@@ -86,6 +86,10 @@ pub unsafe fn getppid() -> pid_t {
     unsafe { libc::getppid() }
 }
 
+pub unsafe fn gethostname(name: *mut c_char, len: size_t) -> int {
+    unsafe { libc::gethostname(name, len) }
+}
+
 pub unsafe fn dup(fildes: int) -> int {
     unsafe { libc::dup(fildes) }
 }
@@ -18,6 +18,7 @@ use crate::posix::types::*;
 pub const GLOBAL_CONFIG_PATH: &str = "";
 pub const USER_CONFIG_PATH: &str = "";
 pub const USER_NAME_LENGTH: usize = 255;
+pub const HOST_NAME_LENGTH: usize = 255;
 pub const THREAD_NAME_LENGTH: usize = 16;
 pub const GROUP_NAME_LENGTH: usize = 31;
 
@@ -60,6 +61,7 @@ pub const MCL_FUTURE: int = 32;
 pub const MAP_FAILED: *mut void = 0 as *mut void;
 pub const MAP_PRIVATE: int = 2;
 pub const MAP_ANONYMOUS: int = 32;
+pub const MAP_HUGETLB: int = 0;
 pub const MAP_SHARED: int = 64;
 
 pub const PTHREAD_MUTEX_NORMAL: int = 1;
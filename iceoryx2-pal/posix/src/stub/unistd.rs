@@ -47,6 +47,10 @@ pub unsafe fn getppid() -> pid_t {
     0
 }
 
+pub unsafe fn gethostname(name: *mut c_char, len: size_t) -> int {
+    -1
+}
+
 pub unsafe fn dup(fildes: int) -> int {
     unimplemented!("dup")
 }
@@ -21,6 +21,7 @@ pub const FD_SETSIZE: usize = windows_sys::Win32::Networking::WinSock::FD_SETSIZ
 pub const NULL_TERMINATOR: c_char = 0;
 pub const USER_NAME_LENGTH: usize = 255;
 pub const GROUP_NAME_LENGTH: usize = 31;
+pub const HOST_NAME_LENGTH: usize = 255;
 
 pub const STDOUT_FILENO: u32 = 1;
 pub const STDERR_FILENO: u32 = 2;
@@ -55,6 +56,7 @@ pub const MCL_CURRENT: int = 16;
 pub const MCL_FUTURE: int = 32;
 pub const MAP_SHARED: int = 64;
 pub const MAP_ANONYMOUS: int = 128;
+pub const MAP_HUGETLB: int = 0;
 pub const MAP_PRIVATE: int = 256;
 pub const MAP_FAILED: *mut void = core::ptr::null_mut::<void>();
 
@@ -40,6 +40,7 @@ use windows_sys::Win32::{
             GetCurrentProcess, GetCurrentProcessId, OpenProcess, PROCESS_QUERY_INFORMATION,
             PROCESS_VM_READ,
         },
+        WindowsProgramming::GetComputerNameA,
     },
 };
 
@@ -172,6 +173,15 @@ pub unsafe fn getppid() -> pid_t {
     parent_process_id
 }
 
+pub unsafe fn gethostname(name: *mut c_char, len: size_t) -> int {
+    let mut size = len as u32;
+    let (has_name, _) = unsafe {
+        win32call! { GetComputerNameA(name.cast(), &mut size) }
+    };
+
+    if has_name == FALSE { -1 } else { 0 }
+}
+
 impl MemZeroedStruct for WSAPROTOCOL_INFOA {}
 
 pub unsafe fn dup(fildes: int) -> int {
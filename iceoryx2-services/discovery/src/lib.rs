@@ -22,5 +22,8 @@
 
 extern crate alloc;
 
+/// Discovery and tracking of nodes and their metadata in an iceoryx2 system
+pub mod node_discovery;
+
 /// Discovery and tracking of services in an iceoryx2 system
 pub mod service_discovery;
@@ -0,0 +1,67 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Node Discovery
+//!
+//! This module provides functionality for discovering and tracking nodes in an iceoryx2 system
+//! together with their metadata, like the user-defined attributes, the process id, the host,
+//! the user and the command line of their process.
+//!
+//! The node discovery system consists of two main components:
+//!
+//! 1. **Service**: A service that tracks and publishes information about the nodes in the system.
+//!    It detects when nodes are added or removed and notifies interested parties about these changes.
+//!
+//! 2. **Tracker**: A component that keeps track of the alive nodes in the system. It maintains a
+//!    list of the currently alive nodes and can detect changes in the node landscape.
+//!
+//! ## Usage
+//!
+//! To use the node discovery system, you typically create a `Service` instance with appropriate
+//! configuration, and then periodically call its `spin` method to process node changes and emit
+//! events/notifications.
+//!
+//! ```no_run
+//! use iceoryx2_services_discovery::node_discovery::{NodeInfo, Service};
+//! use iceoryx2_services_discovery::node_discovery::Config as DiscoveryConfig;
+//! use iceoryx2::prelude::*;
+//!
+//! fn main() -> Result<(), Box<dyn core::error::Error>> {
+//!
+//!     // Create a node discovery service
+//!     let config = DiscoveryConfig::default();
+//!     let mut service = Service::<ipc::Service>::create(&config, &Config::global_config()).expect("Failed to create service");
+//!
+//!     let on_added = |node: &NodeInfo| {
+//!         // ...process added nodes
+//!     };
+//!     let on_removed = |node: &NodeInfo| {
+//!         // ...process removed nodes
+//!     };
+//!
+//!     // Periodically process node changes
+//!     loop {
+//!         service.spin(on_added, on_removed)?;
+//!         // Sleep or do other work...
+//!     }
+//!
+//!     Ok(())
+//! }
+
+/// A node discovery service that tracks and publishes information about nodes in the system.
+mod service;
+
+/// A tracker for nodes that maintains a list of currently alive nodes.
+mod tracker;
+
+pub use service::*;
+pub use tracker::*;
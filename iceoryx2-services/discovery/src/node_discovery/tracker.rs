@@ -0,0 +1,236 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::collections::BTreeMap;
+use alloc::collections::BTreeSet;
+use alloc::collections::btree_map::Entry;
+use alloc::vec::Vec;
+
+use iceoryx2::{
+    config::Config,
+    constants::MAX_NODE_NAME_LENGTH,
+    identifiers::UniqueNodeId,
+    node::{Node, NodeDetails, NodeListFailure, NodeState, NodeView},
+    prelude::{AttributeSet, AttributeSpecifier, CallbackProgression, ZeroCopySend},
+    service::Service,
+};
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_container::string::StaticString;
+use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_bb_system_types::{file_name::FileName, host_name::HostName, user_name::UserName};
+
+/// The maximum length of the command line that is transmitted in the [`NodeInfo`].
+pub const MAX_COMMAND_LINE_LENGTH: usize = 1024;
+
+/// The name of a node as it is transmitted in the [`NodeInfo`].
+pub type NodeNameString = StaticString<MAX_NODE_NAME_LENGTH>;
+
+/// The executable of a node as it is transmitted in the [`NodeInfo`].
+pub type ExecutableString = StaticString<{ FileName::max_len() }>;
+
+/// The host name of a node as it is transmitted in the [`NodeInfo`].
+pub type HostNameString = StaticString<{ HostName::max_len() }>;
+
+/// The user name of a node as it is transmitted in the [`NodeInfo`].
+pub type UserNameString = StaticString<{ UserName::max_len() }>;
+
+/// The command line of a node as it is transmitted in the [`NodeInfo`].
+pub type CommandLineString = StaticString<MAX_COMMAND_LINE_LENGTH>;
+
+/// Errors that can occur during node synchronization.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SyncError {
+    /// The caller does not have permissions to look up nodes.
+    InsufficientPermissions,
+
+    /// Failure looking up nodes present in the iceoryx2 system.
+    NodeLookupFailure,
+}
+
+impl core::fmt::Display for SyncError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SyncError::{self:?}")
+    }
+}
+
+impl core::error::Error for SyncError {}
+
+impl From<NodeListFailure> for SyncError {
+    fn from(error: NodeListFailure) -> Self {
+        match error {
+            NodeListFailure::InsufficientPermissions => Self::InsufficientPermissions,
+            NodeListFailure::Interrupt | NodeListFailure::InternalError => Self::NodeLookupFailure,
+        }
+    }
+}
+
+/// The metadata of a node in a shared memory compatible representation.
+///
+/// The strings are empty and the remaining fields have their default value when the details of
+/// the node were not accessible, see [`NodeInfo::has_details`].
+#[derive(Debug, Clone, PartialEq, Eq, ZeroCopySend, serde::Serialize, serde::Deserialize)]
+#[repr(C)]
+pub struct NodeInfo {
+    /// The id of the node.
+    pub node_id: UniqueNodeId,
+
+    /// Whether the details of the node were accessible.
+    pub has_details: bool,
+
+    /// The name of the node.
+    pub name: NodeNameString,
+
+    /// The executable of the node's process.
+    pub executable: ExecutableString,
+
+    /// The process id of the node's process as seen from inside the process.
+    pub process_id: u32,
+
+    /// The host the node's process runs on.
+    pub host_name: HostNameString,
+
+    /// The user that owns the node's process.
+    pub user_name: UserNameString,
+
+    /// The command line of the node's process, the arguments are separated by spaces. It is
+    /// truncated when it exceeds [`MAX_COMMAND_LINE_LENGTH`].
+    pub command_line: CommandLineString,
+
+    /// The time, based on the realtime clock, when the node was created.
+    pub start_time: Time,
+
+    /// The user-defined attributes of the node.
+    pub attributes: AttributeSet,
+}
+
+impl NodeInfo {
+    /// Creates the [`NodeInfo`] of a node from its [`NodeDetails`].
+    pub fn new(node_id: &UniqueNodeId, details: Option<&NodeDetails>) -> Self {
+        let mut info = Self {
+            node_id: *node_id,
+            has_details: false,
+            name: NodeNameString::new(),
+            executable: ExecutableString::new(),
+            process_id: 0,
+            host_name: HostNameString::new(),
+            user_name: UserNameString::new(),
+            command_line: CommandLineString::new(),
+            start_time: Time::default(),
+            attributes: AttributeSpecifier::new().attributes().clone(),
+        };
+
+        if let Some(details) = details {
+            info.has_details = true;
+            info.name =
+                NodeNameString::from_str_truncated(details.name().as_str()).unwrap_or_default();
+            info.executable =
+                ExecutableString::from_bytes_truncated(details.executable().as_bytes())
+                    .unwrap_or_default();
+            info.process_id = details.process_id().value() as u32;
+            if let Some(host_name) = details.host_name() {
+                info.host_name =
+                    HostNameString::from_bytes_truncated(host_name.as_bytes()).unwrap_or_default();
+            }
+            if let Some(user_name) = details.user_name() {
+                info.user_name =
+                    UserNameString::from_bytes_truncated(user_name.as_bytes()).unwrap_or_default();
+            }
+            info.command_line =
+                CommandLineString::from_str_truncated(&details.command_line().join(" "))
+                    .unwrap_or_default();
+            info.start_time = details.start_time();
+            info.attributes = details.attributes().clone();
+        }
+
+        info
+    }
+}
+
+/// A tracker for monitoring the alive nodes of a specific service type.
+///
+/// The `Tracker` keeps track of the alive nodes in the system, allowing for discovery
+/// of new nodes and detection of nodes that are no longer alive.
+///
+/// # Type Parameters
+///
+/// * `S` - The service type of the nodes to track, which must implement the `Service` trait
+#[derive(Debug)]
+pub struct Tracker<S: Service> {
+    config: Config,
+    nodes: BTreeMap<UniqueNodeId, NodeInfo>,
+    _service: core::marker::PhantomData<S>,
+}
+
+impl<S: Service> Tracker<S> {
+    /// Create a new Tracker instance.
+    pub fn new(config: &Config) -> Self {
+        Self {
+            config: config.clone(),
+            nodes: BTreeMap::new(),
+            _service: core::marker::PhantomData,
+        }
+    }
+
+    /// Synchronizes the tracker with the current state of the nodes in the system.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// * A vector of node IDs that were newly discovered, their infos are stored in the tracker
+    ///   and retrievable with `Tracker::get()`
+    /// * A vector of node infos for nodes that are no longer alive, these infos are no longer
+    ///   stored in the tracker
+    pub fn sync(&mut self) -> Result<(Vec<UniqueNodeId>, Vec<NodeInfo>), SyncError> {
+        let mut discovered_ids = BTreeSet::<UniqueNodeId>::new();
+        let mut added_ids = Vec::<UniqueNodeId>::new();
+
+        Node::<S>::list(&self.config, |node_state| {
+            if let NodeState::Alive(view) = node_state {
+                let id = *view.id();
+                discovered_ids.insert(id);
+
+                // Track new nodes.
+                if let Entry::Vacant(e) = self.nodes.entry(id) {
+                    e.insert(NodeInfo::new(&id, view.details().as_ref()));
+                    added_ids.push(id);
+                }
+            }
+            CallbackProgression::Continue
+        })?;
+
+        let undiscovered_ids: Vec<UniqueNodeId> = self
+            .nodes
+            .keys()
+            .filter(|&id| !discovered_ids.contains(id))
+            .cloned()
+            .collect();
+
+        let mut removed_nodes = Vec::new();
+        for id in undiscovered_ids {
+            if let Some(node) = self.nodes.remove(&id) {
+                removed_nodes.push(node);
+            }
+        }
+
+        Ok((added_ids, removed_nodes))
+    }
+
+    /// Retrieves the node info for a specific node ID if tracked.
+    pub fn get(&self, id: &UniqueNodeId) -> Option<&NodeInfo> {
+        self.nodes.get(id)
+    }
+
+    /// Retrieves the node infos of all the nodes that are being currently tracked.
+    pub fn get_all(&self) -> Vec<&NodeInfo> {
+        self.nodes.values().collect()
+    }
+}
@@ -107,7 +107,9 @@ impl From<PublisherCreateError> for CreationError {
                 CreationError::PublisherAlreadyExists
             }
             PublisherCreateError::UnableToCreateDataSegment
-            | PublisherCreateError::FailedToDeployThreadsafetyPolicy => {
+            | PublisherCreateError::FailedToDeployThreadsafetyPolicy
+            | PublisherCreateError::InsufficientResources
+            | PublisherCreateError::UnableToCreateWakeupEvent => {
                 CreationError::PublisherCreationError
             }
         }
@@ -0,0 +1,194 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod node_discovery_service {
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_services_discovery::node_discovery::{
+        Config, Discovery, NodeInfo, Payload, Service, service_name,
+    };
+
+    #[test]
+    fn publishes_metadata_of_added_and_removed_nodes_when_configured() {
+        const NUMBER_OF_NODES_ADDED: usize = 5;
+        const NUMBER_OF_NODES_REMOVED: usize = 3;
+
+        let iceoryx_config = generate_isolated_config();
+
+        let discovery_config = Config {
+            sync_on_initialization: true,
+            publish_events: true,
+            max_subscribers: 1,
+            max_buffer_size: 10,
+            send_notifications: false,
+            max_listeners: 1,
+            ..Default::default()
+        };
+        let mut sut = Service::<ipc::Service>::create(&discovery_config, &iceoryx_config).unwrap();
+
+        // subscribe to the discovery service
+        let node = NodeBuilder::new()
+            .config(&iceoryx_config)
+            .create::<ipc::Service>()
+            .unwrap();
+
+        let service = node
+            .service_builder(service_name())
+            .publish_subscribe::<Payload>()
+            .open_or_create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        // add some nodes, the subscribing node is added as well
+        let mut nodes = vec![];
+        for _ in 0..NUMBER_OF_NODES_ADDED {
+            nodes.push(
+                NodeBuilder::new()
+                    .config(&iceoryx_config)
+                    .create::<ipc::Service>()
+                    .unwrap(),
+            );
+        }
+        sut.spin(|_| {}, |_| {}).unwrap();
+
+        // remove some nodes
+        for _ in 0..NUMBER_OF_NODES_REMOVED {
+            nodes.pop();
+        }
+        sut.spin(|_| {}, |_| {}).unwrap();
+
+        let mut num_added = 0;
+        let mut num_removed = 0;
+        while let Ok(Some(sample)) = subscriber.receive() {
+            match sample.payload() {
+                Discovery::Added(_) => {
+                    num_added += 1;
+                }
+                Discovery::Removed(_) => {
+                    num_removed += 1;
+                }
+            }
+        }
+
+        assert_that!(num_added, eq NUMBER_OF_NODES_ADDED + 1);
+        assert_that!(num_removed, eq NUMBER_OF_NODES_REMOVED);
+    }
+
+    #[test]
+    fn sends_events_for_added_or_removed_nodes_when_configured() {
+        let iceoryx_config = generate_isolated_config();
+
+        let discovery_config = Config {
+            sync_on_initialization: true,
+            publish_events: false,
+            send_notifications: true,
+            max_listeners: 1,
+            ..Default::default()
+        };
+        let mut sut = Service::<ipc::Service>::create(&discovery_config, &iceoryx_config).unwrap();
+
+        // listen to the discovery service
+        let node = NodeBuilder::new()
+            .config(&iceoryx_config)
+            .create::<ipc::Service>()
+            .unwrap();
+
+        let service = node
+            .service_builder(service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        let listener = service.listener_builder().create().unwrap();
+
+        // add a node
+        let added_node = NodeBuilder::new()
+            .config(&iceoryx_config)
+            .create::<ipc::Service>()
+            .unwrap();
+        sut.spin(|_| {}, |_| {}).unwrap();
+
+        let result = listener.try_wait_one();
+        assert_that!(result, is_ok);
+        assert_that!(result.unwrap(), is_some);
+
+        // remove a node
+        drop(added_node);
+        sut.spin(|_| {}, |_| {}).unwrap();
+
+        let result = listener.try_wait_one();
+        assert_that!(result, is_ok);
+        assert_that!(result.unwrap(), is_some);
+    }
+
+    #[test]
+    fn get_current_discovery_states() -> Result<(), Box<dyn core::error::Error>> {
+        const NUM_NODES: usize = 10;
+
+        let iceoryx_config = generate_isolated_config();
+
+        let node = NodeBuilder::new()
+            .config(&iceoryx_config)
+            .create::<ipc::Service>()
+            .unwrap();
+
+        let nodes: Vec<_> = (0..NUM_NODES)
+            .map(|_| {
+                NodeBuilder::new()
+                    .config(&iceoryx_config)
+                    .create::<ipc::Service>()
+                    .unwrap()
+            })
+            .collect();
+
+        let discovery_config = Config {
+            sync_on_initialization: true,
+            publish_events: false,
+            send_notifications: false,
+            initial_max_slice_len: NUM_NODES + 2,
+            ..Default::default()
+        };
+
+        let mut sut = Service::<ipc::Service>::create(&discovery_config, &iceoryx_config).unwrap();
+
+        // === Request current discovery state ===
+        let service = node
+            .service_builder(service_name())
+            .request_response::<(), [NodeInfo]>()
+            .open_or_create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.loan_uninit()?.write_payload(()).send()?;
+
+        sut.spin(|_| {}, |_| {})?;
+
+        let mut received_ids = vec![];
+        while let Some(response) = pending_response.receive()? {
+            for info in response.payload().iter() {
+                assert_that!(info.has_details, eq true);
+                received_ids.push(info.node_id);
+            }
+        }
+
+        // the requesting node and the node of the discovery service are also alive
+        assert_that!(received_ids, len NUM_NODES + 2);
+        assert_that!(received_ids, contains * node.id());
+        for n in &nodes {
+            assert_that!(received_ids, contains * n.id());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod node_discovery_tracker {
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::attribute::{AttributeKey, AttributeValue};
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_services_discovery::node_discovery::Tracker;
+
+    #[test]
+    fn syncs_added_nodes<S: Service>() {
+        const NUMBER_OF_NODES_ADDED: usize = 8;
+
+        let config = generate_isolated_config();
+        let mut sut = Tracker::<S>::new(&config);
+
+        let mut nodes = vec![];
+        for _ in 0..NUMBER_OF_NODES_ADDED {
+            nodes.push(NodeBuilder::new().config(&config).create::<S>().unwrap());
+        }
+
+        // verify added nodes are detected
+        let (added, _) = sut.sync().expect("failed to sync tracker");
+
+        assert_that!(added.len(), eq NUMBER_OF_NODES_ADDED);
+        for node in &nodes {
+            assert_that!(added, contains * node.id());
+        }
+
+        // verify added nodes are not detected again in subsequent sync
+        let (added, removed) = sut.sync().expect("failed to sync tracker");
+        assert_that!(added.len(), eq 0);
+        assert_that!(removed.len(), eq 0);
+    }
+
+    #[test]
+    fn syncs_removed_nodes<S: Service>() {
+        const NUMBER_OF_NODES_ADDED: usize = 8;
+        const NUMBER_OF_NODES_REMOVED: usize = 3;
+
+        let config = generate_isolated_config();
+        let mut sut = Tracker::<S>::new(&config);
+
+        let mut nodes = vec![];
+        for _ in 0..NUMBER_OF_NODES_ADDED {
+            nodes.push(NodeBuilder::new().config(&config).create::<S>().unwrap());
+        }
+
+        let (added, _) = sut.sync().expect("failed to sync tracker");
+        assert_that!(added.len(), eq NUMBER_OF_NODES_ADDED);
+
+        // remove some nodes by dropping them
+        let mut removed_ids = vec![];
+        for _ in 0..NUMBER_OF_NODES_REMOVED {
+            let removed = nodes.pop().unwrap();
+            removed_ids.push(*removed.id());
+            drop(removed);
+        }
+
+        // verify the dropped nodes are detected as removed
+        let (_, removed) = sut.sync().expect("failed to sync tracker");
+        assert_that!(removed.len(), eq NUMBER_OF_NODES_REMOVED);
+        for node in removed {
+            assert_that!(removed_ids, contains node.node_id);
+        }
+    }
+
+    #[test]
+    fn tracked_nodes_contain_metadata<S: Service>() {
+        let config = generate_isolated_config();
+        let key = AttributeKey::try_from("version").unwrap();
+        let value = AttributeValue::try_from("1.2.3").unwrap();
+        let node = NodeBuilder::new()
+            .name(&"metadata-node".try_into().unwrap())
+            .attributes(&AttributeSpecifier::new().define(&key, &value).unwrap())
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        let mut sut = Tracker::<S>::new(&config);
+        sut.sync().expect("failed to sync tracker");
+
+        let info = sut.get(node.id());
+        assert_that!(info, is_some);
+        let info = info.unwrap();
+        assert_that!(info.has_details, eq true);
+        assert_that!(info.name, eq "metadata-node");
+        assert_that!(info.process_id, eq std::process::id());
+        assert_that!(info.command_line.len(), gt 0);
+        assert_that!(info.start_time.seconds(), gt 0);
+        assert_that!(info.attributes, eq * node.attributes());
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -0,0 +1,117 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec::Vec;
+
+use iceoryx2::node::{Node, NodeBuilder};
+use iceoryx2::service::Service;
+use iceoryx2_log::{fail, trace};
+use iceoryx2_services_tunnel_backend::traits::Backend;
+
+use crate::discovery::DomainDiscovery;
+use crate::relays::{Factory, event, publish_subscribe};
+use crate::remapping::{Remapping, Rule};
+
+/// Errors that can occur while creating the [`DomainBackend`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    /// A [`Rule`] contains an invalid service name
+    ServiceName,
+    /// The node in the other domain could not be created
+    Node,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+/// Configuration of the [`DomainBackend`].
+#[derive(Debug, Default)]
+pub struct Config {
+    /// The iceoryx2 config of the other domain
+    pub peer: iceoryx2::config::Config,
+    /// The services that are forwarded into the other domain
+    pub rules: Vec<Rule>,
+}
+
+/// A [`Backend`] that relays the services of the tunnel into another iceoryx2
+/// domain of the same host instead of over a network.
+#[derive(Debug)]
+pub struct DomainBackend<S: Service> {
+    node: Node<S>,
+    remapping: Remapping,
+    discovery: DomainDiscovery<S>,
+}
+
+impl<S: Service> DomainBackend<S> {
+    /// Returns the [`Node`] of the [`DomainBackend`] in the other domain.
+    pub fn node(&self) -> &Node<S> {
+        &self.node
+    }
+}
+
+impl<S: Service> Backend<S> for DomainBackend<S> {
+    type Config = Config;
+    type CreationError = CreationError;
+    type Discovery = DomainDiscovery<S>;
+
+    type PublishSubscribeRelay = publish_subscribe::Relay<S>;
+    type EventRelay = event::Relay<S>;
+
+    type RelayFactory<'b>
+        = Factory<'b, S>
+    where
+        Self: 'b;
+
+    fn create(config: &Self::Config) -> Result<Self, Self::CreationError> {
+        let origin = "DomainBackend::create";
+
+        trace!(
+            from origin,
+            "Initializing domain backend"
+        );
+
+        let remapping = fail!(
+            from origin,
+            when Remapping::new(&config.rules),
+            with CreationError::ServiceName,
+            "Failed to create domain backend since the rules contain an invalid service name"
+        );
+
+        let node = fail!(
+            from origin,
+            when NodeBuilder::new().config(&config.peer).create::<S>(),
+            with CreationError::Node,
+            "Failed to create node in the other domain"
+        );
+
+        let discovery = DomainDiscovery::create(&config.peer, remapping.clone());
+
+        Ok(Self {
+            node,
+            remapping,
+            discovery,
+        })
+    }
+
+    fn relay_builder(&self) -> Self::RelayFactory<'_> {
+        Self::RelayFactory::new(&self.node, &self.remapping)
+    }
+
+    fn discovery(&self) -> &impl iceoryx2_services_tunnel_backend::traits::Discovery {
+        &self.discovery
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::config::Config;
+use iceoryx2::service::Service;
+use iceoryx2::service::static_config::StaticConfig;
+use iceoryx2::service::static_config::messaging_pattern::MessagingPattern;
+use iceoryx2_bb_concurrency::cell::RefCell;
+use iceoryx2_log::{fail, fatal_panic};
+use iceoryx2_services_discovery::service_discovery::Tracker;
+
+use crate::remapping::Remapping;
+
+/// Errors that can occur while discovering the services of the other domain.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum DiscoveryError {
+    /// The tracker could not synchronize with the services of the other domain
+    TrackerSynchronization,
+    /// The callback failed to process a discovered service
+    DiscoveryProcessing,
+}
+
+impl core::fmt::Display for DiscoveryError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "DiscoveryError::{self:?}")
+    }
+}
+
+impl core::error::Error for DiscoveryError {}
+
+/// Errors that can occur while announcing a service to the other domain.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum AnnouncementError {}
+
+impl core::fmt::Display for AnnouncementError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "AnnouncementError::{self:?}")
+    }
+}
+
+impl core::error::Error for AnnouncementError {}
+
+/// Tracks the services of the other domain and reports the publish-subscribe
+/// and event services that are selected by a [`Rule`](crate::Rule) under
+/// their name in the first domain.
+#[derive(Debug)]
+pub struct DomainDiscovery<S: Service> {
+    tracker: RefCell<Tracker<S>>,
+    remapping: Remapping,
+}
+
+impl<S: Service> DomainDiscovery<S> {
+    pub(crate) fn create(peer_config: &Config, remapping: Remapping) -> Self {
+        Self {
+            tracker: RefCell::new(Tracker::new(peer_config)),
+            remapping,
+        }
+    }
+}
+
+impl<S: Service> iceoryx2_services_tunnel_backend::traits::Discovery for DomainDiscovery<S> {
+    type DiscoveryError = DiscoveryError;
+    type AnnouncementError = AnnouncementError;
+
+    fn announce(&self, _static_config: &StaticConfig) -> Result<(), Self::AnnouncementError> {
+        // NOOP - the relays create the service in the other domain where it
+        // is discovered by iceoryx2 itself
+        Ok(())
+    }
+
+    fn discover<E: core::error::Error, F: FnMut(&StaticConfig) -> Result<(), E>>(
+        &self,
+        mut process_discovery: F,
+    ) -> Result<(), Self::DiscoveryError> {
+        let tracker = &mut self.tracker.borrow_mut();
+        let (added, _removed) = fail!(
+            from self,
+            when tracker.sync(),
+            with DiscoveryError::TrackerSynchronization,
+            "Failed to synchronize tracker of the other domain"
+        );
+
+        for id in added {
+            let static_config = match &tracker.get(&id) {
+                Some(service_details) => &service_details.static_details,
+                None => {
+                    fatal_panic!(
+                        from "DomainDiscovery::discover",
+                        "This should never happen. Service discovered by tracker is not retrievable."
+                    )
+                }
+            };
+
+            // request-response services are bridged by the gateway itself
+            if !matches!(
+                static_config.messaging_pattern(),
+                MessagingPattern::PublishSubscribe(_) | MessagingPattern::Event(_)
+            ) {
+                continue;
+            }
+
+            if let Some(local_name) = self.remapping.to_local(static_config.name()) {
+                fail!(
+                    from self,
+                    when process_discovery(&static_config.__internal_rename::<S>(local_name)),
+                    with DiscoveryError::DiscoveryProcessing,
+                    "Failed to process discovery event"
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,295 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::collections::BTreeMap;
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::vec::Vec;
+
+use iceoryx2::service::Service;
+use iceoryx2::service::service_hash::ServiceHash;
+use iceoryx2::service::static_config::StaticConfig;
+use iceoryx2::service::static_config::messaging_pattern::MessagingPattern;
+use iceoryx2_log::{fail, info, trace};
+use iceoryx2_services_discovery::service_discovery::Tracker;
+use iceoryx2_services_tunnel::Tunnel;
+
+use crate::backend::{self, DomainBackend};
+use crate::remapping::{Remapping, Rule};
+use crate::request_response::{RequestResponseBridge, RequestResponsePorts};
+
+/// Errors that can occur while creating the [`Gateway`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    /// A [`Rule`] contains an invalid service name
+    ServiceName,
+    /// The tunnel that forwards the publish-subscribe and event services could
+    /// not be created
+    Tunnel,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+/// Errors that can occur while discovering the services to forward.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum DiscoveryError {
+    /// The tunnel failed to discover the publish-subscribe and event services
+    Tunnel,
+    /// The services of a domain could not be tracked
+    DiscoveryOverTracker,
+    /// The ports to bridge a request-response service could not be created
+    RequestResponsePortsCreation,
+}
+
+impl core::fmt::Display for DiscoveryError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "DiscoveryError::{self:?}")
+    }
+}
+
+impl core::error::Error for DiscoveryError {}
+
+/// Errors that can occur while forwarding between the domains.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum PropagateError {
+    /// The tunnel failed to forward the publish-subscribe payloads or events
+    Tunnel,
+    /// A request or response could not be forwarded
+    RequestResponsePropagation,
+}
+
+impl core::fmt::Display for PropagateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "PropagateError::{self:?}")
+    }
+}
+
+impl core::error::Error for PropagateError {}
+
+/// Configuration of the [`Gateway`].
+#[derive(Debug, Default, Clone)]
+pub struct Config {
+    /// The services that are forwarded between the domains. Services that are
+    /// not selected by a [`Rule`] stay isolated in their domain.
+    pub rules: Vec<Rule>,
+}
+
+/// Forwards the services selected by the [`Rule`]s between two iceoryx2
+/// domains with one [`Node`](iceoryx2::node::Node) in each domain.
+#[derive(Debug)]
+pub struct Gateway<S: Service> {
+    tunnel: Tunnel<S, DomainBackend<S>>,
+    remapping: Remapping,
+    local_tracker: Tracker<S>,
+    peer_tracker: Tracker<S>,
+    request_response: BTreeMap<ServiceHash, RequestResponseBridge<S>>,
+}
+
+impl<S: Service> Gateway<S> {
+    /// Creates a [`Gateway`] between the domain of `first_domain` and the
+    /// domain of `second_domain`. The services are not forwarded before
+    /// [`Gateway::discover()`] was called.
+    pub fn create(
+        config: &Config,
+        first_domain: &iceoryx2::config::Config,
+        second_domain: &iceoryx2::config::Config,
+    ) -> Result<Self, CreationError> {
+        let origin = format!("Gateway<{}>::create()", core::any::type_name::<S>());
+
+        trace!(
+            from origin,
+            "Creating Gateway:\n{:?}",
+            &config);
+
+        let remapping = fail!(
+            from origin,
+            when Remapping::new(&config.rules),
+            with CreationError::ServiceName,
+            "Failed to create gateway since the rules contain an invalid service name"
+        );
+
+        let tunnel_config = iceoryx2_services_tunnel::Config {
+            discovery_service: None,
+            services: Some(config.rules.iter().map(|rule| rule.from.clone()).collect()),
+        };
+        let backend_config = backend::Config {
+            peer: second_domain.clone(),
+            rules: config.rules.clone(),
+        };
+
+        let tunnel = fail!(
+            from origin,
+            when Tunnel::create(&tunnel_config, first_domain, &backend_config),
+            with CreationError::Tunnel,
+            "Failed to create the tunnel between the domains"
+        );
+
+        Ok(Self {
+            tunnel,
+            remapping,
+            local_tracker: Tracker::new(first_domain),
+            peer_tracker: Tracker::new(second_domain),
+            request_response: BTreeMap::new(),
+        })
+    }
+
+    /// Discovers the services of both domains and sets up the forwarding for
+    /// every service that is selected by a [`Rule`].
+    pub fn discover(&mut self) -> Result<(), DiscoveryError> {
+        fail!(
+            from self,
+            when self.tunnel.discover(),
+            with DiscoveryError::Tunnel,
+            "Failed to discover publish-subscribe and event services"
+        );
+
+        self.discover_request_response()
+    }
+
+    /// Forwards all pending payloads, events, requests and responses between
+    /// the domains.
+    pub fn propagate(&mut self) -> Result<(), PropagateError> {
+        fail!(
+            from self,
+            when self.tunnel.propagate(),
+            with PropagateError::Tunnel,
+            "Failed to propagate publish-subscribe payloads and events"
+        );
+
+        for bridge in self.request_response.values_mut() {
+            let propagated = fail!(
+                from "Gateway::propagate",
+                when bridge.propagate(),
+                with PropagateError::RequestResponsePropagation,
+                "Failed to propagate requests and responses of {}",
+                bridge.local.service_name
+            );
+            if propagated {
+                info!(
+                    from "Gateway::propagate",
+                    "Propagated RequestResponse({})",
+                    bridge.local.service_name
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the [`ServiceHash`]es of the forwarded services in the first
+    /// domain.
+    pub fn forwarded_services(&self) -> BTreeSet<ServiceHash> {
+        let mut services = self.tunnel.tunneled_services();
+        services.extend(self.request_response.keys().cloned());
+        services
+    }
+
+    fn discover_request_response(&mut self) -> Result<(), DiscoveryError> {
+        let (added, _removed) = fail!(
+            from self,
+            when self.local_tracker.sync(),
+            with DiscoveryError::DiscoveryOverTracker,
+            "Failed to synchronize tracker of the first domain"
+        );
+        for id in added {
+            if let Some(details) = self.local_tracker.get(&id) {
+                let static_config = &details.static_details;
+                if let (MessagingPattern::RequestResponse(_), Some(peer_name)) = (
+                    static_config.messaging_pattern(),
+                    self.remapping.to_peer(static_config.name()),
+                ) {
+                    setup_request_response(
+                        &self.tunnel,
+                        &mut self.request_response,
+                        static_config,
+                        &static_config.__internal_rename::<S>(peer_name),
+                    )?;
+                }
+            }
+        }
+
+        let (added, _removed) = fail!(
+            from self,
+            when self.peer_tracker.sync(),
+            with DiscoveryError::DiscoveryOverTracker,
+            "Failed to synchronize tracker of the second domain"
+        );
+        for id in added {
+            if let Some(details) = self.peer_tracker.get(&id) {
+                let static_config = &details.static_details;
+                if let (MessagingPattern::RequestResponse(_), Some(local_name)) = (
+                    static_config.messaging_pattern(),
+                    self.remapping.to_local(static_config.name()),
+                ) {
+                    setup_request_response(
+                        &self.tunnel,
+                        &mut self.request_response,
+                        &static_config.__internal_rename::<S>(local_name),
+                        static_config,
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn setup_request_response<S: Service>(
+    tunnel: &Tunnel<S, DomainBackend<S>>,
+    bridges: &mut BTreeMap<ServiceHash, RequestResponseBridge<S>>,
+    local_config: &StaticConfig,
+    peer_config: &StaticConfig,
+) -> Result<(), DiscoveryError> {
+    let origin = format!(
+        "Gateway<{}>::setup_request_response()",
+        core::any::type_name::<S>()
+    );
+
+    if bridges.contains_key(local_config.service_hash()) {
+        // Nothing to do.
+        return Ok(());
+    }
+
+    info!(
+        from origin,
+        "Discovered {}({})",
+        local_config.messaging_pattern(),
+        local_config.name()
+    );
+
+    let local = fail!(
+        from origin,
+        when RequestResponsePorts::new(local_config, tunnel.node()),
+        with DiscoveryError::RequestResponsePortsCreation,
+        "Failed to create request-response ports in the first domain"
+    );
+    let peer = fail!(
+        from origin,
+        when RequestResponsePorts::new(peer_config, tunnel.backend().node()),
+        with DiscoveryError::RequestResponsePortsCreation,
+        "Failed to create request-response ports in the second domain"
+    );
+
+    bridges.insert(
+        *local_config.service_hash(),
+        RequestResponseBridge::new(local, peer),
+    );
+
+    Ok(())
+}
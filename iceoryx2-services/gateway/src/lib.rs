@@ -0,0 +1,82 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # iceoryx2-services-gateway
+//!
+//! Selectively shares services between two iceoryx2 domains on the same host.
+//!
+//! Domains are isolated from each other by their `global.prefix` and root path,
+//! services of one domain are invisible to the other. The gateway runs one
+//! node in each domain and forwards only the services listed in its
+//! [`Rule`]s, optionally under a different name in the other domain.
+//! Publish-subscribe, event and request-response services are supported and
+//! are forwarded in both directions.
+//!
+//! ## Architecture
+//!
+//! The publish-subscribe and event services are forwarded by a
+//! [`Tunnel`](iceoryx2_services_tunnel::Tunnel) that uses the
+//! [`DomainBackend`](backend::DomainBackend), an in-process backend that
+//! relays the payloads and events into the other domain instead of over a
+//! network. Request-response services are bridged by the gateway itself: a
+//! request is forwarded by a client in the other domain and every response is
+//! routed back to the originating client.
+//!
+//! Like the tunnel, the gateway does not spawn any threads and is driven by
+//! the user.
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_services_gateway::{Config, Gateway, Rule};
+//!
+//! fn main() -> Result<(), Box<dyn core::error::Error>> {
+//!     let mut safety_domain = iceoryx2::config::Config::default();
+//!     safety_domain.global.prefix = FileName::new(b"safety_")?;
+//!     let mut infotainment_domain = iceoryx2::config::Config::default();
+//!     infotainment_domain.global.prefix = FileName::new(b"infotainment_")?;
+//!
+//!     let config = Config {
+//!         rules: vec![Rule::new("vehicle/speed", "public/vehicle/speed")],
+//!     };
+//!     let mut gateway =
+//!         Gateway::<ipc::Service>::create(&config, &safety_domain, &infotainment_domain)?;
+//!
+//!     loop {
+//!         gateway.discover()?;
+//!         gateway.propagate()?;
+//!         // Sleep or do other work...
+//!     }
+//! }
+//! ```
+
+#![no_std]
+
+extern crate alloc;
+
+/// The in-process [`Backend`](iceoryx2_services_tunnel_backend::traits::Backend)
+/// that relays publish-subscribe and event services into another domain
+pub mod backend;
+
+/// Discovers the forwarded services of the other domain
+pub mod discovery;
+
+/// Relays that forward payloads and events into the other domain
+pub mod relays;
+
+mod gateway;
+mod remapping;
+mod request_response;
+
+pub use gateway::*;
+pub use remapping::Rule;
@@ -0,0 +1,202 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::node::Node;
+use iceoryx2::port::listener::Listener;
+use iceoryx2::port::notifier::Notifier;
+use iceoryx2::prelude::EventId;
+use iceoryx2::service::{Service, static_config::StaticConfig};
+use iceoryx2_log::{fail, trace};
+use iceoryx2_services_tunnel_backend::traits::{EventRelay, RelayBuilder};
+
+use crate::remapping::Remapping;
+
+/// Errors that can occur while creating an event [`Relay`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    /// No [`Rule`](crate::Rule) selects the service
+    UnmappedService,
+    /// The service could not be opened or created in the other domain
+    Service,
+    /// The notifier could not be created in the other domain
+    Notifier,
+    /// The listener could not be created in the other domain
+    Listener,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+/// Errors that can occur while forwarding an event into the other domain.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SendError {
+    /// The listeners of the other domain could not be notified
+    NotificationDelivery,
+}
+
+impl core::fmt::Display for SendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SendError::{self:?}")
+    }
+}
+
+impl core::error::Error for SendError {}
+
+/// Errors that can occur while receiving an event from the other domain.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ReceiveError {
+    /// The event could not be received from the other domain
+    EventReceive,
+}
+
+impl core::fmt::Display for ReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ReceiveError::{self:?}")
+    }
+}
+
+impl core::error::Error for ReceiveError {}
+
+/// Builds an event [`Relay`] into the other domain.
+#[derive(Debug)]
+pub struct Builder<'a, S: Service> {
+    node: &'a Node<S>,
+    remapping: &'a Remapping,
+    static_config: &'a StaticConfig,
+}
+
+impl<'a, S: Service> Builder<'a, S> {
+    pub(crate) fn new(
+        node: &'a Node<S>,
+        remapping: &'a Remapping,
+        static_config: &'a StaticConfig,
+    ) -> Builder<'a, S> {
+        Builder {
+            node,
+            remapping,
+            static_config,
+        }
+    }
+}
+
+impl<S: Service> RelayBuilder for Builder<'_, S> {
+    type CreationError = CreationError;
+    type Relay = Relay<S>;
+
+    fn create(self) -> Result<Self::Relay, Self::CreationError> {
+        let peer_name = match self.remapping.to_peer(self.static_config.name()) {
+            Some(peer_name) => peer_name,
+            None => {
+                fail!(from self, with CreationError::UnmappedService,
+                    "No rule forwards the service {}({})",
+                    self.static_config.messaging_pattern(), self.static_config.name());
+            }
+        };
+        let static_config = self.static_config.__internal_rename::<S>(peer_name);
+
+        let event_config = static_config.event();
+        let service = fail!(
+            from self,
+            when self.node
+                .service_builder(static_config.name())
+                .event()
+                .max_nodes(event_config.max_nodes())
+                .max_listeners(event_config.max_listeners())
+                .max_notifiers(event_config.max_notifiers())
+                .event_id_max_value(event_config.event_id_max_value())
+                .open_or_create(),
+            with CreationError::Service,
+            "Failed to open or create service {}({}) in the other domain",
+            static_config.messaging_pattern(), static_config.name()
+        );
+
+        let notifier = fail!(
+            from self,
+            when service.notifier_builder().create(),
+            with CreationError::Notifier,
+            "Failed to create Notifier for {}({}) in the other domain",
+            static_config.messaging_pattern(), static_config.name()
+        );
+
+        let listener = fail!(
+            from self,
+            when service.listener_builder().create(),
+            with CreationError::Listener,
+            "Failed to create Listener for {}({}) in the other domain",
+            static_config.messaging_pattern(), static_config.name()
+        );
+
+        Ok(Relay {
+            static_config,
+            notifier,
+            listener,
+        })
+    }
+}
+
+/// Forwards the events of an event service into the other domain and receives
+/// the events notified there.
+#[derive(Debug)]
+pub struct Relay<S: Service> {
+    static_config: StaticConfig,
+    notifier: Notifier<S>,
+    listener: Listener<S>,
+}
+
+impl<S: Service> EventRelay<S> for Relay<S> {
+    type SendError = SendError;
+    type ReceiveError = ReceiveError;
+
+    fn send(&self, event_id: EventId) -> Result<(), Self::SendError> {
+        trace!(
+            from self,
+            "Sending {}({})",
+            self.static_config.messaging_pattern(),
+            self.static_config.name()
+        );
+
+        // skip the own listener, otherwise the event would be forwarded back
+        fail!(
+            from self,
+            when self.notifier.__internal_notify(event_id, true),
+            with SendError::NotificationDelivery,
+            "Failed to notify the listeners of the other domain"
+        );
+
+        Ok(())
+    }
+
+    fn receive(&self) -> Result<Option<EventId>, Self::ReceiveError> {
+        let event_id = fail!(
+            from self,
+            when self.listener.try_wait_one(),
+            with ReceiveError::EventReceive,
+            "Failed to receive event from the other domain"
+        );
+
+        if event_id.is_some() {
+            trace!(
+                from self,
+                "Ingesting {}({})",
+                self.static_config.messaging_pattern(),
+                self.static_config.name()
+            );
+        }
+
+        Ok(event_id)
+    }
+}
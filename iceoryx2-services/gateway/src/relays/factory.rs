@@ -0,0 +1,65 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::node::Node;
+use iceoryx2::service::{Service, static_config::StaticConfig};
+use iceoryx2_services_tunnel_backend::traits::RelayFactory;
+
+use crate::relays::{event, publish_subscribe};
+use crate::remapping::Remapping;
+
+/// Creates the [`RelayBuilder`](iceoryx2_services_tunnel_backend::traits::RelayBuilder)s
+/// of the [`DomainBackend`](crate::backend::DomainBackend).
+#[derive(Debug)]
+pub struct Factory<'backend, S: Service> {
+    /// The node of the other domain. The node must outlive the Factory.
+    node: &'backend Node<S>,
+    remapping: &'backend Remapping,
+}
+
+impl<'backend, S: Service> Factory<'backend, S> {
+    pub(crate) fn new(node: &'backend Node<S>, remapping: &'backend Remapping) -> Self {
+        Factory { node, remapping }
+    }
+}
+
+impl<S: Service> RelayFactory<S> for Factory<'_, S> {
+    type PublishSubscribeRelay = publish_subscribe::Relay<S>;
+    type EventRelay = event::Relay<S>;
+
+    type PublishSubscribeBuilder<'config>
+        = publish_subscribe::Builder<'config, S>
+    where
+        Self: 'config;
+
+    type EventBuilder<'config>
+        = event::Builder<'config, S>
+    where
+        Self: 'config;
+
+    fn publish_subscribe<'config>(
+        &self,
+        static_config: &'config StaticConfig,
+    ) -> Self::PublishSubscribeBuilder<'config>
+    where
+        Self: 'config,
+    {
+        publish_subscribe::Builder::new(self.node, self.remapping, static_config)
+    }
+
+    fn event<'config>(&self, static_config: &'config StaticConfig) -> Self::EventBuilder<'config>
+    where
+        Self: 'config,
+    {
+        event::Builder::new(self.node, self.remapping, static_config)
+    }
+}
@@ -0,0 +1,296 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::identifiers::UniqueNodeId;
+use iceoryx2::node::Node;
+use iceoryx2::prelude::AllocationStrategy;
+use iceoryx2::service::builder::CustomHeaderMarker;
+use iceoryx2::service::{Service, static_config::StaticConfig};
+use iceoryx2_log::{fail, trace};
+use iceoryx2_services_tunnel_backend::traits::{PublishSubscribeRelay, RelayBuilder};
+use iceoryx2_services_tunnel_backend::types::publish_subscribe::{
+    Header, LoanFn, Payload, Publisher, Sample, SampleMut, Subscriber,
+};
+
+use crate::remapping::Remapping;
+
+/// Errors that can occur while creating a publish-subscribe [`Relay`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    /// No [`Rule`](crate::Rule) selects the service
+    UnmappedService,
+    /// The service could not be opened or created in the other domain
+    Service,
+    /// The publisher could not be created in the other domain
+    Publisher,
+    /// The subscriber could not be created in the other domain
+    Subscriber,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+/// Errors that can occur while forwarding a sample into the other domain.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SendError {
+    /// The sample could not be loaned in the other domain
+    SampleLoan,
+    /// The sample could not be delivered in the other domain
+    SampleDelivery,
+}
+
+impl core::fmt::Display for SendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SendError::{self:?}")
+    }
+}
+
+impl core::error::Error for SendError {}
+
+/// Errors that can occur while receiving a sample from the other domain.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ReceiveError {
+    /// The sample could not be received from the other domain
+    SampleReceive,
+    /// The sample could not be loaned in the first domain
+    IceoryxLoan,
+}
+
+impl core::fmt::Display for ReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ReceiveError::{self:?}")
+    }
+}
+
+impl core::error::Error for ReceiveError {}
+
+/// Builds a publish-subscribe [`Relay`] into the other domain.
+#[derive(Debug)]
+pub struct Builder<'a, S: Service> {
+    node: &'a Node<S>,
+    remapping: &'a Remapping,
+    static_config: &'a StaticConfig,
+}
+
+impl<'a, S: Service> Builder<'a, S> {
+    pub(crate) fn new(
+        node: &'a Node<S>,
+        remapping: &'a Remapping,
+        static_config: &'a StaticConfig,
+    ) -> Builder<'a, S> {
+        Builder {
+            node,
+            remapping,
+            static_config,
+        }
+    }
+}
+
+impl<S: Service> RelayBuilder for Builder<'_, S> {
+    type CreationError = CreationError;
+    type Relay = Relay<S>;
+
+    fn create(self) -> Result<Self::Relay, Self::CreationError> {
+        let peer_name = match self.remapping.to_peer(self.static_config.name()) {
+            Some(peer_name) => peer_name,
+            None => {
+                fail!(from self, with CreationError::UnmappedService,
+                    "No rule forwards the service {}({})",
+                    self.static_config.messaging_pattern(), self.static_config.name());
+            }
+        };
+        let static_config = self.static_config.__internal_rename::<S>(peer_name);
+
+        let port_config = static_config.publish_subscribe();
+        let service = unsafe {
+            fail!(
+                from self,
+                when self.node.service_builder(static_config.name())
+                        .publish_subscribe::<Payload>()
+                        .user_header::<Header>()
+                        .__internal_set_user_header_type_details(
+                            &port_config.message_type_details().user_header,
+                        )
+                        .__internal_set_payload_type_details(
+                            &port_config.message_type_details().payload,
+                        )
+                        .enable_safe_overflow(port_config.has_safe_overflow())
+                        .history_size(port_config.history_size())
+                        .max_nodes(port_config.max_nodes())
+                        .max_publishers(port_config.max_publishers())
+                        .max_subscribers(port_config.max_subscribers())
+                        .subscriber_max_buffer_size(port_config.subscriber_max_buffer_size())
+                        .subscriber_max_borrowed_samples(
+                            port_config.subscriber_max_borrowed_samples(),
+                        )
+                        .open_or_create(),
+                with CreationError::Service,
+                "Failed to open or create service {}({}) in the other domain",
+                static_config.messaging_pattern(), static_config.name()
+            )
+        };
+
+        let publisher = fail!(
+            from self,
+            when service
+                .publisher_builder()
+                .allocation_strategy(AllocationStrategy::PowerOfTwo)
+                .create(),
+            with CreationError::Publisher,
+            "Failed to create Publisher for {}({}) in the other domain",
+            static_config.messaging_pattern(), static_config.name()
+        );
+
+        let subscriber = fail!(
+            from self,
+            when service.subscriber_builder().create(),
+            with CreationError::Subscriber,
+            "Failed to create Subscriber for {}({}) in the other domain",
+            static_config.messaging_pattern(), static_config.name()
+        );
+
+        Ok(Relay {
+            static_config,
+            node_id: *self.node.id(),
+            publisher,
+            subscriber,
+        })
+    }
+}
+
+/// Forwards the samples of a publish-subscribe service into the other domain
+/// and receives the samples published there.
+#[derive(Debug)]
+pub struct Relay<S: Service> {
+    static_config: StaticConfig,
+    node_id: UniqueNodeId,
+    publisher: Publisher<S>,
+    subscriber: Subscriber<S>,
+}
+
+impl<S: Service> PublishSubscribeRelay<S> for Relay<S> {
+    type SendError = SendError;
+    type ReceiveError = ReceiveError;
+
+    fn send(&self, sample: Sample<S>) -> Result<(), Self::SendError> {
+        trace!(
+            from self,
+            "Sending {}({})",
+            self.static_config.messaging_pattern(),
+            self.static_config.name()
+        );
+
+        let number_of_elements = sample.header().number_of_elements() as usize;
+        let forwarded_sample = unsafe { self.publisher.loan_custom_payload(number_of_elements) };
+        let mut forwarded_sample = fail!(
+            from self,
+            when forwarded_sample,
+            with SendError::SampleLoan,
+            "Failed to loan sample in the other domain"
+        );
+
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                sample.user_header() as *const CustomHeaderMarker as *const u8,
+                forwarded_sample.user_header_mut() as *mut CustomHeaderMarker as *mut u8,
+                user_header_size(&self.static_config),
+            );
+            core::ptr::copy_nonoverlapping(
+                sample.payload().as_ptr().cast::<u8>(),
+                forwarded_sample.payload_mut().as_mut_ptr().cast::<u8>(),
+                sample.payload().len(),
+            );
+        }
+        if let Some(trace_context) = sample.header().trace_context() {
+            forwarded_sample.set_trace_context(trace_context);
+        }
+
+        fail!(
+            from self,
+            when unsafe { forwarded_sample.assume_init() }.send(),
+            with SendError::SampleDelivery,
+            "Failed to deliver sample in the other domain"
+        );
+
+        Ok(())
+    }
+
+    fn receive<LoanError>(
+        &self,
+        loan: &mut LoanFn<'_, S, LoanError>,
+    ) -> Result<Option<SampleMut<S>>, Self::ReceiveError> {
+        loop {
+            let sample = unsafe { self.subscriber.receive_custom_payload() };
+            let sample = fail!(
+                from self,
+                when sample,
+                with ReceiveError::SampleReceive,
+                "Failed to receive sample from the other domain"
+            );
+
+            let sample = match sample {
+                Some(sample) => sample,
+                None => return Ok(None),
+            };
+
+            if sample.header().node_id() == self.node_id {
+                // Ignore samples forwarded by the gateway itself to avoid loopback.
+                continue;
+            }
+
+            trace!(
+                from self,
+                "Ingesting {}({})",
+                self.static_config.messaging_pattern(),
+                self.static_config.name()
+            );
+
+            let mut ingested_sample = fail!(
+                from self,
+                when loan(sample.payload().len()),
+                with ReceiveError::IceoryxLoan,
+                "Failed to loan sample from iceoryx"
+            );
+
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    sample.user_header() as *const CustomHeaderMarker as *const u8,
+                    ingested_sample.user_header_mut() as *mut CustomHeaderMarker as *mut u8,
+                    user_header_size(&self.static_config),
+                );
+                core::ptr::copy_nonoverlapping(
+                    sample.payload().as_ptr().cast::<u8>(),
+                    ingested_sample.payload_mut().as_mut_ptr().cast::<u8>(),
+                    sample.payload().len(),
+                );
+            }
+            if let Some(trace_context) = sample.header().trace_context() {
+                ingested_sample.set_trace_context(trace_context);
+            }
+
+            return Ok(Some(unsafe { ingested_sample.assume_init() }));
+        }
+    }
+}
+
+fn user_header_size(static_config: &StaticConfig) -> usize {
+    static_config
+        .publish_subscribe()
+        .message_type_details()
+        .user_header
+        .size()
+}
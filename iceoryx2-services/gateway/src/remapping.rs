@@ -0,0 +1,74 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use iceoryx2::service::service_name::{ServiceName, ServiceNameError};
+
+/// Selects a service that is forwarded by the gateway. The service `from` of
+/// the first domain is available as service `to` in the second domain and
+/// vice versa.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Rule {
+    /// Name of the service in the first domain
+    pub from: String,
+    /// Name of the service in the second domain
+    pub to: String,
+}
+
+impl Rule {
+    /// Creates a new [`Rule`] that forwards the service `from` of the first
+    /// domain as service `to` into the second domain.
+    pub fn new(from: &str, to: &str) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+        }
+    }
+}
+
+/// The validated [`Rule`]s, used to map the service names between the domains.
+#[derive(Debug, Clone)]
+pub(crate) struct Remapping {
+    names: Vec<(ServiceName, ServiceName)>,
+}
+
+impl Remapping {
+    pub(crate) fn new(rules: &[Rule]) -> Result<Self, ServiceNameError> {
+        let mut names = Vec::with_capacity(rules.len());
+        for rule in rules {
+            names.push((
+                ServiceName::new(rule.from.as_str())?,
+                ServiceName::new(rule.to.as_str())?,
+            ));
+        }
+
+        Ok(Self { names })
+    }
+
+    /// Returns the name of the service in the second domain.
+    pub(crate) fn to_peer(&self, name: &ServiceName) -> Option<&ServiceName> {
+        self.names
+            .iter()
+            .find(|(from, _)| from == name)
+            .map(|(_, to)| to)
+    }
+
+    /// Returns the name of the service in the first domain.
+    pub(crate) fn to_local(&self, name: &ServiceName) -> Option<&ServiceName> {
+        self.names
+            .iter()
+            .find(|(_, to)| to == name)
+            .map(|(from, _)| from)
+    }
+}
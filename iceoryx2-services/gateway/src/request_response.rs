@@ -0,0 +1,355 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use iceoryx2::identifiers::UniqueNodeId;
+use iceoryx2::node::Node;
+use iceoryx2::prelude::AllocationStrategy;
+use iceoryx2::service::builder::CustomHeaderMarker;
+use iceoryx2::service::service_name::ServiceName;
+use iceoryx2::service::{Service, static_config::StaticConfig};
+use iceoryx2_log::{fail, trace};
+use iceoryx2_services_tunnel_backend::types::publish_subscribe::{Header, Payload};
+
+type Client<S> = iceoryx2::port::client::Client<S, Payload, Header, Payload, Header>;
+type Server<S> = iceoryx2::port::server::Server<S, Payload, Header, Payload, Header>;
+type ActiveRequest<S> =
+    iceoryx2::active_request::ActiveRequest<S, Payload, Header, Payload, Header>;
+type PendingResponse<S> =
+    iceoryx2::pending_response::PendingResponse<S, Payload, Header, Payload, Header>;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Service,
+    Client,
+    Server,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum PropagateError {
+    RequestReceive,
+    RequestLoan,
+    RequestDelivery,
+    ResponseReceive,
+    ResponseLoan,
+    ResponseDelivery,
+}
+
+impl core::fmt::Display for PropagateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "PropagateError::{self:?}")
+    }
+}
+
+impl core::error::Error for PropagateError {}
+
+/// The client and server of the gateway in one domain.
+#[derive(Debug)]
+pub(crate) struct RequestResponsePorts<S: Service> {
+    pub(crate) service_name: ServiceName,
+    request_user_header_size: usize,
+    response_user_header_size: usize,
+    node_id: UniqueNodeId,
+    client: Client<S>,
+    server: Server<S>,
+}
+
+impl<S: Service> RequestResponsePorts<S> {
+    pub(crate) fn new(static_config: &StaticConfig, node: &Node<S>) -> Result<Self, CreationError> {
+        let origin = format!("RequestResponsePorts<{}>::new", core::any::type_name::<S>());
+
+        // the builder is assembled in a separate frame, the large temporaries of the
+        // builder chain would otherwise stay on the stack while the service is opened
+        let service = fail!(
+            from origin,
+            when service_builder(static_config, node).open_or_create(),
+            with CreationError::Service,
+            "Failed to open or create service {}({})", static_config.messaging_pattern(), static_config.name()
+        );
+
+        let client = fail!(
+            from origin,
+            when service
+                .client_builder()
+                .allocation_strategy(AllocationStrategy::PowerOfTwo)
+                .create(),
+            with CreationError::Client,
+            "Failed to create Client for {}({})", static_config.messaging_pattern(), static_config.name()
+        );
+
+        let server = fail!(
+            from origin,
+            when service
+                .server_builder()
+                .allocation_strategy(AllocationStrategy::PowerOfTwo)
+                .create(),
+            with CreationError::Server,
+            "Failed to create Server for {}({})", static_config.messaging_pattern(), static_config.name()
+        );
+
+        let port_config = static_config.request_response();
+        Ok(Self {
+            service_name: *static_config.name(),
+            request_user_header_size: port_config
+                .request_message_type_details()
+                .user_header
+                .size(),
+            response_user_header_size: port_config
+                .response_message_type_details()
+                .user_header
+                .size(),
+            node_id: *node.id(),
+            client,
+            server,
+        })
+    }
+}
+
+fn service_builder<S: Service>(
+    static_config: &StaticConfig,
+    node: &Node<S>,
+) -> iceoryx2::service::builder::request_response::Builder<Payload, Header, Payload, Header, S> {
+    let port_config = static_config.request_response();
+    unsafe {
+        node.service_builder(static_config.name())
+            .request_response::<Payload, Payload>()
+            .request_user_header::<Header>()
+            .response_user_header::<Header>()
+            .__internal_set_request_payload_type_details(
+                &port_config.request_message_type_details().payload,
+            )
+            .__internal_set_request_header_type_details(
+                &port_config.request_message_type_details().user_header,
+            )
+            .__internal_set_response_payload_type_details(
+                &port_config.response_message_type_details().payload,
+            )
+            .__internal_set_response_header_type_details(
+                &port_config.response_message_type_details().user_header,
+            )
+            .enable_safe_overflow_for_requests(port_config.has_safe_overflow_for_requests())
+            .enable_safe_overflow_for_responses(port_config.has_safe_overflow_for_responses())
+            .enable_fire_and_forget_requests(port_config.does_support_fire_and_forget_requests())
+            .max_active_requests_per_client(port_config.max_active_requests_per_client())
+            .max_loaned_requests(port_config.max_loaned_requests())
+            .max_response_buffer_size(port_config.max_response_buffer_size())
+            .max_borrowed_responses_per_pending_response(
+                port_config.max_borrowed_responses_per_pending_response(),
+            )
+            .max_servers(port_config.max_servers())
+            .max_clients(port_config.max_clients())
+            .max_nodes(port_config.max_nodes())
+    }
+}
+
+/// A request that was forwarded into the other domain together with the
+/// request of the original client the responses are routed back to.
+#[derive(Debug)]
+struct ForwardedRequest<S: Service> {
+    active_request: ActiveRequest<S>,
+    pending_response: PendingResponse<S>,
+}
+
+/// Bridges a request-response service between two domains. Requests are
+/// forwarded in both directions.
+#[derive(Debug)]
+pub(crate) struct RequestResponseBridge<S: Service> {
+    pub(crate) local: RequestResponsePorts<S>,
+    pub(crate) peer: RequestResponsePorts<S>,
+    local_to_peer: Vec<ForwardedRequest<S>>,
+    peer_to_local: Vec<ForwardedRequest<S>>,
+}
+
+impl<S: Service> RequestResponseBridge<S> {
+    pub(crate) fn new(local: RequestResponsePorts<S>, peer: RequestResponsePorts<S>) -> Self {
+        Self {
+            local,
+            peer,
+            local_to_peer: Vec::new(),
+            peer_to_local: Vec::new(),
+        }
+    }
+
+    /// Forwards the pending requests and responses. Returns [`true`] when
+    /// anything was forwarded.
+    pub(crate) fn propagate(&mut self) -> Result<bool, PropagateError> {
+        let mut propagated = forward_requests(&self.local, &self.peer, &mut self.local_to_peer)?;
+        propagated |= forward_requests(&self.peer, &self.local, &mut self.peer_to_local)?;
+        propagated |= forward_responses(&self.local, &mut self.local_to_peer)?;
+        propagated |= forward_responses(&self.peer, &mut self.peer_to_local)?;
+
+        Ok(propagated)
+    }
+}
+
+fn forward_requests<S: Service>(
+    source: &RequestResponsePorts<S>,
+    destination: &RequestResponsePorts<S>,
+    forwarded_requests: &mut Vec<ForwardedRequest<S>>,
+) -> Result<bool, PropagateError> {
+    let mut forwarded = false;
+
+    loop {
+        let active_request = unsafe { source.server.receive_custom_payload() };
+        let active_request = fail!(
+            from source,
+            when active_request,
+            with PropagateError::RequestReceive,
+            "Failed to receive request to forward"
+        );
+
+        let active_request = match active_request {
+            Some(active_request) => active_request,
+            None => break,
+        };
+
+        if active_request.header().node_id() == source.node_id {
+            // Ignore requests forwarded by the gateway itself to avoid loopback.
+            continue;
+        }
+
+        trace!(
+            from source,
+            "Forwarding request of RequestResponse({}) to {}",
+            source.service_name,
+            destination.service_name
+        );
+
+        let number_of_elements = active_request.header().number_of_elements() as usize;
+        let request = unsafe { destination.client.loan_custom_payload(number_of_elements) };
+        let mut request = fail!(
+            from destination,
+            when request,
+            with PropagateError::RequestLoan,
+            "Failed to loan request to forward"
+        );
+
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                active_request.user_header() as *const CustomHeaderMarker as *const u8,
+                request.user_header_mut() as *mut CustomHeaderMarker as *mut u8,
+                destination.request_user_header_size,
+            );
+            core::ptr::copy_nonoverlapping(
+                active_request.payload().as_ptr().cast::<u8>(),
+                request.payload_mut().as_mut_ptr().cast::<u8>(),
+                active_request.payload().len(),
+            );
+        }
+
+        let mut request = unsafe { request.assume_init() };
+        if let Some(trace_context) = active_request.header().trace_context() {
+            request.set_trace_context(trace_context);
+        }
+
+        let pending_response = fail!(
+            from destination,
+            when request.send(),
+            with PropagateError::RequestDelivery,
+            "Failed to deliver forwarded request"
+        );
+
+        forwarded_requests.push(ForwardedRequest {
+            active_request,
+            pending_response,
+        });
+        forwarded = true;
+    }
+
+    Ok(forwarded)
+}
+
+fn forward_responses<S: Service>(
+    destination: &RequestResponsePorts<S>,
+    forwarded_requests: &mut Vec<ForwardedRequest<S>>,
+) -> Result<bool, PropagateError> {
+    let mut forwarded = false;
+
+    for forwarded_request in forwarded_requests.iter() {
+        let active_request = &forwarded_request.active_request;
+        let pending_response = &forwarded_request.pending_response;
+
+        if active_request.has_disconnect_hint() {
+            pending_response.set_disconnect_hint();
+        }
+
+        loop {
+            let response = unsafe { pending_response.receive_custom_payload() };
+            let response = fail!(
+                from destination,
+                when response,
+                with PropagateError::ResponseReceive,
+                "Failed to receive response to forward"
+            );
+
+            let response = match response {
+                Some(response) => response,
+                None => break,
+            };
+
+            let number_of_elements = response.header().number_of_elements() as usize;
+            let forwarded_response =
+                unsafe { active_request.loan_custom_payload(number_of_elements) };
+            let mut forwarded_response = fail!(
+                from destination,
+                when forwarded_response,
+                with PropagateError::ResponseLoan,
+                "Failed to loan response to forward"
+            );
+
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    response.user_header() as *const CustomHeaderMarker as *const u8,
+                    forwarded_response.user_header_mut() as *mut CustomHeaderMarker as *mut u8,
+                    destination.response_user_header_size,
+                );
+                core::ptr::copy_nonoverlapping(
+                    response.payload().as_ptr().cast::<u8>(),
+                    forwarded_response.payload_mut().as_mut_ptr().cast::<u8>(),
+                    response.payload().len(),
+                );
+            }
+            if let Some(trace_context) = response.header().trace_context() {
+                forwarded_response.set_trace_context(trace_context);
+            }
+
+            fail!(
+                from destination,
+                when unsafe { forwarded_response.assume_init() }.send(),
+                with PropagateError::ResponseDelivery,
+                "Failed to deliver forwarded response"
+            );
+
+            forwarded = true;
+        }
+    }
+
+    // Dropping the forwarded request signals the end of the response stream to
+    // the other side.
+    forwarded_requests.retain(|forwarded_request| {
+        forwarded_request.active_request.is_connected()
+            && (forwarded_request.pending_response.is_connected()
+                || forwarded_request.pending_response.has_response())
+    });
+
+    Ok(forwarded)
+}
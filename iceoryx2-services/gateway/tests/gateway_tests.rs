@@ -0,0 +1,313 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod gateway {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::messaging_pattern::MessagingPattern;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_services_gateway::{Config, CreationError, Gateway, Rule};
+
+    type S = ipc::Service;
+
+    const MAX_PROPAGATIONS: usize = 10;
+
+    fn create_gateway(
+        from: &ServiceName,
+        to: &ServiceName,
+        first_domain: &iceoryx2::config::Config,
+        second_domain: &iceoryx2::config::Config,
+    ) -> Gateway<S> {
+        let config = Config {
+            rules: vec![Rule::new(from.as_str(), to.as_str())],
+        };
+        Gateway::<S>::create(&config, first_domain, second_domain).unwrap()
+    }
+
+    fn create_node(config: &iceoryx2::config::Config) -> Node<S> {
+        NodeBuilder::new().config(config).create::<S>().unwrap()
+    }
+
+    #[test]
+    fn creation_fails_with_invalid_service_name_in_rule() {
+        let config = Config {
+            rules: vec![Rule::new("", "some/service")],
+        };
+
+        let sut = Gateway::<S>::create(
+            &config,
+            &generate_isolated_config(),
+            &generate_isolated_config(),
+        );
+
+        assert_that!(sut.err(), eq Some(CreationError::ServiceName));
+    }
+
+    #[test]
+    fn publish_subscribe_is_forwarded_into_second_domain_with_remapped_name() {
+        let first_domain = generate_isolated_config();
+        let second_domain = generate_isolated_config();
+        let from = generate_service_name();
+        let to = generate_service_name();
+        let first_node = create_node(&first_domain);
+        let second_node = create_node(&second_domain);
+
+        let service = first_node
+            .service_builder(&from)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let mut sut = create_gateway(&from, &to, &first_domain, &second_domain);
+        sut.discover().unwrap();
+
+        assert_that!(sut.forwarded_services().contains(service.service_hash()), eq true);
+
+        let subscriber = second_node
+            .service_builder(&to)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap()
+            .subscriber_builder()
+            .create()
+            .unwrap();
+
+        publisher.send_copy(8127).unwrap();
+
+        let mut received = None;
+        for _ in 0..MAX_PROPAGATIONS {
+            sut.propagate().unwrap();
+            if let Some(sample) = subscriber.receive().unwrap() {
+                received = Some(*sample);
+                break;
+            }
+        }
+
+        assert_that!(received, eq Some(8127));
+    }
+
+    #[test]
+    fn publish_subscribe_is_forwarded_into_first_domain() {
+        let first_domain = generate_isolated_config();
+        let second_domain = generate_isolated_config();
+        let from = generate_service_name();
+        let to = generate_service_name();
+        let first_node = create_node(&first_domain);
+        let second_node = create_node(&second_domain);
+
+        let publisher = second_node
+            .service_builder(&to)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap()
+            .publisher_builder()
+            .create()
+            .unwrap();
+
+        let mut sut = create_gateway(&from, &to, &first_domain, &second_domain);
+        sut.discover().unwrap();
+
+        let subscriber = first_node
+            .service_builder(&from)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap()
+            .subscriber_builder()
+            .create()
+            .unwrap();
+
+        publisher.send_copy(4711).unwrap();
+
+        let mut received = Vec::new();
+        for _ in 0..MAX_PROPAGATIONS {
+            sut.propagate().unwrap();
+            while let Some(sample) = subscriber.receive().unwrap() {
+                received.push(*sample);
+            }
+        }
+
+        // the forwarded sample must not be forwarded back and arrive twice
+        assert_that!(received, eq vec![4711]);
+    }
+
+    #[test]
+    fn services_without_rule_are_not_forwarded() {
+        let first_domain = generate_isolated_config();
+        let second_domain = generate_isolated_config();
+        let service_name = generate_service_name();
+        let first_node = create_node(&first_domain);
+
+        let _service = first_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let mut sut = create_gateway(
+            &generate_service_name(),
+            &generate_service_name(),
+            &first_domain,
+            &second_domain,
+        );
+        sut.discover().unwrap();
+
+        assert_that!(sut.forwarded_services(), len 0);
+        assert_that!(
+            S::does_exist(&service_name, &second_domain, MessagingPattern::PublishSubscribe)
+                .unwrap(),
+            eq false
+        );
+    }
+
+    #[test]
+    fn events_are_forwarded_into_second_domain() {
+        let first_domain = generate_isolated_config();
+        let second_domain = generate_isolated_config();
+        let from = generate_service_name();
+        let to = generate_service_name();
+        let first_node = create_node(&first_domain);
+        let second_node = create_node(&second_domain);
+
+        let notifier = first_node
+            .service_builder(&from)
+            .event()
+            .create()
+            .unwrap()
+            .notifier_builder()
+            .create()
+            .unwrap();
+
+        let mut sut = create_gateway(&from, &to, &first_domain, &second_domain);
+        sut.discover().unwrap();
+
+        let listener = second_node
+            .service_builder(&to)
+            .event()
+            .open()
+            .unwrap()
+            .listener_builder()
+            .create()
+            .unwrap();
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(12))
+            .unwrap();
+
+        let mut received = None;
+        for _ in 0..MAX_PROPAGATIONS {
+            sut.propagate().unwrap();
+            if let Some(event_id) = listener.try_wait_one().unwrap() {
+                received = Some(event_id);
+                break;
+            }
+        }
+
+        assert_that!(received, eq Some(EventId::new(12)));
+    }
+
+    #[test]
+    fn requests_are_forwarded_and_responses_are_routed_back() {
+        let first_domain = generate_isolated_config();
+        let second_domain = generate_isolated_config();
+        let from = generate_service_name();
+        let to = generate_service_name();
+        let first_node = create_node(&first_domain);
+        let second_node = create_node(&second_domain);
+
+        let server = second_node
+            .service_builder(&to)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap()
+            .server_builder()
+            .create()
+            .unwrap();
+
+        let mut sut = create_gateway(&from, &to, &first_domain, &second_domain);
+        sut.discover().unwrap();
+
+        let service = first_node
+            .service_builder(&from)
+            .request_response::<u64, u64>()
+            .open()
+            .unwrap();
+        assert_that!(sut.forwarded_services().contains(service.service_hash()), eq true);
+
+        let client = service.client_builder().create().unwrap();
+        let pending_response = client.send_copy(21).unwrap();
+
+        let mut received = Vec::new();
+        for _ in 0..MAX_PROPAGATIONS {
+            sut.propagate().unwrap();
+            while let Some(active_request) = server.receive().unwrap() {
+                active_request.send_copy(*active_request * 2).unwrap();
+            }
+            while let Some(response) = pending_response.receive().unwrap() {
+                received.push(*response);
+            }
+        }
+
+        assert_that!(received, eq vec![42]);
+    }
+
+    #[test]
+    fn request_stream_ends_when_server_in_other_domain_drops_request() {
+        let first_domain = generate_isolated_config();
+        let second_domain = generate_isolated_config();
+        let from = generate_service_name();
+        let to = generate_service_name();
+        let first_node = create_node(&first_domain);
+        let second_node = create_node(&second_domain);
+
+        let client = first_node
+            .service_builder(&from)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap()
+            .client_builder()
+            .create()
+            .unwrap();
+
+        let mut sut = create_gateway(&from, &to, &first_domain, &second_domain);
+        sut.discover().unwrap();
+
+        let server = second_node
+            .service_builder(&to)
+            .request_response::<u64, u64>()
+            .open()
+            .unwrap()
+            .server_builder()
+            .create()
+            .unwrap();
+
+        let pending_response = client.send_copy(1).unwrap();
+
+        let mut active_request = None;
+        for _ in 0..MAX_PROPAGATIONS {
+            sut.propagate().unwrap();
+            if let Some(request) = server.receive().unwrap() {
+                active_request = Some(request);
+                break;
+            }
+        }
+        assert_that!(active_request.is_some(), eq true);
+        active_request.unwrap().send_copy(2).unwrap();
+
+        for _ in 0..MAX_PROPAGATIONS {
+            sut.propagate().unwrap();
+        }
+
+        assert_that!(pending_response.receive().unwrap().map(|r| *r), eq Some(2));
+        assert_that!(pending_response.is_connected(), eq false);
+    }
+}
@@ -0,0 +1,268 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    path::PathBuf,
+};
+
+use iceoryx2::{
+    config::Config as IceoryxConfig,
+    node::{Node, NodeBuilder, NodeCreationFailure},
+    port::{ReceiveError, subscriber::Subscriber, subscriber::SubscriberCreateError},
+    service::{
+        Service as ServiceType, builder::publish_subscribe::PublishSubscribeOpenOrCreateError,
+    },
+};
+
+use crate::{
+    record::{Level, LogRecord},
+    service::{Config, Payload, open_or_create},
+};
+
+/// Errors that can occur when creating the [`Collector`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    /// Failed to create the underlying node.
+    NodeCreationFailure,
+
+    /// Failed to open or create the log service.
+    ServiceCreationFailure,
+
+    /// Failed to create the subscriber, for instance since the maximum number of subscribers
+    /// of the log service is exceeded.
+    SubscriberCreationError,
+
+    /// The log directory or the log file could not be created.
+    UnableToOpenLogFile,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+impl From<NodeCreationFailure> for CreationError {
+    fn from(_: NodeCreationFailure) -> Self {
+        CreationError::NodeCreationFailure
+    }
+}
+
+impl From<PublishSubscribeOpenOrCreateError> for CreationError {
+    fn from(_: PublishSubscribeOpenOrCreateError) -> Self {
+        CreationError::ServiceCreationFailure
+    }
+}
+
+impl From<SubscriberCreateError> for CreationError {
+    fn from(_: SubscriberCreateError) -> Self {
+        CreationError::SubscriberCreationError
+    }
+}
+
+/// Errors that can occur during the spin operation of the [`Collector`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SpinError {
+    /// Failed to receive log records from the log service.
+    ReceiveFailure,
+
+    /// Failed to write a log record into the log file.
+    WriteFailure,
+
+    /// Failed to rotate the log files.
+    RotationFailure,
+}
+
+impl core::fmt::Display for SpinError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SpinError::{self:?}")
+    }
+}
+
+impl core::error::Error for SpinError {}
+
+impl From<ReceiveError> for SpinError {
+    fn from(_: ReceiveError) -> Self {
+        SpinError::ReceiveFailure
+    }
+}
+
+/// Configuration of the [`Collector`].
+#[derive(Debug, Clone)]
+pub struct CollectorConfig {
+    /// The directory in which the log files are stored. It is created when it does not exist.
+    pub directory: PathBuf,
+
+    /// The name of the active log file is `{file_prefix}.log`, rotated files have an
+    /// additional index suffix, `{file_prefix}.log.1` is the most recent one.
+    pub file_prefix: String,
+
+    /// The size in bytes after which the active log file is rotated.
+    pub max_file_size: u64,
+
+    /// The maximum number of log files, including the active one. When it is exceeded,
+    /// the oldest log file is removed.
+    pub max_files: usize,
+
+    /// Log records with a lower [`Level`] are discarded.
+    pub min_level: Level,
+
+    /// The configuration of the log service.
+    pub service: Config,
+}
+
+impl Default for CollectorConfig {
+    fn default() -> Self {
+        Self {
+            directory: PathBuf::from("."),
+            file_prefix: "iceoryx2".into(),
+            max_file_size: 10 * 1024 * 1024,
+            max_files: 5,
+            min_level: Level::Trace,
+            service: Config::default(),
+        }
+    }
+}
+
+/// Receives the [`LogRecord`]s of all nodes and writes them into rotated log files.
+///
+/// # Type Parameters
+///
+/// * `S` - The service type that the collector operates on.
+#[derive(Debug)]
+pub struct Collector<S: ServiceType> {
+    _node: Node<S>,
+    subscriber: Subscriber<S, Payload, ()>,
+    config: CollectorConfig,
+    file: File,
+    file_size: u64,
+}
+
+impl<S: ServiceType> Collector<S> {
+    /// Creates the collector and opens, or creates, the active log file.
+    ///
+    /// # Parameters
+    ///
+    /// * `collector_config` - Configuration for the collector.
+    /// * `iceoryx_config` - Configuration for the underlying iceoryx system.
+    ///
+    /// # Returns
+    ///
+    /// A result containing either the created collector or an error if creation failed.
+    pub fn create(
+        collector_config: &CollectorConfig,
+        iceoryx_config: &IceoryxConfig,
+    ) -> Result<Self, CreationError> {
+        let node = NodeBuilder::new().config(iceoryx_config).create::<S>()?;
+        let service = open_or_create(&node, &collector_config.service)?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        std::fs::create_dir_all(&collector_config.directory)
+            .map_err(|_| CreationError::UnableToOpenLogFile)?;
+        let path = log_file_path(collector_config, 0);
+        let file = open_log_file(&path).map_err(|_| CreationError::UnableToOpenLogFile)?;
+        let file_size = file
+            .metadata()
+            .map_err(|_| CreationError::UnableToOpenLogFile)?
+            .len();
+
+        Ok(Self {
+            _node: node,
+            subscriber,
+            config: collector_config.clone(),
+            file,
+            file_size,
+        })
+    }
+
+    /// Returns the path of the active log file.
+    pub fn log_file(&self) -> PathBuf {
+        log_file_path(&self.config, 0)
+    }
+
+    /// Receives all available [`LogRecord`]s and writes every record that passes the
+    /// configured [`CollectorConfig::min_level`] into the active log file. The log files are
+    /// rotated whenever the active one would exceed [`CollectorConfig::max_file_size`].
+    ///
+    /// # Parameters
+    ///
+    /// * `on_record` - Callback function that is called for each written log record
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SpinError` if the log records could not be received or written.
+    pub fn spin<F: FnMut(&LogRecord)>(&mut self, mut on_record: F) -> Result<(), SpinError> {
+        while let Some(sample) = self.subscriber.receive()? {
+            let record = sample.payload();
+            if record.level < self.config.min_level {
+                continue;
+            }
+
+            let line = format!("{record}\n");
+            if self.file_size != 0 && self.file_size + line.len() as u64 > self.config.max_file_size
+            {
+                self.rotate()?;
+            }
+
+            self.file
+                .write_all(line.as_bytes())
+                .map_err(|_| SpinError::WriteFailure)?;
+            self.file_size += line.len() as u64;
+
+            on_record(record);
+        }
+
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> Result<(), SpinError> {
+        let max_index = self.config.max_files.saturating_sub(1);
+        if max_index == 0 {
+            self.file
+                .set_len(0)
+                .map_err(|_| SpinError::RotationFailure)?;
+            self.file_size = 0;
+            return Ok(());
+        }
+
+        // the oldest file is overwritten by its successor
+        for index in (0..max_index).rev() {
+            let from = log_file_path(&self.config, index);
+            if from.exists() {
+                std::fs::rename(&from, log_file_path(&self.config, index + 1))
+                    .map_err(|_| SpinError::RotationFailure)?;
+            }
+        }
+
+        self.file = open_log_file(&log_file_path(&self.config, 0))
+            .map_err(|_| SpinError::RotationFailure)?;
+        self.file_size = 0;
+
+        Ok(())
+    }
+}
+
+fn log_file_path(config: &CollectorConfig, index: usize) -> PathBuf {
+    let name = match index {
+        0 => format!("{}.log", config.file_prefix),
+        n => format!("{}.log.{n}", config.file_prefix),
+    };
+    config.directory.join(name)
+}
+
+fn open_log_file(path: &PathBuf) -> std::io::Result<File> {
+    OpenOptions::new().append(true).create(true).open(path)
+}
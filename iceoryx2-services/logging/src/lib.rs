@@ -0,0 +1,57 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Log Service
+//!
+//! The `iceoryx2-services-logging` crate provides centralized logging without a syslog
+//! daemon. Every process installs the [`Logger`](logger::Logger) as iceoryx2 log backend
+//! and publishes its log messages as structured [`LogRecord`](record::LogRecord)s on a
+//! dedicated shared-memory service. A [`Collector`](collector::Collector) receives the
+//! records of all nodes and writes them into rotated log files, `iox2 log` tails them
+//! on the console.
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_services_logging::collector::{Collector, CollectorConfig};
+//! use iceoryx2_services_logging::logger::Logger;
+//!
+//! fn main() -> Result<(), Box<dyn core::error::Error>> {
+//!     // in every process
+//!     let _logger_guard = Logger::new().install();
+//!
+//!     // in the process that stores the logs
+//!     let config = CollectorConfig {
+//!         directory: "/var/log/iceoryx2".into(),
+//!         ..Default::default()
+//!     };
+//!     let mut collector = Collector::<ipc::Service>::create(&config, Config::global_config())?;
+//!
+//!     loop {
+//!         collector.spin(|_| {})?;
+//!         // Sleep or do other work...
+//!     }
+//! }
+//! ```
+
+#![warn(missing_docs)]
+
+/// Receives the log records of all nodes and writes them into rotated files
+pub mod collector;
+
+/// A logger backend that publishes log messages on the log service
+pub mod logger;
+
+/// The structured log record that is transmitted via the log service
+pub mod record;
+
+/// Configuration and name of the log service
+pub mod service;
@@ -0,0 +1,287 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use std::sync::Mutex;
+
+use iceoryx2::{
+    config::Config as IceoryxConfig,
+    node::{Node, NodeBuilder},
+    port::{publisher::Publisher, unable_to_deliver_strategy::UnableToDeliverStrategy},
+    prelude::{NodeName, ipc_threadsafe},
+};
+use iceoryx2_bb_concurrency::atomic::{AtomicUsize, Ordering};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+use iceoryx2_log::{Log, LogLevel};
+
+use crate::{
+    record::LogRecord,
+    service::{Config, Payload, open_or_create},
+};
+
+type S = ipc_threadsafe::Service;
+
+const NO_OWNER: usize = 0;
+
+// a failed connection attempt is repeated after this duration, every further failed attempt
+// doubles it until MAX_RECONNECT_BACKOFF is reached
+const INITIAL_RECONNECT_BACKOFF: Duration = Duration::from_millis(10);
+const MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(10);
+
+thread_local! {
+    static THREAD_MARKER: u8 = const { 0 };
+}
+
+/// Returns an address that is unique for the calling thread while it is alive.
+fn current_thread_marker() -> usize {
+    THREAD_MARKER.with(|marker| marker as *const u8 as usize)
+}
+
+enum State {
+    Disconnected {
+        last_attempt: Option<Time>,
+        backoff: Duration,
+    },
+    Ready {
+        node: Node<S>,
+        publisher: Publisher<S, Payload, ()>,
+    },
+    ShutDown,
+}
+
+impl State {
+    fn disconnected() -> Self {
+        State::Disconnected {
+            last_attempt: None,
+            backoff: INITIAL_RECONNECT_BACKOFF,
+        }
+    }
+}
+
+/// A [`Log`]ger that publishes every log message as [`LogRecord`] on the log service, see
+/// [`service_name()`](crate::service::service_name).
+///
+/// The node and the publisher are created with the first log message, therefore the logger
+/// can be installed with [`Logger::install()`] before anything else is done. When the log
+/// service is not accessible, the connection is attempted again with an increasing backoff.
+/// Log messages that iceoryx2 emits while the logger itself is publishing, as well as all
+/// messages that cannot be published, are forwarded to the optional fallback [`Log`]ger.
+///
+/// ```no_run
+/// use iceoryx2::prelude::*;
+/// use iceoryx2_log::info;
+/// use iceoryx2_services_logging::logger::Logger;
+///
+/// let _logger_guard = Logger::new()
+///     .node_name(&NodeName::new("my_app").unwrap())
+///     .install()
+///     .expect("no other logger is installed");
+///
+/// info!("published on the log service");
+/// ```
+pub struct Logger {
+    iceoryx_config: Option<IceoryxConfig>,
+    service_config: Config,
+    node_name: Option<NodeName>,
+    fallback: Option<&'static dyn Log>,
+    owner: AtomicUsize,
+    state: Mutex<State>,
+}
+
+impl Default for Logger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Logger {
+    /// Creates a new [`Logger`] that uses the global iceoryx2 config and the default
+    /// [`Config`] of the log service.
+    pub fn new() -> Self {
+        Self {
+            iceoryx_config: None,
+            service_config: Config::default(),
+            node_name: None,
+            fallback: None,
+            owner: AtomicUsize::new(NO_OWNER),
+            state: Mutex::new(State::disconnected()),
+        }
+    }
+
+    /// Defines the iceoryx2 config of the underlying node. If it is not set, the global
+    /// config is used.
+    pub fn config(mut self, value: &IceoryxConfig) -> Self {
+        self.iceoryx_config = Some(value.clone());
+        self
+    }
+
+    /// Defines the [`Config`] that is used when the logger creates the log service.
+    pub fn service_config(mut self, value: &Config) -> Self {
+        self.service_config = value.clone();
+        self
+    }
+
+    /// Defines the name of the underlying node.
+    pub fn node_name(mut self, value: &NodeName) -> Self {
+        self.node_name = Some(value.clone());
+        self
+    }
+
+    /// Defines the [`Log`]ger that receives all messages that cannot be published on the log
+    /// service.
+    pub fn fallback(mut self, value: &'static dyn Log) -> Self {
+        self.fallback = Some(value);
+        self
+    }
+
+    /// Installs the [`Logger`] with [`iceoryx2_log::set_logger()`]. Since the installed
+    /// [`Logger`] is never dropped, the returned [`LoggerGuard`] calls [`Logger::shutdown()`]
+    /// when it goes out of scope. Returns [`None`] when a [`Log`]ger is already installed.
+    pub fn install(self) -> Option<LoggerGuard> {
+        let logger: &'static Logger = Box::leak(Box::new(self));
+        match iceoryx2_log::set_logger(logger) {
+            true => Some(LoggerGuard { logger }),
+            false => None,
+        }
+    }
+
+    /// Releases the node and the publisher of the [`Logger`] so that they are removed before
+    /// the process exits. All messages that are logged afterwards are forwarded to the
+    /// fallback [`Log`]ger.
+    pub fn shutdown(&self) {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        // dropping the ports and the node logs messages that must not reenter the logger
+        self.owner.store(current_thread_marker(), Ordering::Relaxed);
+        *state = State::ShutDown;
+        self.owner.store(NO_OWNER, Ordering::Relaxed);
+    }
+
+    fn connect(&self) -> Option<State> {
+        let mut node_builder = NodeBuilder::new();
+        if let Some(name) = &self.node_name {
+            node_builder = node_builder.name(name);
+        }
+        let node = match &self.iceoryx_config {
+            Some(config) => node_builder.config(config).create::<S>(),
+            None => node_builder.create::<S>(),
+        }
+        .ok()?;
+
+        let service = open_or_create(&node, &self.service_config).ok()?;
+        let publisher = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardData)
+            .create()
+            .ok()?;
+
+        Some(State::Ready { node, publisher })
+    }
+
+    fn publish(
+        &self,
+        state: &mut State,
+        log_level: LogLevel,
+        origin: core::fmt::Arguments,
+        formatted_message: core::fmt::Arguments,
+    ) -> bool {
+        if let State::Disconnected {
+            last_attempt,
+            backoff,
+        } = state
+        {
+            let is_attempt_due = match last_attempt {
+                Some(last_attempt) => last_attempt
+                    .elapsed()
+                    .map_or(true, |elapsed| *backoff <= elapsed),
+                None => true,
+            };
+
+            if is_attempt_due {
+                *state = match self.connect() {
+                    Some(ready) => ready,
+                    None => State::Disconnected {
+                        backoff: match last_attempt {
+                            Some(_) => (*backoff * 2).min(MAX_RECONNECT_BACKOFF),
+                            None => *backoff,
+                        },
+                        last_attempt: Time::now().ok(),
+                    },
+                };
+            }
+        }
+
+        match state {
+            State::Ready { node, publisher } => {
+                let timestamp = Time::now_with_clock(ClockType::Realtime)
+                    .map(|now| now.as_duration())
+                    .unwrap_or_default();
+                let record = LogRecord::new(
+                    log_level.into(),
+                    *node.id(),
+                    timestamp,
+                    origin,
+                    formatted_message,
+                );
+                publisher.send_copy(record).is_ok()
+            }
+            State::Disconnected { .. } | State::ShutDown => false,
+        }
+    }
+}
+
+impl Log for Logger {
+    fn log(
+        &self,
+        log_level: LogLevel,
+        origin: core::fmt::Arguments,
+        formatted_message: core::fmt::Arguments,
+    ) {
+        let thread = current_thread_marker();
+
+        // iceoryx2 logs while the logger creates or uses its ports, those messages must not
+        // reenter the logger since it would dead lock
+        let is_published = if self.owner.load(Ordering::Relaxed) == thread {
+            false
+        } else {
+            let mut state = self
+                .state
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            self.owner.store(thread, Ordering::Relaxed);
+            let is_published = self.publish(&mut state, log_level, origin, formatted_message);
+            self.owner.store(NO_OWNER, Ordering::Relaxed);
+            is_published
+        };
+
+        if !is_published {
+            if let Some(fallback) = self.fallback {
+                fallback.log(log_level, origin, formatted_message);
+            }
+        }
+    }
+}
+
+/// Returned by [`Logger::install()`]. Calls [`Logger::shutdown()`] on the installed [`Logger`]
+/// when it goes out of scope.
+pub struct LoggerGuard {
+    logger: &'static Logger,
+}
+
+impl Drop for LoggerGuard {
+    fn drop(&mut self) {
+        self.logger.shutdown();
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::fmt::{Display, Write};
+use core::time::Duration;
+
+use iceoryx2::identifiers::UniqueNodeId;
+use iceoryx2::prelude::ZeroCopySend;
+use iceoryx2_bb_container::string::{StaticString, String};
+use iceoryx2_bb_posix::clock::RelocatableDuration;
+use iceoryx2_log::LogLevel;
+
+/// The maximum length of the origin of a [`LogRecord`]. Longer origins are truncated.
+pub const MAX_ORIGIN_LENGTH: usize = 128;
+
+/// The maximum length of the message of a [`LogRecord`]. Longer messages are truncated.
+pub const MAX_MESSAGE_LENGTH: usize = 1024;
+
+/// The origin of a [`LogRecord`], usually the type or module that emitted it.
+pub type OriginString = StaticString<MAX_ORIGIN_LENGTH>;
+
+/// The formatted message of a [`LogRecord`].
+pub type MessageString = StaticString<MAX_MESSAGE_LENGTH>;
+
+/// The severity of a [`LogRecord`]. It mirrors [`LogLevel`] so that it can be
+/// transmitted via shared memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ZeroCopySend)]
+#[repr(C)]
+pub enum Level {
+    /// See [`LogLevel::Trace`]
+    Trace = 0,
+    /// See [`LogLevel::Debug`]
+    Debug = 1,
+    /// See [`LogLevel::Info`]
+    Info = 2,
+    /// See [`LogLevel::Warn`]
+    Warn = 3,
+    /// See [`LogLevel::Error`]
+    Error = 4,
+    /// See [`LogLevel::Fatal`]
+    Fatal = 5,
+}
+
+impl From<LogLevel> for Level {
+    fn from(value: LogLevel) -> Self {
+        match value {
+            LogLevel::Trace => Level::Trace,
+            LogLevel::Debug => Level::Debug,
+            LogLevel::Info => Level::Info,
+            LogLevel::Warn => Level::Warn,
+            LogLevel::Error => Level::Error,
+            LogLevel::Fatal => Level::Fatal,
+        }
+    }
+}
+
+impl From<Level> for LogLevel {
+    fn from(value: Level) -> Self {
+        match value {
+            Level::Trace => LogLevel::Trace,
+            Level::Debug => LogLevel::Debug,
+            Level::Info => LogLevel::Info,
+            Level::Warn => LogLevel::Warn,
+            Level::Error => LogLevel::Error,
+            Level::Fatal => LogLevel::Fatal,
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let value = match self {
+            Level::Trace => "TRACE",
+            Level::Debug => "DEBUG",
+            Level::Info => "INFO",
+            Level::Warn => "WARN",
+            Level::Error => "ERROR",
+            Level::Fatal => "FATAL",
+        };
+        f.pad(value)
+    }
+}
+
+/// A structured log entry that is published on the log service by the
+/// [`Logger`](crate::logger::Logger).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ZeroCopySend)]
+#[repr(C)]
+pub struct LogRecord {
+    /// The severity of the entry.
+    pub level: Level,
+
+    /// The id of the node that emitted the entry.
+    pub node_id: UniqueNodeId,
+
+    /// The time since the UNIX epoch at which the entry was emitted.
+    pub timestamp: RelocatableDuration,
+
+    /// The origin of the entry. Non-ASCII characters are replaced with `?`.
+    pub origin: OriginString,
+
+    /// The message of the entry. Non-ASCII characters are replaced with `?`.
+    pub message: MessageString,
+}
+
+impl LogRecord {
+    /// Creates a new [`LogRecord`]. The `origin` and the `message` are truncated when they
+    /// exceed [`MAX_ORIGIN_LENGTH`] or [`MAX_MESSAGE_LENGTH`] respectively.
+    pub fn new(
+        level: Level,
+        node_id: UniqueNodeId,
+        timestamp: Duration,
+        origin: core::fmt::Arguments,
+        message: core::fmt::Arguments,
+    ) -> Self {
+        Self {
+            level,
+            node_id,
+            timestamp: timestamp.into(),
+            origin: to_static_string(origin),
+            message: to_static_string(message),
+        }
+    }
+}
+
+impl Display for LogRecord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let timestamp = Duration::from(self.timestamp);
+        write!(
+            f,
+            "{}.{:06} {:<5} [{}] ",
+            timestamp.as_secs(),
+            timestamp.subsec_micros(),
+            self.level,
+            self.node_id
+        )?;
+
+        if !self.origin.is_empty() {
+            write!(f, "{}: ", self.origin)?;
+        }
+
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Writes formatted text into a [`StaticString`], replaces every byte that the string does
+/// not accept with `?` and silently truncates everything that does not fit.
+struct TruncatingWriter<'a, const CAPACITY: usize> {
+    value: &'a mut StaticString<CAPACITY>,
+}
+
+impl<const CAPACITY: usize> Write for TruncatingWriter<'_, CAPACITY> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for c in s.chars() {
+            if self.value.is_full() {
+                break;
+            }
+
+            let byte = if c.is_ascii() && c != '\0' {
+                c as u8
+            } else {
+                b'?'
+            };
+            // cannot fail, the capacity was checked and the byte is valid ASCII
+            let _ = self.value.push(byte);
+        }
+
+        Ok(())
+    }
+}
+
+fn to_static_string<const CAPACITY: usize>(args: core::fmt::Arguments) -> StaticString<CAPACITY> {
+    let mut value = StaticString::new();
+    let _ = TruncatingWriter { value: &mut value }.write_fmt(args);
+    value
+}
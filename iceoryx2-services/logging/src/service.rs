@@ -0,0 +1,90 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::{
+    node::Node,
+    prelude::ServiceName,
+    service::{
+        Service as ServiceType, builder::publish_subscribe::PublishSubscribeOpenOrCreateError,
+        port_factory::publish_subscribe::PortFactory,
+    },
+};
+use iceoryx2_bb_concurrency::lazy_lock::LazyLock;
+
+use crate::record::LogRecord;
+
+const SERVICE_NAME: &str = "log";
+
+/// The payload type of the log service.
+pub type Payload = LogRecord;
+
+/// Configuration of the log service. All participants, the
+/// [`Logger`](crate::logger::Logger)s and the [`Collector`](crate::collector::Collector)s,
+/// must use compatible values since the first one creates the service.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The maximum number of processes that can publish log records at the same time.
+    pub max_publishers: usize,
+
+    /// The maximum number of collectors and tail sessions that can receive log records at the
+    /// same time.
+    pub max_subscribers: usize,
+
+    /// The maximum number of nodes that can open the log service at the same time.
+    pub max_nodes: usize,
+
+    /// The maximum number of log records a subscriber retains in its buffer. When the buffer
+    /// is full, the oldest log record is discarded.
+    pub subscriber_max_buffer_size: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_publishers: 64,
+            max_subscribers: 8,
+            max_nodes: 72,
+            subscriber_max_buffer_size: 256,
+        }
+    }
+}
+
+/// Returns the service name used by the log service.
+///
+/// # Panics
+///
+/// This function will panic during the first call if the service name is invalid,
+/// which should never happen with the predefined constants.
+pub fn service_name() -> &'static ServiceName {
+    static SERVICE_NAME_INSTANCE: LazyLock<ServiceName> = LazyLock::new(|| {
+        ServiceName::__internal_new_prefixed(SERVICE_NAME)
+            .expect("shouldn't occur: invalid service name for log service")
+    });
+
+    &SERVICE_NAME_INSTANCE
+}
+
+/// Opens the log service or creates it with the provided [`Config`] if it does not exist.
+pub fn open_or_create<S: ServiceType>(
+    node: &Node<S>,
+    config: &Config,
+) -> Result<PortFactory<S, Payload, ()>, PublishSubscribeOpenOrCreateError> {
+    node.service_builder(service_name())
+        .publish_subscribe::<Payload>()
+        .max_publishers(config.max_publishers)
+        .max_subscribers(config.max_subscribers)
+        .max_nodes(config.max_nodes)
+        .subscriber_max_buffer_size(config.subscriber_max_buffer_size)
+        .history_size(0)
+        .enable_safe_overflow(true)
+        .open_or_create()
+}
@@ -0,0 +1,277 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod logging_service {
+    use std::sync::Mutex;
+
+    use iceoryx2::node::NodeView;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_container::string::String as _;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_log::{Log, LogLevel};
+    use iceoryx2_services_logging::collector::{Collector, CollectorConfig};
+    use iceoryx2_services_logging::logger::Logger;
+    use iceoryx2_services_logging::record::{
+        Level, LogRecord, MAX_MESSAGE_LENGTH, MAX_ORIGIN_LENGTH,
+    };
+    use iceoryx2_services_logging::service::{Config, open_or_create, service_name};
+
+    type S = ipc::Service;
+
+    #[derive(Default)]
+    struct RecordingLogger {
+        messages: Mutex<Vec<String>>,
+    }
+
+    impl Log for RecordingLogger {
+        fn log(
+            &self,
+            _log_level: LogLevel,
+            _origin: core::fmt::Arguments,
+            formatted_message: core::fmt::Arguments,
+        ) {
+            self.messages
+                .lock()
+                .unwrap()
+                .push(formatted_message.to_string());
+        }
+    }
+
+    fn collector_config(directory: &std::path::Path) -> CollectorConfig {
+        CollectorConfig {
+            directory: directory.to_path_buf(),
+            file_prefix: "test".into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn record_replaces_non_ascii_characters() {
+        let node = NodeBuilder::new()
+            .config(&generate_isolated_config())
+            .create::<S>()
+            .unwrap();
+        let sut = LogRecord::new(
+            Level::Info,
+            *node.id(),
+            core::time::Duration::ZERO,
+            format_args!("Ünïcode"),
+            format_args!("a\0b ✓"),
+        );
+
+        assert_that!(sut.origin.as_str(), eq "?n?code");
+        assert_that!(sut.message.as_str(), eq "a?b ?");
+    }
+
+    #[test]
+    fn record_truncates_too_long_origin_and_message() {
+        let node = NodeBuilder::new()
+            .config(&generate_isolated_config())
+            .create::<S>()
+            .unwrap();
+        let long = "x".repeat(MAX_MESSAGE_LENGTH * 2);
+        let sut = LogRecord::new(
+            Level::Info,
+            *node.id(),
+            core::time::Duration::ZERO,
+            format_args!("{long}"),
+            format_args!("{long}"),
+        );
+
+        assert_that!(sut.origin.len(), eq MAX_ORIGIN_LENGTH);
+        assert_that!(sut.message.len(), eq MAX_MESSAGE_LENGTH);
+    }
+
+    #[test]
+    fn level_conversion_preserves_order() {
+        assert_that!(Level::from(LogLevel::Trace), eq Level::Trace);
+        assert_that!(Level::from(LogLevel::Fatal), eq Level::Fatal);
+        assert_that!(LogLevel::from(Level::Warn), eq LogLevel::Warn);
+        assert_that!(Level::Debug < Level::Error, eq true);
+    }
+
+    #[test]
+    fn logger_publishes_log_messages_on_log_service() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = open_or_create(&node, &Config::default()).unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let sut = Logger::new()
+            .config(&config)
+            .node_name(&NodeName::new("logging_app").unwrap());
+        sut.log(
+            LogLevel::Warn,
+            format_args!("MyOrigin"),
+            format_args!("hello {}", 42),
+        );
+
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        let record = *sample.unwrap().payload();
+        assert_that!(record.level, eq Level::Warn);
+        assert_that!(record.origin.as_str(), eq "MyOrigin");
+        assert_that!(record.message.as_str(), eq "hello 42");
+        assert_that!(record.node_id, ne * node.id());
+        assert_that!(record.timestamp.as_secs(), gt 0);
+    }
+
+    #[test]
+    fn logger_forwards_messages_to_fallback_when_log_service_is_inaccessible() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _incompatible_service = node
+            .service_builder(service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let fallback: &'static RecordingLogger = Box::leak(Box::default());
+        let sut = Logger::new().config(&config).fallback(fallback);
+        sut.log(LogLevel::Error, format_args!(""), format_args!("first"));
+        sut.log(LogLevel::Error, format_args!(""), format_args!("second"));
+
+        let messages = fallback.messages.lock().unwrap();
+        assert_that!(messages.contains(&"first".to_string()), eq true);
+        assert_that!(messages.contains(&"second".to_string()), eq true);
+    }
+
+    #[test]
+    fn logger_reconnects_when_log_service_becomes_accessible() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let incompatible_service = node
+            .service_builder(service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let fallback: &'static RecordingLogger = Box::leak(Box::default());
+        let sut = Logger::new().config(&config).fallback(fallback);
+        sut.log(LogLevel::Error, format_args!(""), format_args!("lost"));
+        assert_that!(fallback.messages.lock().unwrap().contains(&"lost".to_string()), eq true);
+
+        drop(incompatible_service);
+        let service = open_or_create(&node, &Config::default()).unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut sample = None;
+        for _ in 0..100 {
+            sut.log(LogLevel::Error, format_args!(""), format_args!("found"));
+            sample = subscriber.receive().unwrap();
+            if sample.is_some() {
+                break;
+            }
+            std::thread::sleep(core::time::Duration::from_millis(10));
+        }
+
+        assert_that!(sample, is_some);
+        let record = *sample.unwrap().payload();
+        assert_that!(record.message.as_str(), eq "found");
+    }
+
+    #[test]
+    fn logger_shutdown_removes_its_node() {
+        let config = generate_isolated_config();
+        let node_name = NodeName::new("logging_app").unwrap();
+        let number_of_logging_nodes = || {
+            let mut counter = 0;
+            Node::<ipc_threadsafe::Service>::list(&config, |node_state| {
+                if let NodeState::Alive(view) = node_state {
+                    if view.details().as_ref().map(|details| details.name()) == Some(&node_name) {
+                        counter += 1;
+                    }
+                }
+                CallbackProgression::Continue
+            })
+            .unwrap();
+            counter
+        };
+
+        let fallback: &'static RecordingLogger = Box::leak(Box::default());
+        let sut = Logger::new()
+            .config(&config)
+            .node_name(&node_name)
+            .fallback(fallback);
+        sut.log(LogLevel::Info, format_args!(""), format_args!("published"));
+        assert_that!(number_of_logging_nodes(), eq 1);
+
+        sut.shutdown();
+        assert_that!(number_of_logging_nodes(), eq 0);
+
+        sut.log(
+            LogLevel::Info,
+            format_args!(""),
+            format_args!("after shutdown"),
+        );
+        let messages = fallback.messages.lock().unwrap();
+        assert_that!(messages.contains(&"published".to_string()), eq false);
+        assert_that!(messages.contains(&"after shutdown".to_string()), eq true);
+    }
+
+    #[test]
+    fn collector_writes_records_with_sufficient_level_into_log_file() {
+        let config = generate_isolated_config();
+        let directory = tempfile::tempdir().unwrap();
+        let collector_config = CollectorConfig {
+            min_level: Level::Info,
+            ..collector_config(directory.path())
+        };
+        let mut sut = Collector::<S>::create(&collector_config, &config).unwrap();
+
+        let logger = Logger::new().config(&config);
+        logger.log(LogLevel::Debug, format_args!(""), format_args!("debug"));
+        logger.log(
+            LogLevel::Error,
+            format_args!("Origin"),
+            format_args!("error"),
+        );
+
+        let mut written = Vec::new();
+        sut.spin(|record| written.push(*record)).unwrap();
+
+        assert_that!(written, len 1);
+        assert_that!(written[0].level, eq Level::Error);
+
+        let content = std::fs::read_to_string(sut.log_file()).unwrap();
+        assert_that!(content.lines().count(), eq 1);
+        assert_that!(content.contains("ERROR"), eq true);
+        assert_that!(content.contains("Origin: error"), eq true);
+        assert_that!(content.contains("debug"), eq false);
+    }
+
+    #[test]
+    fn collector_rotates_log_files() {
+        const MAX_FILES: usize = 3;
+        let config = generate_isolated_config();
+        let directory = tempfile::tempdir().unwrap();
+        let collector_config = CollectorConfig {
+            max_file_size: 1,
+            max_files: MAX_FILES,
+            ..collector_config(directory.path())
+        };
+        let mut sut = Collector::<S>::create(&collector_config, &config).unwrap();
+
+        let logger = Logger::new().config(&config);
+        for n in 0..5 {
+            logger.log(LogLevel::Info, format_args!(""), format_args!("msg{n}"));
+        }
+        sut.spin(|_| {}).unwrap();
+
+        let read = |name: &str| std::fs::read_to_string(directory.path().join(name)).unwrap();
+        assert_that!(read("test.log").contains("msg4"), eq true);
+        assert_that!(read("test.log.1").contains("msg3"), eq true);
+        assert_that!(read("test.log.2").contains("msg2"), eq true);
+        assert_that!(directory.path().join("test.log.3").exists(), eq false);
+    }
+}
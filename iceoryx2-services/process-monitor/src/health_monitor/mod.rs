@@ -0,0 +1,56 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Health Monitor
+//!
+//! This module provides a service that watches the heartbeats of all nodes that enabled them
+//! via `NodeBuilder::heartbeat_interval()`. It detects nodes whose process is still alive but
+//! that missed their heartbeat, for instance due to a deadlock, and publishes the health of
+//! every node whenever it changes.
+//!
+//! ## Usage
+//!
+//! Create a `Service` instance with the appropriate configuration and call its `spin`
+//! method periodically. Every call publishes a [`NodeHealth`] event for each node whose
+//! [`HealthState`] changed since the last call.
+//!
+//! ```no_run
+//! use iceoryx2_services_process_monitor::health_monitor::Service;
+//! use iceoryx2_services_process_monitor::health_monitor::Config as HealthMonitorConfig;
+//! use iceoryx2_services_process_monitor::health_monitor::{HealthState, NodeHealth};
+//! use iceoryx2::prelude::*;
+//!
+//! fn main() -> Result<(), Box<dyn core::error::Error>> {
+//!
+//!     // Create a health monitor service
+//!     let config = HealthMonitorConfig::default();
+//!     let mut service = Service::<ipc::Service>::create(&config, &Config::global_config()).expect("Failed to create service");
+//!
+//!     let on_change = |health: &NodeHealth| {
+//!         if health.state == HealthState::Unresponsive {
+//!             // ...restart the hanging process
+//!         }
+//!     };
+//!
+//!     // Periodically check the heartbeats of all nodes
+//!     loop {
+//!         service.spin(on_change)?;
+//!         // Sleep or do other work...
+//!     }
+//!
+//!     Ok(())
+//! }
+
+/// A health monitor service that publishes changes in the health of nodes.
+mod service;
+
+pub use service::*;
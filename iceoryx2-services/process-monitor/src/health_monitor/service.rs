@@ -0,0 +1,435 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use iceoryx2::constants::MAX_NODE_NAME_LENGTH;
+use iceoryx2::node::{
+    AliveNodeView, NodeHeartbeatReader, NodeListFailure, NodeState, NodeView, NodeWatcher,
+};
+use iceoryx2::prelude::{CallbackProgression, EventId, ZeroCopySend};
+use iceoryx2::{
+    config::Config as IceoryxConfig,
+    identifiers::UniqueNodeId,
+    node::{Node, NodeBuilder, NodeCreationFailure},
+    port::{
+        LoanError, SendError,
+        notifier::{Notifier, NotifierCreateError, NotifierNotifyError},
+        publisher::{Publisher, PublisherCreateError},
+    },
+    prelude::ServiceName,
+    service::{
+        Service as ServiceType,
+        builder::{
+            event::EventOpenOrCreateError, publish_subscribe::PublishSubscribeOpenOrCreateError,
+        },
+    },
+};
+use iceoryx2_bb_concurrency::lazy_lock::LazyLock;
+use iceoryx2_bb_container::string::StaticString;
+use iceoryx2_bb_posix::clock::RelocatableDuration;
+
+const SERVICE_NAME: &str = "monitor/node_health/";
+
+/// The name of a node as it is transmitted in the [`NodeHealth`] event.
+pub type NodeNameString = StaticString<MAX_NODE_NAME_LENGTH>;
+
+/// The health of a node that has enabled heartbeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ZeroCopySend)]
+#[repr(C)]
+pub enum HealthState {
+    /// The node sends its heartbeats within its heartbeat interval.
+    Responsive,
+
+    /// The node missed its heartbeat. Its process is alive but hangs, for instance due to a
+    /// deadlock.
+    Unresponsive,
+}
+
+impl From<HealthState> for EventId {
+    fn from(value: HealthState) -> Self {
+        EventId::new(value as usize)
+    }
+}
+
+/// Event emitted by the health monitor service whenever the [`HealthState`] of a node changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ZeroCopySend)]
+#[repr(C)]
+pub struct NodeHealth {
+    /// The id of the node.
+    pub node_id: UniqueNodeId,
+
+    /// The name of the node. It is empty when the node has no name or when the details of the
+    /// node were not accessible.
+    pub node_name: NodeNameString,
+
+    /// The new health state of the node.
+    pub state: HealthState,
+
+    /// The interval in which the node promised to send a heartbeat.
+    pub heartbeat_interval: RelocatableDuration,
+
+    /// The time that has passed since the last heartbeat of the node.
+    pub time_since_last_heartbeat: RelocatableDuration,
+}
+
+/// The payload type used for publishing health changes
+pub type Payload = NodeHealth;
+
+/// Errors that can occur when creating the health monitor service.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    /// Failed to create the underlying node.
+    NodeCreationFailure,
+
+    /// Failed to create the service.
+    ServiceCreationFailure,
+
+    /// Failed to create the publisher for reasons other than it already existing.
+    PublisherCreationError,
+
+    /// A publisher to the service already exists, another health monitor is running.
+    PublisherAlreadyExists,
+
+    /// A notifier to the service already exists, another health monitor is running.
+    NotifierAlreadyExists,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+impl From<NodeCreationFailure> for CreationError {
+    fn from(_: NodeCreationFailure) -> Self {
+        CreationError::NodeCreationFailure
+    }
+}
+
+impl From<PublishSubscribeOpenOrCreateError> for CreationError {
+    fn from(_: PublishSubscribeOpenOrCreateError) -> Self {
+        CreationError::ServiceCreationFailure
+    }
+}
+
+impl From<PublisherCreateError> for CreationError {
+    fn from(error: PublisherCreateError) -> Self {
+        match error {
+            PublisherCreateError::ExceedsMaxSupportedPublishers => {
+                CreationError::PublisherAlreadyExists
+            }
+            PublisherCreateError::UnableToCreateDataSegment
+            | PublisherCreateError::FailedToDeployThreadsafetyPolicy
+            | PublisherCreateError::InsufficientResources
+            | PublisherCreateError::UnableToCreateWakeupEvent => {
+                CreationError::PublisherCreationError
+            }
+        }
+    }
+}
+
+impl From<EventOpenOrCreateError> for CreationError {
+    fn from(_: EventOpenOrCreateError) -> Self {
+        CreationError::ServiceCreationFailure
+    }
+}
+
+impl From<NotifierCreateError> for CreationError {
+    fn from(_: NotifierCreateError) -> Self {
+        CreationError::NotifierAlreadyExists
+    }
+}
+
+/// Errors that can occur during the spin operation of the health monitor service.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SpinError {
+    /// The caller does not have sufficient permissions to list the nodes.
+    InsufficientPermissions,
+
+    /// The process received an interrupt signal while listing the nodes.
+    Interrupt,
+
+    /// Failed to list the nodes of the iceoryx2 system.
+    NodeListFailure,
+
+    /// Failed to publish a health event.
+    PublishFailure,
+
+    /// Failed to send a notification about a health change.
+    NotifyFailure,
+}
+
+impl core::fmt::Display for SpinError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SpinError::{self:?}")
+    }
+}
+
+impl core::error::Error for SpinError {}
+
+impl From<NodeListFailure> for SpinError {
+    fn from(error: NodeListFailure) -> Self {
+        match error {
+            NodeListFailure::InsufficientPermissions => SpinError::InsufficientPermissions,
+            NodeListFailure::Interrupt => SpinError::Interrupt,
+            NodeListFailure::InternalError => SpinError::NodeListFailure,
+        }
+    }
+}
+
+impl From<LoanError> for SpinError {
+    fn from(_: LoanError) -> Self {
+        SpinError::PublishFailure
+    }
+}
+
+impl From<SendError> for SpinError {
+    fn from(_: SendError) -> Self {
+        SpinError::PublishFailure
+    }
+}
+
+impl From<NotifierNotifyError> for SpinError {
+    fn from(_: NotifierNotifyError) -> Self {
+        SpinError::NotifyFailure
+    }
+}
+
+/// Configuration for the health monitor service.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Whether to publish health events.
+    pub publish_events: bool,
+
+    /// The maximum number of subscribers to the service permitted.
+    pub max_subscribers: usize,
+
+    /// The maximum number of samples the subscriber retains in its buffer.
+    pub max_buffer_size: usize,
+
+    /// The number of older samples the subscriber can request from the service when starting.
+    pub history_size: usize,
+
+    /// Whether to send notifications when the health of a node changed. The
+    /// [`EventId`] of the notification corresponds to the new [`HealthState`].
+    pub send_notifications: bool,
+
+    /// The maximum number of listeners to the service permitted.
+    pub max_listeners: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        let defaults = iceoryx2::config::Config::default().defaults;
+        Self {
+            publish_events: true,
+            max_subscribers: defaults.publish_subscribe.max_subscribers,
+            max_buffer_size: defaults.publish_subscribe.subscriber_max_buffer_size,
+            history_size: defaults.publish_subscribe.publisher_history_size,
+            send_notifications: true,
+            max_listeners: defaults.event.max_listeners,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct MonitoredNode<S: ServiceType> {
+    view: AliveNodeView<S>,
+    heartbeat: Option<NodeHeartbeatReader<S>>,
+    state: Option<HealthState>,
+}
+
+/// The health monitor service.
+///
+/// This service is responsible for watching the heartbeats of all nodes that enabled them
+/// and informing interested parties whenever a node becomes unresponsive or recovers.
+///
+/// # Type Parameters
+///
+/// * `S` - The service type that this health monitor operates on.
+#[derive(Debug)]
+pub struct Service<S: ServiceType> {
+    watcher: NodeWatcher<S>,
+    _node: Node<S>,
+    publisher: Option<Publisher<S, Payload, ()>>,
+    notifier: Option<Notifier<S>>,
+    monitored_nodes: BTreeMap<UniqueNodeId, MonitoredNode<S>>,
+}
+
+impl<S: ServiceType> Service<S> {
+    /// Creates the health monitor service.
+    ///
+    /// # Parameters
+    ///
+    /// * `monitor_config` - Configuration for the health monitor service.
+    /// * `iceoryx_config` - Configuration for the underlying iceoryx system.
+    ///
+    /// # Returns
+    ///
+    /// A result containing either the created service or an error if creation failed.
+    pub fn create(
+        monitor_config: &Config,
+        iceoryx_config: &IceoryxConfig,
+    ) -> Result<Self, CreationError> {
+        let node = NodeBuilder::new().config(iceoryx_config).create::<S>()?;
+
+        let mut publisher = None;
+        if monitor_config.publish_events {
+            let publish_subscribe = node
+                .service_builder(service_name())
+                .publish_subscribe::<Payload>()
+                .subscriber_max_buffer_size(monitor_config.max_buffer_size)
+                .history_size(monitor_config.history_size)
+                .max_subscribers(monitor_config.max_subscribers)
+                .max_publishers(1)
+                .open_or_create()?;
+
+            publisher = Some(publish_subscribe.publisher_builder().create()?);
+        }
+
+        let mut notifier = None;
+        if monitor_config.send_notifications {
+            let event = node
+                .service_builder(service_name())
+                .event()
+                .max_listeners(monitor_config.max_listeners)
+                .max_notifiers(1)
+                .open_or_create()?;
+
+            notifier = Some(event.notifier_builder().create()?);
+        }
+
+        Ok(Service::<S> {
+            watcher: NodeWatcher::new(iceoryx_config),
+            _node: node,
+            publisher,
+            notifier,
+            monitored_nodes: BTreeMap::new(),
+        })
+    }
+
+    /// Checks the heartbeats of all nodes and emits events/notifications for every node
+    /// whose [`HealthState`] changed.
+    ///
+    /// This function should be called periodically, at least as often as the shortest
+    /// heartbeat interval of all monitored nodes. A node is reported when it is detected for
+    /// the first time, when it misses its heartbeat and when it recovers.
+    ///
+    /// # Parameters
+    ///
+    /// * `on_change` - Callback function that is called for each node whose health changed
+    ///
+    /// # Returns
+    ///
+    /// A result containing `()` if successful.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SpinError` if the nodes could not be listed or if there was an error
+    /// publishing events or sending notifications.
+    pub fn spin<F: FnMut(&NodeHealth)>(&mut self, mut on_change: F) -> Result<(), SpinError> {
+        let mut alive_nodes = BTreeSet::new();
+        let monitored_nodes = &mut self.monitored_nodes;
+        self.watcher.update(|node_state| {
+            if let NodeState::Alive(view) = node_state {
+                alive_nodes.insert(*view.id());
+                // the heartbeat is created before the node becomes alive, therefore it is opened
+                // only once
+                monitored_nodes
+                    .entry(*view.id())
+                    .or_insert_with(|| MonitoredNode {
+                        heartbeat: view.heartbeat_reader(),
+                        view,
+                        state: None,
+                    });
+            }
+            CallbackProgression::Continue
+        })?;
+
+        // nodes that are no longer alive have either shut down or died
+        self.monitored_nodes
+            .retain(|id, _| alive_nodes.contains(id));
+
+        for (node_id, monitored_node) in self.monitored_nodes.iter_mut() {
+            let heartbeat = match monitored_node
+                .heartbeat
+                .as_ref()
+                .and_then(|heartbeat| heartbeat.last_heartbeat())
+            {
+                Some(heartbeat) => heartbeat,
+                None => continue,
+            };
+
+            let state = if heartbeat.has_missed_heartbeat() {
+                HealthState::Unresponsive
+            } else {
+                HealthState::Responsive
+            };
+
+            if monitored_node.state.replace(state) == Some(state) {
+                continue;
+            }
+
+            let node_name = monitored_node
+                .view
+                .details()
+                .as_ref()
+                .and_then(|details| NodeNameString::try_from(details.name().as_str()).ok())
+                .unwrap_or_default();
+
+            let event = NodeHealth {
+                node_id: *node_id,
+                node_name,
+                state,
+                heartbeat_interval: heartbeat.interval().into(),
+                time_since_last_heartbeat: heartbeat.time_since_last_heartbeat().into(),
+            };
+
+            if let Some(publisher) = &self.publisher {
+                publisher.send_copy(event)?;
+            }
+            if let Some(notifier) = &self.notifier {
+                notifier.notify_with_custom_event_id(state.into())?;
+            }
+            on_change(&event);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the last known [`HealthState`] of the node with the provided
+    /// [`UniqueNodeId`]. If the node has not enabled heartbeats or was not yet detected by
+    /// [`Service::spin()`] it returns [`None`].
+    pub fn health_of(&self, node_id: &UniqueNodeId) -> Option<HealthState> {
+        self.monitored_nodes
+            .get(node_id)
+            .and_then(|monitored_node| monitored_node.state)
+    }
+}
+
+/// Returns the service name used by the health monitor service.
+///
+/// # Panics
+///
+/// This function will panic during the first call if the service name is invalid,
+/// which should never happen with the predefined constants.
+pub fn service_name() -> &'static ServiceName {
+    static SERVICE_NAME_INSTANCE: LazyLock<ServiceName> = LazyLock::new(|| {
+        ServiceName::__internal_new_prefixed(SERVICE_NAME)
+            .expect("shouldn't occur: invalid service name for health monitor service")
+    });
+
+    &SERVICE_NAME_INSTANCE
+}
@@ -0,0 +1,31 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Process Monitor Service
+//!
+//! The `iceoryx2-services-process-monitor` crate provides a service that watches the
+//! monitoring tokens of all nodes of an iceoryx2 system. As soon as the process owning a
+//! node dies, the stale resources of the node are removed and all interested parties are
+//! informed about the dead node. Additionally, it provides a service that detects nodes that
+//! are alive but hang since they missed their heartbeat.
+//!
+
+#![no_std]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+/// Detection of unresponsive nodes in an iceoryx2 system
+pub mod health_monitor;
+
+/// Detection and cleanup of dead nodes in an iceoryx2 system
+pub mod process_monitor;
@@ -0,0 +1,52 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Process Monitor
+//!
+//! This module provides a service that detects dead nodes, removes their stale resources
+//! and emits a "node died" event on a well-known service.
+//!
+//! ## Usage
+//!
+//! Create a `Service` instance with the appropriate configuration and call its `spin`
+//! method periodically. Every call detects the nodes whose process died since the last
+//! call, cleans up their resources and publishes a [`DeadNode`] event for each of them.
+//!
+//! ```no_run
+//! use iceoryx2_services_process_monitor::process_monitor::Service;
+//! use iceoryx2_services_process_monitor::process_monitor::Config as MonitorConfig;
+//! use iceoryx2_services_process_monitor::process_monitor::DeadNode;
+//! use iceoryx2::prelude::*;
+//!
+//! fn main() -> Result<(), Box<dyn core::error::Error>> {
+//!
+//!     // Create a process monitor service
+//!     let config = MonitorConfig::default();
+//!     let mut service = Service::<ipc::Service>::create(&config, &Config::global_config()).expect("Failed to create service");
+//!
+//!     let on_dead_node = |node: &DeadNode| {
+//!         // ...process dead nodes
+//!     };
+//!
+//!     // Periodically detect and cleanup dead nodes
+//!     loop {
+//!         service.spin(on_dead_node)?;
+//!         // Sleep or do other work...
+//!     }
+//!
+//!     Ok(())
+//! }
+
+/// A process monitor service that cleans up dead nodes and publishes information about them.
+mod service;
+
+pub use service::*;
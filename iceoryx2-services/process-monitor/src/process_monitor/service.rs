@@ -0,0 +1,400 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use iceoryx2::constants::MAX_NODE_NAME_LENGTH;
+use iceoryx2::node::{CleanupState, NodeListFailure, NodeState, NodeView, NodeWatcher};
+use iceoryx2::prelude::{CallbackProgression, ZeroCopySend};
+use iceoryx2::{
+    config::Config as IceoryxConfig,
+    identifiers::UniqueNodeId,
+    node::{Node, NodeBuilder, NodeCreationFailure},
+    port::{
+        LoanError, SendError,
+        notifier::{Notifier, NotifierCreateError, NotifierNotifyError},
+        publisher::{Publisher, PublisherCreateError},
+    },
+    prelude::ServiceName,
+    service::{
+        Service as ServiceType,
+        builder::{
+            event::EventOpenOrCreateError, publish_subscribe::PublishSubscribeOpenOrCreateError,
+        },
+    },
+};
+use iceoryx2_bb_concurrency::lazy_lock::LazyLock;
+use iceoryx2_bb_container::string::StaticString;
+
+const SERVICE_NAME: &str = "monitor/dead_nodes/";
+
+/// The name of a dead node as it is transmitted in the [`DeadNode`] event.
+pub type DeadNodeName = StaticString<MAX_NODE_NAME_LENGTH>;
+
+/// Event emitted by the process monitor service whenever the process of a node died.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ZeroCopySend)]
+#[repr(C)]
+pub struct DeadNode {
+    /// The id of the dead node.
+    pub node_id: UniqueNodeId,
+
+    /// The name of the dead node. It is empty when the node had no name or when the details
+    /// of the node were not accessible.
+    pub node_name: DeadNodeName,
+
+    /// Whether the stale resources of the dead node were successfully removed. If the
+    /// process monitor lacks the permissions to remove them, it is `false`.
+    pub is_cleaned_up: bool,
+}
+
+/// The payload type used for publishing dead nodes
+pub type Payload = DeadNode;
+
+/// Errors that can occur when creating the process monitor service.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    /// Failed to create the underlying node.
+    NodeCreationFailure,
+
+    /// Failed to create the service.
+    ServiceCreationFailure,
+
+    /// Failed to create the publisher for reasons other than it already existing.
+    PublisherCreationError,
+
+    /// A publisher to the service already exists, another process monitor is running.
+    PublisherAlreadyExists,
+
+    /// A notifier to the service already exists, another process monitor is running.
+    NotifierAlreadyExists,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+impl From<NodeCreationFailure> for CreationError {
+    fn from(_: NodeCreationFailure) -> Self {
+        CreationError::NodeCreationFailure
+    }
+}
+
+impl From<PublishSubscribeOpenOrCreateError> for CreationError {
+    fn from(_: PublishSubscribeOpenOrCreateError) -> Self {
+        CreationError::ServiceCreationFailure
+    }
+}
+
+impl From<PublisherCreateError> for CreationError {
+    fn from(error: PublisherCreateError) -> Self {
+        match error {
+            PublisherCreateError::ExceedsMaxSupportedPublishers => {
+                CreationError::PublisherAlreadyExists
+            }
+            PublisherCreateError::UnableToCreateDataSegment
+            | PublisherCreateError::FailedToDeployThreadsafetyPolicy
+            | PublisherCreateError::InsufficientResources
+            | PublisherCreateError::UnableToCreateWakeupEvent => {
+                CreationError::PublisherCreationError
+            }
+        }
+    }
+}
+
+impl From<EventOpenOrCreateError> for CreationError {
+    fn from(_: EventOpenOrCreateError) -> Self {
+        CreationError::ServiceCreationFailure
+    }
+}
+
+impl From<NotifierCreateError> for CreationError {
+    fn from(_: NotifierCreateError) -> Self {
+        CreationError::NotifierAlreadyExists
+    }
+}
+
+/// Errors that can occur during the spin operation of the process monitor service.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SpinError {
+    /// The caller does not have sufficient permissions to list the nodes.
+    InsufficientPermissions,
+
+    /// The process received an interrupt signal while listing the nodes.
+    Interrupt,
+
+    /// Failed to list the nodes of the iceoryx2 system.
+    NodeListFailure,
+
+    /// Failed to publish a dead node event.
+    PublishFailure,
+
+    /// Failed to send a notification about dead nodes.
+    NotifyFailure,
+}
+
+impl core::fmt::Display for SpinError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SpinError::{self:?}")
+    }
+}
+
+impl core::error::Error for SpinError {}
+
+impl From<NodeListFailure> for SpinError {
+    fn from(error: NodeListFailure) -> Self {
+        match error {
+            NodeListFailure::InsufficientPermissions => SpinError::InsufficientPermissions,
+            NodeListFailure::Interrupt => SpinError::Interrupt,
+            NodeListFailure::InternalError => SpinError::NodeListFailure,
+        }
+    }
+}
+
+impl From<LoanError> for SpinError {
+    fn from(_: LoanError) -> Self {
+        SpinError::PublishFailure
+    }
+}
+
+impl From<SendError> for SpinError {
+    fn from(_: SendError) -> Self {
+        SpinError::PublishFailure
+    }
+}
+
+impl From<NotifierNotifyError> for SpinError {
+    fn from(_: NotifierNotifyError) -> Self {
+        SpinError::NotifyFailure
+    }
+}
+
+/// Configuration for the process monitor service.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Whether to publish dead node events.
+    pub publish_events: bool,
+
+    /// The maximum number of subscribers to the service permitted.
+    pub max_subscribers: usize,
+
+    /// The maximum number of samples the subscriber retains in its buffer.
+    pub max_buffer_size: usize,
+
+    /// The number of older samples the subscriber can request from the service when starting.
+    pub history_size: usize,
+
+    /// Whether to send notifications when dead nodes were detected.
+    pub send_notifications: bool,
+
+    /// The maximum number of listeners to the service permitted.
+    pub max_listeners: usize,
+
+    /// The maximum time the service waits for another instance that is currently cleaning
+    /// up the same dead node.
+    pub cleanup_timeout: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        let defaults = iceoryx2::config::Config::default().defaults;
+        Self {
+            publish_events: true,
+            max_subscribers: defaults.publish_subscribe.max_subscribers,
+            max_buffer_size: defaults.publish_subscribe.subscriber_max_buffer_size,
+            history_size: defaults.publish_subscribe.publisher_history_size,
+            send_notifications: true,
+            max_listeners: defaults.event.max_listeners,
+            cleanup_timeout: Duration::from_secs(1),
+        }
+    }
+}
+
+/// The process monitor service.
+///
+/// This service is responsible for detecting nodes whose process died, removing their
+/// stale resources and informing interested parties about them.
+///
+/// # Type Parameters
+///
+/// * `S` - The service type that this process monitor operates on.
+#[derive(Debug)]
+pub struct Service<S: ServiceType> {
+    monitor_config: Config,
+    watcher: NodeWatcher<S>,
+    _node: Node<S>,
+    publisher: Option<Publisher<S, Payload, ()>>,
+    notifier: Option<Notifier<S>>,
+    reported_nodes: BTreeSet<UniqueNodeId>,
+}
+
+impl<S: ServiceType> Service<S> {
+    /// Creates the process monitor service.
+    ///
+    /// # Parameters
+    ///
+    /// * `monitor_config` - Configuration for the process monitor service.
+    /// * `iceoryx_config` - Configuration for the underlying iceoryx system.
+    ///
+    /// # Returns
+    ///
+    /// A result containing either the created service or an error if creation failed.
+    pub fn create(
+        monitor_config: &Config,
+        iceoryx_config: &IceoryxConfig,
+    ) -> Result<Self, CreationError> {
+        let node = NodeBuilder::new().config(iceoryx_config).create::<S>()?;
+
+        let mut publisher = None;
+        if monitor_config.publish_events {
+            let publish_subscribe = node
+                .service_builder(service_name())
+                .publish_subscribe::<Payload>()
+                .subscriber_max_buffer_size(monitor_config.max_buffer_size)
+                .history_size(monitor_config.history_size)
+                .max_subscribers(monitor_config.max_subscribers)
+                .max_publishers(1)
+                .open_or_create()?;
+
+            publisher = Some(publish_subscribe.publisher_builder().create()?);
+        }
+
+        let mut notifier = None;
+        if monitor_config.send_notifications {
+            let event = node
+                .service_builder(service_name())
+                .event()
+                .max_listeners(monitor_config.max_listeners)
+                .max_notifiers(1)
+                .open_or_create()?;
+
+            notifier = Some(event.notifier_builder().create()?);
+        }
+
+        Ok(Service::<S> {
+            monitor_config: monitor_config.clone(),
+            watcher: NodeWatcher::new(iceoryx_config),
+            _node: node,
+            publisher,
+            notifier,
+            reported_nodes: BTreeSet::new(),
+        })
+    }
+
+    /// Detects dead nodes, removes their stale resources and emits events/notifications.
+    ///
+    /// This function should be called periodically. Every dead node is reported exactly
+    /// once via the provided callback and a published [`DeadNode`] event. When the stale
+    /// resources of a dead node could not be removed, the cleanup is retried in every
+    /// subsequent call and the node is reported again as soon as the cleanup succeeded.
+    ///
+    /// # Parameters
+    ///
+    /// * `on_dead_node` - Callback function that is called for each reported dead node
+    ///
+    /// # Returns
+    ///
+    /// A result containing the [`CleanupState`] of this call if successful.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SpinError` if the nodes could not be listed or if there was an error
+    /// publishing events or sending notifications.
+    pub fn spin<F: FnMut(&DeadNode)>(
+        &mut self,
+        mut on_dead_node: F,
+    ) -> Result<CleanupState, SpinError> {
+        let mut dead_nodes = Vec::new();
+        self.watcher.update(|node_state| {
+            if let NodeState::Dead(view) = node_state {
+                dead_nodes.push(view);
+            }
+            CallbackProgression::Continue
+        })?;
+
+        // nodes that are no longer listed were removed by someone else in the meantime
+        self.reported_nodes
+            .retain(|id| dead_nodes.iter().any(|view| view.id() == id));
+
+        let mut cleanup_state = CleanupState {
+            cleanups: 0,
+            failed_cleanups: 0,
+        };
+        let mut has_reported_nodes = false;
+
+        for view in dead_nodes {
+            let node_id = *view.id();
+            let node_name = view
+                .details()
+                .as_ref()
+                .and_then(|details| DeadNodeName::try_from(details.name().as_str()).ok())
+                .unwrap_or_default();
+
+            let is_cleaned_up = view
+                .blocking_remove_stale_resources(self.monitor_config.cleanup_timeout)
+                .is_ok();
+
+            let is_new = if is_cleaned_up {
+                cleanup_state.cleanups += 1;
+                self.reported_nodes.remove(&node_id);
+                true
+            } else {
+                cleanup_state.failed_cleanups += 1;
+                self.reported_nodes.insert(node_id)
+            };
+
+            if !is_new {
+                continue;
+            }
+
+            let event = DeadNode {
+                node_id,
+                node_name,
+                is_cleaned_up,
+            };
+
+            if let Some(publisher) = &self.publisher {
+                publisher.send_copy(event)?;
+            }
+            on_dead_node(&event);
+            has_reported_nodes = true;
+        }
+
+        if let Some(notifier) = &self.notifier {
+            if has_reported_nodes {
+                notifier.notify()?;
+            }
+        }
+
+        Ok(cleanup_state)
+    }
+}
+
+/// Returns the service name used by the process monitor service.
+///
+/// # Panics
+///
+/// This function will panic during the first call if the service name is invalid,
+/// which should never happen with the predefined constants.
+pub fn service_name() -> &'static ServiceName {
+    static SERVICE_NAME_INSTANCE: LazyLock<ServiceName> = LazyLock::new(|| {
+        ServiceName::__internal_new_prefixed(SERVICE_NAME)
+            .expect("shouldn't occur: invalid service name for process monitor service")
+    });
+
+    &SERVICE_NAME_INSTANCE
+}
@@ -0,0 +1,212 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+extern crate alloc;
+
+mod health_monitor_service {
+    use alloc::vec::Vec;
+    use core::time::Duration;
+
+    use iceoryx2::config::Config as IceoryxConfig;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::clock::nanosleep;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_services_process_monitor::health_monitor::{
+        Config, CreationError, HealthState, NodeHealth, NodeNameString, Payload, Service,
+        service_name,
+    };
+
+    type S = ipc::Service;
+
+    const HEARTBEAT_INTERVAL: Duration = Duration::from_millis(50);
+
+    fn create_node_with_heartbeat(config: &IceoryxConfig, name: &str) -> Node<S> {
+        NodeBuilder::new()
+            .name(&NodeName::new(name).unwrap())
+            .heartbeat_interval(HEARTBEAT_INTERVAL)
+            .config(config)
+            .create::<S>()
+            .unwrap()
+    }
+
+    fn miss_heartbeat() {
+        nanosleep(HEARTBEAT_INTERVAL * 2).unwrap();
+    }
+
+    #[test]
+    fn responsive_node_is_reported_on_discovery() {
+        let iceoryx_config = generate_isolated_config();
+        let mut sut = Service::<S>::create(&Config::default(), &iceoryx_config).unwrap();
+        let node = create_node_with_heartbeat(&iceoryx_config, "worker");
+
+        let mut reported = Vec::new();
+        sut.spin(|health| reported.push(*health)).unwrap();
+
+        assert_that!(reported, len 1);
+        assert_that!(reported[0].node_id, eq * node.id());
+        assert_that!(reported[0].node_name, eq NodeNameString::try_from("worker").unwrap());
+        assert_that!(reported[0].state, eq HealthState::Responsive);
+        assert_that!(Duration::from(reported[0].heartbeat_interval), eq HEARTBEAT_INTERVAL);
+        assert_that!(sut.health_of(node.id()), eq Some(HealthState::Responsive));
+    }
+
+    #[test]
+    fn nodes_without_heartbeat_are_not_monitored() {
+        let iceoryx_config = generate_isolated_config();
+        let mut sut = Service::<S>::create(&Config::default(), &iceoryx_config).unwrap();
+        let node = NodeBuilder::new()
+            .config(&iceoryx_config)
+            .create::<S>()
+            .unwrap();
+
+        let mut counter = 0;
+        sut.spin(|_| counter += 1).unwrap();
+
+        assert_that!(counter, eq 0);
+        assert_that!(sut.health_of(node.id()), is_none);
+    }
+
+    #[test]
+    fn unchanged_health_is_reported_only_once() {
+        let iceoryx_config = generate_isolated_config();
+        let mut sut = Service::<S>::create(&Config::default(), &iceoryx_config).unwrap();
+        let _node = create_node_with_heartbeat(&iceoryx_config, "worker");
+
+        let mut counter = 0;
+        sut.spin(|_| counter += 1).unwrap();
+        sut.spin(|_| counter += 1).unwrap();
+
+        assert_that!(counter, eq 1);
+    }
+
+    #[test]
+    fn missed_heartbeat_is_reported_as_unresponsive() {
+        let iceoryx_config = generate_isolated_config();
+        let mut sut = Service::<S>::create(&Config::default(), &iceoryx_config).unwrap();
+        let node = create_node_with_heartbeat(&iceoryx_config, "worker");
+        sut.spin(|_| {}).unwrap();
+
+        miss_heartbeat();
+        let mut reported = Vec::new();
+        sut.spin(|health| reported.push(*health)).unwrap();
+
+        assert_that!(reported, len 1);
+        assert_that!(reported[0].state, eq HealthState::Unresponsive);
+        assert_that!(Duration::from(reported[0].time_since_last_heartbeat), gt HEARTBEAT_INTERVAL);
+        assert_that!(sut.health_of(node.id()), eq Some(HealthState::Unresponsive));
+    }
+
+    #[test]
+    fn recovered_node_is_reported_as_responsive() {
+        let iceoryx_config = generate_isolated_config();
+        let mut sut = Service::<S>::create(&Config::default(), &iceoryx_config).unwrap();
+        let node = create_node_with_heartbeat(&iceoryx_config, "worker");
+        miss_heartbeat();
+        sut.spin(|_| {}).unwrap();
+        assert_that!(sut.health_of(node.id()), eq Some(HealthState::Unresponsive));
+
+        node.heartbeat();
+        let mut reported = Vec::new();
+        sut.spin(|health| reported.push(*health)).unwrap();
+
+        assert_that!(reported, len 1);
+        assert_that!(reported[0].state, eq HealthState::Responsive);
+    }
+
+    #[test]
+    fn removed_node_is_no_longer_monitored() {
+        let iceoryx_config = generate_isolated_config();
+        let mut sut = Service::<S>::create(&Config::default(), &iceoryx_config).unwrap();
+        let node = create_node_with_heartbeat(&iceoryx_config, "worker");
+        let node_id = *node.id();
+        sut.spin(|_| {}).unwrap();
+
+        drop(node);
+        sut.spin(|_| {}).unwrap();
+
+        assert_that!(sut.health_of(&node_id), is_none);
+    }
+
+    #[test]
+    fn publishes_health_changes_when_configured() {
+        let iceoryx_config = generate_isolated_config();
+        let monitor_config = Config {
+            publish_events: true,
+            send_notifications: false,
+            ..Default::default()
+        };
+        let mut sut = Service::<S>::create(&monitor_config, &iceoryx_config).unwrap();
+
+        let node = create_node_with_heartbeat(&iceoryx_config, "worker");
+        let subscriber = node
+            .service_builder(service_name())
+            .publish_subscribe::<Payload>()
+            .open()
+            .unwrap()
+            .subscriber_builder()
+            .create()
+            .unwrap();
+
+        let mut reported: Option<NodeHealth> = None;
+        sut.spin(|health| reported = Some(*health)).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(Some(*sample), eq reported);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn notifies_listeners_with_health_state_when_configured() {
+        let iceoryx_config = generate_isolated_config();
+        let monitor_config = Config {
+            publish_events: false,
+            send_notifications: true,
+            ..Default::default()
+        };
+        let mut sut = Service::<S>::create(&monitor_config, &iceoryx_config).unwrap();
+
+        let node = NodeBuilder::new()
+            .config(&iceoryx_config)
+            .create::<S>()
+            .unwrap();
+        let listener = node
+            .service_builder(service_name())
+            .event()
+            .open()
+            .unwrap()
+            .listener_builder()
+            .create()
+            .unwrap();
+
+        sut.spin(|_| {}).unwrap();
+        assert_that!(listener.try_wait_one().unwrap(), is_none);
+
+        let _worker = create_node_with_heartbeat(&iceoryx_config, "worker");
+        sut.spin(|_| {}).unwrap();
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(HealthState::Responsive.into()));
+
+        miss_heartbeat();
+        sut.spin(|_| {}).unwrap();
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(HealthState::Unresponsive.into()));
+    }
+
+    #[test]
+    fn only_one_instance_can_be_created() {
+        let iceoryx_config = generate_isolated_config();
+        let _sut = Service::<S>::create(&Config::default(), &iceoryx_config).unwrap();
+
+        let result = Service::<S>::create(&Config::default(), &iceoryx_config);
+
+        assert_that!(result.err(), eq Some(CreationError::PublisherAlreadyExists));
+    }
+}
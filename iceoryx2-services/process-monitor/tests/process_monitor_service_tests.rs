@@ -0,0 +1,194 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+extern crate alloc;
+
+mod process_monitor_service {
+    use alloc::vec::Vec;
+
+    use iceoryx2::config::Config as IceoryxConfig;
+    use iceoryx2::identifiers::UniqueNodeId;
+    use iceoryx2::node::NodeState;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_services_process_monitor::process_monitor::{
+        Config, CreationError, DeadNode, Payload, Service, service_name,
+    };
+
+    type S = ipc::Service;
+
+    fn generate_config() -> IceoryxConfig {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        config.global.node.cleanup_dead_nodes_on_destruction = false;
+        config.global.service.cleanup_dead_nodes_on_open = false;
+        config
+    }
+
+    fn create_dead_node(config: &IceoryxConfig, name: &str) -> UniqueNodeId {
+        let node = NodeBuilder::new()
+            .name(&NodeName::new(name).unwrap())
+            .config(config)
+            .create::<S>()
+            .unwrap();
+        let node_id = *node.id();
+        Node::abandon(node);
+        node_id
+    }
+
+    fn number_of_dead_nodes(config: &IceoryxConfig) -> usize {
+        let mut counter = 0;
+        Node::<S>::list(config, |node_state| {
+            if let NodeState::Dead(_) = node_state {
+                counter += 1;
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        counter
+    }
+
+    #[test]
+    fn dead_nodes_are_cleaned_up_and_reported() {
+        const NUMBER_OF_DEAD_NODES: usize = 3;
+        let iceoryx_config = generate_config();
+        let mut sut = Service::<S>::create(&Config::default(), &iceoryx_config).unwrap();
+
+        let mut dead_node_ids = Vec::new();
+        for n in 0..NUMBER_OF_DEAD_NODES {
+            dead_node_ids.push(create_dead_node(&iceoryx_config, &format!("zombie-{n}")));
+        }
+        assert_that!(number_of_dead_nodes(&iceoryx_config), eq NUMBER_OF_DEAD_NODES);
+
+        let mut reported = Vec::new();
+        let cleanup_state = sut.spin(|dead_node| reported.push(*dead_node)).unwrap();
+
+        assert_that!(cleanup_state.cleanups, eq NUMBER_OF_DEAD_NODES as u64);
+        assert_that!(cleanup_state.failed_cleanups, eq 0);
+        assert_that!(number_of_dead_nodes(&iceoryx_config), eq 0);
+        assert_that!(reported, len NUMBER_OF_DEAD_NODES);
+        for dead_node in &reported {
+            assert_that!(dead_node_ids, contains dead_node.node_id);
+            assert_that!(dead_node.node_name.starts_with(b"zombie-"), eq true);
+            assert_that!(dead_node.is_cleaned_up, eq true);
+        }
+    }
+
+    #[test]
+    fn alive_nodes_are_not_reported() {
+        let iceoryx_config = generate_config();
+        let mut sut = Service::<S>::create(&Config::default(), &iceoryx_config).unwrap();
+        let _node = NodeBuilder::new()
+            .config(&iceoryx_config)
+            .create::<S>()
+            .unwrap();
+
+        let mut counter = 0;
+        let cleanup_state = sut.spin(|_| counter += 1).unwrap();
+
+        assert_that!(counter, eq 0);
+        assert_that!(cleanup_state.cleanups, eq 0);
+        assert_that!(cleanup_state.failed_cleanups, eq 0);
+    }
+
+    #[test]
+    fn dead_node_is_reported_only_once() {
+        let iceoryx_config = generate_config();
+        let mut sut = Service::<S>::create(&Config::default(), &iceoryx_config).unwrap();
+        create_dead_node(&iceoryx_config, "zombie");
+
+        let mut counter = 0;
+        sut.spin(|_| counter += 1).unwrap();
+        sut.spin(|_| counter += 1).unwrap();
+
+        assert_that!(counter, eq 1);
+    }
+
+    #[test]
+    fn publishes_dead_nodes_when_configured() {
+        let iceoryx_config = generate_config();
+        let monitor_config = Config {
+            publish_events: true,
+            send_notifications: false,
+            ..Default::default()
+        };
+        let mut sut = Service::<S>::create(&monitor_config, &iceoryx_config).unwrap();
+
+        let node = NodeBuilder::new()
+            .config(&iceoryx_config)
+            .create::<S>()
+            .unwrap();
+        let subscriber = node
+            .service_builder(service_name())
+            .publish_subscribe::<Payload>()
+            .open()
+            .unwrap()
+            .subscriber_builder()
+            .create()
+            .unwrap();
+
+        let dead_node_id = create_dead_node(&iceoryx_config, "zombie");
+        sut.spin(|_| {}).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        let expected = DeadNode {
+            node_id: dead_node_id,
+            node_name: "zombie".try_into().unwrap(),
+            is_cleaned_up: true,
+        };
+        assert_that!(*sample, eq expected);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn notifies_listeners_when_configured() {
+        let iceoryx_config = generate_config();
+        let monitor_config = Config {
+            publish_events: false,
+            send_notifications: true,
+            ..Default::default()
+        };
+        let mut sut = Service::<S>::create(&monitor_config, &iceoryx_config).unwrap();
+
+        let node = NodeBuilder::new()
+            .config(&iceoryx_config)
+            .create::<S>()
+            .unwrap();
+        let listener = node
+            .service_builder(service_name())
+            .event()
+            .open()
+            .unwrap()
+            .listener_builder()
+            .create()
+            .unwrap();
+
+        sut.spin(|_| {}).unwrap();
+        assert_that!(listener.try_wait_one().unwrap(), is_none);
+
+        create_dead_node(&iceoryx_config, "zombie");
+        sut.spin(|_| {}).unwrap();
+        assert_that!(listener.try_wait_one().unwrap(), is_some);
+    }
+
+    #[test]
+    fn only_one_instance_can_be_created() {
+        let iceoryx_config = generate_config();
+        let _sut = Service::<S>::create(&Config::default(), &iceoryx_config).unwrap();
+
+        let result = Service::<S>::create(&Config::default(), &iceoryx_config);
+
+        assert_that!(result.err(), eq Some(CreationError::PublisherAlreadyExists));
+    }
+}
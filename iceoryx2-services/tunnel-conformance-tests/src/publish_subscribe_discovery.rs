@@ -61,6 +61,7 @@ pub mod publish_subscribe_discovery {
 
         let tunnel_config = TunnelConfig {
             discovery_service: Some("iox2://discovery/services/".into()),
+            ..Default::default()
         };
         let mut tunnel =
             Tunnel::<S, B>::create(&tunnel_config, &iceoryx_config, &B::Config::default()).unwrap();
@@ -101,6 +102,41 @@ pub mod publish_subscribe_discovery {
         assert_that!(tunnel.tunneled_services().contains(service.service_hash()), eq true);
     }
 
+    #[conformance_test]
+    pub fn discovers_only_selected_services<S: Service, B: Backend<S> + Debug, T: Testing>() {
+        // === SETUP ==
+        let iceoryx_config = generate_isolated_config();
+        let selected_service_name = generate_service_name();
+        let other_service_name = generate_service_name();
+        let node = NodeBuilder::new()
+            .config(&iceoryx_config)
+            .create::<S>()
+            .unwrap();
+        let selected_service = node
+            .service_builder(&selected_service_name)
+            .publish_subscribe::<[u8]>()
+            .open_or_create()
+            .unwrap();
+        let _other_service = node
+            .service_builder(&other_service_name)
+            .publish_subscribe::<[u8]>()
+            .open_or_create()
+            .unwrap();
+
+        let tunnel_config = TunnelConfig {
+            services: Some([selected_service_name.as_str().into()].into()),
+            ..Default::default()
+        };
+        let mut tunnel =
+            Tunnel::<S, B>::create(&tunnel_config, &iceoryx_config, &B::Config::default()).unwrap();
+
+        // === TEST ===
+        tunnel.discover_over_iceoryx().unwrap();
+
+        assert_that!(tunnel.tunneled_services().len(), eq 1);
+        assert_that!(tunnel.tunneled_services().contains(selected_service.service_hash()), eq true);
+    }
+
     #[conformance_test]
     pub fn discovers_services_via_backend<S: Service, B: Backend<S> + Debug, T: Testing>() {
         // === SETUP ===
@@ -118,6 +118,9 @@ impl<S: Service, B: Backend<S>> Relays<S, B> {
 #[derive(Debug, Default)]
 pub struct Config {
     pub discovery_service: Option<String>,
+    /// Restricts the tunnel to the services with the given names. When [`None`],
+    /// all discovered services are tunneled.
+    pub services: Option<BTreeSet<String>>,
 }
 
 #[derive(Debug)]
@@ -128,6 +131,7 @@ pub struct Tunnel<S: Service, B: for<'a> Backend<S> + Debug> {
     relays: Relays<S, B>,
     subscriber: Option<discovery::subscriber::DiscoverySubscriber<S>>,
     tracker: Option<discovery::tracker::DiscoveryTracker<S>>,
+    services: Option<BTreeSet<String>>,
 }
 
 impl<S: Service, B: for<'a> Backend<S> + Debug> Tunnel<S, B> {
@@ -195,6 +199,7 @@ impl<S: Service, B: for<'a> Backend<S> + Debug> Tunnel<S, B> {
             relays: Relays::new(),
             subscriber,
             tracker,
+            services: tunnel_config.services.clone(),
         })
     }
 
@@ -211,7 +216,7 @@ impl<S: Service, B: for<'a> Backend<S> + Debug> Tunnel<S, B> {
             fail!(
                 from self,
                 when subscriber.discover(|static_config| {
-                    on_discovery(static_config, &self.node, &self.backend, &tunneled_services, &mut self.ports, &mut self.relays)
+                    on_discovery(static_config, &self.node, &self.backend, &self.services, &tunneled_services, &mut self.ports, &mut self.relays)
                 }),
                 with DiscoveryError::DiscoveryOverService,
                 "Failed to discover services via subscriber to discovery service"
@@ -221,7 +226,7 @@ impl<S: Service, B: for<'a> Backend<S> + Debug> Tunnel<S, B> {
             fail!(
                 from self,
                 when tracker.discover(|static_config| {
-                    on_discovery(static_config, &self.node, &self.backend, &tunneled_services, &mut self.ports, &mut self.relays)
+                    on_discovery(static_config, &self.node, &self.backend, &self.services, &tunneled_services, &mut self.ports, &mut self.relays)
                 }),
                 with DiscoveryError::DiscoveryOverTracker,
                 "Failed to discover services via discovery tracker"
@@ -236,7 +241,7 @@ impl<S: Service, B: for<'a> Backend<S> + Debug> Tunnel<S, B> {
         fail!(
             from self,
             when self.backend.discovery().discover(|static_config| {
-                on_discovery(static_config, &self.node, &self.backend, &tunneled_services, &mut self.ports, &mut self.relays)
+                on_discovery(static_config, &self.node, &self.backend, &self.services, &tunneled_services, &mut self.ports, &mut self.relays)
             }),
             with DiscoveryError::DiscoveryOverBackend,
             "Failed to discover services via Backend"
@@ -272,6 +277,14 @@ impl<S: Service, B: for<'a> Backend<S> + Debug> Tunnel<S, B> {
         Ok(())
     }
 
+    pub fn node(&self) -> &Node<S> {
+        &self.node
+    }
+
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
     pub fn tunneled_services(&self) -> BTreeSet<ServiceHash> {
         self.ports
             .publish_subscribe
@@ -286,7 +299,8 @@ fn on_discovery<S: Service, B: Backend<S> + Debug>(
     static_config: &StaticConfig,
     node: &Node<S>,
     backend: &B,
-    services: &BTreeSet<ServiceHash>,
+    selected_services: &Option<BTreeSet<String>>,
+    tunneled_services: &BTreeSet<ServiceHash>,
     ports: &mut Ports<S>,
     relays: &mut Relays<S, B>,
 ) -> Result<(), DiscoveryError> {
@@ -296,11 +310,18 @@ fn on_discovery<S: Service, B: Backend<S> + Debug>(
         core::any::type_name::<B>()
     );
 
-    if services.contains(static_config.service_hash()) {
+    if tunneled_services.contains(static_config.service_hash()) {
         // Nothing to do.
         return Ok(());
     }
 
+    if let Some(selected_services) = selected_services {
+        if !selected_services.contains(static_config.name().as_str()) {
+            // Not selected for tunneling. Nothing to do.
+            return Ok(());
+        }
+    }
+
     info!(
         from origin,
         "Discovered {}({})",
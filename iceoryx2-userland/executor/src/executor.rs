@@ -0,0 +1,599 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`Executor`] waits on a [`WaitSet`] and dispatches the callbacks of all triggered
+//! attachments to a pool of worker threads.
+
+use core::fmt::Debug;
+use core::time::Duration;
+use iceoryx2_bb_concurrency::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+
+use iceoryx2::prelude::{CallbackProgression, SignalHandlingMode};
+use iceoryx2::service::Service;
+use iceoryx2::waitset::{
+    WaitSet, WaitSetAttachmentError, WaitSetAttachmentId, WaitSetBuilder, WaitSetCreateError,
+    WaitSetGuard, WaitSetRunError, WaitSetRunResult,
+};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_bb_posix::socket_pair::StreamingSocket;
+use iceoryx2_bb_posix::system_configuration::SystemInfo;
+use iceoryx2_log::{fail, warn};
+
+use crate::worker::{Callback, Completions, Job, WorkerGroup, WorkerSettings};
+
+/// Defines the failures that can occur when creating an [`Executor`] with
+/// [`ExecutorBuilder::create()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ExecutorCreateError {
+    /// The underlying [`WaitSet`] could not be created.
+    UnableToCreateWaitSet,
+    /// The worker threads could not be spawned.
+    UnableToSpawnWorkers,
+    /// An internal error has occurred.
+    InternalError,
+}
+
+impl core::fmt::Display for ExecutorCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ExecutorCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for ExecutorCreateError {}
+
+impl From<WaitSetCreateError> for ExecutorCreateError {
+    fn from(_: WaitSetCreateError) -> Self {
+        ExecutorCreateError::UnableToCreateWaitSet
+    }
+}
+
+/// Defines the failures that can occur when attaching something with
+/// [`Executor::attach_notification()`] or [`Executor::attach_interval()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ExecutorAttachmentError {
+    /// The capacity of the underlying [`WaitSet`] is exceeded.
+    InsufficientCapacity,
+    /// The attachment is already attached.
+    AlreadyAttached,
+    /// The dedicated worker thread defined by the [`WorkerSettings`] could not be spawned.
+    UnableToSpawnWorker,
+    /// An internal error has occurred.
+    InternalError,
+    /// Insufficient resources to add another attachment.
+    InsufficientResources,
+}
+
+impl core::fmt::Display for ExecutorAttachmentError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ExecutorAttachmentError::{self:?}")
+    }
+}
+
+impl core::error::Error for ExecutorAttachmentError {}
+
+impl From<WaitSetAttachmentError> for ExecutorAttachmentError {
+    fn from(value: WaitSetAttachmentError) -> Self {
+        match value {
+            WaitSetAttachmentError::InsufficientCapacity => {
+                ExecutorAttachmentError::InsufficientCapacity
+            }
+            WaitSetAttachmentError::AlreadyAttached => ExecutorAttachmentError::AlreadyAttached,
+            WaitSetAttachmentError::InternalError => ExecutorAttachmentError::InternalError,
+            WaitSetAttachmentError::InsufficientResources => {
+                ExecutorAttachmentError::InsufficientResources
+            }
+        }
+    }
+}
+
+/// Defines the failures that can occur when calling [`Executor::run()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ExecutorRunError {
+    /// The process has not sufficient permissions to wait on the attachments.
+    InsufficientPermissions,
+    /// An internal error has occurred.
+    InternalError,
+    /// Running an [`Executor`] without attachments would lead to a deadlock.
+    NoAttachments,
+    /// An attachment could not be attached to the underlying [`WaitSet`].
+    UnableToAttach,
+}
+
+impl core::fmt::Display for ExecutorRunError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ExecutorRunError::{self:?}")
+    }
+}
+
+impl core::error::Error for ExecutorRunError {}
+
+impl From<WaitSetRunError> for ExecutorRunError {
+    fn from(value: WaitSetRunError) -> Self {
+        match value {
+            WaitSetRunError::InsufficientPermissions => ExecutorRunError::InsufficientPermissions,
+            WaitSetRunError::InternalError => ExecutorRunError::InternalError,
+            WaitSetRunError::NoAttachments => ExecutorRunError::NoAttachments,
+        }
+    }
+}
+
+/// The builder of the [`Executor`].
+#[derive(Debug, Clone)]
+pub struct ExecutorBuilder {
+    number_of_workers: usize,
+    signal_handling_mode: SignalHandlingMode,
+}
+
+impl Default for ExecutorBuilder {
+    fn default() -> Self {
+        Self {
+            number_of_workers: SystemInfo::NumberOfCpuCores.value().max(1),
+            signal_handling_mode: SignalHandlingMode::default(),
+        }
+    }
+}
+
+impl ExecutorBuilder {
+    /// Creates a new [`ExecutorBuilder`]. By default, the shared worker pool has one worker
+    /// thread per CPU core.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines the number of worker threads in the shared worker pool. It must be at least `1`.
+    pub fn number_of_workers(mut self, value: usize) -> Self {
+        self.number_of_workers = value.max(1);
+        self
+    }
+
+    /// Defines the [`SignalHandlingMode`] of the underlying [`WaitSet`]. See
+    /// [`WaitSetBuilder::signal_handling_mode()`].
+    pub fn signal_handling_mode(mut self, value: SignalHandlingMode) -> Self {
+        self.signal_handling_mode = value;
+        self
+    }
+
+    /// Creates the [`Executor`] and spawns the shared worker pool.
+    pub fn create<'attachment, S: Service>(
+        self,
+    ) -> Result<Executor<'attachment, S>, ExecutorCreateError> {
+        let msg = "Unable to create Executor";
+        let waitset = fail!(from self, when WaitSetBuilder::new()
+                            .signal_handling_mode(self.signal_handling_mode)
+                            .create::<S>(),
+                            "{msg} since the underlying WaitSet could not be created.");
+
+        let (completion_receiver, completion_sender) = fail!(from self,
+                when StreamingSocket::create_pair(),
+                with ExecutorCreateError::InternalError,
+                "{msg} since the socket pair to signal completed callbacks could not be created.");
+        let completions = Arc::new(Completions::new(completion_sender));
+
+        let shared_workers = fail!(from self,
+                when WorkerGroup::new(self.number_of_workers, &WorkerSettings::default(), &completions),
+                with ExecutorCreateError::UnableToSpawnWorkers,
+                "{msg} since the shared worker pool could not be spawned.");
+
+        Ok(Executor {
+            waitset,
+            completion_receiver,
+            completions,
+            shared_workers,
+            attachments: RefCell::new(BTreeMap::new()),
+            attachment_counter: Cell::new(0),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum AttachmentType<'attachment> {
+    Notification(&'attachment dyn Attachable),
+    Interval(Duration),
+}
+
+/// Marker trait for everything that can be attached with [`Executor::attach_notification()`].
+trait Attachable: SynchronousMultiplexing + Debug {}
+
+impl<T: SynchronousMultiplexing + Debug + ?Sized> Attachable for T {}
+
+struct Attachment<'attachment> {
+    attachment_type: AttachmentType<'attachment>,
+    callback: Arc<Mutex<Callback>>,
+    dedicated_worker: Option<WorkerGroup>,
+}
+
+/// Is returned when something is attached to the [`Executor`]. As soon as it goes out of
+/// scope, the attachment is detached and its callback is no longer called.
+#[derive(Debug)]
+pub struct ExecutorGuard<'executor, 'attachment, S: Service> {
+    executor: &'executor Executor<'attachment, S>,
+    id: usize,
+}
+
+impl<S: Service> Drop for ExecutorGuard<'_, '_, S> {
+    fn drop(&mut self) {
+        self.executor.attachments.borrow_mut().remove(&self.id);
+    }
+}
+
+/// The [`Executor`] waits in [`Executor::run()`] on all attachments with an internal
+/// [`WaitSet`] and dispatches the callbacks of the triggered attachments to worker threads.
+/// Callbacks of different attachments are executed concurrently, so that a slow callback
+/// does not block the processing of the other attachments.
+///
+/// The callbacks of one attachment are never executed concurrently. While a callback is
+/// running, its attachment is detached from the [`WaitSet`] and attached again as soon as the
+/// callback has returned.
+///
+/// By default, all callbacks are executed by a shared worker pool, see
+/// [`ExecutorBuilder::number_of_workers()`]. When a callback is attached with
+/// [`WorkerSettings`] that define a priority, a [`Scheduler`](iceoryx2_bb_posix::scheduler::Scheduler)
+/// or a CPU affinity, a dedicated worker thread with those settings is spawned for it.
+///
+/// # Example
+///
+/// ```no_run
+/// use iceoryx2::prelude::*;
+/// use iceoryx2_userland_executor::prelude::*;
+/// use std::sync::Arc;
+///
+/// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+/// let node = NodeBuilder::new().create::<ipc_threadsafe::Service>()?;
+/// let event = node
+///     .service_builder(&"MyEventName".try_into()?)
+///     .event()
+///     .open_or_create()?;
+/// let listener = Arc::new(event.listener_builder().create()?);
+///
+/// let executor = ExecutorBuilder::new()
+///     .number_of_workers(2)
+///     .create::<ipc_threadsafe::Service>()?;
+///
+/// let callback_listener = listener.clone();
+/// let _guard = executor.attach_notification(&*listener, &WorkerSettings::new(), move || {
+///     while let Ok(Some(event_id)) = callback_listener.try_wait_one() {
+///         println!("received: {event_id:?}");
+///     }
+///     CallbackProgression::Continue
+/// })?;
+///
+/// executor.run()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Executor<'attachment, S: Service> {
+    waitset: WaitSet<S>,
+    completion_receiver: StreamingSocket,
+    completions: Arc<Completions>,
+    attachments: RefCell<BTreeMap<usize, Attachment<'attachment>>>,
+    attachment_counter: Cell<usize>,
+    shared_workers: WorkerGroup,
+}
+
+impl<S: Service> Debug for Executor<'_, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Executor<{}> {{ number_of_attachments: {}, shared_workers: {:?} }}",
+            core::any::type_name::<S>(),
+            self.len(),
+            self.shared_workers
+        )
+    }
+}
+
+impl<'attachment, S: Service> Executor<'attachment, S> {
+    /// Attaches an object as notification to the [`Executor`]. Whenever an event is received on
+    /// the object, the `callback` is executed on a worker thread defined by the provided
+    /// [`WorkerSettings`]. The callback must handle all events of the object, otherwise it is
+    /// called again immediately.
+    ///
+    /// When the callback returns [`CallbackProgression::Stop`] or panics, [`Executor::run()`]
+    /// returns with [`WaitSetRunResult::StopRequest`].
+    pub fn attach_notification<
+        'executor,
+        T: SynchronousMultiplexing + Debug,
+        F: FnMut() -> CallbackProgression + Send + 'static,
+    >(
+        &'executor self,
+        attachment: &'attachment T,
+        settings: &WorkerSettings,
+        callback: F,
+    ) -> Result<ExecutorGuard<'executor, 'attachment, S>, ExecutorAttachmentError> {
+        let msg = "Unable to attach notification to the Executor";
+        for entry in self.attachments.borrow().values() {
+            if let AttachmentType::Notification(other) = entry.attachment_type {
+                if unsafe {
+                    other.file_descriptor().native_handle()
+                        == attachment.file_descriptor().native_handle()
+                } {
+                    fail!(from self, with ExecutorAttachmentError::AlreadyAttached,
+                        "{msg} since the attachment {attachment:?} is already attached.");
+                }
+            }
+        }
+
+        self.add_attachment(
+            AttachmentType::Notification(attachment),
+            settings,
+            Box::new(callback),
+            msg,
+        )
+    }
+
+    /// Attaches an interval to the [`Executor`]. Whenever the interval has passed, the
+    /// `callback` is executed on a worker thread defined by the provided [`WorkerSettings`].
+    /// When the previous call of the callback is still running, the interval is skipped.
+    ///
+    /// When the callback returns [`CallbackProgression::Stop`] or panics, [`Executor::run()`]
+    /// returns with [`WaitSetRunResult::StopRequest`].
+    pub fn attach_interval<'executor, F: FnMut() -> CallbackProgression + Send + 'static>(
+        &'executor self,
+        interval: Duration,
+        settings: &WorkerSettings,
+        callback: F,
+    ) -> Result<ExecutorGuard<'executor, 'attachment, S>, ExecutorAttachmentError> {
+        let msg = "Unable to attach interval to the Executor";
+        self.add_attachment(
+            AttachmentType::Interval(interval),
+            settings,
+            Box::new(callback),
+            msg,
+        )
+    }
+
+    /// Returns the number of attachments.
+    pub fn len(&self) -> usize {
+        self.attachments.borrow().len()
+    }
+
+    /// Returns true if the [`Executor`] has no attachments, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.attachments.borrow().is_empty()
+    }
+
+    /// Returns the capacity of the underlying [`WaitSet`]. One slot is used internally to
+    /// receive the completion of callbacks.
+    pub fn capacity(&self) -> usize {
+        self.waitset.capacity().saturating_sub(1)
+    }
+
+    /// Returns the [`SignalHandlingMode`] of the underlying [`WaitSet`].
+    pub fn signal_handling_mode(&self) -> SignalHandlingMode {
+        self.waitset.signal_handling_mode()
+    }
+
+    /// Waits on all attachments and dispatches the callbacks of the triggered attachments to
+    /// the worker threads until a callback returns [`CallbackProgression::Stop`] or a signal was
+    /// received. Before it returns, it waits until all dispatched callbacks have returned.
+    ///
+    /// The attachments are attached to the underlying [`WaitSet`] when the [`Executor`] starts
+    /// running. If one of them is not accepted by the [`WaitSet`], it fails with
+    /// [`ExecutorRunError::UnableToAttach`].
+    pub fn run(&self) -> Result<WaitSetRunResult, ExecutorRunError> {
+        let msg = "Unable to run the Executor";
+        if self.is_empty() {
+            fail!(from self, with ExecutorRunError::NoAttachments,
+                "{msg} since it has no attachments, therefore the call would end up in a deadlock.");
+        }
+
+        let mut run = RunState {
+            executor: self,
+            notifications: BTreeMap::new(),
+            intervals: BTreeMap::new(),
+            in_flight: BTreeSet::new(),
+        };
+
+        let completion_guard = fail!(from self,
+            when self.waitset.attach_notification(&self.completion_receiver),
+            with ExecutorRunError::InternalError,
+            "{msg} since the completion signal could not be attached to the WaitSet.");
+        let completion_id = WaitSetAttachmentId::from_guard(&completion_guard);
+
+        for (id, attachment) in self.attachments.borrow().iter() {
+            match attachment.attachment_type {
+                AttachmentType::Notification(value) => {
+                    let guard = fail!(from self, when self.waitset.attach_notification(value),
+                        with ExecutorRunError::UnableToAttach,
+                        "{msg} since the attachment {value:?} could not be attached to the WaitSet.");
+                    run.notifications.insert(*id, guard);
+                }
+                AttachmentType::Interval(interval) => {
+                    let guard = fail!(from self, when self.waitset.attach_interval(interval),
+                        with ExecutorRunError::UnableToAttach,
+                        "{msg} since the interval {interval:?} could not be attached to the WaitSet.");
+                    run.intervals.insert(*id, guard);
+                }
+            }
+        }
+
+        let result = loop {
+            let mut triggered = vec![];
+            let wait_result = self.waitset.wait_and_process_once(|attachment_id| {
+                triggered.push(attachment_id);
+                CallbackProgression::Continue
+            });
+
+            let wait_result = match wait_result {
+                Ok(v) => v,
+                Err(e) => {
+                    run.wait_for_in_flight_callbacks();
+                    fail!(from self, with e.into(),
+                        "{msg} since the underlying WaitSet failed to wait ({e:?}).");
+                }
+            };
+
+            for attachment_id in triggered {
+                if attachment_id == completion_id {
+                    self.drain_completion_signal();
+                } else {
+                    run.dispatch(&attachment_id);
+                }
+            }
+
+            let stop_requested = run.handle_completions(self.completions.take());
+
+            match wait_result {
+                WaitSetRunResult::Interrupt | WaitSetRunResult::TerminationRequest => {
+                    break wait_result;
+                }
+                _ if stop_requested => break WaitSetRunResult::StopRequest,
+                _ => (),
+            }
+        };
+
+        run.wait_for_in_flight_callbacks();
+        Ok(result)
+    }
+
+    fn add_attachment<'executor>(
+        &'executor self,
+        attachment_type: AttachmentType<'attachment>,
+        settings: &WorkerSettings,
+        callback: Callback,
+        msg: &str,
+    ) -> Result<ExecutorGuard<'executor, 'attachment, S>, ExecutorAttachmentError> {
+        if self.len() >= self.capacity() {
+            fail!(from self, with ExecutorAttachmentError::InsufficientCapacity,
+                "{msg} since it would exceed the capacity of {}.", self.capacity());
+        }
+
+        let dedicated_worker = if settings.requires_dedicated_worker() {
+            Some(fail!(from self,
+                when WorkerGroup::new(1, settings, &self.completions),
+                with ExecutorAttachmentError::UnableToSpawnWorker,
+                "{msg} since the dedicated worker with the settings {settings:?} could not be spawned."))
+        } else {
+            None
+        };
+
+        let id = self.attachment_counter.get() + 1;
+        self.attachment_counter.set(id);
+
+        self.attachments.borrow_mut().insert(
+            id,
+            Attachment {
+                attachment_type,
+                callback: Arc::new(Mutex::new(callback)),
+                dedicated_worker,
+            },
+        );
+
+        Ok(ExecutorGuard { executor: self, id })
+    }
+
+    fn drain_completion_signal(&self) {
+        let mut buffer = [0u8; 64];
+        loop {
+            match self.completion_receiver.try_receive(&mut buffer) {
+                Ok(0) => return,
+                Ok(_) => (),
+                Err(e) => {
+                    warn!(from self, "Unable to drain the completion signal ({e:?}).");
+                    return;
+                }
+            }
+        }
+    }
+}
+
+struct RunState<'executor, 'attachment, S: Service> {
+    executor: &'executor Executor<'attachment, S>,
+    notifications: BTreeMap<usize, WaitSetGuard<'executor, 'attachment, S>>,
+    intervals: BTreeMap<usize, WaitSetGuard<'executor, 'static, S>>,
+    in_flight: BTreeSet<usize>,
+}
+
+impl<S: Service> RunState<'_, '_, S> {
+    fn find(&self, attachment_id: &WaitSetAttachmentId<S>) -> Option<usize> {
+        let notification = self
+            .notifications
+            .iter()
+            .find(|(_, guard)| attachment_id.has_event_from(guard))
+            .map(|(id, _)| *id);
+
+        notification.or_else(|| {
+            self.intervals
+                .iter()
+                .find(|(_, guard)| attachment_id.has_event_from(guard))
+                .map(|(id, _)| *id)
+        })
+    }
+
+    fn dispatch(&mut self, attachment_id: &WaitSetAttachmentId<S>) {
+        let id = match self.find(attachment_id) {
+            Some(id) => id,
+            None => return,
+        };
+
+        if self.in_flight.contains(&id) {
+            return;
+        }
+
+        let attachments = self.executor.attachments.borrow();
+        let attachment = match attachments.get(&id) {
+            Some(attachment) => attachment,
+            None => return,
+        };
+
+        // detach the notification while its callback is running, otherwise the WaitSet
+        // would wake up immediately again since the event was not yet consumed
+        self.notifications.remove(&id);
+        self.in_flight.insert(id);
+
+        let job = Job {
+            attachment_id: id,
+            callback: attachment.callback.clone(),
+        };
+
+        match &attachment.dedicated_worker {
+            Some(worker) => worker.dispatch(job),
+            None => self.executor.shared_workers.dispatch(job),
+        }
+    }
+
+    fn handle_completions(&mut self, completions: Vec<(usize, CallbackProgression)>) -> bool {
+        let mut stop_requested = false;
+        for (id, progression) in completions {
+            self.in_flight.remove(&id);
+            stop_requested |= progression == CallbackProgression::Stop;
+
+            let attachments = self.executor.attachments.borrow();
+            if let Some(AttachmentType::Notification(value)) =
+                attachments.get(&id).map(|a| a.attachment_type)
+            {
+                match self.executor.waitset.attach_notification(value) {
+                    Ok(guard) => {
+                        self.notifications.insert(id, guard);
+                    }
+                    Err(e) => {
+                        warn!(from self.executor,
+                            "Unable to reattach {value:?} to the WaitSet ({e:?}). Its callback will not be called anymore.");
+                    }
+                }
+            }
+        }
+
+        stop_requested
+    }
+
+    fn wait_for_in_flight_callbacks(&mut self) {
+        while !self.in_flight.is_empty() {
+            for (id, _) in self.executor.completions.blocking_take() {
+                self.in_flight.remove(&id);
+            }
+        }
+        self.executor.drain_completion_signal();
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![warn(missing_docs)]
+
+//! # iceoryx2 userland - executor
+//!
+//! Provides a multi-threaded [`Executor`](crate::executor::Executor) on top of the
+//! [`WaitSet`](iceoryx2::waitset::WaitSet). The
+//! [`WaitSet::wait_and_process()`](iceoryx2::waitset::WaitSet::wait_and_process()) calls all
+//! callbacks on the calling thread, so one slow callback delays every other attachment. The
+//! [`Executor`](crate::executor::Executor) waits on the attachments and dispatches the
+//! callbacks to worker threads instead, while guaranteeing that the callbacks of one
+//! attachment are never executed concurrently.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_bb_posix::scheduler::Scheduler;
+//! use iceoryx2_userland_executor::prelude::*;
+//! use std::sync::Arc;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc_threadsafe::Service>()?;
+//! let service = node
+//!     .service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .enable_subscriber_wakeup(true)
+//!     .open_or_create()?;
+//! let subscriber = Arc::new(service.subscriber_builder().create_with_wakeup()?);
+//!
+//! let executor = ExecutorBuilder::new()
+//!     .number_of_workers(4)
+//!     .create::<ipc_threadsafe::Service>()?;
+//!
+//! // executed on a dedicated high priority worker thread pinned to CPU core 0
+//! let settings = WorkerSettings::new()
+//!     .priority(255)
+//!     .scheduler(Scheduler::Fifo)
+//!     .affinity(&[0]);
+//! let callback_subscriber = subscriber.clone();
+//! let _subscriber_guard = executor.attach_notification(&*subscriber, &settings, move || {
+//!     while let Ok(Some(sample)) = callback_subscriber.receive() {
+//!         println!("received: {:?}", *sample);
+//!     }
+//!     CallbackProgression::Continue
+//! })?;
+//!
+//! // executed in the shared worker pool
+//! let _interval_guard = executor.attach_interval(
+//!     Duration::from_secs(1),
+//!     &WorkerSettings::new(),
+//!     || {
+//!         println!("tick");
+//!         CallbackProgression::Continue
+//!     },
+//! )?;
+//!
+//! executor.run()?;
+//! # Ok(())
+//! # }
+//! ```
+
+/// Contains the [`Executor`](crate::executor::Executor) and its
+/// [`ExecutorBuilder`](crate::executor::ExecutorBuilder).
+pub mod executor;
+
+/// Loads a meaninful subset.
+pub mod prelude;
+
+/// Contains the [`WorkerSettings`](crate::worker::WorkerSettings) that define on which
+/// thread a callback is executed.
+pub mod worker;
@@ -0,0 +1,262 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Defines the [`WorkerSettings`] that configure the threads on which the callbacks of the
+//! [`Executor`](crate::executor::Executor) are executed.
+
+use std::collections::VecDeque;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::sync::{Arc, Condvar, Mutex};
+
+use iceoryx2::prelude::CallbackProgression;
+use iceoryx2_bb_posix::scheduler::Scheduler;
+use iceoryx2_bb_posix::socket_pair::StreamingSocket;
+use iceoryx2_bb_posix::thread::{Thread, ThreadBuilder, ThreadName, ThreadSpawnError};
+use iceoryx2_log::{error, fatal_panic, warn};
+
+pub(crate) type Callback = Box<dyn FnMut() -> CallbackProgression + Send>;
+
+/// Defines the thread settings of the worker that executes a callback. When no setting is
+/// defined, the callback is executed by the shared worker pool of the
+/// [`Executor`](crate::executor::Executor). Otherwise, a dedicated worker thread with the
+/// provided settings is spawned for the callback.
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2_bb_posix::scheduler::Scheduler;
+/// use iceoryx2_userland_executor::prelude::*;
+///
+/// let settings = WorkerSettings::new()
+///     .priority(128)
+///     .scheduler(Scheduler::Other)
+///     .affinity(&[0]);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct WorkerSettings {
+    priority: Option<u8>,
+    scheduler: Option<Scheduler>,
+    affinity: Option<Vec<usize>>,
+}
+
+impl WorkerSettings {
+    /// Creates new [`WorkerSettings`] that execute the callback in the shared worker pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the priority of the worker thread whereby `0` represents the lowest and `255` the
+    /// highest priority. See [`ThreadBuilder::priority()`].
+    pub fn priority(mut self, value: u8) -> Self {
+        self.priority = Some(value);
+        self
+    }
+
+    /// Sets the [`Scheduler`] of the worker thread. See [`ThreadBuilder::scheduler()`].
+    pub fn scheduler(mut self, value: Scheduler) -> Self {
+        self.scheduler = Some(value);
+        self
+    }
+
+    /// Sets the CPU affinity of the worker thread to the provided list of `cpu_core_id`s.
+    /// See [`ThreadBuilder::affinity()`].
+    pub fn affinity(mut self, cpu_core_ids: &[usize]) -> Self {
+        self.affinity = Some(cpu_core_ids.to_vec());
+        self
+    }
+
+    /// Returns true when the callback requires a dedicated worker thread, otherwise the
+    /// callback is executed in the shared worker pool.
+    pub fn requires_dedicated_worker(&self) -> bool {
+        self.priority.is_some() || self.scheduler.is_some() || self.affinity.is_some()
+    }
+
+    pub(crate) fn thread_builder(&self, name: &ThreadName) -> ThreadBuilder {
+        let mut builder = ThreadBuilder::new().name(name);
+
+        if self.priority.is_some() || self.scheduler.is_some() {
+            builder = builder.inherit_scheduling_attributes(false);
+        }
+
+        if let Some(priority) = self.priority {
+            builder = builder.priority(priority);
+        }
+
+        if let Some(scheduler) = self.scheduler {
+            builder = builder.scheduler(scheduler);
+        }
+
+        if let Some(affinity) = &self.affinity {
+            builder = builder.affinity(affinity);
+        }
+
+        builder
+    }
+}
+
+/// A callback that was dispatched to a [`WorkerGroup`] together with the id of the
+/// attachment it belongs to.
+pub(crate) struct Job {
+    pub(crate) attachment_id: usize,
+    pub(crate) callback: Arc<Mutex<Callback>>,
+}
+
+/// Collects the results of all executed [`Job`]s and wakes up the dispatcher.
+#[derive(Debug)]
+pub(crate) struct Completions {
+    queue: Mutex<Vec<(usize, CallbackProgression)>>,
+    has_completions: Condvar,
+    trigger: Mutex<StreamingSocket>,
+}
+
+impl Completions {
+    pub(crate) fn new(trigger: StreamingSocket) -> Self {
+        Self {
+            queue: Mutex::new(Vec::new()),
+            has_completions: Condvar::new(),
+            trigger: Mutex::new(trigger),
+        }
+    }
+
+    fn push(&self, attachment_id: usize, progression: CallbackProgression) {
+        self.queue
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push((attachment_id, progression));
+        self.has_completions.notify_all();
+
+        let trigger = self.trigger.lock().unwrap_or_else(|e| e.into_inner());
+        // when the socket buffer is full, the dispatcher has not yet been woken up and will
+        // collect this completion as well
+        if let Err(e) = trigger.try_send(&[1]) {
+            warn!(from self,
+                "Unable to wake up the dispatcher ({e:?}). The completion of attachment {attachment_id} may be handled delayed.");
+        }
+    }
+
+    /// Returns all completions without blocking.
+    pub(crate) fn take(&self) -> Vec<(usize, CallbackProgression)> {
+        core::mem::take(&mut *self.queue.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// Blocks until at least one completion is available and returns all completions.
+    pub(crate) fn blocking_take(&self) -> Vec<(usize, CallbackProgression)> {
+        let mut queue = self.queue.lock().unwrap_or_else(|e| e.into_inner());
+        while queue.is_empty() {
+            queue = self
+                .has_completions
+                .wait(queue)
+                .unwrap_or_else(|e| e.into_inner());
+        }
+        core::mem::take(&mut *queue)
+    }
+}
+
+struct JobQueue {
+    jobs: Mutex<(VecDeque<Job>, bool)>,
+    has_jobs: Condvar,
+}
+
+impl JobQueue {
+    fn pop(&self) -> Option<Job> {
+        let mut state = self.jobs.lock().unwrap_or_else(|e| e.into_inner());
+        loop {
+            let (jobs, keep_running) = &mut *state;
+            if !*keep_running {
+                return None;
+            }
+
+            if let Some(job) = jobs.pop_front() {
+                return Some(job);
+            }
+
+            state = self.has_jobs.wait(state).unwrap_or_else(|e| e.into_inner());
+        }
+    }
+}
+
+/// A set of worker threads that share one queue of [`Job`]s.
+pub(crate) struct WorkerGroup {
+    queue: Arc<JobQueue>,
+    threads: Vec<Thread>,
+}
+
+impl core::fmt::Debug for WorkerGroup {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "WorkerGroup {{ number_of_threads: {} }}",
+            self.threads.len()
+        )
+    }
+}
+
+impl Drop for WorkerGroup {
+    fn drop(&mut self) {
+        self.queue.jobs.lock().unwrap_or_else(|e| e.into_inner()).1 = false;
+        self.queue.has_jobs.notify_all();
+        // the threads are joined when they go out of scope
+        self.threads.clear();
+    }
+}
+
+impl WorkerGroup {
+    pub(crate) fn new(
+        number_of_threads: usize,
+        settings: &WorkerSettings,
+        completions: &Arc<Completions>,
+    ) -> Result<Self, ThreadSpawnError> {
+        let mut group = Self {
+            queue: Arc::new(JobQueue {
+                jobs: Mutex::new((VecDeque::new(), true)),
+                has_jobs: Condvar::new(),
+            }),
+            threads: Vec::with_capacity(number_of_threads),
+        };
+
+        for n in 0..number_of_threads {
+            let name = ThreadName::from_bytes_truncated(format!("iox2-worker-{n}").as_bytes())
+                .unwrap_or_else(|_| ThreadName::new());
+            let queue = group.queue.clone();
+            let completions = completions.clone();
+            let thread = settings.thread_builder(&name).spawn(move || {
+                while let Some(job) = queue.pop() {
+                    // a panicking callback must neither terminate the worker nor leave the
+                    // dispatcher waiting for its completion
+                    let progression = catch_unwind(AssertUnwindSafe(|| {
+                        (*job.callback.lock().unwrap_or_else(|e| e.into_inner()))()
+                    }))
+                    .unwrap_or_else(|_| {
+                        error!(from "WorkerGroup",
+                            "The callback of attachment {} panicked, the executor is stopped.",
+                            job.attachment_id);
+                        CallbackProgression::Stop
+                    });
+                    completions.push(job.attachment_id, progression);
+                }
+            })?;
+            group.threads.push(thread);
+        }
+
+        Ok(group)
+    }
+
+    pub(crate) fn dispatch(&self, job: Job) {
+        let mut state = self.queue.jobs.lock().unwrap_or_else(|e| e.into_inner());
+        if !state.1 {
+            fatal_panic!(from self,
+                "This should never happen! Unable to dispatch a job to a worker group that is shutting down.");
+        }
+        state.0.push_back(job);
+        self.queue.has_jobs.notify_one();
+    }
+}
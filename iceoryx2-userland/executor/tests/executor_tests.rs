@@ -0,0 +1,355 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod executor_tests {
+    use core::time::Duration;
+    use std::sync::Arc;
+
+    use iceoryx2::port::listener::Listener;
+    use iceoryx2::port::notifier::Notifier;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::{generate_isolated_config, generate_service_name};
+    use iceoryx2::waitset::WaitSetRunResult;
+    use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use iceoryx2_bb_posix::clock::nanosleep;
+    use iceoryx2_bb_posix::thread::{ThreadHandle, ThreadProperties};
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_userland_executor::prelude::*;
+
+    type S = ipc_threadsafe::Service;
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    fn create_event(node: &Node<S>) -> (Arc<Listener<S>>, Notifier<S>) {
+        let service = node
+            .service_builder(&generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        (
+            Arc::new(service.listener_builder().create().unwrap()),
+            service.notifier_builder().create().unwrap(),
+        )
+    }
+
+    fn wait_until(flag: &AtomicBool) -> bool {
+        let mut waited = Duration::ZERO;
+        while !flag.load(Ordering::Relaxed) {
+            if waited >= TIMEOUT {
+                return false;
+            }
+            nanosleep(Duration::from_millis(1)).unwrap();
+            waited += Duration::from_millis(1);
+        }
+        true
+    }
+
+    #[test]
+    fn run_without_attachments_fails() {
+        let sut = ExecutorBuilder::new()
+            .number_of_workers(1)
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(sut.is_empty(), eq true);
+        assert_that!(sut.run().err(), eq Some(ExecutorRunError::NoAttachments));
+    }
+
+    #[test]
+    fn attaching_same_notification_twice_fails() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = ExecutorBuilder::new()
+            .number_of_workers(1)
+            .create::<S>()
+            .unwrap();
+        let (listener, _notifier) = create_event(&node);
+
+        let _guard = sut
+            .attach_notification(&*listener, &WorkerSettings::new(), || {
+                CallbackProgression::Continue
+            })
+            .unwrap();
+        let result = sut.attach_notification(&*listener, &WorkerSettings::new(), || {
+            CallbackProgression::Continue
+        });
+
+        assert_that!(result.err(), eq Some(ExecutorAttachmentError::AlreadyAttached));
+        assert_that!(sut.len(), eq 1);
+    }
+
+    #[test]
+    fn dropping_guard_detaches_attachment() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = ExecutorBuilder::new()
+            .number_of_workers(1)
+            .create::<S>()
+            .unwrap();
+        let (listener, _notifier) = create_event(&node);
+
+        let guard = sut
+            .attach_notification(&*listener, &WorkerSettings::new(), || {
+                CallbackProgression::Continue
+            })
+            .unwrap();
+        assert_that!(sut.len(), eq 1);
+
+        drop(guard);
+        assert_that!(sut.len(), eq 0);
+
+        let guard = sut.attach_notification(&*listener, &WorkerSettings::new(), || {
+            CallbackProgression::Continue
+        });
+        assert_that!(guard, is_ok);
+    }
+
+    #[test]
+    fn notification_callback_is_executed_on_worker() {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = ExecutorBuilder::new()
+            .number_of_workers(1)
+            .create::<S>()
+            .unwrap();
+        let (listener, notifier) = create_event(&node);
+        let counter = Arc::new(AtomicUsize::new(0));
+
+        let callback_listener = listener.clone();
+        let callback_counter = counter.clone();
+        let _guard = sut
+            .attach_notification(&*listener, &WorkerSettings::new(), move || {
+                callback_listener.try_wait_all(|_| {}).unwrap();
+                callback_counter.fetch_add(1, Ordering::Relaxed);
+                CallbackProgression::Stop
+            })
+            .unwrap();
+
+        notifier.notify().unwrap();
+
+        assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+        assert_that!(counter.load(Ordering::Relaxed), eq 1);
+    }
+
+    #[test]
+    fn slow_callback_does_not_block_other_attachments() {
+        let _watchdog = Watchdog::new_with_timeout(TIMEOUT * 2);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = ExecutorBuilder::new()
+            .number_of_workers(2)
+            .create::<S>()
+            .unwrap();
+        let (slow_listener, slow_notifier) = create_event(&node);
+        let (fast_listener, fast_notifier) = create_event(&node);
+        let fast_callback_was_called = Arc::new(AtomicBool::new(false));
+        let slow_callback_succeeded = Arc::new(AtomicBool::new(false));
+
+        let callback_listener = slow_listener.clone();
+        let was_called = fast_callback_was_called.clone();
+        let succeeded = slow_callback_succeeded.clone();
+        let _slow_guard = sut
+            .attach_notification(&*slow_listener, &WorkerSettings::new(), move || {
+                callback_listener.try_wait_all(|_| {}).unwrap();
+                succeeded.store(wait_until(&was_called), Ordering::Relaxed);
+                CallbackProgression::Stop
+            })
+            .unwrap();
+
+        let callback_listener = fast_listener.clone();
+        let was_called = fast_callback_was_called.clone();
+        let _fast_guard = sut
+            .attach_notification(&*fast_listener, &WorkerSettings::new(), move || {
+                callback_listener.try_wait_all(|_| {}).unwrap();
+                was_called.store(true, Ordering::Relaxed);
+                CallbackProgression::Continue
+            })
+            .unwrap();
+
+        slow_notifier.notify().unwrap();
+        fast_notifier.notify().unwrap();
+
+        assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+        assert_that!(slow_callback_succeeded.load(Ordering::Relaxed), eq true);
+    }
+
+    #[test]
+    fn callbacks_of_one_attachment_are_never_executed_concurrently() {
+        let _watchdog = Watchdog::new();
+        const NUMBER_OF_NOTIFICATIONS: usize = 20;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = ExecutorBuilder::new()
+            .number_of_workers(4)
+            .create::<S>()
+            .unwrap();
+        let (listener, notifier) = create_event(&node);
+        let concurrent_calls = Arc::new(AtomicUsize::new(0));
+        let has_concurrent_calls = Arc::new(AtomicBool::new(false));
+        let received = Arc::new(AtomicUsize::new(0));
+
+        let callback_listener = listener.clone();
+        let calls = concurrent_calls.clone();
+        let has_concurrent = has_concurrent_calls.clone();
+        let callback_received = received.clone();
+        let _guard = sut
+            .attach_notification(&*listener, &WorkerSettings::new(), move || {
+                if calls.fetch_add(1, Ordering::Relaxed) != 0 {
+                    has_concurrent.store(true, Ordering::Relaxed);
+                }
+
+                let mut progression = CallbackProgression::Continue;
+                callback_listener
+                    .try_wait_all(|_| {
+                        if callback_received.fetch_add(1, Ordering::Relaxed) + 1
+                            == NUMBER_OF_NOTIFICATIONS
+                        {
+                            progression = CallbackProgression::Stop;
+                        }
+                    })
+                    .unwrap();
+                nanosleep(Duration::from_millis(2)).unwrap();
+
+                calls.fetch_sub(1, Ordering::Relaxed);
+                progression
+            })
+            .unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                for n in 0..NUMBER_OF_NOTIFICATIONS {
+                    notifier
+                        .notify_with_custom_event_id(EventId::new(n))
+                        .unwrap();
+                    nanosleep(Duration::from_millis(1)).unwrap();
+                }
+            });
+
+            assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+        });
+
+        assert_that!(has_concurrent_calls.load(Ordering::Relaxed), eq false);
+        assert_that!(received.load(Ordering::Relaxed), eq NUMBER_OF_NOTIFICATIONS);
+    }
+
+    #[test]
+    fn interval_callback_is_executed_repeatedly() {
+        let _watchdog = Watchdog::new();
+        const NUMBER_OF_TICKS: usize = 3;
+        let sut = ExecutorBuilder::new()
+            .number_of_workers(1)
+            .create::<S>()
+            .unwrap();
+        let counter = Arc::new(AtomicUsize::new(0));
+
+        let callback_counter = counter.clone();
+        let _guard = sut
+            .attach_interval(
+                Duration::from_millis(10),
+                &WorkerSettings::new(),
+                move || {
+                    if callback_counter.fetch_add(1, Ordering::Relaxed) + 1 == NUMBER_OF_TICKS {
+                        CallbackProgression::Stop
+                    } else {
+                        CallbackProgression::Continue
+                    }
+                },
+            )
+            .unwrap();
+
+        assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+        assert_that!(counter.load(Ordering::Relaxed), eq NUMBER_OF_TICKS);
+    }
+
+    #[test]
+    fn callback_with_custom_settings_is_executed_on_dedicated_worker() {
+        let _watchdog = Watchdog::new();
+        let sut = ExecutorBuilder::new()
+            .number_of_workers(1)
+            .create::<S>()
+            .unwrap();
+        let affinity = Arc::new(std::sync::Mutex::new(vec![]));
+
+        let callback_affinity = affinity.clone();
+        let _guard = sut
+            .attach_interval(
+                Duration::from_millis(1),
+                &WorkerSettings::new().affinity(&[0]),
+                move || {
+                    *callback_affinity.lock().unwrap() =
+                        ThreadHandle::from_self().get_affinity().unwrap();
+                    CallbackProgression::Stop
+                },
+            )
+            .unwrap();
+
+        assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+        assert_that!(*affinity.lock().unwrap(), eq vec![0]);
+    }
+
+    #[test]
+    fn attaching_callback_with_invalid_affinity_fails() {
+        let sut = ExecutorBuilder::new()
+            .number_of_workers(1)
+            .create::<S>()
+            .unwrap();
+
+        let result = sut.attach_interval(
+            Duration::from_millis(1),
+            &WorkerSettings::new().affinity(&[usize::MAX]),
+            || CallbackProgression::Continue,
+        );
+
+        assert_that!(result.err(), eq Some(ExecutorAttachmentError::UnableToSpawnWorker));
+        assert_that!(sut.is_empty(), eq true);
+    }
+
+    #[test]
+    fn panicking_callback_stops_the_executor() {
+        let _watchdog = Watchdog::new();
+        let sut = ExecutorBuilder::new()
+            .number_of_workers(1)
+            .create::<S>()
+            .unwrap();
+
+        let _guard = sut
+            .attach_interval(Duration::from_millis(1), &WorkerSettings::new(), || {
+                panic!("the callback panics")
+            })
+            .unwrap();
+
+        assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+    }
+
+    #[test]
+    fn panicking_callback_on_dedicated_worker_stops_the_executor() {
+        let _watchdog = Watchdog::new();
+        let sut = ExecutorBuilder::new()
+            .number_of_workers(1)
+            .create::<S>()
+            .unwrap();
+
+        let _guard = sut
+            .attach_interval(
+                Duration::from_millis(1),
+                &WorkerSettings::new().affinity(&[0]),
+                || panic!("the callback panics"),
+            )
+            .unwrap();
+
+        assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+    }
+}
@@ -18,6 +18,7 @@ use alloc::vec;
 use alloc::vec::Vec;
 
 use iceoryx2::service::static_config::message_type_details::TypeVariant;
+use iceoryx2::service::static_config::type_schema::{SchemaValue, TypeSchemaDecodeError};
 use iceoryx2_bb_posix::file::{File, FileReadLineState};
 use iceoryx2_log::fail;
 
@@ -25,7 +26,7 @@ use anyhow::Result;
 
 use crate::{
     hex_conversion::{bytes_to_hex_string, hex_string_to_bytes},
-    record_header::RecordHeaderDetails,
+    record_header::{RecordHeader, RecordHeaderDetails},
     recorder::RecorderWriteError,
     replayer::ReplayerOpenError,
 };
@@ -67,6 +68,29 @@ pub struct Record {
     pub payload: Vec<u8>,
 }
 
+impl Record {
+    /// Decodes the payload with the [`TypeSchema`](iceoryx2::service::static_config::type_schema::TypeSchema)
+    /// that is stored in the [`RecordHeader`].
+    pub fn decode_payload(
+        &self,
+        header: &RecordHeader,
+    ) -> Result<SchemaValue, TypeSchemaDecodeError> {
+        match header.details.types.payload.variant() {
+            TypeVariant::FixedSize => header.type_schema.payload.decode(&self.payload),
+            TypeVariant::Dynamic => header.type_schema.payload.decode_slice(&self.payload),
+        }
+    }
+
+    /// Decodes the user header with the [`TypeSchema`](iceoryx2::service::static_config::type_schema::TypeSchema)
+    /// that is stored in the [`RecordHeader`].
+    pub fn decode_user_header(
+        &self,
+        header: &RecordHeader,
+    ) -> Result<SchemaValue, TypeSchemaDecodeError> {
+        header.type_schema.user_header.decode(&self.user_header)
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct RecordReader {
     header: RecordHeaderDetails,
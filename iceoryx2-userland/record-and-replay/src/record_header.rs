@@ -11,6 +11,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use iceoryx2::prelude::{MessagingPattern, ServiceName};
+use iceoryx2::service::static_config::type_schema::MessageTypeSchema;
 use iceoryx2_bb_elementary::package_version::PackageVersion;
 
 use crate::recorder::ServiceTypes;
@@ -19,7 +20,7 @@ use crate::recorder::ServiceTypes;
 pub const FILE_FORMAT_HUMAN_READABLE_VERSION: u64 = 1;
 
 /// Defines the current file format version of the iox2dump version
-pub const FILE_FORMAT_IOX2_DUMP_VERSION: u64 = 1;
+pub const FILE_FORMAT_IOX2_DUMP_VERSION: u64 = 2;
 
 #[repr(C)]
 #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
@@ -66,4 +67,8 @@ pub struct RecordHeader {
     pub service_name: ServiceName,
     /// The version, message and type details
     pub details: RecordHeaderDetails,
+    /// The schema of the user header and payload type that allows to decode the records.
+    /// It is empty when the schema of the service was unknown.
+    #[serde(default)]
+    pub type_schema: MessageTypeSchema,
 }
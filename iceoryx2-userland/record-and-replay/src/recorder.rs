@@ -55,6 +55,7 @@ use alloc::format;
 
 use iceoryx2::prelude::{MessagingPattern, ServiceName};
 use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+use iceoryx2::service::static_config::type_schema::MessageTypeSchema;
 use iceoryx2_bb_elementary::package_version::PackageVersion;
 use iceoryx2_bb_posix::file::{CreationMode, FileCreationError, FileWriteError};
 use iceoryx2_bb_posix::file::{File, FileBuilder};
@@ -132,6 +133,7 @@ pub struct ServiceTypes {
 /// Builder to create a new [`Recorder`].
 pub struct RecorderBuilder {
     types: ServiceTypes,
+    type_schema: MessageTypeSchema,
     data_representation: DataRepresentation,
     messaging_pattern: MessagingPattern,
 }
@@ -141,6 +143,7 @@ impl RecorderBuilder {
     pub fn new(types: &ServiceTypes) -> Self {
         Self {
             types: types.clone(),
+            type_schema: MessageTypeSchema::default(),
             data_representation: DataRepresentation::default(),
             messaging_pattern: MessagingPattern::PublishSubscribe,
         }
@@ -152,6 +155,16 @@ impl RecorderBuilder {
         self
     }
 
+    /// Defines the [`MessageTypeSchema`] that is stored in the [`RecordHeader`] so that the
+    /// records can be decoded with [`Record::decode_payload()`](crate::record::Record::decode_payload())
+    /// and [`Record::decode_user_header()`](crate::record::Record::decode_user_header()).
+    /// It can be acquired with
+    /// [`publish_subscribe::StaticConfig::message_type_schema()`](iceoryx2::service::static_config::publish_subscribe::StaticConfig::message_type_schema()).
+    pub fn type_schema(mut self, value: &MessageTypeSchema) -> Self {
+        self.type_schema = *value;
+        self
+    }
+
     /// Defines the messaging pattern of the recorded file.
     pub fn messaging_pattern(mut self, value: MessagingPattern) -> Self {
         self.messaging_pattern = value;
@@ -193,6 +206,7 @@ impl RecorderBuilder {
                 types: self.types.clone(),
                 messaging_pattern: self.messaging_pattern,
             },
+            type_schema: self.type_schema,
         };
         self.write_header(&mut file, &header, self.data_representation)?;
 
@@ -28,6 +28,9 @@ mod recorder_replayer {
     };
 
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeName};
+    use iceoryx2::service::static_config::type_schema::{
+        MessageTypeSchema, SchemaValue, TypeSchema,
+    };
     use iceoryx2_bb_concurrency::atomic::AtomicU8;
     use iceoryx2_bb_posix::testing::generate_file_path;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
@@ -126,6 +129,71 @@ mod recorder_replayer {
         File::remove(&file_name).unwrap();
     }
 
+    fn record_and_replay_preserves_type_schema(data_representation: DataRepresentation) {
+        let service_name = iceoryx2::testing::generate_service_name();
+        let file_name = generate_file_path();
+        let types = ServiceTypes {
+            payload: TypeDetail::new::<u64>(TypeVariant::Dynamic),
+            user_header: TypeDetail::new::<i32>(TypeVariant::FixedSize),
+            system_header: generate_type_detail(TypeVariant::FixedSize, 16, 8),
+        };
+        let type_schema = MessageTypeSchema {
+            user_header: TypeSchema::new::<i32>(),
+            payload: TypeSchema::new::<u64>(),
+        };
+
+        let mut recorder = RecorderBuilder::new(&types)
+            .type_schema(&type_schema)
+            .data_representation(data_representation)
+            .messaging_pattern(MessagingPattern::PublishSubscribe)
+            .create(&file_name, &service_name)
+            .unwrap();
+
+        let payload = [3u64.to_ne_bytes(), 5u64.to_ne_bytes()].concat();
+        assert_that!(
+            recorder.write(RawRecord {
+                timestamp: Duration::ZERO,
+                system_header: &[0u8; 16],
+                user_header: &(-7i32).to_ne_bytes(),
+                payload: &payload
+            }),
+            is_ok
+        );
+
+        let replay = ReplayerOpener::new(&file_name)
+            .data_representation(data_representation)
+            .open()
+            .unwrap();
+        let record_header = replay.header().clone();
+        let buffer = replay.read_into_buffer().unwrap();
+
+        assert_that!(record_header.type_schema, eq type_schema);
+        assert_that!(buffer, len 1);
+        assert_that!(
+            buffer[0].decode_payload(&record_header),
+            eq Ok(SchemaValue::Sequence(vec![
+                SchemaValue::Unsigned(3),
+                SchemaValue::Unsigned(5)
+            ]))
+        );
+        assert_that!(
+            buffer[0].decode_user_header(&record_header),
+            eq Ok(SchemaValue::Signed(-7))
+        );
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn record_and_replay_preserves_type_schema_for_iox2dump() {
+        record_and_replay_preserves_type_schema(DataRepresentation::Iox2Dump);
+    }
+
+    #[test]
+    fn record_and_replay_preserves_type_schema_for_human_readable() {
+        record_and_replay_preserves_type_schema(DataRepresentation::HumanReadable);
+    }
+
     #[test]
     fn record_and_replay_works_for_iox2dump() {
         record_and_replay_works(
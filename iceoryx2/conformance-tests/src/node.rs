@@ -23,18 +23,28 @@ pub mod node {
 
     use iceoryx2::config::Config;
     use iceoryx2::node::{
-        NodeCleanupFailure, NodeCreationFailure, NodeListFailure, NodeState, NodeView,
+        NodeCleanupFailure, NodeCreationFailure, NodeHeartbeat, NodeListFailure,
+        NodeShutdownRequestFailure, NodeState, NodeView, NodeWaitFailure, NodeWatcher,
     };
     use iceoryx2::prelude::*;
     use iceoryx2::service::Service;
+    use iceoryx2::service::attribute::{AttributeKey, AttributeValue};
+    use iceoryx2::service::service_name_remapping::{
+        ServiceNameRemapping, ServiceNameRemappingError,
+    };
     use iceoryx2::testing::*;
+    use iceoryx2::waitset::WaitSetRunResult;
     use iceoryx2_bb_posix::barrier::{BarrierBuilder, BarrierHandle};
+    use iceoryx2_bb_posix::clock::{ClockType, nanosleep};
+    use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
     use iceoryx2_bb_posix::ipc_capable::Handle;
-    use iceoryx2_bb_posix::system_configuration::SystemInfo;
+    use iceoryx2_bb_posix::process::Process;
+    use iceoryx2_bb_posix::system_configuration::{SystemInfo, host_name};
     use iceoryx2_bb_posix::thread::thread_scope;
     use iceoryx2_bb_testing::watchdog::Watchdog;
     use iceoryx2_bb_testing::{assert_that, test_fail};
     use iceoryx2_bb_testing_macros::conformance_test;
+    use iceoryx2_cal::event::Event;
 
     #[derive(Debug, Eq, PartialEq)]
     struct Details {
@@ -261,6 +271,35 @@ pub mod node {
             format!("{}", NodeCreationFailure::InsufficientPermissions), eq "NodeCreationFailure::InsufficientPermissions");
         assert_that!(
             format!("{}", NodeCreationFailure::InternalError), eq "NodeCreationFailure::InternalError");
+        assert_that!(
+            format!("{}", NodeCreationFailure::ReadOnlyDataSegmentsNotSupported), eq "NodeCreationFailure::ReadOnlyDataSegmentsNotSupported");
+        assert_that!(
+            format!("{}", NodeCreationFailure::InvalidServiceNameRemapping), eq "NodeCreationFailure::InvalidServiceNameRemapping");
+    }
+
+    #[conformance_test]
+    pub fn node_creation_with_enforced_read_only_data_segments_fails_when_unsupported<
+        S: Service,
+    >() {
+        let mut config = generate_isolated_config();
+        config.global.service.enforce_read_only_data_segments = true;
+
+        let sut = NodeBuilder::new().config(&config).create::<S>();
+
+        if does_support_read_only_data_segments::<S>() {
+            assert_that!(sut, is_ok);
+        } else {
+            assert_that!(sut.err(), eq Some(NodeCreationFailure::ReadOnlyDataSegmentsNotSupported));
+        }
+    }
+
+    #[conformance_test]
+    pub fn read_only_data_segments_are_not_enforced_by_default<S: Service>() {
+        let config = generate_isolated_config();
+        assert_that!(config.global.service.enforce_read_only_data_segments, eq false);
+
+        let sut = NodeBuilder::new().config(&config).create::<S>();
+        assert_that!(sut, is_ok);
     }
 
     #[conformance_test]
@@ -377,6 +416,306 @@ pub mod node {
         }
     }
 
+    #[conformance_test]
+    pub fn node_watcher_reports_alive_nodes_with_details<S: Service>() {
+        let config = generate_isolated_config();
+        let node_name = NodeName::new("the watched one").unwrap();
+        let node = NodeBuilder::new()
+            .name(&node_name)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        let mut sut = NodeWatcher::<S>::new(&config);
+
+        for _ in 0..2 {
+            let mut nodes = vec![];
+            let result = sut.update(|node_state| {
+                nodes.push(node_state);
+                CallbackProgression::Continue
+            });
+
+            assert_that!(result, is_ok);
+            assert_that!(nodes, len 1);
+            assert_that!(sut.len(), eq 1);
+
+            if let NodeState::Alive(node_view) = &nodes[0] {
+                assert_that!(node_view.id(), eq node.id());
+                assert_that!(*node_view.details().as_ref().unwrap().name(), eq node_name);
+            } else {
+                test_fail!("Process internal nodes shall be always detected as alive.");
+            }
+        }
+    }
+
+    #[conformance_test]
+    pub fn node_watcher_stops_watching_removed_nodes<S: Service>() {
+        let config = generate_isolated_config();
+        let node_1 = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let node_2 = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let mut sut = NodeWatcher::<S>::new(&config);
+
+        assert_that!(sut.update(|_| CallbackProgression::Continue), is_ok);
+        assert_that!(sut.len(), eq 2);
+
+        drop(node_1);
+
+        let mut node_ids = vec![];
+        let result = sut.update(|node_state| {
+            node_ids.push(*node_state.node_id());
+            CallbackProgression::Continue
+        });
+
+        assert_that!(result, is_ok);
+        assert_that!(sut.len(), eq 1);
+        assert_that!(node_ids, eq vec![*node_2.id()]);
+
+        drop(node_2);
+
+        assert_that!(sut.update(|_| CallbackProgression::Continue), is_ok);
+        assert_that!(sut.is_empty(), eq true);
+    }
+
+    #[conformance_test]
+    pub fn node_watcher_stops_when_callback_progression_signals_stop<S: Service>() {
+        let config = generate_isolated_config();
+        let _node_1 = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _node_2 = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let mut sut = NodeWatcher::<S>::new(&config);
+
+        let mut node_counter = 0;
+        let result = sut.update(|_| {
+            node_counter += 1;
+            CallbackProgression::Stop
+        });
+
+        assert_that!(result, is_ok);
+        assert_that!(node_counter, eq 1);
+    }
+
+    #[conformance_test]
+    pub fn by_default_node_has_no_attributes<S: Service>() {
+        let config = generate_isolated_config();
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(sut.attributes().is_empty(), eq true);
+    }
+
+    #[conformance_test]
+    pub fn node_attributes_are_visible_in_node_list<S: Service>() {
+        let config = generate_isolated_config();
+        let key = AttributeKey::try_from("role").unwrap();
+        let value = AttributeValue::try_from("camera-driver").unwrap();
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .attributes(&AttributeSpecifier::new().define(&key, &value).unwrap())
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(sut.attributes().number_of_key_values(&key), eq 1);
+
+        let mut has_found_node = false;
+        Node::<S>::list(&config, |node_state| {
+            if let NodeState::Alive(view) = node_state {
+                let details = view.details().as_ref().unwrap();
+                assert_that!(view.id(), eq sut.id());
+                assert_that!(details.attributes(), eq sut.attributes());
+                assert_that!(details.attributes().key_value(&key, 0), eq Some(&value));
+                has_found_node = true;
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(has_found_node, eq true);
+    }
+
+    #[conformance_test]
+    pub fn service_name_remapping_can_be_created_from_rule<S: Service>() {
+        let from = generate_service_name();
+        let to = generate_service_name();
+
+        let sut = ServiceNameRemapping::try_from(format!("{from}:={to}").as_str()).unwrap();
+
+        assert_that!(*sut.source(), eq from);
+        assert_that!(*sut.target(), eq to);
+        assert_that!(sut.to_string(), eq format!("{from}:={to}"));
+        assert_that!(sut, eq ServiceNameRemapping::new(&from, &to));
+    }
+
+    #[conformance_test]
+    pub fn service_name_remapping_creation_fails_with_invalid_rule<S: Service>() {
+        assert_that!(ServiceNameRemapping::try_from("camera=rig/camera").err(),
+            eq Some(ServiceNameRemappingError::MissingSeparator));
+        assert_that!(ServiceNameRemapping::try_from(":=rig/camera").err(),
+            eq Some(ServiceNameRemappingError::InvalidServiceName));
+        assert_that!(ServiceNameRemapping::try_from("camera:=").err(),
+            eq Some(ServiceNameRemappingError::InvalidServiceName));
+        assert_that!(ServiceNameRemapping::try_from("camera:=iox2://camera").err(),
+            eq Some(ServiceNameRemappingError::InvalidServiceName));
+    }
+
+    #[conformance_test]
+    pub fn service_name_remappings_can_be_created_from_list<S: Service>() {
+        let sut = ServiceNameRemapping::from_list("  a:=b\tc:=d\n").unwrap();
+
+        assert_that!(sut, len 2);
+        assert_that!(sut[0].to_string(), eq "a:=b");
+        assert_that!(sut[1].to_string(), eq "c:=d");
+        assert_that!(ServiceNameRemapping::from_list("a:=b c").err(),
+            eq Some(ServiceNameRemappingError::MissingSeparator));
+    }
+
+    #[conformance_test]
+    pub fn service_name_remapping_of_node_builder_is_applied_to_service_builder<S: Service>() {
+        let config = generate_isolated_config();
+        let from = generate_service_name();
+        let to = generate_service_name();
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .service_name_remapping(&ServiceNameRemapping::new(&from, &to))
+            .create::<S>()
+            .unwrap();
+        let other_node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let service = sut
+            .service_builder(&from)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(*service.name(), eq to);
+        assert_that!(
+            other_node
+                .service_builder(&to)
+                .publish_subscribe::<u64>()
+                .open(),
+            is_ok
+        );
+        assert_that!(
+            other_node
+                .service_builder(&from)
+                .publish_subscribe::<u64>()
+                .open(),
+            is_err
+        );
+    }
+
+    #[conformance_test]
+    pub fn service_names_without_matching_remapping_are_not_changed<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .service_name_remapping(&ServiceNameRemapping::new(
+                &generate_service_name(),
+                &generate_service_name(),
+            ))
+            .create::<S>()
+            .unwrap();
+
+        let service = sut.service_builder(&service_name).event().create().unwrap();
+
+        assert_that!(*service.name(), eq service_name);
+    }
+
+    #[conformance_test]
+    pub fn service_name_remapping_of_config_is_applied_to_service_builder<S: Service>() {
+        let mut config = generate_isolated_config();
+        let from = generate_service_name();
+        let to = generate_service_name();
+        config
+            .global
+            .service
+            .name_remappings
+            .push(ServiceNameRemapping::new(&from, &to));
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let service = sut
+            .service_builder(&from)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(*service.name(), eq to);
+    }
+
+    #[conformance_test]
+    pub fn service_name_remapping_of_node_builder_takes_precedence_over_config<S: Service>() {
+        let mut config = generate_isolated_config();
+        let from = generate_service_name();
+        let to_config = generate_service_name();
+        let to_node_builder = generate_service_name();
+        config
+            .global
+            .service
+            .name_remappings
+            .push(ServiceNameRemapping::new(&from, &to_config));
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .service_name_remapping(&ServiceNameRemapping::new(&from, &to_node_builder))
+            .create::<S>()
+            .unwrap();
+
+        let service = sut.service_builder(&from).event().create().unwrap();
+
+        assert_that!(*service.name(), eq to_node_builder);
+        assert_that!(sut.service_name_remappings(), len 2);
+        assert_that!(*sut.service_name_remappings()[0].target(), eq to_node_builder);
+        assert_that!(*sut.service_name_remappings()[1].target(), eq to_config);
+    }
+
+    #[conformance_test]
+    pub fn service_name_remappings_are_visible_in_node_details<S: Service>() {
+        let config = generate_isolated_config();
+        let remapping =
+            ServiceNameRemapping::new(&generate_service_name(), &generate_service_name());
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .service_name_remapping(&remapping)
+            .create::<S>()
+            .unwrap();
+
+        let mut has_found_node = false;
+        Node::<S>::list(&config, |node_state| {
+            if let NodeState::Alive(view) = node_state {
+                let details = view.details().as_ref().unwrap();
+                assert_that!(view.id(), eq sut.id());
+                assert_that!(details.service_name_remappings(), eq sut.service_name_remappings());
+                assert_that!(details.service_name_remappings()[0], eq remapping);
+                has_found_node = true;
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(has_found_node, eq true);
+    }
+
+    #[conformance_test]
+    pub fn node_details_contain_process_information<S: Service>() {
+        let config = generate_isolated_config();
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let mut has_found_node = false;
+        Node::<S>::list(&config, |node_state| {
+            if let NodeState::Alive(view) = node_state {
+                let details = view.details().as_ref().unwrap();
+                assert_that!(details.process_id(), eq Process::from_self().id());
+                assert_that!(details.host_name().copied(), eq host_name().ok());
+                #[cfg(feature = "std")]
+                assert_that!(details.command_line(), len std::env::args().count());
+                assert_that!(details.start_time().clock_type(), eq ClockType::Realtime);
+                assert_that!(details.start_time().seconds(), gt 0);
+                assert_that!(view.id(), eq sut.id());
+                has_found_node = true;
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(has_found_node, eq true);
+    }
+
     #[conformance_test]
     pub fn signal_handling_mechanism_can_be_configured<S: Service>() {
         let config = generate_isolated_config();
@@ -403,4 +742,308 @@ pub mod node {
 
         assert_that!(node.signal_handling_mode(), eq SignalHandlingMode::HandleTerminationRequests);
     }
+
+    fn last_heartbeat<S: Service>(node: &Node<S>) -> Option<NodeHeartbeat> {
+        let mut heartbeat = None;
+        Node::<S>::list(node.config(), |node_state| {
+            if let NodeState::Alive(view) = node_state {
+                if view.id() == node.id() {
+                    heartbeat = view.last_heartbeat();
+                    return CallbackProgression::Stop;
+                }
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        heartbeat
+    }
+
+    #[conformance_test]
+    pub fn by_default_heartbeats_are_disabled<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(node.heartbeat_interval(), is_none);
+        assert_that!(last_heartbeat(&node), is_none);
+    }
+
+    #[conformance_test]
+    pub fn heartbeat_interval_can_be_configured<S: Service>() {
+        const INTERVAL: Duration = Duration::from_secs(3600);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .heartbeat_interval(INTERVAL)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(node.heartbeat_interval(), eq Some(INTERVAL));
+
+        let heartbeat = last_heartbeat(&node);
+        assert_that!(heartbeat, is_some);
+        let heartbeat = heartbeat.unwrap();
+        assert_that!(heartbeat.interval(), eq INTERVAL);
+        assert_that!(heartbeat.has_missed_heartbeat(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn missed_heartbeat_is_detected<S: Service>() {
+        const INTERVAL: Duration = Duration::from_millis(10);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .heartbeat_interval(INTERVAL)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        nanosleep(INTERVAL * 5).unwrap();
+
+        let heartbeat = last_heartbeat(&node).unwrap();
+        assert_that!(heartbeat.has_missed_heartbeat(), eq true);
+        assert_that!(heartbeat.time_since_last_heartbeat(), ge INTERVAL * 5);
+    }
+
+    #[conformance_test]
+    pub fn heartbeat_resets_missed_heartbeat<S: Service>() {
+        const INTERVAL: Duration = Duration::from_millis(500);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .heartbeat_interval(INTERVAL)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        nanosleep(INTERVAL + Duration::from_millis(10)).unwrap();
+        assert_that!(last_heartbeat(&node).unwrap().has_missed_heartbeat(), eq true);
+
+        node.heartbeat();
+        assert_that!(last_heartbeat(&node).unwrap().has_missed_heartbeat(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn wait_sends_heartbeat<S: Service>() {
+        const INTERVAL: Duration = Duration::from_millis(500);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .heartbeat_interval(INTERVAL)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        nanosleep(INTERVAL + Duration::from_millis(10)).unwrap();
+        assert_that!(last_heartbeat(&node).unwrap().has_missed_heartbeat(), eq true);
+
+        assert_that!(node.wait(Duration::ZERO), is_ok);
+        assert_that!(last_heartbeat(&node).unwrap().has_missed_heartbeat(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn heartbeat_reader_observes_heartbeats_continuously<S: Service>() {
+        const INTERVAL: Duration = Duration::from_millis(500);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .heartbeat_interval(INTERVAL)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        let mut sut = None;
+        Node::<S>::list(node.config(), |node_state| {
+            if let NodeState::Alive(view) = node_state {
+                sut = view.heartbeat_reader();
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        let sut = sut.unwrap();
+
+        assert_that!(sut.last_heartbeat().unwrap().interval(), eq INTERVAL);
+
+        nanosleep(INTERVAL + Duration::from_millis(10)).unwrap();
+        assert_that!(sut.last_heartbeat().unwrap().has_missed_heartbeat(), eq true);
+
+        node.heartbeat();
+        assert_that!(sut.last_heartbeat().unwrap().has_missed_heartbeat(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn heartbeat_reader_is_not_available_when_heartbeats_are_disabled<S: Service>() {
+        let config = generate_isolated_config();
+        let _node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let mut has_heartbeat_reader = true;
+        Node::<S>::list(&config, |node_state| {
+            if let NodeState::Alive(view) = node_state {
+                has_heartbeat_reader = view.heartbeat_reader().is_some();
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(has_heartbeat_reader, eq false);
+    }
+
+    fn request_shutdown<S: Service>(node: &Node<S>) -> Result<(), NodeShutdownRequestFailure> {
+        let mut result = None;
+        Node::<S>::list(node.config(), |node_state| {
+            if let NodeState::Alive(view) = node_state {
+                if view.id() == node.id() {
+                    result = Some(view.request_shutdown());
+                    return CallbackProgression::Stop;
+                }
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        result.unwrap()
+    }
+
+    #[conformance_test]
+    pub fn by_default_shutdown_requests_are_disabled<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(node.accepts_shutdown_requests(), eq false);
+        assert_that!(node.control_channel(), is_none);
+        assert_that!(request_shutdown(&node), eq Err(NodeShutdownRequestFailure::ShutdownRequestsNotEnabled));
+        assert_that!(node.has_shutdown_request(), eq false);
+        assert_that!(node.wait(Duration::ZERO), is_ok);
+    }
+
+    #[conformance_test]
+    pub fn without_shutdown_request_wait_succeeds<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .enable_shutdown_requests(true)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(node.accepts_shutdown_requests(), eq true);
+        assert_that!(node.control_channel(), is_some);
+        assert_that!(node.has_shutdown_request(), eq false);
+        assert_that!(node.wait(Duration::ZERO), is_ok);
+    }
+
+    #[conformance_test]
+    pub fn shutdown_request_is_received<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .enable_shutdown_requests(true)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(request_shutdown(&node), is_ok);
+
+        assert_that!(node.has_shutdown_request(), eq true);
+        // the shutdown request stays requested
+        assert_that!(node.has_shutdown_request(), eq true);
+    }
+
+    #[conformance_test]
+    pub fn wait_returns_shutdown_request<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .enable_shutdown_requests(true)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(request_shutdown(&node), is_ok);
+
+        assert_that!(node.wait(Duration::ZERO).err(), eq Some(NodeWaitFailure::ShutdownRequest));
+        assert_that!(node.wait(Duration::ZERO).err(), eq Some(NodeWaitFailure::ShutdownRequest));
+    }
+
+    #[conformance_test]
+    pub fn wait_returns_as_soon_as_the_shutdown_is_requested<S: Service>() {
+        let _watch_dog = Watchdog::new_with_timeout(Duration::from_secs(30));
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .enable_shutdown_requests(true)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        thread_scope(|s| {
+            s.thread_builder().spawn(|| {
+                nanosleep(Duration::from_millis(100)).unwrap();
+                Node::<S>::list(&config, |node_state| {
+                    if let NodeState::Alive(view) = node_state {
+                        assert_that!(view.request_shutdown(), is_ok);
+                    }
+                    CallbackProgression::Continue
+                })
+                .unwrap();
+            })?;
+
+            assert_that!(node.wait(Duration::from_secs(3600)).err(), eq Some(NodeWaitFailure::ShutdownRequest));
+
+            Ok(())
+        })
+        .expect("failed to execute thread scope");
+    }
+
+    #[conformance_test]
+    pub fn shutdown_request_wakes_up_waitset<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .enable_shutdown_requests(true)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        let waitset = WaitSetBuilder::new().create::<S>().unwrap();
+        let guard = waitset
+            .attach_notification(node.control_channel().unwrap())
+            .unwrap();
+
+        assert_that!(request_shutdown(&node), is_ok);
+
+        let mut has_shutdown_request = false;
+        let result = waitset.wait_and_process_once_with_timeout(
+            |attachment_id| {
+                if attachment_id.has_event_from(&guard) {
+                    has_shutdown_request = node.has_shutdown_request();
+                }
+                CallbackProgression::Continue
+            },
+            Duration::from_secs(10),
+        );
+
+        assert_that!(result, eq Ok(WaitSetRunResult::AllEventsHandled));
+        assert_that!(has_shutdown_request, eq true);
+    }
+
+    #[conformance_test]
+    pub fn dropped_node_does_not_accept_shutdown_requests<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .enable_shutdown_requests(true)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        let node_id = *node.id();
+
+        let mut views = vec![];
+        Node::<S>::list(&config, |node_state| {
+            if let NodeState::Alive(view) = node_state {
+                if *view.id() == node_id {
+                    views.push(view);
+                }
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        drop(node);
+
+        assert_that!(views, len 1);
+        assert_that!(views[0].request_shutdown(), eq Err(NodeShutdownRequestFailure::ShutdownRequestsNotEnabled));
+    }
 }
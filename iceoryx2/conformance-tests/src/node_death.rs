@@ -18,9 +18,10 @@ use alloc::string::ToString;
 use alloc::vec;
 use alloc::vec::Vec;
 use iceoryx2::config::Config;
-use iceoryx2::node::{CleanupState, NodeState};
+use iceoryx2::node::{CleanupState, NodeState, NodeView, NodeWatcher};
 use iceoryx2::prelude::*;
-use iceoryx2::service::Service;
+use iceoryx2::service::messaging_pattern::MessagingPattern;
+use iceoryx2::service::{Service, ServiceRemoveError, ServiceRemovePolicy};
 use iceoryx2::testing::*;
 use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
 use iceoryx2_bb_testing::watchdog::Watchdog;
@@ -133,7 +134,12 @@ impl Test for ZeroCopy {
 #[allow(clippy::module_inception)]
 #[conformance_tests]
 pub mod node_death {
+    use alloc::sync::Arc;
+    use iceoryx2::port::{DegradationAction, DegradationCause, ReceiveError};
+    use iceoryx2::waitset::WaitSetRunResult;
+    use iceoryx2_bb_concurrency::atomic::{AtomicUsize, Ordering};
     use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
+    use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
 
     use super::*;
 
@@ -171,6 +177,47 @@ pub mod node_death {
         }
     }
 
+    #[conformance_test]
+    pub fn node_watcher_reports_dead_node_until_it_is_cleaned_up<S: Test>() {
+        let test = S::new();
+        let mut sut = NodeWatcher::<S::Service>::new(test.config());
+
+        let dead_node = test.create_bad_node();
+        let dead_node_id = *dead_node.id();
+        S::leak(dead_node);
+
+        for _ in 0..2 {
+            let mut dead_nodes = vec![];
+            let result = sut.update(|node_state| {
+                if let NodeState::Dead(view) = node_state {
+                    dead_nodes.push(view);
+                }
+                CallbackProgression::Continue
+            });
+
+            assert_that!(result, is_ok);
+            assert_that!(dead_nodes, len 1);
+            assert_that!(*dead_nodes[0].id(), eq dead_node_id);
+            assert_that!(dead_nodes[0].details(), is_some);
+        }
+
+        let mut dead_nodes = vec![];
+        sut.update(|node_state| {
+            if let NodeState::Dead(view) = node_state {
+                dead_nodes.push(view);
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(
+            dead_nodes.pop().unwrap().try_remove_stale_resources(),
+            is_ok
+        );
+
+        assert_that!(sut.update(|_| CallbackProgression::Continue), is_ok);
+        assert_that!(sut.is_empty(), eq true);
+    }
+
     #[conformance_test]
     pub fn dead_node_is_removed_from_pub_sub_service<S: Test>() {
         test_requires!(does_support_persistency::<S>());
@@ -604,6 +651,58 @@ pub mod node_death {
         );
     }
 
+    #[conformance_test]
+    pub fn service_of_dead_node_can_be_removed_without_force<S: Test>() {
+        test_requires!(does_support_persistency::<S>());
+
+        let test = S::new();
+        let service_name = generate_service_name();
+
+        let bad_node = test.create_bad_node();
+        let bad_service = bad_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let bad_publisher = bad_service.publisher_builder().create().unwrap();
+        S::leak(bad_node);
+        S::leak(bad_publisher);
+        S::leak(bad_service);
+
+        assert_that!(S::Service::remove(&service_name, test.config(), MessagingPattern::PublishSubscribe, ServiceRemovePolicy::OnlyWithoutAliveNodes), eq Ok(()));
+        assert_that!(S::Service::does_exist(&service_name, test.config(), MessagingPattern::PublishSubscribe), eq Ok(false));
+
+        assert_that!(Node::<S::Service>::try_cleanup_dead_nodes(test.config()), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+        assert_that!(test.number_of_nodes(), eq 0);
+    }
+
+    #[conformance_test]
+    pub fn service_of_dead_and_alive_node_can_be_removed_only_with_force<S: Test>() {
+        let test = S::new();
+        let service_name = generate_service_name();
+
+        let bad_node = test.create_bad_node();
+        let node = test.create_good_node();
+        let bad_service = bad_node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        S::leak(bad_node);
+        S::leak(bad_service);
+
+        let service = node.service_builder(&service_name).event().open().unwrap();
+
+        assert_that!(S::Service::remove(&service_name, test.config(), MessagingPattern::Event, ServiceRemovePolicy::OnlyWithoutAliveNodes), eq Err(ServiceRemoveError::InUse));
+        assert_that!(S::Service::does_exist(&service_name, test.config(), MessagingPattern::Event), eq Ok(true));
+
+        assert_that!(S::Service::remove(&service_name, test.config(), MessagingPattern::Event, ServiceRemovePolicy::Force), eq Ok(()));
+        assert_that!(S::Service::does_exist(&service_name, test.config(), MessagingPattern::Event), eq Ok(false));
+
+        drop(service);
+        assert_that!(Node::<S::Service>::try_cleanup_dead_nodes(test.config()), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+    }
+
     #[conformance_test]
     pub fn request_response_service_is_removed_when_last_node_dies<S: Test>() {
         let test = S::new();
@@ -1042,4 +1141,223 @@ pub mod node_death {
             },
         );
     }
+
+    #[conformance_test]
+    pub fn subscriber_reports_liveliness_loss_of_publisher_of_dead_node<S: Test>() {
+        test_requires!(does_support_persistency::<S>());
+
+        let test = S::new();
+        let service_name = generate_service_name();
+
+        let dead_node = test.create_bad_node();
+        let node = test.create_good_node();
+
+        let dead_service = dead_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let dead_publisher = dead_service.publisher_builder().create().unwrap();
+        let dead_publisher_id = dead_publisher.id().value();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let _publisher = service.publisher_builder().create().unwrap();
+
+        let lost_publishers = Arc::new(AtomicUsize::new(0));
+        let lost_publishers_in_handler = lost_publishers.clone();
+        let subscriber = service
+            .subscriber_builder()
+            .set_degradation_handler(move |cause, info| {
+                if matches!(cause, DegradationCause::PublisherLivelinessLost) {
+                    assert_that!(info.sender_port_id, eq dead_publisher_id);
+                    lost_publishers_in_handler.fetch_add(1, Ordering::Relaxed);
+                }
+                DegradationAction::Ignore
+            })
+            .create()
+            .unwrap();
+
+        S::leak(dead_node);
+        S::leak(dead_publisher);
+        S::leak(dead_service);
+
+        assert_that!(Node::<S::Service>::try_cleanup_dead_nodes(test.config()), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(lost_publishers.load(Ordering::Relaxed), eq 1);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(lost_publishers.load(Ordering::Relaxed), eq 1);
+    }
+
+    #[conformance_test]
+    pub fn dead_copy_on_send_publisher_is_removed_and_subscriber_stays_functional<S: Test>() {
+        test_requires!(does_support_persistency::<S>());
+
+        let test = S::new();
+        let service_name = generate_service_name();
+
+        let dead_node = test.create_bad_node();
+        let node = test.create_good_node();
+
+        let dead_service = dead_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_copy_on_send(true)
+            .create()
+            .unwrap();
+        let dead_publisher = dead_service.publisher_builder().create().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_copy_on_send(true)
+            .open()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        assert_that!(dead_publisher.send_copy(123), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 123);
+
+        S::leak(dead_node);
+        S::leak(dead_publisher);
+        S::leak(dead_service);
+
+        assert_that!(Node::<S::Service>::try_cleanup_dead_nodes(test.config()), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+
+        let publisher = service.publisher_builder().create().unwrap();
+        assert_that!(publisher.send_copy(456), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 456);
+    }
+
+    #[conformance_test]
+    pub fn subscriber_does_not_report_liveliness_loss_of_dropped_publisher<S: Test>() {
+        let test = S::new();
+        let service_name = generate_service_name();
+        let node = test.create_good_node();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let lost_publishers = Arc::new(AtomicUsize::new(0));
+        let lost_publishers_in_handler = lost_publishers.clone();
+        let subscriber = service
+            .subscriber_builder()
+            .set_degradation_handler(move |cause, _| {
+                if matches!(cause, DegradationCause::PublisherLivelinessLost) {
+                    lost_publishers_in_handler.fetch_add(1, Ordering::Relaxed);
+                }
+                DegradationAction::Ignore
+            })
+            .create()
+            .unwrap();
+
+        drop(publisher);
+
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(lost_publishers.load(Ordering::Relaxed), eq 0);
+    }
+
+    #[conformance_test]
+    pub fn receive_fails_on_liveliness_loss_when_degradation_handler_fails<S: Test>() {
+        test_requires!(does_support_persistency::<S>());
+
+        let test = S::new();
+        let service_name = generate_service_name();
+
+        let dead_node = test.create_bad_node();
+        let node = test.create_good_node();
+
+        let dead_service = dead_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let dead_publisher = dead_service.publisher_builder().create().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let subscriber = service
+            .subscriber_builder()
+            .set_degradation_handler(|_, _| DegradationAction::DegradeAndFail)
+            .create()
+            .unwrap();
+
+        S::leak(dead_node);
+        S::leak(dead_publisher);
+        S::leak(dead_service);
+
+        assert_that!(Node::<S::Service>::try_cleanup_dead_nodes(test.config()), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+
+        assert_that!(subscriber.receive().err(), eq Some(ReceiveError::PublisherLivelinessLost));
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn subscriber_with_wakeup_is_woken_up_when_publisher_node_dies<S: Test>()
+    where
+        <<S::Service as Service>::Event as iceoryx2_cal::event::Event>::Listener:
+            SynchronousMultiplexing,
+    {
+        test_requires!(does_support_persistency::<S>());
+
+        let test = S::new();
+        let service_name = generate_service_name();
+
+        let dead_node = test.create_bad_node();
+        let node = test.create_good_node();
+
+        let dead_service = dead_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_subscriber_wakeup(true)
+            .create()
+            .unwrap();
+        let dead_publisher = dead_service.publisher_builder().create().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let subscriber = service
+            .subscriber_builder()
+            .set_degradation_handler(|_, _| DegradationAction::DegradeAndFail)
+            .create_with_wakeup()
+            .unwrap();
+
+        let waitset = WaitSetBuilder::new().create::<S::Service>().unwrap();
+        let guard = waitset.attach_notification(&subscriber).unwrap();
+
+        S::leak(dead_node);
+        S::leak(dead_publisher);
+        S::leak(dead_service);
+
+        assert_that!(Node::<S::Service>::try_cleanup_dead_nodes(test.config()), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+
+        let mut receive_result = Ok(None);
+        let result = waitset.wait_and_process_once_with_timeout(
+            |attachment_id| {
+                if attachment_id.has_event_from(&guard) {
+                    receive_result = subscriber.receive().map(|sample| sample.map(|s| *s));
+                }
+                CallbackProgression::Continue
+            },
+            Duration::from_secs(10),
+        );
+
+        assert_that!(result, eq Ok(WaitSetRunResult::AllEventsHandled));
+        assert_that!(receive_result, eq Err(ReceiveError::PublisherLivelinessLost));
+    }
 }
@@ -31,10 +31,11 @@ pub mod publisher {
     use iceoryx2_bb_posix::clock::{Time, nanosleep};
     use iceoryx2_bb_posix::mutex::{MutexBuilder, MutexHandle};
     use iceoryx2_bb_posix::thread::thread_scope;
-    use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_bb_testing::{assert_that, test_requires};
     use iceoryx2_bb_testing_macros::conformance_test;
+    use iceoryx2_pal_posix::posix::POSIX_SUPPORT_MEMORY_LOCK;
 
     const TIMEOUT: Duration = Duration::from_millis(25);
 
@@ -857,4 +858,60 @@ pub mod publisher {
 
         Ok(())
     }
+
+    #[conformance_test]
+    pub fn publisher_with_locked_and_prefaulted_data_segment_works<Sut: Service>() {
+        test_requires!(POSIX_SUPPORT_MEMORY_LOCK);
+
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .is_memory_locked(true)
+            .prefault(true)
+            .use_huge_pages(true)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(8127).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 8127);
+    }
+
+    #[conformance_test]
+    pub fn publisher_memory_options_default_is_taken_from_config<Sut: Service>() {
+        test_requires!(POSIX_SUPPORT_MEMORY_LOCK);
+
+        let service_name = generate_service_name();
+        let mut config = testing::generate_isolated_config();
+        config.defaults.publish_subscribe.publisher_is_memory_locked = true;
+        config.defaults.publish_subscribe.publisher_prefault = true;
+        config.defaults.publish_subscribe.publisher_use_huge_pages = true;
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(4)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+
+        let sample = publisher.loan_slice(16).unwrap();
+        assert_that!(sample.payload().len(), eq 16);
+    }
 }
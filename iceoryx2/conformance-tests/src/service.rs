@@ -29,14 +29,18 @@ pub mod service {
     use iceoryx2::service::builder::request_response::{
         RequestResponseCreateError, RequestResponseOpenError,
     };
+    use iceoryx2::service::lifetime::ServiceLifetime;
     use iceoryx2::service::messaging_pattern::MessagingPattern;
     use iceoryx2::service::port_factory::{blackboard, event, publish_subscribe, request_response};
-    use iceoryx2::service::{ServiceDetailsError, ServiceListError};
+    use iceoryx2::service::{
+        ServiceDetailsError, ServiceListError, ServiceRemoveError, ServiceRemovePolicy,
+    };
     use iceoryx2::testing::generate_service_name;
     use iceoryx2::testing::*;
     use iceoryx2_bb_concurrency::atomic::AtomicU64;
     use iceoryx2_bb_concurrency::atomic::Ordering;
     use iceoryx2_bb_posix::barrier::{BarrierBuilder, BarrierHandle};
+    use iceoryx2_bb_posix::clock::nanosleep;
     use iceoryx2_bb_posix::ipc_capable::Handle;
     use iceoryx2_bb_posix::system_configuration::SystemInfo;
     use iceoryx2_bb_posix::thread::thread_scope;
@@ -63,6 +67,12 @@ pub mod service {
             service_name: &ServiceName,
             attributes: &AttributeVerifier,
         ) -> Result<Self::Factory, Self::OpenError>;
+        fn create_with_lifetime(
+            &self,
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            lifetime: ServiceLifetime,
+        ) -> Result<Self::Factory, Self::CreateError>;
         fn messaging_pattern() -> MessagingPattern;
 
         fn assert_create_error(error: Self::CreateError);
@@ -131,6 +141,18 @@ pub mod service {
                 .create_with_attributes(attributes)
         }
 
+        fn create_with_lifetime(
+            &self,
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            lifetime: ServiceLifetime,
+        ) -> Result<Self::Factory, Self::CreateError> {
+            node.service_builder(service_name)
+                .publish_subscribe::<u64>()
+                .lifetime(lifetime)
+                .create()
+        }
+
         fn assert_attribute_error(error: Self::OpenError) {
             assert_that!(error, eq PublishSubscribeOpenError::IncompatibleAttributes);
         }
@@ -197,6 +219,18 @@ pub mod service {
                 .create_with_attributes(attributes)
         }
 
+        fn create_with_lifetime(
+            &self,
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            lifetime: ServiceLifetime,
+        ) -> Result<Self::Factory, Self::CreateError> {
+            node.service_builder(service_name)
+                .event()
+                .lifetime(lifetime)
+                .create()
+        }
+
         fn assert_attribute_error(error: Self::OpenError) {
             assert_that!(error, eq EventOpenError::IncompatibleAttributes);
         }
@@ -263,6 +297,18 @@ pub mod service {
                 .create_with_attributes(attributes)
         }
 
+        fn create_with_lifetime(
+            &self,
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            lifetime: ServiceLifetime,
+        ) -> Result<Self::Factory, Self::CreateError> {
+            node.service_builder(service_name)
+                .request_response::<u64, u64>()
+                .lifetime(lifetime)
+                .create()
+        }
+
         fn assert_attribute_error(error: Self::OpenError) {
             assert_that!(error, eq RequestResponseOpenError::IncompatibleAttributes);
         }
@@ -330,6 +376,19 @@ pub mod service {
                 .create_with_attributes(attributes)
         }
 
+        fn create_with_lifetime(
+            &self,
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            lifetime: ServiceLifetime,
+        ) -> Result<Self::Factory, Self::CreateError> {
+            node.service_builder(service_name)
+                .blackboard_creator::<u64>()
+                .lifetime(lifetime)
+                .add::<u32>(0, 0)
+                .create()
+        }
+
         fn assert_attribute_error(error: Self::OpenError) {
             assert_that!(error, eq BlackboardOpenError::IncompatibleAttributes);
         }
@@ -1240,4 +1299,231 @@ pub mod service {
 
         assert_that!(sut.service_hash(), eq sut2.service_hash());
     }
+
+    #[conformance_test]
+    pub fn by_default_service_is_transient<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        {
+            let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+            let sut = test
+                .create(&node, &service_name, &AttributeSpecifier::new())
+                .unwrap();
+            assert_that!(*sut.lifetime(), eq ServiceLifetime::Transient);
+        }
+
+        assert_that!(Sut::does_exist(&service_name, &config, Factory::messaging_pattern()), eq Ok(false));
+    }
+
+    #[conformance_test]
+    pub fn persistent_service_outlives_its_nodes<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        {
+            let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+            let sut = test
+                .create_with_lifetime(&node, &service_name, ServiceLifetime::Persistent)
+                .unwrap();
+            assert_that!(*sut.lifetime(), eq ServiceLifetime::Persistent);
+        }
+
+        assert_that!(Sut::does_exist(&service_name, &config, Factory::messaging_pattern()), eq Ok(true));
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = test.open(&node, &service_name, &AttributeVerifier::new());
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(*sut.lifetime(), eq ServiceLifetime::Persistent);
+        drop(sut);
+        assert_that!(Sut::does_exist(&service_name, &config, Factory::messaging_pattern()), eq Ok(true));
+
+        assert_that!(
+            Sut::remove_unused(&service_name, &config, Factory::messaging_pattern()),
+            is_ok
+        );
+    }
+
+    #[conformance_test]
+    pub fn persistent_service_cannot_be_removed_while_in_use<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = test
+            .create_with_lifetime(&node, &service_name, ServiceLifetime::Persistent)
+            .unwrap();
+
+        assert_that!(Sut::remove_unused(&service_name, &config, Factory::messaging_pattern()), eq Err(ServiceRemoveError::InUse));
+
+        drop(sut);
+        assert_that!(Sut::remove_unused(&service_name, &config, Factory::messaging_pattern()), eq Ok(()));
+        assert_that!(Sut::does_exist(&service_name, &config, Factory::messaging_pattern()), eq Ok(false));
+        assert_that!(Sut::remove_unused(&service_name, &config, Factory::messaging_pattern()), eq Err(ServiceRemoveError::DoesNotExist));
+
+        let sut = test.create(&node, &service_name, &AttributeSpecifier::new());
+        assert_that!(sut, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn remove_fails_when_service_does_not_exist<Sut: Service, Factory: SutFactory<Sut>>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        assert_that!(Sut::remove(&service_name, &config, Factory::messaging_pattern(), ServiceRemovePolicy::OnlyWithoutAliveNodes), eq Err(ServiceRemoveError::DoesNotExist));
+        assert_that!(Sut::remove(&service_name, &config, Factory::messaging_pattern(), ServiceRemovePolicy::Force), eq Err(ServiceRemoveError::DoesNotExist));
+    }
+
+    #[conformance_test]
+    pub fn remove_without_force_fails_while_alive_node_uses_service<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+
+        assert_that!(Sut::remove(&service_name, &config, Factory::messaging_pattern(), ServiceRemovePolicy::OnlyWithoutAliveNodes), eq Err(ServiceRemoveError::InUse));
+        assert_that!(Sut::does_exist(&service_name, &config, Factory::messaging_pattern()), eq Ok(true));
+
+        drop(sut);
+        assert_that!(Sut::does_exist(&service_name, &config, Factory::messaging_pattern()), eq Ok(false));
+    }
+
+    #[conformance_test]
+    pub fn remove_removes_unused_persistent_service<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = test
+            .create_with_lifetime(&node, &service_name, ServiceLifetime::Persistent)
+            .unwrap();
+        drop(sut);
+
+        assert_that!(Sut::remove(&service_name, &config, Factory::messaging_pattern(), ServiceRemovePolicy::OnlyWithoutAliveNodes), eq Ok(()));
+        assert_that!(Sut::does_exist(&service_name, &config, Factory::messaging_pattern()), eq Ok(false));
+
+        let sut = test.create(&node, &service_name, &AttributeSpecifier::new());
+        assert_that!(sut, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn remove_with_force_removes_service_used_by_alive_node<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+
+        assert_that!(Sut::remove(&service_name, &config, Factory::messaging_pattern(), ServiceRemovePolicy::Force), eq Ok(()));
+        assert_that!(Sut::does_exist(&service_name, &config, Factory::messaging_pattern()), eq Ok(false));
+
+        drop(sut);
+        assert_that!(Sut::does_exist(&service_name, &config, Factory::messaging_pattern()), eq Ok(false));
+    }
+
+    #[conformance_test]
+    pub fn service_with_expired_time_to_live_is_removed_by_dead_node_cleanup<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = test
+            .create_with_lifetime(
+                &node,
+                &service_name,
+                ServiceLifetime::TimeToLive(Duration::ZERO.into()),
+            )
+            .unwrap();
+        assert_that!(sut.lifetime().time_to_live(), eq Some(Duration::ZERO));
+        drop(sut);
+
+        assert_that!(Sut::does_exist(&service_name, &config, Factory::messaging_pattern()), eq Ok(true));
+
+        Node::<Sut>::try_cleanup_dead_nodes(&config);
+
+        assert_that!(Sut::does_exist(&service_name, &config, Factory::messaging_pattern()), eq Ok(false));
+    }
+
+    #[conformance_test]
+    pub fn service_with_sub_second_time_to_live_is_removed_when_expired<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        const TIME_TO_LIVE: Duration = Duration::from_millis(500);
+        let test = Factory::new();
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = test
+            .create_with_lifetime(
+                &node,
+                &service_name,
+                ServiceLifetime::TimeToLive(TIME_TO_LIVE.into()),
+            )
+            .unwrap();
+        drop(sut);
+
+        Node::<Sut>::try_cleanup_dead_nodes(&config);
+        assert_that!(Sut::does_exist(&service_name, &config, Factory::messaging_pattern()), eq Ok(true));
+
+        nanosleep(TIME_TO_LIVE).unwrap();
+
+        Node::<Sut>::try_cleanup_dead_nodes(&config);
+        assert_that!(Sut::does_exist(&service_name, &config, Factory::messaging_pattern()), eq Ok(false));
+    }
+
+    #[conformance_test]
+    pub fn service_with_pending_time_to_live_is_not_removed_by_dead_node_cleanup<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        {
+            let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+            let _sut = test
+                .create_with_lifetime(
+                    &node,
+                    &service_name,
+                    ServiceLifetime::TimeToLive(Duration::from_secs(3600).into()),
+                )
+                .unwrap();
+        }
+
+        Node::<Sut>::try_cleanup_dead_nodes(&config);
+
+        assert_that!(Sut::does_exist(&service_name, &config, Factory::messaging_pattern()), eq Ok(true));
+        assert_that!(
+            Sut::remove_unused(&service_name, &config, Factory::messaging_pattern()),
+            is_ok
+        );
+    }
 }
@@ -15,37 +15,61 @@ use iceoryx2_bb_testing_macros::conformance_tests;
 #[allow(clippy::module_inception)]
 #[conformance_tests]
 pub mod service_publish_subscribe {
-    use alloc::{format, sync::Arc, vec, vec::Vec};
+    use alloc::{
+        format,
+        string::{String, ToString},
+        sync::Arc,
+        vec,
+        vec::Vec,
+    };
     use core::time::Duration;
 
     use iceoryx2::config::Config;
     use iceoryx2::port::publisher::PublisherCreateError;
     use iceoryx2::port::subscriber::SubscriberCreateError;
     use iceoryx2::port::update_connections::UpdateConnections;
-    use iceoryx2::port::{LoanError, SendError, UnableToDeliverAction};
+    use iceoryx2::port::{
+        DegradationAction, DegradationCause, LoanError, ReceiveDeserializedError, ReceiveError,
+        SendError, SendSerializedError, UnableToDeliverAction,
+    };
     use iceoryx2::prelude::{AllocationStrategy, *};
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
     use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
+    use iceoryx2::service::header::TraceContext;
+    use iceoryx2::service::lifetime::ServiceLifetime;
     use iceoryx2::service::messaging_pattern::MessagingPattern;
     use iceoryx2::service::port_factory::publisher::PortFactoryPublisher;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+    use iceoryx2::service::static_config::publish_subscribe::MAX_NUMBER_OF_PRIORITIES;
+    use iceoryx2::service::static_config::type_schema::{SchemaCompatibility, SchemaValue};
     use iceoryx2::service::{Service, ServiceDetails};
     use iceoryx2::testing;
     use iceoryx2::testing::generate_service_name;
+    use iceoryx2::waitset::WaitSetRunResult;
     use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
     use iceoryx2_bb_derive_macros::ZeroCopySend;
     use iceoryx2_bb_elementary::CallbackProgression;
     use iceoryx2_bb_elementary::alignment::Alignment;
     use iceoryx2_bb_posix::barrier::{BarrierBuilder, BarrierHandle};
     use iceoryx2_bb_posix::clock::{Time, nanosleep};
+    use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
     use iceoryx2_bb_posix::ipc_capable::Handle;
     use iceoryx2_bb_posix::mutex::{MutexBuilder, MutexHandle};
+    use iceoryx2_bb_posix::testing::is_memory_writable;
     use iceoryx2_bb_posix::thread::thread_scope;
-    use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_bb_testing::{assert_that, test_requires};
     use iceoryx2_bb_testing_macros::conformance_test;
+    use iceoryx2_cal::serialize::postcard::Postcard;
     use iceoryx2_log::{LogLevel, set_log_level};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct NonZeroCopyPayload {
+        name: String,
+        values: Vec<u64>,
+    }
 
     #[derive(Debug, ZeroCopySend)]
     #[repr(C)]
@@ -439,6 +463,148 @@ pub mod service_publish_subscribe {
         );
     }
 
+    #[conformance_test]
+    pub fn open_fails_when_service_does_not_satisfy_safe_overflow_for_priority_requirement<
+        Sut: Service,
+    >() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .number_of_priorities(2)
+            .enable_safe_overflow(true)
+            .enable_safe_overflow_for_priority(1, false)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow_for_priority(1, true)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::IncompatibleOverflowBehavior
+        );
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow_for_priority(0, true)
+            .enable_safe_overflow_for_priority(1, false)
+            .open();
+
+        assert_that!(sut3, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn open_fails_when_service_does_not_satisfy_number_of_priorities_requirement<
+        Sut: Service,
+    >() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .number_of_priorities(3)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .number_of_priorities(4)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::DoesNotSupportRequestedNumberOfPriorities
+        );
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .number_of_priorities(2)
+            .open();
+
+        assert_that!(sut3, is_ok);
+        assert_that!(sut3.unwrap().static_config().number_of_priorities(), eq 3);
+    }
+
+    #[conformance_test]
+    pub fn open_fails_when_service_does_not_satisfy_subscriber_wakeup_requirement<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_subscriber_wakeup(true)
+            .create();
+        assert_that!(sut, is_ok);
+        assert_that!(sut.as_ref().unwrap().static_config().has_subscriber_wakeup(), eq true);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_subscriber_wakeup(false)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::IncompatibleSubscriberWakeup
+        );
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_subscriber_wakeup(true)
+            .open();
+
+        assert_that!(sut3, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn open_fails_when_service_does_not_satisfy_copy_on_send_requirement<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_copy_on_send(true)
+            .create();
+        assert_that!(sut, is_ok);
+        assert_that!(sut.as_ref().unwrap().static_config().has_copy_on_send(), eq true);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_copy_on_send(false)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::IncompatibleCopyOnSend
+        );
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_copy_on_send(true)
+            .open();
+
+        assert_that!(sut3, is_ok);
+    }
+
     #[conformance_test]
     pub fn open_fails_when_service_does_not_satisfy_history_requirement<Sut: Service>() {
         let service_name = generate_service_name();
@@ -1560,29 +1726,45 @@ pub mod service_publish_subscribe {
     }
 
     #[conformance_test]
-    pub fn publish_non_overflow_with_greater_history_than_buffer_fails<Sut: Service>() {
+    pub fn samples_with_higher_priority_are_received_first<Sut: Service>() {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const BUFFER_SIZE: usize = 4;
 
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<usize>()
-            .enable_safe_overflow(false)
-            .history_size(12)
-            .subscriber_max_buffer_size(11)
-            .create();
+            .number_of_priorities(3)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
 
-        assert_that!(sut, is_err);
-        assert_that!(
-            sut.err().unwrap(), eq
-            PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize
-        );
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for priority in 0..3 {
+            for i in 0..BUFFER_SIZE {
+                let mut sample = publisher.loan().unwrap();
+                sample.set_priority(priority);
+                *sample.payload_mut() = priority as usize * 100 + i;
+                assert_that!(sample.send(), is_ok);
+            }
+        }
+
+        for priority in (0..3).rev() {
+            for i in 0..BUFFER_SIZE {
+                let sample = subscriber.receive().unwrap().unwrap();
+                assert_that!(sample.header().priority(), eq priority);
+                assert_that!(*sample, eq priority as usize * 100 + i);
+            }
+        }
+
+        assert_that!(subscriber.receive().unwrap(), is_none);
     }
 
     #[conformance_test]
-    pub fn publish_history_is_delivered_on_subscription<Sut: Service>() {
-        const BUFFER_SIZE: usize = 2;
+    pub fn publisher_default_priority_is_applied_to_samples<Sut: Service>() {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
@@ -1590,1901 +1772,3227 @@ pub mod service_publish_subscribe {
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<usize>()
-            .history_size(3)
-            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .number_of_priorities(2)
             .create()
             .unwrap();
 
-        let sut_publisher = sut.publisher_builder().create().unwrap();
-        assert_that!(sut_publisher.send_copy(29), is_ok);
-        assert_that!(sut_publisher.send_copy(32), is_ok);
-        assert_that!(sut_publisher.send_copy(35), is_ok);
+        let low_priority_publisher = sut.publisher_builder().create().unwrap();
+        let high_priority_publisher = sut.publisher_builder().priority(1).create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        let sut_subscriber = sut.subscriber_builder().create().unwrap();
-        assert_that!(sut_publisher.update_connections(), is_ok);
+        assert_that!(low_priority_publisher.send_copy(12), is_ok);
+        assert_that!(high_priority_publisher.send_copy(34), is_ok);
 
-        for i in 0..BUFFER_SIZE {
-            let data = sut_subscriber.receive().unwrap();
-            assert_that!(data, is_some);
-            assert_that!(*data.unwrap(), eq 29 + (i + 1) * 3 )
-        }
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 34);
+        assert_that!(sample.header().priority(), eq 1);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 12);
+        assert_that!(sample.header().priority(), eq 0);
     }
 
     #[conformance_test]
-    pub fn publish_history_of_zero_works<Sut: Service>() {
-        const BUFFER_SIZE: usize = 2;
+    pub fn trace_context_of_sample_is_delivered_to_subscriber<Sut: Service>() {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let trace_context = TraceContext {
+            trace_id: [7; 16],
+            span_id: [3; 8],
+            trace_flags: 1,
+        };
 
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<usize>()
-            .history_size(0)
-            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_buffer_size(2)
             .create()
             .unwrap();
 
-        let sut_publisher = sut.publisher_builder().create().unwrap();
-        assert_that!(sut_publisher.send_copy(29), is_ok);
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        let sut_subscriber = sut.subscriber_builder().create().unwrap();
-        assert_that!(sut_publisher.update_connections(), is_ok);
+        assert_that!(publisher.send_copy(12), is_ok);
 
-        let data = sut_subscriber.receive().unwrap();
-        assert_that!(data, is_none);
+        let mut sample = publisher.loan().unwrap();
+        sample.set_trace_context(trace_context);
+        *sample.payload_mut() = 34;
+        assert_that!(sample.send(), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 12);
+        assert_that!(sample.header().trace_context(), is_none);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 34);
+        assert_that!(sample.header().trace_context(), eq Some(trace_context));
     }
 
     #[conformance_test]
-    pub fn publish_send_copy_with_huge_overflow_works<Sut: Service>() {
+    pub fn unsupported_priority_is_reduced_to_highest_supported_priority<Sut: Service>() {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        const BUFFER_SIZE: usize = 5;
 
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<usize>()
-            .max_publishers(1)
-            .max_subscribers(2)
-            .history_size(0)
+            .number_of_priorities(2)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().priority(200).create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan().unwrap();
+        assert_that!(sample.header().priority(), eq 1);
+        sample.set_priority(7);
+        assert_that!(sample.header().priority(), eq 1);
+        *sample.payload_mut() = 56;
+        assert_that!(sample.send(), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 56);
+        assert_that!(sample.header().priority(), eq 1);
+    }
+
+    #[conformance_test]
+    pub fn safe_overflow_can_be_configured_per_priority<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const BUFFER_SIZE: usize = 2;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .number_of_priorities(2)
+            .enable_safe_overflow(true)
+            .enable_safe_overflow_for_priority(1, false)
             .subscriber_max_buffer_size(BUFFER_SIZE)
-            .subscriber_max_borrowed_samples(1)
             .create()
             .unwrap();
 
-        let sut_publisher = sut
+        assert_that!(sut.static_config().has_safe_overflow_for_priority(0), eq true);
+        assert_that!(sut.static_config().has_safe_overflow_for_priority(1), eq false);
+
+        let low_priority_publisher = sut.publisher_builder().create().unwrap();
+        let high_priority_publisher = sut
             .publisher_builder()
-            .max_loaned_samples(1)
+            .priority(1)
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardData)
             .create()
             .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        let mut subscribers = vec![];
-        for _ in 0..2 {
-            let sut_subscriber = sut.subscriber_builder().create();
-            subscribers.push(sut_subscriber);
+        for i in 0..2 * BUFFER_SIZE {
+            assert_that!(low_priority_publisher.send_copy(i), is_ok);
+            assert_that!(high_priority_publisher.send_copy(100 + i), is_ok);
         }
 
-        for _ in 0..BUFFER_SIZE * 100 {
-            assert_that!(sut_publisher.send_copy(889), is_ok);
+        for i in 0..BUFFER_SIZE {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq 100 + i);
         }
-    }
 
-    const VALUE_FIRST_SAMPLE: usize = 1;
-    const VALUE_SECOND_SAMPLE: usize = 2;
+        for i in BUFFER_SIZE..2 * BUFFER_SIZE {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq i);
+        }
 
-    fn publisher_with_unable_to_deliver_handler<Sut, PublisherBuilder>(
-        save_overflow: bool,
-        publisher_builder: PublisherBuilder,
-        expected_second_send_result: Result<usize, SendError>,
-        expected_receive_value_subscriber_1: Option<usize>,
-        expected_receive_value_subscriber_2: Option<usize>,
-    ) -> Duration
-    where
-        Sut: Service,
-        PublisherBuilder: Fn(
-            PortFactoryPublisher<'_, Sut, usize, ()>,
-        ) -> PortFactoryPublisher<'_, Sut, usize, ()>,
-    {
-        let _watchdog = Watchdog::new();
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
 
+    #[conformance_test]
+    pub fn subscriber_buffer_size_applies_to_every_priority<Sut: Service>() {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const BUFFER_SIZE: usize = 2;
 
-        let service = node
+        let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<usize>()
-            .max_publishers(1)
-            .max_subscribers(2)
-            .history_size(0)
-            .enable_safe_overflow(save_overflow)
-            .subscriber_max_buffer_size(1)
-            .subscriber_max_borrowed_samples(2)
+            .number_of_priorities(2)
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
             .create()
             .unwrap();
 
-        let publisher_port_factory = service.publisher_builder().max_loaned_samples(1);
+        let low_priority_publisher = sut.publisher_builder().create().unwrap();
+        let high_priority_publisher = sut.publisher_builder().priority(1).create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        let sut_publisher = publisher_builder(publisher_port_factory).create().unwrap();
+        for i in 0..BUFFER_SIZE {
+            assert_that!(high_priority_publisher.send_copy(100 + i), is_ok);
+        }
 
-        let sut_subscriber_1 = service.subscriber_builder().create().unwrap();
-        let sut_subscriber_2 = service.subscriber_builder().create().unwrap();
+        // overflowing the low priority buffer does not displace high priority samples
+        for i in 0..BUFFER_SIZE + 1 {
+            assert_that!(low_priority_publisher.send_copy(i), is_ok);
+        }
 
-        let now = Time::now().unwrap();
+        for i in 0..BUFFER_SIZE {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq 100 + i);
+        }
 
-        assert_that!(sut_publisher.send_copy(VALUE_FIRST_SAMPLE), eq(Ok(2)));
-        assert_that!(
-            *sut_subscriber_2.receive().unwrap().unwrap(),
-            eq(VALUE_FIRST_SAMPLE)
-        );
+        for i in 1..BUFFER_SIZE + 1 {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq i);
+        }
 
-        assert_that!(
-            sut_publisher.send_copy(VALUE_SECOND_SAMPLE),
-            eq(expected_second_send_result)
-        );
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
 
-        let elapsed_blocking_time = now.elapsed().unwrap();
+    #[conformance_test]
+    pub fn subscriber_max_borrowed_samples_is_shared_by_all_priorities<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const MAX_BORROWED_SAMPLES: usize = 2;
 
-        // check receive result for subscriber 1
-        let mut receive_result = sut_subscriber_1.receive();
-        if let Some(expected_value) = expected_receive_value_subscriber_1 {
-            assert_that!(receive_result, is_ok);
-            let receive_value = receive_result.unwrap();
-            assert_that!(receive_value, is_some);
-            let sample = receive_value.unwrap();
-            assert_that!(*sample, eq(expected_value));
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .number_of_priorities(2)
+            .subscriber_max_buffer_size(MAX_BORROWED_SAMPLES)
+            .subscriber_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+            .create()
+            .unwrap();
 
-            receive_result = sut_subscriber_1.receive();
-        }
-        assert_that!(receive_result, is_ok);
-        let receive_value = receive_result.unwrap();
-        assert_that!(receive_value, is_none);
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        // check receive result for subscriber 2
-        let mut receive_result = sut_subscriber_2.receive();
-        if let Some(expected_value) = expected_receive_value_subscriber_2 {
-            assert_that!(receive_result, is_ok);
-            let receive_value = receive_result.unwrap();
-            assert_that!(receive_value, is_some);
-            let sample = receive_value.unwrap();
-            assert_that!(*sample, eq(expected_value));
+        for priority in 0..2 {
+            for i in 0..MAX_BORROWED_SAMPLES {
+                let mut sample = publisher.loan().unwrap();
+                sample.set_priority(priority);
+                *sample.payload_mut() = priority as usize * 100 + i;
+                assert_that!(sample.send(), is_ok);
+            }
+        }
 
-            receive_result = sut_subscriber_2.receive();
+        let mut borrowed_samples = vec![];
+        for i in 0..MAX_BORROWED_SAMPLES {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq 100 + i);
+            borrowed_samples.push(sample);
         }
-        assert_that!(receive_result, is_ok);
-        let receive_value = receive_result.unwrap();
-        assert_that!(receive_value, is_none);
 
-        elapsed_blocking_time
+        assert_that!(subscriber.receive().err(), eq Some(ReceiveError::ExceedsMaxBorrows));
+
+        borrowed_samples.pop();
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 0);
     }
 
     #[conformance_test]
-    pub fn publisher_with_unable_to_deliver_handler_does_not_block_with_safe_overflow<
-        Sut: Service,
-    >() {
-        const SAFE_OVERFLOW: bool = true;
-        const EXPECTED_SECOND_SEND_RESULT: Result<usize, SendError> = Ok(2);
-        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1: Option<usize> = Some(VALUE_SECOND_SAMPLE);
-        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2: Option<usize> = Some(VALUE_SECOND_SAMPLE);
+    pub fn publish_non_overflow_with_greater_history_than_buffer_fails<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let handler_call_count = Arc::new(AtomicU64::new(0));
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .enable_safe_overflow(false)
+            .history_size(12)
+            .subscriber_max_buffer_size(11)
+            .create();
 
-        publisher_with_unable_to_deliver_handler::<Sut, _>(
-            SAFE_OVERFLOW,
-            |publisher_port_factory| {
-                publisher_port_factory.set_unable_to_deliver_handler({
-                    let handler_call_count = handler_call_count.clone();
-                    move |_| {
-                        handler_call_count.fetch_add(1, Ordering::Relaxed);
-                        UnableToDeliverAction::Retry
-                    }
-                })
-            },
-            EXPECTED_SECOND_SEND_RESULT,
-            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1,
-            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2,
+        assert_that!(sut, is_err);
+        assert_that!(
+            sut.err().unwrap(), eq
+            PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize
         );
-
-        assert_that!(handler_call_count.load(Ordering::Relaxed), eq(0));
     }
 
     #[conformance_test]
-    pub fn publisher_with_unable_to_deliver_handler_discards_sample<Sut: Service>() {
-        const SAFE_OVERFLOW: bool = false;
-        const EXPECTED_SECOND_SEND_RESULT: Result<usize, SendError> = Ok(1);
-        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1: Option<usize> = Some(VALUE_FIRST_SAMPLE);
-        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2: Option<usize> = Some(VALUE_SECOND_SAMPLE);
+    pub fn publish_history_is_delivered_on_subscription<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let handler_call_count = Arc::new(AtomicU64::new(0));
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(3)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
 
-        publisher_with_unable_to_deliver_handler::<Sut, _>(
-            SAFE_OVERFLOW,
-            |publisher_port_factory| {
-                publisher_port_factory.set_unable_to_deliver_handler({
-                    let handler_call_count = handler_call_count.clone();
-                    move |_| {
-                        handler_call_count.fetch_add(1, Ordering::Relaxed);
-                        UnableToDeliverAction::DiscardData
-                    }
-                })
-            },
-            EXPECTED_SECOND_SEND_RESULT,
-            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1,
-            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2,
-        );
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher.send_copy(29), is_ok);
+        assert_that!(sut_publisher.send_copy(32), is_ok);
+        assert_that!(sut_publisher.send_copy(35), is_ok);
 
-        assert_that!(handler_call_count.load(Ordering::Relaxed), eq(1));
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+
+        for i in 0..BUFFER_SIZE {
+            let data = sut_subscriber.receive().unwrap();
+            assert_that!(data, is_some);
+            assert_that!(*data.unwrap(), eq 29 + (i + 1) * 3 )
+        }
     }
 
     #[conformance_test]
-    pub fn publisher_with_unable_to_deliver_handler_retries_twice<Sut: Service>() {
-        const SAFE_OVERFLOW: bool = false;
-        const EXPECTED_SECOND_SEND_RESULT: Result<usize, SendError> = Ok(1);
-        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1: Option<usize> = Some(VALUE_FIRST_SAMPLE);
-        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2: Option<usize> = Some(VALUE_SECOND_SAMPLE);
+    pub fn persistent_service_delivers_last_sample_of_gone_publisher_on_subscription<
+        Sut: Service,
+    >() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let handler_call_count = Arc::new(AtomicU64::new(0));
-        const RETRY_COUNT: u64 = 2;
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .lifetime(ServiceLifetime::Persistent)
+            .history_size(1)
+            .create()
+            .unwrap();
 
-        publisher_with_unable_to_deliver_handler::<Sut, _>(
-            SAFE_OVERFLOW,
-            |publisher_port_factory| {
-                publisher_port_factory.set_unable_to_deliver_handler({
-                    let handler_call_count = handler_call_count.clone();
-                    move |info| {
-                        if info.retries == RETRY_COUNT {
-                            UnableToDeliverAction::DiscardData
-                        } else {
-                            handler_call_count.fetch_add(1, Ordering::Relaxed);
-                            UnableToDeliverAction::Retry
-                        }
-                    }
-                })
-            },
-            EXPECTED_SECOND_SEND_RESULT,
-            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1,
-            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2,
-        );
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher.send_copy(71), is_ok);
+        assert_that!(sut_publisher.send_copy(73), is_ok);
+        drop(sut_publisher);
+        drop(sut);
 
-        assert_that!(handler_call_count.load(Ordering::Relaxed), eq(RETRY_COUNT));
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .open()
+            .unwrap();
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+
+        let data = sut_subscriber.receive().unwrap();
+        assert_that!(data, is_some);
+        assert_that!(*data.unwrap(), eq 73);
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
     }
 
     #[conformance_test]
-    pub fn publisher_with_unable_to_deliver_handler_retries_until_timeout<Sut: Service>() {
-        const TIMEOUT: Duration = Duration::from_millis(25);
-        const SAFE_OVERFLOW: bool = false;
-        const EXPECTED_SECOND_SEND_RESULT: Result<usize, SendError> = Ok(1);
-        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1: Option<usize> = Some(VALUE_FIRST_SAMPLE);
-        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2: Option<usize> = Some(VALUE_SECOND_SAMPLE);
+    pub fn persistent_service_does_not_deliver_sample_of_connected_publisher_twice<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let elapsed_blocking_time = publisher_with_unable_to_deliver_handler::<Sut, _>(
-            SAFE_OVERFLOW,
-            |publisher_port_factory| {
-                publisher_port_factory.set_unable_to_deliver_handler({
-                    move |info| {
-                        if info.elapsed_time > TIMEOUT {
-                            UnableToDeliverAction::DiscardData
-                        } else {
-                            UnableToDeliverAction::Retry
-                        }
-                    }
-                })
-            },
-            EXPECTED_SECOND_SEND_RESULT,
-            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1,
-            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2,
-        );
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .lifetime(ServiceLifetime::Persistent)
+            .history_size(1)
+            .create()
+            .unwrap();
 
-        assert_that!(elapsed_blocking_time, time_at_least(TIMEOUT));
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher.send_copy(79), is_ok);
+
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+
+        let data = sut_subscriber.receive().unwrap();
+        assert_that!(data, is_some);
+        assert_that!(*data.unwrap(), eq 79);
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
     }
 
     #[conformance_test]
-    pub fn publisher_with_unable_to_deliver_handler_discards_ample_and_fails<Sut: Service>() {
-        const SAFE_OVERFLOW: bool = false;
-        const EXPECTED_SECOND_SEND_RESULT: Result<usize, SendError> =
-            Err(SendError::UnableToDeliver);
-        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1: Option<usize> = Some(VALUE_FIRST_SAMPLE);
-        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2: Option<usize> = Some(VALUE_SECOND_SAMPLE);
-
-        let handler_call_count = Arc::new(AtomicU64::new(0));
+    pub fn open_fails_when_service_does_not_satisfy_history_cache_requirement<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_cache_size(2)
+            .create();
+        assert_that!(sut, is_ok);
+        assert_that!(sut.as_ref().unwrap().static_config().history_cache_size(), eq 2);
 
-        publisher_with_unable_to_deliver_handler::<Sut, _>(
-            SAFE_OVERFLOW,
-            |publisher_port_factory| {
-                publisher_port_factory.set_unable_to_deliver_handler({
-                    let handler_call_count = handler_call_count.clone();
-                    move |_| {
-                        handler_call_count.fetch_add(1, Ordering::Relaxed);
-                        UnableToDeliverAction::DiscardDataAndFail
-                    }
-                })
-            },
-            EXPECTED_SECOND_SEND_RESULT,
-            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1,
-            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2,
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_cache_size(3)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::DoesNotSupportRequestedMinHistoryCacheSize
         );
 
-        assert_that!(handler_call_count.load(Ordering::Relaxed), eq(1));
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_cache_size(1)
+            .open();
+
+        assert_that!(sut2, is_ok);
     }
 
     #[conformance_test]
-    pub fn publisher_with_unable_to_deliver_handler_follows_unable_to_deliver_strategy_with_discard_data<
-        Sut: Service,
-    >() {
-        const SAFE_OVERFLOW: bool = false;
-        const EXPECTED_SECOND_SEND_RESULT: Result<usize, SendError> = Ok(1);
-        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1: Option<usize> = Some(VALUE_FIRST_SAMPLE);
-        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2: Option<usize> = Some(VALUE_SECOND_SAMPLE);
+    pub fn history_cache_delivers_samples_of_all_gone_publishers_on_subscription<Sut: Service>() {
+        const HISTORY_CACHE_SIZE: usize = 4;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let handler_call_count = Arc::new(AtomicU64::new(0));
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_cache_size(HISTORY_CACHE_SIZE)
+            .subscriber_max_buffer_size(HISTORY_CACHE_SIZE)
+            .max_publishers(2)
+            .create()
+            .unwrap();
 
-        publisher_with_unable_to_deliver_handler::<Sut, _>(
-            SAFE_OVERFLOW,
-            |publisher_port_factory| {
-                publisher_port_factory
-                    .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardData)
-                    .set_unable_to_deliver_handler({
-                        let handler_call_count = handler_call_count.clone();
-                        move |_| {
-                            handler_call_count.fetch_add(1, Ordering::Relaxed);
-                            UnableToDeliverAction::FollowUnableToDeliveryStrategy
-                        }
-                    })
-            },
-            EXPECTED_SECOND_SEND_RESULT,
-            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1,
-            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2,
-        );
+        let sut_publisher_1 = sut.publisher_builder().create().unwrap();
+        let sut_publisher_2 = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher_1.send_copy(1), is_ok);
+        assert_that!(sut_publisher_2.send_copy(2), is_ok);
+        assert_that!(sut_publisher_1.send_copy(3), is_ok);
+        assert_that!(sut_publisher_2.send_copy(4), is_ok);
+        drop(sut_publisher_1);
+        drop(sut_publisher_2);
 
-        assert_that!(handler_call_count.load(Ordering::Relaxed), eq(1));
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+
+        for i in 1..=HISTORY_CACHE_SIZE {
+            let data = sut_subscriber.receive().unwrap();
+            assert_that!(data, is_some);
+            assert_that!(*data.unwrap(), eq i);
+        }
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
     }
 
     #[conformance_test]
-    pub fn publisher_with_unable_to_deliver_handler_follows_unable_to_deliver_strategy_with_retry_until_delivered<
-        Sut: Service,
-    >() {
-        let _watchdog = Watchdog::new();
-
+    pub fn history_cache_retains_only_the_most_recent_samples<Sut: Service>() {
+        const HISTORY_CACHE_SIZE: usize = 2;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        thread_scope(|s| {
-            const TIMEOUT: Duration = Duration::from_millis(25);
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_cache_size(HISTORY_CACHE_SIZE)
+            .subscriber_max_buffer_size(HISTORY_CACHE_SIZE * 2)
+            .create()
+            .unwrap();
 
-            let connect_handle = BarrierHandle::new();
-            let connect_barrier = BarrierBuilder::new(2).create(&connect_handle).unwrap();
-            let ready_handle = BarrierHandle::new();
-            let ready_barrier = BarrierBuilder::new(2).create(&ready_handle).unwrap();
-            let start_handle = BarrierHandle::new();
-            let start_barrier = BarrierBuilder::new(2).create(&start_handle).unwrap();
-            let finish_handle = BarrierHandle::new();
-            let finish_barrier = BarrierBuilder::new(2).create(&finish_handle).unwrap();
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        for i in 0..5 {
+            assert_that!(sut_publisher.send_copy(i), is_ok);
+        }
+        drop(sut_publisher);
 
-            let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
 
-            let sut = node
-                .service_builder(&service_name)
-                .publish_subscribe::<usize>()
-                .max_publishers(1)
-                .max_subscribers(2)
-                .history_size(0)
-                .enable_safe_overflow(false)
-                .subscriber_max_buffer_size(1)
-                .subscriber_max_borrowed_samples(2)
-                .create()
-                .unwrap();
+        for i in 3..5 {
+            let data = sut_subscriber.receive().unwrap();
+            assert_that!(data, is_some);
+            assert_that!(*data.unwrap(), eq i);
+        }
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
+    }
 
-            let sut_subscriber_1 = sut.subscriber_builder().create().unwrap();
+    #[conformance_test]
+    pub fn history_cache_delivers_at_most_buffer_size_samples<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-            s.thread_builder().spawn(|| {
-                let handler_call_count = Arc::new(AtomicU64::new(0));
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_cache_size(3)
+            .subscriber_max_buffer_size(3)
+            .create()
+            .unwrap();
 
-                let sut_publisher = sut
-                    .publisher_builder()
-                    .max_loaned_samples(1)
-                    .unable_to_deliver_strategy(UnableToDeliverStrategy::RetryUntilDelivered)
-                    .set_unable_to_deliver_handler({
-                        let handler_call_count = handler_call_count.clone();
-                        move |_| {
-                            handler_call_count.fetch_add(1, Ordering::Relaxed);
-                            UnableToDeliverAction::FollowUnableToDeliveryStrategy
-                        }
-                    })
-                    .create()
-                    .unwrap();
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        for i in 0..3 {
+            assert_that!(sut_publisher.send_copy(i), is_ok);
+        }
 
-                let sut_subscriber_2 = sut.subscriber_builder().create().unwrap();
+        let sut_subscriber = sut.subscriber_builder().buffer_size(1).create().unwrap();
 
-                connect_barrier.wait();
-                ready_barrier.wait();
+        let data = sut_subscriber.receive().unwrap();
+        assert_that!(data, is_some);
+        assert_that!(*data.unwrap(), eq 2);
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
+    }
 
-                sut_publisher.send_copy(1).unwrap();
-                assert_that!(*sut_subscriber_2.receive().unwrap().unwrap(), eq(1));
+    #[conformance_test]
+    pub fn history_cache_replaces_history_of_connected_publisher<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-                start_barrier.wait();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(2)
+            .history_cache_size(2)
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
 
-                sut_publisher.send_copy(2).unwrap();
-                assert_that!(*sut_subscriber_2.receive().unwrap().unwrap(), eq(2));
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher.send_copy(83), is_ok);
+        assert_that!(sut_publisher.send_copy(89), is_ok);
 
-                finish_barrier.wait();
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
 
-                assert_that!(handler_call_count.load(Ordering::Relaxed), eq(1));
-            })?;
+        let data = sut_subscriber.receive().unwrap();
+        assert_that!(*data.unwrap(), eq 83);
+        let data = sut_subscriber.receive().unwrap();
+        assert_that!(*data.unwrap(), eq 89);
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
+    }
 
-            connect_barrier.wait();
+    #[conformance_test]
+    pub fn history_cache_does_not_deliver_concurrently_sent_samples_twice<Sut: Service>() {
+        let _watch_dog = Watchdog::new();
 
-            let _ = sut_subscriber_1.update_connections();
+        const NUMBER_OF_SUBSCRIBERS: usize = 100;
+        const NUMBER_OF_RECEIVED_SAMPLES: usize = 8;
 
-            ready_barrier.wait();
+        let barrier_handle = BarrierHandle::new();
+        let create_service_barrier = BarrierBuilder::new(2).create(&barrier_handle).unwrap();
+        let service_name = generate_service_name();
+        let keep_running = AtomicBool::new(true);
+        let config = testing::generate_isolated_config();
+        let mutex_handle = MutexHandle::new();
+        let node = MutexBuilder::new()
+            .create(
+                NodeBuilder::new().config(&config).create::<Sut>().unwrap(),
+                &mutex_handle,
+            )
+            .unwrap();
 
-            let start = Time::now().unwrap();
+        let create_service = || {
+            node.lock()
+                .unwrap()
+                .service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .history_cache_size(4)
+                .subscriber_max_buffer_size(4)
+                .open_or_create()
+                .unwrap()
+        };
 
-            start_barrier.wait();
+        thread_scope(|s| {
+            s.thread_builder().spawn(|| {
+                let sut = create_service();
+                let publisher = sut.publisher_builder().create().unwrap();
+                create_service_barrier.wait();
 
-            nanosleep(TIMEOUT).unwrap();
-            assert_that!(*sut_subscriber_1.receive().unwrap().unwrap(), eq(1));
+                let mut counter = 1u64;
+                while keep_running.load(Ordering::Relaxed) {
+                    assert_that!(publisher.send_copy(counter), is_ok);
+                    counter += 1;
+                }
+            })?;
 
-            finish_barrier.wait();
+            let sut = create_service();
+            create_service_barrier.wait();
 
-            assert_that!(*sut_subscriber_1.receive().unwrap().unwrap(), eq(2));
+            for _ in 0..NUMBER_OF_SUBSCRIBERS {
+                let subscriber = sut.subscriber_builder().create().unwrap();
 
-            assert_that!(start.elapsed().unwrap(),  time_at_least TIMEOUT);
+                let mut last_received = 0;
+                let mut number_of_received_samples = 0;
+                while number_of_received_samples < NUMBER_OF_RECEIVED_SAMPLES {
+                    if let Some(sample) = subscriber.receive().unwrap() {
+                        assert_that!(*sample, gt last_received);
+                        last_received = *sample;
+                        number_of_received_samples += 1;
+                    }
+                }
+            }
 
+            keep_running.store(false, Ordering::Relaxed);
             Ok(())
         })
         .unwrap();
     }
 
-    fn publisher_never_goes_out_of_memory_impl<Sut: Service>(
-        buffer_size: usize,
-        history_size: usize,
-        max_borrow: usize,
-        max_subscribers: usize,
-        max_loan: usize,
-    ) {
-        const ITERATIONS: usize = 16;
+    #[conformance_test]
+    pub fn history_cache_retains_only_slices_up_to_max_slice_len<Sut: Service>() {
+        const MAX_SLICE_LEN: usize = 4;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .max_publishers(1)
-            .max_subscribers(max_subscribers)
-            .enable_safe_overflow(true)
-            .history_size(history_size)
-            .subscriber_max_buffer_size(buffer_size)
-            .subscriber_max_borrowed_samples(max_borrow)
+            .publish_subscribe::<[u64]>()
+            .history_cache_size(2)
+            .history_cache_max_slice_len(MAX_SLICE_LEN)
+            .subscriber_max_buffer_size(2)
             .create()
             .unwrap();
+        assert_that!(sut.static_config().history_cache_max_slice_len(), eq MAX_SLICE_LEN);
 
         let sut_publisher = sut
             .publisher_builder()
-            .max_loaned_samples(max_loan)
+            .initial_max_slice_len(MAX_SLICE_LEN + 1)
             .create()
             .unwrap();
+        let sample = sut_publisher.loan_slice_uninit(MAX_SLICE_LEN).unwrap();
+        assert_that!(sample.write_from_fn(|i| i as u64 * 7).send(), is_ok);
+        let sample = sut_publisher.loan_slice_uninit(MAX_SLICE_LEN + 1).unwrap();
+        assert_that!(sample.write_from_fn(|i| i as u64 * 11).send(), is_ok);
+        drop(sut_publisher);
 
-        let mut subscribers = vec![];
-        for _ in 0..max_subscribers {
-            let sut_subscriber = sut.subscriber_builder().create().unwrap();
-            subscribers.push(sut_subscriber);
-        }
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
 
-        for _ in 0..ITERATIONS {
-            // max out borrow
-            let mut borrowed_samples = vec![];
-            while borrowed_samples.len() < max_borrow * max_subscribers {
-                for _ in 0..buffer_size {
-                    assert_that!(sut_publisher.send_copy(889), is_ok);
-                }
+        let data = sut_subscriber.receive().unwrap().unwrap();
+        assert_that!(data.payload(), len MAX_SLICE_LEN);
+        for (i, element) in data.payload().iter().enumerate() {
+            assert_that!(*element, eq i as u64 * 7);
+        }
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
+    }
 
-                for (i, s) in subscribers.iter().enumerate().take(max_subscribers) {
-                    while let Ok(Some(sample)) = s.receive() {
-                        borrowed_samples.push((i, sample));
-                    }
-                }
-            }
+    #[conformance_test]
+    pub fn publish_history_of_zero_works<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-            // max out buffer
-            for _ in 0..buffer_size {
-                assert_that!(sut_publisher.send_copy(8127), is_ok);
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(0)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher.send_copy(29), is_ok);
+
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+
+        let data = sut_subscriber.receive().unwrap();
+        assert_that!(data, is_none);
+    }
+
+    #[conformance_test]
+    pub fn publish_send_copy_with_huge_overflow_works<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const BUFFER_SIZE: usize = 5;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .max_publishers(1)
+            .max_subscribers(2)
+            .history_size(0)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_borrowed_samples(1)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut
+            .publisher_builder()
+            .max_loaned_samples(1)
+            .create()
+            .unwrap();
+
+        let mut subscribers = vec![];
+        for _ in 0..2 {
+            let sut_subscriber = sut.subscriber_builder().create();
+            subscribers.push(sut_subscriber);
+        }
+
+        for _ in 0..BUFFER_SIZE * 100 {
+            assert_that!(sut_publisher.send_copy(889), is_ok);
+        }
+    }
+
+    const VALUE_FIRST_SAMPLE: usize = 1;
+    const VALUE_SECOND_SAMPLE: usize = 2;
+
+    fn publisher_with_unable_to_deliver_handler<Sut, PublisherBuilder>(
+        save_overflow: bool,
+        publisher_builder: PublisherBuilder,
+        expected_second_send_result: Result<usize, SendError>,
+        expected_receive_value_subscriber_1: Option<usize>,
+        expected_receive_value_subscriber_2: Option<usize>,
+    ) -> Duration
+    where
+        Sut: Service,
+        PublisherBuilder: Fn(
+            PortFactoryPublisher<'_, Sut, usize, ()>,
+        ) -> PortFactoryPublisher<'_, Sut, usize, ()>,
+    {
+        let _watchdog = Watchdog::new();
+
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .max_publishers(1)
+            .max_subscribers(2)
+            .history_size(0)
+            .enable_safe_overflow(save_overflow)
+            .subscriber_max_buffer_size(1)
+            .subscriber_max_borrowed_samples(2)
+            .create()
+            .unwrap();
+
+        let publisher_port_factory = service.publisher_builder().max_loaned_samples(1);
+
+        let sut_publisher = publisher_builder(publisher_port_factory).create().unwrap();
+
+        let sut_subscriber_1 = service.subscriber_builder().create().unwrap();
+        let sut_subscriber_2 = service.subscriber_builder().create().unwrap();
+
+        let now = Time::now().unwrap();
+
+        assert_that!(sut_publisher.send_copy(VALUE_FIRST_SAMPLE), eq(Ok(2)));
+        assert_that!(
+            *sut_subscriber_2.receive().unwrap().unwrap(),
+            eq(VALUE_FIRST_SAMPLE)
+        );
+
+        assert_that!(
+            sut_publisher.send_copy(VALUE_SECOND_SAMPLE),
+            eq(expected_second_send_result)
+        );
+
+        let elapsed_blocking_time = now.elapsed().unwrap();
+
+        // check receive result for subscriber 1
+        let mut receive_result = sut_subscriber_1.receive();
+        if let Some(expected_value) = expected_receive_value_subscriber_1 {
+            assert_that!(receive_result, is_ok);
+            let receive_value = receive_result.unwrap();
+            assert_that!(receive_value, is_some);
+            let sample = receive_value.unwrap();
+            assert_that!(*sample, eq(expected_value));
+
+            receive_result = sut_subscriber_1.receive();
+        }
+        assert_that!(receive_result, is_ok);
+        let receive_value = receive_result.unwrap();
+        assert_that!(receive_value, is_none);
+
+        // check receive result for subscriber 2
+        let mut receive_result = sut_subscriber_2.receive();
+        if let Some(expected_value) = expected_receive_value_subscriber_2 {
+            assert_that!(receive_result, is_ok);
+            let receive_value = receive_result.unwrap();
+            assert_that!(receive_value, is_some);
+            let sample = receive_value.unwrap();
+            assert_that!(*sample, eq(expected_value));
+
+            receive_result = sut_subscriber_2.receive();
+        }
+        assert_that!(receive_result, is_ok);
+        let receive_value = receive_result.unwrap();
+        assert_that!(receive_value, is_none);
+
+        elapsed_blocking_time
+    }
+
+    #[conformance_test]
+    pub fn publisher_with_unable_to_deliver_handler_does_not_block_with_safe_overflow<
+        Sut: Service,
+    >() {
+        const SAFE_OVERFLOW: bool = true;
+        const EXPECTED_SECOND_SEND_RESULT: Result<usize, SendError> = Ok(2);
+        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1: Option<usize> = Some(VALUE_SECOND_SAMPLE);
+        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2: Option<usize> = Some(VALUE_SECOND_SAMPLE);
+
+        let handler_call_count = Arc::new(AtomicU64::new(0));
+
+        publisher_with_unable_to_deliver_handler::<Sut, _>(
+            SAFE_OVERFLOW,
+            |publisher_port_factory| {
+                publisher_port_factory.set_unable_to_deliver_handler({
+                    let handler_call_count = handler_call_count.clone();
+                    move |_| {
+                        handler_call_count.fetch_add(1, Ordering::Relaxed);
+                        UnableToDeliverAction::Retry
+                    }
+                })
+            },
+            EXPECTED_SECOND_SEND_RESULT,
+            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1,
+            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2,
+        );
+
+        assert_that!(handler_call_count.load(Ordering::Relaxed), eq(0));
+    }
+
+    #[conformance_test]
+    pub fn publisher_with_unable_to_deliver_handler_discards_sample<Sut: Service>() {
+        const SAFE_OVERFLOW: bool = false;
+        const EXPECTED_SECOND_SEND_RESULT: Result<usize, SendError> = Ok(1);
+        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1: Option<usize> = Some(VALUE_FIRST_SAMPLE);
+        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2: Option<usize> = Some(VALUE_SECOND_SAMPLE);
+
+        let handler_call_count = Arc::new(AtomicU64::new(0));
+
+        publisher_with_unable_to_deliver_handler::<Sut, _>(
+            SAFE_OVERFLOW,
+            |publisher_port_factory| {
+                publisher_port_factory.set_unable_to_deliver_handler({
+                    let handler_call_count = handler_call_count.clone();
+                    move |_| {
+                        handler_call_count.fetch_add(1, Ordering::Relaxed);
+                        UnableToDeliverAction::DiscardData
+                    }
+                })
+            },
+            EXPECTED_SECOND_SEND_RESULT,
+            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1,
+            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2,
+        );
+
+        assert_that!(handler_call_count.load(Ordering::Relaxed), eq(1));
+    }
+
+    #[conformance_test]
+    pub fn publisher_with_unable_to_deliver_handler_retries_twice<Sut: Service>() {
+        const SAFE_OVERFLOW: bool = false;
+        const EXPECTED_SECOND_SEND_RESULT: Result<usize, SendError> = Ok(1);
+        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1: Option<usize> = Some(VALUE_FIRST_SAMPLE);
+        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2: Option<usize> = Some(VALUE_SECOND_SAMPLE);
+
+        let handler_call_count = Arc::new(AtomicU64::new(0));
+        const RETRY_COUNT: u64 = 2;
+
+        publisher_with_unable_to_deliver_handler::<Sut, _>(
+            SAFE_OVERFLOW,
+            |publisher_port_factory| {
+                publisher_port_factory.set_unable_to_deliver_handler({
+                    let handler_call_count = handler_call_count.clone();
+                    move |info| {
+                        if info.retries == RETRY_COUNT {
+                            UnableToDeliverAction::DiscardData
+                        } else {
+                            handler_call_count.fetch_add(1, Ordering::Relaxed);
+                            UnableToDeliverAction::Retry
+                        }
+                    }
+                })
+            },
+            EXPECTED_SECOND_SEND_RESULT,
+            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1,
+            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2,
+        );
+
+        assert_that!(handler_call_count.load(Ordering::Relaxed), eq(RETRY_COUNT));
+    }
+
+    #[conformance_test]
+    pub fn publisher_with_unable_to_deliver_handler_retries_until_timeout<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(25);
+        const SAFE_OVERFLOW: bool = false;
+        const EXPECTED_SECOND_SEND_RESULT: Result<usize, SendError> = Ok(1);
+        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1: Option<usize> = Some(VALUE_FIRST_SAMPLE);
+        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2: Option<usize> = Some(VALUE_SECOND_SAMPLE);
+
+        let elapsed_blocking_time = publisher_with_unable_to_deliver_handler::<Sut, _>(
+            SAFE_OVERFLOW,
+            |publisher_port_factory| {
+                publisher_port_factory.set_unable_to_deliver_handler({
+                    move |info| {
+                        if info.elapsed_time > TIMEOUT {
+                            UnableToDeliverAction::DiscardData
+                        } else {
+                            UnableToDeliverAction::Retry
+                        }
+                    }
+                })
+            },
+            EXPECTED_SECOND_SEND_RESULT,
+            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1,
+            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2,
+        );
+
+        assert_that!(elapsed_blocking_time, time_at_least(TIMEOUT));
+    }
+
+    #[conformance_test]
+    pub fn publisher_with_unable_to_deliver_handler_discards_ample_and_fails<Sut: Service>() {
+        const SAFE_OVERFLOW: bool = false;
+        const EXPECTED_SECOND_SEND_RESULT: Result<usize, SendError> =
+            Err(SendError::UnableToDeliver);
+        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1: Option<usize> = Some(VALUE_FIRST_SAMPLE);
+        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2: Option<usize> = Some(VALUE_SECOND_SAMPLE);
+
+        let handler_call_count = Arc::new(AtomicU64::new(0));
+
+        publisher_with_unable_to_deliver_handler::<Sut, _>(
+            SAFE_OVERFLOW,
+            |publisher_port_factory| {
+                publisher_port_factory.set_unable_to_deliver_handler({
+                    let handler_call_count = handler_call_count.clone();
+                    move |_| {
+                        handler_call_count.fetch_add(1, Ordering::Relaxed);
+                        UnableToDeliverAction::DiscardDataAndFail
+                    }
+                })
+            },
+            EXPECTED_SECOND_SEND_RESULT,
+            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1,
+            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2,
+        );
+
+        assert_that!(handler_call_count.load(Ordering::Relaxed), eq(1));
+    }
+
+    #[conformance_test]
+    pub fn publisher_with_unable_to_deliver_handler_follows_unable_to_deliver_strategy_with_discard_data<
+        Sut: Service,
+    >() {
+        const SAFE_OVERFLOW: bool = false;
+        const EXPECTED_SECOND_SEND_RESULT: Result<usize, SendError> = Ok(1);
+        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1: Option<usize> = Some(VALUE_FIRST_SAMPLE);
+        const EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2: Option<usize> = Some(VALUE_SECOND_SAMPLE);
+
+        let handler_call_count = Arc::new(AtomicU64::new(0));
+
+        publisher_with_unable_to_deliver_handler::<Sut, _>(
+            SAFE_OVERFLOW,
+            |publisher_port_factory| {
+                publisher_port_factory
+                    .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardData)
+                    .set_unable_to_deliver_handler({
+                        let handler_call_count = handler_call_count.clone();
+                        move |_| {
+                            handler_call_count.fetch_add(1, Ordering::Relaxed);
+                            UnableToDeliverAction::FollowUnableToDeliveryStrategy
+                        }
+                    })
+            },
+            EXPECTED_SECOND_SEND_RESULT,
+            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_1,
+            EXPECTED_RECEIVE_VALUE_SUBSCRIBER_2,
+        );
+
+        assert_that!(handler_call_count.load(Ordering::Relaxed), eq(1));
+    }
+
+    #[conformance_test]
+    pub fn publisher_with_unable_to_deliver_handler_follows_unable_to_deliver_strategy_with_retry_until_delivered<
+        Sut: Service,
+    >() {
+        let _watchdog = Watchdog::new();
+
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+
+        thread_scope(|s| {
+            const TIMEOUT: Duration = Duration::from_millis(25);
+
+            let connect_handle = BarrierHandle::new();
+            let connect_barrier = BarrierBuilder::new(2).create(&connect_handle).unwrap();
+            let ready_handle = BarrierHandle::new();
+            let ready_barrier = BarrierBuilder::new(2).create(&ready_handle).unwrap();
+            let start_handle = BarrierHandle::new();
+            let start_barrier = BarrierBuilder::new(2).create(&start_handle).unwrap();
+            let finish_handle = BarrierHandle::new();
+            let finish_barrier = BarrierBuilder::new(2).create(&finish_handle).unwrap();
+
+            let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+            let sut = node
+                .service_builder(&service_name)
+                .publish_subscribe::<usize>()
+                .max_publishers(1)
+                .max_subscribers(2)
+                .history_size(0)
+                .enable_safe_overflow(false)
+                .subscriber_max_buffer_size(1)
+                .subscriber_max_borrowed_samples(2)
+                .create()
+                .unwrap();
+
+            let sut_subscriber_1 = sut.subscriber_builder().create().unwrap();
+
+            s.thread_builder().spawn(|| {
+                let handler_call_count = Arc::new(AtomicU64::new(0));
+
+                let sut_publisher = sut
+                    .publisher_builder()
+                    .max_loaned_samples(1)
+                    .unable_to_deliver_strategy(UnableToDeliverStrategy::RetryUntilDelivered)
+                    .set_unable_to_deliver_handler({
+                        let handler_call_count = handler_call_count.clone();
+                        move |_| {
+                            handler_call_count.fetch_add(1, Ordering::Relaxed);
+                            UnableToDeliverAction::FollowUnableToDeliveryStrategy
+                        }
+                    })
+                    .create()
+                    .unwrap();
+
+                let sut_subscriber_2 = sut.subscriber_builder().create().unwrap();
+
+                connect_barrier.wait();
+                ready_barrier.wait();
+
+                sut_publisher.send_copy(1).unwrap();
+                assert_that!(*sut_subscriber_2.receive().unwrap().unwrap(), eq(1));
+
+                start_barrier.wait();
+
+                sut_publisher.send_copy(2).unwrap();
+                assert_that!(*sut_subscriber_2.receive().unwrap().unwrap(), eq(2));
+
+                finish_barrier.wait();
+
+                assert_that!(handler_call_count.load(Ordering::Relaxed), eq(1));
+            })?;
+
+            connect_barrier.wait();
+
+            let _ = sut_subscriber_1.update_connections();
+
+            ready_barrier.wait();
+
+            let start = Time::now().unwrap();
+
+            start_barrier.wait();
+
+            nanosleep(TIMEOUT).unwrap();
+            assert_that!(*sut_subscriber_1.receive().unwrap().unwrap(), eq(1));
+
+            finish_barrier.wait();
+
+            assert_that!(*sut_subscriber_1.receive().unwrap().unwrap(), eq(2));
+
+            assert_that!(start.elapsed().unwrap(),  time_at_least TIMEOUT);
+
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    fn publisher_never_goes_out_of_memory_impl<Sut: Service>(
+        buffer_size: usize,
+        history_size: usize,
+        max_borrow: usize,
+        max_subscribers: usize,
+        max_loan: usize,
+    ) {
+        const ITERATIONS: usize = 16;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .max_publishers(1)
+            .max_subscribers(max_subscribers)
+            .enable_safe_overflow(true)
+            .history_size(history_size)
+            .subscriber_max_buffer_size(buffer_size)
+            .subscriber_max_borrowed_samples(max_borrow)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut
+            .publisher_builder()
+            .max_loaned_samples(max_loan)
+            .create()
+            .unwrap();
+
+        let mut subscribers = vec![];
+        for _ in 0..max_subscribers {
+            let sut_subscriber = sut.subscriber_builder().create().unwrap();
+            subscribers.push(sut_subscriber);
+        }
+
+        for _ in 0..ITERATIONS {
+            // max out borrow
+            let mut borrowed_samples = vec![];
+            while borrowed_samples.len() < max_borrow * max_subscribers {
+                for _ in 0..buffer_size {
+                    assert_that!(sut_publisher.send_copy(889), is_ok);
+                }
+
+                for (i, s) in subscribers.iter().enumerate().take(max_subscribers) {
+                    while let Ok(Some(sample)) = s.receive() {
+                        borrowed_samples.push((i, sample));
+                    }
+                }
+            }
+
+            // max out buffer
+            for _ in 0..buffer_size {
+                assert_that!(sut_publisher.send_copy(8127), is_ok);
+            }
+
+            // max out history
+            for _ in 0..history_size {
+                assert_that!(sut_publisher.send_copy(17283), is_ok);
+            }
+
+            // max out loan
+            let mut loaned_samples = vec![];
+            for _ in 0..max_loan {
+                let sample = sut_publisher.loan_uninit();
+                assert_that!(sample, is_ok);
+                loaned_samples.push(sample);
+            }
+
+            let sample = sut_publisher.loan_uninit();
+            assert_that!(sample, is_err);
+            assert_that!(sample.err().unwrap(), eq LoanError::ExceedsMaxLoans);
+
+            // cleanup
+            borrowed_samples.clear();
+            loaned_samples.clear();
+            for s in subscribers.iter().take(max_subscribers) {
+                while let Ok(Some(_)) = s.receive() {}
+            }
+        }
+    }
+
+    #[conformance_test]
+    pub fn publisher_never_goes_out_of_memory_with_huge_max_loan<Sut: Service>() {
+        const BUFFER_SIZE: usize = 1;
+        const HISTORY_SIZE: usize = 0;
+        const MAX_BORROW: usize = 1;
+        const MAX_SUBSCRIBERS: usize = 1;
+        const MAX_LOAN: usize = 100;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    #[conformance_test]
+    pub fn publisher_never_goes_out_of_memory_with_huge_max_subscribers<Sut: Service>() {
+        const BUFFER_SIZE: usize = 1;
+        const HISTORY_SIZE: usize = 0;
+        const MAX_BORROW: usize = 1;
+        const MAX_SUBSCRIBERS: usize = 50;
+        const MAX_LOAN: usize = 1;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    #[conformance_test]
+    pub fn publisher_never_goes_out_of_memory_with_huge_max_borrow<Sut: Service>() {
+        const BUFFER_SIZE: usize = 1;
+        const HISTORY_SIZE: usize = 0;
+        const MAX_BORROW: usize = 100;
+        const MAX_SUBSCRIBERS: usize = 1;
+        const MAX_LOAN: usize = 1;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    #[conformance_test]
+    pub fn publisher_never_goes_out_of_memory_with_huge_history_size<Sut: Service>() {
+        const BUFFER_SIZE: usize = 1;
+        const HISTORY_SIZE: usize = 100;
+        const MAX_BORROW: usize = 1;
+        const MAX_SUBSCRIBERS: usize = 1;
+        const MAX_LOAN: usize = 1;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    #[conformance_test]
+    pub fn publisher_never_goes_out_of_memory_with_huge_buffer_size<Sut: Service>() {
+        const BUFFER_SIZE: usize = 3;
+        const HISTORY_SIZE: usize = 0;
+        const MAX_BORROW: usize = 1;
+        const MAX_SUBSCRIBERS: usize = 1;
+        const MAX_LOAN: usize = 1;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    #[conformance_test]
+    pub fn publisher_never_goes_out_of_memory_with_huge_values<Sut: Service>() {
+        const BUFFER_SIZE: usize = 29;
+        const HISTORY_SIZE: usize = 31;
+        const MAX_BORROW: usize = 12;
+        const MAX_SUBSCRIBERS: usize = 25;
+        const MAX_LOAN: usize = 35;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    fn retrieve_channel_capacity_is_never_exceeded<Sut: Service>(
+        publisher_borrow_size: usize,
+        buffer_size: usize,
+        max_borrow: usize,
+    ) {
+        const ITERATIONS: usize = 16;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .max_publishers(1)
+            .max_subscribers(1)
+            .enable_safe_overflow(false)
+            .history_size(0)
+            .subscriber_max_buffer_size(buffer_size)
+            .subscriber_max_borrowed_samples(max_borrow)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut
+            .publisher_builder()
+            .max_loaned_samples(publisher_borrow_size)
+            .create()
+            .unwrap();
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut borrowed_samples = vec![];
+        let mut cached_samples = vec![];
+
+        let mut send_sample = || {
+            if borrowed_samples.is_empty() {
+                for _ in 0..publisher_borrow_size {
+                    borrowed_samples.push(sut_publisher.loan().unwrap());
+                }
+            }
+
+            let sample = borrowed_samples.pop().unwrap();
+            sample.send().unwrap();
+        };
+
+        for _ in 0..ITERATIONS {
+            for _ in 0..max_borrow {
+                send_sample();
+                cached_samples.push(sut_subscriber.receive().unwrap().unwrap());
+            }
+
+            for _ in 0..buffer_size {
+                send_sample();
+            }
+
+            cached_samples.clear();
+            for _ in 0..buffer_size {
+                sut_subscriber.receive().unwrap().unwrap();
+            }
+        }
+    }
+
+    #[conformance_test]
+    pub fn retrieve_channel_capacity_is_never_exceeded_with_large_publisher_borrow_size<
+        Sut: Service,
+    >() {
+        const PUBLISHER_BORROW_SIZE: usize = 10;
+        const BUFFER_SIZE: usize = 1;
+        const MAX_BORROW: usize = 1;
+        retrieve_channel_capacity_is_never_exceeded::<Sut>(
+            PUBLISHER_BORROW_SIZE,
+            BUFFER_SIZE,
+            MAX_BORROW,
+        );
+    }
+
+    #[conformance_test]
+    pub fn retrieve_channel_capacity_is_never_exceeded_with_large_buffer_size<Sut: Service>() {
+        const PUBLISHER_BORROW_SIZE: usize = 1;
+        const BUFFER_SIZE: usize = 10;
+        const MAX_BORROW: usize = 1;
+        retrieve_channel_capacity_is_never_exceeded::<Sut>(
+            PUBLISHER_BORROW_SIZE,
+            BUFFER_SIZE,
+            MAX_BORROW,
+        );
+    }
+
+    #[conformance_test]
+    pub fn retrieve_channel_capacity_is_never_exceeded_with_large_max_borrow<Sut: Service>() {
+        const PUBLISHER_BORROW_SIZE: usize = 1;
+        const BUFFER_SIZE: usize = 1;
+        const MAX_BORROW: usize = 10;
+
+        retrieve_channel_capacity_is_never_exceeded::<Sut>(
+            PUBLISHER_BORROW_SIZE,
+            BUFFER_SIZE,
+            MAX_BORROW,
+        );
+    }
+
+    #[conformance_test]
+    pub fn retrieve_channel_capacity_is_never_exceeded_with_large_settings<Sut: Service>() {
+        const PUBLISHER_BORROW_SIZE: usize = 20;
+        const BUFFER_SIZE: usize = 14;
+        const MAX_BORROW: usize = 15;
+
+        retrieve_channel_capacity_is_never_exceeded::<Sut>(
+            PUBLISHER_BORROW_SIZE,
+            BUFFER_SIZE,
+            MAX_BORROW,
+        );
+    }
+
+    #[conformance_test]
+    pub fn retrieve_channel_capacity_is_never_exceeded_with_small_settings<Sut: Service>() {
+        const PUBLISHER_BORROW_SIZE: usize = 1;
+        const BUFFER_SIZE: usize = 1;
+        const MAX_BORROW: usize = 1;
+
+        retrieve_channel_capacity_is_never_exceeded::<Sut>(
+            PUBLISHER_BORROW_SIZE,
+            BUFFER_SIZE,
+            MAX_BORROW,
+        );
+    }
+
+    #[conformance_test]
+    pub fn creating_max_supported_amount_of_ports_work<Sut: Service>() {
+        const MAX_PUBLISHERS: usize = 4;
+        const MAX_SUBSCRIBERS: usize = 8;
+
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(MAX_PUBLISHERS)
+            .max_subscribers(MAX_SUBSCRIBERS)
+            .create()
+            .unwrap();
+
+        let mut publishers = vec![];
+        let mut subscribers = vec![];
+
+        // acquire all possible ports
+        for _ in 0..MAX_PUBLISHERS {
+            let publisher = sut.publisher_builder().create();
+            assert_that!(publisher, is_ok);
+            publishers.push(publisher);
+        }
+
+        for _ in 0..MAX_SUBSCRIBERS {
+            let subscriber = sut.subscriber_builder().create();
+            assert_that!(subscriber, is_ok);
+            subscribers.push(subscriber);
+        }
+
+        // create additional ports and fail
+        let publisher = sut.publisher_builder().create();
+        assert_that!(publisher, is_err);
+        assert_that!(
+            publisher.err().unwrap(), eq
+            PublisherCreateError::ExceedsMaxSupportedPublishers
+        );
+
+        let subscriber = sut.subscriber_builder().create();
+        assert_that!(subscriber, is_err);
+        assert_that!(
+            subscriber.err().unwrap(), eq
+            SubscriberCreateError::ExceedsMaxSupportedSubscribers
+        );
+
+        // remove a publisher and subscriber
+        assert_that!(publishers.remove(0), is_ok);
+        assert_that!(subscribers.remove(0), is_ok);
+
+        // create additional ports shall work again
+        let publisher = sut.publisher_builder().create();
+        assert_that!(publisher, is_ok);
+
+        let subscriber = sut.subscriber_builder().create();
+        assert_that!(subscriber, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn set_max_nodes_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_nodes(0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_nodes(), eq 1);
+    }
+
+    #[conformance_test]
+    pub fn set_max_publishers_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_publishers(), eq 1);
+    }
+
+    #[conformance_test]
+    pub fn set_max_subscribers_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_subscribers(), eq 1);
+    }
+
+    #[conformance_test]
+    pub fn set_subscriber_max_borrowed_samples_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_borrowed_samples(0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().subscriber_max_borrowed_samples(), eq 1);
+    }
+
+    #[conformance_test]
+    pub fn set_buffer_size_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().subscriber_max_buffer_size(), eq 1);
+    }
+
+    #[conformance_test]
+    pub fn set_number_of_priorities_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .number_of_priorities(0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().number_of_priorities(), eq 1);
+    }
+
+    #[conformance_test]
+    pub fn set_number_of_priorities_above_max_adjusts_it_to_max<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .number_of_priorities(u8::MAX)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().number_of_priorities() as usize, eq MAX_NUMBER_OF_PRIORITIES);
+    }
+
+    #[conformance_test]
+    pub fn does_exist_works_single<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
+        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
+
+        drop(_sut);
+
+        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn does_exist_works_many<Sut: Service>() {
+        const NUMBER_OF_SERVICES: usize = 8;
+
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let mut services = vec![];
+        let mut service_names = vec![];
+
+        for i in 0..NUMBER_OF_SERVICES {
+            let service_name = generate_service_name();
+            assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
+
+            services.push(
+                node.service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .create()
+                    .unwrap(),
+            );
+            service_names.push(service_name);
+
+            for s in service_names.iter().take(i + 1) {
+                assert_that!(Sut::does_exist(s, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
+            }
+        }
+
+        for i in 0..NUMBER_OF_SERVICES {
+            for s in service_names.iter().take(NUMBER_OF_SERVICES - i) {
+                assert_that!(Sut::does_exist(s, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
+            }
+
+            for s in service_names
+                .iter()
+                .take(NUMBER_OF_SERVICES)
+                .skip(NUMBER_OF_SERVICES - i)
+            {
+                assert_that!(Sut::does_exist(s, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
+            }
+
+            services.pop();
+        }
+    }
+
+    #[conformance_test]
+    pub fn list_works<Sut: Service>() {
+        const NUMBER_OF_SERVICES: usize = 8;
+
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let mut services = vec![];
+        let mut service_names = vec![];
+
+        let contains_service_names = |names, state: Vec<ServiceDetails<Sut>>| {
+            for n in names {
+                let mut name_found = false;
+                for s in &state {
+                    if *s.static_details.name() == n {
+                        name_found = true;
+                        break;
+                    }
+                }
+
+                if !name_found {
+                    return false;
+                }
             }
 
-            // max out history
-            for _ in 0..history_size {
-                assert_that!(sut_publisher.send_copy(17283), is_ok);
+            true
+        };
+
+        for i in 0..NUMBER_OF_SERVICES {
+            let service_name = generate_service_name();
+
+            services.push(
+                node.service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .create()
+                    .unwrap(),
+            );
+            service_names.push(service_name);
+
+            let mut service_list = vec![];
+            Sut::list(&config, |s| {
+                service_list.push(s);
+                CallbackProgression::Continue
+            })
+            .unwrap();
+            assert_that!(service_list, len i + 1);
+
+            assert_that!(contains_service_names(service_names.clone(), service_list), eq true);
+        }
+
+        for i in 0..NUMBER_OF_SERVICES {
+            services.pop();
+            service_names.pop();
+
+            let mut service_list = vec![];
+            Sut::list(&config, |s| {
+                service_list.push(s);
+                CallbackProgression::Continue
+            })
+            .unwrap();
+            assert_that!(service_list, len NUMBER_OF_SERVICES - i - 1);
+            assert_that!(contains_service_names(service_names.clone(), service_list), eq true);
+        }
+    }
+
+    #[conformance_test]
+    pub fn dropping_service_keeps_established_communication<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        drop(sut);
+
+        const PAYLOAD: u64 = 98129312938;
+
+        assert_that!(publisher.send_copy(PAYLOAD), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq PAYLOAD);
+    }
+
+    #[conformance_test]
+    pub fn ports_of_dropped_service_block_new_service_creation<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        drop(sut);
+
+        assert_that!(node.service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create().err().unwrap(),
+            eq PublishSubscribeCreateError::AlreadyExists);
+
+        drop(subscriber);
+
+        assert_that!(node.service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create().err().unwrap(),
+            eq PublishSubscribeCreateError::AlreadyExists);
+
+        drop(publisher);
+
+        assert_that!(
+            node.service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .create(),
+            is_ok
+        );
+    }
+
+    #[conformance_test]
+    pub fn service_can_be_opened_when_there_is_a_publisher<Sut: Service>() {
+        let payload = 1809723987;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        drop(sut);
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut, is_ok);
+        drop(sut);
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeCreateError::AlreadyExists);
+        drop(subscriber);
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        publisher.send_copy(payload).unwrap();
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample.payload(), eq payload);
+
+        drop(subscriber);
+        drop(sut);
+        drop(publisher);
+        drop(sample);
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn service_can_be_opened_when_there_is_a_subscriber<Sut: Service>() {
+        let payload = 59123544;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        drop(sut);
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut, is_ok);
+        drop(sut);
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeCreateError::AlreadyExists);
+        drop(publisher);
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        publisher.send_copy(payload).unwrap();
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample.payload(), eq payload);
+
+        drop(publisher);
+        drop(sut);
+        drop(subscriber);
+        drop(sample);
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn subscriber_can_decrease_buffer_size<Sut: Service>() {
+        const BUFFER_SIZE: usize = 16;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .open()
+            .unwrap();
+
+        for i in 1..=BUFFER_SIZE {
+            let publisher_before_sub = sut2.publisher_builder().create().unwrap();
+            let subscriber = sut.subscriber_builder().buffer_size(i).create().unwrap();
+            let publisher_after_sub = sut2.publisher_builder().create().unwrap();
+
+            assert_that!(subscriber.buffer_size(), eq i);
+
+            for n in 0..i * 2 {
+                assert_that!(publisher_before_sub.send_copy(n), is_ok);
             }
 
-            // max out loan
-            let mut loaned_samples = vec![];
-            for _ in 0..max_loan {
-                let sample = sut_publisher.loan_uninit();
-                assert_that!(sample, is_ok);
-                loaned_samples.push(sample);
+            for n in 0..i {
+                let sample = subscriber.receive().unwrap().unwrap();
+                assert_that!(*sample, eq i + n);
             }
 
-            let sample = sut_publisher.loan_uninit();
-            assert_that!(sample, is_err);
-            assert_that!(sample.err().unwrap(), eq LoanError::ExceedsMaxLoans);
+            let sample = subscriber.receive().unwrap();
+            assert_that!(sample, is_none);
+
+            for n in 0..i * 2 {
+                assert_that!(publisher_after_sub.send_copy(n as _), is_ok);
+            }
 
-            // cleanup
-            borrowed_samples.clear();
-            loaned_samples.clear();
-            for s in subscribers.iter().take(max_subscribers) {
-                while let Ok(Some(_)) = s.receive() {}
+            for n in 0..i {
+                let sample = subscriber.receive().unwrap().unwrap();
+                assert_that!(*sample, eq i + n);
             }
+
+            let sample = subscriber.receive().unwrap();
+            assert_that!(sample, is_none);
         }
     }
 
     #[conformance_test]
-    pub fn publisher_never_goes_out_of_memory_with_huge_max_loan<Sut: Service>() {
-        const BUFFER_SIZE: usize = 1;
-        const HISTORY_SIZE: usize = 0;
-        const MAX_BORROW: usize = 1;
-        const MAX_SUBSCRIBERS: usize = 1;
-        const MAX_LOAN: usize = 100;
+    pub fn subscriber_creation_fails_when_buffer_size_exceeds_service_max<Sut: Service>() {
+        const BUFFER_SIZE: usize = 16;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
-        );
-    }
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
 
-    #[conformance_test]
-    pub fn publisher_never_goes_out_of_memory_with_huge_max_subscribers<Sut: Service>() {
-        const BUFFER_SIZE: usize = 1;
-        const HISTORY_SIZE: usize = 0;
-        const MAX_BORROW: usize = 1;
-        const MAX_SUBSCRIBERS: usize = 50;
-        const MAX_LOAN: usize = 1;
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
-        );
+        let subscriber = sut2
+            .subscriber_builder()
+            .buffer_size(BUFFER_SIZE + 1)
+            .create();
+        assert_that!(subscriber, is_err);
+        assert_that!(subscriber.err().unwrap(), eq SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService);
     }
 
     #[conformance_test]
-    pub fn publisher_never_goes_out_of_memory_with_huge_max_borrow<Sut: Service>() {
-        const BUFFER_SIZE: usize = 1;
-        const HISTORY_SIZE: usize = 0;
-        const MAX_BORROW: usize = 100;
-        const MAX_SUBSCRIBERS: usize = 1;
-        const MAX_LOAN: usize = 1;
+    pub fn subscriber_buffer_size_is_at_least_one<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
-        );
+        let subscriber = sut.subscriber_builder().buffer_size(0).create().unwrap();
+        assert_that!(subscriber.buffer_size(), eq 1);
     }
 
     #[conformance_test]
-    pub fn publisher_never_goes_out_of_memory_with_huge_history_size<Sut: Service>() {
-        const BUFFER_SIZE: usize = 1;
-        const HISTORY_SIZE: usize = 100;
-        const MAX_BORROW: usize = 1;
-        const MAX_SUBSCRIBERS: usize = 1;
-        const MAX_LOAN: usize = 1;
+    pub fn sliced_service_works<Sut: Service>() {
+        const MAX_ELEMENTS: usize = 91;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
-        );
-    }
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(MAX_ELEMENTS)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-    #[conformance_test]
-    pub fn publisher_never_goes_out_of_memory_with_huge_buffer_size<Sut: Service>() {
-        const BUFFER_SIZE: usize = 3;
-        const HISTORY_SIZE: usize = 0;
-        const MAX_BORROW: usize = 1;
-        const MAX_SUBSCRIBERS: usize = 1;
-        const MAX_LOAN: usize = 1;
+        for n in 0..=MAX_ELEMENTS {
+            let sample = publisher.loan_slice_uninit(n).unwrap();
+            sample.write_from_fn(|i| i as u64 * 25).send().unwrap();
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
-        );
+            let recv_sample = subscriber.receive().unwrap().unwrap();
+
+            assert_that!(recv_sample.payload(), len n);
+            for (i, element) in recv_sample.payload().iter().enumerate() {
+                assert_that!(*element, eq i as u64 * 25);
+            }
+        }
     }
 
     #[conformance_test]
-    pub fn publisher_never_goes_out_of_memory_with_huge_values<Sut: Service>() {
-        const BUFFER_SIZE: usize = 29;
-        const HISTORY_SIZE: usize = 31;
-        const MAX_BORROW: usize = 12;
-        const MAX_SUBSCRIBERS: usize = 25;
-        const MAX_LOAN: usize = 35;
+    pub fn slice_aligned_service_works<Sut: Service>() {
+        const MAX_ELEMENTS: usize = 91;
+        const ALIGNMENT: usize = 64;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
-        );
+        let service_pub = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .subscriber_max_buffer_size(MAX_ELEMENTS + 1)
+            .subscriber_max_borrowed_samples(MAX_ELEMENTS + 1)
+            .payload_alignment(Alignment::new(ALIGNMENT).unwrap())
+            .create()
+            .unwrap();
+
+        let service_sub = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .open()
+            .unwrap();
+
+        let publisher = service_pub
+            .publisher_builder()
+            .initial_max_slice_len(MAX_ELEMENTS)
+            .create()
+            .unwrap();
+        let subscriber = service_sub.subscriber_builder().create().unwrap();
+
+        let mut samples = vec![];
+        for n in 0..=MAX_ELEMENTS {
+            let sample = publisher.loan_slice_uninit(n).unwrap();
+            assert_that!((sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
+            sample.write_from_fn(|i| i as u64 * 25).send().unwrap();
+
+            let recv_sample = subscriber.receive().unwrap().unwrap();
+
+            assert_that!((recv_sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
+            assert_that!(recv_sample.payload(), len n);
+            for (i, element) in recv_sample.payload().iter().enumerate() {
+                assert_that!(*element, eq i as u64 * 25);
+            }
+            samples.push(recv_sample);
+        }
     }
 
-    fn retrieve_channel_capacity_is_never_exceeded<Sut: Service>(
-        publisher_borrow_size: usize,
-        buffer_size: usize,
-        max_borrow: usize,
-    ) {
-        const ITERATIONS: usize = 16;
+    #[conformance_test]
+    pub fn simple_communication_with_user_header_works<Sut: Service>() {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .max_publishers(1)
-            .max_subscribers(1)
-            .enable_safe_overflow(false)
-            .history_size(0)
-            .subscriber_max_buffer_size(buffer_size)
-            .subscriber_max_borrowed_samples(max_borrow)
+            .publish_subscribe::<u64>()
+            .user_header::<SomeUserHeader>()
             .create()
             .unwrap();
 
-        let sut_publisher = sut
-            .publisher_builder()
-            .max_loaned_samples(publisher_borrow_size)
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<SomeUserHeader>()
+            .open()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut2.publisher_builder().create().unwrap();
+        let mut sample = publisher.loan().unwrap();
+
+        for i in 0..1024 {
+            sample.user_header_mut().value[i] = i as u64;
+        }
+        *sample.payload_mut() = 1829731;
+        sample.send().unwrap();
+
+        let result = subscriber.receive().unwrap();
+        assert_that!(result, is_some);
+        let sample = result.unwrap();
+        assert_that!(*sample, eq 1829731);
+        assert_that!(*sample.payload(), eq 1829731);
+
+        for i in 0..1024 {
+            assert_that!(sample.user_header().value[i], eq i as u64);
+        }
+    }
+
+    #[conformance_test]
+    pub fn same_payload_type_but_different_user_header_does_not_connect<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<SomeUserHeader>()
             .create()
             .unwrap();
-        let sut_subscriber = sut.subscriber_builder().create().unwrap();
 
-        let mut borrowed_samples = vec![];
-        let mut cached_samples = vec![];
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
 
-        let mut send_sample = || {
-            if borrowed_samples.is_empty() {
-                for _ in 0..publisher_borrow_size {
-                    borrowed_samples.push(sut_publisher.loan().unwrap());
-                }
-            }
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
 
-            let sample = borrowed_samples.pop().unwrap();
-            sample.send().unwrap();
+    #[conformance_test]
+    pub fn create_with_custom_payload_type_works<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&TypeDetail::new::<u64>(
+                    TypeVariant::FixedSize,
+                ))
+                .create()
+                .unwrap()
         };
 
-        for _ in 0..ITERATIONS {
-            for _ in 0..max_borrow {
-                send_sample();
-                cached_samples.push(sut_subscriber.receive().unwrap().unwrap());
-            }
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
 
-            for _ in 0..buffer_size {
-                send_sample();
-            }
+        assert_that!(sut2, is_ok);
 
-            cached_samples.clear();
-            for _ in 0..buffer_size {
-                sut_subscriber.receive().unwrap().unwrap();
-            }
-        }
-    }
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u32>()
+            .open();
 
-    #[conformance_test]
-    pub fn retrieve_channel_capacity_is_never_exceeded_with_large_publisher_borrow_size<
-        Sut: Service,
-    >() {
-        const PUBLISHER_BORROW_SIZE: usize = 10;
-        const BUFFER_SIZE: usize = 1;
-        const MAX_BORROW: usize = 1;
-        retrieve_channel_capacity_is_never_exceeded::<Sut>(
-            PUBLISHER_BORROW_SIZE,
-            BUFFER_SIZE,
-            MAX_BORROW,
-        );
+        assert_that!(sut3, is_err);
+        assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
     }
 
     #[conformance_test]
-    pub fn retrieve_channel_capacity_is_never_exceeded_with_large_buffer_size<Sut: Service>() {
-        const PUBLISHER_BORROW_SIZE: usize = 1;
-        const BUFFER_SIZE: usize = 10;
-        const MAX_BORROW: usize = 1;
-        retrieve_channel_capacity_is_never_exceeded::<Sut>(
-            PUBLISHER_BORROW_SIZE,
-            BUFFER_SIZE,
-            MAX_BORROW,
-        );
-    }
+    pub fn create_with_custom_user_header_type_works<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const HEADER_SIZE: usize = 1024;
 
-    #[conformance_test]
-    pub fn retrieve_channel_capacity_is_never_exceeded_with_large_max_borrow<Sut: Service>() {
-        const PUBLISHER_BORROW_SIZE: usize = 1;
-        const BUFFER_SIZE: usize = 1;
-        const MAX_BORROW: usize = 10;
+        let sut_pub = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[u8]>()
+                .user_header::<CustomHeaderMarker>()
+                .__internal_set_user_header_type_details(&TypeDetail::new::<[u64; HEADER_SIZE]>(
+                    TypeVariant::FixedSize,
+                ))
+                .create()
+                .unwrap()
+        };
 
-        retrieve_channel_capacity_is_never_exceeded::<Sut>(
-            PUBLISHER_BORROW_SIZE,
-            BUFFER_SIZE,
-            MAX_BORROW,
-        );
-    }
+        let sut_sub = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[u8]>()
+                .user_header::<CustomHeaderMarker>()
+                .__internal_set_user_header_type_details(&TypeDetail::new::<[u64; HEADER_SIZE]>(
+                    TypeVariant::FixedSize,
+                ))
+                .open()
+                .unwrap()
+        };
 
-    #[conformance_test]
-    pub fn retrieve_channel_capacity_is_never_exceeded_with_large_settings<Sut: Service>() {
-        const PUBLISHER_BORROW_SIZE: usize = 20;
-        const BUFFER_SIZE: usize = 14;
-        const MAX_BORROW: usize = 15;
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .open();
 
-        retrieve_channel_capacity_is_never_exceeded::<Sut>(
-            PUBLISHER_BORROW_SIZE,
-            BUFFER_SIZE,
-            MAX_BORROW,
-        );
-    }
+        assert_that!(sut3, is_err);
+        assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
 
-    #[conformance_test]
-    pub fn retrieve_channel_capacity_is_never_exceeded_with_small_settings<Sut: Service>() {
-        const PUBLISHER_BORROW_SIZE: usize = 1;
-        const BUFFER_SIZE: usize = 1;
-        const MAX_BORROW: usize = 1;
+        let publisher = sut_pub.publisher_builder().create().unwrap();
+        let subscriber = sut_sub.subscriber_builder().create().unwrap();
 
-        retrieve_channel_capacity_is_never_exceeded::<Sut>(
-            PUBLISHER_BORROW_SIZE,
-            BUFFER_SIZE,
-            MAX_BORROW,
-        );
+        let mut sample = publisher.loan_slice(1).unwrap();
+        let header = (sample.user_header_mut() as *mut CustomHeaderMarker) as *mut u64;
+        for i in 0..HEADER_SIZE {
+            unsafe { *header.add(i) = (4 * i + 1) as u64 };
+        }
+        sample.send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        let header = (sample.user_header() as *const CustomHeaderMarker) as *const u64;
+
+        for i in 0..HEADER_SIZE {
+            assert_that!(unsafe { *header.add(i) }, eq(4 * i + 1) as u64);
+        }
     }
 
     #[conformance_test]
-    pub fn creating_max_supported_amount_of_ports_work<Sut: Service>() {
-        const MAX_PUBLISHERS: usize = 4;
-        const MAX_SUBSCRIBERS: usize = 8;
-
+    pub fn open_with_custom_payload_type_works<Sut: Service>() {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
+
+        let _sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .max_publishers(MAX_PUBLISHERS)
-            .max_subscribers(MAX_SUBSCRIBERS)
+            .publish_subscribe::<u128>()
             .create()
             .unwrap();
 
-        let mut publishers = vec![];
-        let mut subscribers = vec![];
-
-        // acquire all possible ports
-        for _ in 0..MAX_PUBLISHERS {
-            let publisher = sut.publisher_builder().create();
-            assert_that!(publisher, is_ok);
-            publishers.push(publisher);
-        }
-
-        for _ in 0..MAX_SUBSCRIBERS {
-            let subscriber = sut.subscriber_builder().create();
-            assert_that!(subscriber, is_ok);
-            subscribers.push(subscriber);
-        }
-
-        // create additional ports and fail
-        let publisher = sut.publisher_builder().create();
-        assert_that!(publisher, is_err);
-        assert_that!(
-            publisher.err().unwrap(), eq
-            PublisherCreateError::ExceedsMaxSupportedPublishers
-        );
+        let sut2 = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&TypeDetail::new::<u128>(
+                    TypeVariant::FixedSize,
+                ))
+                .open()
+        };
 
-        let subscriber = sut.subscriber_builder().create();
-        assert_that!(subscriber, is_err);
-        assert_that!(
-            subscriber.err().unwrap(), eq
-            SubscriberCreateError::ExceedsMaxSupportedSubscribers
-        );
+        assert_that!(sut2, is_ok);
 
-        // remove a publisher and subscriber
-        assert_that!(publishers.remove(0), is_ok);
-        assert_that!(subscribers.remove(0), is_ok);
+        let sut3 = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&TypeDetail::new::<u64>(
+                    TypeVariant::FixedSize,
+                ))
+                .open()
+        };
 
-        // create additional ports shall work again
-        let publisher = sut.publisher_builder().create();
-        assert_that!(publisher, is_ok);
+        assert_that!(sut3, is_err);
+        assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
 
-        let subscriber = sut.subscriber_builder().create();
-        assert_that!(subscriber, is_ok);
+    #[conformance_test]
+    pub fn open_error_display_works<Sut: Service>() {
+        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotExist), eq
+                                  "PublishSubscribeOpenError::DoesNotExist");
+        assert_that!(format!("{}", PublishSubscribeOpenError::InternalFailure), eq
+                                  "PublishSubscribeOpenError::InternalFailure");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleTypes), eq
+                                  "PublishSubscribeOpenError::IncompatibleTypes");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleMessagingPattern), eq
+                                  "PublishSubscribeOpenError::IncompatibleMessagingPattern");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleAttributes), eq
+                                  "PublishSubscribeOpenError::IncompatibleAttributes");
+        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize), eq
+                                  "PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize");
+        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize), eq
+                                  "PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize");
+        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples), eq
+                                  "PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples");
+        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfPublishers), eq
+                                  "PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfPublishers");
+        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfSubscribers), eq
+                                  "PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfSubscribers");
+        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes), eq
+                                  "PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleOverflowBehavior), eq
+                                  "PublishSubscribeOpenError::IncompatibleOverflowBehavior");
+        assert_that!(format!("{}", PublishSubscribeOpenError::InsufficientPermissions), eq
+                                  "PublishSubscribeOpenError::InsufficientPermissions");
+        assert_that!(format!("{}", PublishSubscribeOpenError::ServiceInCorruptedState), eq
+                                  "PublishSubscribeOpenError::ServiceInCorruptedState");
+        assert_that!(format!("{}", PublishSubscribeOpenError::HangsInCreation), eq
+                                  "PublishSubscribeOpenError::HangsInCreation");
+        assert_that!(format!("{}", PublishSubscribeOpenError::ExceedsMaxNumberOfNodes), eq
+                                  "PublishSubscribeOpenError::ExceedsMaxNumberOfNodes");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IsMarkedForDestruction), eq
+                                  "PublishSubscribeOpenError::IsMarkedForDestruction");
     }
 
     #[conformance_test]
-    pub fn set_max_nodes_to_zero_adjusts_it_to_one<Sut: Service>() {
-        let service_name = generate_service_name();
-        let config = testing::generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .max_nodes(0)
-            .create()
-            .unwrap();
-
-        assert_that!(sut.static_config().max_nodes(), eq 1);
+    pub fn create_error_display_works<Sut: Service>() {
+        assert_that!(format!("{}", PublishSubscribeCreateError::ServiceInCorruptedState), eq
+                                  "PublishSubscribeCreateError::ServiceInCorruptedState");
+        assert_that!(format!("{}", PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize), eq
+                                  "PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize");
+        assert_that!(format!("{}", PublishSubscribeCreateError::AlreadyExists), eq
+                                  "PublishSubscribeCreateError::AlreadyExists");
+        assert_that!(format!("{}", PublishSubscribeCreateError::InsufficientPermissions), eq
+                                  "PublishSubscribeCreateError::InsufficientPermissions");
+        assert_that!(format!("{}", PublishSubscribeCreateError::InternalFailure), eq
+                                  "PublishSubscribeCreateError::InternalFailure");
+        assert_that!(format!("{}", PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance), eq
+                                  "PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance");
     }
 
     #[conformance_test]
-    pub fn set_max_publishers_to_zero_adjusts_it_to_one<Sut: Service>() {
+    pub fn has_samples_tracks_receivable_samples_in_subscriber<Sut: Service>() {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .max_publishers(0)
             .create()
             .unwrap();
 
-        assert_that!(sut.static_config().max_publishers(), eq 1);
-    }
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
 
-    #[conformance_test]
-    pub fn set_max_subscribers_to_zero_adjusts_it_to_one<Sut: Service>() {
-        let service_name = generate_service_name();
-        let config = testing::generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .max_subscribers(0)
-            .create()
-            .unwrap();
+        assert_that!(subscriber.has_samples().unwrap(), eq false);
+        assert_that!(publisher.send_copy(1234), is_ok);
+        assert_that!(subscriber.has_samples().unwrap(), eq true);
 
-        assert_that!(sut.static_config().max_subscribers(), eq 1);
+        let _ = subscriber.receive().unwrap();
+
+        assert_that!(subscriber.has_samples().unwrap(), eq false);
     }
 
     #[conformance_test]
-    pub fn set_subscriber_max_borrowed_samples_to_zero_adjusts_it_to_one<Sut: Service>() {
+    pub fn subscriber_can_still_receive_sample_when_publisher_was_disconnected<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 4;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .subscriber_max_borrowed_samples(0)
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .max_publishers(1)
             .create()
             .unwrap();
 
-        assert_that!(sut.static_config().subscriber_max_borrowed_samples(), eq 1);
-    }
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-    #[conformance_test]
-    pub fn set_buffer_size_to_zero_adjusts_it_to_one<Sut: Service>() {
-        let service_name = generate_service_name();
-        let config = testing::generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .subscriber_max_buffer_size(0)
-            .create()
-            .unwrap();
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
 
-        assert_that!(sut.static_config().subscriber_max_buffer_size(), eq 1);
+        drop(publisher);
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            let result = subscriber.receive().unwrap();
+            assert_that!(result, is_some);
+            let sample = result.unwrap();
+            assert_that!(*sample, eq i);
+        }
     }
 
     #[conformance_test]
-    pub fn does_exist_works_single<Sut: Service>() {
+    pub fn sample_is_still_acessible_when_publisher_was_disconnected<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 4;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
 
-        let _sut = node
+        let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .subscriber_max_borrowed_samples(NUMBER_OF_SAMPLES)
+            .max_publishers(1)
             .create()
             .unwrap();
 
-        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
-        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
-
-        drop(_sut);
-
-        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
-    }
-
-    #[conformance_test]
-    pub fn does_exist_works_many<Sut: Service>() {
-        const NUMBER_OF_SERVICES: usize = 8;
-
-        let config = testing::generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let mut services = vec![];
-        let mut service_names = vec![];
-
-        for i in 0..NUMBER_OF_SERVICES {
-            let service_name = generate_service_name();
-            assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-            services.push(
-                node.service_builder(&service_name)
-                    .publish_subscribe::<u64>()
-                    .create()
-                    .unwrap(),
-            );
-            service_names.push(service_name);
+        let mut samples = Vec::new();
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(i), is_ok);
 
-            for s in service_names.iter().take(i + 1) {
-                assert_that!(Sut::does_exist(s, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
-            }
+            let result = subscriber.receive().unwrap();
+            assert_that!(result, is_some);
+            samples.push(result.unwrap());
         }
 
-        for i in 0..NUMBER_OF_SERVICES {
-            for s in service_names.iter().take(NUMBER_OF_SERVICES - i) {
-                assert_that!(Sut::does_exist(s, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
-            }
-
-            for s in service_names
-                .iter()
-                .take(NUMBER_OF_SERVICES)
-                .skip(NUMBER_OF_SERVICES - i)
-            {
-                assert_that!(Sut::does_exist(s, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
-            }
+        drop(publisher);
+        let _ = subscriber.receive(); // 'receive' will internally call 'update_connections'
 
-            services.pop();
+        for (i, sample) in samples.iter().enumerate() {
+            assert_that!(**sample, eq i);
         }
     }
 
     #[conformance_test]
-    pub fn list_works<Sut: Service>() {
-        const NUMBER_OF_SERVICES: usize = 8;
-
+    pub fn subscriber_disconnected_publisher_does_not_block_new_publishers<Sut: Service>() {
+        set_log_level(LogLevel::Error);
+        const NUMBER_OF_SAMPLES: usize = 4;
+        let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let mut services = vec![];
-        let mut service_names = vec![];
-
-        let contains_service_names = |names, state: Vec<ServiceDetails<Sut>>| {
-            for n in names {
-                let mut name_found = false;
-                for s in &state {
-                    if *s.static_details.name() == n {
-                        name_found = true;
-                        break;
-                    }
-                }
-
-                if !name_found {
-                    return false;
-                }
-            }
-
-            true
-        };
-
-        for i in 0..NUMBER_OF_SERVICES {
-            let service_name = generate_service_name();
 
-            services.push(
-                node.service_builder(&service_name)
-                    .publish_subscribe::<u64>()
-                    .create()
-                    .unwrap(),
-            );
-            service_names.push(service_name);
-
-            let mut service_list = vec![];
-            Sut::list(&config, |s| {
-                service_list.push(s);
-                CallbackProgression::Continue
-            })
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .max_publishers(1)
+            .create()
             .unwrap();
-            assert_that!(service_list, len i + 1);
 
-            assert_that!(contains_service_names(service_names.clone(), service_list), eq true);
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(i), is_ok);
         }
 
-        for i in 0..NUMBER_OF_SERVICES {
-            services.pop();
-            service_names.pop();
+        drop(publisher);
 
-            let mut service_list = vec![];
-            Sut::list(&config, |s| {
-                service_list.push(s);
-                CallbackProgression::Continue
-            })
-            .unwrap();
-            assert_that!(service_list, len NUMBER_OF_SERVICES - i - 1);
-            assert_that!(contains_service_names(service_names.clone(), service_list), eq true);
+        let _publisher = sut.publisher_builder().create().unwrap();
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            let result = subscriber.receive().unwrap();
+            assert_that!(result, is_some);
+            let sample = result.unwrap();
+            assert_that!(*sample, eq i);
         }
     }
 
     #[conformance_test]
-    pub fn dropping_service_keeps_established_communication<Sut: Service>() {
+    pub fn subscriber_acquires_samples_of_disconnected_publisher_first<Sut: Service>() {
+        set_log_level(LogLevel::Error);
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(2)
+            .max_publishers(1)
             .create()
             .unwrap();
 
         let publisher = sut.publisher_builder().create().unwrap();
         let subscriber = sut.subscriber_builder().create().unwrap();
 
-        drop(sut);
+        assert_that!(publisher.send_copy(123), is_ok);
 
-        const PAYLOAD: u64 = 98129312938;
+        drop(publisher);
 
-        assert_that!(publisher.send_copy(PAYLOAD), eq Ok(1));
-        assert_that!(*subscriber.receive().unwrap().unwrap(), eq PAYLOAD);
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(publisher.send_copy(456), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 123);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 456);
     }
 
     #[conformance_test]
-    pub fn ports_of_dropped_service_block_new_service_creation<Sut: Service>() {
+    pub fn communication_with_custom_payload_works<Sut: Service>() {
+        set_log_level(LogLevel::Error);
+        const NUMBER_OF_ELEMENTS: usize = 1;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .create()
-            .unwrap();
-
-        let subscriber = sut.subscriber_builder().create().unwrap();
-        let publisher = sut.publisher_builder().create().unwrap();
-
-        drop(sut);
+        let mut type_details = TypeDetail::new::<u8>(TypeVariant::FixedSize);
+        testing::type_detail_set_size(&mut type_details, 1024);
+        testing::type_detail_set_alignment(&mut type_details, 1024);
 
-        assert_that!(node.service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .create().err().unwrap(),
-            eq PublishSubscribeCreateError::AlreadyExists);
+        let sut = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .user_header::<CustomHeaderMarker>()
+                .__internal_set_payload_type_details(&type_details)
+                .create()
+                .unwrap()
+        };
 
-        drop(subscriber);
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        assert_that!(node.service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .create().err().unwrap(),
-            eq PublishSubscribeCreateError::AlreadyExists);
+        let sample = unsafe { publisher.loan_custom_payload(NUMBER_OF_ELEMENTS).unwrap() };
+        assert_that!(sample.payload(), len type_details.size());
+        assert_that!((sample.payload().as_ptr() as usize % type_details.alignment()), eq 0);
+        assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
 
-        drop(publisher);
+        unsafe { sample.assume_init().send().unwrap() };
 
-        assert_that!(
-            node.service_builder(&service_name)
-                .publish_subscribe::<u64>()
-                .create(),
-            is_ok
-        );
+        let sample = unsafe { subscriber.receive_custom_payload().unwrap().unwrap() };
+        assert_that!(sample.payload(), len type_details.size());
+        assert_that!((sample.payload().as_ptr() as usize % type_details.alignment()), eq 0);
+        assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
     }
 
     #[conformance_test]
-    pub fn service_can_be_opened_when_there_is_a_publisher<Sut: Service>() {
-        let payload = 1809723987;
+    pub fn communication_with_custom_slice_payload_works<Sut: Service>() {
+        set_log_level(LogLevel::Error);
+        const NUMBER_OF_ELEMENTS: usize = 7;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .create()
-            .unwrap();
-        let subscriber = sut.subscriber_builder().create().unwrap();
-        let publisher = sut.publisher_builder().create().unwrap();
+        let mut type_details = TypeDetail::new::<u8>(TypeVariant::Dynamic);
+        testing::type_detail_set_size(&mut type_details, 2048);
+        testing::type_detail_set_alignment(&mut type_details, 2048);
 
-        drop(sut);
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open();
-        assert_that!(sut, is_ok);
-        drop(sut);
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .create();
-        assert_that!(sut.err().unwrap(), eq PublishSubscribeCreateError::AlreadyExists);
-        drop(subscriber);
+        let sut = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .user_header::<CustomHeaderMarker>()
+                .__internal_set_payload_type_details(&type_details)
+                .create()
+                .unwrap()
+        };
 
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open()
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(NUMBER_OF_ELEMENTS)
+            .create()
             .unwrap();
         let subscriber = sut.subscriber_builder().create().unwrap();
-        publisher.send_copy(payload).unwrap();
-        let sample = subscriber.receive().unwrap().unwrap();
-        assert_that!(*sample.payload(), eq payload);
 
-        drop(subscriber);
-        drop(sut);
-        drop(publisher);
-        drop(sample);
+        let sample = unsafe { publisher.loan_custom_payload(NUMBER_OF_ELEMENTS).unwrap() };
+        assert_that!(sample.payload(), len type_details.size() * NUMBER_OF_ELEMENTS);
+        assert_that!((sample.payload().as_ptr() as usize % type_details.alignment()), eq 0);
+        assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
 
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open();
-        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .create();
-        assert_that!(sut, is_ok);
+        unsafe { sample.assume_init().send().unwrap() };
+
+        let sample = unsafe { subscriber.receive_custom_payload().unwrap().unwrap() };
+        assert_that!(sample.payload(), len type_details.size() * NUMBER_OF_ELEMENTS);
+        assert_that!((sample.payload().as_ptr() as usize % type_details.alignment()), eq 0);
+        assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
     }
 
     #[conformance_test]
-    pub fn service_can_be_opened_when_there_is_a_subscriber<Sut: Service>() {
-        let payload = 59123544;
+    pub fn send_increasing_samples_with_static_allocation_strategy_fails<Sut: Service>() {
+        const SLICE_SIZE: usize = 1024;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
+
+        let service_pub = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
+            .publish_subscribe::<[u8]>()
             .create()
             .unwrap();
-        let subscriber = sut.subscriber_builder().create().unwrap();
-        let publisher = sut.publisher_builder().create().unwrap();
-
-        drop(sut);
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open();
-        assert_that!(sut, is_ok);
-        drop(sut);
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .create();
-        assert_that!(sut.err().unwrap(), eq PublishSubscribeCreateError::AlreadyExists);
-        drop(publisher);
 
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open()
+        let publisher = service_pub
+            .publisher_builder()
+            .initial_max_slice_len(SLICE_SIZE)
+            .allocation_strategy(AllocationStrategy::Static)
+            .create()
             .unwrap();
-        let publisher = sut.publisher_builder().create().unwrap();
-        publisher.send_copy(payload).unwrap();
-        let sample = subscriber.receive().unwrap().unwrap();
-        assert_that!(*sample.payload(), eq payload);
 
-        drop(publisher);
-        drop(sut);
-        drop(subscriber);
-        drop(sample);
+        let sample = publisher.loan_slice(SLICE_SIZE - 1);
+        assert_that!(sample, is_ok);
 
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open();
-        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .create();
-        assert_that!(sut, is_ok);
+        let sample = publisher.loan_slice(SLICE_SIZE);
+        assert_that!(sample, is_ok);
+
+        let sample = publisher.loan_slice(SLICE_SIZE + 1);
+        assert_that!(sample, is_err);
+        assert_that!(sample.err(), eq Some(LoanError::ExceedsMaxLoanSize));
     }
 
-    #[conformance_test]
-    pub fn subscriber_can_decrease_buffer_size<Sut: Service>() {
-        const BUFFER_SIZE: usize = 16;
+    fn send_and_receives_increasing_samples_works<Sut: Service>(
+        allocation_strategy: AllocationStrategy,
+    ) {
+        const ITERATIONS: usize = 128;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let sut = node
+        let service_pub = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .publish_subscribe::<[u8]>()
             .create()
             .unwrap();
 
-        let sut2 = node
+        let service_sub = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
+            .publish_subscribe::<[u8]>()
             .open()
             .unwrap();
 
-        for i in 1..=BUFFER_SIZE {
-            let publisher_before_sub = sut2.publisher_builder().create().unwrap();
-            let subscriber = sut.subscriber_builder().buffer_size(i).create().unwrap();
-            let publisher_after_sub = sut2.publisher_builder().create().unwrap();
-
-            assert_that!(subscriber.buffer_size(), eq i);
-
-            for n in 0..i * 2 {
-                assert_that!(publisher_before_sub.send_copy(n), is_ok);
-            }
-
-            for n in 0..i {
-                let sample = subscriber.receive().unwrap().unwrap();
-                assert_that!(*sample, eq i + n);
-            }
-
-            let sample = subscriber.receive().unwrap();
-            assert_that!(sample, is_none);
+        let publisher = service_pub
+            .publisher_builder()
+            .initial_max_slice_len(1)
+            .allocation_strategy(allocation_strategy)
+            .create()
+            .unwrap();
 
-            for n in 0..i * 2 {
-                assert_that!(publisher_after_sub.send_copy(n as _), is_ok);
-            }
+        let subscriber = service_sub.subscriber_builder().create().unwrap();
 
-            for n in 0..i {
-                let sample = subscriber.receive().unwrap().unwrap();
-                assert_that!(*sample, eq i + n);
+        for n in 0..ITERATIONS {
+            let sample_size = (n + 1) * 32;
+            let mut sample = publisher.loan_slice(sample_size).unwrap();
+            for byte in sample.payload_mut() {
+                *byte = n as u8;
             }
 
-            let sample = subscriber.receive().unwrap();
-            assert_that!(sample, is_none);
-        }
-    }
-
-    #[conformance_test]
-    pub fn subscriber_creation_fails_when_buffer_size_exceeds_service_max<Sut: Service>() {
-        const BUFFER_SIZE: usize = 16;
-        let service_name = generate_service_name();
-        let config = testing::generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
-        let _sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .subscriber_max_buffer_size(BUFFER_SIZE)
-            .create()
-            .unwrap();
+            sample.send().unwrap();
 
-        let sut2 = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open()
-            .unwrap();
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.payload(), len sample_size);
+            for byte in sample.payload() {
+                assert_that!(*byte, eq n as u8);
+            }
+        }
+    }
 
-        let subscriber = sut2
-            .subscriber_builder()
-            .buffer_size(BUFFER_SIZE + 1)
-            .create();
-        assert_that!(subscriber, is_err);
-        assert_that!(subscriber.err().unwrap(), eq SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService);
+    #[conformance_test]
+    pub fn send_and_receives_increasing_samples_works_for_best_fit_allocation_strategy<
+        Sut: Service,
+    >() {
+        send_and_receives_increasing_samples_works::<Sut>(AllocationStrategy::BestFit);
     }
 
     #[conformance_test]
-    pub fn subscriber_buffer_size_is_at_least_one<Sut: Service>() {
+    pub fn send_and_receives_increasing_samples_works_for_power_of_two_allocation_strategy<
+        Sut: Service,
+    >() {
+        send_and_receives_increasing_samples_works::<Sut>(AllocationStrategy::PowerOfTwo);
+    }
+
+    fn send_and_receives_increasing_samples_with_overflow_works<Sut: Service>(
+        allocation_strategy: AllocationStrategy,
+    ) {
+        const SUBSCRIBER_MAX_BUFFER_SIZE: usize = 5;
+        const ITERATIONS: usize = 128;
+        const REPETITIONS: usize = 13;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
+
+        let service_pub = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
+            .publish_subscribe::<[u8]>()
+            .subscriber_max_buffer_size(SUBSCRIBER_MAX_BUFFER_SIZE)
             .create()
             .unwrap();
 
-        let subscriber = sut.subscriber_builder().buffer_size(0).create().unwrap();
-        assert_that!(subscriber.buffer_size(), eq 1);
-    }
-
-    #[conformance_test]
-    pub fn sliced_service_works<Sut: Service>() {
-        const MAX_ELEMENTS: usize = 91;
-        let service_name = generate_service_name();
-        let config = testing::generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
+        let service_sub = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u64]>()
-            .create()
+            .publish_subscribe::<[u8]>()
+            .open()
             .unwrap();
 
-        let publisher = sut
+        let publisher = service_pub
             .publisher_builder()
-            .initial_max_slice_len(MAX_ELEMENTS)
+            .initial_max_slice_len(1)
+            .allocation_strategy(allocation_strategy)
             .create()
             .unwrap();
-        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        for n in 0..=MAX_ELEMENTS {
-            let sample = publisher.loan_slice_uninit(n).unwrap();
-            sample.write_from_fn(|i| i as u64 * 25).send().unwrap();
+        let subscriber = service_sub.subscriber_builder().create().unwrap();
 
-            let recv_sample = subscriber.receive().unwrap().unwrap();
+        for n in 0..ITERATIONS {
+            let sample_size = (n + 1) * 32;
+            for _ in 0..REPETITIONS {
+                let mut sample = publisher.loan_slice(sample_size).unwrap();
+                for byte in sample.payload_mut() {
+                    *byte = n as u8;
+                }
 
-            assert_that!(recv_sample.payload(), len n);
-            for (i, element) in recv_sample.payload().iter().enumerate() {
-                assert_that!(*element, eq i as u64 * 25);
+                sample.send().unwrap();
+            }
+
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.payload(), len sample_size);
+            for byte in sample.payload() {
+                assert_that!(*byte, eq n as u8);
             }
         }
     }
 
     #[conformance_test]
-    pub fn slice_aligned_service_works<Sut: Service>() {
-        const MAX_ELEMENTS: usize = 91;
-        const ALIGNMENT: usize = 64;
+    pub fn send_and_receives_increasing_samples_with_overflow_for_best_fit_allocation_strategy<
+        Sut: Service,
+    >() {
+        send_and_receives_increasing_samples_with_overflow_works::<Sut>(
+            AllocationStrategy::BestFit,
+        );
+    }
+
+    #[conformance_test]
+    pub fn send_and_receives_increasing_samples_with_overflow_for_power_of_two_allocation_strategy<
+        Sut: Service,
+    >() {
+        send_and_receives_increasing_samples_with_overflow_works::<Sut>(
+            AllocationStrategy::PowerOfTwo,
+        );
+    }
+
+    fn deliver_history_with_increasing_samples_works<Sut: Service>(
+        allocation_strategy: AllocationStrategy,
+    ) {
+        const SUBSCRIBER_MAX_BUFFER_SIZE: usize = 12;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
         let service_pub = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u64]>()
-            .subscriber_max_buffer_size(MAX_ELEMENTS + 1)
-            .subscriber_max_borrowed_samples(MAX_ELEMENTS + 1)
-            .payload_alignment(Alignment::new(ALIGNMENT).unwrap())
+            .publish_subscribe::<[u8]>()
+            .subscriber_max_buffer_size(SUBSCRIBER_MAX_BUFFER_SIZE)
+            .history_size(SUBSCRIBER_MAX_BUFFER_SIZE)
             .create()
             .unwrap();
 
         let service_sub = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u64]>()
+            .publish_subscribe::<[u8]>()
             .open()
             .unwrap();
 
         let publisher = service_pub
             .publisher_builder()
-            .initial_max_slice_len(MAX_ELEMENTS)
+            .initial_max_slice_len(1)
+            .allocation_strategy(allocation_strategy)
             .create()
             .unwrap();
-        let subscriber = service_sub.subscriber_builder().create().unwrap();
 
-        let mut samples = vec![];
-        for n in 0..=MAX_ELEMENTS {
-            let sample = publisher.loan_slice_uninit(n).unwrap();
-            assert_that!((sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
-            sample.write_from_fn(|i| i as u64 * 25).send().unwrap();
+        for n in 0..SUBSCRIBER_MAX_BUFFER_SIZE {
+            let sample_size = (n + 1) * 32;
+            let mut sample = publisher.loan_slice(sample_size).unwrap();
+            for byte in sample.payload_mut() {
+                *byte = n as u8;
+            }
 
-            let recv_sample = subscriber.receive().unwrap().unwrap();
+            sample.send().unwrap();
+        }
 
-            assert_that!((recv_sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
-            assert_that!(recv_sample.payload(), len n);
-            for (i, element) in recv_sample.payload().iter().enumerate() {
-                assert_that!(*element, eq i as u64 * 25);
+        let subscriber = service_sub.subscriber_builder().create().unwrap();
+        publisher.update_connections().unwrap();
+
+        for n in 0..SUBSCRIBER_MAX_BUFFER_SIZE {
+            let sample_size = (n + 1) * 32;
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.payload(), len sample_size);
+            for byte in sample.payload() {
+                assert_that!(*byte, eq n as u8);
             }
-            samples.push(recv_sample);
         }
     }
 
     #[conformance_test]
-    pub fn simple_communication_with_user_header_works<Sut: Service>() {
+    pub fn deliver_history_with_increasing_samples_works_for_best_fit_allocation_strategy<
+        Sut: Service,
+    >() {
+        deliver_history_with_increasing_samples_works::<Sut>(AllocationStrategy::BestFit);
+    }
+
+    #[conformance_test]
+    pub fn deliver_history_with_increasing_samples_works_for_power_of_two_allocation_strategy<
+        Sut: Service,
+    >() {
+        deliver_history_with_increasing_samples_works::<Sut>(AllocationStrategy::PowerOfTwo);
+    }
+
+    #[conformance_test]
+    pub fn does_not_leak_when_subscriber_has_smaller_buffer_size_than_history_size<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        const HISTORY_SIZE: usize = 1000;
+        const REPETITIONS: usize = 10;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .user_header::<SomeUserHeader>()
-            .create()
-            .unwrap();
+        let finish_handle = BarrierHandle::new();
+        let start_handle = BarrierHandle::new();
+        let end_handle = BarrierHandle::new();
+        let finish_setup = BarrierBuilder::new(2).create(&finish_handle).unwrap();
+        let start = BarrierBuilder::new(2).create(&start_handle).unwrap();
+        let end = BarrierBuilder::new(2).create(&end_handle).unwrap();
+
+        thread_scope(|s| {
+            s.thread_builder().spawn(|| {
+                let service = node
+                    .service_builder(&service_name)
+                    .publish_subscribe::<usize>()
+                    .max_publishers(1)
+                    .max_subscribers(1)
+                    .subscriber_max_borrowed_samples(1)
+                    .history_size(HISTORY_SIZE)
+                    .subscriber_max_buffer_size(HISTORY_SIZE)
+                    .create()
+                    .unwrap();
+
+                let publisher = service
+                    .publisher_builder()
+                    .max_loaned_samples(1)
+                    .create()
+                    .unwrap();
+
+                for n in 0..HISTORY_SIZE {
+                    publisher.send_copy(n).unwrap();
+                }
+
+                finish_setup.wait();
+
+                for _ in 0..REPETITIONS {
+                    start.wait();
+
+                    publisher.update_connections().unwrap();
+
+                    end.wait();
+                }
+            })?;
+
+            s.thread_builder().spawn(|| {
+                finish_setup.wait();
+
+                let service = node
+                    .service_builder(&service_name)
+                    .publish_subscribe::<usize>()
+                    .open()
+                    .unwrap();
+
+                for _ in 0..REPETITIONS {
+                    let subscriber = service
+                        .subscriber_builder()
+                        .buffer_size(1)
+                        .create()
+                        .unwrap();
+                    start.wait();
+
+                    let mut previous_value = 0;
+                    for _ in 0..HISTORY_SIZE {
+                        let sample = subscriber.receive().unwrap();
+                        if let Some(sample) = sample {
+                            assert_that!(*sample, ge previous_value);
+                            previous_value = *sample;
+                        }
+                    }
+
+                    end.wait();
+                }
+            })?;
+
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[conformance_test]
+    pub fn listing_all_publishers_works<S: Service>() {
+        const NUMBER_OF_PUBLISHERS: usize = 18;
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
 
-        let sut2 = node
+        let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .user_header::<SomeUserHeader>()
-            .open()
+            .max_publishers(NUMBER_OF_PUBLISHERS)
+            .create()
             .unwrap();
 
-        let subscriber = sut.subscriber_builder().create().unwrap();
-        let publisher = sut2.publisher_builder().create().unwrap();
-        let mut sample = publisher.loan().unwrap();
+        let mut publishers = vec![];
 
-        for i in 0..1024 {
-            sample.user_header_mut().value[i] = i as u64;
+        for _ in 0..NUMBER_OF_PUBLISHERS {
+            publishers.push(sut.publisher_builder().create().unwrap());
         }
-        *sample.payload_mut() = 1829731;
-        sample.send().unwrap();
 
-        let result = subscriber.receive().unwrap();
-        assert_that!(result, is_some);
-        let sample = result.unwrap();
-        assert_that!(*sample, eq 1829731);
-        assert_that!(*sample.payload(), eq 1829731);
+        let mut publisher_details = vec![];
+        sut.dynamic_config().list_publishers(|details| {
+            publisher_details.push(details.publisher_id);
+            CallbackProgression::Continue
+        });
 
-        for i in 0..1024 {
-            assert_that!(sample.user_header().value[i], eq i as u64);
+        assert_that!(publisher_details, len NUMBER_OF_PUBLISHERS);
+        for publisher in publishers {
+            assert_that!(publisher_details, contains publisher.id());
         }
     }
 
     #[conformance_test]
-    pub fn same_payload_type_but_different_user_header_does_not_connect<Sut: Service>() {
+    pub fn listing_all_publishers_stops_on_request<S: Service>() {
+        const NUMBER_OF_PUBLISHERS: usize = 16;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
 
-        let _sut = node
+        let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .user_header::<SomeUserHeader>()
+            .max_publishers(NUMBER_OF_PUBLISHERS)
             .create()
             .unwrap();
 
-        let sut2 = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open();
+        let mut publishers = vec![];
 
-        assert_that!(sut2, is_err);
-        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+        for _ in 0..NUMBER_OF_PUBLISHERS {
+            publishers.push(sut.publisher_builder().create().unwrap());
+        }
+
+        let mut counter = 0;
+        sut.dynamic_config().list_publishers(|_| {
+            counter += 1;
+            CallbackProgression::Stop
+        });
+
+        assert_that!(counter, eq 1);
     }
 
     #[conformance_test]
-    pub fn create_with_custom_payload_type_works<Sut: Service>() {
+    pub fn listing_all_subscribers_works<S: Service>() {
+        const NUMBER_OF_SUBSCRIBERS: usize = 18;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
-        let _sut = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .__internal_set_payload_type_details(&TypeDetail::new::<u64>(
-                    TypeVariant::FixedSize,
-                ))
-                .create()
-                .unwrap()
-        };
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
 
-        let sut2 = node
+        let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .open();
+            .max_subscribers(NUMBER_OF_SUBSCRIBERS)
+            .create()
+            .unwrap();
 
-        assert_that!(sut2, is_ok);
+        let mut subscribers = vec![];
 
-        let sut3 = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u32>()
-            .open();
+        for _ in 0..NUMBER_OF_SUBSCRIBERS {
+            subscribers.push(sut.subscriber_builder().create().unwrap());
+        }
 
-        assert_that!(sut3, is_err);
-        assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+        let mut subscriber_details = vec![];
+        sut.dynamic_config().list_subscribers(|details| {
+            subscriber_details.push(details.subscriber_id);
+            CallbackProgression::Continue
+        });
+
+        assert_that!(subscriber_details, len NUMBER_OF_SUBSCRIBERS);
+        for subscriber in subscribers {
+            assert_that!(subscriber_details, contains subscriber.id());
+        }
     }
 
     #[conformance_test]
-    pub fn create_with_custom_user_header_type_works<Sut: Service>() {
+    pub fn listing_all_subscribers_stops_on_request<S: Service>() {
+        const NUMBER_OF_SUBSCRIBERS: usize = 16;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        const HEADER_SIZE: usize = 1024;
-
-        let sut_pub = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[u8]>()
-                .user_header::<CustomHeaderMarker>()
-                .__internal_set_user_header_type_details(&TypeDetail::new::<[u64; HEADER_SIZE]>(
-                    TypeVariant::FixedSize,
-                ))
-                .create()
-                .unwrap()
-        };
-
-        let sut_sub = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[u8]>()
-                .user_header::<CustomHeaderMarker>()
-                .__internal_set_user_header_type_details(&TypeDetail::new::<[u64; HEADER_SIZE]>(
-                    TypeVariant::FixedSize,
-                ))
-                .open()
-                .unwrap()
-        };
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
 
-        let sut3 = node
+        let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u8]>()
-            .open();
-
-        assert_that!(sut3, is_err);
-        assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+            .publish_subscribe::<u64>()
+            .max_subscribers(NUMBER_OF_SUBSCRIBERS)
+            .create()
+            .unwrap();
 
-        let publisher = sut_pub.publisher_builder().create().unwrap();
-        let subscriber = sut_sub.subscriber_builder().create().unwrap();
+        let mut subscribers = vec![];
 
-        let mut sample = publisher.loan_slice(1).unwrap();
-        let header = (sample.user_header_mut() as *mut CustomHeaderMarker) as *mut u64;
-        for i in 0..HEADER_SIZE {
-            unsafe { *header.add(i) = (4 * i + 1) as u64 };
+        for _ in 0..NUMBER_OF_SUBSCRIBERS {
+            subscribers.push(sut.subscriber_builder().create().unwrap());
         }
-        sample.send().unwrap();
 
-        let sample = subscriber.receive().unwrap().unwrap();
-        let header = (sample.user_header() as *const CustomHeaderMarker) as *const u64;
+        let mut counter = 0;
+        sut.dynamic_config().list_subscribers(|_| {
+            counter += 1;
+            CallbackProgression::Stop
+        });
 
-        for i in 0..HEADER_SIZE {
-            assert_that!(unsafe { *header.add(i) }, eq(4 * i + 1) as u64);
-        }
+        assert_that!(counter, eq 1);
     }
 
     #[conformance_test]
-    pub fn open_with_custom_payload_type_works<Sut: Service>() {
+    pub fn receive_does_not_return_error_when_pub_goes_out_of_scope_after_reallocation<
+        S: Service,
+    >() {
+        const SLICE_MAX_LEN: usize = 1;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
 
-        let _sut = node
+        let service = node
             .service_builder(&service_name)
-            .publish_subscribe::<u128>()
+            .publish_subscribe::<[u8]>()
             .create()
             .unwrap();
 
-        let sut2 = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .__internal_set_payload_type_details(&TypeDetail::new::<u128>(
-                    TypeVariant::FixedSize,
-                ))
-                .open()
-        };
-
-        assert_that!(sut2, is_ok);
-
-        let sut3 = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .__internal_set_payload_type_details(&TypeDetail::new::<u64>(
-                    TypeVariant::FixedSize,
-                ))
-                .open()
-        };
-
-        assert_that!(sut3, is_err);
-        assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
-    }
+        let subscriber = service.subscriber_builder().create().unwrap();
 
-    #[conformance_test]
-    pub fn open_error_display_works<Sut: Service>() {
-        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotExist), eq
-                                  "PublishSubscribeOpenError::DoesNotExist");
-        assert_that!(format!("{}", PublishSubscribeOpenError::InternalFailure), eq
-                                  "PublishSubscribeOpenError::InternalFailure");
-        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleTypes), eq
-                                  "PublishSubscribeOpenError::IncompatibleTypes");
-        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleMessagingPattern), eq
-                                  "PublishSubscribeOpenError::IncompatibleMessagingPattern");
-        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleAttributes), eq
-                                  "PublishSubscribeOpenError::IncompatibleAttributes");
-        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize), eq
-                                  "PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize");
-        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize), eq
-                                  "PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize");
-        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples), eq
-                                  "PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples");
-        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfPublishers), eq
-                                  "PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfPublishers");
-        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfSubscribers), eq
-                                  "PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfSubscribers");
-        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes), eq
-                                  "PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes");
-        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleOverflowBehavior), eq
-                                  "PublishSubscribeOpenError::IncompatibleOverflowBehavior");
-        assert_that!(format!("{}", PublishSubscribeOpenError::InsufficientPermissions), eq
-                                  "PublishSubscribeOpenError::InsufficientPermissions");
-        assert_that!(format!("{}", PublishSubscribeOpenError::ServiceInCorruptedState), eq
-                                  "PublishSubscribeOpenError::ServiceInCorruptedState");
-        assert_that!(format!("{}", PublishSubscribeOpenError::HangsInCreation), eq
-                                  "PublishSubscribeOpenError::HangsInCreation");
-        assert_that!(format!("{}", PublishSubscribeOpenError::ExceedsMaxNumberOfNodes), eq
-                                  "PublishSubscribeOpenError::ExceedsMaxNumberOfNodes");
-        assert_that!(format!("{}", PublishSubscribeOpenError::IsMarkedForDestruction), eq
-                                  "PublishSubscribeOpenError::IsMarkedForDestruction");
-    }
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(SLICE_MAX_LEN)
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .create()
+            .unwrap();
 
-    #[conformance_test]
-    pub fn create_error_display_works<Sut: Service>() {
-        assert_that!(format!("{}", PublishSubscribeCreateError::ServiceInCorruptedState), eq
-                                  "PublishSubscribeCreateError::ServiceInCorruptedState");
-        assert_that!(format!("{}", PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize), eq
-                                  "PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize");
-        assert_that!(format!("{}", PublishSubscribeCreateError::AlreadyExists), eq
-                                  "PublishSubscribeCreateError::AlreadyExists");
-        assert_that!(format!("{}", PublishSubscribeCreateError::InsufficientPermissions), eq
-                                  "PublishSubscribeCreateError::InsufficientPermissions");
-        assert_that!(format!("{}", PublishSubscribeCreateError::InternalFailure), eq
-                                  "PublishSubscribeCreateError::InternalFailure");
-        assert_that!(format!("{}", PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance), eq
-                                  "PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance");
+        // send and receive once so that the subscriber maps the data segment
+        let sample = publisher.loan_slice_uninit(SLICE_MAX_LEN).unwrap();
+        sample.write_from_fn(|i| i as u8).send().unwrap();
+
+        let recv_sample = subscriber.receive();
+        assert_that!(recv_sample, is_ok);
+
+        // publisher has to reallocate the data segment
+        let sample = publisher.loan_slice_uninit(SLICE_MAX_LEN + 4096).unwrap();
+        sample.write_from_fn(|i| i as u8).send().unwrap();
+        // publisher goes out of scope and closes the reallocated data segment as it was not yet mapped by the
+        // subscriber
+        drop(publisher);
+
+        let recv_res = subscriber.receive();
+        assert_that!(recv_res, is_ok);
     }
 
     #[conformance_test]
-    pub fn has_samples_tracks_receivable_samples_in_subscriber<Sut: Service>() {
+    pub fn send_and_receive_serialized_payload_works<Sut: Service>() {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
-        let sut = node
+        let service = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Postcard, NonZeroCopyPayload>()
             .create()
             .unwrap();
 
-        let subscriber = sut.subscriber_builder().create().unwrap();
-        let publisher = sut.publisher_builder().create().unwrap();
+        let publisher = service
+            .publisher_builder()
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
 
-        assert_that!(subscriber.has_samples().unwrap(), eq false);
-        assert_that!(publisher.send_copy(1234), is_ok);
-        assert_that!(subscriber.has_samples().unwrap(), eq true);
+        for i in 0..4 {
+            let payload = NonZeroCopyPayload {
+                name: format!("the answer is {i}"),
+                values: (0..i * 100).collect(),
+            };
+            assert_that!(publisher.send_serialized::<Postcard, _>(&payload), eq Ok(1));
 
-        let _ = subscriber.receive().unwrap();
+            let received = subscriber
+                .receive_deserialized::<Postcard, NonZeroCopyPayload>()
+                .unwrap();
+            assert_that!(received, eq Some(payload));
+        }
 
-        assert_that!(subscriber.has_samples().unwrap(), eq false);
+        let received = subscriber
+            .receive_deserialized::<Postcard, NonZeroCopyPayload>()
+            .unwrap();
+        assert_that!(received, is_none);
     }
 
     #[conformance_test]
-    pub fn subscriber_can_still_receive_sample_when_publisher_was_disconnected<Sut: Service>() {
-        const NUMBER_OF_SAMPLES: usize = 4;
+    pub fn open_serialized_service_with_incompatible_type_fails<Sut: Service>() {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
-        let sut = node
+        let _sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
-            .max_publishers(1)
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Postcard, NonZeroCopyPayload>()
             .create()
             .unwrap();
 
-        let publisher = sut.publisher_builder().create().unwrap();
-        let subscriber = sut.subscriber_builder().create().unwrap();
-
-        for i in 0..NUMBER_OF_SAMPLES {
-            assert_that!(publisher.send_copy(i), is_ok);
-        }
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Postcard, String>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
 
-        drop(publisher);
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
 
-        for i in 0..NUMBER_OF_SAMPLES {
-            let result = subscriber.receive().unwrap();
-            assert_that!(result, is_some);
-            let sample = result.unwrap();
-            assert_that!(*sample, eq i);
-        }
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Postcard, NonZeroCopyPayload>()
+            .open();
+        assert_that!(sut2, is_ok);
     }
 
     #[conformance_test]
-    pub fn sample_is_still_acessible_when_publisher_was_disconnected<Sut: Service>() {
-        const NUMBER_OF_SAMPLES: usize = 4;
+    pub fn send_and_receive_serialized_payload_with_incompatible_type_fails<Sut: Service>() {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
-        let sut = node
+        let service = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
-            .subscriber_max_borrowed_samples(NUMBER_OF_SAMPLES)
-            .max_publishers(1)
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Postcard, NonZeroCopyPayload>()
             .create()
             .unwrap();
 
-        let publisher = sut.publisher_builder().create().unwrap();
-        let subscriber = sut.subscriber_builder().create().unwrap();
-
-        let mut samples = Vec::new();
-        for i in 0..NUMBER_OF_SAMPLES {
-            assert_that!(publisher.send_copy(i), is_ok);
-
-            let result = subscriber.receive().unwrap();
-            assert_that!(result, is_some);
-            samples.push(result.unwrap());
-        }
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(128)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
 
-        drop(publisher);
-        let _ = subscriber.receive(); // 'receive' will internally call 'update_connections'
+        let result = publisher.send_serialized::<Postcard, _>(&String::from("hypnotoad"));
+        assert_that!(result, eq Err(SendSerializedError::IncompatibleSerialization));
 
-        for (i, sample) in samples.iter().enumerate() {
-            assert_that!(**sample, eq i);
-        }
+        let result = subscriber.receive_deserialized::<Postcard, String>();
+        assert_that!(result, eq Err(ReceiveDeserializedError::IncompatibleSerialization));
     }
 
     #[conformance_test]
-    pub fn subscriber_disconnected_publisher_does_not_block_new_publishers<Sut: Service>() {
-        set_log_level(LogLevel::Error);
-        const NUMBER_OF_SAMPLES: usize = 4;
+    pub fn subscriber_wakeup_is_disabled_by_default<Sut: Service>() {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
-            .max_publishers(1)
+            .publish_subscribe::<u64>()
             .create()
             .unwrap();
-
-        let publisher = sut.publisher_builder().create().unwrap();
         let subscriber = sut.subscriber_builder().create().unwrap();
 
-        for i in 0..NUMBER_OF_SAMPLES {
-            assert_that!(publisher.send_copy(i), is_ok);
-        }
-
-        drop(publisher);
-
-        let _publisher = sut.publisher_builder().create().unwrap();
-
-        for i in 0..NUMBER_OF_SAMPLES {
-            let result = subscriber.receive().unwrap();
-            assert_that!(result, is_some);
-            let sample = result.unwrap();
-            assert_that!(*sample, eq i);
-        }
+        assert_that!(sut.static_config().has_subscriber_wakeup(), eq false);
+        assert_that!(subscriber.has_wakeup(), eq false);
+        assert_that!(subscriber.wakeup(), is_none);
+        assert_that!(
+            sut.subscriber_builder().create_with_wakeup().err(),
+            eq Some(SubscriberCreateError::SubscriberWakeupNotEnabled)
+        );
     }
 
     #[conformance_test]
-    pub fn subscriber_acquires_samples_of_disconnected_publisher_first<Sut: Service>() {
-        set_log_level(LogLevel::Error);
+    pub fn subscriber_with_wakeup_wakes_up_waitset_on_send<Sut: Service>()
+    where
+        <Sut::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing,
+    {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .subscriber_max_buffer_size(2)
-            .max_publishers(1)
+            .publish_subscribe::<u64>()
+            .enable_subscriber_wakeup(true)
             .create()
             .unwrap();
 
         let publisher = sut.publisher_builder().create().unwrap();
-        let subscriber = sut.subscriber_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create_with_wakeup().unwrap();
+        assert_that!(subscriber.has_wakeup(), eq true);
 
-        assert_that!(publisher.send_copy(123), is_ok);
+        let waitset = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard = waitset.attach_notification(&subscriber).unwrap();
 
-        drop(publisher);
+        assert_that!(publisher.send_copy(1234), eq Ok(1));
 
-        let publisher = sut.publisher_builder().create().unwrap();
-        assert_that!(publisher.send_copy(456), is_ok);
+        let mut received_samples = vec![];
+        let result = waitset.wait_and_process_once_with_timeout(
+            |attachment_id| {
+                if attachment_id.has_event_from(&guard) {
+                    while let Some(sample) = subscriber.receive().unwrap() {
+                        received_samples.push(*sample);
+                    }
+                }
+                CallbackProgression::Continue
+            },
+            Duration::from_secs(10),
+        );
 
-        let sample = subscriber.receive().unwrap().unwrap();
-        assert_that!(*sample, eq 123);
-        let sample = subscriber.receive().unwrap().unwrap();
-        assert_that!(*sample, eq 456);
+        assert_that!(result, eq Ok(WaitSetRunResult::AllEventsHandled));
+        assert_that!(received_samples, eq vec![1234]);
+
+        // all pending wakeups were consumed, the waitset times out
+        let mut has_event = false;
+        let result = waitset.wait_and_process_once_with_timeout(
+            |_| {
+                has_event = true;
+                CallbackProgression::Continue
+            },
+            Duration::from_millis(10),
+        );
+        assert_that!(result, eq Ok(WaitSetRunResult::AllEventsHandled));
+        assert_that!(has_event, eq false);
+        assert_that!(subscriber.receive().unwrap(), is_none);
     }
 
     #[conformance_test]
-    pub fn communication_with_custom_payload_works<Sut: Service>() {
-        set_log_level(LogLevel::Error);
-        const NUMBER_OF_ELEMENTS: usize = 1;
+    pub fn copy_on_send_is_disabled_by_default<Sut: Service>() {
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let mut type_details = TypeDetail::new::<u8>(TypeVariant::FixedSize);
-        testing::type_detail_set_size(&mut type_details, 1024);
-        testing::type_detail_set_alignment(&mut type_details, 1024);
 
-        let sut = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .user_header::<CustomHeaderMarker>()
-                .__internal_set_payload_type_details(&type_details)
-                .create()
-                .unwrap()
-        };
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
 
-        let publisher = sut.publisher_builder().create().unwrap();
-        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut.static_config().has_copy_on_send(), eq false);
+    }
 
-        let sample = unsafe { publisher.loan_custom_payload(NUMBER_OF_ELEMENTS).unwrap() };
-        assert_that!(sample.payload(), len type_details.size());
-        assert_that!((sample.payload().as_ptr() as usize % type_details.alignment()), eq 0);
-        assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
+    #[conformance_test]
+    pub fn copy_on_send_delivers_a_private_copy_to_every_subscriber<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        unsafe { sample.assume_init().send().unwrap() };
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_copy_on_send(true)
+            .create()
+            .unwrap();
 
-        let sample = unsafe { subscriber.receive_custom_payload().unwrap().unwrap() };
-        assert_that!(sample.payload(), len type_details.size());
-        assert_that!((sample.payload().as_ptr() as usize % type_details.alignment()), eq 0);
-        assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber_1 = sut.subscriber_builder().create().unwrap();
+        let subscriber_2 = sut.subscriber_builder().create().unwrap();
+
+        let sample = publisher.loan_uninit().unwrap().write_payload(8192);
+        let sent_payload = sample.payload() as *const u64;
+        assert_that!(sample.send(), eq Ok(2));
+
+        let sample_1 = subscriber_1.receive().unwrap().unwrap();
+        let sample_2 = subscriber_2.receive().unwrap().unwrap();
+        assert_that!(*sample_1, eq 8192);
+        assert_that!(*sample_2, eq 8192);
+
+        let payload_1 = sample_1.payload() as *const u64;
+        let payload_2 = sample_2.payload() as *const u64;
+        assert_that!(payload_1, ne payload_2);
+        assert_that!(payload_1, ne sent_payload);
+        assert_that!(payload_2, ne sent_payload);
     }
 
     #[conformance_test]
-    pub fn communication_with_custom_slice_payload_works<Sut: Service>() {
-        set_log_level(LogLevel::Error);
-        const NUMBER_OF_ELEMENTS: usize = 7;
+    pub fn copy_on_send_subscriber_holding_samples_does_not_affect_other_subscribers<
+        Sut: Service,
+    >() {
+        const ITERATIONS: u64 = 64;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let mut type_details = TypeDetail::new::<u8>(TypeVariant::Dynamic);
-        testing::type_detail_set_size(&mut type_details, 2048);
-        testing::type_detail_set_alignment(&mut type_details, 2048);
-
-        let sut = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .user_header::<CustomHeaderMarker>()
-                .__internal_set_payload_type_details(&type_details)
-                .create()
-                .unwrap()
-        };
 
-        let publisher = sut
-            .publisher_builder()
-            .initial_max_slice_len(NUMBER_OF_ELEMENTS)
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_copy_on_send(true)
+            .subscriber_max_borrowed_samples(2)
             .create()
             .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let greedy_subscriber = sut.subscriber_builder().create().unwrap();
         let subscriber = sut.subscriber_builder().create().unwrap();
 
-        let sample = unsafe { publisher.loan_custom_payload(NUMBER_OF_ELEMENTS).unwrap() };
-        assert_that!(sample.payload(), len type_details.size() * NUMBER_OF_ELEMENTS);
-        assert_that!((sample.payload().as_ptr() as usize % type_details.alignment()), eq 0);
-        assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
+        assert_that!(publisher.send_copy(1), eq Ok(2));
+        assert_that!(publisher.send_copy(2), eq Ok(2));
+        let borrowed_1 = greedy_subscriber.receive().unwrap().unwrap();
+        let borrowed_2 = greedy_subscriber.receive().unwrap().unwrap();
+        while subscriber.receive().unwrap().is_some() {}
 
-        unsafe { sample.assume_init().send().unwrap() };
+        for n in 0..ITERATIONS {
+            assert_that!(publisher.send_copy(n), eq Ok(2));
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n);
+        }
 
-        let sample = unsafe { subscriber.receive_custom_payload().unwrap().unwrap() };
-        assert_that!(sample.payload(), len type_details.size() * NUMBER_OF_ELEMENTS);
-        assert_that!((sample.payload().as_ptr() as usize % type_details.alignment()), eq 0);
-        assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
+        assert_that!(*borrowed_1, eq 1);
+        assert_that!(*borrowed_2, eq 2);
     }
 
     #[conformance_test]
-    pub fn send_increasing_samples_with_static_allocation_strategy_fails<Sut: Service>() {
-        const SLICE_SIZE: usize = 1024;
+    pub fn copy_on_send_delivers_history_to_new_subscriber<Sut: Service>() {
+        const HISTORY_SIZE: usize = 3;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let service_pub = node
+        let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u8]>()
-            .create()
-            .unwrap();
-
-        let publisher = service_pub
-            .publisher_builder()
-            .initial_max_slice_len(SLICE_SIZE)
-            .allocation_strategy(AllocationStrategy::Static)
+            .publish_subscribe::<u64>()
+            .enable_copy_on_send(true)
+            .history_size(HISTORY_SIZE)
+            .subscriber_max_buffer_size(HISTORY_SIZE)
             .create()
             .unwrap();
 
-        let sample = publisher.loan_slice(SLICE_SIZE - 1);
-        assert_that!(sample, is_ok);
-
-        let sample = publisher.loan_slice(SLICE_SIZE);
-        assert_that!(sample, is_ok);
+        let publisher = sut.publisher_builder().create().unwrap();
+        for n in 0..HISTORY_SIZE as u64 {
+            assert_that!(publisher.send_copy(n), eq Ok(0));
+        }
 
-        let sample = publisher.loan_slice(SLICE_SIZE + 1);
-        assert_that!(sample, is_err);
-        assert_that!(sample.err(), eq Some(LoanError::ExceedsMaxLoanSize));
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+
+        for n in 0..HISTORY_SIZE as u64 {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
     }
 
-    fn send_and_receives_increasing_samples_works<Sut: Service>(
-        allocation_strategy: AllocationStrategy,
-    ) {
-        const ITERATIONS: usize = 128;
+    #[conformance_test]
+    pub fn copy_on_send_works_with_increasing_samples<Sut: Service>() {
+        const ITERATIONS: usize = 32;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let service_pub = node
+        let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<[u8]>()
+            .enable_copy_on_send(true)
             .create()
             .unwrap();
 
-        let service_sub = node
-            .service_builder(&service_name)
-            .publish_subscribe::<[u8]>()
-            .open()
-            .unwrap();
-
-        let publisher = service_pub
+        let publisher = sut
             .publisher_builder()
             .initial_max_slice_len(1)
-            .allocation_strategy(allocation_strategy)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
             .create()
             .unwrap();
-
-        let subscriber = service_sub.subscriber_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
         for n in 0..ITERATIONS {
             let sample_size = (n + 1) * 32;
@@ -3492,8 +5000,7 @@ pub mod service_publish_subscribe {
             for byte in sample.payload_mut() {
                 *byte = n as u8;
             }
-
-            sample.send().unwrap();
+            assert_that!(sample.send(), eq Ok(1));
 
             let sample = subscriber.receive().unwrap().unwrap();
             assert_that!(sample.payload(), len sample_size);
@@ -3504,400 +5011,660 @@ pub mod service_publish_subscribe {
     }
 
     #[conformance_test]
-    pub fn send_and_receives_increasing_samples_works_for_best_fit_allocation_strategy<
-        Sut: Service,
-    >() {
-        send_and_receives_increasing_samples_works::<Sut>(AllocationStrategy::BestFit);
+    pub fn deadline_can_be_set<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_secs(3600);
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut_create = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .deadline(DEADLINE)
+            .create()
+            .unwrap();
+        let sut_open = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let subscriber = sut_open.subscriber_builder().create().unwrap();
+
+        assert_that!(sut_create.static_config().deadline(), eq Some(DEADLINE));
+        assert_that!(sut_open.static_config().deadline(), eq Some(DEADLINE));
+        assert_that!(subscriber.deadline(), eq Some(DEADLINE));
     }
 
     #[conformance_test]
-    pub fn send_and_receives_increasing_samples_works_for_power_of_two_allocation_strategy<
-        Sut: Service,
-    >() {
-        send_and_receives_increasing_samples_works::<Sut>(AllocationStrategy::PowerOfTwo);
+    pub fn deadline_is_disabled_by_default<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.static_config().deadline(), eq None);
+        assert_that!(subscriber.deadline(), eq None);
     }
 
-    fn send_and_receives_increasing_samples_with_overflow_works<Sut: Service>(
-        allocation_strategy: AllocationStrategy,
-    ) {
-        const SUBSCRIBER_MAX_BUFFER_SIZE: usize = 5;
-        const ITERATIONS: usize = 128;
-        const REPETITIONS: usize = 13;
+    #[conformance_test]
+    pub fn open_fails_when_deadline_does_not_match<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_secs(3600);
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let service_pub = node
+        let _sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u8]>()
-            .subscriber_max_buffer_size(SUBSCRIBER_MAX_BUFFER_SIZE)
+            .publish_subscribe::<u64>()
+            .deadline(DEADLINE)
             .create()
             .unwrap();
 
-        let service_sub = node
+        let sut_open = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u8]>()
-            .open()
-            .unwrap();
+            .publish_subscribe::<u64>()
+            .deadline(DEADLINE * 2)
+            .open();
+        assert_that!(sut_open.err(), eq Some(PublishSubscribeOpenError::IncompatibleDeadline));
 
-        let publisher = service_pub
-            .publisher_builder()
-            .initial_max_slice_len(1)
-            .allocation_strategy(allocation_strategy)
+        let sut_open = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .disable_deadline()
+            .open();
+        assert_that!(sut_open.err(), eq Some(PublishSubscribeOpenError::IncompatibleDeadline));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .deadline(DEADLINE)
+            .open();
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn message_type_schema_describes_user_header_and_payload<Sut: Service>() {
+        #[derive(Debug, ZeroCopySend)]
+        #[repr(C)]
+        struct Payload {
+            counter: u32,
+            values: [i16; 2],
+        }
+
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Payload>()
+            .user_header::<u64>()
             .create()
             .unwrap();
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Payload>()
+            .user_header::<u64>()
+            .open()
+            .unwrap();
 
-        let subscriber = service_sub.subscriber_builder().create().unwrap();
-
-        for n in 0..ITERATIONS {
-            let sample_size = (n + 1) * 32;
-            for _ in 0..REPETITIONS {
-                let mut sample = publisher.loan_slice(sample_size).unwrap();
-                for byte in sample.payload_mut() {
-                    *byte = n as u8;
-                }
+        let schema = sut.static_config().message_type_schema();
+        assert_that!(*sut2.static_config().message_type_schema(), eq * schema);
+        assert_that!(schema.user_header.entries(), len 1);
+        assert_that!(schema.payload.entries(), len 4);
 
-                sample.send().unwrap();
-            }
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut2.subscriber_builder().create().unwrap();
+        let mut sample = publisher.loan_uninit().unwrap();
+        *sample.user_header_mut() = 31;
+        sample
+            .write_payload(Payload {
+                counter: 9,
+                values: [-1, 2],
+            })
+            .send()
+            .unwrap();
+        let sample = subscriber.receive().unwrap().unwrap();
 
-            let sample = subscriber.receive().unwrap().unwrap();
-            assert_that!(sample.payload(), len sample_size);
-            for byte in sample.payload() {
-                assert_that!(*byte, eq n as u8);
-            }
-        }
+        let payload = unsafe {
+            core::slice::from_raw_parts(
+                (sample.payload() as *const Payload).cast::<u8>(),
+                core::mem::size_of::<Payload>(),
+            )
+        };
+        let expected = SchemaValue::Struct(vec![
+            ("counter".to_string(), SchemaValue::Unsigned(9)),
+            (
+                "values".to_string(),
+                SchemaValue::Sequence(vec![SchemaValue::Signed(-1), SchemaValue::Signed(2)]),
+            ),
+        ]);
+        assert_that!(schema.payload.decode(payload), eq Ok(expected));
+        assert_that!(
+            schema.user_header.decode(&sample.user_header().to_ne_bytes()),
+            eq Ok(SchemaValue::Unsigned(31))
+        );
     }
 
     #[conformance_test]
-    pub fn send_and_receives_increasing_samples_with_overflow_for_best_fit_allocation_strategy<
-        Sut: Service,
-    >() {
-        send_and_receives_increasing_samples_with_overflow_works::<Sut>(
-            AllocationStrategy::BestFit,
-        );
+    pub fn message_type_schema_is_empty_for_custom_payload_types<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .user_header::<CustomHeaderMarker>()
+                .__internal_set_payload_type_details(&TypeDetail::new::<u64>(
+                    TypeVariant::FixedSize,
+                ))
+                .__internal_set_user_header_type_details(&TypeDetail::new::<u8>(
+                    TypeVariant::FixedSize,
+                ))
+                .create()
+                .unwrap()
+        };
+
+        assert_that!(sut.static_config().message_type_schema().payload.is_empty(), eq true);
+        assert_that!(sut.static_config().message_type_schema().user_header.is_empty(), eq true);
     }
 
     #[conformance_test]
-    pub fn send_and_receives_increasing_samples_with_overflow_for_power_of_two_allocation_strategy<
-        Sut: Service,
-    >() {
-        send_and_receives_increasing_samples_with_overflow_works::<Sut>(
-            AllocationStrategy::PowerOfTwo,
-        );
-    }
+    pub fn open_fails_when_type_schema_has_reordered_fields<Sut: Service>() {
+        mod v1 {
+            use super::*;
+            #[derive(Debug, ZeroCopySend)]
+            #[repr(C)]
+            #[type_name("Sensor")]
+            pub struct Sensor {
+                pub id: u32,
+                pub value: f32,
+            }
+        }
+        mod v2 {
+            use super::*;
+            #[derive(Debug, ZeroCopySend)]
+            #[repr(C)]
+            #[type_name("Sensor")]
+            pub struct Sensor {
+                pub value: f32,
+                pub id: u32,
+            }
+        }
 
-    fn deliver_history_with_increasing_samples_works<Sut: Service>(
-        allocation_strategy: AllocationStrategy,
-    ) {
-        const SUBSCRIBER_MAX_BUFFER_SIZE: usize = 12;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let service_pub = node
+        let _sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u8]>()
-            .subscriber_max_buffer_size(SUBSCRIBER_MAX_BUFFER_SIZE)
-            .history_size(SUBSCRIBER_MAX_BUFFER_SIZE)
+            .publish_subscribe::<v1::Sensor>()
             .create()
             .unwrap();
 
-        let service_sub = node
+        let sut2 = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u8]>()
-            .open()
-            .unwrap();
+            .publish_subscribe::<v2::Sensor>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypeSchema));
 
-        let publisher = service_pub
-            .publisher_builder()
-            .initial_max_slice_len(1)
-            .allocation_strategy(allocation_strategy)
-            .create()
-            .unwrap();
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<v2::Sensor>()
+            .schema_compatibility(SchemaCompatibility::AppendOnly)
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::TypeSchemaIsNotAppendOnly));
 
-        for n in 0..SUBSCRIBER_MAX_BUFFER_SIZE {
-            let sample_size = (n + 1) * 32;
-            let mut sample = publisher.loan_slice(sample_size).unwrap();
-            for byte in sample.payload_mut() {
-                *byte = n as u8;
-            }
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<v2::Sensor>()
+            .schema_compatibility(SchemaCompatibility::Ignore)
+            .open();
+        assert_that!(sut2, is_ok);
+    }
 
-            sample.send().unwrap();
+    #[conformance_test]
+    pub fn open_fails_when_type_version_differs<Sut: Service>() {
+        mod v1 {
+            use super::*;
+            #[derive(Debug, ZeroCopySend)]
+            #[repr(C)]
+            #[type_name("Sensor")]
+            #[type_version(1)]
+            pub struct Sensor {
+                pub id: u32,
+            }
         }
-
-        let subscriber = service_sub.subscriber_builder().create().unwrap();
-        publisher.update_connections().unwrap();
-
-        for n in 0..SUBSCRIBER_MAX_BUFFER_SIZE {
-            let sample_size = (n + 1) * 32;
-            let sample = subscriber.receive().unwrap().unwrap();
-            assert_that!(sample.payload(), len sample_size);
-            for byte in sample.payload() {
-                assert_that!(*byte, eq n as u8);
+        mod v2 {
+            use super::*;
+            #[derive(Debug, ZeroCopySend)]
+            #[repr(C)]
+            #[type_name("Sensor")]
+            #[type_version(2)]
+            pub struct Sensor {
+                pub id: u32,
             }
         }
+
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<v1::Sensor>()
+            .user_header::<v1::Sensor>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<v1::Sensor>()
+            .user_header::<v2::Sensor>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypeVersion));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<v2::Sensor>()
+            .user_header::<v1::Sensor>()
+            .schema_compatibility(SchemaCompatibility::AppendOnly)
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypeVersion));
     }
 
     #[conformance_test]
-    pub fn deliver_history_with_increasing_samples_works_for_best_fit_allocation_strategy<
-        Sut: Service,
-    >() {
-        deliver_history_with_increasing_samples_works::<Sut>(AllocationStrategy::BestFit);
+    pub fn append_only_schema_compatibility_allows_older_types_to_open_service<Sut: Service>() {
+        mod v1 {
+            use super::*;
+            #[derive(Debug, ZeroCopySend)]
+            #[repr(C)]
+            #[type_name("Sensor")]
+            #[type_version(1)]
+            pub struct Sensor {
+                pub id: u32,
+                pub value: f32,
+            }
+        }
+        mod v2 {
+            use super::*;
+            #[derive(Debug, ZeroCopySend)]
+            #[repr(C)]
+            #[type_name("Sensor")]
+            #[type_version(2)]
+            pub struct Sensor {
+                pub id: u32,
+                pub value: f32,
+                pub timestamp: u64,
+            }
+        }
+
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<v2::Sensor>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<v1::Sensor>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<v1::Sensor>()
+            .schema_compatibility(SchemaCompatibility::AppendOnly)
+            .open()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut2.subscriber_builder().create().unwrap();
+        publisher
+            .send_copy(v2::Sensor {
+                id: 12,
+                value: 3.5,
+                timestamp: 789,
+            })
+            .unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.id, eq 12);
+        assert_that!(sample.value, eq 3.5);
     }
 
     #[conformance_test]
-    pub fn deliver_history_with_increasing_samples_works_for_power_of_two_allocation_strategy<
+    pub fn append_only_schema_compatibility_zeroes_appended_fields_of_older_publisher<
         Sut: Service,
     >() {
-        deliver_history_with_increasing_samples_works::<Sut>(AllocationStrategy::PowerOfTwo);
-    }
+        mod v1 {
+            use super::*;
+            #[derive(Debug, Default, ZeroCopySend)]
+            #[repr(C)]
+            #[type_name("Sensor")]
+            #[type_version(1)]
+            pub struct Sensor {
+                pub id: u32,
+                pub value: f32,
+            }
+        }
+        mod v2 {
+            use super::*;
+            #[derive(Debug, Default, ZeroCopySend)]
+            #[repr(C)]
+            #[type_name("Sensor")]
+            #[type_version(2)]
+            pub struct Sensor {
+                pub id: u32,
+                pub value: f32,
+                pub timestamp: u64,
+            }
+        }
 
-    #[conformance_test]
-    pub fn does_not_leak_when_subscriber_has_smaller_buffer_size_than_history_size<Sut: Service>() {
-        let _watchdog = Watchdog::new();
-        const HISTORY_SIZE: usize = 1000;
-        const REPETITIONS: usize = 10;
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let finish_handle = BarrierHandle::new();
-        let start_handle = BarrierHandle::new();
-        let end_handle = BarrierHandle::new();
-        let finish_setup = BarrierBuilder::new(2).create(&finish_handle).unwrap();
-        let start = BarrierBuilder::new(2).create(&start_handle).unwrap();
-        let end = BarrierBuilder::new(2).create(&end_handle).unwrap();
-
-        thread_scope(|s| {
-            s.thread_builder().spawn(|| {
-                let service = node
-                    .service_builder(&service_name)
-                    .publish_subscribe::<usize>()
-                    .max_publishers(1)
-                    .max_subscribers(1)
-                    .subscriber_max_borrowed_samples(1)
-                    .history_size(HISTORY_SIZE)
-                    .subscriber_max_buffer_size(HISTORY_SIZE)
-                    .create()
-                    .unwrap();
-
-                let publisher = service
-                    .publisher_builder()
-                    .max_loaned_samples(1)
-                    .create()
-                    .unwrap();
-
-                for n in 0..HISTORY_SIZE {
-                    publisher.send_copy(n).unwrap();
-                }
-
-                finish_setup.wait();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<v2::Sensor>()
+            .create()
+            .unwrap();
 
-                for _ in 0..REPETITIONS {
-                    start.wait();
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<v1::Sensor>()
+            .schema_compatibility(SchemaCompatibility::AppendOnly)
+            .open()
+            .unwrap();
 
-                    publisher.update_connections().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut2.publisher_builder().create().unwrap();
 
-                    end.wait();
-                }
-            })?;
+        // the chunks of the service are large enough for the newer type, the memory behind the
+        // older type is dirtied before the chunk is returned and loaned again
+        let mut sample = publisher.loan().unwrap();
+        unsafe {
+            (sample.payload_mut() as *mut v1::Sensor)
+                .cast::<u8>()
+                .add(size_of::<v1::Sensor>())
+                .write_bytes(0xff, size_of::<v2::Sensor>() - size_of::<v1::Sensor>())
+        };
+        drop(sample);
 
-            s.thread_builder().spawn(|| {
-                finish_setup.wait();
+        let mut sample = publisher.loan().unwrap();
+        sample.payload_mut().id = 13;
+        sample.payload_mut().value = 4.5;
+        sample.send().unwrap();
 
-                let service = node
-                    .service_builder(&service_name)
-                    .publish_subscribe::<usize>()
-                    .open()
-                    .unwrap();
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.id, eq 13);
+        assert_that!(sample.value, eq 4.5);
+        assert_that!(sample.timestamp, eq 0);
+    }
 
-                for _ in 0..REPETITIONS {
-                    let subscriber = service
-                        .subscriber_builder()
-                        .buffer_size(1)
-                        .create()
-                        .unwrap();
-                    start.wait();
+    #[conformance_test]
+    pub fn subscriber_reports_every_missed_deadline_once<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_nanos(1);
+        let service_name = generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-                    let mut previous_value = 0;
-                    for _ in 0..HISTORY_SIZE {
-                        let sample = subscriber.receive().unwrap();
-                        if let Some(sample) = sample {
-                            assert_that!(*sample, ge previous_value);
-                            previous_value = *sample;
-                        }
-                    }
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .deadline(DEADLINE)
+            .create()
+            .unwrap();
 
-                    end.wait();
+        let missed_deadlines = Arc::new(AtomicUsize::new(0));
+        let missed_deadlines_in_handler = missed_deadlines.clone();
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .set_degradation_handler(move |cause, info| {
+                if matches!(cause, DegradationCause::MissedDeadline) {
+                    assert_that!(info.sender_port_id, eq 0);
+                    missed_deadlines_in_handler.fetch_add(1, Ordering::Relaxed);
                 }
-            })?;
+                DegradationAction::Ignore
+            })
+            .create()
+            .unwrap();
 
-            Ok(())
-        })
-        .unwrap();
+        nanosleep(Duration::from_millis(1)).unwrap();
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(missed_deadlines.load(Ordering::Relaxed), eq 1);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(missed_deadlines.load(Ordering::Relaxed), eq 1);
+
+        assert_that!(publisher.send_copy(1234), eq Ok(1));
+        nanosleep(Duration::from_millis(1)).unwrap();
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 1234);
+        assert_that!(missed_deadlines.load(Ordering::Relaxed), eq 2);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(missed_deadlines.load(Ordering::Relaxed), eq 2);
     }
 
     #[conformance_test]
-    pub fn listing_all_publishers_works<S: Service>() {
-        const NUMBER_OF_PUBLISHERS: usize = 18;
+    pub fn subscriber_does_not_report_deadline_when_samples_arrive_in_time<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_secs(3600);
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .max_publishers(NUMBER_OF_PUBLISHERS)
+            .deadline(DEADLINE)
             .create()
             .unwrap();
 
-        let mut publishers = vec![];
-
-        for _ in 0..NUMBER_OF_PUBLISHERS {
-            publishers.push(sut.publisher_builder().create().unwrap());
-        }
-
-        let mut publisher_details = vec![];
-        sut.dynamic_config().list_publishers(|details| {
-            publisher_details.push(details.publisher_id);
-            CallbackProgression::Continue
-        });
+        let missed_deadlines = Arc::new(AtomicUsize::new(0));
+        let missed_deadlines_in_handler = missed_deadlines.clone();
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .set_degradation_handler(move |cause, _| {
+                if matches!(cause, DegradationCause::MissedDeadline) {
+                    missed_deadlines_in_handler.fetch_add(1, Ordering::Relaxed);
+                }
+                DegradationAction::Ignore
+            })
+            .create()
+            .unwrap();
 
-        assert_that!(publisher_details, len NUMBER_OF_PUBLISHERS);
-        for publisher in publishers {
-            assert_that!(publisher_details, contains publisher.id());
-        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(publisher.send_copy(1234), eq Ok(1));
+        assert_that!(subscriber.receive().unwrap(), is_some);
+        assert_that!(missed_deadlines.load(Ordering::Relaxed), eq 0);
     }
 
     #[conformance_test]
-    pub fn listing_all_publishers_stops_on_request<S: Service>() {
-        const NUMBER_OF_PUBLISHERS: usize = 16;
+    pub fn receive_fails_on_missed_deadline_when_degradation_handler_fails<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_nanos(1);
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .max_publishers(NUMBER_OF_PUBLISHERS)
+            .deadline(DEADLINE)
             .create()
             .unwrap();
 
-        let mut publishers = vec![];
-
-        for _ in 0..NUMBER_OF_PUBLISHERS {
-            publishers.push(sut.publisher_builder().create().unwrap());
-        }
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .set_degradation_handler(|_, _| DegradationAction::DegradeAndFail)
+            .create()
+            .unwrap();
 
-        let mut counter = 0;
-        sut.dynamic_config().list_publishers(|_| {
-            counter += 1;
-            CallbackProgression::Stop
-        });
+        nanosleep(Duration::from_millis(1)).unwrap();
+        assert_that!(subscriber.receive().err(), eq Some(ReceiveError::MissedDeadline));
+        assert_that!(subscriber.receive().unwrap(), is_none);
 
-        assert_that!(counter, eq 1);
+        assert_that!(publisher.send_copy(1234), eq Ok(1));
+        nanosleep(Duration::from_millis(1)).unwrap();
+        assert_that!(subscriber.receive().err(), eq Some(ReceiveError::MissedDeadline));
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 1234);
     }
 
     #[conformance_test]
-    pub fn listing_all_subscribers_works<S: Service>() {
-        const NUMBER_OF_SUBSCRIBERS: usize = 18;
+    pub fn subscriber_with_wakeup_wakes_up_waitset_on_missed_deadline<Sut: Service>()
+    where
+        <Sut::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing,
+    {
+        const DEADLINE: Duration = Duration::from_millis(10);
         let service_name = generate_service_name();
         let config = testing::generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .max_subscribers(NUMBER_OF_SUBSCRIBERS)
+            .enable_subscriber_wakeup(true)
+            .deadline(DEADLINE)
             .create()
             .unwrap();
 
-        let mut subscribers = vec![];
+        let subscriber = sut
+            .subscriber_builder()
+            .set_degradation_handler(|_, _| DegradationAction::DegradeAndFail)
+            .create_with_wakeup()
+            .unwrap();
 
-        for _ in 0..NUMBER_OF_SUBSCRIBERS {
-            subscribers.push(sut.subscriber_builder().create().unwrap());
-        }
+        let waitset = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard = waitset
+            .attach_deadline(&subscriber, subscriber.deadline().unwrap())
+            .unwrap();
 
-        let mut subscriber_details = vec![];
-        sut.dynamic_config().list_subscribers(|details| {
-            subscriber_details.push(details.subscriber_id);
-            CallbackProgression::Continue
-        });
+        let mut has_missed_deadline = false;
+        let result = waitset.wait_and_process_once_with_timeout(
+            |attachment_id| {
+                has_missed_deadline = attachment_id.has_missed_deadline(&guard);
+                CallbackProgression::Continue
+            },
+            Duration::from_secs(10),
+        );
 
-        assert_that!(subscriber_details, len NUMBER_OF_SUBSCRIBERS);
-        for subscriber in subscribers {
-            assert_that!(subscriber_details, contains subscriber.id());
-        }
+        assert_that!(result, eq Ok(WaitSetRunResult::AllEventsHandled));
+        assert_that!(has_missed_deadline, eq true);
+        assert_that!(subscriber.receive().err(), eq Some(ReceiveError::MissedDeadline));
     }
 
     #[conformance_test]
-    pub fn listing_all_subscribers_stops_on_request<S: Service>() {
-        const NUMBER_OF_SUBSCRIBERS: usize = 16;
+    pub fn subscriber_cannot_write_into_received_sample<Sut: Service>() {
+        test_requires!(testing::does_support_read_only_data_segments::<Sut>());
+
         let service_name = generate_service_name();
-        let config = testing::generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let mut config = testing::generate_isolated_config();
+        config.global.service.enforce_read_only_data_segments = true;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .max_subscribers(NUMBER_OF_SUBSCRIBERS)
             .create()
             .unwrap();
 
-        let mut subscribers = vec![];
-
-        for _ in 0..NUMBER_OF_SUBSCRIBERS {
-            subscribers.push(sut.subscriber_builder().create().unwrap());
-        }
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        let mut counter = 0;
-        sut.dynamic_config().list_subscribers(|_| {
-            counter += 1;
-            CallbackProgression::Stop
-        });
+        let sample = publisher.loan_uninit().unwrap().write_payload(8127);
+        let sent_payload = sample.payload() as *const u64 as *const u8;
+        assert_that!(unsafe { is_memory_writable(sent_payload, size_of::<u64>()) }, eq true);
+        sample.send().unwrap();
 
-        assert_that!(counter, eq 1);
+        let sample = subscriber.receive().unwrap().unwrap();
+        let received_payload = sample.payload() as *const u64 as *const u8;
+        assert_that!(unsafe { is_memory_writable(received_payload, size_of::<u64>()) }, eq false);
+        assert_that!(*sample, eq 8127);
     }
 
     #[conformance_test]
-    pub fn receive_does_not_return_error_when_pub_goes_out_of_scope_after_reallocation<
-        S: Service,
-    >() {
-        const SLICE_MAX_LEN: usize = 1;
+    pub fn subscriber_cannot_write_into_received_sample_of_resized_data_segment<Sut: Service>() {
+        test_requires!(testing::does_support_read_only_data_segments::<Sut>());
+
+        const INITIAL_SLICE_LEN: usize = 8;
         let service_name = generate_service_name();
-        let config = testing::generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let mut config = testing::generate_isolated_config();
+        config.global.service.enforce_read_only_data_segments = true;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let service = node
+        let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<[u8]>()
             .create()
             .unwrap();
 
-        let subscriber = service.subscriber_builder().create().unwrap();
-
-        let publisher = service
+        let publisher = sut
             .publisher_builder()
-            .initial_max_slice_len(SLICE_MAX_LEN)
-            .allocation_strategy(AllocationStrategy::BestFit)
+            .initial_max_slice_len(INITIAL_SLICE_LEN)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
             .create()
             .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        // send and receive once so that the subscriber maps the data segment
-        let sample = publisher.loan_slice_uninit(SLICE_MAX_LEN).unwrap();
-        sample.write_from_fn(|i| i as u8).send().unwrap();
+        for slice_len in [INITIAL_SLICE_LEN, INITIAL_SLICE_LEN * 64] {
+            let sample = publisher
+                .loan_slice_uninit(slice_len)
+                .unwrap()
+                .write_from_fn(|_| 0xaf);
+            sample.send().unwrap();
 
-        let recv_sample = subscriber.receive();
-        assert_that!(recv_sample, is_ok);
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.payload(), len slice_len);
+            assert_that!(
+                unsafe { is_memory_writable(sample.payload().as_ptr(), slice_len) },
+                eq false
+            );
+            for byte in sample.payload() {
+                assert_that!(*byte, eq 0xaf);
+            }
+        }
+    }
 
-        // publisher has to reallocate the data segment
-        let sample = publisher.loan_slice_uninit(SLICE_MAX_LEN + 4096).unwrap();
-        sample.write_from_fn(|i| i as u8).send().unwrap();
-        // publisher goes out of scope and closes the reallocated data segment as it was not yet mapped by the
-        // subscriber
-        drop(publisher);
+    #[conformance_test]
+    pub fn copy_on_send_subscriber_cannot_write_into_received_sample<Sut: Service>() {
+        test_requires!(testing::does_support_read_only_data_segments::<Sut>());
 
-        let recv_res = subscriber.receive();
-        assert_that!(recv_res, is_ok);
+        let service_name = generate_service_name();
+        let mut config = testing::generate_isolated_config();
+        config.global.service.enforce_read_only_data_segments = true;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_copy_on_send(true)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(1911).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        let received_payload = sample.payload() as *const u64 as *const u8;
+        assert_that!(unsafe { is_memory_writable(received_payload, size_of::<u64>()) }, eq false);
+        assert_that!(*sample, eq 1911);
     }
 }
@@ -16,18 +16,35 @@ use iceoryx2_bb_testing_macros::conformance_tests;
 #[conformance_tests]
 pub mod service_request_response {
     use alloc::collections::BTreeSet;
-    use alloc::{vec, vec::Vec};
+    use alloc::{format, string::String, vec, vec::Vec};
 
     use iceoryx2::node::NodeBuilder;
-    use iceoryx2::port::LoanError;
-    use iceoryx2::port::client::Client;
+    use iceoryx2::port::client::{Client, RequestSendSerializedError};
     use iceoryx2::port::server::Server;
+    use iceoryx2::port::{LoanError, ReceiveDeserializedError, SendSerializedError};
     use iceoryx2::prelude::{PortFactory, *};
+    use iceoryx2::service::builder::request_response::RequestResponseOpenError;
     use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
+    use iceoryx2::service::header::TraceContext;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+    use iceoryx2::service::static_config::type_schema::SchemaCompatibility;
     use iceoryx2::testing;
-    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_posix::testing::is_memory_writable;
+    use iceoryx2_bb_testing::{assert_that, test_requires};
     use iceoryx2_bb_testing_macros::conformance_test;
+    use iceoryx2_cal::serialize::postcard::Postcard;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct NonZeroCopyRequest {
+        name: String,
+        values: Vec<u64>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct NonZeroCopyResponse {
+        answer: String,
+    }
 
     #[derive(Clone, Copy)]
     struct Args {
@@ -1610,4 +1627,374 @@ pub mod service_request_response {
         assert_that!(active_request.is_connected(), eq false);
         assert_that!(active_request.has_disconnect_hint(), eq false);
     }
+
+    #[conformance_test]
+    pub fn response_continues_trace_context_of_request<S: Service>() {
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let request_trace_context = TraceContext {
+            trace_id: [5; 16],
+            span_id: [1; 8],
+            trace_flags: 1,
+        };
+        let response_trace_context = TraceContext {
+            span_id: [2; 8],
+            ..request_trace_context
+        };
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<usize, usize>()
+            .max_response_buffer_size(2)
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let mut request = client.loan().unwrap();
+        request.set_trace_context(request_trace_context);
+        *request.payload_mut() = 123;
+        let pending_response = request.send().unwrap();
+
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(active_request.header().trace_context(), eq Some(request_trace_context));
+
+        assert_that!(active_request.send_copy(456), is_ok);
+        let mut response = active_request.loan().unwrap();
+        response.set_trace_context(response_trace_context);
+        *response.payload_mut() = 789;
+        assert_that!(response.send(), is_ok);
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response, eq 456);
+        assert_that!(response.header().trace_context(), eq Some(request_trace_context));
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response, eq 789);
+        assert_that!(response.header().trace_context(), eq Some(response_trace_context));
+    }
+
+    #[conformance_test]
+    pub fn request_without_trace_context_has_none<S: Service>() {
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<usize, usize>()
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(123).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(active_request.header().trace_context(), is_none);
+
+        assert_that!(active_request.send_copy(456), is_ok);
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(response.header().trace_context(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn server_cannot_write_into_received_request<Sut: Service>() {
+        test_requires!(testing::does_support_read_only_data_segments::<Sut>());
+
+        let service_name = testing::generate_service_name();
+        let mut config = testing::generate_isolated_config();
+        config.global.service.enforce_read_only_data_segments = true;
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let server = sut.server_builder().create().unwrap();
+        let client = sut.client_builder().create().unwrap();
+
+        let request = client.loan_uninit().unwrap().write_payload(5512);
+        let sent_payload = request.payload() as *const u64 as *const u8;
+        assert_that!(unsafe { is_memory_writable(sent_payload, size_of::<u64>()) }, eq true);
+        let _pending_response = request.send().unwrap();
+
+        let active_request = server.receive().unwrap().unwrap();
+        let received_payload = active_request.payload() as *const u64 as *const u8;
+        assert_that!(unsafe { is_memory_writable(received_payload, size_of::<u64>()) }, eq false);
+        assert_that!(*active_request.payload(), eq 5512);
+    }
+
+    #[conformance_test]
+    pub fn client_cannot_write_into_received_response<Sut: Service>() {
+        test_requires!(testing::does_support_read_only_data_segments::<Sut>());
+
+        let service_name = testing::generate_service_name();
+        let mut config = testing::generate_isolated_config();
+        config.global.service.enforce_read_only_data_segments = true;
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let server = sut.server_builder().create().unwrap();
+        let client = sut.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(0).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+
+        let response = active_request.loan_uninit().unwrap().write_payload(9921);
+        let sent_payload = response.payload() as *const u64 as *const u8;
+        assert_that!(unsafe { is_memory_writable(sent_payload, size_of::<u64>()) }, eq true);
+        response.send().unwrap();
+
+        let response = pending_response.receive().unwrap().unwrap();
+        let received_payload = response.payload() as *const u64 as *const u8;
+        assert_that!(unsafe { is_memory_writable(received_payload, size_of::<u64>()) }, eq false);
+        assert_that!(*response.payload(), eq 9921);
+    }
+
+    #[conformance_test]
+    pub fn send_and_receive_serialized_request_and_response_works<Sut: Service>() {
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .serialized_request_payload::<Postcard, NonZeroCopyRequest>()
+            .serialized_response_payload::<Postcard, NonZeroCopyResponse>()
+            .create()
+            .unwrap();
+
+        let server = sut
+            .server_builder()
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        let client = sut
+            .client_builder()
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+
+        for i in 0..4 {
+            let request = NonZeroCopyRequest {
+                name: format!("what is the answer {i}"),
+                values: (0..i * 100).collect(),
+            };
+            let pending_response = client.send_serialized::<Postcard, _>(&request).unwrap();
+
+            let (active_request, received_request) = server
+                .receive_deserialized::<Postcard, NonZeroCopyRequest>()
+                .unwrap()
+                .unwrap();
+            assert_that!(received_request, eq request);
+
+            let response = NonZeroCopyResponse {
+                answer: format!("the answer is {i}"),
+            };
+            assert_that!(
+                active_request.send_serialized::<Postcard, _>(&response),
+                is_ok
+            );
+
+            let received_response = pending_response
+                .receive_deserialized::<Postcard, NonZeroCopyResponse>()
+                .unwrap();
+            assert_that!(received_response, eq Some(response));
+            assert_that!(
+                pending_response
+                    .receive_deserialized::<Postcard, NonZeroCopyResponse>()
+                    .unwrap(),
+                is_none
+            );
+        }
+
+        let received = server
+            .receive_deserialized::<Postcard, NonZeroCopyRequest>()
+            .unwrap();
+        assert_that!(received, is_none);
+    }
+
+    #[conformance_test]
+    pub fn open_serialized_request_response_service_with_incompatible_type_fails<Sut: Service>() {
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .serialized_request_payload::<Postcard, NonZeroCopyRequest>()
+            .serialized_response_payload::<Postcard, NonZeroCopyResponse>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .serialized_request_payload::<Postcard, String>()
+            .serialized_response_payload::<Postcard, NonZeroCopyResponse>()
+            .open();
+        assert_that!(sut2.err(), eq Some(RequestResponseOpenError::IncompatibleRequestType));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .serialized_request_payload::<Postcard, NonZeroCopyRequest>()
+            .serialized_response_payload::<Postcard, String>()
+            .open();
+        assert_that!(sut2.err(), eq Some(RequestResponseOpenError::IncompatibleResponseType));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .serialized_request_payload::<Postcard, NonZeroCopyRequest>()
+            .serialized_response_payload::<Postcard, NonZeroCopyResponse>()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn send_and_receive_serialized_request_and_response_with_incompatible_type_fails<
+        Sut: Service,
+    >() {
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .serialized_request_payload::<Postcard, NonZeroCopyRequest>()
+            .serialized_response_payload::<Postcard, NonZeroCopyResponse>()
+            .create()
+            .unwrap();
+
+        let server = sut
+            .server_builder()
+            .initial_max_slice_len(128)
+            .create()
+            .unwrap();
+        let client = sut
+            .client_builder()
+            .initial_max_slice_len(128)
+            .create()
+            .unwrap();
+
+        let result = client.send_serialized::<Postcard, _>(&String::from("hypnotoad"));
+        assert_that!(result.err(), eq Some(RequestSendSerializedError::IncompatibleSerialization));
+
+        let result = server.receive_deserialized::<Postcard, String>();
+        assert_that!(result.err(), eq Some(ReceiveDeserializedError::IncompatibleSerialization));
+
+        let request = NonZeroCopyRequest {
+            name: String::from("all glory"),
+            values: vec![1, 2, 3],
+        };
+        let pending_response = client.send_serialized::<Postcard, _>(&request).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+
+        let result = active_request.send_serialized::<Postcard, _>(&String::from("hypnotoad"));
+        assert_that!(result, eq Err(SendSerializedError::IncompatibleSerialization));
+
+        let result = pending_response.receive_deserialized::<Postcard, String>();
+        assert_that!(result.err(), eq Some(ReceiveDeserializedError::IncompatibleSerialization));
+    }
+
+    #[conformance_test]
+    pub fn append_only_schema_compatibility_zeroes_appended_fields_of_older_ports<Sut: Service>() {
+        mod v1 {
+            use super::*;
+            #[derive(Debug, Default, ZeroCopySend)]
+            #[repr(C)]
+            #[type_name("Command")]
+            #[type_version(1)]
+            pub struct Command {
+                pub id: u32,
+            }
+        }
+        mod v2 {
+            use super::*;
+            #[derive(Debug, Default, ZeroCopySend)]
+            #[repr(C)]
+            #[type_name("Command")]
+            #[type_version(2)]
+            pub struct Command {
+                pub id: u32,
+                pub priority: u32,
+            }
+        }
+
+        // dirties the memory behind the older type, the chunks of the service are large enough
+        // for the newer type
+        fn dirty_appended_fields(payload: &mut v1::Command) {
+            unsafe {
+                (payload as *mut v1::Command)
+                    .cast::<u8>()
+                    .add(size_of::<v1::Command>())
+                    .write_bytes(0xff, size_of::<v2::Command>() - size_of::<v1::Command>())
+            };
+        }
+
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<v2::Command, v2::Command>()
+            .create()
+            .unwrap();
+        let sut_old = node
+            .service_builder(&service_name)
+            .request_response::<v1::Command, v1::Command>()
+            .schema_compatibility(SchemaCompatibility::AppendOnly)
+            .open()
+            .unwrap();
+
+        // request of an older client
+        let server = sut.server_builder().create().unwrap();
+        let client = sut_old.client_builder().create().unwrap();
+
+        let mut request = client.loan().unwrap();
+        dirty_appended_fields(request.payload_mut());
+        drop(request);
+
+        let mut request = client.loan().unwrap();
+        request.payload_mut().id = 3;
+        let _pending_response = request.send().unwrap();
+
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(active_request.id, eq 3);
+        assert_that!(active_request.priority, eq 0);
+        drop(active_request);
+        drop(client);
+        drop(server);
+
+        // response of an older server
+        let server = sut_old.server_builder().create().unwrap();
+        let client = sut.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(v2::Command::default()).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+
+        let mut response = active_request.loan().unwrap();
+        dirty_appended_fields(response.payload_mut());
+        drop(response);
+
+        let mut response = active_request.loan().unwrap();
+        response.payload_mut().id = 5;
+        response.send().unwrap();
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(response.id, eq 5);
+        assert_that!(response.priority, eq 0);
+    }
 }
@@ -26,6 +26,7 @@ pub mod service_request_response_builder {
     use iceoryx2::service::port_factory::client::ClientCreateError;
     use iceoryx2::service::port_factory::server::ServerCreateError;
     use iceoryx2::service::static_config::message_type_details::TypeVariant;
+    use iceoryx2::service::static_config::type_schema::SchemaCompatibility;
     use iceoryx2::testing::*;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing_macros::conformance_test;
@@ -1252,4 +1253,155 @@ pub mod service_request_response_builder {
             .open();
         assert_that!(sut_open, is_ok);
     }
+
+    #[conformance_test]
+    pub fn opening_service_with_mismatching_request_or_response_type_schema_fails<Sut: Service>() {
+        mod v1 {
+            use super::*;
+            #[derive(Debug, ZeroCopySend)]
+            #[repr(C)]
+            #[type_name("Command")]
+            #[type_version(1)]
+            pub struct Command {
+                pub id: u32,
+                pub value: f32,
+            }
+        }
+        mod v2 {
+            use super::*;
+            #[derive(Debug, ZeroCopySend)]
+            #[repr(C)]
+            #[type_name("Command")]
+            #[type_version(1)]
+            pub struct Command {
+                pub value: f32,
+                pub id: u32,
+            }
+        }
+        mod v3 {
+            use super::*;
+            #[derive(Debug, ZeroCopySend)]
+            #[repr(C)]
+            #[type_name("Command")]
+            #[type_version(3)]
+            pub struct Command {
+                pub id: u32,
+                pub value: f32,
+            }
+        }
+
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<v1::Command, v1::Command>()
+            .create();
+        assert_that!(sut_create, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<v2::Command, v1::Command>()
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleRequestTypeSchema));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<v1::Command, v2::Command>()
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleResponseTypeSchema));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<v3::Command, v1::Command>()
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleRequestTypeVersion));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<v1::Command, v3::Command>()
+            .schema_compatibility(SchemaCompatibility::AppendOnly)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleResponseTypeVersion));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<v2::Command, v2::Command>()
+            .schema_compatibility(SchemaCompatibility::Ignore)
+            .open();
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn opening_service_with_append_only_schema_compatibility_works<Sut: Service>() {
+        mod v1 {
+            use super::*;
+            #[derive(Debug, ZeroCopySend)]
+            #[repr(C)]
+            #[type_name("Command")]
+            #[type_version(1)]
+            pub struct Command {
+                pub id: u32,
+            }
+        }
+        mod v2 {
+            use super::*;
+            #[derive(Debug, ZeroCopySend)]
+            #[repr(C)]
+            #[type_name("Command")]
+            #[type_version(2)]
+            pub struct Command {
+                pub id: u32,
+                pub priority: u32,
+            }
+        }
+        mod v3 {
+            use super::*;
+            #[derive(Debug, ZeroCopySend)]
+            #[repr(C)]
+            #[type_name("Command")]
+            #[type_version(1)]
+            pub struct Command {
+                pub priority: u32,
+            }
+        }
+
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<v2::Command, v2::Command>()
+            .create();
+        assert_that!(sut_create, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<v1::Command, v1::Command>()
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleRequestType));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<v1::Command, v1::Command>()
+            .schema_compatibility(SchemaCompatibility::AppendOnly)
+            .open();
+        assert_that!(sut_open, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<v3::Command, v1::Command>()
+            .schema_compatibility(SchemaCompatibility::AppendOnly)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::RequestTypeSchemaIsNotAppendOnly));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<v1::Command, v3::Command>()
+            .schema_compatibility(SchemaCompatibility::AppendOnly)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::ResponseTypeSchemaIsNotAppendOnly));
+    }
 }
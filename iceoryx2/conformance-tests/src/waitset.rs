@@ -30,7 +30,7 @@ pub mod waitset {
         UnixDatagramReceiver, UnixDatagramSender, UnixDatagramSenderBuilder,
     };
     use iceoryx2_bb_posix::{
-        file_descriptor_set::SynchronousMultiplexing,
+        file_descriptor::FileDescriptorBased, file_descriptor_set::SynchronousMultiplexing,
         unix_datagram_socket::UnixDatagramReceiverBuilder,
     };
     use iceoryx2_bb_testing::watchdog::Watchdog;
@@ -156,6 +156,51 @@ pub mod waitset {
         assert_that!(sut.attach_deadline(&receiver, TIMEOUT).err(), eq Some(WaitSetAttachmentError::AlreadyAttached));
     }
 
+    #[conformance_test]
+    pub fn attached_file_descriptors_contains_all_notifications_and_deadlines<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, _) = create_event::<S>(&node);
+        let (receiver, _) = create_socket();
+        let listener_fd = unsafe { listener.file_descriptor().native_handle() };
+        let receiver_fd = unsafe { receiver.file_descriptor().native_handle() };
+
+        assert_that!(sut.attached_file_descriptors(), len 0);
+
+        let listener_guard = sut.attach_notification(&listener).unwrap();
+        let receiver_guard = sut.attach_deadline(&receiver, TIMEOUT).unwrap();
+        let _tick_guard = sut.attach_interval(TIMEOUT).unwrap();
+
+        let fds = sut.attached_file_descriptors();
+        assert_that!(fds, len 2);
+        assert_that!(fds, contains listener_fd);
+        assert_that!(fds, contains receiver_fd);
+
+        drop(listener_guard);
+        assert_that!(sut.attached_file_descriptors(), eq vec![receiver_fd]);
+
+        drop(receiver_guard);
+        assert_that!(sut.attached_file_descriptors(), len 0);
+    }
+
+    #[conformance_test]
+    pub fn duration_until_next_deadline_is_bounded_by_attached_interval<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        assert_that!(sut.duration_until_next_deadline().unwrap(), eq Duration::MAX);
+
+        let tick_guard = sut.attach_interval(TIMEOUT).unwrap();
+        assert_that!(sut.duration_until_next_deadline().unwrap(), le TIMEOUT);
+
+        drop(tick_guard);
+        assert_that!(sut.duration_until_next_deadline().unwrap(), eq Duration::MAX);
+    }
+
     #[conformance_test]
     pub fn wait_and_process_once_lists_all_notifications<S: Service>()
     where
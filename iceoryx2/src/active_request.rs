@@ -48,15 +48,15 @@ use iceoryx2_bb_concurrency::atomic::Ordering;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::{
-    arc_sync_policy::ArcSyncPolicy, shm_allocator::AllocationStrategy,
+    arc_sync_policy::ArcSyncPolicy, serialize::Serialize, shm_allocator::AllocationStrategy,
     zero_copy_connection::ChannelId,
 };
-use iceoryx2_log::fail;
+use iceoryx2_log::{TraceContext, fail};
 
 use crate::{
     identifiers::{UniqueClientId, UniqueServerId},
     port::{
-        LoanError, SendError,
+        LoanError, SendError, SendSerializedError,
         details::chunk_details::ChunkDetails,
         server::{INVALID_CONNECTION_ID, SharedServerState},
     },
@@ -66,7 +66,7 @@ use crate::{
     service::{
         self,
         builder::{CustomHeaderMarker, CustomPayloadMarker},
-        static_config::message_type_details::TypeVariant,
+        static_config::message_type_details::{TypeDetail, TypeVariant},
     },
 };
 
@@ -264,6 +264,13 @@ impl<
             }
         }
     }
+
+    // responses without a trace of their own continue the trace of the request
+    fn response_trace_context(&self) -> TraceContext {
+        iceoryx2_log::current_trace_context()
+            .or_else(|| self.header().trace_context())
+            .unwrap_or_default()
+    }
 }
 
 ////////////////////////
@@ -324,9 +331,13 @@ impl<
                 )),
                 request_id: self.request_id,
                 number_of_elements: 1,
+                trace_context: self.response_trace_context(),
             })
         };
         unsafe { user_header_ptr.write(ResponseHeader::default()) };
+        chunk.zero_appended_fields::<ResponseHeader, ResponsePayload>(
+            &shared_state.response_sender.message_type_details,
+        );
 
         let ptr = unsafe {
             RawSampleMut::<
@@ -571,6 +582,7 @@ impl<
                 )),
                 request_id: self.request_id,
                 number_of_elements: slice_len as _,
+                trace_context: self.response_trace_context(),
             })
         };
         unsafe { user_header_ptr.write(ResponseHeader::default()) };
@@ -640,3 +652,76 @@ impl<Service: crate::service::Service>
 ////////////////////////
 // END: sliced API
 ////////////////////////
+
+////////////////////////
+// BEGIN: serialized API
+////////////////////////
+impl<
+    Service: crate::service::Service,
+    RequestPayload: Debug + ZeroCopySend + ?Sized,
+    RequestHeader: Debug + ZeroCopySend,
+    ResponseHeader: Default + Debug + ZeroCopySend,
+> ActiveRequest<Service, RequestPayload, RequestHeader, [u8], ResponseHeader>
+{
+    /// Serializes the input `value` with the provided `Serializer` into a loaned response and
+    /// sends it to the [`Client`](crate::port::client::Client). The
+    /// [`Service`](crate::service::Service) must have been created with
+    /// [`Builder::serialized_response_payload()`](crate::service::builder::request_response::Builder::serialized_response_payload())
+    /// with the same `Serializer` and `T`.
+    /// On failure it returns a [`SendSerializedError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_cal::serialize::postcard::Postcard;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"Whatever7".try_into()?)
+    /// #     .request_response::<u64, [u8]>()
+    /// #     .serialized_response_payload::<Postcard, String>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    /// let server = service.server_builder()
+    ///                     .allocation_strategy(AllocationStrategy::PowerOfTwo)
+    ///                     .create()?;
+    /// # let pending_response = client.send_copy(0)?;
+    /// let active_request = server.receive()?.unwrap();
+    /// active_request.send_serialized::<Postcard, _>(&String::from("hello"))?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_serialized<Serializer: Serialize, T: serde::Serialize>(
+        &self,
+        value: &T,
+    ) -> Result<(), SendSerializedError> {
+        let msg = "Unable to send serialized response";
+        let expected_type = TypeDetail::new_serialized::<Serializer, T>();
+        let payload_type = self
+            .shared_state
+            .lock()
+            .response_sender
+            .message_type_details
+            .payload;
+        if payload_type != expected_type {
+            fail!(from self, with SendSerializedError::IncompatibleSerialization,
+                "{} since the service response payload type \"{}\" does not match the serialized type \"{}\".",
+                msg, payload_type.type_name(), expected_type.type_name());
+        }
+
+        let serialized = fail!(from self, when Serializer::serialize(value),
+                with SendSerializedError::SerializationFailed,
+                "{} since the payload could not be serialized.", msg);
+
+        let response = fail!(from self, when self.loan_slice_uninit(serialized.len()),
+                "{} since the loan of a response failed.", msg);
+
+        Ok(response.write_from_slice(&serialized).send()?)
+    }
+}
+////////////////////////
+// END: serialized API
+////////////////////////
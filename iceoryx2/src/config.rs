@@ -72,6 +72,7 @@
 use core::time::Duration;
 
 use alloc::string::String;
+use alloc::vec::Vec;
 
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::{CallbackProgression, lazy_singleton::*};
@@ -88,6 +89,7 @@ use serde::{Deserialize, Serialize};
 use iceoryx2_log::{debug, fail, fatal_panic, info, trace, warn};
 
 use crate::port::unable_to_deliver_strategy::UnableToDeliverStrategy;
+use crate::service::service_name_remapping::ServiceNameRemapping;
 use iceoryx2_cal::shm_allocator::AllocationStrategy;
 
 use iceoryx2_pal_configuration::ICEORYX2_ROOT_PATH;
@@ -152,6 +154,16 @@ pub struct Service {
     /// Whenever an existing [`Service`](crate::service::Service) is opened the builder will
     /// scan for dead nodes and clean up the stale resources that might block another process.
     pub cleanup_dead_nodes_on_open: bool,
+    /// When true, a [`Node`](crate::node::Node) can only be created when the
+    /// [`Service`](crate::service::Service) implementation maps the data segments of all
+    /// connected ports read-only, so that a receiving port can never modify the payload of
+    /// another port.
+    pub enforce_read_only_data_segments: bool,
+    /// The [`ServiceNameRemapping`]s every [`Node`](crate::node::Node) applies when it creates
+    /// a [`ServiceBuilder`](crate::service::builder::Builder). Rules defined with
+    /// [`NodeBuilder::service_name_remapping()`](crate::node::NodeBuilder::service_name_remapping())
+    /// or the environment take precedence.
+    pub name_remappings: Vec<ServiceNameRemapping>,
 }
 
 impl Default for Service {
@@ -166,6 +178,8 @@ impl Default for Service {
             blackboard_mgmt_suffix: FileName::new(b".blackboard_mgmt").unwrap(),
             blackboard_data_suffix: FileName::new(b".blackboard_data").unwrap(),
             cleanup_dead_nodes_on_open: true,
+            enforce_read_only_data_segments: false,
+            name_remappings: Vec::new(),
         }
     }
 }
@@ -186,6 +200,10 @@ pub struct Node {
     pub service_tag_suffix: FileName,
     /// The suffix of the global management segment.
     pub global_mgmt_suffix: FileName,
+    /// The suffix of the heartbeat of the node.
+    pub heartbeat_suffix: FileName,
+    /// The suffix of the control channel of the node that receives shutdown requests.
+    pub control_suffix: FileName,
     /// When true, the [`NodeBuilder`](crate::node::NodeBuilder) checks for dead nodes and
     /// cleans up all their stale resources whenever a new [`Node`](crate::node::Node) is
     /// created.
@@ -204,6 +222,8 @@ impl Default for Node {
             global_mgmt_suffix: FileName::new(b".global_mgmt").unwrap(),
             static_config_suffix: FileName::new(b".details").unwrap(),
             service_tag_suffix: FileName::new(b".service_tag").unwrap(),
+            heartbeat_suffix: FileName::new(b".heartbeat").unwrap(),
+            control_suffix: FileName::new(b".control").unwrap(),
             cleanup_dead_nodes_on_creation: true,
             cleanup_dead_nodes_on_destruction: true,
         }
@@ -325,6 +345,16 @@ pub struct PublishSubscribe {
     /// [`Publisher`](crate::port::publisher::Publisher) when the initially preallocated memory is
     /// insufficient.
     pub publisher_allocation_strategy: AllocationStrategy,
+    /// Defines if the data segment of the [`Publisher`](crate::port::publisher::Publisher) is
+    /// locked into the RAM.
+    pub publisher_is_memory_locked: bool,
+    /// Defines if every page of the data segment of the
+    /// [`Publisher`](crate::port::publisher::Publisher) is touched on creation to avoid page
+    /// faults on the first [`SampleMut`](crate::sample_mut::SampleMut)s.
+    pub publisher_prefault: bool,
+    /// Defines if the data segment of the [`Publisher`](crate::port::publisher::Publisher)
+    /// shall be backed by huge pages when available.
+    pub publisher_use_huge_pages: bool,
 }
 
 impl Default for PublishSubscribe {
@@ -341,6 +371,9 @@ impl Default for PublishSubscribe {
             unable_to_deliver_strategy: UnableToDeliverStrategy::RetryUntilDelivered,
             subscriber_expired_connection_buffer: 128,
             publisher_allocation_strategy: AllocationStrategy::Static,
+            publisher_is_memory_locked: false,
+            publisher_prefault: false,
+            publisher_use_huge_pages: false,
         }
     }
 }
@@ -29,6 +29,16 @@ pub const MAX_NODE_NAME_LENGTH: usize = 128;
 /// Defines the maximum length of a [`TypeName`](crate::service::static_config::message_type_details::TypeName)
 pub const MAX_TYPE_NAME_LENGTH: usize = 256;
 
+/// Defines the maximum number of [`SchemaEntry`](crate::service::static_config::type_schema::SchemaEntry)s
+/// a [`TypeSchema`](crate::service::static_config::type_schema::TypeSchema) can contain.
+/// The type itself, every nested struct and every field of them occupy one entry each,
+/// therefore structs with more than 31 flattened fields are described by a single
+/// [`FieldKind::Opaque`](crate::service::static_config::type_schema::FieldKind::Opaque) entry.
+pub const MAX_TYPE_SCHEMA_ENTRIES: usize = 32;
+
+/// Defines the maximum length of a [`FieldName`](crate::service::static_config::type_schema::FieldName)
+pub const MAX_TYPE_SCHEMA_FIELD_NAME_LENGTH: usize = 32;
+
 /// The maximum size the [`MessagingPattern::Blackboard`](crate::service::static_config::messaging_pattern::MessagingPattern::Blackboard)
 /// supports for the keytype.
 pub const MAX_BLACKBOARD_KEY_SIZE: usize = 64;
@@ -0,0 +1,195 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::ptr::NonNull;
+use core::time::Duration;
+
+use crate::service::config_scheme::node_control_config;
+use crate::{config::Config, identifiers::UniqueNodeId, service::Service};
+use iceoryx2_bb_concurrency::atomic::AtomicBool;
+use iceoryx2_bb_concurrency::atomic::Ordering;
+use iceoryx2_bb_elementary_traits::non_null::NonNullCompat;
+use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_cal::event::*;
+use iceoryx2_cal::named_concept::NamedConceptRemoveError;
+use iceoryx2_log::{fail, warn};
+
+use super::NodeShutdownRequestFailure;
+
+const SHUTDOWN_REQUEST: TriggerId = TriggerId::new(0);
+
+/// The control channel of a [`Node`](crate::node::Node) on which other processes can
+/// request its shutdown. It is acquired with
+/// [`Node::control_channel()`](crate::node::Node::control_channel()) and can be attached to a
+/// [`WaitSet`](crate::waitset::WaitSet) that wakes up whenever another process calls
+/// [`AliveNodeView::request_shutdown()`](crate::node::AliveNodeView::request_shutdown()).
+#[derive(Debug)]
+pub struct NodeControlChannel<S: Service> {
+    listener: <S::Event as Event>::Listener,
+    has_shutdown_request: AtomicBool,
+}
+
+impl<S: Service> Abandonable for NodeControlChannel<S> {
+    unsafe fn abandon_in_place(mut this: NonNull<Self>) {
+        let this = unsafe { this.as_mut() };
+        unsafe {
+            <<S::Event as Event>::Listener as Abandonable>::abandon_in_place(
+                NonNull::iox2_from_mut(&mut this.listener),
+            )
+        };
+    }
+}
+
+impl<S: Service> NodeControlChannel<S> {
+    pub(crate) fn create(
+        global_config: &Config,
+        node_id: &UniqueNodeId,
+    ) -> Result<Self, ListenerCreateError> {
+        let listener = fail!(from "NodeControlChannel::create()",
+            when <S::Event as Event>::ListenerBuilder::new(&node_id.as_file_name())
+                .trigger_id_max(SHUTDOWN_REQUEST)
+                .config(&node_control_config::<S>(global_config))
+                .create(),
+            "Unable to create the control channel of the node.");
+
+        Ok(Self {
+            listener,
+            has_shutdown_request: AtomicBool::new(false),
+        })
+    }
+
+    pub(crate) fn remove(
+        global_config: &Config,
+        node_id: &UniqueNodeId,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        unsafe {
+            <S::Event as NamedConceptMgmt>::remove_cfg(
+                &node_id.as_file_name(),
+                &node_control_config::<S>(global_config),
+            )
+        }
+    }
+
+    pub(crate) fn request_shutdown(
+        global_config: &Config,
+        node_id: &UniqueNodeId,
+    ) -> Result<(), NodeShutdownRequestFailure> {
+        let origin = "NodeControlChannel::request_shutdown()";
+        let msg = "Unable to request the shutdown of the node";
+        let notifier = match <S::Event as Event>::NotifierBuilder::new(&node_id.as_file_name())
+            .config(&node_control_config::<S>(global_config))
+            .open()
+        {
+            Ok(notifier) => notifier,
+            Err(NotifierCreateError::DoesNotExist) => {
+                fail!(from origin, with NodeShutdownRequestFailure::ShutdownRequestsNotEnabled,
+                    "{msg} since the node does not accept shutdown requests.");
+            }
+            Err(NotifierCreateError::InsufficientPermissions) => {
+                fail!(from origin, with NodeShutdownRequestFailure::InsufficientPermissions,
+                    "{msg} due to insufficient permissions to open the control channel.");
+            }
+            Err(NotifierCreateError::Interrupt) => {
+                fail!(from origin, with NodeShutdownRequestFailure::Interrupt,
+                    "{msg} since an interrupt signal was received while opening the control channel.");
+            }
+            Err(e) => {
+                fail!(from origin, with NodeShutdownRequestFailure::InternalError,
+                    "{msg} since the control channel could not be opened ({:?}).", e);
+            }
+        };
+
+        match notifier.notify(SHUTDOWN_REQUEST) {
+            Ok(()) => Ok(()),
+            Err(NotifierNotifyError::Disconnected) => {
+                fail!(from origin, with NodeShutdownRequestFailure::ShutdownRequestsNotEnabled,
+                    "{msg} since the node is no longer connected to its control channel.");
+            }
+            Err(NotifierNotifyError::Interrupt) => {
+                fail!(from origin, with NodeShutdownRequestFailure::Interrupt,
+                    "{msg} since an interrupt signal was received while sending the request.");
+            }
+            Err(e) => {
+                fail!(from origin, with NodeShutdownRequestFailure::InternalError,
+                    "{msg} since the request could not be delivered ({:?}).", e);
+            }
+        }
+    }
+
+    /// Waits until either the shutdown was requested or the timeout has passed.
+    pub(crate) fn timed_wait_for_shutdown_request(
+        &self,
+        timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        if self.has_shutdown_request.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        let mut has_shutdown_request = false;
+        let on_request = |id| {
+            if id == SHUTDOWN_REQUEST {
+                has_shutdown_request = true;
+            }
+        };
+
+        // some listeners interpret a zero timeout as infinite timeout
+        if timeout.is_zero() {
+            self.listener.try_wait_all(on_request)?;
+        } else {
+            self.listener.timed_wait_all(on_request, timeout)?;
+        }
+
+        if has_shutdown_request {
+            self.has_shutdown_request.store(true, Ordering::Relaxed);
+        }
+
+        Ok(())
+    }
+
+    /// Consumes all pending requests. Once a shutdown was requested it stays requested.
+    pub(crate) fn has_shutdown_request(&self) -> bool {
+        if self.has_shutdown_request.load(Ordering::Relaxed) {
+            return true;
+        }
+
+        let mut has_shutdown_request = false;
+        if let Err(e) = self.listener.try_wait_all(|id| {
+            if id == SHUTDOWN_REQUEST {
+                has_shutdown_request = true;
+            }
+        }) {
+            warn!(from self, "Unable to receive the pending requests of the control channel ({:?}).", e);
+        }
+
+        if has_shutdown_request {
+            self.has_shutdown_request.store(true, Ordering::Relaxed);
+        }
+
+        has_shutdown_request
+    }
+}
+
+impl<S: Service> FileDescriptorBased for NodeControlChannel<S>
+where
+    <S::Event as Event>::Listener: FileDescriptorBased,
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.listener.file_descriptor()
+    }
+}
+
+impl<S: Service> SynchronousMultiplexing for NodeControlChannel<S> where
+    <S::Event as Event>::Listener: SynchronousMultiplexing
+{
+}
@@ -0,0 +1,196 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::ptr::NonNull;
+use core::time::Duration;
+
+use crate::service::config_scheme::node_heartbeat_config;
+use crate::{config::Config, identifiers::UniqueNodeId, service::Service};
+use iceoryx2_bb_concurrency::atomic::AtomicU64;
+use iceoryx2_bb_concurrency::atomic::Ordering;
+use iceoryx2_bb_elementary_traits::non_null::NonNullCompat;
+use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
+use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_bb_posix::file::AccessMode;
+use iceoryx2_cal::dynamic_storage::*;
+use iceoryx2_cal::named_concept::*;
+use iceoryx2_log::{fail, warn};
+
+/// The heartbeat of a [`Node`](crate::node::Node) that has enabled heartbeats with
+/// [`NodeBuilder::heartbeat_interval()`](crate::node::NodeBuilder::heartbeat_interval()).
+/// It can be acquired from any process via
+/// [`AliveNodeView::last_heartbeat()`](crate::node::AliveNodeView::last_heartbeat()).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeHeartbeat {
+    interval: Duration,
+    time_since_last_heartbeat: Duration,
+}
+
+impl NodeHeartbeat {
+    /// Returns the interval in which the [`Node`](crate::node::Node) promised to send a
+    /// heartbeat.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Returns the time that has passed since the [`Node`](crate::node::Node) sent its last
+    /// heartbeat.
+    pub fn time_since_last_heartbeat(&self) -> Duration {
+        self.time_since_last_heartbeat
+    }
+
+    /// Returns true when the [`Node`](crate::node::Node) did not send a heartbeat within its
+    /// interval. This indicates that the process is alive but hangs, for instance due to a
+    /// deadlock.
+    pub fn has_missed_heartbeat(&self) -> bool {
+        self.time_since_last_heartbeat > self.interval
+    }
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct State {
+    interval: u64,
+    creation_time: Time,
+    elapsed_time_since_last_heartbeat: AtomicU64,
+}
+
+#[derive(Debug)]
+pub(crate) struct Heartbeat<S: Service> {
+    storage: S::DynamicStorage<State>,
+}
+
+impl<S: Service> Abandonable for Heartbeat<S> {
+    unsafe fn abandon_in_place(mut this: NonNull<Self>) {
+        let this = unsafe { this.as_mut() };
+        unsafe {
+            <S::DynamicStorage<State> as Abandonable>::abandon_in_place(NonNull::iox2_from_mut(
+                &mut this.storage,
+            ))
+        };
+    }
+}
+
+impl<S: Service> Heartbeat<S> {
+    pub(crate) fn create(
+        global_config: &Config,
+        node_id: &UniqueNodeId,
+        interval: Duration,
+    ) -> Result<Self, DynamicStorageCreateError> {
+        let origin = "Heartbeat::create()";
+        let msg = "Unable to create the heartbeat of the node";
+        let creation_time = match Time::now() {
+            Ok(time) => time,
+            Err(e) => {
+                fail!(from origin, with DynamicStorageCreateError::InternalError,
+                    "{msg} since the current system time could not be acquired. [{e:?}]");
+            }
+        };
+
+        let storage = match <<S::DynamicStorage<State> as DynamicStorage<State>>::Builder<'_> as NamedConceptBuilder<
+            S::DynamicStorage<State>,
+        >>::new(&node_id.as_file_name())
+        .has_ownership(false)
+        .config(&node_heartbeat_config::<S, State>(global_config, node_id))
+        .create(State {
+            interval: interval.as_nanos() as u64,
+            creation_time,
+            elapsed_time_since_last_heartbeat: AtomicU64::new(0),
+        }) {
+            Ok(storage) => storage,
+            Err(e) => {
+                fail!(from origin, with e,
+                    "{msg} since the underlying dynamic storage could not be created. [{e:?}]");
+            }
+        };
+
+        Ok(Self { storage })
+    }
+
+    pub(crate) fn open(
+        global_config: &Config,
+        node_id: &UniqueNodeId,
+    ) -> Result<Self, DynamicStorageOpenError> {
+        let storage = <<S::DynamicStorage<State> as DynamicStorage<State>>::Builder<'_> as NamedConceptBuilder<
+            S::DynamicStorage<State>,
+        >>::new(&node_id.as_file_name())
+        .config(&node_heartbeat_config::<S, State>(global_config, node_id))
+        .open(AccessMode::Read)?;
+
+        Ok(Self { storage })
+    }
+
+    pub(crate) fn remove(
+        global_config: &Config,
+        node_id: &UniqueNodeId,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        unsafe {
+            <S::DynamicStorage<State> as NamedConceptMgmt>::remove_cfg(
+                &node_id.as_file_name(),
+                &node_heartbeat_config::<S, State>(global_config, node_id),
+            )
+        }
+    }
+
+    pub(crate) fn interval(&self) -> Duration {
+        Duration::from_nanos(self.storage.get().interval)
+    }
+
+    pub(crate) fn beat(&self) {
+        let state = self.storage.get();
+        match state.creation_time.elapsed() {
+            Ok(elapsed) => state
+                .elapsed_time_since_last_heartbeat
+                .store(elapsed.as_nanos() as u64, Ordering::Relaxed),
+            Err(e) => {
+                warn!(from self,
+                    "Unable to send heartbeat since the elapsed system time could not be acquired. [{e:?}]");
+            }
+        }
+    }
+
+    pub(crate) fn last_heartbeat(&self) -> Option<NodeHeartbeat> {
+        let state = self.storage.get();
+        let elapsed = state.creation_time.elapsed().ok()?;
+        let last_heartbeat = Duration::from_nanos(
+            state
+                .elapsed_time_since_last_heartbeat
+                .load(Ordering::Relaxed),
+        );
+
+        Some(NodeHeartbeat {
+            interval: Duration::from_nanos(state.interval),
+            time_since_last_heartbeat: elapsed.saturating_sub(last_heartbeat),
+        })
+    }
+}
+
+/// Provides read-only access to the heartbeat of a [`Node`](crate::node::Node). It is acquired
+/// with [`AliveNodeView::heartbeat_reader()`](crate::node::AliveNodeView::heartbeat_reader())
+/// and can be kept to observe the heartbeat continuously without opening the underlying
+/// resources again.
+#[derive(Debug)]
+pub struct NodeHeartbeatReader<S: Service> {
+    heartbeat: Heartbeat<S>,
+}
+
+impl<S: Service> NodeHeartbeatReader<S> {
+    pub(crate) fn new(heartbeat: Heartbeat<S>) -> Self {
+        Self { heartbeat }
+    }
+
+    /// Returns the current [`NodeHeartbeat`]. If the elapsed system time could not be acquired
+    /// it returns [`None`].
+    pub fn last_heartbeat(&self) -> Option<NodeHeartbeat> {
+        self.heartbeat.last_heartbeat()
+    }
+}
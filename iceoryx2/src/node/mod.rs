@@ -43,6 +43,35 @@
 //! });
 //! ```
 //!
+//! # Attach Metadata to a [`Node`](crate::node::Node)
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::node::NodeView;
+//! use iceoryx2::service::attribute::{AttributeKey, AttributeValue};
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new()
+//!                 .name(&"my_little_node".try_into()?)
+//!                 .attributes(&AttributeSpecifier::new()
+//!                     .define(&AttributeKey::try_from("version")?,
+//!                             &AttributeValue::try_from("1.2.3")?)?)
+//!                 .create::<ipc::Service>()?;
+//!
+//! // other processes can read the attributes together with the process information
+//! Node::<ipc::Service>::list(node.config(), |node_state| {
+//!     if let NodeState::<ipc::Service>::Alive(view) = node_state {
+//!         if let Some(details) = view.details() {
+//!             println!("{:?} runs on {:?} with attributes {:?}",
+//!                 details.name(), details.host_name(), details.attributes());
+//!         }
+//!     }
+//!     CallbackProgression::Continue
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # Cleanup stale resources of all dead [`Node`](crate::node::Node)s
 //!
 //! ```
@@ -133,15 +162,62 @@
 //!         Err(NodeWaitFailure::Interrupt) => {
 //!             println!("Someone send an interrupt signal ...");
 //!         }
+//!         Err(NodeWaitFailure::ShutdownRequest) => {
+//!             println!("Another process requested the shutdown, terminating");
+//!             break;
+//!         }
 //!     }
 //! }
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Shutdown Requests From Other Processes
+//!
+//! The [`Node::control_channel()`](crate::node::Node::control_channel()) of a
+//! [`Node`](crate::node::Node) created with
+//! [`NodeBuilder::enable_shutdown_requests()`](crate::node::NodeBuilder::enable_shutdown_requests())
+//! can be attached to a [`WaitSet`](crate::waitset::WaitSet) that wakes up as soon as another
+//! process calls
+//! [`AliveNodeView::request_shutdown()`](crate::node::AliveNodeView::request_shutdown()).
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new()
+//!                 .name(&"my_little_node".try_into()?)
+//!                 .enable_shutdown_requests(true)
+//!                 .create::<ipc::Service>()?;
+//!
+//! let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+//! let control_channel = node
+//!     .control_channel()
+//!     .ok_or("the node does not accept shutdown requests")?;
+//! let guard = waitset.attach_notification(control_channel)?;
+//!
+//! waitset.wait_and_process(|attachment_id| {
+//!     if attachment_id.has_event_from(&guard) && node.has_shutdown_request() {
+//!         println!("Another process requested the shutdown, terminating");
+//!         return CallbackProgression::Stop;
+//!     }
+//!
+//!     CallbackProgression::Continue
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
 
+mod control;
 mod global_management_segment;
+mod heartbeat;
 /// The name for a node.
 pub mod node_name;
+mod watcher;
+
+pub use control::NodeControlChannel;
+pub use heartbeat::{NodeHeartbeat, NodeHeartbeatReader};
+pub use watcher::NodeWatcher;
 
 use core::marker::PhantomData;
 use core::ptr::NonNull;
@@ -167,17 +243,25 @@ use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
-use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
 use iceoryx2_bb_posix::clock::{NanosleepError, nanosleep};
 use iceoryx2_bb_posix::mutex::Handle;
 use iceoryx2_bb_posix::mutex::Mutex;
 use iceoryx2_bb_posix::mutex::MutexBuilder;
 use iceoryx2_bb_posix::mutex::MutexHandle;
 use iceoryx2_bb_posix::mutex::MutexType;
-use iceoryx2_bb_posix::process::Process;
+use iceoryx2_bb_posix::process::{Process, ProcessId};
 use iceoryx2_bb_posix::signal::SignalHandler;
+use iceoryx2_bb_posix::system_configuration::host_name;
+use iceoryx2_bb_posix::user::User;
 use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::host_name::HostName;
+use iceoryx2_bb_system_types::user_name::UserName;
+use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
+use iceoryx2_cal::event::{ListenerCreateError, ListenerWaitError};
 use iceoryx2_cal::named_concept::{NamedConceptPathHintRemoveError, NamedConceptRemoveError};
+use iceoryx2_cal::resizable_shared_memory::ResizableSharedMemory;
+use iceoryx2_cal::shared_memory::SharedMemory;
 use iceoryx2_cal::{
     monitoring::*, named_concept::NamedConceptListError, serialize::*, static_storage::*,
 };
@@ -185,13 +269,17 @@ use iceoryx2_log::{debug, fail, fatal_panic, trace, warn};
 
 use crate::identifiers::UniqueNodeId;
 use crate::node::global_management_segment::GlobalManagementSegment;
+use crate::node::heartbeat::Heartbeat;
 use crate::node::node_name::NodeName;
+use crate::service::attribute::{AttributeSet, AttributeSpecifier};
 use crate::service::builder::{Builder, OpenDynamicStorageFailure};
 use crate::service::config_scheme::{
     node_details_path, node_monitoring_config, service_tag_config,
 };
 use crate::service::service_hash::ServiceHash;
 use crate::service::service_name::ServiceName;
+use crate::service::service_name_remapping::{self, ServiceNameRemapping};
+use crate::service::stale_resource_cleanup::remove_expired_services;
 use crate::service::{
     self, ServiceRemoveNodeError, remove_service_tag, remove_static_service_config,
 };
@@ -214,6 +302,13 @@ pub enum NodeCreationFailure {
     InternalError,
     /// Indicates that another "instance" on the system removed the resource required by the [`Node`].
     SystemCorrupted,
+    /// The [`Config`] enforces read-only data segments but the [`service::Service`]
+    /// implementation is unable to map the data segments of other ports read-only.
+    ReadOnlyDataSegmentsNotSupported,
+    /// The environment variable
+    /// [`SERVICE_NAME_REMAPPINGS_ENV_VAR`](crate::service::service_name_remapping::SERVICE_NAME_REMAPPINGS_ENV_VAR)
+    /// contains an invalid [`ServiceNameRemapping`].
+    InvalidServiceNameRemapping,
 }
 
 impl core::fmt::Display for NodeCreationFailure {
@@ -231,6 +326,9 @@ pub enum NodeWaitFailure {
     Interrupt,
     /// A termination signal `SIGTERM` was received.
     TerminationRequest,
+    /// Another process requested the shutdown of the [`Node`] via
+    /// [`AliveNodeView::request_shutdown()`].
+    ShutdownRequest,
 }
 
 impl core::fmt::Display for NodeWaitFailure {
@@ -241,6 +339,30 @@ impl core::fmt::Display for NodeWaitFailure {
 
 impl core::error::Error for NodeWaitFailure {}
 
+/// The failures that can occur when the shutdown of a [`Node`] is requested with
+/// [`AliveNodeView::request_shutdown()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NodeShutdownRequestFailure {
+    /// The [`Node`] does not accept shutdown requests since it was created without
+    /// [`NodeBuilder::enable_shutdown_requests()`] or it has already terminated.
+    ShutdownRequestsNotEnabled,
+    /// The process does not have sufficient permissions to access the control channel of the
+    /// [`Node`].
+    InsufficientPermissions,
+    /// The process received an interrupt signal while sending the shutdown request.
+    Interrupt,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
+}
+
+impl core::fmt::Display for NodeShutdownRequestFailure {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NodeShutdownRequestFailure::{self:?}")
+    }
+}
+
+impl core::error::Error for NodeShutdownRequestFailure {}
+
 /// The failures that can occur when a list of [`NodeState`]s is created with [`Node::list()`].
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum NodeListFailure {
@@ -305,25 +427,69 @@ enum NodeReadServiceTagsFailure {
 pub struct NodeDetails {
     executable: FileName,
     name: NodeName,
+    process_id: u32,
+    host_name: Option<HostName>,
+    user_name: Option<UserName>,
+    command_line: Vec<String>,
+    start_time: Time,
     config: Config,
+    attributes: AttributeSet,
+    #[serde(default)]
+    service_name_remappings: Vec<ServiceNameRemapping>,
 }
 
 impl NodeDetails {
     #[doc(hidden)]
     pub fn __internal_new(node_name: &Option<NodeName>, config: &Config) -> Self {
-        Self::new(node_name, config)
+        Self::new(node_name, config, &AttributeSet::new(), Vec::new())
     }
 
-    fn new(node_name: &Option<NodeName>, config: &Config) -> Self {
-        let executable = match Process::from_self().executable() {
+    fn new(
+        node_name: &Option<NodeName>,
+        config: &Config,
+        attributes: &AttributeSet,
+        service_name_remappings: Vec<ServiceNameRemapping>,
+    ) -> Self {
+        let origin = "NodeDetails::new()";
+        let process = Process::from_self();
+        let executable = match process.executable() {
             Ok(n) => n.file_name(),
             Err(e) => {
-                debug!(from "NodeDetails::new()", "Unable to acquire executable name of the Node's process ({:?}).", e);
+                debug!(from origin, "Unable to acquire executable name of the Node's process ({:?}).", e);
                 const FALLBACK_EXEC: &[u8] = b"undefined";
                 unsafe { FileName::new_unchecked(FALLBACK_EXEC) }
             }
         };
 
+        let host_name = match host_name() {
+            Ok(n) => Some(n),
+            Err(e) => {
+                debug!(from origin, "Unable to acquire host name of the Node's process ({:?}).", e);
+                None
+            }
+        };
+
+        let user_name = match User::from_self() {
+            Ok(user) => user.details().map(|details| *details.name()),
+            Err(e) => {
+                debug!(from origin, "Unable to acquire user of the Node's process ({:?}).", e);
+                None
+            }
+        };
+
+        let start_time = match Time::now_with_clock(ClockType::Realtime) {
+            Ok(t) => t,
+            Err(e) => {
+                debug!(from origin, "Unable to acquire start time of the Node ({:?}).", e);
+                Time::default()
+            }
+        };
+
+        #[cfg(feature = "std")]
+        let command_line = std::env::args().collect();
+        #[cfg(not(feature = "std"))]
+        let command_line = Vec::new();
+
         Self {
             executable,
             name: if let Some(name) = node_name {
@@ -331,7 +497,14 @@ impl NodeDetails {
             } else {
                 NodeName::new("").expect("An empty NodeName is always valid.")
             },
+            process_id: process.id().value() as u32,
+            host_name,
+            user_name,
+            command_line,
+            start_time,
             config: config.clone(),
+            attributes: attributes.clone(),
+            service_name_remappings,
         }
     }
 
@@ -346,10 +519,51 @@ impl NodeDetails {
         &self.name
     }
 
+    /// Returns the [`ProcessId`] of the [`Node`]s owner process as seen from inside the process.
+    /// When the process runs in a container it can differ from [`UniqueNodeId::pid()`].
+    pub fn process_id(&self) -> ProcessId {
+        ProcessId::new(self.process_id as _)
+    }
+
+    /// Returns the [`HostName`] of the system the [`Node`]s owner process runs on, if it
+    /// could be acquired.
+    pub fn host_name(&self) -> Option<&HostName> {
+        self.host_name.as_ref()
+    }
+
+    /// Returns the [`UserName`] of the owner of the [`Node`]s process, if it could be
+    /// acquired.
+    pub fn user_name(&self) -> Option<&UserName> {
+        self.user_name.as_ref()
+    }
+
+    /// Returns the command line arguments, including the executable, the [`Node`]s owner
+    /// process was started with. It is empty when the information is not available.
+    pub fn command_line(&self) -> &[String] {
+        &self.command_line
+    }
+
+    /// Returns the [`Time`], based on [`ClockType::Realtime`], when the [`Node`] was created.
+    pub fn start_time(&self) -> Time {
+        self.start_time
+    }
+
     /// Returns the [`Config`] the [`Node`] uses to create all entities.
     pub fn config(&self) -> &Config {
         &self.config
     }
+
+    /// Returns the user-defined [`AttributeSet`] of the [`Node`], see
+    /// [`NodeBuilder::attributes()`].
+    pub fn attributes(&self) -> &AttributeSet {
+        &self.attributes
+    }
+
+    /// Returns the [`ServiceNameRemapping`]s the [`Node`] applies to all
+    /// [`ServiceName`]s, ordered by precedence.
+    pub fn service_name_remappings(&self) -> &[ServiceNameRemapping] {
+        &self.service_name_remappings
+    }
 }
 
 /// The current state of the [`Node`]. If the [`Node`] is dead all of its resources can be removed
@@ -456,6 +670,51 @@ impl<Service: service::Service> Clone for AliveNodeView<Service> {
     }
 }
 
+impl<Service: service::Service> AliveNodeView<Service> {
+    /// Returns the [`NodeHeartbeat`] of the [`Node`]. If the [`Node`] has not enabled
+    /// heartbeats via [`NodeBuilder::heartbeat_interval()`] or the heartbeat is not accessible
+    /// it returns [`None`]. Every call opens the heartbeat again, use
+    /// [`AliveNodeView::heartbeat_reader()`] to observe the heartbeat continuously.
+    pub fn last_heartbeat(&self) -> Option<NodeHeartbeat> {
+        self.heartbeat_reader()?.last_heartbeat()
+    }
+
+    /// Opens the heartbeat of the [`Node`] read-only and returns a [`NodeHeartbeatReader`]
+    /// to observe it. If the [`Node`] has not enabled heartbeats via
+    /// [`NodeBuilder::heartbeat_interval()`] or the heartbeat is not accessible it returns
+    /// [`None`].
+    pub fn heartbeat_reader(&self) -> Option<NodeHeartbeatReader<Service>> {
+        let config = if let Some(d) = self.details() {
+            d.config()
+        } else {
+            Config::global_config()
+        };
+
+        match Heartbeat::<Service>::open(config, self.id()) {
+            Ok(heartbeat) => Some(NodeHeartbeatReader::new(heartbeat)),
+            Err(e) => {
+                trace!(from self, "The heartbeat of the node is not available ({:?}).", e);
+                None
+            }
+        }
+    }
+
+    /// Requests the shutdown of the [`Node`]. The [`Node`] is informed via
+    /// [`NodeWaitFailure::ShutdownRequest`] in [`Node::wait()`] or, when it is attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet), by waking up the
+    /// [`WaitSet`](crate::waitset::WaitSet), see [`Node::has_shutdown_request()`].
+    /// The [`Node`] must have been created with [`NodeBuilder::enable_shutdown_requests()`].
+    pub fn request_shutdown(&self) -> Result<(), NodeShutdownRequestFailure> {
+        let config = if let Some(d) = self.details() {
+            d.config()
+        } else {
+            Config::global_config()
+        };
+
+        NodeControlChannel::<Service>::request_shutdown(config, self.id())
+    }
+}
+
 impl<Service: service::Service> NodeView for AliveNodeView<Service> {
     fn id(&self) -> &UniqueNodeId {
         &self.id
@@ -761,6 +1020,44 @@ fn remove_detail_storages<Service: service::Service>(
     Ok(())
 }
 
+fn remove_heartbeat<Service: service::Service>(
+    origin: &str,
+    node_id: &UniqueNodeId,
+    config: &Config,
+) -> Result<(), NodeCleanupFailure> {
+    let msg = "Unable to remove node heartbeat";
+    match Heartbeat::<Service>::remove(config, node_id) {
+        Ok(_) => Ok(()),
+        Err(NamedConceptRemoveError::InsufficientPermissions) => {
+            fail!(from origin, with NodeCleanupFailure::InsufficientPermissions,
+                "{} due to insufficient permissions.", msg);
+        }
+        Err(NamedConceptRemoveError::InternalError) => {
+            fail!(from origin, with NodeCleanupFailure::InternalError,
+                "{} due to an internal failure.", msg);
+        }
+    }
+}
+
+fn remove_control_channel<Service: service::Service>(
+    origin: &str,
+    node_id: &UniqueNodeId,
+    config: &Config,
+) -> Result<(), NodeCleanupFailure> {
+    let msg = "Unable to remove node control channel";
+    match NodeControlChannel::<Service>::remove(config, node_id) {
+        Ok(_) => Ok(()),
+        Err(NamedConceptRemoveError::InsufficientPermissions) => {
+            fail!(from origin, with NodeCleanupFailure::InsufficientPermissions,
+                "{} due to insufficient permissions.", msg);
+        }
+        Err(NamedConceptRemoveError::InternalError) => {
+            fail!(from origin, with NodeCleanupFailure::InternalError,
+                "{} due to an internal failure.", msg);
+        }
+    }
+}
+
 fn remove_node_details_directory<Service: service::Service>(
     config: &Config,
     node_id: &UniqueNodeId,
@@ -794,6 +1091,8 @@ fn remove_node<Service: service::Service>(
     let details_config = node_details_config::<Service>(config, &id);
     let detail_storages = acquire_all_node_detail_storages::<Service>(&origin, &details_config)?;
     remove_detail_storages::<Service>(&origin, detail_storages, &details_config)?;
+    remove_heartbeat::<Service>(&origin, &id, config)?;
+    remove_control_channel::<Service>(&origin, &id, config)?;
     remove_node_details_directory::<Service>(config, &id)?;
 
     Ok(true)
@@ -906,6 +1205,8 @@ struct SharedNodeState<Service: service::Service> {
     registered_services: RegisteredServices,
     signal_handling_mode: SignalHandlingMode,
     details_storage: Service::StaticStorage,
+    heartbeat: Option<Heartbeat<Service>>,
+    control_channel: Option<NodeControlChannel<Service>>,
 }
 
 unsafe impl<Service: service::Service> Send for SharedNodeState<Service> {}
@@ -919,6 +1220,14 @@ impl<Service: service::Service> Abandonable for SharedNodeState<Service> {
                 &mut this.details_storage,
             ))
         };
+        if let Some(heartbeat) = &mut this.heartbeat {
+            unsafe { Heartbeat::abandon_in_place(NonNull::iox2_from_mut(heartbeat)) };
+        }
+        if let Some(control_channel) = &mut this.control_channel {
+            unsafe {
+                NodeControlChannel::abandon_in_place(NonNull::iox2_from_mut(control_channel))
+            };
+        }
         if let Some(token) = this.monitoring_token.get_mut() {
             unsafe {
                 <<Service::Monitoring as Monitoring>::Token as Abandonable>::abandon_in_place(
@@ -931,6 +1240,10 @@ impl<Service: service::Service> Abandonable for SharedNodeState<Service> {
 
 impl<Service: service::Service> Drop for SharedNodeState<Service> {
     fn drop(&mut self) {
+        // the control channel owns its resources and must release them before
+        // remove_node() removes them
+        drop(self.control_channel.take());
+
         let config = self.details.config();
         if self.monitoring_token.get_mut().is_some() {
             if config.global.node.cleanup_dead_nodes_on_destruction {
@@ -977,6 +1290,14 @@ impl<Service: service::Service> SharedNode<Service> {
     pub(crate) fn name(&self) -> &NodeName {
         &self.state.details.name
     }
+
+    pub(crate) fn attributes(&self) -> &AttributeSet {
+        &self.state.details.attributes
+    }
+
+    pub(crate) fn service_name_remappings(&self) -> &[ServiceNameRemapping] {
+        &self.state.details.service_name_remappings
+    }
 }
 
 /// The [`Node`] is the entry point to the whole iceoryx2 infrastructure and owns all entities.
@@ -1016,9 +1337,28 @@ impl<Service: service::Service> Node<Service> {
         self.shared.id()
     }
 
-    /// Instantiates a [`ServiceBuilder`](Builder) for a service with the provided name.
+    /// Returns the user-defined [`AttributeSet`] of the [`Node`], see
+    /// [`NodeBuilder::attributes()`].
+    pub fn attributes(&self) -> &AttributeSet {
+        self.shared.attributes()
+    }
+
+    /// Returns the [`ServiceNameRemapping`]s the [`Node`] applies to all [`ServiceName`]s,
+    /// ordered by precedence. See [`NodeBuilder::service_name_remapping()`].
+    pub fn service_name_remappings(&self) -> &[ServiceNameRemapping] {
+        self.shared.service_name_remappings()
+    }
+
+    /// Instantiates a [`ServiceBuilder`](Builder) for a service with the provided name. When
+    /// a [`ServiceNameRemapping`] of the [`Node`] matches the name, the remapped name is used.
     pub fn service_builder(&self, name: &ServiceName) -> Builder<Service> {
-        Builder::new(name, self.shared.clone())
+        let remapped_name =
+            service_name_remapping::remap(self.shared.service_name_remappings(), name);
+        if remapped_name != *name {
+            debug!(from self, "Remapped service name \"{}\" to \"{}\".", name, remapped_name);
+        }
+
+        Builder::new(&remapped_name, self.shared.clone())
     }
 
     /// Calls the provided callback for all [`Node`]s in the system under a given [`Config`] and
@@ -1077,30 +1417,86 @@ impl<Service: service::Service> Node<Service> {
                 "{error_msg} since a termination request was received.");
         }
 
+        if self.has_shutdown_request() {
+            fail!(from self, with NodeWaitFailure::ShutdownRequest,
+                "{error_msg} since another process requested the shutdown of the node.");
+        }
+
         Ok(())
     }
 
     /// Waits until the cycle time has passed. It returns [`NodeWaitFailure::TerminationRequest`]
-    /// when a `SIGTERM` signal was received or [`NodeWaitFailure::Interrupt`] when a `SIGINT`
-    /// signal was received.
+    /// when a `SIGTERM` signal was received, [`NodeWaitFailure::Interrupt`] when a `SIGINT`
+    /// signal was received or [`NodeWaitFailure::ShutdownRequest`] when another process
+    /// requested the shutdown via [`AliveNodeView::request_shutdown()`]. When the [`Node`]
+    /// accepts shutdown requests, it returns as soon as the shutdown was requested without
+    /// waiting for the cycle time to pass.
     pub fn wait(&self, cycle_time: Duration) -> Result<(), NodeWaitFailure> {
         let msg = "Unable to wait on node";
         self.handle_termination_request(msg)?;
-
-        match nanosleep(cycle_time) {
-            Ok(()) => {
-                self.handle_termination_request(msg)?;
-                Ok(())
-            }
-            Err(NanosleepError::InterruptedBySignal(_)) => {
-                fail!(from self, with NodeWaitFailure::Interrupt,
-                        "{msg} since a interrupt signal was received.");
-            }
-            Err(v) => {
-                fatal_panic!(from self,
-                    "Failed to wait with cycle time {:?} in main event look, caused by ({:?}).",
-                    cycle_time, v);
+        self.heartbeat();
+
+        let is_interrupted = match &self.shared.state.control_channel {
+            // wakes up as soon as another process requests the shutdown
+            Some(control_channel) => {
+                match control_channel.timed_wait_for_shutdown_request(cycle_time) {
+                    Ok(()) => false,
+                    Err(ListenerWaitError::InterruptSignal) => true,
+                    Err(v) => {
+                        fatal_panic!(from self,
+                            "Failed to wait on the control channel with cycle time {:?} in main event look, caused by ({:?}).",
+                            cycle_time, v);
+                    }
+                }
             }
+            None => match nanosleep(cycle_time) {
+                Ok(()) => false,
+                Err(NanosleepError::InterruptedBySignal(_)) => true,
+                Err(v) => {
+                    fatal_panic!(from self,
+                        "Failed to wait with cycle time {:?} in main event look, caused by ({:?}).",
+                        cycle_time, v);
+                }
+            },
+        };
+
+        if is_interrupted {
+            fail!(from self, with NodeWaitFailure::Interrupt,
+                "{msg} since a interrupt signal was received.");
+        }
+
+        self.heartbeat();
+        self.handle_termination_request(msg)?;
+        Ok(())
+    }
+
+    /// Returns true when the [`Node`] was created with
+    /// [`NodeBuilder::enable_shutdown_requests()`] and accepts shutdown requests from other
+    /// processes.
+    pub fn accepts_shutdown_requests(&self) -> bool {
+        self.shared.state.control_channel.is_some()
+    }
+
+    /// Returns the [`NodeControlChannel`] that is notified whenever another process requests
+    /// the shutdown of the [`Node`]. It can be attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet) with
+    /// [`WaitSet::attach_notification()`](crate::waitset::WaitSet::attach_notification()).
+    ///
+    /// Returns [`None`] when the [`Node`] was created without
+    /// [`NodeBuilder::enable_shutdown_requests()`].
+    pub fn control_channel(&self) -> Option<&NodeControlChannel<Service>> {
+        self.shared.state.control_channel.as_ref()
+    }
+
+    /// Returns true when another process requested the shutdown of the [`Node`] via
+    /// [`AliveNodeView::request_shutdown()`]. Once a shutdown was requested, it stays
+    /// requested. When the [`Node::control_channel()`] is attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet), this function must be called whenever the
+    /// [`WaitSet`](crate::waitset::WaitSet) reports an event for it.
+    pub fn has_shutdown_request(&self) -> bool {
+        match &self.shared.state.control_channel {
+            Some(control_channel) => control_channel.has_shutdown_request(),
+            None => false,
         }
     }
 
@@ -1109,8 +1505,32 @@ impl<Service: service::Service> Node<Service> {
         self.shared.state.signal_handling_mode
     }
 
+    /// Returns the interval in which the [`Node`] has to send a heartbeat. If heartbeats
+    /// were not enabled via [`NodeBuilder::heartbeat_interval()`] it returns [`None`].
+    pub fn heartbeat_interval(&self) -> Option<Duration> {
+        self.shared
+            .state
+            .heartbeat
+            .as_ref()
+            .map(|heartbeat| heartbeat.interval())
+    }
+
+    /// Signals that the [`Node`] is still responsive. It must be called at least once
+    /// within the [`Node::heartbeat_interval()`], otherwise monitoring processes consider
+    /// the [`Node`] as unresponsive, see [`NodeHeartbeat::has_missed_heartbeat()`].
+    /// [`Node::wait()`] sends a heartbeat automatically. If heartbeats were not enabled it
+    /// does nothing.
+    pub fn heartbeat(&self) {
+        if let Some(heartbeat) = &self.shared.state.heartbeat {
+            heartbeat.beat();
+        }
+    }
+
     /// Removes the stale system resources of all dead [`Node`]s. The dead [`Node`]s are also
-    /// removed from all registered [`Service`](crate::service::Service)s.
+    /// removed from all registered [`Service`](crate::service::Service)s. Unused
+    /// [`Service`](crate::service::Service)s whose
+    /// [`ServiceLifetime::TimeToLive`](crate::service::lifetime::ServiceLifetime::TimeToLive)
+    /// expired are removed as well.
     ///
     /// If a [`Node`] cannot be cleaned up since the process has insufficient permissions or it
     /// is currently being cleaned up by another process then the [`Node`] is skipped.
@@ -1119,7 +1539,10 @@ impl<Service: service::Service> Node<Service> {
     }
 
     /// Removes the stale system resources of all dead [`Node`]s. The dead [`Node`]s are also
-    /// removed from all registered [`Service`](crate::service::Service)s.
+    /// removed from all registered [`Service`](crate::service::Service)s. Unused
+    /// [`Service`](crate::service::Service)s whose
+    /// [`ServiceLifetime::TimeToLive`](crate::service::lifetime::ServiceLifetime::TimeToLive)
+    /// expired are removed as well.
     ///
     /// If a [`Node`] cannot be cleaned up since the process has insufficient permissions then the
     /// [`Node`] is skipped. If it is currently being cleaned up by another process then the
@@ -1155,13 +1578,16 @@ impl<Service: service::Service> Node<Service> {
             CallbackProgression::Continue
         };
 
-        match Node::<Service>::list(config, cleanup_call) {
-            Ok(()) => cleanup_state,
-            Err(e) => {
-                debug!(from origin, "Unable to perform a full scan for dead nodes since the all existing nodes could not be listed ({:?}).", e);
-                cleanup_state
-            }
+        if let Err(e) = Node::<Service>::list(config, cleanup_call) {
+            debug!(from origin, "Unable to perform a full scan for dead nodes since the all existing nodes could not be listed ({:?}).", e);
+        }
+
+        let number_of_expired_services = remove_expired_services::<Service>(config);
+        if number_of_expired_services != 0 {
+            trace!(from origin, "Removed {} services whose time-to-live expired.", number_of_expired_services);
         }
+
+        cleanup_state
     }
 
     fn list_all_nodes(
@@ -1365,6 +1791,10 @@ pub struct NodeBuilder {
     name: Option<NodeName>,
     signal_handling_mode: SignalHandlingMode,
     config: Option<Config>,
+    heartbeat_interval: Option<Duration>,
+    enable_shutdown_requests: bool,
+    attributes: Option<AttributeSpecifier>,
+    service_name_remappings: Vec<ServiceNameRemapping>,
 }
 
 impl NodeBuilder {
@@ -1394,6 +1824,44 @@ impl NodeBuilder {
         self
     }
 
+    /// Enables heartbeats for the [`Node`]. The [`Node`] promises to call [`Node::heartbeat()`]
+    /// or [`Node::wait()`] at least once within the provided interval so that monitoring
+    /// processes can detect when the [`Node`]s process hangs, see
+    /// [`AliveNodeView::last_heartbeat()`]. By default, heartbeats are disabled.
+    pub fn heartbeat_interval(mut self, value: Duration) -> Self {
+        self.heartbeat_interval = Some(value);
+        self
+    }
+
+    /// Enables the control channel of the [`Node`] so that other processes can request its
+    /// shutdown via [`AliveNodeView::request_shutdown()`]. The request is reported by
+    /// [`Node::wait()`] and [`Node::has_shutdown_request()`] and, when the [`Node`] is attached
+    /// to a [`WaitSet`](crate::waitset::WaitSet), it wakes up the
+    /// [`WaitSet`](crate::waitset::WaitSet). By default, shutdown requests are disabled.
+    pub fn enable_shutdown_requests(mut self, value: bool) -> Self {
+        self.enable_shutdown_requests = value;
+        self
+    }
+
+    /// Defines user-defined [`Attribute`](crate::service::attribute::Attribute)s, like the
+    /// role or version of the application, that other processes can read via
+    /// [`NodeDetails::attributes()`].
+    pub fn attributes(mut self, value: &AttributeSpecifier) -> Self {
+        self.attributes = Some(value.clone());
+        self
+    }
+
+    /// Adds a [`ServiceNameRemapping`] that the [`Node`] applies to every
+    /// [`ServiceName`] passed to [`Node::service_builder()`]. When multiple rules match, the
+    /// first one wins. Rules added with this method take precedence over the rules of the
+    /// environment variable
+    /// [`SERVICE_NAME_REMAPPINGS_ENV_VAR`](crate::service::service_name_remapping::SERVICE_NAME_REMAPPINGS_ENV_VAR),
+    /// which take precedence over the rules of the [`Config`].
+    pub fn service_name_remapping(mut self, value: &ServiceNameRemapping) -> Self {
+        self.service_name_remappings.push(*value);
+        self
+    }
+
     /// Creates a new [`Node`] for a specific [`service::Service`]. All entities owned by the
     /// [`Node`] will have the same [`service::Service`].
     pub fn create<Service: service::Service>(self) -> Result<Node<Service>, NodeCreationFailure> {
@@ -1422,15 +1890,31 @@ impl NodeBuilder {
             .config
             .as_ref()
             .unwrap_or_else(|| Config::global_config());
+        let msg = "Unable to create node";
+
+        if config.global.service.enforce_read_only_data_segments
+            && !(Service::SharedMemory::does_support_read_only_mapping()
+                && Service::ResizableSharedMemory::does_support_read_only_mapping())
+        {
+            fail!(from self, with NodeCreationFailure::ReadOnlyDataSegmentsNotSupported,
+                "{msg} since the config enforces read-only data segments but the service implementation cannot map them read-only.");
+        }
+
         if config.global.node.cleanup_dead_nodes_on_creation {
             Node::<Service>::try_cleanup_dead_nodes(config);
         }
-
-        let msg = "Unable to create node";
         let monitor_name = fatal_panic!(from self, when FileName::new(node_id.value().to_string().as_bytes()),
                                 "This should never happen! {msg} since the UniqueSystemId is not a valid file name.");
         let (details_storage, details) =
             self.create_node_details_storage::<Service>(config, &node_id)?;
+        let heartbeat = match self.heartbeat_interval {
+            Some(interval) => Some(self.create_heartbeat::<Service>(config, &node_id, interval)?),
+            None => None,
+        };
+        let control_channel = match self.enable_shutdown_requests {
+            true => Some(self.create_control_channel::<Service>(config, &node_id)?),
+            false => None,
+        };
         let monitoring_token = self.create_token::<Service>(config, &monitor_name)?;
 
         let new_node = Node {
@@ -1442,6 +1926,8 @@ impl NodeBuilder {
                     details_storage,
                     signal_handling_mode: self.signal_handling_mode,
                     details,
+                    heartbeat,
+                    control_channel,
                 }),
             },
         };
@@ -1450,6 +1936,48 @@ impl NodeBuilder {
         Ok(new_node)
     }
 
+    fn create_heartbeat<Service: service::Service>(
+        &self,
+        config: &Config,
+        node_id: &UniqueNodeId,
+        interval: Duration,
+    ) -> Result<Heartbeat<Service>, NodeCreationFailure> {
+        let msg = "Unable to create heartbeat for new node";
+        match Heartbeat::<Service>::create(config, node_id, interval) {
+            Ok(heartbeat) => {
+                heartbeat.beat();
+                Ok(heartbeat)
+            }
+            Err(DynamicStorageCreateError::InsufficientPermissions) => {
+                fail!(from self, with NodeCreationFailure::InsufficientPermissions,
+                    "{msg} due to insufficient permissions to create the heartbeat.");
+            }
+            Err(e) => {
+                fail!(from self, with NodeCreationFailure::InternalError,
+                    "{msg} since the heartbeat could not be created ({:?}).", e);
+            }
+        }
+    }
+
+    fn create_control_channel<Service: service::Service>(
+        &self,
+        config: &Config,
+        node_id: &UniqueNodeId,
+    ) -> Result<NodeControlChannel<Service>, NodeCreationFailure> {
+        let msg = "Unable to create control channel for new node";
+        match NodeControlChannel::<Service>::create(config, node_id) {
+            Ok(control_channel) => Ok(control_channel),
+            Err(ListenerCreateError::InsufficientPermissions) => {
+                fail!(from self, with NodeCreationFailure::InsufficientPermissions,
+                    "{msg} due to insufficient permissions to create the control channel.");
+            }
+            Err(e) => {
+                fail!(from self, with NodeCreationFailure::InternalError,
+                    "{msg} since the control channel could not be created ({:?}).", e);
+            }
+        }
+    }
+
     fn create_token<Service: service::Service>(
         &self,
         config: &Config,
@@ -1481,13 +2009,49 @@ impl NodeBuilder {
         }
     }
 
+    fn service_name_remappings(
+        &self,
+        config: &Config,
+    ) -> Result<Vec<ServiceNameRemapping>, NodeCreationFailure> {
+        let mut remappings = self.service_name_remappings.clone();
+
+        #[cfg(feature = "std")]
+        if let Ok(rules) = std::env::var(service_name_remapping::SERVICE_NAME_REMAPPINGS_ENV_VAR) {
+            match ServiceNameRemapping::from_list(&rules) {
+                Ok(env_remappings) => remappings.extend(env_remappings),
+                Err(e) => {
+                    fail!(from self, with NodeCreationFailure::InvalidServiceNameRemapping,
+                        "Unable to create node since the environment variable {} contains an invalid service name remapping ({:?}).",
+                        service_name_remapping::SERVICE_NAME_REMAPPINGS_ENV_VAR, e);
+                }
+            }
+        }
+
+        remappings.extend_from_slice(&config.global.service.name_remappings);
+        Ok(remappings)
+    }
+
     fn create_node_details_storage<Service: service::Service>(
         &self,
         config: &Config,
         node_id: &UniqueNodeId,
     ) -> Result<(Service::StaticStorage, NodeDetails), NodeCreationFailure> {
         let msg = "Unable to create node details storage";
-        let details = NodeDetails::new(&self.name, config);
+        let service_name_remappings = self.service_name_remappings(config)?;
+        let details = match &self.attributes {
+            Some(attributes) => NodeDetails::new(
+                &self.name,
+                config,
+                attributes.attributes(),
+                service_name_remappings,
+            ),
+            None => NodeDetails::new(
+                &self.name,
+                config,
+                &AttributeSet::new(),
+                service_name_remappings,
+            ),
+        };
 
         let details_config = node_details_config::<Service>(&details.config, node_id);
         let serialized_details = match <Service::ConfigSerializer>::serialize(&details) {
@@ -0,0 +1,186 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::collections::BTreeMap;
+use alloc::collections::BTreeSet;
+use core::marker::PhantomData;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_cal::monitoring::*;
+use iceoryx2_log::fail;
+
+use crate::config::Config;
+use crate::identifiers::UniqueNodeId;
+use crate::service;
+use crate::service::config_scheme::node_monitoring_config;
+
+use super::{AliveNodeView, DeadNodeView, Node, NodeListFailure, NodeState};
+
+#[derive(Debug)]
+struct WatchedNode<Service: service::Service> {
+    monitor: <Service::Monitoring as Monitoring>::Monitor,
+    view: AliveNodeView<Service>,
+}
+
+/// Watches the monitoring tokens of all [`Node`]s under a given [`Config`]. In contrast to
+/// [`Node::list()`], the monitor of every [`Node`] is created and its
+/// [`NodeDetails`](crate::node::NodeDetails) are read only once, when its monitoring token
+/// appears. Therefore, it is intended for processes that observe the [`Node`]s of the
+/// system continuously, like a process monitor.
+///
+/// ```
+/// # use iceoryx2::prelude::*;
+/// # use iceoryx2::node::NodeWatcher;
+/// let mut watcher = NodeWatcher::<ipc::Service>::new(Config::global_config());
+///
+/// watcher.update(|node_state| {
+///     if let NodeState::Dead(view) = node_state {
+///         println!("found dead node {:?}", view);
+///     }
+///     CallbackProgression::Continue
+/// });
+/// ```
+#[derive(Debug)]
+pub struct NodeWatcher<Service: service::Service> {
+    config: Config,
+    nodes: BTreeMap<UniqueNodeId, WatchedNode<Service>>,
+}
+
+impl<Service: service::Service> NodeWatcher<Service> {
+    /// Creates a new [`NodeWatcher`] that watches all [`Node`]s under the provided [`Config`].
+    pub fn new(config: &Config) -> Self {
+        Self {
+            config: config.clone(),
+            nodes: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the number of [`Node`]s that were watched in the last
+    /// [`NodeWatcher::update()`].
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true when no [`Node`] was watched in the last [`NodeWatcher::update()`].
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Starts watching the monitoring tokens that appeared since the last call, stops
+    /// watching the ones that were removed and calls the provided callback with the
+    /// [`NodeState`] of every watched [`Node`]. With every iteration the callback has to return
+    /// [`CallbackProgression::Continue`] to perform the next iteration or
+    /// [`CallbackProgression::Stop`] to stop the iteration immediately.
+    pub fn update<F: FnMut(NodeState<Service>) -> CallbackProgression>(
+        &mut self,
+        mut callback: F,
+    ) -> Result<(), NodeListFailure> {
+        let msg = "Unable to update the watched nodes";
+        let monitoring_config = node_monitoring_config::<Service>(&self.config);
+        let node_list = fail!(from self, when Node::<Service>::list_all_nodes(&monitoring_config),
+            "{msg} since the monitoring tokens could not be listed.");
+
+        let node_ids: BTreeSet<UniqueNodeId> = node_list
+            .iter()
+            .filter_map(|name| core::str::from_utf8(name.as_bytes()).ok())
+            .filter_map(|name| name.parse::<u128>().ok())
+            .map(|id| UniqueNodeId(id.into()))
+            .collect();
+        self.nodes.retain(|id, _| node_ids.contains(id));
+
+        for node_id in node_ids {
+            let node_state = match self.node_state(&node_id, &monitoring_config) {
+                Ok(Some(node_state)) => node_state,
+                Ok(None) => continue,
+                Err(e) => {
+                    fail!(from self, with e,
+                        "{msg} since the state of the node {node_id:?} could not be acquired ({e:?}).");
+                }
+            };
+
+            if callback(node_state) == CallbackProgression::Stop {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn node_state(
+        &mut self,
+        node_id: &UniqueNodeId,
+        monitoring_config: &<Service::Monitoring as NamedConceptMgmt>::Configuration,
+    ) -> Result<Option<NodeState<Service>>, NodeListFailure> {
+        if !self.nodes.contains_key(node_id) {
+            let monitor =
+                match <Service::Monitoring as Monitoring>::Builder::new(&node_id.as_file_name())
+                    .config(monitoring_config)
+                    .monitor()
+                {
+                    Ok(monitor) => monitor,
+                    Err(MonitoringCreateMonitorError::InsufficientPermissions) => {
+                        return Ok(Some(NodeState::Inaccessible(*node_id)));
+                    }
+                    Err(MonitoringCreateMonitorError::Interrupt) => {
+                        return Err(NodeListFailure::Interrupt);
+                    }
+                    Err(MonitoringCreateMonitorError::InternalError)
+                    | Err(MonitoringCreateMonitorError::ConceptNameNotSupportedOnPlatform) => {
+                        return Ok(Some(NodeState::Undefined(*node_id)));
+                    }
+                };
+
+            self.nodes.insert(
+                *node_id,
+                WatchedNode {
+                    monitor,
+                    view: AliveNodeView {
+                        id: *node_id,
+                        details: None,
+                        _service: PhantomData,
+                    },
+                },
+            );
+        }
+
+        let watched_node = match self.nodes.get_mut(node_id) {
+            Some(watched_node) => watched_node,
+            None => return Ok(None),
+        };
+
+        let state = match Node::<Service>::state_from_monitor(&watched_node.monitor) {
+            Ok(state) => state,
+            Err(NodeListFailure::InsufficientPermissions) => {
+                return Ok(Some(NodeState::Inaccessible(*node_id)));
+            }
+            Err(NodeListFailure::InternalError) => {
+                return Ok(Some(NodeState::Undefined(*node_id)));
+            }
+            Err(e) => return Err(e),
+        };
+
+        // the details are written while the node is starting, therefore they are read as soon
+        // as the node is no longer starting
+        if state != State::DoesNotExist && watched_node.view.details.is_none() {
+            watched_node.view.details =
+                Node::<Service>::get_node_details(&self.config, node_id).unwrap_or_default();
+        }
+
+        match state {
+            State::Alive => Ok(Some(NodeState::Alive(watched_node.view.clone()))),
+            State::Dead => Ok(Some(NodeState::Dead(DeadNodeView(
+                watched_node.view.clone(),
+            )))),
+            State::DoesNotExist => Ok(None),
+        }
+    }
+}
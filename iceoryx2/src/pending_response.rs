@@ -61,6 +61,7 @@ use core::{fmt::Debug, marker::PhantomData};
 use iceoryx2_bb_concurrency::atomic::Ordering;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
+use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_log::fail;
 
 use crate::port::client::ClientSharedState;
@@ -68,7 +69,13 @@ use crate::port::details::chunk::Chunk;
 use crate::port::details::chunk_details::ChunkDetails;
 use crate::raw_sample::RawSample;
 use crate::service::builder::CustomPayloadMarker;
-use crate::{port::ReceiveError, request_mut::RequestMut, response::Response, service};
+use crate::service::static_config::message_type_details::TypeDetail;
+use crate::{
+    port::{ReceiveDeserializedError, ReceiveError},
+    request_mut::RequestMut,
+    response::Response,
+    service,
+};
 
 /// Represents an active connection to all [`Server`](crate::port::server::Server)
 /// that received the [`RequestMut`]. The
@@ -246,9 +253,18 @@ impl<
         fail!(from self, when client_shared_state.update_connections(),
                 "{msg} since the connections could not be updated.");
 
-        client_shared_state
+        let result = client_shared_state
             .response_receiver
-            .receive(self.request.channel_id)
+            .receive(self.request.channel_id);
+
+        if let Ok(Some((_, chunk))) = &result {
+            let header = unsafe {
+                &*(chunk.header as *const service::header::request_response::ResponseHeader)
+            };
+            iceoryx2_log::receive_trace_context(&header.trace_context);
+        }
+
+        result
     }
 }
 
@@ -458,3 +474,73 @@ impl<
         }
     }
 }
+
+impl<
+    Service: crate::service::Service,
+    RequestPayload: Debug + ZeroCopySend + ?Sized,
+    RequestHeader: Debug + ZeroCopySend,
+    ResponseHeader: Debug + ZeroCopySend,
+> PendingResponse<Service, RequestPayload, RequestHeader, [u8], ResponseHeader>
+{
+    /// Receives a serialized response from one of the [`Server`](crate::port::server::Server)s
+    /// that received the [`RequestMut`] and deserializes it with the provided `Serializer` into
+    /// `T`. The [`Service`](crate::service::Service) must have been created with
+    /// [`Builder::serialized_response_payload()`](crate::service::builder::request_response::Builder::serialized_response_payload())
+    /// with the same `Serializer` and `T`. If no response could be received [`None`] is
+    /// returned. If a failure occurs [`ReceiveDeserializedError`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_cal::serialize::postcard::Postcard;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node
+    /// #    .service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #    .request_response::<u64, [u8]>()
+    /// #    .serialized_response_payload::<Postcard, String>()
+    /// #    .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let pending_response = client.send_copy(0)?;
+    ///
+    /// if let Some(response) = pending_response.receive_deserialized::<Postcard, String>()? {
+    ///     println!("received response: {}", response);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn receive_deserialized<Serializer: Serialize, T: serde::de::DeserializeOwned>(
+        &self,
+    ) -> Result<Option<T>, ReceiveDeserializedError> {
+        let msg = "Unable to receive deserialized response";
+        let expected_type = TypeDetail::new_serialized::<Serializer, T>();
+        let payload_type = self
+            .request
+            .client_shared_state
+            .lock()
+            .response_receiver
+            .message_type_details
+            .payload;
+        if payload_type != expected_type {
+            fail!(from self, with ReceiveDeserializedError::IncompatibleSerialization,
+                "{} since the service response payload type \"{}\" does not match the serialized type \"{}\".",
+                msg, payload_type.type_name(), expected_type.type_name());
+        }
+
+        match self.receive()? {
+            None => Ok(None),
+            Some(response) => {
+                let value = fail!(from self, when Serializer::deserialize(response.payload()),
+                    with ReceiveDeserializedError::DeserializationFailed,
+                    "{} since the received payload could not be deserialized.", msg);
+                Ok(Some(value))
+            }
+        }
+    }
+}
@@ -89,6 +89,7 @@ use iceoryx2_cal::zero_copy_connection::{CHANNEL_STATE_CLOSED, CHANNEL_STATE_OPE
 use iceoryx2_cal::{
     arc_sync_policy::ArcSyncPolicy,
     dynamic_storage::DynamicStorage,
+    serialize::Serialize,
     shm_allocator::{AllocationStrategy, PointerOffset},
     zero_copy_connection::ChannelId,
 };
@@ -98,7 +99,10 @@ use crate::active_request::RequestId;
 use crate::{
     identifiers::UniqueClientId,
     pending_response::PendingResponse,
-    port::{details::data_segment::DataSegment, update_connections::UpdateConnections},
+    port::{
+        details::data_segment::{DataSegment, DataSegmentMemoryOptions},
+        update_connections::UpdateConnections,
+    },
     prelude::{PortFactory, UnableToDeliverStrategy},
     raw_sample::RawSampleMut,
     request_mut::RequestMut,
@@ -110,7 +114,7 @@ use crate::{
         header,
         naming_scheme::data_segment_name,
         port_factory::client::{ClientCreateError, LocalClientConfig, PortFactoryClient},
-        static_config::message_type_details::TypeVariant,
+        static_config::message_type_details::{TypeDetail, TypeVariant},
     },
 };
 
@@ -163,6 +167,38 @@ impl core::fmt::Display for RequestSendError {
 
 impl core::error::Error for RequestSendError {}
 
+/// Failure that can be emitted when a request is sent with [`Client::send_serialized()`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum RequestSendSerializedError {
+    /// The serializer or payload type do not match the serialized request payload type of the
+    /// [`Service`](crate::service::Service).
+    IncompatibleSerialization,
+    /// The payload could not be serialized.
+    SerializationFailed,
+    /// The serialized request could not be delivered.
+    RequestSendError(RequestSendError),
+}
+
+impl From<RequestSendError> for RequestSendSerializedError {
+    fn from(value: RequestSendError) -> Self {
+        RequestSendSerializedError::RequestSendError(value)
+    }
+}
+
+impl From<LoanError> for RequestSendSerializedError {
+    fn from(value: LoanError) -> Self {
+        RequestSendSerializedError::RequestSendError(RequestSendError::from(value))
+    }
+}
+
+impl core::fmt::Display for RequestSendSerializedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "RequestSendSerializedError::{self:?}")
+    }
+}
+
+impl core::error::Error for RequestSendSerializedError {}
+
 #[derive(Debug)]
 pub(crate) struct ClientSharedState<Service: service::Service> {
     pub(crate) config: LocalClientConfig,
@@ -411,6 +447,7 @@ impl<
                 sample_layout,
                 global_config,
                 number_of_requests,
+                DataSegmentMemoryOptions::default(),
             ),
             DataSegmentType::Dynamic => DataSegment::<Service>::create_dynamic_segment(
                 &segment_name,
@@ -418,6 +455,7 @@ impl<
                 global_config,
                 number_of_requests,
                 client_factory.config.allocation_strategy,
+                DataSegmentMemoryOptions::default(),
             ),
         };
 
@@ -454,6 +492,7 @@ impl<
             receiver_max_buffer_size: static_config.max_active_requests_per_client,
             receiver_max_borrowed_samples: static_config.max_active_requests_per_client,
             enable_safe_overflow: static_config.enable_safe_overflow_for_requests,
+            enable_safe_overflow_for_channel: Vec::new(),
             degradation_handler: client_factory.request_degradation_handler,
             unable_to_deliver_handler: client_factory.unable_to_deliver_handler,
             number_of_samples: number_of_requests,
@@ -471,6 +510,7 @@ impl<
             // one channel suffices
             number_of_channels: 1,
             initial_channel_state: CHANNEL_STATE_OPEN,
+            receive_segment: None,
         };
 
         let number_of_to_be_removed_connections = service
@@ -502,10 +542,13 @@ impl<
             message_type_details: static_config.response_message_type_details,
             receiver_max_borrowed_samples: static_config
                 .max_borrowed_responses_per_pending_response,
+            share_max_borrowed_samples_across_channels: false,
             enable_safe_overflow: static_config.enable_safe_overflow_for_responses,
+            enable_safe_overflow_for_channel: Vec::new(),
             number_of_channels: number_of_requests,
             connection_storage: UnsafeCell::new(SlotMap::new(number_of_connections)),
             initial_channel_state: CHANNEL_STATE_CLOSED,
+            use_receive_segment: false,
         };
 
         let client_shared_state = Service::ArcThreadSafetyPolicy::new(ClientSharedState {
@@ -719,9 +762,13 @@ impl<
                 channel_id,
                 request_id: self.next_request_id(),
                 number_of_elements: 1,
+                trace_context: iceoryx2_log::current_trace_context().unwrap_or_default(),
             })
         };
         unsafe { user_header_ptr.write(RequestHeader::default()) };
+        chunk.zero_appended_fields::<RequestHeader, RequestPayload>(
+            &client_shared_state.request_sender.message_type_details,
+        );
 
         let ptr = unsafe {
             RawSampleMut::<
@@ -998,6 +1045,7 @@ impl<
                 channel_id,
                 request_id: self.next_request_id(),
                 number_of_elements: slice_len as _,
+                trace_context: iceoryx2_log::current_trace_context().unwrap_or_default(),
             })
         };
         unsafe { user_header_ptr.write(RequestHeader::default()) };
@@ -1074,3 +1122,82 @@ impl<Service: service::Service>
 ////////////////////////
 // END: sliced API
 ////////////////////////
+
+////////////////////////
+// BEGIN: serialized API
+////////////////////////
+impl<
+    Service: service::Service,
+    RequestHeader: Default + Debug + ZeroCopySend,
+    ResponsePayload: Debug + ZeroCopySend + ?Sized,
+    ResponseHeader: Debug + ZeroCopySend,
+> Client<Service, [u8], RequestHeader, ResponsePayload, ResponseHeader>
+{
+    /// Serializes the input `value` with the provided `Serializer` into a loaned request and
+    /// sends it. The [`Service`](crate::service::Service) must have been created with
+    /// [`Builder::serialized_request_payload()`](crate::service::builder::request_response::Builder::serialized_request_payload())
+    /// with the same `Serializer` and `T`.
+    /// On success it returns the [`PendingResponse`] to receive the responses, otherwise a
+    /// [`RequestSendSerializedError`] describing the failure.
+    ///
+    /// Since the size of the serialized data is only known at runtime, the [`Client`]
+    /// should be created with a sufficient
+    /// [`PortFactoryClient::initial_max_slice_len()`] or an [`AllocationStrategy`] that
+    /// allows the data segment to grow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_cal::serialize::postcard::Postcard;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .request_response::<[u8], u64>()
+    ///     .serialized_request_payload::<Postcard, String>()
+    ///     .open_or_create()?;
+    ///
+    /// let client = service.client_builder()
+    ///     .allocation_strategy(AllocationStrategy::PowerOfTwo)
+    ///     .create()?;
+    ///
+    /// let pending_response = client.send_serialized::<Postcard, _>(&String::from("hello"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)] // type alias would require 5 generic parameters which hardly reduces complexity
+    pub fn send_serialized<Serializer: Serialize, T: serde::Serialize>(
+        &self,
+        value: &T,
+    ) -> Result<
+        PendingResponse<Service, [u8], RequestHeader, ResponsePayload, ResponseHeader>,
+        RequestSendSerializedError,
+    > {
+        let msg = "Unable to send serialized request";
+        let expected_type = TypeDetail::new_serialized::<Serializer, T>();
+        let payload_type = self
+            .client_shared_state
+            .lock()
+            .request_sender
+            .message_type_details
+            .payload;
+        if payload_type != expected_type {
+            fail!(from self, with RequestSendSerializedError::IncompatibleSerialization,
+                "{} since the service request payload type \"{}\" does not match the serialized type \"{}\".",
+                msg, payload_type.type_name(), expected_type.type_name());
+        }
+
+        let serialized = fail!(from self, when Serializer::serialize(value),
+                with RequestSendSerializedError::SerializationFailed,
+                "{} since the payload could not be serialized.", msg);
+
+        let request = fail!(from self, when self.loan_slice_uninit(serialized.len()),
+                "{} since the loan of a request failed.", msg);
+
+        Ok(request.write_from_slice(&serialized).send()?)
+    }
+}
+////////////////////////
+// END: serialized API
+////////////////////////
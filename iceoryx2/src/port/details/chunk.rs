@@ -59,4 +59,31 @@ impl ChunkMut {
             size,
         }
     }
+
+    /// Zeroes the fields that were appended to the user header and payload of the service and
+    /// are unknown to the older `UserHeader` and `Payload` type of the sending port, see
+    /// [`SchemaCompatibility::AppendOnly`](crate::service::static_config::type_schema::SchemaCompatibility::AppendOnly).
+    pub(crate) fn zero_appended_fields<UserHeader, Payload>(
+        &self,
+        message_type_details: &MessageTypeDetails,
+    ) {
+        unsafe {
+            Self::zero_tail(
+                self.user_header,
+                size_of::<UserHeader>(),
+                message_type_details.user_header.size,
+            );
+            Self::zero_tail(
+                self.payload,
+                size_of::<Payload>(),
+                message_type_details.payload.size,
+            );
+        }
+    }
+
+    unsafe fn zero_tail(ptr: *mut u8, known_size: usize, size: usize) {
+        if known_size < size {
+            unsafe { ptr.add(known_size).write_bytes(0, size - known_size) };
+        }
+    }
 }
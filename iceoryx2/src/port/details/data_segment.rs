@@ -58,6 +58,14 @@ impl DataSegmentType {
     }
 }
 
+/// Defines how the memory of a [`DataSegment`] is backed and prepared when it is created.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct DataSegmentMemoryOptions {
+    pub(crate) is_memory_locked: bool,
+    pub(crate) prefault: bool,
+    pub(crate) use_huge_pages: bool,
+}
+
 #[derive(Debug)]
 enum MemoryType<Service: service::Service> {
     Static(Service::SharedMemory),
@@ -89,6 +97,7 @@ impl<Service: service::Service> DataSegment<Service> {
         chunk_layout: Layout,
         global_config: &config::Config,
         number_of_chunks: usize,
+        memory_options: DataSegmentMemoryOptions,
     ) -> Result<Self, SharedMemoryCreateError> {
         let allocator_config = shm_allocator::pool_allocator::Config {
             bucket_layout: chunk_layout,
@@ -102,6 +111,9 @@ impl<Service: service::Service> DataSegment<Service> {
                                 Service::SharedMemory,
                                     >>::new(segment_name)
                                     .config(&segment_config)
+                                    .lock_memory(memory_options.is_memory_locked)
+                                    .prefault(memory_options.prefault)
+                                    .use_huge_pages(memory_options.use_huge_pages)
                                     .size(chunk_layout.size() * number_of_chunks + chunk_layout.align() - 1)
                                     .create(&allocator_config),
                                 "{msg}");
@@ -117,6 +129,7 @@ impl<Service: service::Service> DataSegment<Service> {
         global_config: &config::Config,
         number_of_chunks: usize,
         allocation_strategy: AllocationStrategy,
+        memory_options: DataSegmentMemoryOptions,
     ) -> Result<Self, SharedMemoryCreateError> {
         let msg = "Unable to create the dynamic data segment since the underlying shared memory could not be created.";
         let origin = "DataSegment::create_dynamic_segment()";
@@ -133,6 +146,9 @@ impl<Service: service::Service> DataSegment<Service> {
                     .max_number_of_chunks_hint(number_of_chunks)
                     .max_chunk_layout_hint(chunk_layout)
                     .allocation_strategy(allocation_strategy)
+                    .lock_memory(memory_options.is_memory_locked)
+                    .prefault(memory_options.prefault)
+                    .use_huge_pages(memory_options.use_huge_pages)
                     .create(),
                     "{msg}");
 
@@ -182,6 +198,15 @@ impl<Service: service::Service> DataSegment<Service> {
         }
     }
 
+    pub(crate) fn chunk_address(&self, offset: PointerOffset) -> usize {
+        match &self.memory {
+            MemoryType::Static(memory) => memory.payload_start_address() + offset.offset(),
+            MemoryType::Dynamic(memory) => {
+                memory.payload_start_address(offset.segment_id()) + offset.offset()
+            }
+        }
+    }
+
     pub(crate) fn max_number_of_segments(data_segment_type: DataSegmentType) -> u8 {
         match data_segment_type {
             DataSegmentType::Static => 1,
@@ -299,8 +324,4 @@ impl<Service: service::Service> DataSegmentView<Service> {
             }
         }
     }
-
-    pub(crate) fn is_dynamic(&self) -> bool {
-        matches!(&self.memory, MemoryViewType::Dynamic(_))
-    }
 }
@@ -16,3 +16,4 @@ pub(crate) mod data_segment;
 pub(crate) mod receiver;
 pub(crate) mod segment_state;
 pub(crate) mod sender;
+pub(crate) mod subscriber_wakeup;
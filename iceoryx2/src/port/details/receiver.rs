@@ -21,18 +21,21 @@ use iceoryx2_bb_elementary::cyclic_tagger::*;
 use iceoryx2_bb_elementary_traits::non_null::NonNullCompat;
 use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
 use iceoryx2_bb_memory::heap_allocator::HeapAllocator;
+use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shared_memory::SharedMemoryOpenError;
 use iceoryx2_cal::zero_copy_connection::*;
 use iceoryx2_log::fatal_panic;
 use iceoryx2_log::{error, fail, warn};
 
+use crate::config;
 use crate::port::DegradationCause;
 use crate::port::DegradationInfo;
 use crate::port::update_connections::ConnectionFailure;
 use crate::port::{DegradationAction, DegradationHandler, ReceiveError};
 use crate::service::NoResource;
 use crate::service::SharedServiceState;
-use crate::service::naming_scheme::data_segment_name;
+use crate::service::naming_scheme::{data_segment_name, receive_segment_name};
 use crate::service::static_config::message_type_details::MessageTypeDetails;
 use crate::service::{self, config_scheme::connection_config, naming_scheme::connection_name};
 
@@ -51,7 +54,11 @@ pub(crate) struct SenderDetails {
 #[derive(Debug)]
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
-    pub(crate) data_segment: DataSegmentView<Service>,
+    // the receive segment of a sender that copies on send is created by the sender when it
+    // connects, therefore it is opened on the first receive
+    data_segment: UnsafeCell<Option<DataSegmentView<Service>>>,
+    data_segment_name: FileName,
+    data_segment_type: DataSegmentType,
     pub(crate) sender_port_id: u128,
     tag: Tag,
 }
@@ -65,9 +72,9 @@ impl<Service: service::Service> Abandonable for Connection<Service> {
                 NonNull::iox2_from_mut(&mut this.receiver),
             )
         };
-        unsafe {
-            DataSegmentView::abandon_in_place(NonNull::iox2_from_mut(&mut this.data_segment))
-        };
+        if let Some(data_segment) = this.data_segment.get_mut() {
+            unsafe { DataSegmentView::abandon_in_place(NonNull::iox2_from_mut(data_segment)) };
+        }
     }
 }
 
@@ -93,41 +100,68 @@ impl<Service: service::Service> Connection<Service> {
         );
 
         let global_config = this.service_state.shared_node().config();
-        let receiver = fail!(from this,
-                        when <Service::Connection as ZeroCopyConnection>::
-                            Builder::new( &connection_name(sender_port_id, this.receiver_port_id))
-                                    .config(&connection_config::<Service>(global_config))
-                                    .buffer_size(this.buffer_size)
-                                    .receiver_max_borrowed_samples_per_channel(this.receiver_max_borrowed_samples)
-                                    .enable_safe_overflow(this.enable_safe_overflow)
-                                    .number_of_samples_per_segment(number_of_samples)
-                                    .number_of_channels(this.number_of_channels)
-                                    .initial_channel_state(initial_channel_state)
-                                    .max_supported_shared_memory_segments(max_number_of_segments)
-                                    .timeout(global_config.global.creation_timeout)
-                                    .create_receiver(),
+        let builder = <Service::Connection as ZeroCopyConnection>::Builder::new(&connection_name(
+            sender_port_id,
+            this.receiver_port_id,
+        ))
+        .config(&connection_config::<Service>(global_config))
+        .buffer_size(this.buffer_size)
+        .receiver_max_borrowed_samples_per_channel(this.receiver_max_borrowed_samples)
+        .enable_safe_overflow(this.enable_safe_overflow)
+        .number_of_samples_per_segment(number_of_samples)
+        .number_of_channels(this.number_of_channels)
+        .initial_channel_state(initial_channel_state)
+        .max_supported_shared_memory_segments(max_number_of_segments)
+        .timeout(global_config.global.creation_timeout);
+
+        let builder = this.enable_safe_overflow_for_channel.iter().fold(
+            builder,
+            |builder, (channel_id, value)| {
+                builder.enable_safe_overflow_for_channel(*channel_id, *value)
+            },
+        );
+
+        let receiver = fail!(from this, when builder.create_receiver(),
                         "{} since the zero copy connection could not be established.", msg);
 
-        let segment_name = data_segment_name(sender_port_id);
-        let data_segment = match data_segment_type {
+        let new_self = Self {
+            receiver,
+            data_segment: UnsafeCell::new(None),
+            data_segment_name: match this.use_receive_segment {
+                false => data_segment_name(sender_port_id),
+                true => receive_segment_name(sender_port_id, this.receiver_port_id),
+            },
+            data_segment_type,
+            sender_port_id,
+            tag: cyclic_tagger.create_tag(),
+        };
+
+        if !this.use_receive_segment {
+            fail!(from this, when new_self.data_segment(global_config),
+                "{} since the sender data segment could not be opened.", msg);
+        }
+
+        Ok(new_self)
+    }
+
+    fn data_segment(
+        &self,
+        global_config: &config::Config,
+    ) -> Result<&DataSegmentView<Service>, SharedMemoryOpenError> {
+        if let Some(data_segment) = unsafe { &*self.data_segment.get() } {
+            return Ok(data_segment);
+        }
+
+        let data_segment = match self.data_segment_type {
             DataSegmentType::Static => {
-                DataSegmentView::open_static_segment(&segment_name, global_config)
+                DataSegmentView::open_static_segment(&self.data_segment_name, global_config)?
             }
             DataSegmentType::Dynamic => {
-                DataSegmentView::open_dynamic_segment(&segment_name, global_config)
+                DataSegmentView::open_dynamic_segment(&self.data_segment_name, global_config)?
             }
         };
 
-        let data_segment = fail!(from this,
-                                 when data_segment,
-                                "{} since the sender data segment could not be opened.", msg);
-
-        Ok(Self {
-            receiver,
-            data_segment,
-            sender_port_id,
-            tag: cyclic_tagger.create_tag(),
-        })
+        Ok(unsafe { &mut *self.data_segment.get() }.insert(data_segment))
     }
 }
 
@@ -143,10 +177,13 @@ pub(crate) struct Receiver<Service: service::Service> {
     pub(crate) degradation_handler: DegradationHandler<'static>,
     pub(crate) message_type_details: MessageTypeDetails,
     pub(crate) receiver_max_borrowed_samples: usize,
+    pub(crate) share_max_borrowed_samples_across_channels: bool,
     pub(crate) enable_safe_overflow: bool,
+    pub(crate) enable_safe_overflow_for_channel: Vec<(ChannelId, bool)>,
     pub(crate) number_of_channels: usize,
     pub(crate) connection_storage: UnsafeCell<SlotMap<Connection<Service>>>,
     pub(crate) initial_channel_state: ChannelState,
+    pub(crate) use_receive_segment: bool,
 }
 
 impl<Service: service::Service> Abandonable for Receiver<Service> {
@@ -167,7 +204,9 @@ impl<Service: service::Service> Receiver<Service> {
                 return;
             }
 
-            unsafe { connection.data_segment.unregister_offset(chunk.offset) };
+            if let Some(data_segment) = unsafe { &*connection.data_segment.get() } {
+                unsafe { data_segment.unregister_offset(chunk.offset) };
+            }
             match connection.receiver.release(chunk.offset, channel_id) {
                 Ok(()) => (),
                 Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
@@ -437,13 +476,14 @@ impl<Service: service::Service> Receiver<Service> {
                         origin: connection.sender_port_id,
                     };
 
+                    let global_config = self.service_state.shared_node().config();
                     let offset = match connection
-                        .data_segment
-                        .register_and_translate_offset(offset)
+                        .data_segment(global_config)
+                        .and_then(|data_segment| data_segment.register_and_translate_offset(offset))
                     {
                         Ok(offset) => offset,
                         Err(e) => {
-                            if connection.data_segment.is_dynamic() {
+                            if connection.data_segment_type == DataSegmentType::Dynamic {
                                 warn!(from self, "Lost a sample. This only happens in the dynamic use case when a sender has reallocated its data segment and gone out of scope before the receiver has mapped the realloacted data segment. To circumvent this, you could either use static memory or increase the initial max slice len.");
                                 return Ok(None);
                             }
@@ -497,7 +537,9 @@ impl<Service: service::Service> Receiver<Service> {
                         };
                         let receiver = &connection.receiver;
 
-                        if receiver.borrow_count(channel_id) == receiver.max_borrowed_samples() {
+                        if self.borrow_count(receiver, channel_id)
+                            >= receiver.max_borrowed_samples()
+                        {
                             continue;
                         }
 
@@ -534,6 +576,20 @@ impl<Service: service::Service> Receiver<Service> {
         Ok(ret_val)
     }
 
+    fn borrow_count(
+        &self,
+        receiver: &<Service::Connection as ZeroCopyConnection>::Receiver,
+        channel_id: ChannelId,
+    ) -> usize {
+        if self.share_max_borrowed_samples_across_channels {
+            (0..self.number_of_channels)
+                .map(|n| receiver.borrow_count(ChannelId::new(n)))
+                .sum()
+        } else {
+            receiver.borrow_count(channel_id)
+        }
+    }
+
     pub(crate) fn receive(
         &self,
         channel_id: ChannelId,
@@ -552,7 +608,7 @@ impl<Service: service::Service> Receiver<Service> {
             }
 
             active_channel_count += 1;
-            if connection.receiver.borrow_count(channel_id)
+            if self.borrow_count(&connection.receiver, channel_id)
                 >= connection.receiver.max_borrowed_samples()
             {
                 continue;
@@ -23,7 +23,10 @@ use iceoryx2_bb_elementary::cyclic_tagger::*;
 use iceoryx2_bb_elementary_traits::non_null::NonNullCompat;
 use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
-use iceoryx2_cal::shm_allocator::{AllocationError, PointerOffset, ShmAllocationError};
+use iceoryx2_cal::shared_memory::SharedMemoryCreateError;
+use iceoryx2_cal::shm_allocator::{
+    AllocationError, AllocationStrategy, PointerOffset, ShmAllocationError,
+};
 use iceoryx2_cal::zero_copy_connection::{
     ChannelId, ChannelState, UnableToDeliverToReceiverAction, ZeroCopyConnection,
     ZeroCopyConnectionBuilder, ZeroCopyCreationError, ZeroCopyPortDetails, ZeroCopySendError,
@@ -38,12 +41,13 @@ use crate::port::{
 };
 use crate::prelude::UnableToDeliverStrategy;
 use crate::service::config_scheme::connection_config;
+use crate::service::naming_scheme::receive_segment_name;
 use crate::service::static_config::message_type_details::{MessageTypeDetails, TypeVariant};
 use crate::service::{NoResource, SharedServiceState};
 use crate::{service, service::naming_scheme::connection_name};
 
 use super::chunk::ChunkMut;
-use super::data_segment::DataSegment;
+use super::data_segment::{DataSegment, DataSegmentMemoryOptions, DataSegmentType};
 use super::segment_state::SegmentState;
 
 #[derive(Clone, Copy)]
@@ -52,10 +56,22 @@ pub(crate) struct ReceiverDetails {
     pub(crate) buffer_size: usize,
 }
 
+/// Describes the private receive segment the [`Sender`] creates for every connection when the
+/// data is copied on send instead of sharing the senders data segment with all receivers.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReceiveSegmentConfig {
+    pub(crate) chunk_layout: Layout,
+    pub(crate) number_of_chunks: usize,
+    pub(crate) data_segment_type: DataSegmentType,
+    pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) memory_options: DataSegmentMemoryOptions,
+}
+
 #[derive(Debug)]
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) sender: <Service::Connection as ZeroCopyConnection>::Sender,
     pub(crate) receiver_port_id: u128,
+    receive_segment: Option<DataSegment<Service>>,
     tag: Tag,
 }
 
@@ -67,6 +83,11 @@ impl<Service: service::Service> Abandonable for Connection<Service> {
                 NonNull::iox2_from_mut(&mut this.sender),
             )
         };
+        if let Some(receive_segment) = &mut this.receive_segment {
+            unsafe {
+                DataSegment::<Service>::abandon_in_place(NonNull::iox2_from_mut(receive_segment))
+            };
+        }
     }
 }
 
@@ -95,26 +116,69 @@ impl<Service: service::Service> Connection<Service> {
                 msg, buffer_size, this.receiver_max_buffer_size);
         }
 
-        let sender = fail!(from this, when <Service::Connection as ZeroCopyConnection>::
-                        Builder::new( &connection_name(this.sender_port_id, receiver_port_id))
-                                .config(&connection_config::<Service>(this.shared_node.config()))
-                                .buffer_size(buffer_size)
-                                .receiver_max_borrowed_samples_per_channel(this.receiver_max_borrowed_samples)
-                                .enable_safe_overflow(this.enable_safe_overflow)
-                                .number_of_samples_per_segment(number_of_samples)
-                                .max_supported_shared_memory_segments(this.max_number_of_segments)
-                                .initial_channel_state(initial_channel_state)
-                                .number_of_channels(this.number_of_channels)
-                                .timeout(this.shared_node.config().global.creation_timeout)
-                                .create_sender(),
-                        "{}.", msg);
+        let builder = <Service::Connection as ZeroCopyConnection>::Builder::new(&connection_name(
+            this.sender_port_id,
+            receiver_port_id,
+        ))
+        .config(&connection_config::<Service>(this.shared_node.config()))
+        .buffer_size(buffer_size)
+        .receiver_max_borrowed_samples_per_channel(this.receiver_max_borrowed_samples)
+        .enable_safe_overflow(this.enable_safe_overflow)
+        .number_of_samples_per_segment(number_of_samples)
+        .max_supported_shared_memory_segments(this.max_number_of_segments)
+        .initial_channel_state(initial_channel_state)
+        .number_of_channels(this.number_of_channels)
+        .timeout(this.shared_node.config().global.creation_timeout);
+
+        let builder = this.enable_safe_overflow_for_channel.iter().fold(
+            builder,
+            |builder, (channel_id, value)| {
+                builder.enable_safe_overflow_for_channel(*channel_id, *value)
+            },
+        );
+
+        let receive_segment = match &this.receive_segment {
+            None => None,
+            Some(config) => Some(fail!(from this,
+                    when Self::create_receive_segment(this, receiver_port_id, config),
+                    with ZeroCopyCreationError::InternalError,
+                    "{} since the receive segment could not be created.", msg)),
+        };
+
+        let sender = fail!(from this, when builder.create_sender(), "{}.", msg);
 
         Ok(Self {
             sender,
             receiver_port_id,
+            receive_segment,
             tag,
         })
     }
+
+    fn create_receive_segment(
+        this: &Sender<Service>,
+        receiver_port_id: u128,
+        config: &ReceiveSegmentConfig,
+    ) -> Result<DataSegment<Service>, SharedMemoryCreateError> {
+        let segment_name = receive_segment_name(this.sender_port_id, receiver_port_id);
+        match config.data_segment_type {
+            DataSegmentType::Static => DataSegment::create_static_segment(
+                &segment_name,
+                config.chunk_layout,
+                this.shared_node.config(),
+                config.number_of_chunks,
+                config.memory_options,
+            ),
+            DataSegmentType::Dynamic => DataSegment::create_dynamic_segment(
+                &segment_name,
+                config.chunk_layout,
+                this.shared_node.config(),
+                config.number_of_chunks,
+                config.allocation_strategy,
+                config.memory_options,
+            ),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -128,6 +192,7 @@ pub(crate) struct Sender<Service: service::Service> {
     pub(crate) receiver_max_borrowed_samples: usize,
     pub(crate) sender_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
+    pub(crate) enable_safe_overflow_for_channel: Vec<(ChannelId, bool)>,
     pub(crate) number_of_samples: usize,
     pub(crate) max_number_of_segments: u8,
     pub(crate) degradation_handler: DegradationHandler<'static>,
@@ -139,6 +204,7 @@ pub(crate) struct Sender<Service: service::Service> {
     pub(crate) message_type_details: MessageTypeDetails,
     pub(crate) number_of_channels: usize,
     pub(crate) initial_channel_state: ChannelState,
+    pub(crate) receive_segment: Option<ReceiveSegmentConfig>,
 }
 
 impl<Service: service::Service> Abandonable for Sender<Service> {
@@ -199,6 +265,12 @@ impl<Service: service::Service> Sender<Service> {
 
         let mut number_of_recipients = 0;
         if let Some(connection) = self.get(connection_id) {
+            let (offset, sample_size) =
+                match self.offset_for_connection(connection, offset, sample_size) {
+                    Some(v) => v,
+                    None => return Ok(0),
+                };
+
             let delivery_call_result = if let Some(handler) =
                 self.unable_to_deliver_handler.as_ref()
             {
@@ -256,6 +328,10 @@ impl<Service: service::Service> Sender<Service> {
                 }
             };
 
+            if delivery_call_result.is_err() {
+                self.discard_offset_of_connection(connection, offset);
+            }
+
             match delivery_call_result {
                 Err(ZeroCopySendError::UnableToDeliver) => {
                     // can only happen with blocking send and the degradation handler triggered a failure
@@ -308,11 +384,11 @@ impl<Service: service::Service> Sender<Service> {
                     }
                 }
                 Ok(overflow) => {
-                    self.borrow_sample(offset);
+                    self.borrow_delivered_sample(connection, offset);
                     number_of_recipients += 1;
 
                     if let Some(old) = overflow {
-                        self.release_sample(old)
+                        self.release_sample_of_connection(connection, old)
                     }
                 }
             }
@@ -320,6 +396,106 @@ impl<Service: service::Service> Sender<Service> {
         Ok(number_of_recipients)
     }
 
+    /// Returns the offset and sample size that shall be sent to the connection. When the data
+    /// is copied on send, the chunk is copied into the receive segment of the connection and
+    /// the offset of the copy is returned. If no copy could be acquired, [`None`] is returned.
+    fn offset_for_connection(
+        &self,
+        connection: &Connection<Service>,
+        offset: PointerOffset,
+        sample_size: usize,
+    ) -> Option<(PointerOffset, usize)> {
+        let receive_segment = match &connection.receive_segment {
+            None => return Some((offset, sample_size)),
+            Some(receive_segment) => receive_segment,
+        };
+
+        // # SAFETY: the sample size is the bucket size of the chunk, which is always a multiple
+        //           of the sample alignment
+        let layout = unsafe {
+            Layout::from_size_align_unchecked(sample_size, self.sample_layout(1).align())
+        };
+        let copy = match receive_segment.allocate(layout) {
+            Ok(copy) => copy,
+            Err(e) => {
+                warn!(from self,
+                    "Unable to deliver {:?} to receiver {:?} since no memory could be acquired from its receive segment ({:?}).",
+                    offset, connection.receiver_port_id, e);
+                return None;
+            }
+        };
+
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                self.data_segment.chunk_address(offset) as *const u8,
+                copy.data_ptr,
+                sample_size,
+            )
+        };
+
+        Some((
+            copy.offset,
+            receive_segment.bucket_size(copy.offset.segment_id()),
+        ))
+    }
+
+    fn borrow_delivered_sample(&self, connection: &Connection<Service>, offset: PointerOffset) {
+        if connection.receive_segment.is_none() {
+            self.borrow_sample(offset);
+        }
+    }
+
+    fn discard_offset_of_connection(
+        &self,
+        connection: &Connection<Service>,
+        offset: PointerOffset,
+    ) {
+        if let Some(receive_segment) = &connection.receive_segment {
+            unsafe { receive_segment.deallocate_bucket(offset) };
+        }
+    }
+
+    fn release_sample_of_connection(
+        &self,
+        connection: &Connection<Service>,
+        offset: PointerOffset,
+    ) {
+        match &connection.receive_segment {
+            None => self.release_sample(offset),
+            Some(receive_segment) => unsafe { receive_segment.deallocate_bucket(offset) },
+        }
+    }
+
+    /// Tries to deliver the offset to the connection without blocking, for instance when a
+    /// newly connected receiver is provided with the history.
+    pub(crate) fn try_send_to_connection(
+        &self,
+        connection: &Connection<Service>,
+        offset: PointerOffset,
+        sample_size: usize,
+        channel_id: ChannelId,
+    ) -> Result<(), ZeroCopySendError> {
+        let (offset, sample_size) =
+            match self.offset_for_connection(connection, offset, sample_size) {
+                Some(v) => v,
+                None => return Err(ZeroCopySendError::InternalError),
+            };
+
+        match connection.sender.try_send(offset, sample_size, channel_id) {
+            Ok(overflow) => {
+                self.borrow_delivered_sample(connection, offset);
+                if let Some(old) = overflow {
+                    self.release_sample_of_connection(connection, old);
+                }
+                Ok(())
+            }
+            Err(e) => {
+                self.discard_offset_of_connection(connection, offset);
+                Err(e)
+            }
+        }
+    }
+
     pub(crate) fn has_disconnect_hint(
         &self,
         channel_id: ChannelId,
@@ -498,7 +674,7 @@ impl<Service: service::Service> Sender<Service> {
                     loop {
                         match connection.sender.reclaim(id) {
                             Ok(Some(ptr_dist)) => {
-                                self.release_sample(ptr_dist);
+                                self.release_sample_of_connection(connection, ptr_dist);
                             }
                             Ok(None) => break,
                             Err(e) => {
@@ -523,13 +699,16 @@ impl<Service: service::Service> Sender<Service> {
 
     fn remove_connection(&self, i: usize) {
         if let Some(connection) = self.get(i) {
-            // # SAFETY: the receiver no longer exist, therefore we can
-            //           reacquire all delivered samples
-            unsafe {
-                connection
-                    .sender
-                    .acquire_used_offsets(|offset| self.release_sample(offset))
-            };
+            // the copies in the receive segment are released together with the segment
+            if connection.receive_segment.is_none() {
+                // # SAFETY: the receiver no longer exist, therefore we can
+                //           reacquire all delivered samples
+                unsafe {
+                    connection
+                        .sender
+                        .acquire_used_offsets(|offset| self.release_sample(offset))
+                };
+            }
 
             *self.get_mut(i) = None;
         }
@@ -0,0 +1,79 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::ptr::NonNull;
+
+use iceoryx2_bb_elementary_traits::non_null::NonNullCompat;
+use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
+use iceoryx2_log::fail;
+
+use crate::port::notifier::{Notifier, NotifierNotifyError};
+use crate::service::{
+    self, NoResource, SharedServiceState,
+    builder::{Builder, event::EventOpenOrCreateError},
+    naming_scheme::subscriber_wakeup_service_name,
+    port_factory::event,
+};
+
+/// The [`Notifier`] a [`Publisher`](crate::port::publisher::Publisher) uses to wake up the
+/// [`Subscriber`](crate::port::subscriber::Subscriber)s.
+#[derive(Debug)]
+pub(crate) struct SubscriberWakeup<Service: service::Service> {
+    notifier: Notifier<Service>,
+}
+
+// The notifier uses the same `ArcThreadSafetyPolicy` as the publisher that owns it, therefore
+// it is as thread-safe as the surrounding publisher state.
+unsafe impl<Service: service::Service> Send for SubscriberWakeup<Service> {}
+unsafe impl<Service: service::Service> Sync for SubscriberWakeup<Service> {}
+
+impl<Service: service::Service> Abandonable for SubscriberWakeup<Service> {
+    unsafe fn abandon_in_place(mut this: NonNull<Self>) {
+        let this = unsafe { this.as_mut() };
+        unsafe { Notifier::abandon_in_place(NonNull::iox2_from_mut(&mut this.notifier)) }
+    }
+}
+
+impl<Service: service::Service> SubscriberWakeup<Service> {
+    pub(crate) fn new(notifier: Notifier<Service>) -> Self {
+        Self { notifier }
+    }
+
+    pub(crate) fn notify(&self) -> Result<usize, NotifierNotifyError> {
+        self.notifier.notify()
+    }
+}
+
+/// Opens or creates the internal event service that is used by the
+/// [`Publisher`](crate::port::publisher::Publisher) to wake up the
+/// [`Subscriber`](crate::port::subscriber::Subscriber)s of a publish-subscribe service.
+pub(crate) fn open_or_create_subscriber_wakeup_service<Service: service::Service>(
+    service: &SharedServiceState<Service, NoResource>,
+) -> Result<event::PortFactory<Service>, EventOpenOrCreateError> {
+    let origin = "open_or_create_subscriber_wakeup_service()";
+    let static_config = service.static_config();
+    let pubsub_config = static_config.publish_subscribe();
+    let name = subscriber_wakeup_service_name(static_config.service_hash());
+
+    Ok(fail!(from origin,
+        when Builder::new(&name, service.shared_node().clone())
+            .event()
+            .max_listeners(pubsub_config.max_subscribers())
+            .max_notifiers(pubsub_config.max_publishers())
+            .max_nodes(pubsub_config.max_nodes())
+            .disable_deadline()
+            .disable_notifier_created_event()
+            .disable_notifier_dropped_event()
+            .disable_notifier_dead_event()
+            .open_or_create(),
+        "Unable to open or create the subscriber wakeup service {:?}.", name))
+}
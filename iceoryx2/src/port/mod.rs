@@ -152,13 +152,19 @@ pub enum DegradationCause {
     FailedToEstablishConnection,
     /// Connection is corrupted
     ConnectionCorrupted,
+    /// No data was sent within the deadline of the [`Service`](crate::service::Service)
+    MissedDeadline,
+    /// The [`Node`](crate::node::Node) of a connected
+    /// [`Publisher`](crate::port::publisher::Publisher) died
+    PublisherLivelinessLost,
 }
 
 /// The degradation context passed to the [`DegradationHandler`]
 pub struct DegradationInfo {
     /// The service id, which is involved in the degradation
     pub service_id: u128,
-    /// The sender port id, which is involved in the degradation
+    /// The sender port id, which is involved in the degradation, or zero when the degradation
+    /// is not caused by a specific sender
     pub sender_port_id: u128,
     /// The receiver port id, which is involved in the degradation
     pub receiver_port_id: u128,
@@ -279,6 +285,15 @@ pub enum ReceiveError {
 
     /// Occurs when a receiver is unable to connect to a corresponding sender.
     ConnectionFailure(ConnectionFailure),
+
+    /// No data was sent within the deadline of the [`Service`](crate::service::Service) and
+    /// the [`DegradationHandler`] requested to fail.
+    MissedDeadline,
+
+    /// The [`Node`](crate::node::Node) of a connected
+    /// [`Publisher`](crate::port::publisher::Publisher) died and the [`DegradationHandler`]
+    /// requested to fail.
+    PublisherLivelinessLost,
 }
 
 impl From<ConnectionFailure> for ReceiveError {
@@ -294,3 +309,66 @@ impl core::fmt::Display for ReceiveError {
 }
 
 impl core::error::Error for ReceiveError {}
+
+/// Failure that can be emitted when data is sent with
+/// [`Publisher::send_serialized()`](crate::port::publisher::Publisher::send_serialized()) or
+/// [`ActiveRequest::send_serialized()`](crate::active_request::ActiveRequest::send_serialized()).
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum SendSerializedError {
+    /// The serializer or payload type do not match the serialized payload type of the
+    /// [`Service`](crate::service::Service).
+    IncompatibleSerialization,
+    /// The payload could not be serialized.
+    SerializationFailed,
+    /// The serialized payload could not be delivered.
+    SendError(SendError),
+}
+
+impl From<SendError> for SendSerializedError {
+    fn from(value: SendError) -> Self {
+        SendSerializedError::SendError(value)
+    }
+}
+
+impl From<LoanError> for SendSerializedError {
+    fn from(value: LoanError) -> Self {
+        SendSerializedError::SendError(SendError::LoanError(value))
+    }
+}
+
+impl core::fmt::Display for SendSerializedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SendSerializedError::{self:?}")
+    }
+}
+
+impl core::error::Error for SendSerializedError {}
+
+/// Failure that can be emitted when data is received with
+/// [`Subscriber::receive_deserialized()`](crate::port::subscriber::Subscriber::receive_deserialized()),
+/// [`Server::receive_deserialized()`](crate::port::server::Server::receive_deserialized()) or
+/// [`PendingResponse::receive_deserialized()`](crate::pending_response::PendingResponse::receive_deserialized()).
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ReceiveDeserializedError {
+    /// The serializer or payload type do not match the serialized payload type of the
+    /// [`Service`](crate::service::Service).
+    IncompatibleSerialization,
+    /// The received payload could not be deserialized.
+    DeserializationFailed,
+    /// The serialized payload could not be received.
+    ReceiveError(ReceiveError),
+}
+
+impl From<ReceiveError> for ReceiveDeserializedError {
+    fn from(value: ReceiveError) -> Self {
+        ReceiveDeserializedError::ReceiveError(value)
+    }
+}
+
+impl core::fmt::Display for ReceiveDeserializedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ReceiveDeserializedError::{self:?}")
+    }
+}
+
+impl core::error::Error for ReceiveDeserializedError {}
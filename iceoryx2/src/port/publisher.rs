@@ -121,11 +121,13 @@ use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::serialize::Serialize;
+use iceoryx2_cal::shared_memory::SharedMemoryCreateError;
 use iceoryx2_cal::shm_allocator::{AllocationStrategy, PointerOffset};
 use iceoryx2_cal::zero_copy_connection::{
-    CHANNEL_STATE_OPEN, ChannelId, ZeroCopyCreationError, ZeroCopyPortDetails, ZeroCopySender,
+    CHANNEL_STATE_OPEN, ChannelId, ZeroCopyCreationError, ZeroCopyPortDetails,
 };
-use iceoryx2_log::{fail, warn};
+use iceoryx2_log::{debug, fail, warn};
 
 use crate::port::details::sender::*;
 use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
@@ -138,12 +140,15 @@ use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, Subscr
 use crate::service::header::publish_subscribe::Header;
 use crate::service::naming_scheme::data_segment_name;
 use crate::service::port_factory::publisher::{LocalPublisherConfig, PortFactoryPublisher};
-use crate::service::static_config::message_type_details::TypeVariant;
+use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
 use crate::service::{self};
 
-use super::details::data_segment::{DataSegment, DataSegmentType};
+use super::details::data_segment::{DataSegment, DataSegmentMemoryOptions, DataSegmentType};
 use super::details::segment_state::SegmentState;
-use super::{LoanError, SendError};
+use super::details::subscriber_wakeup::{
+    SubscriberWakeup, open_or_create_subscriber_wakeup_service,
+};
+use super::{LoanError, SendError, SendSerializedError};
 use crate::identifiers::UniquePublisherId;
 
 /// Defines a failure that can occur when a [`Publisher`] is created with
@@ -160,6 +165,12 @@ pub enum PublisherCreateError {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The data segment could not be locked into memory or prefaulted since a system limit,
+    /// like the maximum amount of lockable memory, was exceeded.
+    InsufficientResources,
+    /// The event to wake up the [`Subscriber`](crate::port::subscriber::Subscriber)s could not
+    /// be created.
+    UnableToCreateWakeupEvent,
 }
 
 impl core::fmt::Display for PublisherCreateError {
@@ -174,6 +185,7 @@ impl core::error::Error for PublisherCreateError {}
 struct OffsetAndSize {
     offset: u64,
     size: usize,
+    priority: u8,
 }
 
 #[derive(Debug)]
@@ -183,17 +195,21 @@ pub(crate) struct PublisherSharedState<Service: service::Service> {
     subscriber_list_state: UnsafeCell<ContainerState<SubscriberDetails>>,
     history: Option<UnsafeCell<Queue<OffsetAndSize>>>,
     is_active: AtomicBool,
+    subscriber_wakeup: Option<SubscriberWakeup<Service>>,
 }
 
 impl<Service: service::Service> Abandonable for PublisherSharedState<Service> {
     unsafe fn abandon_in_place(mut this: NonNull<Self>) {
         let this = unsafe { this.as_mut() };
         unsafe { Sender::<Service>::abandon_in_place(NonNull::iox2_from_mut(&mut this.sender)) }
+        if let Some(subscriber_wakeup) = &mut this.subscriber_wakeup {
+            unsafe { SubscriberWakeup::abandon_in_place(NonNull::iox2_from_mut(subscriber_wakeup)) }
+        }
     }
 }
 
 impl<Service: service::Service> PublisherSharedState<Service> {
-    fn add_sample_to_history(&self, offset: PointerOffset, sample_size: usize) {
+    fn add_sample_to_history(&self, offset: PointerOffset, sample_size: usize, priority: u8) {
         match &self.history {
             None => (),
             Some(history) => {
@@ -202,6 +218,7 @@ impl<Service: service::Service> PublisherSharedState<Service> {
                 match history.push_with_overflow(OffsetAndSize {
                     offset: offset.as_value(),
                     size: sample_size,
+                    priority,
                 }) {
                     None => (),
                     Some(old) => self
@@ -212,6 +229,42 @@ impl<Service: service::Service> PublisherSharedState<Service> {
         }
     }
 
+    fn add_sample_to_history_cache(&self, chunk: *mut u8, sample_size: usize) {
+        let history_cache = &self
+            .sender
+            .service_state
+            .dynamic_storage()
+            .get()
+            .publish_subscribe()
+            .history_cache;
+
+        if history_cache.capacity() == 0 {
+            return;
+        }
+
+        // the position is part of the header, a subscriber that receives the sample from the
+        // cache and from the connection discards the second one
+        let position = history_cache.reserve_position();
+        let header = unsafe { &mut *(chunk as *mut Header) };
+        header.set_history_cache_position(position);
+
+        // the chunk may be larger than the sample, only the used part is retained
+        let number_of_elements = header.number_of_elements();
+        let used_size = self
+            .sender
+            .service_state
+            .static_config()
+            .publish_subscribe()
+            .message_type_details
+            .sample_layout(number_of_elements as usize)
+            .size()
+            .min(sample_size);
+
+        if !unsafe { history_cache.store(position, chunk, used_size) } {
+            debug!(from self, "The sample could not be retained in the history cache of the service.");
+        }
+    }
+
     fn force_update_connections(&self) -> Result<(), ZeroCopyCreationError> {
         let mut result = Ok(());
         self.sender.start_update_connection_cycle();
@@ -263,28 +316,28 @@ impl<Service: service::Service> PublisherSharedState<Service> {
                 let history = unsafe { &mut *history.get() };
                 let buffer_size = connection.sender.buffer_size();
                 let history_start = history.len().saturating_sub(buffer_size);
+                let mut has_delivered_history = false;
 
                 for i in history_start..history.len() {
                     let old_sample = unsafe { history.get_unchecked(i) };
                     self.sender.retrieve_returned_samples();
 
-                    let offset = PointerOffset::from_value(old_sample.offset);
-                    match connection
-                        .sender
-                        .try_send(offset, old_sample.size, ChannelId::new(0))
-                    {
-                        Ok(overflow) => {
-                            self.sender.borrow_sample(offset);
-
-                            if let Some(old) = overflow {
-                                self.sender.release_sample(old);
-                            }
-                        }
+                    match self.sender.try_send_to_connection(
+                        connection,
+                        PointerOffset::from_value(old_sample.offset),
+                        old_sample.size,
+                        ChannelId::new(old_sample.priority as usize),
+                    ) {
+                        Ok(()) => has_delivered_history = true,
                         Err(e) => {
                             warn!(from self, "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e);
                         }
                     }
                 }
+
+                if has_delivered_history {
+                    self.wake_up_subscribers();
+                }
             }
         }
     }
@@ -292,7 +345,9 @@ impl<Service: service::Service> PublisherSharedState<Service> {
     pub(crate) fn send_sample(
         &self,
         offset: PointerOffset,
+        chunk: *mut u8,
         sample_size: usize,
+        priority: u8,
     ) -> Result<usize, SendError> {
         let msg = "Unable to send sample";
         if !self.is_active.load(Ordering::Relaxed) {
@@ -300,12 +355,67 @@ impl<Service: service::Service> PublisherSharedState<Service> {
                 "{} since the corresponding publisher is already disconnected.", msg);
         }
 
+        self.update_time_since_last_sample();
+
+        // a subscriber that is created concurrently either finds the sample in the history
+        // cache or is already connected when the sample is delivered
+        self.add_sample_to_history_cache(chunk, sample_size);
+
         fail!(from self, when self.update_connections(),
             "{} since the connections could not be updated.", msg);
 
-        self.add_sample_to_history(offset, sample_size);
-        self.sender
-            .deliver_offset(offset, sample_size, ChannelId::new(0))
+        let priority = self
+            .sender
+            .service_state
+            .static_config()
+            .publish_subscribe()
+            .clamp_priority(priority);
+
+        self.add_sample_to_history(offset, sample_size, priority);
+        let number_of_recipients =
+            self.sender
+                .deliver_offset(offset, sample_size, ChannelId::new(priority as usize))?;
+
+        if number_of_recipients > 0 {
+            self.wake_up_subscribers();
+        }
+
+        Ok(number_of_recipients)
+    }
+
+    fn update_time_since_last_sample(&self) {
+        if let Some(deadline) = self
+            .sender
+            .service_state
+            .static_config()
+            .publish_subscribe()
+            .deadline
+            .as_option_ref()
+        {
+            match deadline.creation_time.elapsed() {
+                Ok(duration_since_creation) => {
+                    self.sender
+                        .service_state
+                        .dynamic_storage()
+                        .get()
+                        .publish_subscribe()
+                        .elapsed_time_since_last_sample
+                        .fetch_max(duration_since_creation.as_nanos() as u64, Ordering::Relaxed);
+                }
+                Err(e) => {
+                    warn!(from self,
+                        "Unable to acquire the elapsed system time which is required for deadline handling ({:?}).", e);
+                }
+            }
+        }
+    }
+
+    fn wake_up_subscribers(&self) {
+        if let Some(subscriber_wakeup) = &self.subscriber_wakeup {
+            if let Err(e) = subscriber_wakeup.notify() {
+                warn!(from self, "Unable to wake up the subscribers ({:?}).", e);
+            }
+        }
     }
 }
 
@@ -392,12 +502,15 @@ impl<
         let msg = "Unable to create Publisher port";
         let origin = "Publisher::new()";
         let port_id = UniquePublisherId::new();
-        let config = &publisher_factory.config;
         let static_config = publisher_factory
             .factory
             .service
             .static_config()
             .publish_subscribe();
+        let config = &LocalPublisherConfig {
+            priority: static_config.clamp_priority(publisher_factory.config.priority),
+            ..publisher_factory.config
+        };
         let service = &publisher_factory.factory.service;
         let subscriber_list = &service
             .dynamic_storage()
@@ -438,12 +551,18 @@ impl<
         let global_config = service.shared_node().config();
 
         let segment_name = data_segment_name(publisher_details.publisher_id.value());
+        let memory_options = DataSegmentMemoryOptions {
+            is_memory_locked: config.is_memory_locked,
+            prefault: config.prefault,
+            use_huge_pages: config.use_huge_pages,
+        };
         let data_segment = match data_segment_type {
             DataSegmentType::Static => DataSegment::create_static_segment(
                 &segment_name,
                 sample_layout,
                 global_config,
                 number_of_samples,
+                memory_options,
             ),
             DataSegmentType::Dynamic => DataSegment::create_dynamic_segment(
                 &segment_name,
@@ -451,17 +570,40 @@ impl<
                 global_config,
                 number_of_samples,
                 config.allocation_strategy,
+                memory_options,
             ),
         };
 
-        let data_segment = fail!(from origin,
-                when data_segment,
-                with PublisherCreateError::UnableToCreateDataSegment,
-                "{} since the data segment could not be acquired.", msg);
+        let data_segment = match data_segment {
+            Ok(data_segment) => data_segment,
+            Err(SharedMemoryCreateError::InsufficientResources) => {
+                fail!(from origin, with PublisherCreateError::InsufficientResources,
+                    "{} since the data segment could not be locked or prefaulted due to insufficient resources.", msg);
+            }
+            Err(e) => {
+                fail!(from origin, with PublisherCreateError::UnableToCreateDataSegment,
+                    "{} since the data segment could not be acquired ({:?}).", msg, e);
+            }
+        };
+
+        let subscriber_wakeup = if static_config.enable_subscriber_wakeup {
+            let wakeup_service = fail!(from origin,
+                when open_or_create_subscriber_wakeup_service(service),
+                with PublisherCreateError::UnableToCreateWakeupEvent,
+                "{} since the subscriber wakeup service could not be opened or created.", msg);
+
+            Some(SubscriberWakeup::new(fail!(from origin,
+                when wakeup_service.notifier_builder().create(),
+                with PublisherCreateError::UnableToCreateWakeupEvent,
+                "{} since the notifier to wake up the subscribers could not be created.", msg)))
+        } else {
+            None
+        };
 
         let publisher_shared_state =
             <Service as service::Service>::ArcThreadSafetyPolicy::new(PublisherSharedState {
                 is_active: AtomicBool::new(true),
+                subscriber_wakeup,
                 sender: Sender {
                     data_segment,
                     segment_states: {
@@ -480,6 +622,7 @@ impl<
                     receiver_max_buffer_size: static_config.subscriber_max_buffer_size,
                     receiver_max_borrowed_samples: static_config.subscriber_max_borrowed_samples,
                     enable_safe_overflow: static_config.enable_safe_overflow,
+                    enable_safe_overflow_for_channel: static_config.safe_overflow_per_channel(),
                     number_of_samples,
                     max_number_of_segments,
                     degradation_handler: publisher_factory.degradation_handler,
@@ -490,12 +633,26 @@ impl<
                     sender_max_borrowed_samples: config.max_loaned_samples,
                     unable_to_deliver_strategy: config.unable_to_deliver_strategy,
                     message_type_details: static_config.message_type_details,
-                    number_of_channels: 1,
+                    number_of_channels: static_config.number_of_priorities as usize,
                     initial_channel_state: CHANNEL_STATE_OPEN,
+                    receive_segment: match static_config.enable_copy_on_send {
+                        false => None,
+                        true => Some(ReceiveSegmentConfig {
+                            chunk_layout: sample_layout,
+                            number_of_chunks: static_config
+                                .required_amount_of_samples_per_receive_segment(),
+                            data_segment_type,
+                            allocation_strategy: config.allocation_strategy,
+                            memory_options,
+                        }),
+                    },
                 },
                 config: *config,
                 subscriber_list_state: UnsafeCell::new(unsafe { subscriber_list.get_state() }),
-                history: match static_config.history_size == 0 {
+                // the history cache of the service supersedes the history of the publisher
+                history: match static_config.history_size == 0
+                    || static_config.history_cache_size != 0
+                {
                     true => None,
                     false => Some(UnsafeCell::new(Queue::new(static_config.history_size))),
                 },
@@ -640,10 +797,13 @@ impl<
             .sender
             .allocate(shared_state.sender.sample_layout(1))?;
         let node_id = shared_state.sender.service_state.shared_node().id();
+        let priority = shared_state.config.priority;
         let header_ptr = chunk.header as *mut Header;
         let user_header_ptr: *mut UserHeader = chunk.user_header.cast();
-        unsafe { header_ptr.write(Header::new(*node_id, self.id(), 1)) };
+        unsafe { header_ptr.write(Header::new(*node_id, self.id(), 1, priority)) };
         unsafe { user_header_ptr.write(UserHeader::default()) };
+        chunk
+            .zero_appended_fields::<UserHeader, Payload>(&shared_state.sender.message_type_details);
 
         let sample = unsafe {
             RawSampleMut::new_unchecked(header_ptr, user_header_ptr, chunk.payload.cast())
@@ -824,7 +984,8 @@ impl<
         let user_header_ptr: *mut UserHeader = chunk.user_header.cast();
         let header_ptr = chunk.header as *mut Header;
         let node_id = shared_state.sender.service_state.shared_node().id();
-        unsafe { header_ptr.write(Header::new(*node_id, self.id(), slice_len as _)) };
+        let priority = shared_state.config.priority;
+        unsafe { header_ptr.write(Header::new(*node_id, self.id(), slice_len as _, priority)) };
         unsafe { user_header_ptr.write(UserHeader::default()) };
 
         let sample = unsafe {
@@ -879,6 +1040,77 @@ impl<Service: service::Service> Publisher<Service, [CustomPayloadMarker], Custom
 // END: sliced API
 ////////////////////////
 
+////////////////////////
+// BEGIN: serialized API
+////////////////////////
+impl<Service: service::Service, UserHeader: Default + Debug + ZeroCopySend>
+    Publisher<Service, [u8], UserHeader>
+{
+    /// Serializes the input `value` with the provided `Serializer` into a loaned byte slice and
+    /// delivers it. The [`Service`](crate::service::Service) must have been created with
+    /// [`Builder::serialized_payload()`](crate::service::builder::publish_subscribe::Builder::serialized_payload())
+    /// with the same `Serializer` and `T`.
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data, otherwise a [`SendSerializedError`] describing the failure.
+    ///
+    /// Since the size of the serialized data is only known at runtime, the [`Publisher`]
+    /// should be created with a sufficient
+    /// [`PortFactoryPublisher::initial_max_slice_len()`] or an [`AllocationStrategy`] that
+    /// allows the data segment to grow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_cal::serialize::postcard::Postcard;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<[u8]>()
+    ///     .serialized_payload::<Postcard, String>()
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = service.publisher_builder()
+    ///     .allocation_strategy(AllocationStrategy::PowerOfTwo)
+    ///     .create()?;
+    ///
+    /// publisher.send_serialized::<Postcard, _>(&String::from("hello world"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_serialized<Serializer: Serialize, T: serde::Serialize>(
+        &self,
+        value: &T,
+    ) -> Result<usize, SendSerializedError> {
+        let msg = "Unable to send serialized payload";
+        let expected_type = TypeDetail::new_serialized::<Serializer, T>();
+        let payload_type = self
+            .publisher_shared_state
+            .lock()
+            .sender
+            .message_type_details
+            .payload;
+        if payload_type != expected_type {
+            fail!(from self, with SendSerializedError::IncompatibleSerialization,
+                "{} since the service payload type \"{}\" does not match the serialized type \"{}\".",
+                msg, payload_type.type_name(), expected_type.type_name());
+        }
+
+        let serialized = fail!(from self, when Serializer::serialize(value),
+                with SendSerializedError::SerializationFailed,
+                "{} since the payload could not be serialized.", msg);
+
+        let sample = fail!(from self, when self.loan_slice_uninit(serialized.len()),
+                "{} since the loan of a sample failed.", msg);
+
+        Ok(sample.write_from_slice(&serialized).send()?)
+    }
+}
+////////////////////////
+// END: serialized API
+////////////////////////
+
 impl<
     Service: service::Service,
     Payload: Debug + ZeroCopySend + ?Sized,
@@ -78,6 +78,7 @@ use crate::prelude::UnableToDeliverStrategy;
 use crate::service::builder::CustomPayloadMarker;
 use crate::service::naming_scheme::data_segment_name;
 use crate::service::port_factory::server::LocalServerConfig;
+use crate::service::static_config::message_type_details::TypeDetail;
 use crate::service::{NoResource, SharedServiceState};
 use crate::{
     active_request::ActiveRequest,
@@ -106,14 +107,15 @@ use iceoryx2_bb_memory::heap_allocator::HeapAllocator;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::zero_copy_connection::{CHANNEL_STATE_CLOSED, CHANNEL_STATE_OPEN, ChannelId};
 use iceoryx2_log::{fail, warn};
 
-use super::details::data_segment::DataSegment;
+use super::details::data_segment::{DataSegment, DataSegmentMemoryOptions};
 use super::details::segment_state::SegmentState;
 use super::details::sender::{ReceiverDetails, Sender};
 use super::{
-    ReceiveError,
+    ReceiveDeserializedError, ReceiveError,
     details::{
         chunk::Chunk,
         chunk_details::ChunkDetails,
@@ -361,7 +363,9 @@ impl<
             service_state: service.clone(),
             message_type_details: static_config.request_message_type_details,
             receiver_max_borrowed_samples: static_config.max_active_requests_per_client,
+            share_max_borrowed_samples_across_channels: false,
             enable_safe_overflow: static_config.enable_safe_overflow_for_requests,
+            enable_safe_overflow_for_channel: Vec::new(),
             buffer_size: static_config.max_active_requests_per_client,
             tagger: CyclicTagger::new(),
             to_be_removed_connections: if static_config.enable_fire_and_forget_requests {
@@ -379,6 +383,7 @@ impl<
             number_of_channels: 1,
             connection_storage: UnsafeCell::new(SlotMap::new(number_of_connections)),
             initial_channel_state: CHANNEL_STATE_OPEN,
+            use_receive_segment: false,
         };
 
         let global_config = service.shared_node().config();
@@ -397,6 +402,7 @@ impl<
                 sample_layout,
                 global_config,
                 number_of_responses,
+                DataSegmentMemoryOptions::default(),
             ),
             DataSegmentType::Dynamic => DataSegment::<Service>::create_dynamic_segment(
                 &segment_name,
@@ -404,6 +410,7 @@ impl<
                 global_config,
                 number_of_responses,
                 server_factory.config.allocation_strategy,
+                DataSegmentMemoryOptions::default(),
             ),
         };
 
@@ -434,6 +441,7 @@ impl<
                 * static_config.max_active_requests_per_client
                 * static_config.max_clients,
             enable_safe_overflow: static_config.enable_safe_overflow_for_responses,
+            enable_safe_overflow_for_channel: Vec::new(),
             number_of_samples: number_of_responses,
             max_number_of_segments,
             degradation_handler: server_factory.response_degradation_handler,
@@ -445,6 +453,7 @@ impl<
             message_type_details: static_config.response_message_type_details,
             number_of_channels: number_of_requests_per_client,
             initial_channel_state: CHANNEL_STATE_CLOSED,
+            receive_segment: None,
         };
 
         let shared_state = Service::ArcThreadSafetyPolicy::new(SharedServerState {
@@ -555,7 +564,16 @@ impl<
                   "Some requests are not being received since not all connections to the clients could be established.");
         }
 
-        shared_state.request_receiver.receive(REQUEST_CHANNEL_ID)
+        let result = shared_state.request_receiver.receive(REQUEST_CHANNEL_ID);
+
+        if let Ok(Some((_, chunk))) = &result {
+            let header = unsafe {
+                &*(chunk.header as *const service::header::request_response::RequestHeader)
+            };
+            iceoryx2_log::receive_trace_context(&header.trace_context);
+        }
+
+        result
     }
 }
 
@@ -784,6 +802,80 @@ impl<
     }
 }
 
+impl<
+    Service: service::Service,
+    RequestHeader: Debug + ZeroCopySend,
+    ResponsePayload: Debug + ZeroCopySend + ?Sized,
+    ResponseHeader: Debug + ZeroCopySend,
+> Server<Service, [u8], RequestHeader, ResponsePayload, ResponseHeader>
+{
+    /// Receives a serialized request from a [`Client`](crate::port::client::Client) and
+    /// deserializes it with the provided `Serializer` into `T`. The
+    /// [`Service`](crate::service::Service) must have been created with
+    /// [`Builder::serialized_request_payload()`](crate::service::builder::request_response::Builder::serialized_request_payload())
+    /// with the same `Serializer` and `T`. It returns the [`ActiveRequest`] to respond
+    /// together with the deserialized value. If no request could be received [`None`] is
+    /// returned. If a failure occurs [`ReceiveDeserializedError`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_cal::serialize::postcard::Postcard;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .request_response::<[u8], u64>()
+    ///     .serialized_request_payload::<Postcard, Vec<String>>()
+    ///     .open_or_create()?;
+    ///
+    /// let server = service.server_builder().create()?;
+    ///
+    /// while let Some((active_request, value)) =
+    ///     server.receive_deserialized::<Postcard, Vec<String>>()?
+    /// {
+    ///     active_request.send_copy(value.len() as u64)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)] // type alias would require 5 generic parameters which hardly reduces complexity
+    pub fn receive_deserialized<Serializer: Serialize, T: serde::de::DeserializeOwned>(
+        &self,
+    ) -> Result<
+        Option<(
+            ActiveRequest<Service, [u8], RequestHeader, ResponsePayload, ResponseHeader>,
+            T,
+        )>,
+        ReceiveDeserializedError,
+    > {
+        let msg = "Unable to receive deserialized request";
+        let expected_type = TypeDetail::new_serialized::<Serializer, T>();
+        let payload_type = self
+            .shared_state
+            .lock()
+            .request_receiver
+            .message_type_details
+            .payload;
+        if payload_type != expected_type {
+            fail!(from self, with ReceiveDeserializedError::IncompatibleSerialization,
+                "{} since the service request payload type \"{}\" does not match the serialized type \"{}\".",
+                msg, payload_type.type_name(), expected_type.type_name());
+        }
+
+        match self.receive()? {
+            None => Ok(None),
+            Some(active_request) => {
+                let value = fail!(from self, when Serializer::deserialize(active_request.payload()),
+                    with ReceiveDeserializedError::DeserializationFailed,
+                    "{} since the received payload could not be deserialized.", msg);
+                Ok(Some((active_request, value)))
+            }
+        }
+    }
+}
+
 impl<
     Service: service::Service,
     RequestPayload: Debug + ZeroCopySend + ?Sized,
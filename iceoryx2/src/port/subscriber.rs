@@ -31,10 +31,15 @@
 //! # }
 //! ```
 
+use alloc::collections::{BTreeSet, VecDeque};
+use alloc::format;
+use alloc::vec::Vec;
 use core::any::TypeId;
 use core::fmt::Debug;
 use core::marker::PhantomData;
+use core::ops::Deref;
 use core::ptr::NonNull;
+use core::time::Duration;
 
 use iceoryx2_bb_concurrency::atomic::Ordering;
 use iceoryx2_bb_concurrency::cell::UnsafeCell;
@@ -47,27 +52,35 @@ use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_memory::heap_allocator::HeapAllocator;
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::zero_copy_connection::{CHANNEL_STATE_OPEN, ChannelId};
-use iceoryx2_log::{fail, warn};
+use iceoryx2_log::{fail, fatal_panic, warn};
 
 use crate::port::update_connections::UpdateConnections;
+use crate::sample::SampleSource;
 use crate::service::builder::CustomPayloadMarker;
+use crate::service::dynamic_config::history_cache::CachedSample;
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::header::publish_subscribe::Header;
 use crate::service::port_factory::subscriber::SubscriberConfig;
+use crate::service::static_config::message_type_details::TypeDetail;
 use crate::service::static_config::publish_subscribe::StaticConfig;
 use crate::service::{NoResource, SharedServiceState};
 use crate::{raw_sample::RawSample, sample::Sample, service};
 
-use super::ReceiveError;
 use super::details::chunk::Chunk;
-use super::details::chunk_details::ChunkDetails;
 use super::details::receiver::*;
+use super::details::subscriber_wakeup::open_or_create_subscriber_wakeup_service;
+use super::listener::Listener;
 use super::update_connections::ConnectionFailure;
-use crate::identifiers::UniqueSubscriberId;
+use super::{DegradationAction, DegradationCause, DegradationInfo};
+use super::{ReceiveDeserializedError, ReceiveError};
+use crate::identifiers::{UniquePublisherId, UniqueSubscriberId};
 
 /// Describes the failures when a new [`Subscriber`] is created via the
 /// [`crate::service::port_factory::subscriber::PortFactorySubscriber`].
@@ -84,6 +97,13 @@ pub enum SubscriberCreateError {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The event that wakes up the [`Subscriber`] when a new [`Sample`] arrives could not be
+    /// created.
+    UnableToCreateWakeupEvent,
+    /// A [`WakeupSubscriber`] was requested but the [`Service`](crate::service::Service) was
+    /// created without
+    /// [`Builder::enable_subscriber_wakeup()`](crate::service::builder::publish_subscribe::Builder::enable_subscriber_wakeup()).
+    SubscriberWakeupNotEnabled,
 }
 
 impl core::fmt::Display for SubscriberCreateError {
@@ -94,10 +114,23 @@ impl core::fmt::Display for SubscriberCreateError {
 
 impl core::error::Error for SubscriberCreateError {}
 
+#[derive(Debug, Clone, Copy)]
+struct PublisherLiveliness {
+    publisher_id: Option<UniquePublisherId>,
+    lost_counter: u64,
+}
+
 #[derive(Debug)]
 pub(crate) struct SubscriberSharedState<Service: service::Service> {
     pub(crate) receiver: Receiver<Service>,
     pub(crate) publisher_list_state: UnsafeCell<ContainerState<PublisherDetails>>,
+    pub(crate) history_cache_samples: UnsafeCell<VecDeque<CachedSample>>,
+    // the history cache positions of all samples that were retrieved from the history cache,
+    // the same samples are discarded when they are delivered by a connection
+    history_cache_positions: UnsafeCell<BTreeSet<u64>>,
+    publisher_liveliness: UnsafeCell<Vec<PublisherLiveliness>>,
+    has_publisher_list_changed: UnsafeCell<bool>,
+    last_missed_deadline: UnsafeCell<Option<u64>>,
 }
 
 impl<Service: service::Service> Abandonable for SubscriberSharedState<Service> {
@@ -116,6 +149,7 @@ pub struct Subscriber<
 > {
     dynamic_subscriber_handle: Option<ContainerHandle>,
     subscriber_shared_state: Service::ArcThreadSafetyPolicy<SubscriberSharedState<Service>>,
+    subscriber_wakeup: Option<Listener<Service>>,
 
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
@@ -154,6 +188,9 @@ impl<
                 &mut this.subscriber_shared_state,
             ))
         };
+        if let Some(listener) = &mut this.subscriber_wakeup {
+            unsafe { Listener::abandon_in_place(NonNull::iox2_from_mut(listener)) };
+        }
     }
 }
 
@@ -192,11 +229,8 @@ impl<
         let origin = "Subscriber::new()";
         let subscriber_id = UniqueSubscriberId::new();
 
-        let publisher_list = &service
-            .dynamic_storage()
-            .get()
-            .publish_subscribe()
-            .publishers;
+        let dynamic_config = service.dynamic_storage().get().publish_subscribe();
+        let publisher_list = &dynamic_config.publishers;
 
         let buffer_size = match config.buffer_size {
             Some(buffer_size) => {
@@ -233,8 +267,29 @@ impl<
         let number_of_connections =
             number_of_to_be_removed_connections + number_of_active_connections;
 
+        // the lost publisher counters are acquired before the publisher list so that a
+        // publisher that dies in between is reported
+        let mut publisher_liveliness: Vec<PublisherLiveliness> = dynamic_config
+            .lost_publishers
+            .iter()
+            .map(|lost_counter| PublisherLiveliness {
+                publisher_id: None,
+                lost_counter: lost_counter.load(Ordering::Acquire),
+            })
+            .collect();
+        let publisher_list_state = unsafe { publisher_list.get_state() };
+        publisher_list_state.for_each(|index, details| {
+            publisher_liveliness[index].publisher_id = Some(details.publisher_id);
+            CallbackProgression::Continue
+        });
+
         let subscriber_shared_state = Service::ArcThreadSafetyPolicy::new(SubscriberSharedState {
-            publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
+            publisher_list_state: UnsafeCell::new(publisher_list_state),
+            history_cache_samples: UnsafeCell::new(VecDeque::new()),
+            history_cache_positions: UnsafeCell::new(BTreeSet::new()),
+            publisher_liveliness: UnsafeCell::new(publisher_liveliness),
+            has_publisher_list_changed: UnsafeCell::new(false),
+            last_missed_deadline: UnsafeCell::new(None),
             receiver: Receiver {
                 connections: PolymorphicVec::from_fn(
                     HeapAllocator::global(),
@@ -246,7 +301,10 @@ impl<
                 service_state: service.clone(),
                 message_type_details: static_config.message_type_details,
                 receiver_max_borrowed_samples: subscriber_max_borrowed_samples,
+                // every priority is a separate channel, the borrowed samples are limited per connection
+                share_max_borrowed_samples_across_channels: true,
                 enable_safe_overflow: static_config.enable_safe_overflow,
+                enable_safe_overflow_for_channel: static_config.safe_overflow_per_channel(),
                 buffer_size,
                 tagger: CyclicTagger::new(),
                 to_be_removed_connections: Some(UnsafeCell::new(
@@ -257,9 +315,10 @@ impl<
                     .expect("Heap allocator provides memory."),
                 )),
                 degradation_handler: config.degradation_handler,
-                number_of_channels: 1,
+                number_of_channels: static_config.number_of_priorities as usize,
                 connection_storage: UnsafeCell::new(SlotMap::new(number_of_connections)),
                 initial_channel_state: CHANNEL_STATE_OPEN,
+                use_receive_segment: static_config.enable_copy_on_send,
             },
         });
 
@@ -272,8 +331,23 @@ impl<
             }
         };
 
+        let subscriber_wakeup = if static_config.enable_subscriber_wakeup {
+            let wakeup_service = fail!(from origin,
+                when open_or_create_subscriber_wakeup_service(&service),
+                with SubscriberCreateError::UnableToCreateWakeupEvent,
+                "{} since the subscriber wakeup service could not be opened or created.", msg);
+
+            Some(fail!(from origin,
+                when wakeup_service.listener_builder().create(),
+                with SubscriberCreateError::UnableToCreateWakeupEvent,
+                "{} since the listener to wake up the subscriber could not be created.", msg))
+        } else {
+            None
+        };
+
         let mut new_self = Self {
             subscriber_shared_state,
+            subscriber_wakeup,
             dynamic_subscriber_handle: None,
             _payload: PhantomData,
             _user_header: PhantomData,
@@ -307,6 +381,11 @@ impl<
 
         new_self.dynamic_subscriber_handle = Some(dynamic_subscriber_handle);
 
+        // the history cache is read after the registration, a sample that is sent concurrently
+        // is then either in the cache or delivered by the publisher, but never lost, and when it
+        // is both it is received only once
+        new_self.retrieve_history_cache_samples(&new_self.subscriber_shared_state.lock());
+
         Ok(new_self)
     }
 
@@ -345,6 +424,60 @@ impl<
         result
     }
 
+    fn retrieve_history_cache_samples(
+        &self,
+        subscriber_shared_state: &SubscriberSharedState<Service>,
+    ) {
+        let receiver = &subscriber_shared_state.receiver;
+        let history_cache = &receiver
+            .service_state
+            .dynamic_storage()
+            .get()
+            .publish_subscribe()
+            .history_cache;
+        let cached_samples = unsafe { &mut *subscriber_shared_state.history_cache_samples.get() };
+        let cached_positions =
+            unsafe { &mut *subscriber_shared_state.history_cache_positions.get() };
+        let publisher_list_state = unsafe { &*subscriber_shared_state.publisher_list_state.get() };
+        let has_explicit_history_cache = receiver
+            .service_state
+            .static_config()
+            .publish_subscribe()
+            .history_cache_size
+            != 0;
+
+        history_cache.for_each(|sample| {
+            let header = unsafe { &*(sample.as_ptr() as *const Header) };
+            if has_explicit_history_cache {
+                cached_positions.insert(header.history_cache_position());
+                cached_samples.push_back(sample);
+                return;
+            }
+
+            // without an explicit history cache, connected publishers deliver their history on
+            // their own
+            let publisher_id = header.publisher_id();
+            let mut is_connected = false;
+            publisher_list_state.for_each(|_, details| {
+                is_connected = details.publisher_id == publisher_id;
+                if is_connected {
+                    CallbackProgression::Stop
+                } else {
+                    CallbackProgression::Continue
+                }
+            });
+
+            if !is_connected {
+                cached_positions.insert(header.history_cache_position());
+                cached_samples.push_back(sample);
+            }
+        });
+
+        while receiver.buffer_size < cached_samples.len() {
+            cached_samples.pop_front();
+        }
+    }
+
     /// Returns the [`UniqueSubscriberId`] of the [`Subscriber`]
     pub fn id(&self) -> UniqueSubscriberId {
         UniqueSubscriberId(UniqueSystemId::from(
@@ -364,21 +497,268 @@ impl<
     pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
         fail!(from self, when self.update_connections(),
                 "Some samples are not being received since not all connections to publishers could be established.");
-        Ok(self
-            .subscriber_shared_state
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        let receiver = &subscriber_shared_state.receiver;
+        let has_cached_samples =
+            unsafe { !(*subscriber_shared_state.history_cache_samples.get()).is_empty() };
+        Ok(has_cached_samples
+            || (0..receiver.number_of_channels).any(|n| receiver.has_samples(ChannelId::new(n))))
+    }
+
+    /// Returns true if the [`Subscriber`] is woken up whenever a new [`Sample`] arrives and can
+    /// therefore be attached to a [`WaitSet`](crate::waitset::WaitSet) via
+    /// [`Subscriber::wakeup()`] or as [`WakeupSubscriber`].
+    pub fn has_wakeup(&self) -> bool {
+        self.subscriber_wakeup.is_some()
+    }
+
+    /// Returns the [`Listener`] that is notified whenever a new [`Sample`] arrives. It can be
+    /// attached to a [`WaitSet`](crate::waitset::WaitSet) with
+    /// [`WaitSet::attach_notification()`](crate::waitset::WaitSet::attach_notification()) or
+    /// [`WaitSet::attach_deadline()`](crate::waitset::WaitSet::attach_deadline()). The pending
+    /// notifications are consumed by [`Subscriber::receive()`].
+    ///
+    /// Returns [`None`] when the [`Service`](crate::service::Service) was created without
+    /// [`Builder::enable_subscriber_wakeup()`](crate::service::builder::publish_subscribe::Builder::enable_subscriber_wakeup()).
+    pub fn wakeup(&self) -> Option<&Listener<Service>> {
+        self.subscriber_wakeup.as_ref()
+    }
+
+    /// Returns the deadline of the corresponding [`Service`](crate::service::Service). A
+    /// [`WakeupSubscriber`] can be attached with it to a
+    /// [`WaitSet`](crate::waitset::WaitSet) via
+    /// [`WaitSet::attach_deadline()`](crate::waitset::WaitSet::attach_deadline()).
+    pub fn deadline(&self) -> Option<Duration> {
+        self.subscriber_shared_state
             .lock()
             .receiver
-            .has_samples(ChannelId::new(0)))
+            .service_state
+            .static_config()
+            .publish_subscribe()
+            .deadline()
     }
 
-    fn receive_impl(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+    fn handle_degradation(
+        &self,
+        receiver: &Receiver<Service>,
+        cause: DegradationCause,
+        sender_port_id: u128,
+        error: ReceiveError,
+        msg: &str,
+    ) -> Result<(), ReceiveError> {
+        match receiver.degradation_handler.call(
+            cause,
+            &DegradationInfo {
+                service_id: receiver
+                    .service_state
+                    .static_config()
+                    .unique_service_id()
+                    .value(),
+                sender_port_id,
+                receiver_port_id: receiver.receiver_port_id(),
+            },
+        ) {
+            DegradationAction::Ignore => Ok(()),
+            DegradationAction::Warn => {
+                warn!(from self, "{}", msg);
+                Ok(())
+            }
+            DegradationAction::DegradeAndFail => {
+                fail!(from self, with error, "{}", msg);
+            }
+        }
+    }
+
+    fn handle_lost_publishers(
+        &self,
+        subscriber_shared_state: &SubscriberSharedState<Service>,
+    ) -> Result<(), ReceiveError> {
+        let has_publisher_list_changed =
+            unsafe { &mut *subscriber_shared_state.has_publisher_list_changed.get() };
+        if !*has_publisher_list_changed {
+            return Ok(());
+        }
+        *has_publisher_list_changed = false;
+
+        let receiver = &subscriber_shared_state.receiver;
+        let lost_publishers = &receiver
+            .service_state
+            .dynamic_storage()
+            .get()
+            .publish_subscribe()
+            .lost_publishers;
+        let publisher_liveliness =
+            unsafe { &mut *subscriber_shared_state.publisher_liveliness.get() };
+
+        let mut result = Ok(());
+        for (index, liveliness) in publisher_liveliness.iter_mut().enumerate() {
+            let lost_counter = lost_publishers[index].load(Ordering::Acquire);
+            if lost_counter != liveliness.lost_counter {
+                liveliness.lost_counter = lost_counter;
+                if let Some(publisher_id) = liveliness.publisher_id {
+                    let inner_result = self.handle_degradation(
+                        receiver,
+                        DegradationCause::PublisherLivelinessLost,
+                        publisher_id.value(),
+                        ReceiveError::PublisherLivelinessLost,
+                        &format!("The node of the publisher {publisher_id:?} died."),
+                    );
+                    if result.is_ok() {
+                        result = inner_result;
+                    }
+                }
+            }
+            liveliness.publisher_id = None;
+        }
+
+        unsafe { &*subscriber_shared_state.publisher_list_state.get() }.for_each(
+            |index, details| {
+                publisher_liveliness[index].publisher_id = Some(details.publisher_id);
+                CallbackProgression::Continue
+            },
+        );
+
+        result
+    }
+
+    fn handle_missed_deadline(
+        &self,
+        subscriber_shared_state: &SubscriberSharedState<Service>,
+    ) -> Result<(), ReceiveError> {
+        let receiver = &subscriber_shared_state.receiver;
+        let static_config = receiver.service_state.static_config().publish_subscribe();
+        let deadline = match static_config.deadline.as_option_ref() {
+            Some(deadline) => deadline,
+            None => return Ok(()),
+        };
+
+        let duration_since_creation = match deadline.creation_time.elapsed() {
+            Ok(duration) => duration,
+            Err(e) => {
+                warn!(from self,
+                    "Unable to acquire the elapsed system time which is required for deadline handling ({:?}).", e);
+                return Ok(());
+            }
+        };
+
+        let previous_duration_since_creation = receiver
+            .service_state
+            .dynamic_storage()
+            .get()
+            .publish_subscribe()
+            .elapsed_time_since_last_sample
+            .load(Ordering::Relaxed);
+
+        // every missed deadline is reported only once
+        let last_missed_deadline =
+            unsafe { &mut *subscriber_shared_state.last_missed_deadline.get() };
+        if *last_missed_deadline == Some(previous_duration_since_creation) {
+            return Ok(());
+        }
+
+        let duration_since_last_sample = duration_since_creation
+            .saturating_sub(Duration::from_nanos(previous_duration_since_creation));
+        let deadline: Duration = deadline.value.into();
+        if duration_since_last_sample <= deadline {
+            return Ok(());
+        }
+
+        *last_missed_deadline = Some(previous_duration_since_creation);
+        self.handle_degradation(
+            receiver,
+            DegradationCause::MissedDeadline,
+            0,
+            ReceiveError::MissedDeadline,
+            &format!(
+                "The deadline was missed. The service requires a sample after {deadline:?} but {duration_since_last_sample:?} passed without a sample."
+            ),
+        )
+    }
+
+    fn receive_impl(&self) -> Result<Option<(SampleSource, Chunk)>, ReceiveError> {
         fail!(from self, when self.update_connections(),
                 "Some samples are not being received since not all connections to publishers could be established.");
 
-        self.subscriber_shared_state
-            .lock()
-            .receiver
-            .receive(ChannelId::new(0))
+        {
+            let subscriber_shared_state = self.subscriber_shared_state.lock();
+            let lost_publishers_result = self.handle_lost_publishers(&subscriber_shared_state);
+            self.handle_missed_deadline(&subscriber_shared_state)?;
+            lost_publishers_result?;
+        }
+
+        let result = self.receive_from_channels();
+        let result = match (&result, &self.subscriber_wakeup) {
+            (Ok(None), Some(listener)) => {
+                // the pending wakeups are consumed before the buffer is checked again, otherwise
+                // a sample that arrives in between would not wake up the subscriber anymore
+                if let Err(e) = listener.try_wait_all(|_| {}) {
+                    warn!(from self, "Unable to consume the pending wakeups ({:?}).", e);
+                }
+                self.receive_from_channels()
+            }
+            _ => result,
+        };
+
+        if let Ok(Some((_, chunk))) = &result {
+            let header = unsafe { &*(chunk.header as *const Header) };
+            if let Some(trace_context) = header.trace_context() {
+                iceoryx2_log::receive_trace_context(&trace_context);
+            }
+        }
+
+        result
+    }
+
+    fn receive_from_channels(&self) -> Result<Option<(SampleSource, Chunk)>, ReceiveError> {
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        let receiver = &subscriber_shared_state.receiver;
+
+        // samples from the history cache are older than everything the connections provide
+        if let Some(sample) =
+            unsafe { (*subscriber_shared_state.history_cache_samples.get()).pop_front() }
+        {
+            let chunk = Chunk::new(&receiver.message_type_details, sample.as_ptr() as usize);
+            return Ok(Some((
+                SampleSource::HistoryCache { _memory: sample },
+                chunk,
+            )));
+        }
+
+        let cached_positions =
+            unsafe { &mut *subscriber_shared_state.history_cache_positions.get() };
+        let mut result = Ok(None);
+        // every priority is mapped to a channel, the highest priority is received first
+        let mut channels = (0..receiver.number_of_channels).rev().peekable();
+        while let Some(&n) = channels.peek() {
+            let channel_id = ChannelId::new(n);
+            match receiver.receive(channel_id) {
+                Ok(Some((details, chunk))) => {
+                    // the sample was already received from the history cache
+                    let position =
+                        unsafe { (*(chunk.header as *const Header)).history_cache_position() };
+                    if !cached_positions.is_empty() && cached_positions.remove(&position) {
+                        receiver.release_offset(&details, channel_id);
+                        continue;
+                    }
+
+                    return Ok(Some((
+                        SampleSource::Connection {
+                            details,
+                            channel_id,
+                        },
+                        chunk,
+                    )));
+                }
+                Ok(None) => (),
+                Err(ReceiveError::ExceedsMaxBorrows) => {
+                    result = Err(ReceiveError::ExceedsMaxBorrows)
+                }
+                Err(e) => return Err(e),
+            }
+            channels.next();
+        }
+
+        result
     }
 }
 
@@ -400,6 +780,7 @@ impl<
                 .publishers
                 .update_state(&mut *subscriber_shared_state.publisher_list_state.get())
         } {
+            unsafe { *subscriber_shared_state.has_publisher_list_changed.get() = true };
             fail!(from self, when self.force_update_connections(&subscriber_shared_state),
                 "Connections were updated only partially since at least one connection to a publisher failed.");
         }
@@ -414,9 +795,9 @@ impl<Service: service::Service, Payload: Debug + ZeroCopySend, UserHeader: Debug
     /// Receives a [`crate::sample::Sample`] from [`crate::port::publisher::Publisher`]. If no sample could be
     /// received [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
     pub fn receive(&self) -> Result<Option<Sample<Service, Payload, UserHeader>>, ReceiveError> {
-        Ok(self.receive_impl()?.map(|(details, chunk)| Sample {
+        Ok(self.receive_impl()?.map(|(source, chunk)| Sample {
             subscriber_shared_state: self.subscriber_shared_state.clone(),
-            details,
+            source,
             ptr: unsafe {
                 RawSample::new_unchecked(
                     chunk.header.cast(),
@@ -436,13 +817,13 @@ impl<Service: service::Service, Payload: Debug + ZeroCopySend, UserHeader: Debug
     pub fn receive(&self) -> Result<Option<Sample<Service, [Payload], UserHeader>>, ReceiveError> {
         debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
 
-        Ok(self.receive_impl()?.map(|(details, chunk)| {
+        Ok(self.receive_impl()?.map(|(source, chunk)| {
             let header_ptr = chunk.header as *const Header;
             let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
 
             Sample {
                 subscriber_shared_state: self.subscriber_shared_state.clone(),
-                details,
+                source,
                 ptr: unsafe {
                     RawSample::<Header, UserHeader, [Payload]>::new_slice_unchecked(
                         header_ptr,
@@ -458,6 +839,66 @@ impl<Service: service::Service, Payload: Debug + ZeroCopySend, UserHeader: Debug
     }
 }
 
+impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
+    Subscriber<Service, [u8], UserHeader>
+{
+    /// Receives a serialized payload from a [`crate::port::publisher::Publisher`] and deserializes
+    /// it with the provided `Serializer` into `T`. The [`Service`](crate::service::Service) must
+    /// have been created with
+    /// [`Builder::serialized_payload()`](crate::service::builder::publish_subscribe::Builder::serialized_payload())
+    /// with the same `Serializer` and `T`. If no sample could be received [`None`] is returned.
+    /// If a failure occurs [`ReceiveDeserializedError`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_cal::serialize::postcard::Postcard;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<[u8]>()
+    ///     .serialized_payload::<Postcard, Vec<String>>()
+    ///     .open_or_create()?;
+    ///
+    /// let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// while let Some(value) = subscriber.receive_deserialized::<Postcard, Vec<String>>()? {
+    ///     println!("received: {:?}", value);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn receive_deserialized<Serializer: Serialize, T: serde::de::DeserializeOwned>(
+        &self,
+    ) -> Result<Option<T>, ReceiveDeserializedError> {
+        let msg = "Unable to receive deserialized payload";
+        let expected_type = TypeDetail::new_serialized::<Serializer, T>();
+        let payload_type = self
+            .subscriber_shared_state
+            .lock()
+            .receiver
+            .message_type_details
+            .payload;
+        if payload_type != expected_type {
+            fail!(from self, with ReceiveDeserializedError::IncompatibleSerialization,
+                "{} since the service payload type \"{}\" does not match the serialized type \"{}\".",
+                msg, payload_type.type_name(), expected_type.type_name());
+        }
+
+        match self.receive()? {
+            None => Ok(None),
+            Some(sample) => {
+                let value = fail!(from self, when Serializer::deserialize(sample.payload()),
+                    with ReceiveDeserializedError::DeserializationFailed,
+                    "{} since the received payload could not be deserialized.", msg);
+                Ok(Some(value))
+            }
+        }
+    }
+}
+
 impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
     Subscriber<Service, [CustomPayloadMarker], UserHeader>
 {
@@ -474,7 +915,7 @@ impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
     pub unsafe fn receive_custom_payload(
         &self,
     ) -> Result<Option<Sample<Service, [CustomPayloadMarker], UserHeader>>, ReceiveError> {
-        Ok(self.receive_impl()?.map(|(details, chunk)| {
+        Ok(self.receive_impl()?.map(|(source, chunk)| {
             let header_ptr = chunk.header as *const Header;
             let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
             let number_of_bytes = number_of_elements as usize
@@ -482,7 +923,7 @@ impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
 
             Sample {
                 subscriber_shared_state: self.subscriber_shared_state.clone(),
-                details,
+                source,
                 ptr: unsafe {
                     RawSample::<Header, UserHeader, [CustomPayloadMarker]>::new_slice_unchecked(
                         header_ptr,
@@ -494,3 +935,118 @@ impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
         }))
     }
 }
+
+/// A [`Subscriber`] of a [`Service`](crate::service::Service) that was created with
+/// [`Builder::enable_subscriber_wakeup()`](crate::service::builder::publish_subscribe::Builder::enable_subscriber_wakeup()).
+/// It is woken up whenever a new [`Sample`] arrives and can therefore be attached directly to a
+/// [`WaitSet`](crate::waitset::WaitSet). The pending notifications are consumed by
+/// [`Subscriber::receive()`].
+///
+/// It is created with
+/// [`PortFactorySubscriber::create_with_wakeup()`](crate::service::port_factory::subscriber::PortFactorySubscriber::create_with_wakeup())
+/// and dereferences to the [`Subscriber`].
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2::prelude::*;
+///
+/// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+/// let node = NodeBuilder::new().create::<ipc::Service>()?;
+/// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+///     .publish_subscribe::<u64>()
+///     .enable_subscriber_wakeup(true)
+///     .open_or_create()?;
+///
+/// let subscriber = service.subscriber_builder().create_with_wakeup()?;
+///
+/// let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+/// let _guard = waitset.attach_notification(&subscriber)?;
+///
+/// waitset.wait_and_process_once_with_timeout(
+///     |_| {
+///         while let Ok(Some(sample)) = subscriber.receive() {
+///             println!("received: {:?}", *sample);
+///         }
+///         CallbackProgression::Continue
+///     },
+///     core::time::Duration::from_millis(10),
+/// )?;
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct WakeupSubscriber<
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized + 'static,
+    UserHeader: Debug + ZeroCopySend,
+> {
+    subscriber: Subscriber<Service, Payload, UserHeader>,
+}
+
+impl<
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized,
+    UserHeader: Debug + ZeroCopySend,
+> WakeupSubscriber<Service, Payload, UserHeader>
+{
+    pub(crate) fn new(
+        subscriber: Subscriber<Service, Payload, UserHeader>,
+    ) -> Result<Self, SubscriberCreateError> {
+        if !subscriber.has_wakeup() {
+            fail!(from subscriber, with SubscriberCreateError::SubscriberWakeupNotEnabled,
+                "Unable to create subscriber with wakeup since the service was created without subscriber wakeup.");
+        }
+
+        Ok(Self { subscriber })
+    }
+
+    /// Returns the underlying [`Subscriber`].
+    pub fn into_inner(self) -> Subscriber<Service, Payload, UserHeader> {
+        self.subscriber
+    }
+}
+
+impl<
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized,
+    UserHeader: Debug + ZeroCopySend,
+> Deref for WakeupSubscriber<Service, Payload, UserHeader>
+{
+    type Target = Subscriber<Service, Payload, UserHeader>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.subscriber
+    }
+}
+
+impl<
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized,
+    UserHeader: Debug + ZeroCopySend,
+> FileDescriptorBased for WakeupSubscriber<Service, Payload, UserHeader>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    /// Returns the [`FileDescriptor`] that signals new [`Sample`]s.
+    fn file_descriptor(&self) -> &FileDescriptor {
+        match &self.subscriber.subscriber_wakeup {
+            Some(listener) => listener.file_descriptor(),
+            None => {
+                fatal_panic!(from self,
+                    "This should never happen! A subscriber with wakeup has always a wakeup listener.");
+            }
+        }
+    }
+}
+
+impl<
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized,
+    UserHeader: Debug + ZeroCopySend,
+> SynchronousMultiplexing for WakeupSubscriber<Service, Payload, UserHeader>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing,
+{
+}
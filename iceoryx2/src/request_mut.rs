@@ -44,6 +44,7 @@ use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::shm_allocator::PointerOffset;
 use iceoryx2_cal::zero_copy_connection::ChannelId;
+use iceoryx2_log::TraceContext;
 use iceoryx2_log::fatal_panic;
 
 use crate::{
@@ -195,6 +196,12 @@ impl<
         self.ptr.as_user_header_mut()
     }
 
+    /// Overrides the [`TraceContext`] of the request. By default, it is provided by the logger
+    /// that is used, see [`iceoryx2_log::current_trace_context()`].
+    pub fn set_trace_context(&mut self, value: TraceContext) {
+        self.ptr.as_header_mut().trace_context = value;
+    }
+
     /// Returns a reference to the user defined request payload.
     pub fn payload(&self) -> &RequestPayload {
         self.ptr.as_payload_ref()
@@ -38,6 +38,7 @@
 //! ```
 
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_log::TraceContext;
 
 use crate::{port::client::ClientSharedState, request_mut::RequestMut, service};
 use core::{fmt::Debug, mem::MaybeUninit};
@@ -107,6 +108,12 @@ impl<
         self.request.user_header_mut()
     }
 
+    /// Overrides the [`TraceContext`] of the request. By default, it is provided by the logger
+    /// that is used, see [`iceoryx2_log::current_trace_context()`].
+    pub fn set_trace_context(&mut self, value: TraceContext) {
+        self.request.set_trace_context(value)
+    }
+
     /// Returns a reference to the user defined request payload.
     pub fn payload(&self) -> &RequestPayload {
         self.request.payload()
@@ -52,7 +52,7 @@ use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_cal::{
     arc_sync_policy::ArcSyncPolicy, shm_allocator::PointerOffset, zero_copy_connection::ChannelId,
 };
-use iceoryx2_log::fail;
+use iceoryx2_log::{TraceContext, fail};
 
 use crate::{
     port::{
@@ -251,6 +251,13 @@ impl<
         self.ptr.as_user_header_mut()
     }
 
+    /// Overrides the [`TraceContext`] of the response. By default, it is provided by the logger
+    /// that is used, see [`iceoryx2_log::current_trace_context()`], or taken from the
+    /// corresponding request.
+    pub fn set_trace_context(&mut self, value: TraceContext) {
+        self.ptr.as_header_mut().trace_context = value;
+    }
+
     /// Returns a reference to the payload of the response.
     ///
     /// ```
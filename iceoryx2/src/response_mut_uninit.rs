@@ -42,6 +42,7 @@
 //! ```
 
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_log::TraceContext;
 
 use crate::{port::server::SharedServerState, response_mut::ResponseMut, service};
 use core::{fmt::Debug, mem::MaybeUninit};
@@ -176,6 +177,13 @@ impl<
         self.response.user_header_mut()
     }
 
+    /// Overrides the [`TraceContext`] of the response. By default, it is provided by the logger
+    /// that is used, see [`iceoryx2_log::current_trace_context()`], or taken from the
+    /// corresponding request.
+    pub fn set_trace_context(&mut self, value: TraceContext) {
+        self.response.set_trace_context(value)
+    }
+
     /// Returns a reference to the payload of the response.
     ///
     /// ```
@@ -41,8 +41,24 @@ use crate::identifiers::UniquePublisherId;
 use crate::port::details::chunk_details::ChunkDetails;
 use crate::port::subscriber::SubscriberSharedState;
 use crate::raw_sample::RawSample;
+use crate::service::dynamic_config::history_cache::CachedSample;
 use crate::service::header::publish_subscribe::Header;
 
+/// Defines where the memory of a [`Sample`] originates from.
+#[derive(Debug)]
+pub(crate) enum SampleSource {
+    /// The [`Sample`] was received from a connected
+    /// [`Publisher`](crate::port::publisher::Publisher) and is returned to it when it is
+    /// dropped.
+    Connection {
+        details: ChunkDetails,
+        channel_id: ChannelId,
+    },
+    /// The [`Sample`] is an owned copy out of the history cache of the
+    /// [`Service`](crate::service::Service).
+    HistoryCache { _memory: CachedSample },
+}
+
 /// It stores the payload and is acquired by the [`Subscriber`](crate::port::subscriber::Subscriber) whenever
 /// it receives new data from a [`Publisher`](crate::port::publisher::Publisher) via
 /// [`Subscriber::receive()`](crate::port::subscriber::Subscriber::receive()).
@@ -54,7 +70,7 @@ pub struct Sample<
     pub(crate) ptr: RawSample<Header, UserHeader, Payload>,
     pub(crate) subscriber_shared_state:
         Service::ArcThreadSafetyPolicy<SubscriberSharedState<Service>>,
-    pub(crate) details: ChunkDetails,
+    pub(crate) source: SampleSource,
 }
 
 unsafe impl<
@@ -76,12 +92,12 @@ impl<
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "Sample<{}, {}, {}> {{ ptr: {:?}, details: {:?} }}",
+            "Sample<{}, {}, {}> {{ ptr: {:?}, source: {:?} }}",
             core::any::type_name::<Payload>(),
             core::any::type_name::<UserHeader>(),
             core::any::type_name::<Service>(),
             self.ptr,
-            self.details,
+            self.source,
         )
     }
 }
@@ -105,10 +121,16 @@ impl<
 > Drop for Sample<Service, Payload, UserHeader>
 {
     fn drop(&mut self) {
-        self.subscriber_shared_state
-            .lock()
-            .receiver
-            .release_offset(&self.details, ChannelId::new(0));
+        if let SampleSource::Connection {
+            details,
+            channel_id,
+        } = &self.source
+        {
+            self.subscriber_shared_state
+                .lock()
+                .receiver
+                .release_offset(details, *channel_id);
+        }
     }
 }
 
@@ -135,6 +157,11 @@ impl<
 
     /// Returns the [`UniquePublisherId`] of the [`Publisher`](crate::port::publisher::Publisher)
     pub fn origin(&self) -> UniquePublisherId {
-        UniquePublisherId(UniqueSystemId::from(self.details.origin))
+        match &self.source {
+            SampleSource::Connection { details, .. } => {
+                UniquePublisherId(UniqueSystemId::from(details.origin))
+            }
+            SampleSource::HistoryCache { .. } => self.header().publisher_id(),
+        }
     }
 }
@@ -70,6 +70,7 @@ use crate::{
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::shared_memory::*;
+use iceoryx2_log::TraceContext;
 
 use core::fmt::{Debug, Formatter};
 use core::ops::{Deref, DerefMut};
@@ -243,6 +244,73 @@ impl<
         self.ptr.as_user_header_mut()
     }
 
+    /// Sets the priority of the sample. It overrides the default priority of the
+    /// [`crate::port::publisher::Publisher`]. If the priority exceeds the number of priorities
+    /// of the service, it is reduced to the highest supported priority.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .number_of_priorities(4)
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// sample.set_priority(3);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_priority(&mut self, value: u8) {
+        let priority = self
+            .publisher_shared_state
+            .lock()
+            .sender
+            .service_state
+            .static_config()
+            .publish_subscribe()
+            .clamp_priority(value);
+        self.ptr.as_header_mut().set_priority(priority);
+    }
+
+    /// Overrides the [`TraceContext`] of the sample. By default, it is provided by the logger
+    /// that is used, see [`iceoryx2_log::current_trace_context()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::header::TraceContext;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// sample.set_trace_context(TraceContext {
+    ///     trace_id: [1; 16],
+    ///     span_id: [2; 8],
+    ///     trace_flags: 1,
+    /// });
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_trace_context(&mut self, value: TraceContext) {
+        self.ptr.as_header_mut().set_trace_context(value);
+    }
+
     /// Returns a reference to the payload of the sample.
     ///
     /// # Notes
@@ -331,9 +399,13 @@ impl<
     /// # Ok(())
     /// # }
     /// ```
-    pub fn send(self) -> Result<usize, SendError> {
-        self.publisher_shared_state
-            .lock()
-            .send_sample(self.offset_to_chunk, self.sample_size)
+    pub fn send(mut self) -> Result<usize, SendError> {
+        let chunk = (self.ptr.as_header_mut() as *mut Header).cast();
+        self.publisher_shared_state.lock().send_sample(
+            self.offset_to_chunk,
+            chunk,
+            self.sample_size,
+            self.header().priority(),
+        )
     }
 }
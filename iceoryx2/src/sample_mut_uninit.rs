@@ -94,6 +94,7 @@ use core::{fmt::Debug, mem::MaybeUninit};
 
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_cal::shm_allocator::PointerOffset;
+use iceoryx2_log::TraceContext;
 
 use crate::{
     port::publisher::PublisherSharedState, raw_sample::RawSampleMut, sample_mut::SampleMut,
@@ -209,6 +210,40 @@ impl<
         self.sample.user_header_mut()
     }
 
+    /// Sets the priority of the sample. It overrides the default priority of the
+    /// [`crate::port::publisher::Publisher`]. If the priority exceeds the number of priorities
+    /// of the service, it is reduced to the highest supported priority.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .number_of_priorities(4)
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan_uninit()?;
+    /// sample.set_priority(3);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_priority(&mut self, value: u8) {
+        self.sample.set_priority(value)
+    }
+
+    /// Overrides the [`TraceContext`] of the sample. By default, it is provided by the logger
+    /// that is used, see [`iceoryx2_log::current_trace_context()`].
+    pub fn set_trace_context(&mut self, value: TraceContext) {
+        self.sample.set_trace_context(value)
+    }
+
     /// Returns a reference to the payload of the sample.
     ///
     /// # Notes
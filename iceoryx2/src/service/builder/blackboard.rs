@@ -44,6 +44,7 @@ use crate::service::builder::CustomKeyMarker;
 use crate::service::config_scheme::{blackboard_data_config, blackboard_mgmt_config};
 use crate::service::dynamic_config::MessagingPatternSettings;
 use crate::service::dynamic_config::blackboard::DynamicConfigSettings;
+use crate::service::lifetime::ServiceLifetime;
 use crate::service::naming_scheme::blackboard_name;
 use crate::service::port_factory::blackboard;
 use crate::service::static_config::message_type_details::TypeDetail;
@@ -524,6 +525,13 @@ impl<
         self
     }
 
+    /// Defines the [`ServiceLifetime`] of the [`Service`], meaning whether it outlives its
+    /// last [`Node`](crate::node::Node).
+    pub fn lifetime(mut self, value: ServiceLifetime) -> Self {
+        self.builder.base.service_config.lifetime = value;
+        self
+    }
+
     /// Adds key-value pairs to the blackboard.
     pub fn add<ValueType: ZeroCopySend + Copy + 'static>(
         mut self,
@@ -25,6 +25,7 @@ use iceoryx2_log::{fail, fatal_panic};
 
 use crate::service::builder::OpenDynamicStorageFailure;
 use crate::service::dynamic_config::MessagingPatternSettings;
+use crate::service::lifetime::ServiceLifetime;
 use crate::service::port_factory::event;
 use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::*;
@@ -255,6 +256,14 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self
     }
 
+    /// If the [`Service`] is created it defines its [`ServiceLifetime`], meaning whether it
+    /// outlives its last [`Node`](crate::node::Node). It is ignored when an existing
+    /// [`Service`] is opened.
+    pub fn lifetime(mut self, value: ServiceLifetime) -> Self {
+        self.base.service_config.lifetime = value;
+        self
+    }
+
     /// If the [`Service`] is created it set the greatest supported [`UniqueNodeId`] value
     /// If an existing [`Service`] is opened it defines the value size the [`UniqueNodeId`]
     /// must at least support.
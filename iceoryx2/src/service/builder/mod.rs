@@ -350,7 +350,7 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
             .supplementary_size(additional_size + required_memory_size)
             .has_ownership(false)
             .initializer(Self::config_init_call)
-            .create(DynamicConfig::new_uninit(super::dynamic_config::MessagingPattern::new(messaging_pattern_settings), max_number_of_nodes) ) {
+            .create(DynamicConfig::new_uninit(super::dynamic_config::MessagingPattern::new(messaging_pattern_settings), max_number_of_nodes, self.service_config.lifetime()) ) {
                 Ok(dynamic_storage) => {
                     let node_id = self.shared_node.id();
                     let node_handle = fatal_panic!(from self,
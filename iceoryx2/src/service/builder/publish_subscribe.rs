@@ -15,11 +15,14 @@
 //! See [`crate::service`]
 //!
 use core::marker::PhantomData;
+use core::time::Duration;
 
 use alloc::format;
 
+use iceoryx2_bb_container::relocatable_option::RelocatableOption;
 use iceoryx2_bb_elementary::alignment::Alignment;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_posix::clock::Time;
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::static_storage::StaticStorageLocked;
@@ -27,8 +30,11 @@ use iceoryx2_log::{fail, fatal_panic, warn};
 
 use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
 use crate::service::header::publish_subscribe::Header;
+use crate::service::lifetime::ServiceLifetime;
 use crate::service::port_factory::publish_subscribe;
+use crate::service::static_config::event::Deadline;
 use crate::service::static_config::messaging_pattern::MessagingPattern;
+use crate::service::static_config::publish_subscribe::MAX_NUMBER_OF_PRIORITIES;
 use crate::service::*;
 use crate::service::{self, dynamic_config::MessagingPatternSettings};
 
@@ -37,6 +43,7 @@ use super::{CustomHeaderMarker, CustomPayloadMarker, OpenDynamicStorageFailure,
 use self::{
     attribute::{AttributeSpecifier, AttributeVerifier},
     message_type_details::{MessageTypeDetails, TypeDetail, TypeVariant},
+    type_schema::{MessageTypeSchema, SchemaCompatibility, TypeSchema, TypeSchemaMismatch},
 };
 use builder::RETRY_LIMIT;
 
@@ -49,6 +56,15 @@ pub enum PublishSubscribeOpenError {
     InternalFailure,
     /// The [`Service`] has the wrong payload type.
     IncompatibleTypes,
+    /// The [`Service`] has a user header or payload type with a different
+    /// [`TypeSchema::version()`] or, with [`SchemaCompatibility::AppendOnly`], an older one.
+    IncompatibleTypeVersion,
+    /// The [`Service`] has a user header or payload type with the same name and size but a
+    /// different field layout, see [`TypeSchema::fingerprint()`].
+    IncompatibleTypeSchema,
+    /// With [`SchemaCompatibility::AppendOnly`], the user header or payload type of the
+    /// [`Service`] does not extend the requested type by only appending fields.
+    TypeSchemaIsNotAppendOnly,
     /// The [`Service`] has the wrong messaging pattern.
     IncompatibleMessagingPattern,
     /// The [`AttributeVerifier`] required attributes that the [`Service`] does not satisfy.
@@ -57,6 +73,8 @@ pub enum PublishSubscribeOpenError {
     DoesNotSupportRequestedMinBufferSize,
     /// The [`Service`] has a lower minimum history size than requested.
     DoesNotSupportRequestedMinHistorySize,
+    /// The [`Service`] has a smaller history cache than requested.
+    DoesNotSupportRequestedMinHistoryCacheSize,
     /// The [`Service`] has a lower minimum subscriber borrow size than requested.
     DoesNotSupportRequestedMinSubscriberBorrowedSamples,
     /// The [`Service`] supports less [`Publisher`](crate::port::publisher::Publisher)s than requested.
@@ -67,6 +85,14 @@ pub enum PublishSubscribeOpenError {
     DoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     IncompatibleOverflowBehavior,
+    /// The [`Service`] supports less priorities than requested.
+    DoesNotSupportRequestedNumberOfPriorities,
+    /// The [`Service`] required subscriber wakeup setting is not compatible.
+    IncompatibleSubscriberWakeup,
+    /// The [`Service`] required copy on send setting is not compatible.
+    IncompatibleCopyOnSend,
+    /// The [`Service`]s deadline settings are not equal the the user given requirements.
+    IncompatibleDeadline,
     /// The process has not enough permissions to open the [`Service`]
     InsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
@@ -96,6 +122,15 @@ impl From<ServiceAvailabilityState> for PublishSubscribeOpenError {
             ServiceAvailabilityState::IncompatibleTypes => {
                 PublishSubscribeOpenError::IncompatibleTypes
             }
+            ServiceAvailabilityState::IncompatibleTypeSchema(TypeSchemaMismatch::Version) => {
+                PublishSubscribeOpenError::IncompatibleTypeVersion
+            }
+            ServiceAvailabilityState::IncompatibleTypeSchema(TypeSchemaMismatch::Schema) => {
+                PublishSubscribeOpenError::IncompatibleTypeSchema
+            }
+            ServiceAvailabilityState::IncompatibleTypeSchema(TypeSchemaMismatch::NotAppendOnly) => {
+                PublishSubscribeOpenError::TypeSchemaIsNotAppendOnly
+            }
             ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
                 PublishSubscribeOpenError::IncompatibleMessagingPattern
             }
@@ -146,6 +181,7 @@ impl From<ServiceAvailabilityState> for PublishSubscribeCreateError {
     fn from(value: ServiceAvailabilityState) -> Self {
         match value {
             ServiceAvailabilityState::IncompatibleTypes
+            | ServiceAvailabilityState::IncompatibleTypeSchema(_)
             | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
                 PublishSubscribeCreateError::AlreadyExists
             }
@@ -166,6 +202,7 @@ impl From<ServiceAvailabilityState> for PublishSubscribeCreateError {
 enum ServiceAvailabilityState {
     ServiceState(ServiceState),
     IncompatibleTypes,
+    IncompatibleTypeSchema(TypeSchemaMismatch),
 }
 
 /// Errors that can occur when a [`MessagingPattern::PublishSubscribe`] [`Service`] shall be
@@ -222,12 +259,19 @@ pub struct Builder<
     override_alignment: Option<usize>,
     override_payload_type: Option<TypeDetail>,
     override_user_header_type: Option<TypeDetail>,
+    schema_compatibility: SchemaCompatibility,
     verify_number_of_subscribers: bool,
     verify_number_of_publishers: bool,
     verify_subscriber_max_buffer_size: bool,
     verify_subscriber_max_borrowed_samples: bool,
     verify_publisher_history_size: bool,
+    verify_history_cache_size: bool,
     verify_enable_safe_overflow: bool,
+    verify_enable_safe_overflow_for_priority: [bool; MAX_NUMBER_OF_PRIORITIES],
+    verify_number_of_priorities: bool,
+    verify_enable_subscriber_wakeup: bool,
+    verify_enable_copy_on_send: bool,
+    verify_deadline: bool,
     verify_max_nodes: bool,
     _data: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
@@ -245,12 +289,19 @@ impl<
             override_alignment: self.override_alignment,
             override_payload_type: self.override_payload_type,
             override_user_header_type: self.override_user_header_type,
+            schema_compatibility: self.schema_compatibility,
             verify_number_of_subscribers: self.verify_number_of_subscribers,
             verify_number_of_publishers: self.verify_number_of_publishers,
             verify_subscriber_max_buffer_size: self.verify_subscriber_max_buffer_size,
             verify_subscriber_max_borrowed_samples: self.verify_subscriber_max_borrowed_samples,
             verify_publisher_history_size: self.verify_publisher_history_size,
+            verify_history_cache_size: self.verify_history_cache_size,
             verify_enable_safe_overflow: self.verify_enable_safe_overflow,
+            verify_enable_safe_overflow_for_priority: self.verify_enable_safe_overflow_for_priority,
+            verify_number_of_priorities: self.verify_number_of_priorities,
+            verify_enable_subscriber_wakeup: self.verify_enable_subscriber_wakeup,
+            verify_enable_copy_on_send: self.verify_enable_copy_on_send,
+            verify_deadline: self.verify_deadline,
             verify_max_nodes: self.verify_max_nodes,
             _data: PhantomData,
             _user_header: PhantomData,
@@ -271,12 +322,19 @@ impl<
             verify_number_of_subscribers: false,
             verify_subscriber_max_buffer_size: false,
             verify_publisher_history_size: false,
+            verify_history_cache_size: false,
             verify_subscriber_max_borrowed_samples: false,
             verify_enable_safe_overflow: false,
+            verify_enable_safe_overflow_for_priority: [false; MAX_NUMBER_OF_PRIORITIES],
+            verify_number_of_priorities: false,
+            verify_enable_subscriber_wakeup: false,
+            verify_enable_copy_on_send: false,
+            verify_deadline: false,
             verify_max_nodes: false,
             override_alignment: None,
             override_payload_type: None,
             override_user_header_type: None,
+            schema_compatibility: SchemaCompatibility::default(),
             _data: PhantomData,
             _user_header: PhantomData,
         };
@@ -313,16 +371,36 @@ impl<
     ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
         match self.base.is_service_available(error_msg) {
             Ok(Some((config, storage))) => {
+                let requested_schema = &self.config_details().message_type_schema;
+                let existing_schema = &config.publish_subscribe().message_type_schema;
+
                 if !self
                     .config_details()
                     .message_type_details
-                    .is_compatible_to(&config.publish_subscribe().message_type_details)
+                    .is_compatible_to_extension(
+                        &config.publish_subscribe().message_type_details,
+                        requested_schema.user_header.is_extensible_to(
+                            &existing_schema.user_header,
+                            self.schema_compatibility,
+                        ),
+                        requested_schema
+                            .payload
+                            .is_extensible_to(&existing_schema.payload, self.schema_compatibility),
+                    )
                 {
                     fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
                         "{} since the service offers the type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
                         error_msg, &config.publish_subscribe().message_type_details , self.config_details().message_type_details);
                 }
 
+                if let Err(e) = requested_schema
+                    .verify_compatibility(existing_schema, self.schema_compatibility)
+                {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleTypeSchema(e),
+                        "{} since the service offers the type schema \"{:?}\" which is not compatible ({:?}) to the requested type schema \"{:?}\" with {:?}.",
+                        error_msg, existing_schema, e, requested_schema, self.schema_compatibility);
+                }
+
                 Ok(Some((config, storage)))
             }
             Ok(None) => Ok(None),
@@ -344,16 +422,110 @@ impl<
         self
     }
 
+    /// If an existing [`Service`] is opened, it defines how the [`TypeSchema`]s of the user
+    /// header and payload are verified against the ones of the [`Service`]. By default,
+    /// [`SchemaCompatibility::Exact`] is used.
+    pub fn schema_compatibility(mut self, value: SchemaCompatibility) -> Self {
+        self.schema_compatibility = value;
+        self
+    }
+
     /// If the [`Service`] is created, defines the overflow behavior of the service. If an existing
     /// [`Service`] is opened it requires the service to have the defined overflow behavior.
+    /// It applies to every priority whose overflow behavior was not defined with
+    /// [`Builder::enable_safe_overflow_for_priority()`].
     pub fn enable_safe_overflow(mut self, value: bool) -> Self {
         self.config_details_mut().enable_safe_overflow = value;
         self.verify_enable_safe_overflow = true;
+        for priority in 0..MAX_NUMBER_OF_PRIORITIES {
+            if !self.verify_enable_safe_overflow_for_priority[priority] {
+                self.config_details_mut().enable_safe_overflow_for_priority[priority] = value;
+            }
+        }
+        self
+    }
+
+    /// If the [`Service`] is created, defines the overflow behavior of the
+    /// [`crate::port::subscriber::Subscriber`] buffer for the provided priority. If an existing
+    /// [`Service`] is opened it requires the service to have the defined overflow behavior for
+    /// this priority. Priorities greater or equal [`MAX_NUMBER_OF_PRIORITIES`] are ignored.
+    pub fn enable_safe_overflow_for_priority(mut self, priority: u8, value: bool) -> Self {
+        let priority = priority as usize;
+        if priority < MAX_NUMBER_OF_PRIORITIES {
+            self.config_details_mut().enable_safe_overflow_for_priority[priority] = value;
+            self.verify_enable_safe_overflow_for_priority[priority] = true;
+        } else {
+            warn!(from self,
+                "The safe overflow setting of priority {} is ignored since only {} priorities are supported.",
+                priority, MAX_NUMBER_OF_PRIORITIES);
+        }
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many priorities are supported. Every
+    /// [`crate::port::subscriber::Subscriber`] has a separate buffer of
+    /// [`Builder::subscriber_max_buffer_size()`] for every priority, so that
+    /// [`crate::sample::Sample`]s with a low priority never displace the ones with a higher
+    /// priority, and [`crate::port::subscriber::Subscriber::receive()`] returns the
+    /// [`crate::sample::Sample`]s with the highest priority first. The
+    /// [`Builder::subscriber_max_borrowed_samples()`] limit is shared by all priorities.
+    /// If an existing [`Service`] is opened it defines the minimum required.
+    pub fn number_of_priorities(mut self, value: u8) -> Self {
+        self.config_details_mut().number_of_priorities = value;
+        self.verify_number_of_priorities = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines if the [`crate::port::subscriber::Subscriber`]s
+    /// are woken up whenever a [`crate::port::publisher::Publisher`] sends a
+    /// [`crate::sample::Sample`]. The required event channel is provisioned internally so that
+    /// a [`crate::port::subscriber::Subscriber`] can be attached directly to a
+    /// [`WaitSet`](crate::waitset::WaitSet). If an existing [`Service`] is opened it defines
+    /// the required setting.
+    pub fn enable_subscriber_wakeup(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_subscriber_wakeup = value;
+        self.verify_enable_subscriber_wakeup = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines if the [`crate::port::publisher::Publisher`]s
+    /// copy every [`crate::sample::Sample`] on send into a private receive segment of each
+    /// [`crate::port::subscriber::Subscriber`] instead of sharing their data segment with all
+    /// [`crate::port::subscriber::Subscriber`]s. A [`crate::port::subscriber::Subscriber`] then
+    /// maps only its own receive segment, so that it cannot observe or hold back the memory of
+    /// other [`crate::port::subscriber::Subscriber`]s. If an existing [`Service`] is opened it
+    /// defines the required setting.
+    pub fn enable_copy_on_send(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_copy_on_send = value;
+        self.verify_enable_copy_on_send = true;
+        self
+    }
+
+    /// Enables the deadline property of the service. There must be a [`crate::sample::Sample`]
+    /// sent by any [`crate::port::publisher::Publisher`] after at least the provided `deadline`,
+    /// otherwise the [`crate::port::subscriber::Subscriber`]s report a missed deadline to
+    /// their [`DegradationHandler`](crate::port::DegradationHandler).
+    pub fn deadline(mut self, deadline: Duration) -> Self {
+        self.config_details_mut().deadline = RelocatableOption::Some(Deadline {
+            value: deadline.into(),
+            creation_time: Time::default(),
+        });
+        self.verify_deadline = true;
+        self
+    }
+
+    /// Disables the deadline property of the service.
+    /// [`crate::port::publisher::Publisher`]s can send [`crate::sample::Sample`]s at any
+    /// interval.
+    pub fn disable_deadline(mut self) -> Self {
+        self.config_details_mut().deadline = RelocatableOption::None;
+        self.verify_deadline = true;
         self
     }
 
     /// If the [`Service`] is created it defines how many [`crate::sample::Sample`] a
-    /// [`crate::port::subscriber::Subscriber`] can borrow at most in parallel. If an existing
+    /// [`crate::port::subscriber::Subscriber`] can borrow at most in parallel from a
+    /// [`crate::port::publisher::Publisher`], independent of their priority. If an existing
     /// [`Service`] is opened it defines the minimum required.
     pub fn subscriber_max_borrowed_samples(mut self, value: usize) -> Self {
         self.config_details_mut().subscriber_max_borrowed_samples = value;
@@ -370,9 +542,25 @@ impl<
         self
     }
 
+    /// If the [`Service`] is created it defines how many [`crate::sample::Sample`]s of all
+    /// [`crate::port::publisher::Publisher`]s the [`Service`] retains in its history cache. The
+    /// cache is owned by the [`Service`], therefore the [`crate::sample::Sample`]s outlive the
+    /// [`crate::port::publisher::Publisher`] that sent them. A new
+    /// [`crate::port::subscriber::Subscriber`] receives the cached [`crate::sample::Sample`]s,
+    /// at most as many as fit into its buffer, and the [`crate::port::publisher::Publisher`]s
+    /// do not deliver their own history anymore.
+    ///
+    /// If an existing [`Service`] is opened it defines the minimum required.
+    pub fn history_cache_size(mut self, value: usize) -> Self {
+        self.config_details_mut().history_cache_size = value;
+        self.verify_history_cache_size = true;
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`crate::sample::Sample`] a
-    /// [`crate::port::subscriber::Subscriber`] can store in its internal buffer. If an existing
-    /// [`Service`] is opened it defines the minimum required.
+    /// [`crate::port::subscriber::Subscriber`] can store in its internal buffer. Every priority
+    /// has a separate buffer of this size, see [`Builder::number_of_priorities()`]. If an
+    /// existing [`Service`] is opened it defines the minimum required.
     pub fn subscriber_max_buffer_size(mut self, value: usize) -> Self {
         self.config_details_mut().subscriber_max_buffer_size = value;
         self.verify_subscriber_max_buffer_size = true;
@@ -406,6 +594,14 @@ impl<
         self
     }
 
+    /// If the [`Service`] is created it defines its [`ServiceLifetime`], meaning whether it
+    /// outlives its last [`Node`](crate::node::Node). It is ignored when an existing
+    /// [`Service`] is opened.
+    pub fn lifetime(mut self, value: ServiceLifetime) -> Self {
+        self.base.service_config.lifetime = value;
+        self
+    }
+
     /// Validates configuration and overrides the invalid setting with meaningful values.
     fn adjust_configuration_to_meaningful_values(&mut self) {
         let origin = format!("{self:?}");
@@ -423,6 +619,12 @@ impl<
             settings.subscriber_max_buffer_size = 1;
         }
 
+        if settings.history_cache_max_slice_len == 0 {
+            warn!(from origin,
+                "Setting the history cache max slice length to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.history_cache_max_slice_len = 1;
+        }
+
         if settings.max_subscribers == 0 {
             warn!(from origin,
                 "Setting the maximum amount of subscribers to 0 is not supported. Adjust it to 1, the smallest supported value.");
@@ -440,6 +642,19 @@ impl<
                 "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_nodes = 1;
         }
+
+        if settings.number_of_priorities == 0 {
+            warn!(from origin,
+                "Setting the number of priorities to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.number_of_priorities = 1;
+        }
+
+        if settings.number_of_priorities as usize > MAX_NUMBER_OF_PRIORITIES {
+            warn!(from origin,
+                "Setting the number of priorities to {} is not supported. Adjust it to {}, the greatest supported value.",
+                settings.number_of_priorities, MAX_NUMBER_OF_PRIORITIES);
+            settings.number_of_priorities = MAX_NUMBER_OF_PRIORITIES as u8;
+        }
     }
 
     fn verify_service_configuration(
@@ -498,6 +713,14 @@ impl<
                                 msg, existing_settings.history_size, required_settings.history_size);
         }
 
+        if self.verify_history_cache_size
+            && existing_settings.history_cache_size < required_settings.history_cache_size
+        {
+            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedMinHistoryCacheSize,
+                                "{} since the service supports only a history cache size of {} but a history cache size of {} was requested.",
+                                msg, existing_settings.history_cache_size, required_settings.history_cache_size);
+        }
+
         if self.verify_subscriber_max_borrowed_samples
             && existing_settings.subscriber_max_borrowed_samples
                 < required_settings.subscriber_max_borrowed_samples
@@ -515,6 +738,51 @@ impl<
                                 msg);
         }
 
+        for priority in 0..MAX_NUMBER_OF_PRIORITIES {
+            if self.verify_enable_safe_overflow_for_priority[priority]
+                && existing_settings.enable_safe_overflow_for_priority[priority]
+                    != required_settings.enable_safe_overflow_for_priority[priority]
+            {
+                fail!(from self, with PublishSubscribeOpenError::IncompatibleOverflowBehavior,
+                                "{} since the service has an incompatible safe overflow behavior for priority {}.",
+                                msg, priority);
+            }
+        }
+
+        if self.verify_number_of_priorities
+            && existing_settings.number_of_priorities < required_settings.number_of_priorities
+        {
+            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedNumberOfPriorities,
+                                "{} since the service supports only {} priorities but {} priorities were requested.",
+                                msg, existing_settings.number_of_priorities, required_settings.number_of_priorities);
+        }
+
+        if self.verify_enable_subscriber_wakeup
+            && existing_settings.enable_subscriber_wakeup
+                != required_settings.enable_subscriber_wakeup
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleSubscriberWakeup,
+                                "{} since the service has an incompatible subscriber wakeup setting.",
+                                msg);
+        }
+
+        if self.verify_enable_copy_on_send
+            && existing_settings.enable_copy_on_send != required_settings.enable_copy_on_send
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleCopyOnSend,
+                                "{} since the service has an incompatible copy on send setting.",
+                                msg);
+        }
+
+        if self.verify_deadline
+            && existing_settings.deadline.map(|v| v.value)
+                != required_settings.deadline.map(|v| v.value)
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleDeadline,
+                "{} since the deadline is {:?} but a deadline of {:?} is required.",
+                msg, existing_settings.deadline, required_settings.deadline);
+        }
+
         if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
             fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes,
                                 "{} since the service supports only {} nodes but {} are required.",
@@ -535,7 +803,13 @@ impl<
 
         let msg = "Unable to create publish subscribe service";
 
-        if !self.config_details().enable_safe_overflow
+        let has_non_overflowing_priority =
+            (0..self.config_details().number_of_priorities).any(|priority| {
+                !self
+                    .config_details()
+                    .has_safe_overflow_for_priority(priority)
+            });
+        if has_non_overflowing_priority
             && (self.config_details().subscriber_max_buffer_size
                 < self.config_details().history_size)
         {
@@ -549,6 +823,16 @@ impl<
                     .base
                     .create_node_service_tag(msg, PublishSubscribeCreateError::InternalFailure)?;
 
+                if let RelocatableOption::Some(ref mut deadline) =
+                    self.config_details_mut().deadline
+                {
+                    let now = fail!(from self, when Time::now(),
+                                with PublishSubscribeCreateError::InternalFailure,
+                                "{} since the current system time could not be acquired.", msg);
+
+                    deadline.creation_time = now;
+                }
+
                 // create static config
                 let static_config = match self.base.create_static_config_storage() {
                     Ok(c) => c,
@@ -572,10 +856,26 @@ impl<
 
                 let pubsub_config = self.base.service_config.publish_subscribe();
 
+                // without an explicit history cache, persistent services retain the last
+                // sample for late joining subscribers
+                let history_cache_capacity = if pubsub_config.history_cache_size > 0 {
+                    pubsub_config.history_cache_size
+                } else if self.base.service_config.lifetime().is_persistent()
+                    && pubsub_config.history_size > 0
+                {
+                    1
+                } else {
+                    0
+                };
+
                 // create dynamic config
                 let dynamic_config_setting = DynamicConfigSettings {
                     number_of_publishers: pubsub_config.max_publishers,
                     number_of_subscribers: pubsub_config.max_subscribers,
+                    history_cache_capacity,
+                    history_cache_slot_layout: pubsub_config
+                        .message_type_details
+                        .sample_layout(pubsub_config.history_cache_max_slice_len),
                 };
 
                 let dynamic_config = match self.base.create_dynamic_config_storage(
@@ -783,6 +1083,28 @@ impl<UserHeader: Debug + ZeroCopySend, ServiceType: service::Service>
     }
 }
 
+impl<UserHeader: Debug + ZeroCopySend, ServiceType: service::Service>
+    Builder<[u8], UserHeader, ServiceType>
+{
+    /// Declares that the payload of the [`Service`] is a `T` that is serialized with the
+    /// provided `Serializer` into the byte slice. It allows to transfer types that are not
+    /// [`ZeroCopySend`], like types that contain a [`alloc::string::String`] or a
+    /// [`alloc::vec::Vec`].
+    ///
+    /// The `Serializer` and the type name of `T` are stored in the
+    /// [`MessageTypeDetails`] of the [`Service`]. Participants that use a different
+    /// `Serializer`, a different `T` or plain bytes cannot open the [`Service`].
+    ///
+    /// The data can be sent with
+    /// [`Publisher::send_serialized()`](crate::port::publisher::Publisher::send_serialized())
+    /// and received with
+    /// [`Subscriber::receive_deserialized()`](crate::port::subscriber::Subscriber::receive_deserialized()).
+    pub fn serialized_payload<Serializer: Serialize, T: ?Sized>(mut self) -> Self {
+        self.override_payload_type = Some(TypeDetail::new_serialized::<Serializer, T>());
+        self
+    }
+}
+
 impl<Payload: Debug + ?Sized + ZeroCopySend, ServiceType: service::Service>
     Builder<Payload, CustomHeaderMarker, ServiceType>
 {
@@ -799,13 +1121,17 @@ impl<Payload: Debug + ZeroCopySend, UserHeader: Debug + ZeroCopySend, ServiceTyp
     fn prepare_config_details(&mut self) {
         self.config_details_mut().message_type_details =
             MessageTypeDetails::from::<Header, UserHeader, Payload>(TypeVariant::FixedSize);
+        self.config_details_mut().message_type_schema =
+            MessageTypeSchema::from::<UserHeader, Payload>();
 
         if let Some(details) = &self.override_payload_type {
             self.config_details_mut().message_type_details.payload = *details;
+            self.config_details_mut().message_type_schema.payload = TypeSchema::default();
         }
 
         if let Some(details) = &self.override_user_header_type {
             self.config_details_mut().message_type_details.user_header = *details;
+            self.config_details_mut().message_type_schema.user_header = TypeSchema::default();
         }
 
         self.adjust_payload_alignment();
@@ -891,18 +1217,31 @@ impl<Payload: Debug + ZeroCopySend, UserHeader: Debug + ZeroCopySend, ServiceTyp
     fn prepare_config_details(&mut self) {
         self.config_details_mut().message_type_details =
             MessageTypeDetails::from::<Header, UserHeader, Payload>(TypeVariant::Dynamic);
+        self.config_details_mut().message_type_schema =
+            MessageTypeSchema::from::<UserHeader, Payload>();
 
         if let Some(details) = &self.override_payload_type {
             self.config_details_mut().message_type_details.payload = *details;
+            self.config_details_mut().message_type_schema.payload = TypeSchema::default();
         }
 
         if let Some(details) = &self.override_user_header_type {
             self.config_details_mut().message_type_details.user_header = *details;
+            self.config_details_mut().message_type_schema.user_header = TypeSchema::default();
         }
 
         self.adjust_payload_alignment();
     }
 
+    /// If the [`Service`] is created it defines the maximum number of slice elements a
+    /// [`crate::sample::Sample`] can have to be retained in the history cache, see
+    /// [`Builder::history_cache_size()`]. Larger [`crate::sample::Sample`]s are delivered as
+    /// usual but are not retained. It is ignored when an existing [`Service`] is opened.
+    pub fn history_cache_max_slice_len(mut self, value: usize) -> Self {
+        self.config_details_mut().history_cache_max_slice_len = value;
+        self
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created.
     pub fn open_or_create(
@@ -26,6 +26,7 @@ use crate::prelude::{AttributeSpecifier, AttributeVerifier};
 use crate::service::builder::OpenDynamicStorageFailure;
 use crate::service::dynamic_config::MessagingPatternSettings;
 use crate::service::dynamic_config::request_response::DynamicConfigSettings;
+use crate::service::lifetime::ServiceLifetime;
 use crate::service::port_factory::request_response;
 use crate::service::static_config::message_type_details::TypeDetail;
 use crate::service::static_config::messaging_pattern::MessagingPattern;
@@ -33,6 +34,7 @@ use crate::service::{self, NoResource, header, static_config};
 use crate::service::{Service, builder, dynamic_config};
 
 use super::message_type_details::{MessageTypeDetails, TypeVariant};
+use super::type_schema::{MessageTypeSchema, SchemaCompatibility, TypeSchema, TypeSchemaMismatch};
 use super::{CustomHeaderMarker, CustomPayloadMarker, RETRY_LIMIT, ServiceState};
 
 /// Errors that can occur when an existing [`MessagingPattern::RequestResponse`] [`Service`] shall
@@ -65,6 +67,24 @@ pub enum RequestResponseOpenError {
     IncompatibleRequestType,
     /// The [`Service`] has the wrong response payload type, response header type or type alignment.
     IncompatibleResponseType,
+    /// The [`Service`] has a request header or payload type with a different
+    /// [`TypeSchema::version()`] or, with [`SchemaCompatibility::AppendOnly`], an older one.
+    IncompatibleRequestTypeVersion,
+    /// The [`Service`] has a response header or payload type with a different
+    /// [`TypeSchema::version()`] or, with [`SchemaCompatibility::AppendOnly`], an older one.
+    IncompatibleResponseTypeVersion,
+    /// The [`Service`] has a request header or payload type with the same name and size but a
+    /// different field layout, see [`TypeSchema::fingerprint()`].
+    IncompatibleRequestTypeSchema,
+    /// The [`Service`] has a response header or payload type with the same name and size but a
+    /// different field layout, see [`TypeSchema::fingerprint()`].
+    IncompatibleResponseTypeSchema,
+    /// With [`SchemaCompatibility::AppendOnly`], the request header or payload type of the
+    /// [`Service`] does not extend the requested type by only appending fields.
+    RequestTypeSchemaIsNotAppendOnly,
+    /// With [`SchemaCompatibility::AppendOnly`], the response header or payload type of the
+    /// [`Service`] does not extend the requested type by only appending fields.
+    ResponseTypeSchemaIsNotAppendOnly,
     /// The [`AttributeVerifier`] required attributes that the [`Service`] does not satisfy.
     IncompatibleAttributes,
     /// The [`Service`] has the wrong messaging pattern.
@@ -104,6 +124,24 @@ impl From<ServiceAvailabilityState> for RequestResponseOpenError {
             ServiceAvailabilityState::IncompatibleResponseType => {
                 RequestResponseOpenError::IncompatibleResponseType
             }
+            ServiceAvailabilityState::IncompatibleRequestTypeSchema(
+                TypeSchemaMismatch::Version,
+            ) => RequestResponseOpenError::IncompatibleRequestTypeVersion,
+            ServiceAvailabilityState::IncompatibleRequestTypeSchema(TypeSchemaMismatch::Schema) => {
+                RequestResponseOpenError::IncompatibleRequestTypeSchema
+            }
+            ServiceAvailabilityState::IncompatibleRequestTypeSchema(
+                TypeSchemaMismatch::NotAppendOnly,
+            ) => RequestResponseOpenError::RequestTypeSchemaIsNotAppendOnly,
+            ServiceAvailabilityState::IncompatibleResponseTypeSchema(
+                TypeSchemaMismatch::Version,
+            ) => RequestResponseOpenError::IncompatibleResponseTypeVersion,
+            ServiceAvailabilityState::IncompatibleResponseTypeSchema(
+                TypeSchemaMismatch::Schema,
+            ) => RequestResponseOpenError::IncompatibleResponseTypeSchema,
+            ServiceAvailabilityState::IncompatibleResponseTypeSchema(
+                TypeSchemaMismatch::NotAppendOnly,
+            ) => RequestResponseOpenError::ResponseTypeSchemaIsNotAppendOnly,
             ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
                 RequestResponseOpenError::IncompatibleMessagingPattern
             }
@@ -151,6 +189,8 @@ impl From<ServiceAvailabilityState> for RequestResponseCreateError {
         match value {
             ServiceAvailabilityState::IncompatibleRequestType
             | ServiceAvailabilityState::IncompatibleResponseType
+            | ServiceAvailabilityState::IncompatibleRequestTypeSchema(_)
+            | ServiceAvailabilityState::IncompatibleResponseTypeSchema(_)
             | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
                 RequestResponseCreateError::AlreadyExists
             }
@@ -211,6 +251,8 @@ enum ServiceAvailabilityState {
     ServiceState(ServiceState),
     IncompatibleRequestType,
     IncompatibleResponseType,
+    IncompatibleRequestTypeSchema(TypeSchemaMismatch),
+    IncompatibleResponseTypeSchema(TypeSchemaMismatch),
 }
 
 /// Builder to create new [`MessagingPattern::RequestResponse`] based [`Service`]s
@@ -233,6 +275,7 @@ pub struct Builder<
     override_response_payload_type: Option<TypeDetail>,
     override_request_header_type: Option<TypeDetail>,
     override_response_header_type: Option<TypeDetail>,
+    schema_compatibility: SchemaCompatibility,
     verify_enable_safe_overflow_for_requests: bool,
     verify_enable_safe_overflow_for_responses: bool,
     verify_max_active_requests_per_client: bool,
@@ -267,6 +310,7 @@ impl<
             override_response_payload_type: self.override_response_payload_type,
             override_request_header_type: self.override_request_header_type,
             override_response_header_type: self.override_response_header_type,
+            schema_compatibility: self.schema_compatibility,
             verify_enable_safe_overflow_for_requests: self.verify_enable_safe_overflow_for_requests,
             verify_enable_safe_overflow_for_responses: self
                 .verify_enable_safe_overflow_for_responses,
@@ -304,6 +348,7 @@ impl<
             override_request_payload_type: None,
             override_response_header_type: None,
             override_response_payload_type: None,
+            schema_compatibility: SchemaCompatibility::default(),
             verify_enable_safe_overflow_for_requests: false,
             verify_enable_safe_overflow_for_responses: false,
             verify_max_loaned_requests: false,
@@ -381,6 +426,14 @@ impl<
         self
     }
 
+    /// If an existing [`Service`] is opened, it defines how the [`TypeSchema`]s of the request
+    /// and response types are verified against the ones of the [`Service`]. By default,
+    /// [`SchemaCompatibility::Exact`] is used.
+    pub fn schema_compatibility(mut self, value: SchemaCompatibility) -> Self {
+        self.schema_compatibility = value;
+        self
+    }
+
     /// If the [`Service`] is created, defines the overflow behavior of the service for requests.
     /// If an existing [`Service`] is opened it requires the service to have the defined overflow
     /// behavior.
@@ -460,6 +513,14 @@ impl<
         self
     }
 
+    /// If the [`Service`] is created it defines its [`ServiceLifetime`], meaning whether it
+    /// outlives its last [`Node`](crate::node::Node). It is ignored when an existing
+    /// [`Service`] is opened.
+    pub fn lifetime(mut self, value: ServiceLifetime) -> Self {
+        self.base.service_config.lifetime = value;
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`Response`](crate::response::Response)s shall
     /// be able to be borrowed in parallel per [`PendingResponse`](crate::pending_response::PendingResponse). If an existing [`Service`] is opened it defines how many
     /// borrows must be at least supported.
@@ -640,10 +701,26 @@ impl<
     > {
         match self.base.is_service_available(error_msg) {
             Ok(Some((config, storage))) => {
+                let compatibility = self.schema_compatibility;
+                let requested_request_schema = &self.config_details().request_message_type_schema;
+                let existing_request_schema =
+                    &config.request_response().request_message_type_schema;
+                let requested_response_schema = &self.config_details().response_message_type_schema;
+                let existing_response_schema =
+                    &config.request_response().response_message_type_schema;
+
                 if !self
                     .config_details()
                     .request_message_type_details
-                    .is_compatible_to(&config.request_response().request_message_type_details)
+                    .is_compatible_to_extension(
+                        &config.request_response().request_message_type_details,
+                        requested_request_schema
+                            .user_header
+                            .is_extensible_to(&existing_request_schema.user_header, compatibility),
+                        requested_request_schema
+                            .payload
+                            .is_extensible_to(&existing_request_schema.payload, compatibility),
+                    )
                 {
                     fail!(from self, with ServiceAvailabilityState::IncompatibleRequestType,
                         "{} since the services uses the request type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
@@ -654,7 +731,15 @@ impl<
                 if !self
                     .config_details()
                     .response_message_type_details
-                    .is_compatible_to(&config.request_response().response_message_type_details)
+                    .is_compatible_to_extension(
+                        &config.request_response().response_message_type_details,
+                        requested_response_schema
+                            .user_header
+                            .is_extensible_to(&existing_response_schema.user_header, compatibility),
+                        requested_response_schema
+                            .payload
+                            .is_extensible_to(&existing_response_schema.payload, compatibility),
+                    )
                 {
                     fail!(from self, with ServiceAvailabilityState::IncompatibleResponseType,
                         "{} since the services uses the response type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
@@ -662,6 +747,22 @@ impl<
                         self.config_details().response_message_type_details);
                 }
 
+                if let Err(e) = requested_request_schema
+                    .verify_compatibility(existing_request_schema, compatibility)
+                {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleRequestTypeSchema(e),
+                        "{} since the services uses the request type schema \"{:?}\" which is not compatible ({:?}) to the requested type schema \"{:?}\" with {:?}.",
+                        error_msg, existing_request_schema, e, requested_request_schema, compatibility);
+                }
+
+                if let Err(e) = requested_response_schema
+                    .verify_compatibility(existing_response_schema, compatibility)
+                {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleResponseTypeSchema(e),
+                        "{} since the services uses the response type schema \"{:?}\" which is not compatible ({:?}) to the requested type schema \"{:?}\" with {:?}.",
+                        error_msg, existing_response_schema, e, requested_response_schema, compatibility);
+                }
+
                 Ok(Some((config, storage)))
             }
             Ok(None) => Ok(None),
@@ -924,24 +1025,36 @@ impl<
             self.config_details_mut()
                 .request_message_type_details
                 .payload = *details;
+            self.config_details_mut()
+                .request_message_type_schema
+                .payload = TypeSchema::default();
         }
 
         if let Some(details) = &self.override_request_header_type {
             self.config_details_mut()
                 .request_message_type_details
                 .user_header = *details;
+            self.config_details_mut()
+                .request_message_type_schema
+                .user_header = TypeSchema::default();
         }
 
         if let Some(details) = &self.override_response_payload_type {
             self.config_details_mut()
                 .response_message_type_details
                 .payload = *details;
+            self.config_details_mut()
+                .response_message_type_schema
+                .payload = TypeSchema::default();
         }
 
         if let Some(details) = &self.override_response_header_type {
             self.config_details_mut()
                 .response_message_type_details
                 .user_header = *details;
+            self.config_details_mut()
+                .response_message_type_schema
+                .user_header = TypeSchema::default();
         }
 
         if let Some(alignment) = self.override_request_alignment {
@@ -991,6 +1104,11 @@ impl<
             ResponsePayload,
         >(TypeVariant::FixedSize);
 
+        self.config_details_mut().request_message_type_schema =
+            MessageTypeSchema::from::<RequestHeader, RequestPayload>();
+        self.config_details_mut().response_message_type_schema =
+            MessageTypeSchema::from::<ResponseHeader, ResponsePayload>();
+
         self.prepare_message_type();
     }
 
@@ -1125,6 +1243,11 @@ impl<
             ResponsePayload,
         >(TypeVariant::FixedSize);
 
+        self.config_details_mut().request_message_type_schema =
+            MessageTypeSchema::from::<RequestHeader, RequestPayload>();
+        self.config_details_mut().response_message_type_schema =
+            MessageTypeSchema::from::<ResponseHeader, ResponsePayload>();
+
         self.prepare_message_type();
     }
 
@@ -1259,6 +1382,11 @@ impl<
             ResponsePayload,
         >(TypeVariant::Dynamic);
 
+        self.config_details_mut().request_message_type_schema =
+            MessageTypeSchema::from::<RequestHeader, RequestPayload>();
+        self.config_details_mut().response_message_type_schema =
+            MessageTypeSchema::from::<ResponseHeader, ResponsePayload>();
+
         self.prepare_message_type();
     }
 
@@ -1399,6 +1527,11 @@ impl<
             ResponsePayload,
         >(TypeVariant::Dynamic);
 
+        self.config_details_mut().request_message_type_schema =
+            MessageTypeSchema::from::<RequestHeader, RequestPayload>();
+        self.config_details_mut().response_message_type_schema =
+            MessageTypeSchema::from::<ResponseHeader, ResponsePayload>();
+
         self.prepare_message_type();
     }
 
@@ -1554,3 +1687,50 @@ impl<ServiceType: Service>
         self
     }
 }
+
+impl<
+    RequestHeader: Debug + ZeroCopySend,
+    ResponsePayload: Debug + ZeroCopySend + ?Sized,
+    ResponseHeader: Debug + ZeroCopySend,
+    ServiceType: Service,
+> Builder<[u8], RequestHeader, ResponsePayload, ResponseHeader, ServiceType>
+{
+    /// Declares that the request payload of the [`Service`] is a `T` that is serialized with
+    /// the provided `Serializer` into the byte slice. It allows to transfer types that are not
+    /// [`ZeroCopySend`], like types that contain a [`alloc::string::String`] or a
+    /// [`alloc::vec::Vec`].
+    ///
+    /// The `Serializer` and the type name of `T` are stored in the request
+    /// [`MessageTypeDetails`] of the [`Service`]. Participants that use a different
+    /// `Serializer`, a different `T` or plain bytes cannot open the [`Service`].
+    ///
+    /// The requests can be sent with
+    /// [`Client::send_serialized()`](crate::port::client::Client::send_serialized()) and
+    /// received with
+    /// [`Server::receive_deserialized()`](crate::port::server::Server::receive_deserialized()).
+    pub fn serialized_request_payload<Serializer: Serialize, T: ?Sized>(mut self) -> Self {
+        self.override_request_payload_type = Some(TypeDetail::new_serialized::<Serializer, T>());
+        self
+    }
+}
+
+impl<
+    RequestPayload: Debug + ZeroCopySend + ?Sized,
+    RequestHeader: Debug + ZeroCopySend,
+    ResponseHeader: Debug + ZeroCopySend,
+    ServiceType: Service,
+> Builder<RequestPayload, RequestHeader, [u8], ResponseHeader, ServiceType>
+{
+    /// Declares that the response payload of the [`Service`] is a `T` that is serialized with
+    /// the provided `Serializer` into the byte slice. See
+    /// [`Builder::serialized_request_payload()`] for details.
+    ///
+    /// The responses can be sent with
+    /// [`ActiveRequest::send_serialized()`](crate::active_request::ActiveRequest::send_serialized())
+    /// and received with
+    /// [`PendingResponse::receive_deserialized()`](crate::pending_response::PendingResponse::receive_deserialized()).
+    pub fn serialized_response_payload<Serializer: Serialize, T: ?Sized>(mut self) -> Self {
+        self.override_response_payload_type = Some(TypeDetail::new_serialized::<Serializer, T>());
+        self
+    }
+}
@@ -107,6 +107,27 @@ pub(crate) fn node_details_config<Service: crate::service::Service>(
         .path_hint(&node_details_path(global_config, node_id))
 }
 
+pub(crate) fn node_heartbeat_config<Service: crate::service::Service, T: Send + Sync + Debug>(
+    global_config: &config::Config,
+    node_id: &UniqueNodeId,
+) -> <Service::DynamicStorage<T> as NamedConceptMgmt>::Configuration {
+    <<Service::DynamicStorage<T> as NamedConceptMgmt>::Configuration>::default()
+        .prefix(&global_config.global.prefix)
+        .suffix(&global_config.global.node.heartbeat_suffix)
+        .path_hint(&node_details_path(global_config, node_id))
+}
+
+pub(crate) fn node_control_config<Service: crate::service::Service>(
+    global_config: &config::Config,
+) -> <Service::Event as NamedConceptMgmt>::Configuration {
+    // the control channel is not stored in the node directory since socket based events
+    // have a tight limit on the length of their path
+    <<Service::Event as NamedConceptMgmt>::Configuration>::default()
+        .prefix(&global_config.global.prefix)
+        .suffix(&global_config.global.node.control_suffix)
+        .path_hint(global_config.global.root_path())
+}
+
 pub(crate) fn service_tag_config<Service: crate::service::Service>(
     global_config: &config::Config,
     node_id: &UniqueNodeId,
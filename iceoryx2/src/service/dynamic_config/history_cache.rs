@@ -0,0 +1,282 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A service owned ring of sample copies that lives in the dynamic config of a publish-subscribe
+//! [`Service`](crate::service::Service). In contrast to the publisher history, the samples
+//! survive the [`Publisher`](crate::port::publisher::Publisher) that sent them.
+//!
+//! Every slot is protected by a sequence lock. Writers copy the whole chunk (header, user header
+//! and payload) into the slot, readers copy the slot into an owned heap buffer and discard the
+//! copy when a writer modified the slot in between.
+
+use core::alloc::Layout;
+use core::fmt::Debug;
+use core::ptr::NonNull;
+
+use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering, fence};
+use iceoryx2_bb_elementary::math::align;
+use iceoryx2_bb_elementary::relocatable_ptr::{PointerTrait, RelocatablePointer};
+use iceoryx2_bb_elementary_traits::allocator::{AllocationError, BaseAllocator};
+use iceoryx2_bb_memory::heap_allocator::HeapAllocator;
+use iceoryx2_log::{fail, fatal_panic};
+
+// a writer that finds a slot locked retries this many times before it gives up
+const MAX_LOCK_ATTEMPTS: usize = 1024;
+
+#[repr(C)]
+struct SlotHeader {
+    // odd while a writer modifies the slot
+    sequence: AtomicU64,
+    // the write position of the stored sample, starting at 1, 0 when the slot is empty
+    position: AtomicU64,
+    size: AtomicUsize,
+}
+
+/// A sample that was copied out of the [`HistoryCache`] into an owned heap buffer.
+#[derive(Debug)]
+pub(crate) struct CachedSample {
+    memory: NonNull<u8>,
+    layout: Layout,
+}
+
+// the heap buffer is exclusively owned by the CachedSample
+unsafe impl Send for CachedSample {}
+
+impl Drop for CachedSample {
+    fn drop(&mut self) {
+        unsafe { HeapAllocator::global().deallocate(self.memory, self.layout) };
+    }
+}
+
+impl CachedSample {
+    /// Returns the start address of the copied chunk.
+    pub(crate) fn as_ptr(&self) -> *const u8 {
+        self.memory.as_ptr()
+    }
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct HistoryCache {
+    slots: RelocatablePointer<u8>,
+    capacity: usize,
+    slot_size: usize,
+    slot_alignment: usize,
+    write_position: AtomicU64,
+    is_memory_initialized: AtomicBool,
+}
+
+impl HistoryCache {
+    /// Creates a new uninitialized [`HistoryCache`] that stores up to `capacity` chunks that
+    /// fit into `slot_layout`.
+    ///
+    /// # Safety
+    ///
+    ///  * [`HistoryCache::init()`] must be called before the [`HistoryCache`] is used
+    ///
+    pub(crate) unsafe fn new_uninit(capacity: usize, slot_layout: Layout) -> Self {
+        Self {
+            slots: unsafe { RelocatablePointer::new_uninit() },
+            capacity,
+            slot_size: slot_layout.size(),
+            slot_alignment: slot_layout.align().max(align_of::<SlotHeader>()),
+            write_position: AtomicU64::new(0),
+            is_memory_initialized: AtomicBool::new(false),
+        }
+    }
+
+    fn data_offset(slot_alignment: usize) -> usize {
+        align(size_of::<SlotHeader>(), slot_alignment)
+    }
+
+    fn slot_stride(slot_size: usize, slot_alignment: usize) -> usize {
+        align(
+            Self::data_offset(slot_alignment) + slot_size,
+            slot_alignment,
+        )
+    }
+
+    /// Returns the amount of memory the [`HistoryCache`] requires from the allocator
+    /// that is provided in [`HistoryCache::init()`].
+    pub(crate) fn memory_size(capacity: usize, slot_layout: Layout) -> usize {
+        if capacity == 0 {
+            return 0;
+        }
+
+        let slot_alignment = slot_layout.align().max(align_of::<SlotHeader>());
+        Self::slot_stride(slot_layout.size(), slot_alignment) * capacity + slot_alignment - 1
+    }
+
+    /// Allocates the slots of the [`HistoryCache`].
+    ///
+    /// # Safety
+    ///
+    ///  * must be called exactly once
+    ///
+    pub(crate) unsafe fn init<T: BaseAllocator>(
+        &mut self,
+        allocator: &T,
+    ) -> Result<(), AllocationError> {
+        if self.is_memory_initialized.load(Ordering::Relaxed) {
+            fatal_panic!(from self,
+                "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+        }
+
+        if self.capacity != 0 {
+            let stride = Self::slot_stride(self.slot_size, self.slot_alignment);
+            let layout = match Layout::from_size_align(stride * self.capacity, self.slot_alignment)
+            {
+                Ok(v) => v,
+                Err(e) => {
+                    fail!(from self, with AllocationError::SizeTooLarge,
+                        "Failed to initialize since the slot layout is invalid. [{e:?}]");
+                }
+            };
+
+            unsafe {
+                self.slots.init(fail!(from self,
+                    when allocator.allocate(layout),
+                    "Failed to initialize since the allocation of the slots failed."))
+            };
+
+            for n in 0..self.capacity {
+                unsafe {
+                    self.slot_header(n).cast_mut().write(SlotHeader {
+                        sequence: AtomicU64::new(0),
+                        position: AtomicU64::new(0),
+                        size: AtomicUsize::new(0),
+                    })
+                };
+            }
+        }
+
+        self.is_memory_initialized.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Returns how many samples the [`HistoryCache`] can retain.
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn slot_header(&self, n: usize) -> *const SlotHeader {
+        let stride = Self::slot_stride(self.slot_size, self.slot_alignment);
+        unsafe { self.slots.as_ptr().add(n * stride) as *const SlotHeader }
+    }
+
+    fn slot_data(&self, n: usize) -> *mut u8 {
+        unsafe { (self.slot_header(n) as *mut u8).add(Self::data_offset(self.slot_alignment)) }
+    }
+
+    /// Reserves the write position, starting at 1, under which the next chunk is stored with
+    /// [`HistoryCache::store()`]. Returns 0 when the [`HistoryCache`] has no capacity.
+    pub(crate) fn reserve_position(&self) -> u64 {
+        if self.capacity == 0 {
+            return 0;
+        }
+
+        self.write_position.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// Copies the chunk under the previously reserved write position into the
+    /// [`HistoryCache`]. When the [`HistoryCache`] is full, the oldest chunk is overridden.
+    /// Returns false when the chunk does not fit into a slot or could not be stored, otherwise
+    /// true.
+    ///
+    /// # Safety
+    ///
+    ///  * `chunk` must point to at least `size` readable bytes
+    ///  * `position` must be acquired with [`HistoryCache::reserve_position()`]
+    ///
+    pub(crate) unsafe fn store(&self, position: u64, chunk: *const u8, size: usize) -> bool {
+        if self.capacity == 0 || position == 0 || self.slot_size < size {
+            return false;
+        }
+
+        let n = (position % self.capacity as u64) as usize;
+        let header = unsafe { &*self.slot_header(n) };
+
+        let mut attempts = 0;
+        let sequence = loop {
+            let sequence = header.sequence.load(Ordering::Relaxed);
+            if sequence % 2 == 0
+                && header
+                    .sequence
+                    .compare_exchange(sequence, sequence + 1, Ordering::Acquire, Ordering::Relaxed)
+                    .is_ok()
+            {
+                break sequence;
+            }
+
+            attempts += 1;
+            if MAX_LOCK_ATTEMPTS <= attempts {
+                return false;
+            }
+            core::hint::spin_loop();
+        };
+        fence(Ordering::Release);
+
+        // a concurrent writer may have stored a more recent sample in the meantime
+        let has_newer_sample = position < header.position.load(Ordering::Relaxed);
+        if !has_newer_sample {
+            header.size.store(size, Ordering::Relaxed);
+            header.position.store(position, Ordering::Relaxed);
+            unsafe { core::ptr::copy_nonoverlapping(chunk, self.slot_data(n), size) };
+        }
+
+        header.sequence.store(sequence + 2, Ordering::Release);
+        !has_newer_sample
+    }
+
+    /// Copies all retained samples, beginning with the oldest one, out of the [`HistoryCache`]
+    /// and calls the callback with each copy.
+    pub(crate) fn for_each<F: FnMut(CachedSample)>(&self, mut callback: F) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let end = self.write_position.load(Ordering::Relaxed);
+        let start = end.saturating_sub(self.capacity as u64);
+        for position in start + 1..=end {
+            if let Some(sample) = self.read(position) {
+                callback(sample);
+            }
+        }
+    }
+
+    fn read(&self, position: u64) -> Option<CachedSample> {
+        let n = (position % self.capacity as u64) as usize;
+        let header = unsafe { &*self.slot_header(n) };
+
+        let sequence = header.sequence.load(Ordering::Acquire);
+        if sequence % 2 != 0 || header.position.load(Ordering::Relaxed) != position {
+            return None;
+        }
+
+        let size = header.size.load(Ordering::Relaxed).min(self.slot_size);
+        let layout = Layout::from_size_align(size.max(1), self.slot_alignment).ok()?;
+        let memory = HeapAllocator::global().allocate(layout).ok()?;
+        let sample = CachedSample {
+            memory: memory.cast(),
+            layout,
+        };
+
+        unsafe { core::ptr::copy_nonoverlapping(self.slot_data(n), memory.as_ptr().cast(), size) };
+        fence(Ordering::Acquire);
+
+        if header.sequence.load(Ordering::Relaxed) != sequence {
+            return None;
+        }
+
+        Some(sample)
+    }
+}
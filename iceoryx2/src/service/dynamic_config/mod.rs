@@ -30,7 +30,11 @@ pub mod request_response;
 /// based service.
 pub mod blackboard;
 
+pub(crate) mod history_cache;
+
 use core::fmt::Display;
+use core::time::Duration;
+use iceoryx2_bb_concurrency::atomic::{AtomicU64, Ordering};
 use iceoryx2_bb_container::queue::RelocatableContainer;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::{
@@ -38,9 +42,13 @@ use iceoryx2_bb_lock_free::mpmc::{
     unique_index_set_enums::{ReleaseMode, ReleaseState},
 };
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
-use iceoryx2_log::{fail, fatal_panic};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+use iceoryx2_log::{fail, fatal_panic, warn};
 
 use crate::identifiers::{UniqueNodeId, UniquePortId};
+use crate::service::lifetime::ServiceLifetime;
+
+const IN_USE: u64 = 0;
 
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) enum PortCleanupAction {
@@ -99,6 +107,8 @@ impl MessagingPattern {
 pub struct DynamicConfig {
     messaging_pattern: MessagingPattern,
     nodes: Container<UniqueNodeId>,
+    is_persistent: bool,
+    unused_since: AtomicU64,
 }
 
 impl Display for DynamicConfig {
@@ -115,13 +125,59 @@ impl DynamicConfig {
     pub(crate) fn new_uninit(
         messaging_pattern: MessagingPattern,
         max_number_of_nodes: usize,
+        lifetime: &ServiceLifetime,
     ) -> Self {
         Self {
             messaging_pattern,
             nodes: unsafe { Container::new_uninit(max_number_of_nodes) },
+            is_persistent: lifetime.is_persistent(),
+            unused_since: AtomicU64::new(IN_USE),
+        }
+    }
+
+    fn node_release_mode(&self) -> ReleaseMode {
+        // persistent services are never marked for destruction when the last node leaves,
+        // they are removed explicitly or when their time-to-live expired
+        if self.is_persistent {
+            ReleaseMode::Default
+        } else {
+            ReleaseMode::LockIfLastIndex
         }
     }
 
+    fn mark_as_unused_when_empty(&self) {
+        if !self.nodes.is_empty() {
+            return;
+        }
+
+        match Time::now_with_clock(ClockType::Realtime) {
+            Ok(now) => self
+                .unused_since
+                .store(now.as_duration().as_nanos() as u64, Ordering::Relaxed),
+            Err(e) => {
+                warn!(from self,
+                    "Unable to acquire the current time, the unused persistent service may not be removed when its time-to-live expired. [{e:?}]");
+            }
+        }
+    }
+
+    /// Returns the point in time, as duration since the epoch of [`ClockType::Realtime`], since
+    /// when no [`Node`](crate::node::Node) has opened the persistent service anymore.
+    /// Returns [`None`] when the service is in use.
+    pub(crate) fn unused_since(&self) -> Option<Duration> {
+        match self.unused_since.load(Ordering::Relaxed) {
+            IN_USE => None,
+            v => Some(Duration::from_nanos(v)),
+        }
+    }
+
+    /// Prevents any [`Node`](crate::node::Node) from opening the service when no
+    /// [`Node`](crate::node::Node) is registered. Returns true when the service was
+    /// successfully marked for destruction, otherwise false.
+    pub(crate) fn mark_for_destruction_if_unused(&self) -> bool {
+        unsafe { self.nodes.lock_if_empty() == ReleaseState::Locked }
+    }
+
     pub(crate) fn memory_size(max_number_of_nodes: usize) -> usize {
         Container::<UniqueNodeId>::memory_size(max_number_of_nodes)
     }
@@ -168,10 +224,13 @@ impl DynamicConfig {
                     // additional comparision, since the node_id.owner_id() might be not enough
                     entry_node_id == *node_id
                 },
-                ReleaseMode::LockIfLastIndex,
+                self.node_release_mode(),
             ) {
                 ReleaseState::Locked => DeregisterNodeState::NoMoreOwners,
-                ReleaseState::Unlocked => DeregisterNodeState::HasOwners,
+                ReleaseState::Unlocked => {
+                    self.mark_as_unused_when_empty();
+                    DeregisterNodeState::HasOwners
+                }
             }
         }
     }
@@ -182,7 +241,10 @@ impl DynamicConfig {
     ) -> Result<ContainerHandle, RegisterNodeResult> {
         let msg = "Unable to register NodeId in service";
         match unsafe { self.nodes.add(node_id, node_id.owner_id()) } {
-            Ok(handle) => Ok(handle),
+            Ok(handle) => {
+                self.unused_since.store(IN_USE, Ordering::Relaxed);
+                Ok(handle)
+            }
             Err(ContainerAddFailure::IsLocked) => {
                 fail!(from self, with RegisterNodeResult::MarkedForDestruction,
                     "{msg} since the service is already marked for destruction.");
@@ -206,9 +268,12 @@ impl DynamicConfig {
         &self,
         handle: ContainerHandle,
     ) -> Result<DeregisterNodeState, ContainerRemoveError> {
-        match unsafe { self.nodes.remove(handle, ReleaseMode::LockIfLastIndex) } {
+        match unsafe { self.nodes.remove(handle, self.node_release_mode()) } {
             Ok(ReleaseState::Locked) => Ok(DeregisterNodeState::NoMoreOwners),
-            Ok(ReleaseState::Unlocked) => Ok(DeregisterNodeState::HasOwners),
+            Ok(ReleaseState::Unlocked) => {
+                self.mark_as_unused_when_empty();
+                Ok(DeregisterNodeState::HasOwners)
+            }
             Err(ContainerRemoveError::ContainerHandleNotOwnedByContainer) => {
                 fail!(from self, with ContainerRemoveError::ContainerHandleNotOwnedByContainer,
                     "Unable to deregister the node since it was not registered.");
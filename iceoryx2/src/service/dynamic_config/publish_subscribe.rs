@@ -26,6 +26,10 @@
 //! # Ok(())
 //! # }
 //! ```
+use core::alloc::Layout;
+
+use iceoryx2_bb_concurrency::atomic::{AtomicU64, Ordering};
+use iceoryx2_bb_container::vector::relocatable_vec::*;
 use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set_enums::ReleaseMode};
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
@@ -37,12 +41,15 @@ use crate::{
 };
 
 use super::PortCleanupAction;
+use super::history_cache::HistoryCache;
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct DynamicConfigSettings {
     pub number_of_subscribers: usize,
     pub number_of_publishers: usize,
+    pub history_cache_capacity: usize,
+    pub history_cache_slot_layout: Layout,
 }
 
 /// Contains the communication settings of the connected
@@ -91,6 +98,10 @@ pub struct SubscriberDetails {
 pub struct DynamicConfig {
     pub(crate) subscribers: Container<SubscriberDetails>,
     pub(crate) publishers: Container<PublisherDetails>,
+    pub(crate) history_cache: HistoryCache,
+    pub(crate) elapsed_time_since_last_sample: AtomicU64,
+    // counts per publisher slot how often a publisher was removed since its node died
+    pub(crate) lost_publishers: RelocatableVec<AtomicU64>,
 }
 
 impl DynamicConfig {
@@ -98,6 +109,14 @@ impl DynamicConfig {
         Self {
             subscribers: unsafe { Container::new_uninit(config.number_of_subscribers) },
             publishers: unsafe { Container::new_uninit(config.number_of_publishers) },
+            history_cache: unsafe {
+                HistoryCache::new_uninit(
+                    config.history_cache_capacity,
+                    config.history_cache_slot_layout,
+                )
+            },
+            elapsed_time_since_last_sample: AtomicU64::new(0),
+            lost_publishers: unsafe { RelocatableVec::new_uninit(config.number_of_publishers) },
         }
     }
 
@@ -109,12 +128,27 @@ impl DynamicConfig {
             fatal_panic!(from self,
             when self.publishers.init(allocator),
             "This should never happen! Unable to initialize publisher port id container.");
+            fatal_panic!(from self,
+            when self.history_cache.init(allocator),
+            "This should never happen! Unable to initialize the history cache.");
+            fatal_panic!(from self,
+            when self.lost_publishers.init(allocator),
+            "This should never happen! Unable to initialize the lost publisher counters.");
+
+            for _ in 0..self.lost_publishers.capacity() {
+                self.lost_publishers.push_unchecked(AtomicU64::new(0));
+            }
         }
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<SubscriberDetails>::memory_size(config.number_of_subscribers)
             + Container::<PublisherDetails>::memory_size(config.number_of_publishers)
+            + HistoryCache::memory_size(
+                config.history_cache_capacity,
+                config.history_cache_slot_layout,
+            )
+            + RelocatableVec::<AtomicU64>::const_memory_size(config.number_of_publishers)
     }
 
     pub(crate) unsafe fn remove_dead_node_id<
@@ -125,14 +159,30 @@ impl DynamicConfig {
         mut port_cleanup_callback: PortCleanup,
     ) {
         unsafe {
+            let publisher_state = self.publishers.get_state();
             self.publishers.recover(
                 node_id.owner_id(),
                 |registered_publisher| {
                     // additional comparision, since the node_id.owner_id() might be not enough
-                    registered_publisher.node_id == *node_id
+                    let is_removed = registered_publisher.node_id == *node_id
                         && port_cleanup_callback(UniquePortId::Publisher(
                             registered_publisher.publisher_id,
-                        )) == PortCleanupAction::RemovePort
+                        )) == PortCleanupAction::RemovePort;
+
+                    // the counter must be increased before the publisher is released so that
+                    // a subscriber that no longer sees the publisher also sees the counter
+                    if is_removed {
+                        publisher_state.for_each(|index, details| {
+                            if details.publisher_id == registered_publisher.publisher_id {
+                                self.lost_publishers[index].fetch_add(1, Ordering::Release);
+                                CallbackProgression::Stop
+                            } else {
+                                CallbackProgression::Continue
+                            }
+                        });
+                    }
+
+                    is_removed
                 },
                 ReleaseMode::Default,
             );
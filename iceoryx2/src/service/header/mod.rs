@@ -17,3 +17,5 @@ pub mod publish_subscribe;
 /// Payload headers used by
 /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
 pub mod request_response;
+
+pub use iceoryx2_log::TraceContext;
@@ -34,6 +34,8 @@
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 
+use iceoryx2_log::TraceContext;
+
 use crate::identifiers::{UniqueNodeId, UniquePublisherId};
 
 /// Sample header used by
@@ -44,6 +46,9 @@ pub struct Header {
     node_id: UniqueNodeId,
     publisher_port_id: UniquePublisherId,
     number_of_elements: u64,
+    priority: u8,
+    trace_context: TraceContext,
+    history_cache_position: u64,
 }
 
 impl Header {
@@ -51,14 +56,36 @@ impl Header {
         node_id: UniqueNodeId,
         publisher_port_id: UniquePublisherId,
         number_of_elements: u64,
+        priority: u8,
     ) -> Self {
         Self {
             node_id,
             publisher_port_id,
             number_of_elements,
+            priority,
+            trace_context: iceoryx2_log::current_trace_context().unwrap_or_default(),
+            history_cache_position: 0,
         }
     }
 
+    pub(crate) fn set_priority(&mut self, value: u8) {
+        self.priority = value;
+    }
+
+    pub(crate) fn set_trace_context(&mut self, value: TraceContext) {
+        self.trace_context = value;
+    }
+
+    pub(crate) fn set_history_cache_position(&mut self, value: u64) {
+        self.history_cache_position = value;
+    }
+
+    // the position under which the sample was stored in the history cache of the service,
+    // 0 when it was not stored
+    pub(crate) fn history_cache_position(&self) -> u64 {
+        self.history_cache_position
+    }
+
     /// Returns the [`UniqueNodeId`] of the source node that published the
     /// [`Sample`](crate::sample::Sample).
     pub fn node_id(&self) -> UniqueNodeId {
@@ -83,4 +110,16 @@ impl Header {
     pub fn number_of_elements(&self) -> u64 {
         self.number_of_elements
     }
+
+    /// Returns the priority of the [`Sample`](crate::sample::Sample). Samples with a higher
+    /// priority are received before samples with a lower priority.
+    pub fn priority(&self) -> u8 {
+        self.priority
+    }
+
+    /// Returns the [`TraceContext`] of the span in which the [`Sample`](crate::sample::Sample)
+    /// was created. It is [`None`] when the sender had no active trace.
+    pub fn trace_context(&self) -> Option<TraceContext> {
+        Some(self.trace_context).filter(|trace_context| trace_context.is_valid())
+    }
 }
@@ -13,6 +13,7 @@
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_cal::zero_copy_connection::ChannelId;
+use iceoryx2_log::TraceContext;
 
 use crate::{
     active_request::RequestId,
@@ -29,6 +30,7 @@ pub struct RequestHeader {
     pub(crate) channel_id: ChannelId,
     pub(crate) request_id: RequestId,
     pub(crate) number_of_elements: u64,
+    pub(crate) trace_context: TraceContext,
 }
 
 impl RequestHeader {
@@ -56,6 +58,13 @@ impl RequestHeader {
     pub fn node_id(&self) -> UniqueNodeId {
         self.node_id
     }
+
+    /// Returns the [`TraceContext`] of the span in which the
+    /// [`RequestMut`](crate::request_mut::RequestMut) was created. It is [`None`] when the
+    /// sender had no active trace.
+    pub fn trace_context(&self) -> Option<TraceContext> {
+        Some(self.trace_context).filter(|trace_context| trace_context.is_valid())
+    }
 }
 
 /// Response header used by
@@ -67,6 +76,7 @@ pub struct ResponseHeader {
     pub(crate) server_id: UniqueServerId,
     pub(crate) request_id: RequestId,
     pub(crate) number_of_elements: u64,
+    pub(crate) trace_context: TraceContext,
 }
 
 impl ResponseHeader {
@@ -95,4 +105,11 @@ impl ResponseHeader {
     pub fn number_of_elements(&self) -> u64 {
         self.number_of_elements
     }
+
+    /// Returns the [`TraceContext`] of the span in which the
+    /// [`Response`](crate::response::Response) was created. It is [`None`] when neither the
+    /// sender nor the corresponding request had an active trace.
+    pub fn trace_context(&self) -> Option<TraceContext> {
+        Some(self.trace_context).filter(|trace_context| trace_context.is_valid())
+    }
 }
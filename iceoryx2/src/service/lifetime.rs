@@ -0,0 +1,86 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Defines how long the underlying resources of a [`Service`](crate::service::Service)
+//! exist.
+//!
+//! # Example
+//!
+//! ```
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::lifetime::ServiceLifetime;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     // the service survives the last node and is removed 10 minutes after
+//!     // it became unused
+//!     .lifetime(ServiceLifetime::TimeToLive(Duration::from_secs(600).into()))
+//!     .create()?;
+//!
+//! assert_eq!(
+//!     service.lifetime().time_to_live(),
+//!     Some(Duration::from_secs(600))
+//! );
+//! # drop(service);
+//! # ipc::Service::remove_unused(
+//! #     &"My/Funk/ServiceName".try_into()?,
+//! #     iceoryx2::config::Config::global_config(),
+//! #     MessagingPattern::PublishSubscribe,
+//! # )?;
+//! # Ok(())
+//! # }
+//! ```
+
+use core::time::Duration;
+
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_posix::clock::RelocatableDuration;
+use serde::{Deserialize, Serialize};
+
+/// Defines when the resources of a [`Service`](crate::service::Service) are removed.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, ZeroCopySend, Serialize, Deserialize)]
+#[repr(C)]
+pub enum ServiceLifetime {
+    /// The [`Service`](crate::service::Service) is removed as soon as the last
+    /// [`Node`](crate::node::Node) that has it opened releases it.
+    #[default]
+    Transient,
+    /// The [`Service`](crate::service::Service) outlives all of its
+    /// [`Node`](crate::node::Node)s and exists until it is removed explicitly with
+    /// [`Service::remove_unused()`](crate::service::Service::remove_unused()).
+    Persistent,
+    /// The [`Service`](crate::service::Service) outlives all of its
+    /// [`Node`](crate::node::Node)s and is removed by the stale resource cleanup
+    /// when it was unused for longer than the provided duration.
+    TimeToLive(RelocatableDuration),
+}
+
+impl ServiceLifetime {
+    /// Returns true if the [`Service`](crate::service::Service) outlives its
+    /// [`Node`](crate::node::Node)s, otherwise false.
+    pub fn is_persistent(&self) -> bool {
+        !matches!(self, ServiceLifetime::Transient)
+    }
+
+    /// Returns the time-to-live when it was defined, otherwise [`None`].
+    pub fn time_to_live(&self) -> Option<Duration> {
+        match self {
+            ServiceLifetime::TimeToLive(v) => Some((*v).into()),
+            _ => None,
+        }
+    }
+}
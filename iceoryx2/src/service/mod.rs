@@ -220,6 +220,10 @@ pub mod port_factory;
 /// Represents the name of a [`Service`]
 pub mod service_name;
 
+/// Replaces [`ServiceName`](crate::service::service_name::ServiceName)s with `from:=to` rules
+/// when a [`Node`](crate::node::Node) creates a [`Service`]
+pub mod service_name_remapping;
+
 /// Represents the unique hash of a [`Service`]
 pub mod service_hash;
 
@@ -234,6 +238,9 @@ pub mod static_config;
 /// Represents static features of a service that can be set when a [`Service`] is created.
 pub mod attribute;
 
+/// Defines how long a [`Service`] exists after its last [`Node`](crate::node::Node) released it.
+pub mod lifetime;
+
 /// A configuration when communicating within a single process or single address space.
 pub mod local;
 
@@ -275,6 +282,7 @@ use crate::service::dynamic_config::DynamicConfig;
 use crate::service::static_config::*;
 use config_scheme::service_tag_config;
 use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_container::string::*;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::{
@@ -364,6 +372,49 @@ impl core::fmt::Display for ServiceListError {
 
 impl core::error::Error for ServiceListError {}
 
+/// Failure that can be reported by [`Service::remove_unused()`] and [`Service::remove()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceRemoveError {
+    /// The [`Service`] does not exist.
+    DoesNotExist,
+    /// The [`Service`] is still opened by at least one [`Node`](crate::node::Node).
+    InUse,
+    /// The process has insufficient permissions to remove the [`Service`].
+    InsufficientPermissions,
+    /// The [`Service`] was created with a different iceoryx2 version.
+    VersionMismatch,
+    /// Required [`Service`] resources are not available or corrupted.
+    ServiceInCorruptedState,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
+}
+
+impl core::fmt::Display for ServiceRemoveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ServiceRemoveError::{self:?}")
+    }
+}
+
+impl core::error::Error for ServiceRemoveError {}
+
+/// Defines how [`Service::remove()`] handles the [`Node`](crate::node::Node)s that have the
+/// [`Service`] still opened.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceRemovePolicy {
+    /// The dead [`Node`](crate::node::Node)s are removed from the [`Service`] before the
+    /// [`Service`] itself is removed. Fails with [`ServiceRemoveError::InUse`] when a
+    /// [`Node`](crate::node::Node) that is alive, or whose state cannot be determined, has the
+    /// [`Service`] opened.
+    #[default]
+    OnlyWithoutAliveNodes,
+    /// All [`Node`](crate::node::Node)s are removed from the [`Service`], even the alive ones.
+    /// The ports of alive [`Node`](crate::node::Node)s lose all their connections and the
+    /// [`Service`] can no longer be discovered or opened. They can create the [`Service`] again
+    /// only after they dropped all handles to the removed one. Intended to recover a development
+    /// system without removing all iceoryx2 resources.
+    Force,
+}
+
 /// Represents all the [`Service`] information that one can acquire with [`Service::list()`]
 /// when the [`Service`] is accessible by the current process.
 #[derive(Debug, Clone)]
@@ -514,15 +565,14 @@ impl<S: Service, R: ServiceResource> Drop for ServiceState<S, R> {
 pub mod internal {
     use builder::event::EventOpenError;
     use dynamic_config::PortCleanupAction;
-    use iceoryx2_bb_container::string::*;
-    use iceoryx2_log::error;
     use port_factory::PortFactory;
 
     use crate::{
         identifiers::{UniqueNodeId, UniquePortId},
-        node::NodeBuilder,
+        node::{Node, NodeBuilder, NodeCreationFailure},
         port::{listener::remove_connection_of_listener, notifier::Notifier},
         prelude::EventId,
+        service::naming_scheme::subscriber_wakeup_service_name,
         service::stale_resource_cleanup::{
             remove_data_segment_of_port, remove_receiver_port_from_all_connections,
             remove_sender_port_from_all_connections,
@@ -534,6 +584,70 @@ pub mod internal {
     #[derive(Debug)]
     struct CleanupFailure;
 
+    fn create_signal_node<S: Service>(
+        config: &config::Config,
+    ) -> Result<Node<S>, NodeCreationFailure> {
+        let mut config = config.clone();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        config.global.node.cleanup_dead_nodes_on_destruction = false;
+
+        NodeBuilder::new().config(&config).create::<S>()
+    }
+
+    fn send_subscriber_wakeup_signal<S: Service>(
+        service_hash: &ServiceHash,
+        config: &config::Config,
+    ) {
+        let origin = "send_subscriber_wakeup_signal()";
+        let service_name = subscriber_wakeup_service_name(service_hash);
+
+        let node = match create_signal_node::<S>(config) {
+            Ok(node) => node,
+            Err(e) => {
+                warn!(from origin,
+                                "Unable to create node to wake up the subscribers of the service {:?} due to ({:?}).",
+                                service_hash, e);
+                return;
+            }
+        };
+
+        let service = match node.service_builder(&service_name).event().open() {
+            Ok(service) => service,
+            Err(EventOpenError::DoesNotExist) => return,
+            Err(e) => {
+                warn!(from origin,
+                                "Unable to open event service to wake up the subscribers of the service {:?} due to ({:?}).",
+                                service_hash, e);
+                return;
+            }
+        };
+
+        if service.dynamic_config().number_of_listeners() == 0 {
+            return;
+        }
+
+        let notifier = match Notifier::new_without_auto_event_emission(
+            service.service,
+            EventId::new(0),
+        ) {
+            Ok(notifier) => notifier,
+            Err(e) => {
+                warn!(from origin,
+                                "Unable to create notifier to wake up the subscribers of the service {:?} due to ({:?})",
+                                service_hash, e);
+                return;
+            }
+        };
+
+        if let Err(e) = notifier.notify() {
+            warn!(from origin,
+                            "Unable to wake up the subscribers of the service {:?} due to ({:?})",
+                            service_hash, e);
+        }
+
+        trace!(from origin, "Woke up the subscribers of the service {:?}.", service_hash);
+    }
+
     fn send_dead_node_signal<S: Service>(service_hash: &ServiceHash, config: &config::Config) {
         let origin = "send_dead_node_signal()";
 
@@ -550,11 +664,7 @@ pub mod internal {
 
         let service_name = service_details.static_details.name();
 
-        let mut config = config.clone();
-        config.global.node.cleanup_dead_nodes_on_creation = false;
-        config.global.node.cleanup_dead_nodes_on_destruction = false;
-
-        let node = match NodeBuilder::new().config(&config).create::<S>() {
+        let node = match create_signal_node::<S>(config) {
             Ok(node) => node,
             Err(e) => {
                 warn!(from origin,
@@ -646,56 +756,6 @@ pub mod internal {
         Ok(())
     }
 
-    fn remove_additional_blackboard_resources<S: Service>(
-        config: &config::Config,
-        blackboard_name: &FileName,
-        blackboard_payload_config: &<S::BlackboardPayload as NamedConceptMgmt>::Configuration,
-        blackboard_mgmt_name: &StaticString<MAX_TYPE_NAME_LENGTH>,
-        origin: &str,
-        msg: &str,
-    ) {
-        match unsafe {
-            <S::BlackboardPayload as NamedConceptMgmt>::remove_cfg(
-                blackboard_name,
-                blackboard_payload_config,
-            )
-        } {
-            Ok(true) => {
-                trace!(from origin, "Remove blackboard payload segment.");
-            }
-            _ => {
-                error!(from origin,
-                                  "{} since the blackboard payload segment cannot be removed - service seems to be in a corrupted state.", msg);
-            }
-        }
-
-        // u64 is just a placeholder needed for the DynamicStorageConfiguration; it is
-        // overwritten right below
-        let mut blackboard_mgmt_config =
-            crate::service::config_scheme::blackboard_mgmt_config::<S, u64>(config);
-        // Safe since the same type name is set when creating the BlackboardMgmt in
-        // Creator::create_impl so we can safely remove the concept.
-        unsafe {
-            <S::BlackboardMgmt<u64> as DynamicStorage<u64>>::__internal_set_type_name_in_config(
-                &mut blackboard_mgmt_config,
-                blackboard_mgmt_name.as_str(),
-            )
-        };
-        match unsafe {
-            <S::BlackboardMgmt<u64> as NamedConceptMgmt>::remove_cfg(
-                blackboard_name,
-                &blackboard_mgmt_config,
-            )
-        } {
-            Ok(true) => {
-                trace!(from origin, "Remove blackboard mgmt segment.");
-            }
-            _ => {
-                error!(from origin, "{} since the blackboard mgmt segment cannot be removed - service seems to be in a corrupted state.", msg);
-            }
-        }
-    }
-
     pub trait ServiceInternal<S: Service> {
         fn __internal_remove_node_from_service(
             node_id: &UniqueNodeId,
@@ -724,6 +784,7 @@ pub mod internal {
             };
 
             let mut number_of_dead_node_notifications = 0;
+            let mut number_of_dead_publishers = 0;
             let cleanup_port_resources = |port_id| {
                 match port_id {
                     UniquePortId::Publisher(ref id) => {
@@ -737,6 +798,7 @@ pub mod internal {
                         {
                             return PortCleanupAction::SkipPort;
                         }
+                        number_of_dead_publishers += 1;
                     }
                     UniquePortId::Subscriber(ref id) => {
                         if let Err(e) = unsafe {
@@ -797,62 +859,26 @@ pub mod internal {
             };
 
             if remove_service {
-                // check if service was a blackboard service to remove its additional resources
-                let blackboard_name =
-                    crate::service::naming_scheme::blackboard_name(service_hash.as_str());
-                let blackboard_payload_config =
-                    crate::service::config_scheme::blackboard_data_config::<S>(config);
-                let blackboard_payload = <S::BlackboardPayload as NamedConceptMgmt>::does_exist_cfg(
-                    &blackboard_name,
-                    &blackboard_payload_config,
-                );
-                let mut is_blackboard = false;
-                let mut blackboard_mgmt_name = StaticString::<MAX_TYPE_NAME_LENGTH>::new();
-                if let Ok(true) = blackboard_payload {
-                    is_blackboard = true;
-
-                    let details = match __internal_details::<S>(config, &service_hash.0.into()) {
-                        Ok(Some(d)) => d,
-                        _ => {
-                            fail!(from origin,
-                                  with ServiceRemoveNodeError::ServiceInCorruptedState,
-                                  "{} due to a failure while acquiring the service details.", msg);
-                        }
-                    };
-                    blackboard_mgmt_name =
-                        details.static_details.blackboard().type_details.type_name;
-                }
-
-                match unsafe {
-                    // IMPORTANT: The static service config must be removed first. If it cannot be
-                    // removed, the process may lack sufficient permissions and should not remove
-                    // any other resources.
-                    remove_static_service_config::<S>(config, &service_hash.0.into())
+                if let Err(ServiceResourcesRemoveError::ServiceInCorruptedState) = unsafe {
+                    remove_service_resources::<S>(
+                        &dynamic_config,
+                        service_hash,
+                        config,
+                        &origin,
+                        msg,
+                    )
                 } {
-                    Ok(_) => {
-                        trace!(from origin, "Remove unused service.");
-
-                        // remove additional blackboard resources
-                        if is_blackboard {
-                            remove_additional_blackboard_resources::<S>(
-                                config,
-                                &blackboard_name,
-                                &blackboard_payload_config,
-                                &blackboard_mgmt_name,
-                                &origin,
-                                msg,
-                            );
-                        }
+                    return Err(ServiceRemoveNodeError::ServiceInCorruptedState);
+                }
+            } else {
+                if number_of_dead_node_notifications != 0 {
+                    send_dead_node_signal::<S>(service_hash, config);
+                }
 
-                        dynamic_config.acquire_ownership()
-                    }
-                    Err(e) => {
-                        error!(from origin, "Unable to remove static config of unused service ({:?}).",
-                            e);
-                    }
+                // subscribers attached to a waitset shall detect the lost publishers
+                if number_of_dead_publishers != 0 {
+                    send_subscriber_wakeup_signal::<S>(service_hash, config);
                 }
-            } else if number_of_dead_node_notifications != 0 {
-                send_dead_node_signal::<S>(service_hash, config);
             }
 
             Ok(())
@@ -1032,6 +1058,82 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> + Clone {
 
         Ok(())
     }
+
+    /// Removes a [`Service`] that is not opened by any [`Node`](crate::node::Node). This is
+    /// intended for [`Service`]s with a persistent
+    /// [`ServiceLifetime`](crate::service::lifetime::ServiceLifetime) that outlive their
+    /// [`Node`](crate::node::Node)s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    /// use iceoryx2::service::lifetime::ServiceLifetime;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let name = ServiceName::new("Some/Persistent/Name")?;
+    /// {
+    ///     let node = NodeBuilder::new().create::<ipc::Service>()?;
+    ///     let _service = node.service_builder(&name)
+    ///         .event()
+    ///         .lifetime(ServiceLifetime::Persistent)
+    ///         .create()?;
+    /// }
+    ///
+    /// // the service still exists after the node is gone
+    /// ipc::Service::remove_unused(&name, Config::global_config(), MessagingPattern::Event)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn remove_unused(
+        service_name: &ServiceName,
+        config: &config::Config,
+        messaging_pattern: MessagingPattern,
+    ) -> Result<(), ServiceRemoveError> {
+        let service_hash =
+            ServiceHash::new::<Self::ServiceNameHasher>(service_name, messaging_pattern);
+        stale_resource_cleanup::remove_unused_service::<Self>(&service_hash, config)
+    }
+
+    /// Removes a [`Service`] together with the stale resources of the
+    /// [`Node`](crate::node::Node)s that have it opened. The
+    /// [`ServiceRemovePolicy`] defines if the [`Service`] is also removed when it is opened by
+    /// alive [`Node`](crate::node::Node)s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    /// use iceoryx2::service::{ServiceRemoveError, ServiceRemovePolicy};
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let name = ServiceName::new("Some/Service/Of/A/Killed/Process")?;
+    ///
+    /// match ipc::Service::remove(
+    ///     &name,
+    ///     Config::global_config(),
+    ///     MessagingPattern::Event,
+    ///     ServiceRemovePolicy::OnlyWithoutAliveNodes,
+    /// ) {
+    ///     Ok(()) => println!("removed {name}"),
+    ///     Err(ServiceRemoveError::DoesNotExist) => println!("{name} does not exist"),
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn remove(
+        service_name: &ServiceName,
+        config: &config::Config,
+        messaging_pattern: MessagingPattern,
+        policy: ServiceRemovePolicy,
+    ) -> Result<(), ServiceRemoveError> {
+        let service_hash =
+            ServiceHash::new::<Self::ServiceNameHasher>(service_name, messaging_pattern);
+        stale_resource_cleanup::remove_service::<Self>(&service_hash, config, policy)
+    }
 }
 
 pub(crate) unsafe fn remove_static_service_config<S: Service>(
@@ -1052,6 +1154,132 @@ pub(crate) unsafe fn remove_static_service_config<S: Service>(
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ServiceResourcesRemoveError {
+    ServiceInCorruptedState,
+    StaticConfig(NamedConceptRemoveError),
+}
+
+/// Removes all resources of a [`Service`] that can no longer be opened, since its
+/// [`DynamicConfig`] was locked after the last [`Node`](crate::node::Node) left.
+///
+/// # Safety
+///
+///  * The [`DynamicConfig`] must be locked so that no [`Node`](crate::node::Node) can
+///    register itself at the [`Service`] anymore.
+pub(crate) unsafe fn remove_service_resources<S: Service>(
+    dynamic_config: &S::DynamicStorage<DynamicConfig>,
+    service_hash: &ServiceHash,
+    config: &config::Config,
+    origin: &str,
+    msg: &str,
+) -> Result<(), ServiceResourcesRemoveError> {
+    // check if service was a blackboard service to remove its additional resources
+    let blackboard_name = crate::service::naming_scheme::blackboard_name(service_hash.as_str());
+    let blackboard_payload_config =
+        crate::service::config_scheme::blackboard_data_config::<S>(config);
+    let blackboard_payload = <S::BlackboardPayload as NamedConceptMgmt>::does_exist_cfg(
+        &blackboard_name,
+        &blackboard_payload_config,
+    );
+    let mut is_blackboard = false;
+    let mut blackboard_mgmt_name = StaticString::<MAX_TYPE_NAME_LENGTH>::new();
+    if let Ok(true) = blackboard_payload {
+        is_blackboard = true;
+
+        let details = match __internal_details::<S>(config, &service_hash.0.into()) {
+            Ok(Some(d)) => d,
+            _ => {
+                fail!(from origin,
+                      with ServiceResourcesRemoveError::ServiceInCorruptedState,
+                      "{} due to a failure while acquiring the service details.", msg);
+            }
+        };
+        blackboard_mgmt_name = details.static_details.blackboard().type_details.type_name;
+    }
+
+    match unsafe {
+        // IMPORTANT: The static service config must be removed first. If it cannot be
+        // removed, the process may lack sufficient permissions and should not remove
+        // any other resources.
+        remove_static_service_config::<S>(config, &service_hash.0.into())
+    } {
+        Ok(_) => {
+            trace!(from origin, "Remove unused service.");
+
+            // remove additional blackboard resources
+            if is_blackboard {
+                remove_additional_blackboard_resources::<S>(
+                    config,
+                    &blackboard_name,
+                    &blackboard_payload_config,
+                    &blackboard_mgmt_name,
+                    origin,
+                    msg,
+                );
+            }
+
+            dynamic_config.acquire_ownership();
+            Ok(())
+        }
+        Err(e) => {
+            error!(from origin, "Unable to remove static config of unused service ({:?}).",
+                e);
+            Err(ServiceResourcesRemoveError::StaticConfig(e))
+        }
+    }
+}
+
+fn remove_additional_blackboard_resources<S: Service>(
+    config: &config::Config,
+    blackboard_name: &FileName,
+    blackboard_payload_config: &<S::BlackboardPayload as NamedConceptMgmt>::Configuration,
+    blackboard_mgmt_name: &StaticString<MAX_TYPE_NAME_LENGTH>,
+    origin: &str,
+    msg: &str,
+) {
+    match unsafe {
+        <S::BlackboardPayload as NamedConceptMgmt>::remove_cfg(
+            blackboard_name,
+            blackboard_payload_config,
+        )
+    } {
+        Ok(true) => {
+            trace!(from origin, "Remove blackboard payload segment.");
+        }
+        _ => {
+            error!(from origin,
+                              "{} since the blackboard payload segment cannot be removed - service seems to be in a corrupted state.", msg);
+        }
+    }
+
+    // u64 is just a placeholder needed for the DynamicStorageConfiguration; it is
+    // overwritten right below
+    let mut blackboard_mgmt_config =
+        crate::service::config_scheme::blackboard_mgmt_config::<S, u64>(config);
+    // Safe since the same type name is set when creating the BlackboardMgmt in
+    // Creator::create_impl so we can safely remove the concept.
+    unsafe {
+        <S::BlackboardMgmt<u64> as DynamicStorage<u64>>::__internal_set_type_name_in_config(
+            &mut blackboard_mgmt_config,
+            blackboard_mgmt_name.as_str(),
+        )
+    };
+    match unsafe {
+        <S::BlackboardMgmt<u64> as NamedConceptMgmt>::remove_cfg(
+            blackboard_name,
+            &blackboard_mgmt_config,
+        )
+    } {
+        Ok(true) => {
+            trace!(from origin, "Remove blackboard mgmt segment.");
+        }
+        _ => {
+            error!(from origin, "{} since the blackboard mgmt segment cannot be removed - service seems to be in a corrupted state.", msg);
+        }
+    }
+}
+
 #[doc(hidden)]
 pub fn __internal_details<S: Service>(
     config: &config::Config,
@@ -1152,7 +1380,7 @@ fn open_dynamic_config<S: Service>(
                 fail!(from origin, with ServiceDetailsError::VersionMismatch,
                     "{} since there is a version mismatch. Please use the same iceoryx2 version for the whole system.", msg);
             }
-            Err(DynamicStorageOpenError::InternalError) => {
+            Err(DynamicStorageOpenError::InternalError | DynamicStorageOpenError::InsufficientResources) => {
                 fail!(from origin, with ServiceDetailsError::InternalError,
                     "{} due to an internal failure while opening the services dynamic config.", msg);
             }
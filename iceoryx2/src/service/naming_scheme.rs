@@ -10,6 +10,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use alloc::format;
 use alloc::string::ToString;
 
 use iceoryx2_bb_container::semantic_string::SemanticString;
@@ -17,6 +18,8 @@ use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_log::fatal_panic;
 
 use crate::identifiers::UniqueListenerId;
+use crate::service::service_hash::ServiceHash;
+use crate::service::service_name::ServiceName;
 
 pub(crate) fn event_concept_name(listener_id: &UniqueListenerId) -> FileName {
     let msg = "The system does not support the required file name length for the listeners event concept name.";
@@ -55,9 +58,22 @@ pub(crate) fn data_segment_name(port_id_value: u128) -> FileName {
                  "{}", msg)
 }
 
+pub(crate) fn receive_segment_name(sender_port_id: u128, receiver_port_id: u128) -> FileName {
+    connection_name(sender_port_id, receiver_port_id)
+}
+
 pub(crate) fn blackboard_name(service_hash: &str) -> FileName {
     let msg = "The system does not support the required file name length for the blackboard's management segment.";
     let origin = "blackboard_name()";
 
     fatal_panic!(from origin, when FileName::new(service_hash.as_bytes()), "{}", msg)
 }
+
+pub(crate) fn subscriber_wakeup_service_name(service_hash: &ServiceHash) -> ServiceName {
+    let msg = "The system does not support the required service name length for the subscriber wakeup service.";
+    let origin = "subscriber_wakeup_service_name()";
+
+    fatal_panic!(from origin,
+                 when ServiceName::__internal_new_prefixed(&format!("subscriber-wakeup/{}", service_hash.as_str())),
+                 "{}", msg)
+}
@@ -48,6 +48,7 @@ use crate::node::NodeListFailure;
 use crate::service::attribute::AttributeSet;
 use crate::service::builder::CustomKeyMarker;
 use crate::service::builder::blackboard::{BlackboardResources, KeyMemory};
+use crate::service::lifetime::ServiceLifetime;
 use crate::service::port_factory::blocking_cleanup_dead_nodes_in_service;
 use crate::service::service_hash::ServiceHash;
 use crate::service::service_name::ServiceName;
@@ -111,6 +112,10 @@ impl<
         self.service.static_config().attributes()
     }
 
+    fn lifetime(&self) -> &ServiceLifetime {
+        self.service.static_config().lifetime()
+    }
+
     fn static_config(&self) -> &static_config::blackboard::StaticConfig {
         self.service.static_config().blackboard()
     }
@@ -45,6 +45,7 @@ use super::notifier::PortFactoryNotifier;
 use crate::identifiers::UniqueServiceId;
 use crate::node::NodeListFailure;
 use crate::service::attribute::AttributeSet;
+use crate::service::lifetime::ServiceLifetime;
 use crate::service::port_factory::blocking_cleanup_dead_nodes_in_service;
 use crate::service::service_hash::ServiceHash;
 use crate::service::{self, NoResource, ServiceState, SharedServiceState, static_config};
@@ -94,6 +95,10 @@ impl<Service: service::Service> crate::service::port_factory::PortFactory for Po
         self.service.static_config().attributes()
     }
 
+    fn lifetime(&self) -> &ServiceLifetime {
+        self.service.static_config().lifetime()
+    }
+
     fn static_config(&self) -> &static_config::event::StaticConfig {
         self.service.static_config().event()
     }
@@ -21,6 +21,7 @@ use iceoryx2_log::{debug, warn};
 use crate::config::Config;
 use crate::identifiers::UniqueServiceId;
 use crate::node::{CleanupState, NodeListFailure, NodeState, NodeView};
+use crate::service::lifetime::ServiceLifetime;
 use crate::service::service_hash::ServiceHash;
 
 use super::dynamic_config::DynamicConfig;
@@ -90,6 +91,9 @@ pub trait PortFactory: Debug + Abandonable {
     /// Returns the attributes defined in the [`crate::service::Service`]
     fn attributes(&self) -> &AttributeSet;
 
+    /// Returns the [`ServiceLifetime`] of the [`crate::service::Service`]
+    fn lifetime(&self) -> &ServiceLifetime;
+
     /// Returns the StaticConfig of the [`crate::service::Service`].
     /// Contains all settings that never change during the lifetime of the service.
     fn static_config(&self) -> &Self::StaticConfig;
@@ -47,6 +47,7 @@ use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
 use crate::identifiers::UniqueServiceId;
 use crate::node::NodeListFailure;
 use crate::service::attribute::AttributeSet;
+use crate::service::lifetime::ServiceLifetime;
 use crate::service::port_factory::blocking_cleanup_dead_nodes_in_service;
 use crate::service::service_hash::ServiceHash;
 use crate::service::service_name::ServiceName;
@@ -130,6 +131,10 @@ impl<
         self.service.static_config().attributes()
     }
 
+    fn lifetime(&self) -> &ServiceLifetime {
+        self.service.static_config().lifetime()
+    }
+
     fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
         self.service.static_config().publish_subscribe()
     }
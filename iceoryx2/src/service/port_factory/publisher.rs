@@ -100,6 +100,10 @@ pub(crate) struct LocalPublisherConfig {
     pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
     pub(crate) initial_max_slice_len: usize,
     pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) priority: u8,
+    pub(crate) is_memory_locked: bool,
+    pub(crate) prefault: bool,
+    pub(crate) use_huge_pages: bool,
 }
 
 /// Factory to create a new [`Publisher`] port/endpoint for
@@ -169,6 +173,10 @@ impl<
                 initial_max_slice_len: 1,
                 max_loaned_samples: defaults.publisher_max_loaned_samples,
                 unable_to_deliver_strategy: defaults.unable_to_deliver_strategy,
+                priority: 0,
+                is_memory_locked: defaults.publisher_is_memory_locked,
+                prefault: defaults.publisher_prefault,
+                use_huge_pages: defaults.publisher_use_huge_pages,
             },
             degradation_handler: DegradationHandler::new_with(DegradationAction::Warn),
             unable_to_deliver_handler: None,
@@ -212,6 +220,38 @@ impl<
         self
     }
 
+    /// Sets the default priority of all [`SampleMut`](crate::sample_mut::SampleMut)s that are
+    /// loaned by the [`Publisher`]. It can be overridden for a specific sample with
+    /// [`SampleMut::set_priority()`](crate::sample_mut::SampleMut::set_priority()).
+    /// If the priority exceeds the number of priorities of the
+    /// [`Service`](crate::service::Service), it is reduced to the highest supported priority.
+    pub fn priority(mut self, value: u8) -> Self {
+        self.config.priority = value;
+        self
+    }
+
+    /// Locks the data segment of the [`Publisher`] into the RAM so that it is never
+    /// swapped out. If the memory lock limit of the process is exceeded, the creation fails
+    /// with [`PublisherCreateError::InsufficientResources`].
+    pub fn is_memory_locked(mut self, value: bool) -> Self {
+        self.config.is_memory_locked = value;
+        self
+    }
+
+    /// Touches every page of the data segment of the [`Publisher`] when it is created so that
+    /// the first [`SampleMut`](crate::sample_mut::SampleMut)s do not cause page faults.
+    pub fn prefault(mut self, value: bool) -> Self {
+        self.config.prefault = value;
+        self
+    }
+
+    /// Requests huge pages for the data segment of the [`Publisher`]. If the platform or the
+    /// underlying shared memory does not support huge pages, it falls back to regular pages.
+    pub fn use_huge_pages(mut self, value: bool) -> Self {
+        self.config.use_huge_pages = value;
+        self
+    }
+
     /// Sets the [`DegradationHandler`] of the [`Publisher`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this handler
     /// is called and depending on the returned [`DegradationAction`] measures will be taken.
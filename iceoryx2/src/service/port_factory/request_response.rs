@@ -41,6 +41,7 @@
 extern crate alloc;
 
 use super::{client::PortFactoryClient, nodes, server::PortFactoryServer};
+use crate::service::lifetime::ServiceLifetime;
 use crate::{
     identifiers::UniqueServiceId,
     node::NodeListFailure,
@@ -163,6 +164,10 @@ impl<
         self.service.static_config().attributes()
     }
 
+    fn lifetime(&self) -> &ServiceLifetime {
+        self.service.static_config().lifetime()
+    }
+
     fn static_config(&self) -> &Self::StaticConfig {
         self.service.static_config().request_response()
     }
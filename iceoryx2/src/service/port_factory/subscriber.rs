@@ -38,7 +38,7 @@ use iceoryx2_log::fail;
 use crate::{
     port::{
         DegradationAction, DegradationFn, DegradationHandler,
-        subscriber::{Subscriber, SubscriberCreateError},
+        subscriber::{Subscriber, SubscriberCreateError, WakeupSubscriber},
     },
     service,
 };
@@ -111,8 +111,10 @@ impl<
     }
 
     /// Sets the [`DegradationHandler`] of the [`Subscriber`]. Whenever a connection to a
-    /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this handler
-    /// is called and depending on the returned [`DegradationAction`] measures will be taken.
+    /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, the deadline
+    /// of the [`Service`](crate::service::Service) was missed or the node of a
+    /// [`crate::port::publisher::Publisher`] died, this handler is called and depending on the
+    /// returned [`DegradationAction`] measures will be taken.
     pub fn set_degradation_handler<F: DegradationFn + 'static>(mut self, handler: F) -> Self {
         self.config.degradation_handler = DegradationHandler::new(handler);
 
@@ -129,4 +131,27 @@ impl<
                 "Failed to create new Subscriber port."),
         )
     }
+
+    /// Creates a new [`WakeupSubscriber`] that can be attached directly to a
+    /// [`WaitSet`](crate::waitset::WaitSet) or returns a [`SubscriberCreateError`] on failure.
+    /// Fails with [`SubscriberCreateError::SubscriberWakeupNotEnabled`] when the
+    /// [`Service`](crate::service::Service) was created without
+    /// [`Builder::enable_subscriber_wakeup()`](crate::service::builder::publish_subscribe::Builder::enable_subscriber_wakeup()).
+    pub fn create_with_wakeup(
+        self,
+    ) -> Result<WakeupSubscriber<Service, PayloadType, UserHeader>, SubscriberCreateError> {
+        let origin = format!("{self:?}");
+        if !self
+            .factory
+            .service
+            .static_config()
+            .publish_subscribe()
+            .has_subscriber_wakeup()
+        {
+            fail!(from origin, with SubscriberCreateError::SubscriberWakeupNotEnabled,
+                "Failed to create new Subscriber port with wakeup since the service was created without subscriber wakeup.");
+        }
+
+        WakeupSubscriber::new(self.create()?)
+    }
 }
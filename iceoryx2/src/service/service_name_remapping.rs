@@ -0,0 +1,183 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::service_name_remapping::ServiceNameRemapping;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new()
+//!     .service_name_remapping(&ServiceNameRemapping::try_from("camera:=rig_1/camera")?)
+//!     .create::<ipc::Service>()?;
+//!
+//! // creates the service "rig_1/camera"
+//! let service = node.service_builder(&"camera".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! assert_eq!(service.name().as_str(), "rig_1/camera");
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Serialize, de::Visitor};
+
+use crate::service::service_name::ServiceName;
+
+/// The environment variable that contains the [`ServiceNameRemapping`]s of all
+/// [`Node`](crate::node::Node)s of the process. The rules are separated by whitespace,
+/// e.g. `IOX2_SERVICE_NAME_REMAPPINGS="camera:=rig_1/camera lidar:=rig_1/lidar"`.
+pub const SERVICE_NAME_REMAPPINGS_ENV_VAR: &str = "IOX2_SERVICE_NAME_REMAPPINGS";
+
+/// Separates the original from the remapped [`ServiceName`] in a rule.
+pub const SERVICE_NAME_REMAPPING_SEPARATOR: &str = ":=";
+
+/// Errors that can occur when a [`ServiceNameRemapping`] is created from a `from:=to` rule.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServiceNameRemappingError {
+    /// The rule does not contain the separator `:=`.
+    MissingSeparator,
+    /// The original or the remapped name is not a valid [`ServiceName`].
+    InvalidServiceName,
+}
+
+impl core::fmt::Display for ServiceNameRemappingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ServiceNameRemappingError::{self:?}")
+    }
+}
+
+impl core::error::Error for ServiceNameRemappingError {}
+
+/// Replaces a [`ServiceName`] with another one whenever a
+/// [`Node`](crate::node::Node) creates a
+/// [`ServiceBuilder`](crate::service::builder::Builder). It allows to reuse the same binary
+/// in multiple contexts without changing the service names in code.
+///
+/// A rule has the form `from:=to` and matches only the exact [`ServiceName`] `from`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ServiceNameRemapping {
+    from: ServiceName,
+    to: ServiceName,
+}
+
+impl ServiceNameRemapping {
+    /// Creates a new [`ServiceNameRemapping`] that replaces `from` with `to`.
+    pub fn new(from: &ServiceName, to: &ServiceName) -> Self {
+        Self {
+            from: *from,
+            to: *to,
+        }
+    }
+
+    /// Returns the original [`ServiceName`] that is replaced.
+    pub fn source(&self) -> &ServiceName {
+        &self.from
+    }
+
+    /// Returns the [`ServiceName`] that replaces the original one.
+    pub fn target(&self) -> &ServiceName {
+        &self.to
+    }
+
+    /// Returns the remapped [`ServiceName`] when the rule matches `name`, otherwise
+    /// [`None`].
+    pub fn apply(&self, name: &ServiceName) -> Option<ServiceName> {
+        (self.from == *name).then_some(self.to)
+    }
+
+    /// Creates the [`ServiceNameRemapping`]s from a list of whitespace separated rules, like
+    /// the content of [`SERVICE_NAME_REMAPPINGS_ENV_VAR`].
+    pub fn from_list(rules: &str) -> Result<Vec<Self>, ServiceNameRemappingError> {
+        rules.split_whitespace().map(Self::try_from).collect()
+    }
+}
+
+/// Applies the first matching rule of `remappings` to `name`. When no rule matches, `name` is
+/// returned unchanged.
+pub(crate) fn remap(remappings: &[ServiceNameRemapping], name: &ServiceName) -> ServiceName {
+    remappings
+        .iter()
+        .find_map(|remapping| remapping.apply(name))
+        .unwrap_or(*name)
+}
+
+impl TryFrom<&str> for ServiceNameRemapping {
+    type Error = ServiceNameRemappingError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (from, to) = value
+            .split_once(SERVICE_NAME_REMAPPING_SEPARATOR)
+            .ok_or(ServiceNameRemappingError::MissingSeparator)?;
+
+        let from =
+            ServiceName::new(from).map_err(|_| ServiceNameRemappingError::InvalidServiceName)?;
+        let to = ServiceName::new(to).map_err(|_| ServiceNameRemappingError::InvalidServiceName)?;
+
+        Ok(Self { from, to })
+    }
+}
+
+impl core::fmt::Display for ServiceNameRemapping {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}{}{}",
+            self.from, SERVICE_NAME_REMAPPING_SEPARATOR, self.to
+        )
+    }
+}
+
+struct ServiceNameRemappingVisitor;
+
+impl Visitor<'_> for ServiceNameRemappingVisitor {
+    type Value = ServiceNameRemapping;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a string containing a service name remapping of the form from:=to")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match ServiceNameRemapping::try_from(v) {
+            Ok(v) => Ok(v),
+            Err(e) => Err(E::custom(format!(
+                "invalid service name remapping provided {e:?}."
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ServiceNameRemapping {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ServiceNameRemappingVisitor)
+    }
+}
+
+impl Serialize for ServiceNameRemapping {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
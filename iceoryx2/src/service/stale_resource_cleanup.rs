@@ -13,17 +13,28 @@
 use alloc::format;
 use alloc::vec::Vec;
 
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
 use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::event::NamedConceptMgmt;
 use iceoryx2_cal::named_concept::NamedConceptListError;
 use iceoryx2_cal::named_concept::NamedConceptRemoveError;
 use iceoryx2_cal::zero_copy_connection::{ZeroCopyConnection, ZeroCopyPortRemoveError};
-use iceoryx2_log::fail;
+use iceoryx2_log::{debug, fail, trace};
 
 use crate::config;
+use crate::identifiers::UniqueNodeId;
+use crate::node::NodeState;
 use crate::service;
 use crate::service::config_scheme::{data_segment_config, resizable_data_segment_config};
-use crate::service::naming_scheme::data_segment_name;
+use crate::service::naming_scheme::{data_segment_name, receive_segment_name};
+use crate::service::service_hash::ServiceHash;
+use crate::service::{
+    ServiceDetailsError, ServiceRemoveError, ServiceRemoveNodeError, ServiceRemovePolicy,
+    ServiceRemoveTagError, ServiceResourcesRemoveError, open_dynamic_config,
+    remove_service_resources, remove_service_tag,
+};
 
 use super::config_scheme::connection_config;
 use super::naming_scheme::extract_receiver_port_id_from_connection;
@@ -108,6 +119,50 @@ fn handle_port_remove_error(
     }
 }
 
+unsafe fn remove_receive_segment<Service: service::Service>(
+    sender_port_id: u128,
+    receiver_port_id: u128,
+    config: &config::Config,
+) -> Result<(), RemovePortFromAllConnectionsError> {
+    let origin = "remove_receive_segment()";
+    let segment_name = receive_segment_name(sender_port_id, receiver_port_id);
+    let msg = format!("Unable to remove the receive segment {segment_name:?}");
+
+    let to_error = |e| match e {
+        NamedConceptRemoveError::InsufficientPermissions => {
+            RemovePortFromAllConnectionsError::InsufficientPermissions
+        }
+        NamedConceptRemoveError::InternalError => RemovePortFromAllConnectionsError::InternalError,
+    };
+
+    unsafe {
+        fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
+                &segment_name,
+                &data_segment_config::<Service>(config),
+            ).map_err(to_error), "{msg}.");
+    }
+
+    // removing a resizable segment requires to list all of its underlying segments, therefore
+    // it is only removed when it exists
+    let resizable_config = resizable_data_segment_config::<Service>(config);
+    let does_exist = fail!(from origin,
+            when <Service::ResizableSharedMemory as NamedConceptMgmt>::does_exist_cfg(
+                &segment_name, &resizable_config),
+            with RemovePortFromAllConnectionsError::InternalError,
+            "{msg} since its existence could not be verified.");
+
+    if does_exist {
+        unsafe {
+            fail!(from origin, when <Service::ResizableSharedMemory as NamedConceptMgmt>::remove_cfg(
+                    &segment_name,
+                    &resizable_config,
+                ).map_err(to_error), "{msg}.");
+        }
+    }
+
+    Ok(())
+}
+
 pub(crate) unsafe fn remove_sender_port_from_all_connections<Service: service::Service>(
     port_id: u128,
     config: &config::Config,
@@ -136,6 +191,19 @@ pub(crate) unsafe fn remove_sender_port_from_all_connections<Service: service::S
                 if ret_val.is_ok() {
                     ret_val = result;
                 }
+
+                // a sender that copies on send owns a receive segment for every connection
+                if let Some(receiver_port_id) =
+                    extract_receiver_port_id_from_connection(&connection)
+                {
+                    let result = unsafe {
+                        remove_receive_segment::<Service>(port_id, receiver_port_id, config)
+                    };
+
+                    if ret_val.is_ok() {
+                        ret_val = result;
+                    }
+                }
             }
         }
     }
@@ -179,3 +247,203 @@ pub(crate) unsafe fn remove_receiver_port_from_all_connections<Service: service:
 
     ret_val
 }
+
+/// Removes the [`Service`](crate::service::Service) when no [`Node`](crate::node::Node) has it
+/// opened anymore.
+pub(crate) fn remove_unused_service<Service: service::Service>(
+    service_hash: &ServiceHash,
+    config: &config::Config,
+) -> Result<(), ServiceRemoveError> {
+    let origin = format!(
+        "remove_unused_service::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        service_hash
+    );
+    let msg = "Unable to remove unused service";
+
+    let dynamic_config = match open_dynamic_config::<Service>(config, service_hash) {
+        Ok(Some(c)) => c,
+        Ok(None) => {
+            fail!(from origin, with ServiceRemoveError::DoesNotExist,
+                "{} since the service does not exist.", msg);
+        }
+        Err(ServiceDetailsError::VersionMismatch) => {
+            fail!(from origin, with ServiceRemoveError::VersionMismatch,
+                "{} since the service version does not match.", msg);
+        }
+        Err(e) => {
+            fail!(from origin, with ServiceRemoveError::InternalError,
+                "{} due to an internal failure while opening the dynamic config ({:?}).", msg, e);
+        }
+    };
+
+    if !dynamic_config.get().mark_for_destruction_if_unused() {
+        fail!(from origin, with ServiceRemoveError::InUse,
+            "{} since the service is still in use.", msg);
+    }
+
+    match unsafe {
+        remove_service_resources::<Service>(&dynamic_config, service_hash, config, &origin, msg)
+    } {
+        Ok(()) => Ok(()),
+        Err(ServiceResourcesRemoveError::ServiceInCorruptedState) => {
+            Err(ServiceRemoveError::ServiceInCorruptedState)
+        }
+        Err(ServiceResourcesRemoveError::StaticConfig(
+            NamedConceptRemoveError::InsufficientPermissions,
+        )) => Err(ServiceRemoveError::InsufficientPermissions),
+        Err(ServiceResourcesRemoveError::StaticConfig(NamedConceptRemoveError::InternalError)) => {
+            Err(ServiceRemoveError::InternalError)
+        }
+    }
+}
+
+/// Removes the [`Service`](crate::service::Service) after all [`Node`](crate::node::Node)s
+/// were removed from it that the [`ServiceRemovePolicy`] permits to remove.
+pub(crate) fn remove_service<Service: service::Service>(
+    service_hash: &ServiceHash,
+    config: &config::Config,
+    policy: ServiceRemovePolicy,
+) -> Result<(), ServiceRemoveError> {
+    let origin = format!(
+        "remove_service::<{}>::({:?}, {:?})",
+        core::any::type_name::<Service>(),
+        service_hash,
+        policy
+    );
+    let msg = "Unable to remove service";
+
+    let mut node_ids = Vec::<UniqueNodeId>::new();
+    match open_dynamic_config::<Service>(config, service_hash) {
+        Ok(Some(dynamic_config)) => dynamic_config.get().list_node_ids(|node_id| {
+            node_ids.push(*node_id);
+            CallbackProgression::Continue
+        }),
+        Ok(None) => {
+            fail!(from origin, with ServiceRemoveError::DoesNotExist,
+                "{} since the service does not exist.", msg);
+        }
+        Err(ServiceDetailsError::VersionMismatch) => {
+            fail!(from origin, with ServiceRemoveError::VersionMismatch,
+                "{} since the service version does not match.", msg);
+        }
+        Err(e) => {
+            fail!(from origin, with ServiceRemoveError::InternalError,
+                "{} due to an internal failure while opening the dynamic config ({:?}).", msg, e);
+        }
+    };
+
+    if policy == ServiceRemovePolicy::OnlyWithoutAliveNodes {
+        for node_id in &node_ids {
+            match NodeState::<Service>::new(node_id, config) {
+                Ok(None) | Ok(Some(NodeState::Dead(_))) => (),
+                _ => {
+                    fail!(from origin, with ServiceRemoveError::InUse,
+                        "{} since the node {:?} is alive or its state cannot be determined.",
+                        msg, node_id);
+                }
+            }
+        }
+    }
+
+    for node_id in &node_ids {
+        match Service::__internal_remove_node_from_service(node_id, service_hash, config) {
+            Ok(()) => (),
+            Err(ServiceRemoveNodeError::VersionMismatch) => {
+                fail!(from origin, with ServiceRemoveError::VersionMismatch,
+                    "{} since the node {:?} could not be removed due to a version mismatch.",
+                    msg, node_id);
+            }
+            Err(ServiceRemoveNodeError::ServiceInCorruptedState) => {
+                fail!(from origin, with ServiceRemoveError::ServiceInCorruptedState,
+                    "{} since the service is in a corrupted state.", msg);
+            }
+            Err(ServiceRemoveNodeError::InternalError) => {
+                fail!(from origin, with ServiceRemoveError::InternalError,
+                    "{} due to an internal failure while removing the node {:?}.",
+                    msg, node_id);
+            }
+        }
+
+        match remove_service_tag::<Service>(node_id, service_hash, config) {
+            Ok(()) | Err(ServiceRemoveTagError::AlreadyRemoved) => (),
+            Err(ServiceRemoveTagError::InsufficientPermissions) => {
+                fail!(from origin, with ServiceRemoveError::InsufficientPermissions,
+                    "{} due to insufficient permissions to remove the service tag of node {:?}.",
+                    msg, node_id);
+            }
+            Err(ServiceRemoveTagError::InternalError) => {
+                fail!(from origin, with ServiceRemoveError::InternalError,
+                    "{} due to an internal failure while removing the service tag of node {:?}.",
+                    msg, node_id);
+            }
+        }
+    }
+
+    // when the last node was removed the service resources are already gone
+    match remove_unused_service::<Service>(service_hash, config) {
+        Ok(()) | Err(ServiceRemoveError::DoesNotExist) => {
+            trace!(from origin, "Removed service.");
+            Ok(())
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Removes all [`Service`](crate::service::Service)s whose
+/// [`ServiceLifetime::TimeToLive`](crate::service::lifetime::ServiceLifetime::TimeToLive)
+/// expired since no [`Node`](crate::node::Node) has opened them. Returns the number of
+/// removed [`Service`](crate::service::Service)s.
+pub(crate) fn remove_expired_services<Service: service::Service>(config: &config::Config) -> usize {
+    let origin = format!(
+        "remove_expired_services::<{}>()",
+        core::any::type_name::<Service>()
+    );
+
+    let now = match Time::now_with_clock(ClockType::Realtime) {
+        Ok(now) => now.as_duration(),
+        Err(e) => {
+            debug!(from origin,
+                "Unable to remove expired services since the current time could not be acquired ({:?}).", e);
+            return 0;
+        }
+    };
+
+    let mut number_of_removed_services = 0;
+    let result = Service::list(config, |service| {
+        let static_details = &service.static_details;
+        let time_to_live = match static_details.lifetime().time_to_live() {
+            Some(v) => v,
+            None => return CallbackProgression::Continue,
+        };
+
+        let unused_since =
+            match open_dynamic_config::<Service>(config, static_details.service_hash()) {
+                Ok(Some(c)) => c.get().unused_since(),
+                _ => None,
+            };
+
+        if let Some(unused_since) = unused_since {
+            if unused_since.saturating_add(time_to_live) <= now {
+                match remove_unused_service::<Service>(static_details.service_hash(), config) {
+                    Ok(()) => {
+                        trace!(from origin, "Removed expired service {:?}.", static_details.name());
+                        number_of_removed_services += 1;
+                    }
+                    Err(e) => {
+                        debug!(from origin, "Unable to remove expired service {:?} ({:?}).",
+                            static_details.name(), e);
+                    }
+                }
+            }
+        }
+
+        CallbackProgression::Continue
+    });
+
+    if let Err(e) = result {
+        debug!(from origin, "Unable to list all services to remove the expired ones ({:?}).", e);
+    }
+
+    number_of_removed_services
+}
@@ -12,6 +12,8 @@
 
 use core::alloc::Layout;
 
+use alloc::format;
+
 use iceoryx2_bb_container::string::*;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary::math::align;
@@ -83,6 +85,28 @@ impl TypeDetail {
         }
     }
 
+    /// Creates a new [`TypeDetail`] for a payload of type `T` that is transferred as serialized
+    /// byte slice. The name of the `Serializer` becomes part of the type name so that
+    /// participants using different serializers or payload types are incompatible.
+    pub fn new_serialized<Serializer: iceoryx2_cal::serialize::Serialize, T: ?Sized>() -> Self {
+        let type_name = format!(
+            "{}<{}>",
+            core::any::type_name::<Serializer>(),
+            core::any::type_name::<T>()
+        );
+
+        Self {
+            variant: TypeVariant::Dynamic,
+            type_name: fatal_panic!(
+                from "TypeDetail::new_serialized::<Serializer, T>()",
+                when TypeName::try_from(type_name.as_str()),
+                "Name of the serialized type T does not fit into fixed-size TypeNameString"
+            ),
+            size: core::mem::size_of::<u8>(),
+            alignment: core::mem::align_of::<u8>(),
+        }
+    }
+
     /// The [`TypeVariant`] of the type
     pub fn variant(&self) -> TypeVariant {
         self.variant
@@ -103,6 +127,16 @@ impl TypeDetail {
     pub fn alignment(&self) -> usize {
         self.alignment
     }
+
+    fn is_compatible_to(&self, rhs: &Self, is_extensible: bool) -> bool {
+        let is_size_compatible = self.size == rhs.size
+            || (is_extensible && self.variant == TypeVariant::FixedSize && self.size < rhs.size);
+
+        self.type_name == rhs.type_name
+            && self.variant == rhs.variant
+            && is_size_compatible
+            && self.alignment <= rhs.alignment
+    }
 }
 
 /// Contains all type information to the header and payload type.
@@ -159,16 +193,28 @@ impl MessageTypeDetails {
         }
     }
 
+    #[cfg(test)]
     pub(crate) fn is_compatible_to(&self, rhs: &Self) -> bool {
+        self.is_compatible_to_extension(rhs, false, false)
+    }
+
+    /// Like [`MessageTypeDetails::is_compatible_to()`] but the fixed-size user header or
+    /// payload of `rhs` may be larger when they are extensible. It must be verified with the
+    /// [`TypeSchema`](crate::service::static_config::type_schema::TypeSchema) that the
+    /// larger type only appends fields.
+    pub(crate) fn is_compatible_to_extension(
+        &self,
+        rhs: &Self,
+        is_user_header_extensible: bool,
+        is_payload_extensible: bool,
+    ) -> bool {
         self.header == rhs.header
-            && self.user_header.type_name == rhs.user_header.type_name
-            && self.user_header.variant == rhs.user_header.variant
-            && self.user_header.size == rhs.user_header.size
-            && self.user_header.alignment <= rhs.user_header.alignment
-            && self.payload.type_name == rhs.payload.type_name
-            && self.payload.variant == rhs.payload.variant
-            && self.payload.size == rhs.payload.size
-            && self.payload.alignment <= rhs.payload.alignment
+            && self
+                .user_header
+                .is_compatible_to(&rhs.user_header, is_user_header_extensible)
+            && self
+                .payload
+                .is_compatible_to(&rhs.payload, is_payload_extensible)
     }
 }
 
@@ -240,6 +286,39 @@ mod tests {
         assert_that!(sut, eq expected);
     }
 
+    #[test]
+    fn test_new_serialized() {
+        use iceoryx2_cal::serialize::postcard::Postcard;
+        use iceoryx2_cal::serialize::{DeserializeError, Serialize, SerializeError};
+
+        #[derive(Debug)]
+        struct OtherSerializer;
+
+        impl Serialize for OtherSerializer {
+            fn serialize<T: serde::Serialize>(
+                value: &T,
+            ) -> Result<alloc::vec::Vec<u8>, SerializeError> {
+                Postcard::serialize(value)
+            }
+
+            fn deserialize<T: serde::de::DeserializeOwned>(
+                bytes: &[u8],
+            ) -> Result<T, DeserializeError> {
+                Postcard::deserialize(bytes)
+            }
+        }
+
+        let sut = TypeDetail::new_serialized::<Postcard, i64>();
+        assert_that!(sut.variant(), eq TypeVariant::Dynamic);
+        assert_that!(sut.size(), eq 1);
+        assert_that!(sut.alignment(), eq 1);
+        assert_that!(*sut.type_name(), eq TypeName::try_from("iceoryx2_cal::serialize::postcard::Postcard<i64>").unwrap());
+
+        assert_that!(sut, ne TypeDetail::new_serialized::<Postcard, u64>());
+        assert_that!(sut, ne TypeDetail::new_serialized::<OtherSerializer, i64>());
+        assert_that!(sut, ne TypeDetail::new::<u8>(TypeVariant::Dynamic));
+    }
+
     #[test]
     fn test_user_header_ptr_from_header() {
         let details = MessageTypeDetails::from::<i32, bool, i64>(TypeVariant::Dynamic);
@@ -24,6 +24,10 @@ pub mod publish_subscribe;
 /// and the type variant
 pub mod message_type_details;
 
+/// Contains the field-level schema of the user header and payload type that is generated by
+/// the [`ZeroCopySend`] derive macro and used to decode raw payload bytes
+pub mod type_schema;
+
 pub mod request_response;
 
 pub mod messaging_pattern;
@@ -43,7 +47,7 @@ use crate::{config, identifiers::UniqueServiceId, service::service_hash::Service
 
 use self::messaging_pattern::MessagingPattern;
 
-use super::{attribute::AttributeSet, service_name::ServiceName};
+use super::{attribute::AttributeSet, lifetime::ServiceLifetime, service_name::ServiceName};
 
 /// Defines a common set of static service configuration details every service shares.
 #[derive(Debug, Eq, PartialEq, Clone, ZeroCopySend, Serialize, Deserialize)]
@@ -52,6 +56,7 @@ pub struct StaticConfig {
     service_hash: ServiceHash,
     service_name: ServiceName,
     unique_service_id: UniqueServiceId,
+    pub(crate) lifetime: ServiceLifetime,
     pub(crate) attributes: AttributeSet,
     pub(crate) messaging_pattern: MessagingPattern,
 }
@@ -71,6 +76,7 @@ impl StaticConfig {
             unique_service_id: UniqueServiceId::new(),
             service_name: *service_name,
             messaging_pattern,
+            lifetime: ServiceLifetime::default(),
             attributes: AttributeSet::new(),
         }
     }
@@ -88,6 +94,7 @@ impl StaticConfig {
             unique_service_id: UniqueServiceId::new(),
             service_name: *service_name,
             messaging_pattern,
+            lifetime: ServiceLifetime::default(),
             attributes: AttributeSet::new(),
         }
     }
@@ -106,6 +113,7 @@ impl StaticConfig {
             unique_service_id: UniqueServiceId::new(),
             service_name: *service_name,
             messaging_pattern,
+            lifetime: ServiceLifetime::default(),
             attributes: AttributeSet::new(),
         }
     }
@@ -123,6 +131,7 @@ impl StaticConfig {
             unique_service_id: UniqueServiceId::new(),
             service_name: *service_name,
             messaging_pattern,
+            lifetime: ServiceLifetime::default(),
             attributes: AttributeSet::new(),
         }
     }
@@ -132,6 +141,11 @@ impl StaticConfig {
         &self.attributes
     }
 
+    /// Returns the [`ServiceLifetime`] of the [`crate::service::Service`]
+    pub fn lifetime(&self) -> &ServiceLifetime {
+        &self.lifetime
+    }
+
     /// Returns the hash of the [`crate::service::Service`]
     pub fn service_hash(&self) -> &ServiceHash {
         &self.service_hash
@@ -152,6 +166,38 @@ impl StaticConfig {
         &self.messaging_pattern
     }
 
+    /// Returns a copy of the [`StaticConfig`] that describes the same service under a
+    /// different [`ServiceName`]. Used by gateways that mirror a service into another domain.
+    #[doc(hidden)]
+    pub fn __internal_rename<S: crate::service::Service>(
+        &self,
+        service_name: &ServiceName,
+    ) -> Self {
+        let messaging_pattern = match self.messaging_pattern {
+            MessagingPattern::RequestResponse(_) => {
+                crate::service::messaging_pattern::MessagingPattern::RequestResponse
+            }
+            MessagingPattern::PublishSubscribe(_) => {
+                crate::service::messaging_pattern::MessagingPattern::PublishSubscribe
+            }
+            MessagingPattern::Event(_) => {
+                crate::service::messaging_pattern::MessagingPattern::Event
+            }
+            MessagingPattern::Blackboard(_) => {
+                crate::service::messaging_pattern::MessagingPattern::Blackboard
+            }
+        };
+
+        Self {
+            service_hash: ServiceHash::new::<S::ServiceNameHasher>(service_name, messaging_pattern),
+            service_name: *service_name,
+            unique_service_id: UniqueServiceId::new(),
+            lifetime: self.lifetime,
+            attributes: self.attributes.clone(),
+            messaging_pattern: self.messaging_pattern,
+        }
+    }
+
     pub(crate) fn has_same_messaging_pattern(&self, rhs: &StaticConfig) -> bool {
         self.messaging_pattern
             .is_same_pattern(&rhs.messaging_pattern)
@@ -22,23 +22,39 @@
 //!     .open_or_create()?;
 //!
 //! println!("type details:                     {:?}", pubsub.static_config().message_type_details());
+//! println!("type schema:                      {:?}", pubsub.static_config().message_type_schema());
 //! println!("max publishers:                   {:?}", pubsub.static_config().max_publishers());
 //! println!("max subscribers:                  {:?}", pubsub.static_config().max_subscribers());
 //! println!("subscriber buffer size:           {:?}", pubsub.static_config().subscriber_max_buffer_size());
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
 //! println!("safe overflow:                    {:?}", pubsub.static_config().has_safe_overflow());
+//! println!("number of priorities:             {:?}", pubsub.static_config().number_of_priorities());
+//! println!("copy on send:                     {:?}", pubsub.static_config().has_copy_on_send());
+//! println!("deadline:                         {:?}", pubsub.static_config().deadline());
 //!
 //! # Ok(())
 //! # }
 //! ```
 
+use core::time::Duration;
+
+use super::event::Deadline;
 use super::message_type_details::MessageTypeDetails;
+use super::type_schema::MessageTypeSchema;
 use crate::config;
+use alloc::vec::Vec;
+use iceoryx2_bb_container::relocatable_option::RelocatableOption;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_cal::zero_copy_connection::ChannelId;
 use serde::{Deserialize, Serialize};
 
+/// The maximum number of priorities a
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// based service can support.
+pub const MAX_NUMBER_OF_PRIORITIES: usize = 16;
+
 /// The static configuration of an
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 /// based service. Contains all parameters that do not change during the lifetime of a
@@ -50,10 +66,18 @@ pub struct StaticConfig {
     pub(crate) max_publishers: usize,
     pub(crate) max_nodes: usize,
     pub(crate) history_size: usize,
+    pub(crate) history_cache_size: usize,
+    pub(crate) history_cache_max_slice_len: usize,
     pub(crate) subscriber_max_buffer_size: usize,
     pub(crate) subscriber_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
+    pub(crate) number_of_priorities: u8,
+    pub(crate) enable_safe_overflow_for_priority: [bool; MAX_NUMBER_OF_PRIORITIES],
+    pub(crate) enable_subscriber_wakeup: bool,
+    pub(crate) enable_copy_on_send: bool,
+    pub(crate) deadline: RelocatableOption<Deadline>,
     pub(crate) message_type_details: MessageTypeDetails,
+    pub(crate) message_type_schema: MessageTypeSchema,
 }
 
 impl StaticConfig {
@@ -63,6 +87,8 @@ impl StaticConfig {
             max_publishers: config.defaults.publish_subscribe.max_publishers,
             max_nodes: config.defaults.publish_subscribe.max_nodes,
             history_size: config.defaults.publish_subscribe.publisher_history_size,
+            history_cache_size: 0,
+            history_cache_max_slice_len: 1,
             subscriber_max_buffer_size: config
                 .defaults
                 .publish_subscribe
@@ -72,7 +98,17 @@ impl StaticConfig {
                 .publish_subscribe
                 .subscriber_max_borrowed_samples,
             enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
+            number_of_priorities: 1,
+            enable_safe_overflow_for_priority: [config
+                .defaults
+                .publish_subscribe
+                .enable_safe_overflow;
+                MAX_NUMBER_OF_PRIORITIES],
+            enable_subscriber_wakeup: false,
+            enable_copy_on_send: false,
+            deadline: RelocatableOption::None,
             message_type_details: MessageTypeDetails::default(),
+            message_type_schema: MessageTypeSchema::default(),
         }
     }
 
@@ -80,12 +116,26 @@ impl StaticConfig {
         &self,
         publisher_max_loaned_data: usize,
     ) -> usize {
+        if self.enable_copy_on_send {
+            // the offsets of the receive segments must be covered by the connection as well
+            return (self.history_size + publisher_max_loaned_data)
+                .max(self.required_amount_of_samples_per_receive_segment());
+        }
+
         self.max_subscribers
-            * (self.subscriber_max_buffer_size + self.subscriber_max_borrowed_samples)
+            * (self.subscriber_max_buffer_size * self.number_of_priorities as usize
+                + self.subscriber_max_borrowed_samples)
             + self.history_size
             + publisher_max_loaned_data
     }
 
+    pub(crate) fn required_amount_of_samples_per_receive_segment(&self) -> usize {
+        // one additional sample is in flight while the oldest sample is replaced on overflow
+        self.subscriber_max_buffer_size * self.number_of_priorities as usize
+            + self.subscriber_max_borrowed_samples
+            + 1
+    }
+
     /// Returns the maximum supported amount of [`Node`](crate::node::Node)s that can open the
     /// [`Service`](crate::service::Service) in parallel.
     pub fn max_nodes(&self) -> usize {
@@ -107,13 +157,29 @@ impl StaticConfig {
         self.history_size
     }
 
+    /// Returns how many [`crate::sample::Sample`]s of all [`crate::port::publisher::Publisher`]s
+    /// the history cache of the [`crate::service::Service`] retains. When it is not zero, new
+    /// [`crate::port::subscriber::Subscriber`]s receive the history from the cache instead of
+    /// from the [`crate::port::publisher::Publisher`]s.
+    pub fn history_cache_size(&self) -> usize {
+        self.history_cache_size
+    }
+
+    /// Returns the maximum number of slice elements a [`crate::sample::Sample`] can have to be
+    /// retained in the history cache.
+    pub fn history_cache_max_slice_len(&self) -> usize {
+        self.history_cache_max_slice_len
+    }
+
     /// Returns the maximum supported buffer size for [`crate::port::subscriber::Subscriber`] port
+    /// per priority
     pub fn subscriber_max_buffer_size(&self) -> usize {
         self.subscriber_max_buffer_size
     }
 
     /// Returns how many [`crate::sample::Sample`] a [`crate::port::subscriber::Subscriber`] port
-    /// can borrow in parallel at most.
+    /// can borrow in parallel at most from a [`crate::port::publisher::Publisher`] over all
+    /// priorities.
     pub fn subscriber_max_borrowed_samples(&self) -> usize {
         self.subscriber_max_borrowed_samples
     }
@@ -126,8 +192,63 @@ impl StaticConfig {
         self.enable_safe_overflow
     }
 
+    /// Returns the number of priorities the [`crate::service::Service`] supports. Every
+    /// [`crate::port::subscriber::Subscriber`] has a separate buffer for every priority and
+    /// receives the [`crate::sample::Sample`]s with the highest priority first.
+    pub fn number_of_priorities(&self) -> u8 {
+        self.number_of_priorities
+    }
+
+    /// Returns true if the buffer of the provided priority safely overflows, otherwise false.
+    /// If the priority is not supported by the [`crate::service::Service`], the setting of the
+    /// highest supported priority is returned.
+    pub fn has_safe_overflow_for_priority(&self, priority: u8) -> bool {
+        self.enable_safe_overflow_for_priority[self.clamp_priority(priority) as usize]
+    }
+
+    /// Returns true if the [`crate::port::subscriber::Subscriber`]s are woken up whenever a
+    /// [`crate::port::publisher::Publisher`] sends a [`crate::sample::Sample`]. In this case
+    /// a [`crate::port::subscriber::Subscriber`] can be attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet).
+    pub fn has_subscriber_wakeup(&self) -> bool {
+        self.enable_subscriber_wakeup
+    }
+
+    /// Returns true if the [`crate::port::publisher::Publisher`]s copy every
+    /// [`crate::sample::Sample`] on send into a private receive segment of each
+    /// [`crate::port::subscriber::Subscriber`], otherwise the
+    /// [`crate::port::subscriber::Subscriber`]s share the data segment of the
+    /// [`crate::port::publisher::Publisher`].
+    pub fn has_copy_on_send(&self) -> bool {
+        self.enable_copy_on_send
+    }
+
+    /// Returns the deadline of the service. If no new [`crate::sample::Sample`] is sent from
+    /// any [`crate::port::publisher::Publisher`] after the given deadline, it is rated as a
+    /// degradation and reported to the [`crate::port::subscriber::Subscriber`]s.
+    pub fn deadline(&self) -> Option<Duration> {
+        self.deadline.as_option_ref().map(|v| v.value.into())
+    }
+
+    pub(crate) fn clamp_priority(&self, priority: u8) -> u8 {
+        priority.min(self.number_of_priorities.saturating_sub(1))
+    }
+
+    pub(crate) fn safe_overflow_per_channel(&self) -> Vec<(ChannelId, bool)> {
+        (0..self.number_of_priorities as usize)
+            .map(|n| (ChannelId::new(n), self.enable_safe_overflow_for_priority[n]))
+            .collect()
+    }
+
     /// Returns the type details of the [`crate::service::Service`].
     pub fn message_type_details(&self) -> &MessageTypeDetails {
         &self.message_type_details
     }
+
+    /// Returns the field-level schema of the user header and payload type of the
+    /// [`crate::service::Service`]. A [`TypeSchema`](super::type_schema::TypeSchema) is empty
+    /// when the type was not provided by a Rust [`ZeroCopySend`] type.
+    pub fn message_type_schema(&self) -> &MessageTypeSchema {
+        &self.message_type_schema
+    }
 }
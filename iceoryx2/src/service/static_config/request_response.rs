@@ -23,6 +23,8 @@
 //!
 //! println!("request type details: {:?}", req_res.static_config().request_message_type_details());
 //! println!("response type details: {:?}", req_res.static_config().response_message_type_details());
+//! println!("request type schema: {:?}", req_res.static_config().request_message_type_schema());
+//! println!("response type schema: {:?}", req_res.static_config().response_message_type_schema());
 //! println!("max active requests per client: {:?}", req_res.static_config().max_active_requests_per_client());
 //! println!("max response buffer size: {:?}", req_res.static_config().max_response_buffer_size());
 //! println!("client max loaned requests: {:?}", req_res.static_config().max_loaned_requests());
@@ -45,6 +47,7 @@ use serde::{Deserialize, Serialize};
 use crate::config;
 
 use super::message_type_details::MessageTypeDetails;
+use super::type_schema::MessageTypeSchema;
 
 /// The static configuration of an
 /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
@@ -65,6 +68,8 @@ pub struct StaticConfig {
     pub(crate) max_borrowed_responses_per_pending_response: usize,
     pub(crate) request_message_type_details: MessageTypeDetails,
     pub(crate) response_message_type_details: MessageTypeDetails,
+    pub(crate) request_message_type_schema: MessageTypeSchema,
+    pub(crate) response_message_type_schema: MessageTypeSchema,
 }
 
 impl StaticConfig {
@@ -97,6 +102,8 @@ impl StaticConfig {
                 .enable_fire_and_forget_requests,
             request_message_type_details: MessageTypeDetails::default(),
             response_message_type_details: MessageTypeDetails::default(),
+            request_message_type_schema: MessageTypeSchema::default(),
+            response_message_type_schema: MessageTypeSchema::default(),
         }
     }
 
@@ -143,6 +150,20 @@ impl StaticConfig {
         &self.response_message_type_details
     }
 
+    /// Returns the field-level schema of the request header and payload type of the
+    /// [`crate::service::Service`]. A [`TypeSchema`](super::type_schema::TypeSchema) is empty
+    /// when the type was not provided by a Rust type.
+    pub fn request_message_type_schema(&self) -> &MessageTypeSchema {
+        &self.request_message_type_schema
+    }
+
+    /// Returns the field-level schema of the response header and payload type of the
+    /// [`crate::service::Service`]. A [`TypeSchema`](super::type_schema::TypeSchema) is empty
+    /// when the type was not provided by a Rust type.
+    pub fn response_message_type_schema(&self) -> &MessageTypeSchema {
+        &self.response_message_type_schema
+    }
+
     /// Returns true if the request buffer of the [`crate::service::Service`] safely overflows,
     /// otherwise false. Safe overflow means that the [`crate::port::client::Client`] will
     /// recycle the oldest requests from the [`crate::port::server::Server`] when its buffer
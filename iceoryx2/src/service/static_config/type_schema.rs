@@ -0,0 +1,1148 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::static_config::type_schema::TypeSchema;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! #[derive(Debug, ZeroCopySend)]
+//! #[repr(C)]
+//! struct Position {
+//!     x: f32,
+//!     y: f32,
+//! }
+//!
+//! let schema = TypeSchema::new::<Position>();
+//! for entry in schema.entries() {
+//!     println!("{:?} at offset {}: {:?}", entry.name(), entry.offset(), entry.kind());
+//! }
+//!
+//! let payload = Position { x: 1.5, y: -3.0 };
+//! let bytes = unsafe {
+//!     core::slice::from_raw_parts(
+//!         (&payload as *const Position).cast::<u8>(),
+//!         core::mem::size_of::<Position>(),
+//!     )
+//! };
+//! println!("decoded payload: {:?}", schema.decode(bytes)?);
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::hash::{Hash, Hasher};
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use iceoryx2_bb_container::string::*;
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::zero_copy_send::{
+    SchemaKind, SchemaPrimitive, SchemaVisitor, ZeroCopySend,
+};
+use iceoryx2_log::{fail, warn};
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::constants::{MAX_TYPE_SCHEMA_ENTRIES, MAX_TYPE_SCHEMA_FIELD_NAME_LENGTH};
+
+/// A fixed-size string type used to store the field names of a [`TypeSchema`].
+pub type FieldName = StaticString<MAX_TYPE_SCHEMA_FIELD_NAME_LENGTH>;
+
+/// The primitive type of a [`FieldKind::Primitive`] field.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, ZeroCopySend, Serialize, Deserialize)]
+pub enum PrimitiveType {
+    /// [`bool`]
+    Bool,
+    /// [`char`]
+    Char,
+    /// [`u8`]
+    U8,
+    /// [`u16`]
+    U16,
+    /// [`u32`]
+    U32,
+    /// [`u64`]
+    U64,
+    /// [`u128`]
+    U128,
+    /// [`usize`]
+    Usize,
+    /// [`i8`]
+    I8,
+    /// [`i16`]
+    I16,
+    /// [`i32`]
+    I32,
+    /// [`i64`]
+    I64,
+    /// [`i128`]
+    I128,
+    /// [`isize`]
+    Isize,
+    /// [`f32`]
+    F32,
+    /// [`f64`]
+    F64,
+}
+
+impl From<SchemaPrimitive> for PrimitiveType {
+    fn from(value: SchemaPrimitive) -> Self {
+        match value {
+            SchemaPrimitive::Bool => PrimitiveType::Bool,
+            SchemaPrimitive::Char => PrimitiveType::Char,
+            SchemaPrimitive::U8 => PrimitiveType::U8,
+            SchemaPrimitive::U16 => PrimitiveType::U16,
+            SchemaPrimitive::U32 => PrimitiveType::U32,
+            SchemaPrimitive::U64 => PrimitiveType::U64,
+            SchemaPrimitive::U128 => PrimitiveType::U128,
+            SchemaPrimitive::Usize => PrimitiveType::Usize,
+            SchemaPrimitive::I8 => PrimitiveType::I8,
+            SchemaPrimitive::I16 => PrimitiveType::I16,
+            SchemaPrimitive::I32 => PrimitiveType::I32,
+            SchemaPrimitive::I64 => PrimitiveType::I64,
+            SchemaPrimitive::I128 => PrimitiveType::I128,
+            SchemaPrimitive::Isize => PrimitiveType::Isize,
+            SchemaPrimitive::F32 => PrimitiveType::F32,
+            SchemaPrimitive::F64 => PrimitiveType::F64,
+        }
+    }
+}
+
+/// Defines how a [`SchemaEntry`] is laid out in memory. All offsets are relative to the start
+/// of the entry.
+#[repr(C)]
+#[derive(
+    Default, Debug, Clone, Copy, Eq, Hash, PartialEq, ZeroCopySend, Serialize, Deserialize,
+)]
+pub enum FieldKind {
+    /// A primitive value.
+    Primitive(PrimitiveType),
+    /// A struct, its fields are the child entries.
+    Struct,
+    /// A fixed-size array of `len` elements that are described by the single child entry.
+    Array {
+        /// The number of elements
+        len: usize,
+        /// The distance in bytes between two elements
+        stride: usize,
+    },
+    /// A vector with a fixed capacity whose elements are described by the single child entry.
+    Vec {
+        /// The maximum number of elements
+        capacity: usize,
+        /// The offset of the [`u64`] length
+        len_offset: usize,
+        /// The distance in bytes between two elements
+        stride: usize,
+    },
+    /// A UTF-8 string with a fixed capacity.
+    String {
+        /// The maximum number of bytes
+        capacity: usize,
+        /// The offset of the [`u64`] length
+        len_offset: usize,
+        /// The offset of the first byte
+        data_offset: usize,
+    },
+    /// A type whose internal structure is unknown.
+    #[default]
+    Opaque,
+}
+
+impl From<SchemaKind> for FieldKind {
+    fn from(value: SchemaKind) -> Self {
+        match value {
+            SchemaKind::Primitive(v) => FieldKind::Primitive(v.into()),
+            SchemaKind::Struct => FieldKind::Struct,
+            SchemaKind::Array { len, stride } => FieldKind::Array { len, stride },
+            SchemaKind::Vec {
+                capacity,
+                len_offset,
+                stride,
+            } => FieldKind::Vec {
+                capacity,
+                len_offset,
+                stride,
+            },
+            SchemaKind::String {
+                capacity,
+                len_offset,
+                data_offset,
+            } => FieldKind::String {
+                capacity,
+                len_offset,
+                data_offset,
+            },
+            SchemaKind::Opaque => FieldKind::Opaque,
+        }
+    }
+}
+
+/// A single node of a [`TypeSchema`]. The entries are stored in depth-first order, the child
+/// entries of an entry directly follow it and have a [`SchemaEntry::depth()`] that is larger
+/// by one.
+#[repr(C)]
+#[derive(
+    Default, Debug, Clone, Copy, Eq, Hash, PartialEq, ZeroCopySend, Serialize, Deserialize,
+)]
+pub struct SchemaEntry {
+    name: FieldName,
+    depth: usize,
+    offset: usize,
+    size: usize,
+    kind: FieldKind,
+}
+
+impl SchemaEntry {
+    /// The name of the field inside its parent struct. The root entry and the elements of
+    /// arrays and vectors have an empty name.
+    pub fn name(&self) -> &FieldName {
+        &self.name
+    }
+
+    /// The nesting depth of the entry, the root entry has a depth of zero.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The offset in bytes from the start of the described type.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The size of the field calculated by [`core::mem::size_of`].
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The [`FieldKind`] of the entry.
+    pub fn kind(&self) -> FieldKind {
+        self.kind
+    }
+}
+
+/// Failures that can occur when data is decoded with [`TypeSchema::decode()`] or
+/// [`TypeSchema::decode_slice()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum TypeSchemaDecodeError {
+    /// The [`TypeSchema`] is empty since the type does not provide a description.
+    NoSchemaAvailable,
+    /// The provided data is smaller than the described type.
+    InsufficientData,
+    /// The stored length of a vector or string exceeds its capacity.
+    CorruptedLength,
+    /// The data contains a value that is not a valid [`char`].
+    InvalidCharacter,
+}
+
+impl core::fmt::Display for TypeSchemaDecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "TypeSchemaDecodeError::{self:?}")
+    }
+}
+
+impl core::error::Error for TypeSchemaDecodeError {}
+
+/// A value decoded from raw bytes with a [`TypeSchema`]. It implements [`Serialize`] so that
+/// it can be converted into any human-readable format.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SchemaValue {
+    /// A [`bool`]
+    Bool(bool),
+    /// A [`char`]
+    Char(char),
+    /// Any unsigned integer
+    Unsigned(u128),
+    /// Any signed integer
+    Signed(i128),
+    /// A [`f32`]
+    F32(f32),
+    /// A [`f64`]
+    F64(f64),
+    /// A string
+    String(String),
+    /// The elements of an array, a vector or a slice
+    Sequence(Vec<SchemaValue>),
+    /// The fields of a struct in declaration order
+    Struct(Vec<(String, SchemaValue)>),
+    /// The raw bytes of a type without description
+    Opaque(Vec<u8>),
+}
+
+impl Serialize for SchemaValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            SchemaValue::Bool(v) => serializer.serialize_bool(*v),
+            SchemaValue::Char(v) => serializer.serialize_char(*v),
+            // 128-bit integers are not supported by all formats
+            SchemaValue::Unsigned(v) => match u64::try_from(*v) {
+                Ok(v) => serializer.serialize_u64(v),
+                Err(_) => serializer.serialize_str(&v.to_string()),
+            },
+            SchemaValue::Signed(v) => match i64::try_from(*v) {
+                Ok(v) => serializer.serialize_i64(v),
+                Err(_) => serializer.serialize_str(&v.to_string()),
+            },
+            SchemaValue::F32(v) => serializer.serialize_f32(*v),
+            SchemaValue::F64(v) => serializer.serialize_f64(*v),
+            SchemaValue::String(v) => serializer.serialize_str(v),
+            SchemaValue::Sequence(elements) => {
+                let mut seq = serializer.serialize_seq(Some(elements.len()))?;
+                for element in elements {
+                    seq.serialize_element(element)?;
+                }
+                seq.end()
+            }
+            SchemaValue::Struct(fields) => {
+                let mut map = serializer.serialize_map(Some(fields.len()))?;
+                for (name, value) in fields {
+                    map.serialize_entry(name, value)?;
+                }
+                map.end()
+            }
+            SchemaValue::Opaque(bytes) => {
+                let hex: String = bytes.iter().map(|b| format!("{b:0>2x}")).collect();
+                serializer.serialize_str(&hex)
+            }
+        }
+    }
+}
+
+/// Field-level description of a [`ZeroCopySend`] type that is created with
+/// [`ZeroCopySend::describe_schema()`]. It contains the name, offset, size and
+/// [`FieldKind`] of every field and is used to decode raw payload bytes into a
+/// [`SchemaValue`].
+///
+/// Types whose description exceeds [`MAX_TYPE_SCHEMA_ENTRIES`] entries or whose field names
+/// exceed [`MAX_TYPE_SCHEMA_FIELD_NAME_LENGTH`] are stored as a single [`FieldKind::Opaque`]
+/// entry.
+///
+/// Additionally, it stores the [`ZeroCopySend::type_version()`] of the type that is
+/// considered together with the [`TypeSchema::fingerprint()`] when a service is opened,
+/// see [`SchemaCompatibility`].
+#[repr(C)]
+#[derive(Clone, Copy, ZeroCopySend)]
+pub struct TypeSchema {
+    entries: [SchemaEntry; MAX_TYPE_SCHEMA_ENTRIES],
+    len: usize,
+    version: u32,
+}
+
+impl Default for TypeSchema {
+    fn default() -> Self {
+        Self {
+            entries: [SchemaEntry::default(); MAX_TYPE_SCHEMA_ENTRIES],
+            len: 0,
+            version: 0,
+        }
+    }
+}
+
+impl core::fmt::Debug for TypeSchema {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TypeSchema")
+            .field("version", &self.version)
+            .field("entries", &self.entries())
+            .finish()
+    }
+}
+
+impl PartialEq for TypeSchema {
+    fn eq(&self, other: &Self) -> bool {
+        self.version == other.version && self.entries() == other.entries()
+    }
+}
+
+impl Eq for TypeSchema {}
+
+impl core::hash::Hash for TypeSchema {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.version.hash(state);
+        self.entries().hash(state)
+    }
+}
+
+impl Serialize for TypeSchema {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("version", &self.version)?;
+        map.serialize_entry("entries", self.entries())?;
+        map.end()
+    }
+}
+
+#[derive(Deserialize)]
+struct SerializedTypeSchema {
+    #[serde(default)]
+    version: u32,
+    entries: Vec<SchemaEntry>,
+}
+
+impl<'de> Deserialize<'de> for TypeSchema {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let serialized = SerializedTypeSchema::deserialize(deserializer)?;
+        if serialized.entries.len() > MAX_TYPE_SCHEMA_ENTRIES {
+            return Err(<D::Error as serde::de::Error>::invalid_length(
+                serialized.entries.len(),
+                &format!("at most {MAX_TYPE_SCHEMA_ENTRIES} schema entries").as_str(),
+            ));
+        }
+
+        let mut schema = TypeSchema {
+            version: serialized.version,
+            ..TypeSchema::default()
+        };
+        schema.entries[..serialized.entries.len()].copy_from_slice(&serialized.entries);
+        schema.len = serialized.entries.len();
+
+        Ok(schema)
+    }
+}
+
+/// Defines how the [`TypeSchema`]s of the requested types are verified against the
+/// [`TypeSchema`]s stored in the service when an existing service is opened. The check is only
+/// applied when both sides provide a [`TypeSchema`], types defined by other language bindings
+/// are verified with their
+/// [`TypeDetail`](crate::service::static_config::message_type_details::TypeDetail) only.
+#[derive(Default, Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum SchemaCompatibility {
+    /// The [`TypeSchema::fingerprint()`] and the [`TypeSchema::version()`] must be identical.
+    #[default]
+    Exact,
+    /// The type of the service may extend the requested type by appending fields at the end
+    /// of the top-level struct and must have an equal or newer [`TypeSchema::version()`]. This
+    /// allows participants with an older type to connect to a service that was created with a
+    /// newer type.
+    ///
+    /// **Attention:** Participants with the older type do not know the appended fields. They
+    /// do not read them and when they send, e.g. via a
+    /// [`Publisher`](crate::port::publisher::Publisher), the appended fields are zeroed.
+    /// Therefore, every appended field must be valid when all of its bytes are zero.
+    AppendOnly,
+    /// The [`TypeSchema`]s are not verified.
+    Ignore,
+}
+
+/// Describes why a requested [`TypeSchema`] is incompatible to the [`TypeSchema`] of a
+/// service.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub(crate) enum TypeSchemaMismatch {
+    Version,
+    Schema,
+    NotAppendOnly,
+}
+
+struct Fnv1aHasher(u64);
+
+impl core::hash::Hasher for Fnv1aHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+}
+
+struct TypeSchemaBuilder {
+    schema: TypeSchema,
+    depth: usize,
+    exceeds_capacity: bool,
+}
+
+impl SchemaVisitor for TypeSchemaBuilder {
+    fn enter(&mut self, name: &str, offset: usize, size: usize, kind: SchemaKind) {
+        let depth = self.depth;
+        self.depth += 1;
+
+        if self.exceeds_capacity {
+            return;
+        }
+
+        let name = match FieldName::try_from(name) {
+            Ok(name) => name,
+            Err(_) => {
+                self.exceeds_capacity = true;
+                return;
+            }
+        };
+
+        if self.schema.len == MAX_TYPE_SCHEMA_ENTRIES {
+            self.exceeds_capacity = true;
+            return;
+        }
+
+        self.schema.entries[self.schema.len] = SchemaEntry {
+            name,
+            depth,
+            offset,
+            size,
+            kind: kind.into(),
+        };
+        self.schema.len += 1;
+    }
+
+    fn leave(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+impl TypeSchema {
+    /// Creates the [`TypeSchema`] of `T`.
+    pub fn new<T: ZeroCopySend>() -> Self {
+        let mut builder = TypeSchemaBuilder {
+            schema: TypeSchema::default(),
+            depth: 0,
+            exceeds_capacity: false,
+        };
+        T::describe_schema(&mut builder, "", 0);
+        builder.schema.version = T::type_version();
+
+        if !builder.exceeds_capacity {
+            return builder.schema;
+        }
+
+        warn!(from "TypeSchema::new()",
+            "The schema of \"{}\" exceeds the supported number of entries or field name length and is stored as opaque type.",
+            core::any::type_name::<T>());
+
+        let mut schema = TypeSchema::default();
+        schema.entries[0] = SchemaEntry {
+            size: core::mem::size_of::<T>(),
+            ..SchemaEntry::default()
+        };
+        schema.len = 1;
+        schema.version = T::type_version();
+        schema
+    }
+
+    /// Returns all [`SchemaEntry`]s in depth-first order. The first entry describes the type
+    /// itself.
+    pub fn entries(&self) -> &[SchemaEntry] {
+        &self.entries[..self.len]
+    }
+
+    /// Returns `true` when no description of the type is available, for instance when the
+    /// type was defined by another language binding.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the [`ZeroCopySend::type_version()`] of the described type.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns a 64-bit FNV-1a hash over all [`SchemaEntry`]s. Types with an identical layout
+    /// and identical field names have the same fingerprint, independent of their
+    /// [`TypeSchema::version()`].
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = Fnv1aHasher(0xcbf2_9ce4_8422_2325);
+        self.entries().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Verifies that `self`, the schema of the requested type, is compatible to the schema
+    /// of the existing service with respect to the provided [`SchemaCompatibility`].
+    pub(crate) fn verify_compatibility(
+        &self,
+        existing: &TypeSchema,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), TypeSchemaMismatch> {
+        if self.is_empty() || existing.is_empty() {
+            return Ok(());
+        }
+
+        match compatibility {
+            SchemaCompatibility::Ignore => Ok(()),
+            SchemaCompatibility::Exact => {
+                if self.version != existing.version {
+                    Err(TypeSchemaMismatch::Version)
+                } else if self.fingerprint() != existing.fingerprint()
+                    || self.entries() != existing.entries()
+                {
+                    Err(TypeSchemaMismatch::Schema)
+                } else {
+                    Ok(())
+                }
+            }
+            SchemaCompatibility::AppendOnly => {
+                if self.version > existing.version {
+                    Err(TypeSchemaMismatch::Version)
+                } else if !self.is_prefix_of(existing) {
+                    Err(TypeSchemaMismatch::NotAppendOnly)
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    /// Returns `true` when the type of `existing` is allowed to be larger than the requested
+    /// type since its schema is verified to be an append-only extension.
+    pub(crate) fn is_extensible_to(
+        &self,
+        existing: &TypeSchema,
+        compatibility: SchemaCompatibility,
+    ) -> bool {
+        compatibility == SchemaCompatibility::AppendOnly && !self.is_empty() && !existing.is_empty()
+    }
+
+    /// Returns `true` when `existing` is either identical or a struct that contains all
+    /// top-level fields of `self` at the same positions followed by additional fields.
+    fn is_prefix_of(&self, existing: &TypeSchema) -> bool {
+        if self.entries() == existing.entries() {
+            return true;
+        }
+
+        let root = &self.entries[0];
+        let existing_root = &existing.entries[0];
+        root.kind == FieldKind::Struct
+            && existing_root.kind == FieldKind::Struct
+            && root.size <= existing_root.size
+            && self.len <= existing.len
+            && self.entries[1..self.len] == existing.entries[1..self.len]
+            && (self.len == existing.len || existing.entries[self.len].depth == 1)
+    }
+
+    /// Decodes a single value of the described type from the start of `bytes`.
+    pub fn decode(&self, bytes: &[u8]) -> Result<SchemaValue, TypeSchemaDecodeError> {
+        if self.is_empty() {
+            fail!(from self, with TypeSchemaDecodeError::NoSchemaAvailable,
+                "Unable to decode data since no type schema is available.");
+        }
+
+        self.decode_entry(0, 0, bytes)
+    }
+
+    /// Decodes `bytes` as a slice of the described type, like the payload of a
+    /// [`TypeVariant::Dynamic`](crate::service::static_config::message_type_details::TypeVariant::Dynamic)
+    /// service.
+    pub fn decode_slice(&self, bytes: &[u8]) -> Result<SchemaValue, TypeSchemaDecodeError> {
+        if self.is_empty() {
+            fail!(from self, with TypeSchemaDecodeError::NoSchemaAvailable,
+                "Unable to decode data since no type schema is available.");
+        }
+
+        let element_size = self.entries[0].size;
+        if element_size == 0 {
+            return Ok(SchemaValue::Sequence(Vec::new()));
+        }
+
+        if bytes.len() % element_size != 0 {
+            fail!(from self, with TypeSchemaDecodeError::InsufficientData,
+                "Unable to decode data since its size {} is not a multiple of the element size {}.",
+                bytes.len(), element_size);
+        }
+
+        let mut elements = Vec::with_capacity(bytes.len() / element_size);
+        for n in 0..bytes.len() / element_size {
+            elements.push(self.decode_entry(0, n * element_size, bytes)?);
+        }
+
+        Ok(SchemaValue::Sequence(elements))
+    }
+
+    fn children(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        let depth = self.entries[index].depth;
+        (index + 1..self.len)
+            .take_while(move |i| self.entries[*i].depth > depth)
+            .filter(move |i| self.entries[*i].depth == depth + 1)
+    }
+
+    fn read<'a>(
+        &self,
+        bytes: &'a [u8],
+        position: usize,
+        len: usize,
+    ) -> Result<&'a [u8], TypeSchemaDecodeError> {
+        match bytes.get(position..position + len) {
+            Some(v) => Ok(v),
+            None => {
+                fail!(from self, with TypeSchemaDecodeError::InsufficientData,
+                    "Unable to decode data since {len} bytes at position {position} are out of bounds (data size is {}).",
+                    bytes.len());
+            }
+        }
+    }
+
+    fn read_len(
+        &self,
+        bytes: &[u8],
+        position: usize,
+        capacity: usize,
+    ) -> Result<usize, TypeSchemaDecodeError> {
+        let raw = self.read(bytes, position, core::mem::size_of::<u64>())?;
+        let len = u64::from_ne_bytes(raw.try_into().unwrap_or_default());
+
+        if len > capacity as u64 {
+            fail!(from self, with TypeSchemaDecodeError::CorruptedLength,
+                "Unable to decode data since the stored length {len} exceeds the capacity {capacity}.");
+        }
+
+        Ok(len as usize)
+    }
+
+    fn decode_primitive(
+        &self,
+        primitive: PrimitiveType,
+        raw: &[u8],
+    ) -> Result<SchemaValue, TypeSchemaDecodeError> {
+        macro_rules! from_ne_bytes {
+            ($t:ty) => {
+                <$t>::from_ne_bytes(raw.try_into().unwrap_or_default())
+            };
+        }
+
+        Ok(match primitive {
+            PrimitiveType::Bool => SchemaValue::Bool(raw.iter().any(|b| *b != 0)),
+            PrimitiveType::Char => match char::from_u32(from_ne_bytes!(u32)) {
+                Some(c) => SchemaValue::Char(c),
+                None => {
+                    fail!(from self, with TypeSchemaDecodeError::InvalidCharacter,
+                        "Unable to decode data since it contains an invalid character.");
+                }
+            },
+            PrimitiveType::U8 => SchemaValue::Unsigned(from_ne_bytes!(u8) as _),
+            PrimitiveType::U16 => SchemaValue::Unsigned(from_ne_bytes!(u16) as _),
+            PrimitiveType::U32 => SchemaValue::Unsigned(from_ne_bytes!(u32) as _),
+            PrimitiveType::U64 => SchemaValue::Unsigned(from_ne_bytes!(u64) as _),
+            PrimitiveType::U128 => SchemaValue::Unsigned(from_ne_bytes!(u128)),
+            PrimitiveType::Usize => SchemaValue::Unsigned(from_ne_bytes!(usize) as _),
+            PrimitiveType::I8 => SchemaValue::Signed(from_ne_bytes!(i8) as _),
+            PrimitiveType::I16 => SchemaValue::Signed(from_ne_bytes!(i16) as _),
+            PrimitiveType::I32 => SchemaValue::Signed(from_ne_bytes!(i32) as _),
+            PrimitiveType::I64 => SchemaValue::Signed(from_ne_bytes!(i64) as _),
+            PrimitiveType::I128 => SchemaValue::Signed(from_ne_bytes!(i128)),
+            PrimitiveType::Isize => SchemaValue::Signed(from_ne_bytes!(isize) as _),
+            PrimitiveType::F32 => SchemaValue::F32(from_ne_bytes!(f32)),
+            PrimitiveType::F64 => SchemaValue::F64(from_ne_bytes!(f64)),
+        })
+    }
+
+    fn decode_entry(
+        &self,
+        index: usize,
+        shift: usize,
+        bytes: &[u8],
+    ) -> Result<SchemaValue, TypeSchemaDecodeError> {
+        let entry = &self.entries[index];
+        let position = entry.offset + shift;
+
+        match entry.kind {
+            FieldKind::Primitive(primitive) => {
+                let raw = self.read(bytes, position, entry.size)?;
+                self.decode_primitive(primitive, raw)
+            }
+            FieldKind::Struct => {
+                let mut fields = Vec::new();
+                for child in self.children(index) {
+                    fields.push((
+                        self.entries[child].name.to_string(),
+                        self.decode_entry(child, shift, bytes)?,
+                    ));
+                }
+                Ok(SchemaValue::Struct(fields))
+            }
+            FieldKind::Array { len, stride } => {
+                self.decode_elements(index, shift, len, stride, bytes)
+            }
+            FieldKind::Vec {
+                capacity,
+                len_offset,
+                stride,
+            } => {
+                let len = self.read_len(bytes, position + len_offset, capacity)?;
+                self.decode_elements(index, shift, len, stride, bytes)
+            }
+            FieldKind::String {
+                capacity,
+                len_offset,
+                data_offset,
+            } => {
+                let len = self.read_len(bytes, position + len_offset, capacity)?;
+                let raw = self.read(bytes, position + data_offset, len)?;
+                Ok(SchemaValue::String(
+                    String::from_utf8_lossy(raw).into_owned(),
+                ))
+            }
+            FieldKind::Opaque => Ok(SchemaValue::Opaque(
+                self.read(bytes, position, entry.size)?.to_vec(),
+            )),
+        }
+    }
+
+    fn decode_elements(
+        &self,
+        index: usize,
+        shift: usize,
+        len: usize,
+        stride: usize,
+        bytes: &[u8],
+    ) -> Result<SchemaValue, TypeSchemaDecodeError> {
+        let mut elements = Vec::with_capacity(len);
+        if let Some(element) = self.children(index).next() {
+            for n in 0..len {
+                elements.push(self.decode_entry(element, shift + n * stride, bytes)?);
+            }
+        }
+
+        Ok(SchemaValue::Sequence(elements))
+    }
+}
+
+/// Contains the [`TypeSchema`] of the user header and the payload of a message. It is stored
+/// alongside the
+/// [`MessageTypeDetails`](crate::service::static_config::message_type_details::MessageTypeDetails).
+#[derive(
+    Default, Debug, Clone, Copy, Eq, Hash, PartialEq, ZeroCopySend, Serialize, Deserialize,
+)]
+#[repr(C)]
+pub struct MessageTypeSchema {
+    /// The [`TypeSchema`] of the user header.
+    pub user_header: TypeSchema,
+    /// The [`TypeSchema`] of the payload. For slices, it describes a single element.
+    pub payload: TypeSchema,
+}
+
+impl MessageTypeSchema {
+    pub(crate) fn from<UserHeader: ZeroCopySend, Payload: ZeroCopySend>() -> Self {
+        Self {
+            user_header: TypeSchema::new::<UserHeader>(),
+            payload: TypeSchema::new::<Payload>(),
+        }
+    }
+
+    pub(crate) fn verify_compatibility(
+        &self,
+        existing: &MessageTypeSchema,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), TypeSchemaMismatch> {
+        self.user_header
+            .verify_compatibility(&existing.user_header, compatibility)?;
+        self.payload
+            .verify_compatibility(&existing.payload, compatibility)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iceoryx2_bb_container::vector::*;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::serialize::{Serialize, toml::Toml};
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    struct Position {
+        x: f32,
+        y: i16,
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    #[type_version(2)]
+    struct Track {
+        id: u64,
+        name: StaticString<16>,
+        waypoints: StaticVec<Position, 4>,
+        flags: [bool; 2],
+    }
+
+    fn as_bytes<T>(value: &T) -> &[u8] {
+        unsafe {
+            core::slice::from_raw_parts((value as *const T).cast(), core::mem::size_of::<T>())
+        }
+    }
+
+    fn track() -> Track {
+        let mut waypoints = StaticVec::new();
+        waypoints.push(Position { x: 1.5, y: -2 }).unwrap();
+        waypoints.push(Position { x: 0.25, y: 7 }).unwrap();
+
+        Track {
+            id: 42,
+            name: "hypnotoad".try_into().unwrap(),
+            waypoints,
+            flags: [true, false],
+        }
+    }
+
+    fn position_value(x: f32, y: i128) -> SchemaValue {
+        SchemaValue::Struct(alloc::vec![
+            ("x".to_string(), SchemaValue::F32(x)),
+            ("y".to_string(), SchemaValue::Signed(y)),
+        ])
+    }
+
+    #[test]
+    fn schema_of_primitive_has_single_entry() {
+        let sut = TypeSchema::new::<u32>();
+
+        assert_that!(sut.entries(), len 1);
+        assert_that!(sut.entries()[0].kind(), eq FieldKind::Primitive(PrimitiveType::U32));
+        assert_that!(sut.entries()[0].size(), eq 4);
+        assert_that!(sut.decode(&7u32.to_ne_bytes()), eq Ok(SchemaValue::Unsigned(7)));
+    }
+
+    #[test]
+    fn schema_contains_fields_with_names_and_offsets() {
+        let sut = TypeSchema::new::<Position>();
+
+        assert_that!(sut.entries(), len 3);
+        assert_that!(sut.entries()[0].kind(), eq FieldKind::Struct);
+        assert_that!(sut.entries()[1].name().to_string(), eq "x");
+        assert_that!(sut.entries()[1].depth(), eq 1);
+        assert_that!(sut.entries()[1].offset(), eq 0);
+        assert_that!(sut.entries()[2].name().to_string(), eq "y");
+        assert_that!(sut.entries()[2].offset(), eq 4);
+        assert_that!(sut.entries()[2].kind(), eq FieldKind::Primitive(PrimitiveType::I16));
+    }
+
+    #[test]
+    fn decode_works_with_nested_containers() {
+        let sut = TypeSchema::new::<Track>();
+        let value = track();
+
+        let expected = SchemaValue::Struct(alloc::vec![
+            ("id".to_string(), SchemaValue::Unsigned(42)),
+            (
+                "name".to_string(),
+                SchemaValue::String("hypnotoad".to_string())
+            ),
+            (
+                "waypoints".to_string(),
+                SchemaValue::Sequence(alloc::vec![
+                    position_value(1.5, -2),
+                    position_value(0.25, 7)
+                ])
+            ),
+            (
+                "flags".to_string(),
+                SchemaValue::Sequence(alloc::vec![
+                    SchemaValue::Bool(true),
+                    SchemaValue::Bool(false)
+                ])
+            ),
+        ]);
+        assert_that!(sut.decode(as_bytes(&value)), eq Ok(expected));
+    }
+
+    #[test]
+    fn decode_slice_decodes_every_element() {
+        let sut = TypeSchema::new::<Position>();
+        let value = [Position { x: 3.0, y: 1 }, Position { x: 4.0, y: 2 }];
+
+        assert_that!(sut.decode_slice(as_bytes(&value)), eq Ok(SchemaValue::Sequence(alloc::vec![
+            position_value(3.0, 1),
+            position_value(4.0, 2)
+        ])));
+    }
+
+    #[test]
+    fn decode_fails_without_schema() {
+        let sut = TypeSchema::default();
+
+        assert_that!(sut.is_empty(), eq true);
+        assert_that!(sut.decode(&[0u8; 8]), eq Err(TypeSchemaDecodeError::NoSchemaAvailable));
+        assert_that!(sut.decode_slice(&[0u8; 8]), eq Err(TypeSchemaDecodeError::NoSchemaAvailable));
+    }
+
+    #[test]
+    fn decode_fails_with_insufficient_data() {
+        let sut = TypeSchema::new::<Track>();
+        let value = track();
+        let bytes = as_bytes(&value);
+
+        assert_that!(sut.decode(&bytes[..bytes.len() / 2]), eq Err(TypeSchemaDecodeError::InsufficientData));
+    }
+
+    #[test]
+    fn decode_fails_with_corrupted_length() {
+        let sut = TypeSchema::new::<StaticVec<u8, 4>>();
+        let mut value = StaticVec::<u8, 4>::new();
+        value.push(1).unwrap();
+        let mut bytes = as_bytes(&value).to_vec();
+        let len_offset = match sut.entries()[0].kind() {
+            FieldKind::Vec { len_offset, .. } => len_offset,
+            _ => unreachable!(),
+        };
+        bytes[len_offset..len_offset + 8].copy_from_slice(&5u64.to_ne_bytes());
+
+        assert_that!(sut.decode(&bytes), eq Err(TypeSchemaDecodeError::CorruptedLength));
+    }
+
+    #[test]
+    fn schema_with_too_long_field_name_is_opaque() {
+        #[derive(ZeroCopySend)]
+        #[repr(C)]
+        struct TooLong {
+            a_field_name_that_exceeds_the_maximum_length: u8,
+        }
+
+        let sut = TypeSchema::new::<TooLong>();
+
+        assert_that!(sut.entries(), len 1);
+        assert_that!(sut.entries()[0].kind(), eq FieldKind::Opaque);
+        assert_that!(sut.entries()[0].size(), eq 1);
+    }
+
+    #[test]
+    fn schema_can_be_serialized_and_deserialized() {
+        let sut = MessageTypeSchema::from::<u64, Track>();
+
+        let serialized = Toml::serialize(&sut).unwrap();
+        let deserialized = Toml::deserialize::<MessageTypeSchema>(&serialized).unwrap();
+
+        assert_that!(deserialized, eq sut);
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    #[type_version(1)]
+    struct Sensor {
+        id: u32,
+        value: f32,
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    #[type_version(1)]
+    struct ReorderedSensor {
+        value: f32,
+        id: u32,
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    #[type_version(2)]
+    struct ExtendedSensor {
+        id: u32,
+        value: f32,
+        timestamp: u64,
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    #[type_version(2)]
+    struct NestedExtendedSensor {
+        id: u32,
+        value: f32,
+        position: Position,
+    }
+
+    #[test]
+    fn schema_contains_type_version() {
+        assert_that!(TypeSchema::new::<u64>().version(), eq 0);
+        assert_that!(TypeSchema::new::<Track>().version(), eq 2);
+    }
+
+    #[test]
+    fn fingerprint_differs_for_reordered_fields() {
+        let sut = TypeSchema::new::<Sensor>();
+
+        assert_that!(sut.fingerprint(), eq TypeSchema::new::<Sensor>().fingerprint());
+        assert_that!(sut.fingerprint(), ne TypeSchema::new::<ReorderedSensor>().fingerprint());
+    }
+
+    #[test]
+    fn exact_compatibility_requires_identical_schema_and_version() {
+        let sut = TypeSchema::new::<Sensor>();
+
+        assert_that!(
+            sut.verify_compatibility(&TypeSchema::new::<Sensor>(), SchemaCompatibility::Exact),
+            is_ok
+        );
+        assert_that!(sut.verify_compatibility(&TypeSchema::new::<ReorderedSensor>(), SchemaCompatibility::Exact),
+            eq Err(TypeSchemaMismatch::Schema));
+        assert_that!(sut.verify_compatibility(&TypeSchema::new::<ExtendedSensor>(), SchemaCompatibility::Exact),
+            eq Err(TypeSchemaMismatch::Version));
+    }
+
+    #[test]
+    fn append_only_compatibility_accepts_appended_fields() {
+        let sut = TypeSchema::new::<Sensor>();
+
+        assert_that!(
+            sut.verify_compatibility(
+                &TypeSchema::new::<Sensor>(),
+                SchemaCompatibility::AppendOnly
+            ),
+            is_ok
+        );
+        assert_that!(
+            sut.verify_compatibility(
+                &TypeSchema::new::<ExtendedSensor>(),
+                SchemaCompatibility::AppendOnly
+            ),
+            is_ok
+        );
+        assert_that!(
+            sut.verify_compatibility(
+                &TypeSchema::new::<NestedExtendedSensor>(),
+                SchemaCompatibility::AppendOnly
+            ),
+            is_ok
+        );
+    }
+
+    #[test]
+    fn append_only_compatibility_rejects_modified_fields_and_newer_versions() {
+        let sut = TypeSchema::new::<Sensor>();
+
+        assert_that!(sut.verify_compatibility(&TypeSchema::new::<ReorderedSensor>(), SchemaCompatibility::AppendOnly),
+            eq Err(TypeSchemaMismatch::NotAppendOnly));
+        assert_that!(TypeSchema::new::<ExtendedSensor>().verify_compatibility(&sut, SchemaCompatibility::AppendOnly),
+            eq Err(TypeSchemaMismatch::Version));
+        assert_that!(TypeSchema::new::<NestedExtendedSensor>().verify_compatibility(&TypeSchema::new::<ExtendedSensor>(), SchemaCompatibility::AppendOnly),
+            eq Err(TypeSchemaMismatch::NotAppendOnly));
+    }
+
+    #[test]
+    fn ignore_and_empty_schemas_are_always_compatible() {
+        let sut = TypeSchema::new::<Sensor>();
+
+        assert_that!(
+            sut.verify_compatibility(
+                &TypeSchema::new::<ReorderedSensor>(),
+                SchemaCompatibility::Ignore
+            ),
+            is_ok
+        );
+        assert_that!(
+            sut.verify_compatibility(&TypeSchema::default(), SchemaCompatibility::Exact),
+            is_ok
+        );
+        assert_that!(
+            TypeSchema::default().verify_compatibility(&sut, SchemaCompatibility::Exact),
+            is_ok
+        );
+    }
+}
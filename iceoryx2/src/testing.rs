@@ -16,10 +16,13 @@ use iceoryx2_bb_elementary::math::ToB64;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_posix::{config::TEST_DIRECTORY, testing::*};
 use iceoryx2_bb_system_types::file_name::*;
+use iceoryx2_cal::resizable_shared_memory::ResizableSharedMemory;
+use iceoryx2_cal::shared_memory::SharedMemory;
 
 use crate::{
     config::Config,
     prelude::{NodeName, ServiceName},
+    service::Service,
     service::static_config::message_type_details::{TypeDetail, TypeName, TypeVariant},
 };
 
@@ -53,6 +56,11 @@ pub fn generate_isolated_config() -> Config {
     config
 }
 
+pub fn does_support_read_only_data_segments<S: Service>() -> bool {
+    S::SharedMemory::does_support_read_only_mapping()
+        && S::ResizableSharedMemory::does_support_read_only_mapping()
+}
+
 pub fn create_custom_type_detail(
     variant: TypeVariant,
     type_name: TypeName,
@@ -215,7 +215,7 @@
 
 use core::{fmt::Debug, hash::Hash, marker::PhantomData, time::Duration};
 
-use alloc::collections::BTreeMap;
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::vec;
 use alloc::vec::Vec;
 
@@ -464,9 +464,16 @@ where
 
 impl<Service: crate::service::Service> Drop for WaitSetGuard<'_, '_, Service> {
     fn drop(&mut self) {
-        if let GuardType::Deadline(r, t) = &self.guard_type {
-            self.waitset
-                .remove_deadline(unsafe { r.file_descriptor().native_handle() }, t.index())
+        match &self.guard_type {
+            GuardType::Deadline(r, t) => {
+                let reactor_idx = unsafe { r.file_descriptor().native_handle() };
+                self.waitset.remove_deadline(reactor_idx, t.index());
+                self.waitset.remove_file_descriptor(reactor_idx);
+            }
+            GuardType::Notification(r) => self
+                .waitset
+                .remove_file_descriptor(unsafe { r.file_descriptor().native_handle() }),
+            GuardType::Tick(_) => (),
         }
         self.waitset.detach();
     }
@@ -508,6 +515,7 @@ impl WaitSetBuilder {
                 deadline_queue,
                 attachment_to_deadline: RefCell::new(BTreeMap::new()),
                 deadline_to_attachment: RefCell::new(BTreeMap::new()),
+                attached_file_descriptors: RefCell::new(BTreeSet::new()),
                 attachment_counter: AtomicUsize::new(0),
                 signal_handling_mode: self.signal_handling_mode,
             }),
@@ -540,6 +548,7 @@ pub struct WaitSet<Service: crate::service::Service> {
     deadline_queue: DeadlineQueue,
     attachment_to_deadline: RefCell<BTreeMap<i32, DeadlineQueueIndex>>,
     deadline_to_attachment: RefCell<BTreeMap<DeadlineQueueIndex, i32>>,
+    attached_file_descriptors: RefCell<BTreeSet<i32>>,
     attachment_counter: AtomicUsize,
     signal_handling_mode: SignalHandlingMode,
 }
@@ -568,6 +577,12 @@ impl<Service: crate::service::Service> WaitSet<Service> {
             .remove(&deadline_queue_idx);
     }
 
+    fn remove_file_descriptor(&self, reactor_idx: i32) {
+        self.attached_file_descriptors
+            .borrow_mut()
+            .remove(&reactor_idx);
+    }
+
     fn reset_deadline(
         &self,
         reactor_idx: i32,
@@ -947,6 +962,28 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         self.len() == 0
     }
 
+    /// Returns the native handles of all attached file descriptors. Together with
+    /// [`WaitSet::duration_until_next_deadline()`] it allows to integrate the [`WaitSet`]
+    /// into an external event loop, that waits until one of the file descriptors becomes
+    /// readable or the next deadline is reached and then handles the events with
+    /// [`WaitSet::wait_and_process_once_with_timeout()`] and a timeout of zero.
+    pub fn attached_file_descriptors(&self) -> Vec<i32> {
+        self.attached_file_descriptors
+            .borrow()
+            .iter()
+            .copied()
+            .collect()
+    }
+
+    /// Returns the [`Duration`] until the next interval or deadline attachment must be handled
+    /// or [`Duration::MAX`] when nothing with a timeout is attached.
+    pub fn duration_until_next_deadline(&self) -> Result<Duration, WaitSetRunError> {
+        Ok(fail!(from self,
+                 when self.deadline_queue.duration_until_next_deadline(),
+                 with WaitSetRunError::InternalError,
+                 "Unable to acquire the duration until the next deadline."))
+    }
+
     /// Returns the [`SignalHandlingMode`] with which the [`WaitSet`] was created.
     pub fn signal_handling_mode(&self) -> SignalHandlingMode {
         self.signal_handling_mode
@@ -960,7 +997,12 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         let msg = "Unable to attach object to internal reactor";
 
         match self.reactor.attach(attachment) {
-            Ok(guard) => Ok(guard),
+            Ok(guard) => {
+                self.attached_file_descriptors
+                    .borrow_mut()
+                    .insert(unsafe { guard.file_descriptor().native_handle() });
+                Ok(guard)
+            }
             Err(ReactorAttachError::AlreadyAttached) => {
                 fail!(from self, with WaitSetAttachmentError::AlreadyAttached,
                     "{msg} {:?} since it is already attached.", attachment);
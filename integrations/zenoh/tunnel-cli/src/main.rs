@@ -37,6 +37,7 @@ fn main() -> anyhow::Result<()> {
 
     let tunnel_config = TunnelConfig {
         discovery_service: cli.discovery_service,
+        ..Default::default()
     };
     let iceoryx_config = iceoryx2::config::Config::default();
     let zenoh_config = match cli.zenoh_config {